@@ -466,3 +466,43 @@ fn init() {
     tracing_subscriber::init();
     tracing::info!("Example plugin initialized");
 }
+
+#[fp_export_impl(example_bindings)]
+fn export_panic(message: String) {
+    panic!("{}", message);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // When built for the host with the `mock_host` feature, imported functions
+    // are dispatched to the mock registered for the current thread.
+    struct Mock;
+
+    impl MockImports for Mock {
+        fn import_primitive_bool_negate(&self, arg: bool) -> bool {
+            !arg
+        }
+
+        fn import_primitive_u32_add_one(&self, arg: u32) -> u32 {
+            arg + 1
+        }
+    }
+
+    #[test]
+    fn test_primitives() {
+        set_mock_imports(Mock);
+        assert!(!export_primitive_bool_negate(true));
+        assert_eq!(export_primitive_u32_add_three(1), 4);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "No mock implementation provided for imported function `import_primitive_u8_add_one`"
+    )]
+    fn test_unimplemented_import() {
+        set_mock_imports(Mock);
+        export_primitive_u8_add_three(1);
+    }
+}
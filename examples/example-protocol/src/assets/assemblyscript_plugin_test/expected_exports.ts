@@ -0,0 +1,652 @@
+// ============================================= //
+// Exports for AssemblyScript plugins            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {
+  exportArrayF32,
+  exportArrayF64,
+  exportArrayI16,
+  exportArrayI32,
+  exportArrayI8,
+  exportArrayU16,
+  exportArrayU32,
+  exportArrayU8,
+  exportAsyncStruct,
+  exportFpAdjacentlyTagged,
+  exportFpEnum,
+  exportFpFlatten,
+  exportFpInternallyTagged,
+  exportFpStruct,
+  exportFpUntagged,
+  exportGenerics,
+  exportGetBytes,
+  exportGetSerdeBytes,
+  exportIncrementGlobalState,
+  exportMultiplePrimitives,
+  exportPanic,
+  exportPrimitiveBoolNegate,
+  exportPrimitiveBoolNegateAsync,
+  exportPrimitiveF32AddThree,
+  exportPrimitiveF32AddThreeAsync,
+  exportPrimitiveF32AddThreeWasmer2,
+  exportPrimitiveF64AddThree,
+  exportPrimitiveF64AddThreeAsync,
+  exportPrimitiveF64AddThreeWasmer2,
+  exportPrimitiveI16AddThree,
+  exportPrimitiveI16AddThreeAsync,
+  exportPrimitiveI32AddThree,
+  exportPrimitiveI32AddThreeAsync,
+  exportPrimitiveI64AddThree,
+  exportPrimitiveI64AddThreeAsync,
+  exportPrimitiveI8AddThree,
+  exportPrimitiveI8AddThreeAsync,
+  exportPrimitiveU16AddThree,
+  exportPrimitiveU16AddThreeAsync,
+  exportPrimitiveU32AddThree,
+  exportPrimitiveU32AddThreeAsync,
+  exportPrimitiveU64AddThree,
+  exportPrimitiveU64AddThreeAsync,
+  exportPrimitiveU8AddThree,
+  exportPrimitiveU8AddThreeAsync,
+  exportResetGlobalState,
+  exportSerdeAdjacentlyTagged,
+  exportSerdeEnum,
+  exportSerdeFlatten,
+  exportSerdeInternallyTagged,
+  exportSerdeStruct,
+  exportSerdeUntagged,
+  exportString,
+  exportStructWithOptions,
+  exportTimestamp,
+  exportVoidFunction,
+  fetchData,
+  init,
+  reducerBridge,
+} from "../plugin";
+import {
+  FatPtr,
+  Reader,
+  Writer,
+  createAsyncValue,
+  decode_String,
+  encode_String,
+  panic,
+} from "./support";
+import {
+  decode_Array_f32_3,
+  decode_Array_f64_3,
+  decode_Array_i16_3,
+  decode_Array_i32_3,
+  decode_Array_i8_3,
+  decode_Array_u16_3,
+  decode_Array_u32_3,
+  decode_Array_u8_3,
+  decode_FpAdjacentlyTagged,
+  decode_FpFlatten,
+  decode_FpInternallyTagged,
+  decode_FpPropertyRenaming,
+  decode_FpUntagged,
+  decode_FpVariantRenaming,
+  decode_ReduxAction,
+  decode_SerdeAdjacentlyTagged,
+  decode_SerdeFlatten,
+  decode_SerdeInternallyTagged,
+  decode_SerdePropertyRenaming,
+  decode_SerdeUntagged,
+  decode_SerdeVariantRenaming,
+  decode_StructWithGenerics_u64,
+  decode_StructWithOptions,
+  encode_Array_f32_3,
+  encode_Array_f64_3,
+  encode_Array_i16_3,
+  encode_Array_i32_3,
+  encode_Array_i8_3,
+  encode_Array_u16_3,
+  encode_Array_u32_3,
+  encode_Array_u8_3,
+  encode_FpAdjacentlyTagged,
+  encode_FpFlatten,
+  encode_FpInternallyTagged,
+  encode_FpPropertyRenaming,
+  encode_FpUntagged,
+  encode_FpVariantRenaming,
+  encode_Result_Bytes_String,
+  encode_SerdeAdjacentlyTagged,
+  encode_SerdeFlatten,
+  encode_SerdeInternallyTagged,
+  encode_SerdePropertyRenaming,
+  encode_SerdeUntagged,
+  encode_SerdeVariantRenaming,
+  encode_StateUpdate,
+  encode_StructWithGenerics_u64,
+  encode_StructWithOptions,
+} from "./types";
+
+export { __fp_free, __fp_guest_resolve_async_value, __fp_malloc } from "./support";
+
+export function __fp_gen_export_array_f32(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_f32_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayF32()`");
+  }
+  argReader.close();
+  const result = exportArrayF32(arg);
+  const writer = new Writer();
+  encode_Array_f32_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_array_f64(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_f64_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayF64()`");
+  }
+  argReader.close();
+  const result = exportArrayF64(arg);
+  const writer = new Writer();
+  encode_Array_f64_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_array_i16(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_i16_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayI16()`");
+  }
+  argReader.close();
+  const result = exportArrayI16(arg);
+  const writer = new Writer();
+  encode_Array_i16_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_array_i32(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_i32_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayI32()`");
+  }
+  argReader.close();
+  const result = exportArrayI32(arg);
+  const writer = new Writer();
+  encode_Array_i32_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_array_i8(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_i8_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayI8()`");
+  }
+  argReader.close();
+  const result = exportArrayI8(arg);
+  const writer = new Writer();
+  encode_Array_i8_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_array_u16(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_u16_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayU16()`");
+  }
+  argReader.close();
+  const result = exportArrayU16(arg);
+  const writer = new Writer();
+  encode_Array_u16_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_array_u32(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_u32_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayU32()`");
+  }
+  argReader.close();
+  const result = exportArrayU32(arg);
+  const writer = new Writer();
+  encode_Array_u32_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_array_u8(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_Array_u8_3(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportArrayU8()`");
+  }
+  argReader.close();
+  const result = exportArrayU8(arg);
+  const writer = new Writer();
+  encode_Array_u8_3(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_async_struct(arg1Ptr: FatPtr, arg2: u64): FatPtr {
+  const arg1Reader = Reader.open(arg1Ptr);
+  const arg1 = decode_FpPropertyRenaming(arg1Reader);
+  if (arg1Reader.error) {
+    panic("Could not decode argument `arg1` of `exportAsyncStruct()`");
+  }
+  arg1Reader.close();
+  const asyncValue = createAsyncValue();
+  exportAsyncStruct(arg1, arg2, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_fp_adjacently_tagged(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_FpAdjacentlyTagged(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportFpAdjacentlyTagged()`");
+  }
+  argReader.close();
+  const result = exportFpAdjacentlyTagged(arg);
+  const writer = new Writer();
+  encode_FpAdjacentlyTagged(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_fp_enum(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_FpVariantRenaming(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportFpEnum()`");
+  }
+  argReader.close();
+  const result = exportFpEnum(arg);
+  const writer = new Writer();
+  encode_FpVariantRenaming(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_fp_flatten(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_FpFlatten(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportFpFlatten()`");
+  }
+  argReader.close();
+  const result = exportFpFlatten(arg);
+  const writer = new Writer();
+  encode_FpFlatten(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_fp_internally_tagged(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_FpInternallyTagged(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportFpInternallyTagged()`");
+  }
+  argReader.close();
+  const result = exportFpInternallyTagged(arg);
+  const writer = new Writer();
+  encode_FpInternallyTagged(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_fp_struct(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_FpPropertyRenaming(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportFpStruct()`");
+  }
+  argReader.close();
+  const result = exportFpStruct(arg);
+  const writer = new Writer();
+  encode_FpPropertyRenaming(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_fp_untagged(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_FpUntagged(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportFpUntagged()`");
+  }
+  argReader.close();
+  const result = exportFpUntagged(arg);
+  const writer = new Writer();
+  encode_FpUntagged(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_generics(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_StructWithGenerics_u64(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportGenerics()`");
+  }
+  argReader.close();
+  const result = exportGenerics(arg);
+  const writer = new Writer();
+  encode_StructWithGenerics_u64(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_get_bytes(): FatPtr {
+  const result = exportGetBytes();
+  const writer = new Writer();
+  encode_Result_Bytes_String(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_get_serde_bytes(): FatPtr {
+  const result = exportGetSerdeBytes();
+  const writer = new Writer();
+  encode_Result_Bytes_String(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_increment_global_state(): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportIncrementGlobalState(asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_multiple_primitives(arg1: i8, arg2Ptr: FatPtr): i64 {
+  const arg2Reader = Reader.open(arg2Ptr);
+  const arg2 = decode_String(arg2Reader);
+  if (arg2Reader.error) {
+    panic("Could not decode argument `arg2` of `exportMultiplePrimitives()`");
+  }
+  arg2Reader.close();
+  return exportMultiplePrimitives(arg1, arg2);
+}
+
+export function __fp_gen_export_panic(messagePtr: FatPtr): void {
+  const messageReader = Reader.open(messagePtr);
+  const message = decode_String(messageReader);
+  if (messageReader.error) {
+    panic("Could not decode argument `message` of `exportPanic()`");
+  }
+  messageReader.close();
+  exportPanic(message);
+}
+
+export function __fp_gen_export_primitive_bool_negate(arg: bool): bool {
+  return exportPrimitiveBoolNegate(arg);
+}
+
+export function __fp_gen_export_primitive_bool_negate_async(arg: bool): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveBoolNegateAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_f32_add_three(arg: f32): f32 {
+  return exportPrimitiveF32AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_f32_add_three_async(arg: f32): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveF32AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_f32_add_three_wasmer2(arg: f32): f32 {
+  return exportPrimitiveF32AddThreeWasmer2(arg);
+}
+
+export function __fp_gen_export_primitive_f64_add_three(arg: f64): f64 {
+  return exportPrimitiveF64AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_f64_add_three_async(arg: f64): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveF64AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_f64_add_three_wasmer2(arg: f64): f64 {
+  return exportPrimitiveF64AddThreeWasmer2(arg);
+}
+
+export function __fp_gen_export_primitive_i16_add_three(arg: i16): i16 {
+  return exportPrimitiveI16AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_i16_add_three_async(arg: i16): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveI16AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_i32_add_three(arg: i32): i32 {
+  return exportPrimitiveI32AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_i32_add_three_async(arg: i32): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveI32AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_i64_add_three(arg: i64): i64 {
+  return exportPrimitiveI64AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_i64_add_three_async(arg: i64): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveI64AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_i8_add_three(arg: i8): i8 {
+  return exportPrimitiveI8AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_i8_add_three_async(arg: i8): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveI8AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_u16_add_three(arg: u16): u16 {
+  return exportPrimitiveU16AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_u16_add_three_async(arg: u16): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveU16AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_u32_add_three(arg: u32): u32 {
+  return exportPrimitiveU32AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_u32_add_three_async(arg: u32): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveU32AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_u64_add_three(arg: u64): u64 {
+  return exportPrimitiveU64AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_u64_add_three_async(arg: u64): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveU64AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_primitive_u8_add_three(arg: u8): u8 {
+  return exportPrimitiveU8AddThree(arg);
+}
+
+export function __fp_gen_export_primitive_u8_add_three_async(arg: u8): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportPrimitiveU8AddThreeAsync(arg, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_reset_global_state(): FatPtr {
+  const asyncValue = createAsyncValue();
+  exportResetGlobalState(asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_export_serde_adjacently_tagged(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_SerdeAdjacentlyTagged(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportSerdeAdjacentlyTagged()`");
+  }
+  argReader.close();
+  const result = exportSerdeAdjacentlyTagged(arg);
+  const writer = new Writer();
+  encode_SerdeAdjacentlyTagged(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_serde_enum(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_SerdeVariantRenaming(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportSerdeEnum()`");
+  }
+  argReader.close();
+  const result = exportSerdeEnum(arg);
+  const writer = new Writer();
+  encode_SerdeVariantRenaming(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_serde_flatten(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_SerdeFlatten(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportSerdeFlatten()`");
+  }
+  argReader.close();
+  const result = exportSerdeFlatten(arg);
+  const writer = new Writer();
+  encode_SerdeFlatten(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_serde_internally_tagged(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_SerdeInternallyTagged(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportSerdeInternallyTagged()`");
+  }
+  argReader.close();
+  const result = exportSerdeInternallyTagged(arg);
+  const writer = new Writer();
+  encode_SerdeInternallyTagged(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_serde_struct(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_SerdePropertyRenaming(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportSerdeStruct()`");
+  }
+  argReader.close();
+  const result = exportSerdeStruct(arg);
+  const writer = new Writer();
+  encode_SerdePropertyRenaming(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_serde_untagged(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_SerdeUntagged(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportSerdeUntagged()`");
+  }
+  argReader.close();
+  const result = exportSerdeUntagged(arg);
+  const writer = new Writer();
+  encode_SerdeUntagged(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_string(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_String(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportString()`");
+  }
+  argReader.close();
+  const result = exportString(arg);
+  const writer = new Writer();
+  encode_String(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_struct_with_options(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_StructWithOptions(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportStructWithOptions()`");
+  }
+  argReader.close();
+  const result = exportStructWithOptions(arg);
+  const writer = new Writer();
+  encode_StructWithOptions(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_timestamp(argPtr: FatPtr): FatPtr {
+  const argReader = Reader.open(argPtr);
+  const arg = decode_String(argReader);
+  if (argReader.error) {
+    panic("Could not decode argument `arg` of `exportTimestamp()`");
+  }
+  argReader.close();
+  const result = exportTimestamp(arg);
+  const writer = new Writer();
+  encode_String(writer, result);
+  return writer.finish();
+}
+
+export function __fp_gen_export_void_function(): void {
+  exportVoidFunction();
+}
+
+export function __fp_gen_fetch_data(type_Ptr: FatPtr): FatPtr {
+  const type_Reader = Reader.open(type_Ptr);
+  const type_ = decode_String(type_Reader);
+  if (type_Reader.error) {
+    panic("Could not decode argument `type` of `fetchData()`");
+  }
+  type_Reader.close();
+  const asyncValue = createAsyncValue();
+  fetchData(type_, asyncValue);
+  return asyncValue;
+}
+
+export function __fp_gen_init(): void {
+  init();
+}
+
+export function __fp_gen_reducer_bridge(actionPtr: FatPtr): FatPtr {
+  const actionReader = Reader.open(actionPtr);
+  const action = decode_ReduxAction(actionReader);
+  if (actionReader.error) {
+    panic("Could not decode argument `action` of `reducerBridge()`");
+  }
+  actionReader.close();
+  const result = reducerBridge(action);
+  const writer = new Writer();
+  encode_StateUpdate(writer, result);
+  return writer.finish();
+}
+
+export function __fp_protocol_version(): u64 {
+  return 0x1e72896d627cc76e;
+}
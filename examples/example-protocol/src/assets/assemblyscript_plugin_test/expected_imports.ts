@@ -0,0 +1,1224 @@
+// ============================================= //
+// Imports for AssemblyScript plugins            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {
+  AsyncValue,
+  FatPtr,
+  Reader,
+  Writer,
+  awaitAsyncValue,
+  decode_String,
+  decode_bool,
+  decode_f32,
+  decode_f64,
+  decode_i16,
+  decode_i32,
+  decode_i64,
+  decode_i8,
+  decode_u16,
+  decode_u32,
+  decode_u64,
+  decode_u8,
+  encode_String,
+  encode_bool,
+  encode_f32,
+  encode_f64,
+  encode_i16,
+  encode_i32,
+  encode_i64,
+  encode_i8,
+  encode_u16,
+  encode_u32,
+  encode_u64,
+  encode_u8,
+  panic,
+  resolveAsyncValue,
+} from "./support";
+import {
+  ExplicitBoundPoint_u64,
+  FpAdjacentlyTagged,
+  FpFlatten,
+  FpInternallyTagged,
+  FpPropertyRenaming,
+  FpUntagged,
+  FpVariantRenaming,
+  HttpResult,
+  MyDateTime,
+  Request,
+  Result_Bytes_String,
+  Result_String_String,
+  Result_Unit_u32,
+  SerdeAdjacentlyTagged,
+  SerdeFlatten,
+  SerdeInternallyTagged,
+  SerdePropertyRenaming,
+  SerdeUntagged,
+  SerdeVariantRenaming,
+  StructWithGenerics_u64,
+  StructWithOptions,
+  decode_Array_f32_3,
+  decode_Array_f64_3,
+  decode_Array_i16_3,
+  decode_Array_i32_3,
+  decode_Array_i8_3,
+  decode_Array_u16_3,
+  decode_Array_u32_3,
+  decode_Array_u8_3,
+  decode_FpAdjacentlyTagged,
+  decode_FpFlatten,
+  decode_FpInternallyTagged,
+  decode_FpPropertyRenaming,
+  decode_FpUntagged,
+  decode_FpVariantRenaming,
+  decode_Result_Bytes_String,
+  decode_Result_Response_RequestError,
+  decode_Result_Unit_u32,
+  decode_SerdeAdjacentlyTagged,
+  decode_SerdeFlatten,
+  decode_SerdeInternallyTagged,
+  decode_SerdePropertyRenaming,
+  decode_SerdeUntagged,
+  decode_SerdeVariantRenaming,
+  decode_StructWithGenerics_u64,
+  decode_StructWithOptions,
+  encode_Array_f32_1,
+  encode_Array_f32_3,
+  encode_Array_f64_1,
+  encode_Array_f64_3,
+  encode_Array_i16_3,
+  encode_Array_i32_3,
+  encode_Array_i8_3,
+  encode_Array_u16_3,
+  encode_Array_u32_3,
+  encode_Array_u8_3,
+  encode_ExplicitBoundPoint_u64,
+  encode_FpAdjacentlyTagged,
+  encode_FpFlatten,
+  encode_FpInternallyTagged,
+  encode_FpPropertyRenaming,
+  encode_FpUntagged,
+  encode_FpVariantRenaming,
+  encode_Request,
+  encode_Result_String_String,
+  encode_SerdeAdjacentlyTagged,
+  encode_SerdeFlatten,
+  encode_SerdeInternallyTagged,
+  encode_SerdePropertyRenaming,
+  encode_SerdeUntagged,
+  encode_SerdeVariantRenaming,
+  encode_StructWithGenerics_u64,
+  encode_StructWithOptions,
+} from "./types";
+
+// Functions imported from the host.
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_f32")
+declare function __fp_gen_import_array_f32(arg: FatPtr): FatPtr;
+
+export function importArrayF32(arg: StaticArray<f32>): StaticArray<f32> {
+  const argWriter = new Writer();
+  encode_Array_f32_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_f32(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_f32_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayF32()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_f64")
+declare function __fp_gen_import_array_f64(arg: FatPtr): FatPtr;
+
+export function importArrayF64(arg: StaticArray<f64>): StaticArray<f64> {
+  const argWriter = new Writer();
+  encode_Array_f64_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_f64(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_f64_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayF64()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_i16")
+declare function __fp_gen_import_array_i16(arg: FatPtr): FatPtr;
+
+export function importArrayI16(arg: StaticArray<i16>): StaticArray<i16> {
+  const argWriter = new Writer();
+  encode_Array_i16_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_i16(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_i16_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayI16()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_i32")
+declare function __fp_gen_import_array_i32(arg: FatPtr): FatPtr;
+
+export function importArrayI32(arg: StaticArray<i32>): StaticArray<i32> {
+  const argWriter = new Writer();
+  encode_Array_i32_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_i32(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_i32_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayI32()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_i8")
+declare function __fp_gen_import_array_i8(arg: FatPtr): FatPtr;
+
+export function importArrayI8(arg: StaticArray<i8>): StaticArray<i8> {
+  const argWriter = new Writer();
+  encode_Array_i8_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_i8(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_i8_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayI8()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_u16")
+declare function __fp_gen_import_array_u16(arg: FatPtr): FatPtr;
+
+export function importArrayU16(arg: StaticArray<u16>): StaticArray<u16> {
+  const argWriter = new Writer();
+  encode_Array_u16_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_u16(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_u16_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayU16()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_u32")
+declare function __fp_gen_import_array_u32(arg: FatPtr): FatPtr;
+
+export function importArrayU32(arg: StaticArray<u32>): StaticArray<u32> {
+  const argWriter = new Writer();
+  encode_Array_u32_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_u32(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_u32_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayU32()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_array_u8")
+declare function __fp_gen_import_array_u8(arg: FatPtr): FatPtr;
+
+export function importArrayU8(arg: StaticArray<u8>): StaticArray<u8> {
+  const argWriter = new Writer();
+  encode_Array_u8_3(argWriter, arg);
+  const resultPtr = __fp_gen_import_array_u8(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_Array_u8_3(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importArrayU8()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_explicit_bound_point")
+declare function __fp_gen_import_explicit_bound_point(arg: FatPtr): void;
+
+export function importExplicitBoundPoint(arg: ExplicitBoundPoint_u64): void {
+  const argWriter = new Writer();
+  encode_ExplicitBoundPoint_u64(argWriter, arg);
+  __fp_gen_import_explicit_bound_point(argWriter.finish());
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_adjacently_tagged")
+declare function __fp_gen_import_fp_adjacently_tagged(arg: FatPtr): FatPtr;
+
+export function importFpAdjacentlyTagged(arg: FpAdjacentlyTagged): FpAdjacentlyTagged {
+  const argWriter = new Writer();
+  encode_FpAdjacentlyTagged(argWriter, arg);
+  const resultPtr = __fp_gen_import_fp_adjacently_tagged(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_FpAdjacentlyTagged(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importFpAdjacentlyTagged()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_enum")
+declare function __fp_gen_import_fp_enum(arg: FatPtr): FatPtr;
+
+export function importFpEnum(arg: FpVariantRenaming): FpVariantRenaming {
+  const argWriter = new Writer();
+  encode_FpVariantRenaming(argWriter, arg);
+  const resultPtr = __fp_gen_import_fp_enum(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_FpVariantRenaming(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importFpEnum()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_flatten")
+declare function __fp_gen_import_fp_flatten(arg: FatPtr): FatPtr;
+
+export function importFpFlatten(arg: FpFlatten): FpFlatten {
+  const argWriter = new Writer();
+  encode_FpFlatten(argWriter, arg);
+  const resultPtr = __fp_gen_import_fp_flatten(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_FpFlatten(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importFpFlatten()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_internally_tagged")
+declare function __fp_gen_import_fp_internally_tagged(arg: FatPtr): FatPtr;
+
+export function importFpInternallyTagged(arg: FpInternallyTagged): FpInternallyTagged {
+  const argWriter = new Writer();
+  encode_FpInternallyTagged(argWriter, arg);
+  const resultPtr = __fp_gen_import_fp_internally_tagged(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_FpInternallyTagged(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importFpInternallyTagged()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_struct")
+declare function __fp_gen_import_fp_struct(arg: FatPtr): FatPtr;
+
+export function importFpStruct(arg: FpPropertyRenaming): FpPropertyRenaming {
+  const argWriter = new Writer();
+  encode_FpPropertyRenaming(argWriter, arg);
+  const resultPtr = __fp_gen_import_fp_struct(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_FpPropertyRenaming(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importFpStruct()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_untagged")
+declare function __fp_gen_import_fp_untagged(arg: FatPtr): FatPtr;
+
+export function importFpUntagged(arg: FpUntagged): FpUntagged {
+  const argWriter = new Writer();
+  encode_FpUntagged(argWriter, arg);
+  const resultPtr = __fp_gen_import_fp_untagged(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_FpUntagged(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importFpUntagged()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_generics")
+declare function __fp_gen_import_generics(arg: FatPtr): FatPtr;
+
+export function importGenerics(arg: StructWithGenerics_u64): StructWithGenerics_u64 {
+  const argWriter = new Writer();
+  encode_StructWithGenerics_u64(argWriter, arg);
+  const resultPtr = __fp_gen_import_generics(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_StructWithGenerics_u64(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importGenerics()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_get_bytes")
+declare function __fp_gen_import_get_bytes(): FatPtr;
+
+export function importGetBytes(): Result_Bytes_String {
+  const resultPtr = __fp_gen_import_get_bytes();
+  const reader = Reader.open(resultPtr);
+  const result = decode_Result_Bytes_String(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importGetBytes()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_get_serde_bytes")
+declare function __fp_gen_import_get_serde_bytes(): FatPtr;
+
+export function importGetSerdeBytes(): Result_Bytes_String {
+  const resultPtr = __fp_gen_import_get_serde_bytes();
+  const reader = Reader.open(resultPtr);
+  const result = decode_Result_Bytes_String(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importGetSerdeBytes()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_increment_global_state")
+declare function __fp_gen_import_increment_global_state(): FatPtr;
+
+export type ImportIncrementGlobalStateCallback = () => void;
+
+const importIncrementGlobalStateCallbacks = new Map<FatPtr, ImportIncrementGlobalStateCallback>();
+
+function importIncrementGlobalStateReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importIncrementGlobalStateCallbacks.get(asyncValuePtr);
+  importIncrementGlobalStateCallbacks.delete(asyncValuePtr);
+  Reader.open(resultPtr).close();
+  callback();
+}
+
+export function importIncrementGlobalState(callback: ImportIncrementGlobalStateCallback): void {
+  const asyncValuePtr = __fp_gen_import_increment_global_state();
+  importIncrementGlobalStateCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importIncrementGlobalStateReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_multiple_primitives")
+declare function __fp_gen_import_multiple_primitives(arg1: i8, arg2: FatPtr): i64;
+
+export function importMultiplePrimitives(arg1: i8, arg2: string): i64 {
+  const arg2Writer = new Writer();
+  encode_String(arg2Writer, arg2);
+  return __fp_gen_import_multiple_primitives(arg1, arg2Writer.finish());
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_bool_negate")
+declare function __fp_gen_import_primitive_bool_negate(arg: bool): bool;
+
+export function importPrimitiveBoolNegate(arg: bool): bool {
+  return __fp_gen_import_primitive_bool_negate(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_bool_negate_async")
+declare function __fp_gen_import_primitive_bool_negate_async(arg: bool): FatPtr;
+
+export type ImportPrimitiveBoolNegateAsyncCallback = (result: bool) => void;
+
+const importPrimitiveBoolNegateAsyncCallbacks = new Map<FatPtr, ImportPrimitiveBoolNegateAsyncCallback>();
+
+function importPrimitiveBoolNegateAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveBoolNegateAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveBoolNegateAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_bool(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveBoolNegateAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveBoolNegateAsync(arg: bool, callback: ImportPrimitiveBoolNegateAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_bool_negate_async(arg);
+  importPrimitiveBoolNegateAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveBoolNegateAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f32_add_one")
+declare function __fp_gen_import_primitive_f32_add_one(arg: f32): f32;
+
+export function importPrimitiveF32AddOne(arg: f32): f32 {
+  return __fp_gen_import_primitive_f32_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f32_add_one_async")
+declare function __fp_gen_import_primitive_f32_add_one_async(arg: f32): FatPtr;
+
+export type ImportPrimitiveF32AddOneAsyncCallback = (result: f32) => void;
+
+const importPrimitiveF32AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveF32AddOneAsyncCallback>();
+
+function importPrimitiveF32AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveF32AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveF32AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_f32(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveF32AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveF32AddOneAsync(arg: f32, callback: ImportPrimitiveF32AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_f32_add_one_async(arg);
+  importPrimitiveF32AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveF32AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f32_add_one_wasmer2")
+declare function __fp_gen_import_primitive_f32_add_one_wasmer2(arg: FatPtr): f32;
+
+export function importPrimitiveF32AddOneWasmer2(arg: StaticArray<f32>): f32 {
+  const argWriter = new Writer();
+  encode_Array_f32_1(argWriter, arg);
+  return __fp_gen_import_primitive_f32_add_one_wasmer2(argWriter.finish());
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f64_add_one")
+declare function __fp_gen_import_primitive_f64_add_one(arg: f64): f64;
+
+export function importPrimitiveF64AddOne(arg: f64): f64 {
+  return __fp_gen_import_primitive_f64_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f64_add_one_async")
+declare function __fp_gen_import_primitive_f64_add_one_async(arg: f64): FatPtr;
+
+export type ImportPrimitiveF64AddOneAsyncCallback = (result: f64) => void;
+
+const importPrimitiveF64AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveF64AddOneAsyncCallback>();
+
+function importPrimitiveF64AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveF64AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveF64AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_f64(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveF64AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveF64AddOneAsync(arg: f64, callback: ImportPrimitiveF64AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_f64_add_one_async(arg);
+  importPrimitiveF64AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveF64AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f64_add_one_wasmer2")
+declare function __fp_gen_import_primitive_f64_add_one_wasmer2(arg: FatPtr): f64;
+
+export function importPrimitiveF64AddOneWasmer2(arg: StaticArray<f64>): f64 {
+  const argWriter = new Writer();
+  encode_Array_f64_1(argWriter, arg);
+  return __fp_gen_import_primitive_f64_add_one_wasmer2(argWriter.finish());
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i16_add_one")
+declare function __fp_gen_import_primitive_i16_add_one(arg: i16): i16;
+
+export function importPrimitiveI16AddOne(arg: i16): i16 {
+  return __fp_gen_import_primitive_i16_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i16_add_one_async")
+declare function __fp_gen_import_primitive_i16_add_one_async(arg: i16): FatPtr;
+
+export type ImportPrimitiveI16AddOneAsyncCallback = (result: i16) => void;
+
+const importPrimitiveI16AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveI16AddOneAsyncCallback>();
+
+function importPrimitiveI16AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveI16AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveI16AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_i16(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveI16AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveI16AddOneAsync(arg: i16, callback: ImportPrimitiveI16AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_i16_add_one_async(arg);
+  importPrimitiveI16AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveI16AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i32_add_one")
+declare function __fp_gen_import_primitive_i32_add_one(arg: i32): i32;
+
+export function importPrimitiveI32AddOne(arg: i32): i32 {
+  return __fp_gen_import_primitive_i32_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i32_add_one_async")
+declare function __fp_gen_import_primitive_i32_add_one_async(arg: i32): FatPtr;
+
+export type ImportPrimitiveI32AddOneAsyncCallback = (result: i32) => void;
+
+const importPrimitiveI32AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveI32AddOneAsyncCallback>();
+
+function importPrimitiveI32AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveI32AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveI32AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_i32(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveI32AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveI32AddOneAsync(arg: i32, callback: ImportPrimitiveI32AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_i32_add_one_async(arg);
+  importPrimitiveI32AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveI32AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i64_add_one")
+declare function __fp_gen_import_primitive_i64_add_one(arg: i64): i64;
+
+export function importPrimitiveI64AddOne(arg: i64): i64 {
+  return __fp_gen_import_primitive_i64_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i64_add_one_async")
+declare function __fp_gen_import_primitive_i64_add_one_async(arg: i64): FatPtr;
+
+export type ImportPrimitiveI64AddOneAsyncCallback = (result: i64) => void;
+
+const importPrimitiveI64AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveI64AddOneAsyncCallback>();
+
+function importPrimitiveI64AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveI64AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveI64AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_i64(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveI64AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveI64AddOneAsync(arg: i64, callback: ImportPrimitiveI64AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_i64_add_one_async(arg);
+  importPrimitiveI64AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveI64AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i8_add_one")
+declare function __fp_gen_import_primitive_i8_add_one(arg: i8): i8;
+
+export function importPrimitiveI8AddOne(arg: i8): i8 {
+  return __fp_gen_import_primitive_i8_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i8_add_one_async")
+declare function __fp_gen_import_primitive_i8_add_one_async(arg: i8): FatPtr;
+
+export type ImportPrimitiveI8AddOneAsyncCallback = (result: i8) => void;
+
+const importPrimitiveI8AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveI8AddOneAsyncCallback>();
+
+function importPrimitiveI8AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveI8AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveI8AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_i8(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveI8AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveI8AddOneAsync(arg: i8, callback: ImportPrimitiveI8AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_i8_add_one_async(arg);
+  importPrimitiveI8AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveI8AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u16_add_one")
+declare function __fp_gen_import_primitive_u16_add_one(arg: u16): u16;
+
+export function importPrimitiveU16AddOne(arg: u16): u16 {
+  return __fp_gen_import_primitive_u16_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u16_add_one_async")
+declare function __fp_gen_import_primitive_u16_add_one_async(arg: u16): FatPtr;
+
+export type ImportPrimitiveU16AddOneAsyncCallback = (result: u16) => void;
+
+const importPrimitiveU16AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveU16AddOneAsyncCallback>();
+
+function importPrimitiveU16AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveU16AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveU16AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_u16(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveU16AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveU16AddOneAsync(arg: u16, callback: ImportPrimitiveU16AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_u16_add_one_async(arg);
+  importPrimitiveU16AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveU16AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u32_add_one")
+declare function __fp_gen_import_primitive_u32_add_one(arg: u32): u32;
+
+export function importPrimitiveU32AddOne(arg: u32): u32 {
+  return __fp_gen_import_primitive_u32_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u32_add_one_async")
+declare function __fp_gen_import_primitive_u32_add_one_async(arg: u32): FatPtr;
+
+export type ImportPrimitiveU32AddOneAsyncCallback = (result: u32) => void;
+
+const importPrimitiveU32AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveU32AddOneAsyncCallback>();
+
+function importPrimitiveU32AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveU32AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveU32AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_u32(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveU32AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveU32AddOneAsync(arg: u32, callback: ImportPrimitiveU32AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_u32_add_one_async(arg);
+  importPrimitiveU32AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveU32AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u64_add_one")
+declare function __fp_gen_import_primitive_u64_add_one(arg: u64): u64;
+
+export function importPrimitiveU64AddOne(arg: u64): u64 {
+  return __fp_gen_import_primitive_u64_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u64_add_one_async")
+declare function __fp_gen_import_primitive_u64_add_one_async(arg: u64): FatPtr;
+
+export type ImportPrimitiveU64AddOneAsyncCallback = (result: u64) => void;
+
+const importPrimitiveU64AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveU64AddOneAsyncCallback>();
+
+function importPrimitiveU64AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveU64AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveU64AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_u64(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveU64AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveU64AddOneAsync(arg: u64, callback: ImportPrimitiveU64AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_u64_add_one_async(arg);
+  importPrimitiveU64AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveU64AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u8_add_one")
+declare function __fp_gen_import_primitive_u8_add_one(arg: u8): u8;
+
+export function importPrimitiveU8AddOne(arg: u8): u8 {
+  return __fp_gen_import_primitive_u8_add_one(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u8_add_one_async")
+declare function __fp_gen_import_primitive_u8_add_one_async(arg: u8): FatPtr;
+
+export type ImportPrimitiveU8AddOneAsyncCallback = (result: u8) => void;
+
+const importPrimitiveU8AddOneAsyncCallbacks = new Map<FatPtr, ImportPrimitiveU8AddOneAsyncCallback>();
+
+function importPrimitiveU8AddOneAsyncReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importPrimitiveU8AddOneAsyncCallbacks.get(asyncValuePtr);
+  importPrimitiveU8AddOneAsyncCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_u8(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importPrimitiveU8AddOneAsync()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+export function importPrimitiveU8AddOneAsync(arg: u8, callback: ImportPrimitiveU8AddOneAsyncCallback): void {
+  const asyncValuePtr = __fp_gen_import_primitive_u8_add_one_async(arg);
+  importPrimitiveU8AddOneAsyncCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importPrimitiveU8AddOneAsyncReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_reset_global_state")
+declare function __fp_gen_import_reset_global_state(): FatPtr;
+
+export type ImportResetGlobalStateCallback = () => void;
+
+const importResetGlobalStateCallbacks = new Map<FatPtr, ImportResetGlobalStateCallback>();
+
+function importResetGlobalStateReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = importResetGlobalStateCallbacks.get(asyncValuePtr);
+  importResetGlobalStateCallbacks.delete(asyncValuePtr);
+  Reader.open(resultPtr).close();
+  callback();
+}
+
+export function importResetGlobalState(callback: ImportResetGlobalStateCallback): void {
+  const asyncValuePtr = __fp_gen_import_reset_global_state();
+  importResetGlobalStateCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, importResetGlobalStateReady);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_adjacently_tagged")
+declare function __fp_gen_import_serde_adjacently_tagged(arg: FatPtr): FatPtr;
+
+export function importSerdeAdjacentlyTagged(arg: SerdeAdjacentlyTagged): SerdeAdjacentlyTagged {
+  const argWriter = new Writer();
+  encode_SerdeAdjacentlyTagged(argWriter, arg);
+  const resultPtr = __fp_gen_import_serde_adjacently_tagged(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_SerdeAdjacentlyTagged(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importSerdeAdjacentlyTagged()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_enum")
+declare function __fp_gen_import_serde_enum(arg: FatPtr): FatPtr;
+
+export function importSerdeEnum(arg: SerdeVariantRenaming): SerdeVariantRenaming {
+  const argWriter = new Writer();
+  encode_SerdeVariantRenaming(argWriter, arg);
+  const resultPtr = __fp_gen_import_serde_enum(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_SerdeVariantRenaming(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importSerdeEnum()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_flatten")
+declare function __fp_gen_import_serde_flatten(arg: FatPtr): FatPtr;
+
+export function importSerdeFlatten(arg: SerdeFlatten): SerdeFlatten {
+  const argWriter = new Writer();
+  encode_SerdeFlatten(argWriter, arg);
+  const resultPtr = __fp_gen_import_serde_flatten(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_SerdeFlatten(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importSerdeFlatten()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_internally_tagged")
+declare function __fp_gen_import_serde_internally_tagged(arg: FatPtr): FatPtr;
+
+export function importSerdeInternallyTagged(arg: SerdeInternallyTagged): SerdeInternallyTagged {
+  const argWriter = new Writer();
+  encode_SerdeInternallyTagged(argWriter, arg);
+  const resultPtr = __fp_gen_import_serde_internally_tagged(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_SerdeInternallyTagged(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importSerdeInternallyTagged()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_struct")
+declare function __fp_gen_import_serde_struct(arg: FatPtr): FatPtr;
+
+export function importSerdeStruct(arg: SerdePropertyRenaming): SerdePropertyRenaming {
+  const argWriter = new Writer();
+  encode_SerdePropertyRenaming(argWriter, arg);
+  const resultPtr = __fp_gen_import_serde_struct(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_SerdePropertyRenaming(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importSerdeStruct()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_untagged")
+declare function __fp_gen_import_serde_untagged(arg: FatPtr): FatPtr;
+
+export function importSerdeUntagged(arg: SerdeUntagged): SerdeUntagged {
+  const argWriter = new Writer();
+  encode_SerdeUntagged(argWriter, arg);
+  const resultPtr = __fp_gen_import_serde_untagged(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_SerdeUntagged(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importSerdeUntagged()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_string")
+declare function __fp_gen_import_string(arg: FatPtr): FatPtr;
+
+export function importString(arg: string): string {
+  const argWriter = new Writer();
+  encode_String(argWriter, arg);
+  const resultPtr = __fp_gen_import_string(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_String(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importString()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_struct_with_options")
+declare function __fp_gen_import_struct_with_options(arg: FatPtr): FatPtr;
+
+export function importStructWithOptions(arg: StructWithOptions): StructWithOptions {
+  const argWriter = new Writer();
+  encode_StructWithOptions(argWriter, arg);
+  const resultPtr = __fp_gen_import_struct_with_options(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_StructWithOptions(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importStructWithOptions()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_timestamp")
+declare function __fp_gen_import_timestamp(arg: FatPtr): FatPtr;
+
+export function importTimestamp(arg: MyDateTime): MyDateTime {
+  const argWriter = new Writer();
+  encode_String(argWriter, arg);
+  const resultPtr = __fp_gen_import_timestamp(argWriter.finish());
+  const reader = Reader.open(resultPtr);
+  const result = decode_String(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importTimestamp()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_void_function")
+declare function __fp_gen_import_void_function(): void;
+
+export function importVoidFunction(): void {
+  __fp_gen_import_void_function();
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_void_function_empty_result")
+declare function __fp_gen_import_void_function_empty_result(): FatPtr;
+
+export function importVoidFunctionEmptyResult(): Result_Unit_u32 {
+  const resultPtr = __fp_gen_import_void_function_empty_result();
+  const reader = Reader.open(resultPtr);
+  const result = decode_Result_Unit_u32(reader);
+  if (reader.error) {
+    panic("Could not decode result of `importVoidFunctionEmptyResult()`");
+  }
+  reader.close();
+  return result;
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_void_function_empty_return")
+declare function __fp_gen_import_void_function_empty_return(): void;
+
+export function importVoidFunctionEmptyReturn(): void {
+  __fp_gen_import_void_function_empty_return();
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_log")
+declare function __fp_gen_log(message: FatPtr): void;
+
+/**
+ * Logs a message to the (development) console.
+ */
+export function log(message: string): void {
+  const messageWriter = new Writer();
+  encode_String(messageWriter, message);
+  __fp_gen_log(messageWriter.finish());
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_make_http_request")
+declare function __fp_gen_make_http_request(request: FatPtr): FatPtr;
+
+export type MakeHttpRequestCallback = (result: HttpResult) => void;
+
+const makeHttpRequestCallbacks = new Map<FatPtr, MakeHttpRequestCallback>();
+
+function makeHttpRequestReady(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const callback = makeHttpRequestCallbacks.get(asyncValuePtr);
+  makeHttpRequestCallbacks.delete(asyncValuePtr);
+  const reader = Reader.open(resultPtr);
+  const result = decode_Result_Response_RequestError(reader);
+  if (reader.error) {
+    panic("Could not decode result of `makeHttpRequest()`");
+  }
+  reader.close();
+  callback(result);
+}
+
+/**
+ * Example how a runtime could expose a `Fetch`-like function to plugins.
+ *
+ * See `types/http.rs` for more info.
+ */
+export function makeHttpRequest(request: Request, callback: MakeHttpRequestCallback): void {
+  const requestWriter = new Writer();
+  encode_Request(requestWriter, request);
+  const asyncValuePtr = __fp_gen_make_http_request(requestWriter.finish());
+  makeHttpRequestCallbacks.set(asyncValuePtr, callback);
+  awaitAsyncValue(asyncValuePtr, makeHttpRequestReady);
+}
+
+// Functions for resolving the results of async exports.
+
+/**
+ * Resolves the result of `exportAsyncStruct()`.
+ */
+export function exportAsyncStructResolve(asyncValue: AsyncValue, result: FpPropertyRenaming): void {
+  const writer = new Writer();
+  encode_FpPropertyRenaming(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportIncrementGlobalState()`.
+ */
+export function exportIncrementGlobalStateResolve(asyncValue: AsyncValue): void {
+  const writer = new Writer();
+  writer.writeNil();
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveBoolNegateAsync()`.
+ */
+export function exportPrimitiveBoolNegateAsyncResolve(asyncValue: AsyncValue, result: bool): void {
+  const writer = new Writer();
+  encode_bool(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveF32AddThreeAsync()`.
+ */
+export function exportPrimitiveF32AddThreeAsyncResolve(asyncValue: AsyncValue, result: f32): void {
+  const writer = new Writer();
+  encode_f32(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveF64AddThreeAsync()`.
+ */
+export function exportPrimitiveF64AddThreeAsyncResolve(asyncValue: AsyncValue, result: f64): void {
+  const writer = new Writer();
+  encode_f64(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveI16AddThreeAsync()`.
+ */
+export function exportPrimitiveI16AddThreeAsyncResolve(asyncValue: AsyncValue, result: i16): void {
+  const writer = new Writer();
+  encode_i16(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveI32AddThreeAsync()`.
+ */
+export function exportPrimitiveI32AddThreeAsyncResolve(asyncValue: AsyncValue, result: i32): void {
+  const writer = new Writer();
+  encode_i32(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveI64AddThreeAsync()`.
+ */
+export function exportPrimitiveI64AddThreeAsyncResolve(asyncValue: AsyncValue, result: i64): void {
+  const writer = new Writer();
+  encode_i64(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveI8AddThreeAsync()`.
+ */
+export function exportPrimitiveI8AddThreeAsyncResolve(asyncValue: AsyncValue, result: i8): void {
+  const writer = new Writer();
+  encode_i8(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveU16AddThreeAsync()`.
+ */
+export function exportPrimitiveU16AddThreeAsyncResolve(asyncValue: AsyncValue, result: u16): void {
+  const writer = new Writer();
+  encode_u16(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveU32AddThreeAsync()`.
+ */
+export function exportPrimitiveU32AddThreeAsyncResolve(asyncValue: AsyncValue, result: u32): void {
+  const writer = new Writer();
+  encode_u32(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveU64AddThreeAsync()`.
+ */
+export function exportPrimitiveU64AddThreeAsyncResolve(asyncValue: AsyncValue, result: u64): void {
+  const writer = new Writer();
+  encode_u64(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportPrimitiveU8AddThreeAsync()`.
+ */
+export function exportPrimitiveU8AddThreeAsyncResolve(asyncValue: AsyncValue, result: u8): void {
+  const writer = new Writer();
+  encode_u8(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `exportResetGlobalState()`.
+ */
+export function exportResetGlobalStateResolve(asyncValue: AsyncValue): void {
+  const writer = new Writer();
+  writer.writeNil();
+  resolveAsyncValue(asyncValue, writer.finish());
+}
+
+/**
+ * Resolves the result of `fetchData()`.
+ */
+export function fetchDataResolve(asyncValue: AsyncValue, result: Result_String_String): void {
+  const writer = new Writer();
+  encode_Result_String_String(writer, result);
+  resolveAsyncValue(asyncValue, writer.finish());
+}
@@ -0,0 +1,1075 @@
+// ============================================= //
+// Support library for WebAssembly plugins       //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+/**
+ * A fat pointer is passed as a 64-bit integer. Its 32 most-significant bits
+ * contain the offset of the data, while its 32 least-significant bits contain
+ * the length of the data.
+ */
+export type FatPtr = u64;
+
+/**
+ * Fat pointer to an async value, which is used for resolving the result of an
+ * async export at a later time.
+ */
+export type AsyncValue = FatPtr;
+
+/**
+ * Invoked with the result of an async import once it is available.
+ */
+export type AsyncHandler = (asyncValuePtr: FatPtr, resultPtr: FatPtr) => void;
+
+/**
+ * Length of a fat pointer that points to a 4-byte length header, instead of
+ * to the data itself.
+ */
+const FAT_PTR_LEN_HEADER: u32 = 0x01000000;
+
+/**
+ * Maximum length that can be encoded in the length bits of a fat pointer.
+ */
+const FAT_PTR_MAX_LEN: u32 = 0x00ffffff;
+
+const ASYNC_STATUS_READY: u32 = 1;
+const ASYNC_VALUE_SIZE: u32 = 12;
+
+// @ts-ignore: decorator
+@external("fp", "__fp_host_panic")
+declare function __fp_host_panic(panic: FatPtr): void;
+
+// @ts-ignore: decorator
+@external("fp", "__fp_host_resolve_async_value")
+declare function __fp_host_resolve_async_value(asyncValuePtr: FatPtr, resultPtr: FatPtr): void;
+
+function toFatPtr(ptr: usize, len: u32): FatPtr {
+  return (<u64>ptr << 32) | <u64>len;
+}
+
+function fromFatPtr(ptr: FatPtr): usize {
+  return <usize>(ptr >> 32);
+}
+
+/**
+ * Returns the address of the data behind a fat pointer, skipping the length
+ * header if there is one.
+ */
+function fatPtrData(ptr: FatPtr): usize {
+  const address = fromFatPtr(ptr);
+  return <u32>ptr == FAT_PTR_LEN_HEADER ? address + 4 : address;
+}
+
+function fatPtrLength(ptr: FatPtr): u32 {
+  const len = <u32>ptr;
+  if (len == FAT_PTR_LEN_HEADER) {
+    return load<u32>(fromFatPtr(ptr));
+  } else if (len > FAT_PTR_MAX_LEN) {
+    panic("Unknown extension bits");
+  }
+  return len;
+}
+
+/**
+ * Reports a panic to the host and aborts execution.
+ */
+export function panic(message: string, location: string | null = null): void {
+  const writer = new Writer();
+  writer.writeMap(2);
+  writer.writeString("message");
+  writer.writeString(message);
+  writer.writeString("location");
+  if (location !== null) {
+    writer.writeString(location!);
+  } else {
+    writer.writeNil();
+  }
+  __fp_host_panic(writer.finish());
+  unreachable();
+}
+
+/**
+ * Replacement for the `abort()` function of the AssemblyScript runtime, which
+ * reports failed assertions and other errors to the host as panics.
+ *
+ * Enable it by passing `--use abort=<path to bindings>/support/fpAbort` to the
+ * compiler.
+ */
+export function fpAbort(
+  message: string | null,
+  fileName: string | null,
+  lineNumber: u32,
+  columnNumber: u32
+): void {
+  let location: string | null = null;
+  if (fileName !== null) {
+    location = fileName! + ":" + lineNumber.toString() + ":" + columnNumber.toString();
+  }
+  panic(message !== null ? message! : "abort", location);
+}
+
+// Memory management, as required by the spec:
+
+export function __fp_malloc(len: u32): FatPtr {
+  const hasHeader = len > FAT_PTR_MAX_LEN;
+  const ptr = heap.alloc(hasHeader ? <usize>len + 4 : <usize>len);
+  if (hasHeader) {
+    store<u32>(ptr, len);
+    return toFatPtr(ptr, FAT_PTR_LEN_HEADER);
+  }
+  return toFatPtr(ptr, len);
+}
+
+export function __fp_free(ptr: FatPtr): void {
+  heap.free(fromFatPtr(ptr));
+}
+
+/**
+ * Wraps a value type, so that it can be used where a nullable type is
+ * expected.
+ */
+export class Box<T> {
+  value: T;
+
+  constructor(value: T) {
+    this.value = value;
+  }
+}
+
+/**
+ * The unit type, which is always encoded as `nil`.
+ */
+export class Unit {}
+
+/**
+ * An arbitrary MessagePack-encoded value, used for types that have no
+ * AssemblyScript equivalent. An empty value is encoded as `nil`.
+ */
+export class RawValue {
+  bytes: Uint8Array;
+
+  constructor(bytes: Uint8Array = new Uint8Array(0)) {
+    this.bytes = bytes;
+  }
+}
+
+// Encoding:
+
+/**
+ * Growable buffer for encoding MessagePack.
+ */
+export class Writer {
+  private data: usize = 0;
+  private len: u32 = 0;
+  private capacity: u32 = 0;
+
+  /**
+   * Moves the contents of the writer into memory allocated using
+   * `__fp_malloc()`, so that it can be passed to the host. The writer is
+   * reset.
+   */
+  finish(): FatPtr {
+    const ptr = __fp_malloc(this.len);
+    if (this.len > 0) {
+      memory.copy(fatPtrData(ptr), this.data, this.len);
+    }
+    if (this.data != 0) {
+      heap.free(this.data);
+    }
+    this.data = 0;
+    this.len = 0;
+    this.capacity = 0;
+    return ptr;
+  }
+
+  writeNil(): void {
+    this.writeType(0xc0);
+  }
+
+  writeBool(value: bool): void {
+    this.writeType(value ? 0xc3 : 0xc2);
+  }
+
+  writeInt(value: i64): void {
+    if (value >= 0) {
+      this.writeUint(<u64>value);
+    } else if (value >= -32) {
+      this.writeType(<u8>value);
+    } else if (value >= <i64>i8.MIN_VALUE) {
+      this.writeHeader8(0xd0, <u8>value);
+    } else if (value >= <i64>i16.MIN_VALUE) {
+      this.writeHeader16(0xd1, <u16>value);
+    } else if (value >= <i64>i32.MIN_VALUE) {
+      this.writeHeader32(0xd2, <u32>value);
+    } else {
+      this.writeHeader64(0xd3, <u64>value);
+    }
+  }
+
+  writeUint(value: u64): void {
+    if (value < 0x80) {
+      this.writeType(<u8>value);
+    } else if (value <= <u64>u8.MAX_VALUE) {
+      this.writeHeader8(0xcc, <u8>value);
+    } else if (value <= <u64>u16.MAX_VALUE) {
+      this.writeHeader16(0xcd, <u16>value);
+    } else if (value <= <u64>u32.MAX_VALUE) {
+      this.writeHeader32(0xce, <u32>value);
+    } else {
+      this.writeHeader64(0xcf, value);
+    }
+  }
+
+  writeF32(value: f32): void {
+    this.writeHeader32(0xca, reinterpret<u32>(value));
+  }
+
+  writeF64(value: f64): void {
+    this.writeHeader64(0xcb, reinterpret<u64>(value));
+  }
+
+  writeString(value: string): void {
+    const buffer = String.UTF8.encode(value);
+    const len = <u32>buffer.byteLength;
+    if (len < 32) {
+      this.writeType(<u8>(0xa0 | len));
+    } else if (len <= <u32>u8.MAX_VALUE) {
+      this.writeHeader8(0xd9, <u8>len);
+    } else if (len <= <u32>u16.MAX_VALUE) {
+      this.writeHeader16(0xda, <u16>len);
+    } else {
+      this.writeHeader32(0xdb, len);
+    }
+    this.writeRaw(changetype<usize>(buffer), len);
+  }
+
+  writeBytes(value: Uint8Array): void {
+    const len = <u32>value.length;
+    if (len <= <u32>u8.MAX_VALUE) {
+      this.writeHeader8(0xc4, <u8>len);
+    } else if (len <= <u32>u16.MAX_VALUE) {
+      this.writeHeader16(0xc5, <u16>len);
+    } else {
+      this.writeHeader32(0xc6, len);
+    }
+    this.writeRaw(value.dataStart, len);
+  }
+
+  writeArray(len: u32): void {
+    if (len < 16) {
+      this.writeType(<u8>(0x90 | len));
+    } else if (len <= <u32>u16.MAX_VALUE) {
+      this.writeHeader16(0xdc, <u16>len);
+    } else {
+      this.writeHeader32(0xdd, len);
+    }
+  }
+
+  writeMap(len: u32): void {
+    if (len < 16) {
+      this.writeType(<u8>(0x80 | len));
+    } else if (len <= <u32>u16.MAX_VALUE) {
+      this.writeHeader16(0xde, <u16>len);
+    } else {
+      this.writeHeader32(0xdf, len);
+    }
+  }
+
+  writeRaw(data: usize, len: u32): void {
+    if (len > 0) {
+      memory.copy(this.reserve(len), data, len);
+    }
+  }
+
+  private reserve(additional: u32): usize {
+    if (this.capacity - this.len < additional) {
+      let capacity: u64 = this.capacity > 0 ? <u64>this.capacity : 64;
+      while (capacity - <u64>this.len < <u64>additional) {
+        capacity *= 2;
+      }
+      if (capacity > <u64>u32.MAX_VALUE) {
+        panic("Buffer too large");
+      }
+      this.data =
+        this.data != 0
+          ? heap.realloc(this.data, <usize>capacity)
+          : heap.alloc(<usize>capacity);
+      this.capacity = <u32>capacity;
+    }
+    const ptr = this.data + <usize>this.len;
+    this.len += additional;
+    return ptr;
+  }
+
+  private writeType(kind: u8): void {
+    store<u8>(this.reserve(1), kind);
+  }
+
+  private writeHeader8(kind: u8, value: u8): void {
+    const ptr = this.reserve(2);
+    store<u8>(ptr, kind);
+    store<u8>(ptr, value, 1);
+  }
+
+  private writeHeader16(kind: u8, value: u16): void {
+    const ptr = this.reserve(3);
+    store<u8>(ptr, kind);
+    store<u16>(ptr, bswap<u16>(value), 1);
+  }
+
+  private writeHeader32(kind: u8, value: u32): void {
+    const ptr = this.reserve(5);
+    store<u8>(ptr, kind);
+    store<u32>(ptr, bswap<u32>(value), 1);
+  }
+
+  private writeHeader64(kind: u8, value: u64): void {
+    const ptr = this.reserve(9);
+    store<u8>(ptr, kind);
+    store<u64>(ptr, bswap<u64>(value), 1);
+  }
+}
+
+// Decoding:
+
+/**
+ * Cursor for decoding MessagePack from a buffer.
+ *
+ * Decoding functions never throw. Instead, they set the `error` flag of the
+ * reader and return a placeholder value, which should not be used. Once the
+ * flag is set, all further reads fail as well.
+ */
+export class Reader {
+  error: bool = false;
+
+  /**
+   * Whether unknown fields are rejected. This is used when attempting to
+   * decode the variants of untagged enums.
+   */
+  strict: bool = false;
+
+  private data: usize;
+  private len: u32;
+  private pos: u32 = 0;
+  private ptr: FatPtr;
+  private negative: bool = false;
+
+  constructor(data: usize, len: u32, ptr: FatPtr = 0) {
+    this.data = data;
+    this.len = len;
+    this.ptr = ptr;
+  }
+
+  /**
+   * Opens a reader for the data behind a fat pointer received from the host.
+   */
+  static open(ptr: FatPtr): Reader {
+    return new Reader(fatPtrData(ptr), fatPtrLength(ptr), ptr);
+  }
+
+  /**
+   * Frees the data behind the fat pointer the reader was opened with.
+   */
+  close(): void {
+    if (this.ptr != 0) {
+      __fp_free(this.ptr);
+      this.ptr = 0;
+    }
+  }
+
+  /**
+   * Marks the reader as failed and returns a placeholder value.
+   */
+  fail<T>(): T {
+    this.error = true;
+    if (isReference<T>()) {
+      return changetype<T>(0);
+    } else {
+      return <T>0;
+    }
+  }
+
+  /**
+   * Creates a copy of the reader for attempting to decode an untagged enum
+   * variant, which rejects unknown fields.
+   */
+  attempt(): Reader {
+    const attempt = new Reader(this.data, this.len);
+    attempt.pos = this.pos;
+    attempt.error = this.error;
+    attempt.strict = true;
+    return attempt;
+  }
+
+  /**
+   * Advances the reader past a successful attempt.
+   */
+  commit(attempt: Reader): void {
+    this.pos = attempt.pos;
+  }
+
+  /**
+   * Reads a `nil` value, returning whether there was one. Nothing is consumed
+   * if the next value is not `nil`.
+   */
+  readNil(): bool {
+    if (this.peekType() == 0xc0) {
+      this.pos++;
+      return true;
+    }
+    return false;
+  }
+
+  readBool(): bool {
+    const kind = this.peekType();
+    if (kind == 0xc2 || kind == 0xc3) {
+      this.pos++;
+      return kind == 0xc3;
+    }
+    return this.fail<bool>();
+  }
+
+  readInt(): i64 {
+    const bits = this.readInteger();
+    if (!this.negative && bits > <u64>i64.MAX_VALUE) {
+      return this.fail<i64>();
+    }
+    return <i64>bits;
+  }
+
+  readUint(): u64 {
+    const bits = this.readInteger();
+    if (this.negative) {
+      return this.fail<u64>();
+    }
+    return bits;
+  }
+
+  readF32(): f32 {
+    return <f32>this.readF64();
+  }
+
+  readF64(): f64 {
+    const kind = this.peekType();
+    if (kind == 0xca) {
+      this.pos++;
+      return <f64>reinterpret<f32>(<u32>this.readBigEndian(4));
+    } else if (kind == 0xcb) {
+      this.pos++;
+      return reinterpret<f64>(this.readBigEndian(8));
+    }
+    const bits = this.readInteger();
+    return this.negative ? <f64>(<i64>bits) : <f64>bits;
+  }
+
+  readString(): string {
+    const len = this.readStringLength();
+    const data = this.consume(len);
+    return this.error ? "" : String.UTF8.decodeUnsafe(data, <usize>len);
+  }
+
+  /**
+   * Reads a binary value. Strings are accepted as well.
+   */
+  readBytes(): Uint8Array {
+    const kind = this.peekType();
+    let len: u32 = 0;
+    if (kind >= 0xc4 && kind <= 0xc6) {
+      this.pos++;
+      len = <u32>this.readBigEndian(<u32>1 << <u32>(kind - 0xc4));
+    } else {
+      len = this.readStringLength();
+    }
+    const data = this.consume(len);
+    if (this.error) {
+      return new Uint8Array(0);
+    }
+    const bytes = new Uint8Array(<i32>len);
+    memory.copy(bytes.dataStart, data, <usize>len);
+    return bytes;
+  }
+
+  /**
+   * Reads the header of an array, returning its length.
+   */
+  readArray(): u32 {
+    const kind = this.peekType();
+    let len: u32 = 0;
+    if ((kind & 0xf0) == 0x90) {
+      this.pos++;
+      len = <u32>(kind & 0x0f);
+    } else if (kind == 0xdc || kind == 0xdd) {
+      this.pos++;
+      len = <u32>this.readBigEndian(kind == 0xdc ? 2 : 4);
+    } else {
+      return this.fail<u32>();
+    }
+
+    // Every element takes at least one byte, which protects us against
+    // allocating memory for bogus lengths:
+    if (len > this.len - this.pos) {
+      return this.fail<u32>();
+    }
+    return len;
+  }
+
+  /**
+   * Reads the header of a map, returning its number of entries.
+   */
+  readMap(): u32 {
+    const kind = this.peekType();
+    let len: u32 = 0;
+    if ((kind & 0xf0) == 0x80) {
+      this.pos++;
+      len = <u32>(kind & 0x0f);
+    } else if (kind == 0xde || kind == 0xdf) {
+      this.pos++;
+      len = <u32>this.readBigEndian(kind == 0xde ? 2 : 4);
+    } else {
+      return this.fail<u32>();
+    }
+
+    if (2 * <u64>len > <u64>(this.len - this.pos)) {
+      return this.fail<u32>();
+    }
+    return len;
+  }
+
+  /**
+   * Reads the next value without decoding it.
+   */
+  readRaw(): RawValue {
+    const start = this.pos;
+    this.skip();
+    if (this.error) {
+      return new RawValue();
+    }
+    const bytes = new Uint8Array(<i32>(this.pos - start));
+    memory.copy(bytes.dataStart, this.data + <usize>start, <usize>(this.pos - start));
+    return new RawValue(bytes);
+  }
+
+  /**
+   * Starts reading a struct, which may be encoded either as a map or, if
+   * field names are given, as an array containing the field values in order.
+   */
+  readStruct(names: Array<string>): Fields {
+    const fields = new Fields(this, names, this.strict);
+
+    // Only the struct itself is strict, not any of its fields:
+    this.strict = false;
+
+    return fields;
+  }
+
+  /**
+   * Reads the name of an externally tagged enum variant, which is followed
+   * by its content, if there is any.
+   */
+  readVariant(): Variant {
+    const kind = this.peekType();
+    if ((kind & 0xe0) == 0xa0 || (kind >= 0xd9 && kind <= 0xdb)) {
+      return new Variant(this.readString(), this.empty());
+    }
+
+    if (this.readMap() != 1) {
+      return this.failVariant();
+    }
+    const name = this.readString();
+    const content = this.at(this.pos);
+    this.skip();
+    return new Variant(name, content);
+  }
+
+  /**
+   * Reads the name of an internally tagged enum variant, without consuming
+   * anything.
+   */
+  readTag(tag: string): string {
+    const lookahead = this.at(this.pos);
+    if (lookahead.isArray()) {
+      const len = lookahead.readArray();
+      return len > 0 ? lookahead.readString() : this.fail<string>();
+    }
+
+    const len = lookahead.readMap();
+    for (let i: u32 = 0; i < len && !lookahead.error; i++) {
+      if (lookahead.readString() == tag) {
+        return lookahead.readString();
+      }
+      lookahead.skip();
+    }
+    return this.fail<string>();
+  }
+
+  /**
+   * Reads the name of an adjacently tagged enum variant, as well as its
+   * content, if there is any.
+   */
+  readAdjacent(tag: string, contentKey: string): Variant {
+    if (this.isArray()) {
+      const len = this.readArray();
+      if (len != 1 && len != 2) {
+        return this.failVariant();
+      }
+      const name = this.readString();
+      if (len == 1) {
+        return new Variant(name, this.empty());
+      }
+      const content = this.at(this.pos);
+      this.skip();
+      return new Variant(name, content);
+    }
+
+    let name: string | null = null;
+    let content = this.empty();
+    const len = this.readMap();
+    for (let i: u32 = 0; i < len && !this.error; i++) {
+      const key = this.readString();
+      if (key == tag) {
+        name = this.readString();
+      } else {
+        if (key == contentKey) {
+          content = this.at(this.pos);
+        }
+        this.skip();
+      }
+    }
+    if (name === null || this.error) {
+      return this.failVariant();
+    }
+    return new Variant(name!, content);
+  }
+
+  isArray(): bool {
+    const kind = this.peekType();
+    return (kind & 0xf0) == 0x90 || kind == 0xdc || kind == 0xdd;
+  }
+
+  /**
+   * Skips over the next value, including any nested values.
+   */
+  skip(): void {
+    let pending: u64 = 1;
+    while (pending > 0 && !this.error) {
+      pending--;
+
+      const kind = this.peekType();
+      if (kind < 0) {
+        this.error = true;
+        return;
+      }
+      this.pos++;
+
+      let size: u32 = 0;
+      if (kind <= 0x7f || kind >= 0xe0 || kind == 0xc0 || kind == 0xc2 || kind == 0xc3) {
+        continue;
+      } else if ((kind & 0xe0) == 0xa0) {
+        size = <u32>(kind & 0x1f);
+      } else if ((kind & 0xf0) == 0x90) {
+        pending += <u64>(kind & 0x0f);
+        continue;
+      } else if ((kind & 0xf0) == 0x80) {
+        pending += 2 * <u64>(kind & 0x0f);
+        continue;
+      } else if (kind >= 0xc4 && kind <= 0xc6) {
+        size = <u32>this.readBigEndian(<u32>1 << <u32>(kind - 0xc4));
+      } else if (kind >= 0xc7 && kind <= 0xc9) {
+        // Extension type:
+        size = <u32>this.readBigEndian(<u32>1 << <u32>(kind - 0xc7)) + 1;
+      } else if (kind == 0xca) {
+        size = 4;
+      } else if (kind == 0xcb) {
+        size = 8;
+      } else if (kind >= 0xcc && kind <= 0xcf) {
+        size = <u32>1 << <u32>(kind - 0xcc);
+      } else if (kind >= 0xd0 && kind <= 0xd3) {
+        size = <u32>1 << <u32>(kind - 0xd0);
+      } else if (kind >= 0xd4 && kind <= 0xd8) {
+        size = (<u32>1 << <u32>(kind - 0xd4)) + 1;
+      } else if (kind >= 0xd9 && kind <= 0xdb) {
+        size = <u32>this.readBigEndian(<u32>1 << <u32>(kind - 0xd9));
+      } else if (kind == 0xdc || kind == 0xdd) {
+        pending += this.readBigEndian(kind == 0xdc ? 2 : 4);
+        continue;
+      } else if (kind == 0xde || kind == 0xdf) {
+        pending += 2 * this.readBigEndian(kind == 0xde ? 2 : 4);
+        continue;
+      } else {
+        this.error = true;
+        return;
+      }
+      this.consume(size);
+    }
+  }
+
+  /**
+   * Returns a reader for the same buffer, positioned at the given offset.
+   */
+  private at(pos: u32): Reader {
+    const reader = new Reader(this.data, this.len);
+    reader.pos = pos;
+    reader.error = this.error;
+    return reader;
+  }
+
+  /**
+   * Returns a reader from which nothing can be decoded.
+   */
+  private empty(): Reader {
+    return new Reader(0, 0);
+  }
+
+  private failVariant(): Variant {
+    this.error = true;
+    return new Variant("", this.empty());
+  }
+
+  private peekType(): i32 {
+    if (this.error || this.pos >= this.len) {
+      return -1;
+    }
+    return <i32>load<u8>(this.data + <usize>this.pos);
+  }
+
+  /**
+   * Consumes the given number of bytes, returning their address.
+   */
+  private consume(count: u32): usize {
+    if (this.error || this.len - this.pos < count) {
+      return this.fail<usize>();
+    }
+    const data = this.data + <usize>this.pos;
+    this.pos += count;
+    return data;
+  }
+
+  private readBigEndian(size: u32): u64 {
+    const data = this.consume(size);
+    let value: u64 = 0;
+    if (!this.error) {
+      for (let i: u32 = 0; i < size; i++) {
+        value = (value << 8) | <u64>load<u8>(data + <usize>i);
+      }
+    }
+    return value;
+  }
+
+  private readStringLength(): u32 {
+    const kind = this.peekType();
+    if ((kind & 0xe0) == 0xa0) {
+      this.pos++;
+      return <u32>(kind & 0x1f);
+    } else if (kind >= 0xd9 && kind <= 0xdb) {
+      this.pos++;
+      return <u32>this.readBigEndian(<u32>1 << <u32>(kind - 0xd9));
+    }
+    return this.fail<u32>();
+  }
+
+  /**
+   * Reads an integer of any format. If `negative` is set afterwards, the
+   * result should be interpreted as a signed integer.
+   */
+  private readInteger(): u64 {
+    const kind = this.peekType();
+    this.negative = false;
+    if (kind < 0) {
+      return this.fail<u64>();
+    }
+
+    if (kind <= 0x7f) {
+      this.pos++;
+      return <u64>kind;
+    } else if (kind >= 0xe0) {
+      this.pos++;
+      this.negative = true;
+      return <u64>(<i64>(<i8>kind));
+    } else if (kind >= 0xcc && kind <= 0xcf) {
+      this.pos++;
+      return this.readBigEndian(<u32>1 << <u32>(kind - 0xcc));
+    } else if (kind >= 0xd0 && kind <= 0xd3) {
+      this.pos++;
+      const size = <u32>1 << <u32>(kind - 0xd0);
+      let bits = this.readBigEndian(size);
+      if (size < 8 && ((bits >> <u64>(8 * size - 1)) & 1) != 0) {
+        bits |= u64.MAX_VALUE << <u64>(8 * size);
+      }
+      this.negative = <i64>bits < 0;
+      return bits;
+    }
+    return this.fail<u64>();
+  }
+}
+
+/**
+ * Iterates over the fields of a struct. See `Reader.readStruct()`.
+ */
+export class Fields {
+  /**
+   * The name of the current field.
+   */
+  key: string = "";
+
+  private reader: Reader;
+  private names: Array<string>;
+  private strict: bool;
+  private isArray: bool = false;
+  private index: u32 = 0;
+  private len: u32 = 0;
+
+  constructor(reader: Reader, names: Array<string>, strict: bool) {
+    this.reader = reader;
+    this.names = names;
+    this.strict = strict;
+    if (names.length > 0 && reader.isArray()) {
+      this.isArray = true;
+      this.len = reader.readArray();
+    } else {
+      this.len = reader.readMap();
+    }
+  }
+
+  /**
+   * Advances to the next field, returning whether there is one. The reader
+   * is positioned at the value of the field.
+   */
+  next(): bool {
+    if (this.index >= this.len || this.reader.error) {
+      return false;
+    }
+    if (this.isArray) {
+      this.key = this.index < <u32>this.names.length ? this.names[this.index] : "";
+    } else {
+      this.key = this.reader.readString();
+    }
+    this.index++;
+    return !this.reader.error;
+  }
+
+  /**
+   * Skips the value of an unknown field, unless unknown fields are rejected.
+   */
+  skip(): void {
+    if (this.strict) {
+      this.reader.error = true;
+    } else {
+      this.reader.skip();
+    }
+  }
+}
+
+/**
+ * The name of an enum variant, together with a reader for its content.
+ */
+export class Variant {
+  name: string;
+  content: Reader;
+
+  constructor(name: string, content: Reader) {
+    this.name = name;
+    this.content = content;
+  }
+}
+
+// Encoding and decoding of built-in types:
+
+export function encode_bool(writer: Writer, value: bool): void {
+  writer.writeBool(value);
+}
+
+export function decode_bool(reader: Reader): bool {
+  return reader.readBool();
+}
+
+export function encode_i8(writer: Writer, value: i8): void {
+  writer.writeInt(<i64>value);
+}
+
+export function decode_i8(reader: Reader): i8 {
+  const value = reader.readInt();
+  return value < <i64>i8.MIN_VALUE || value > <i64>i8.MAX_VALUE ? reader.fail<i8>() : <i8>value;
+}
+
+export function encode_i16(writer: Writer, value: i16): void {
+  writer.writeInt(<i64>value);
+}
+
+export function decode_i16(reader: Reader): i16 {
+  const value = reader.readInt();
+  return value < <i64>i16.MIN_VALUE || value > <i64>i16.MAX_VALUE
+    ? reader.fail<i16>()
+    : <i16>value;
+}
+
+export function encode_i32(writer: Writer, value: i32): void {
+  writer.writeInt(<i64>value);
+}
+
+export function decode_i32(reader: Reader): i32 {
+  const value = reader.readInt();
+  return value < <i64>i32.MIN_VALUE || value > <i64>i32.MAX_VALUE
+    ? reader.fail<i32>()
+    : <i32>value;
+}
+
+export function encode_i64(writer: Writer, value: i64): void {
+  writer.writeInt(value);
+}
+
+export function decode_i64(reader: Reader): i64 {
+  return reader.readInt();
+}
+
+export function encode_u8(writer: Writer, value: u8): void {
+  writer.writeUint(<u64>value);
+}
+
+export function decode_u8(reader: Reader): u8 {
+  const value = reader.readUint();
+  return value > <u64>u8.MAX_VALUE ? reader.fail<u8>() : <u8>value;
+}
+
+export function encode_u16(writer: Writer, value: u16): void {
+  writer.writeUint(<u64>value);
+}
+
+export function decode_u16(reader: Reader): u16 {
+  const value = reader.readUint();
+  return value > <u64>u16.MAX_VALUE ? reader.fail<u16>() : <u16>value;
+}
+
+export function encode_u32(writer: Writer, value: u32): void {
+  writer.writeUint(<u64>value);
+}
+
+export function decode_u32(reader: Reader): u32 {
+  const value = reader.readUint();
+  return value > <u64>u32.MAX_VALUE ? reader.fail<u32>() : <u32>value;
+}
+
+export function encode_u64(writer: Writer, value: u64): void {
+  writer.writeUint(value);
+}
+
+export function decode_u64(reader: Reader): u64 {
+  return reader.readUint();
+}
+
+export function encode_f32(writer: Writer, value: f32): void {
+  writer.writeF32(value);
+}
+
+export function decode_f32(reader: Reader): f32 {
+  return reader.readF32();
+}
+
+export function encode_f64(writer: Writer, value: f64): void {
+  writer.writeF64(value);
+}
+
+export function decode_f64(reader: Reader): f64 {
+  return reader.readF64();
+}
+
+export function encode_Unit(writer: Writer, value: Unit): void {
+  writer.writeNil();
+}
+
+export function decode_Unit(reader: Reader): Unit {
+  return reader.readNil() ? new Unit() : reader.fail<Unit>();
+}
+
+export function encode_String(writer: Writer, value: string): void {
+  writer.writeString(value);
+}
+
+export function decode_String(reader: Reader): string {
+  return reader.readString();
+}
+
+export function encode_Bytes(writer: Writer, value: Uint8Array): void {
+  writer.writeBytes(value);
+}
+
+export function decode_Bytes(reader: Reader): Uint8Array {
+  return reader.readBytes();
+}
+
+export function encode_RawValue(writer: Writer, value: RawValue): void {
+  if (value.bytes.length > 0) {
+    writer.writeRaw(value.bytes.dataStart, <u32>value.bytes.length);
+  } else {
+    writer.writeNil();
+  }
+}
+
+export function decode_RawValue(reader: Reader): RawValue {
+  return reader.readRaw();
+}
+
+// Async support:
+
+class PendingValue {
+  asyncValuePtr: FatPtr;
+  handler: AsyncHandler;
+
+  constructor(asyncValuePtr: FatPtr, handler: AsyncHandler) {
+    this.asyncValuePtr = asyncValuePtr;
+    this.handler = handler;
+  }
+}
+
+const pendingValues = new Array<PendingValue>();
+
+/**
+ * Creates an async value for an async export. Its result should be resolved
+ * through the `_resolve()` function that belongs to the export.
+ */
+export function createAsyncValue(): AsyncValue {
+  const ptr = heap.alloc(<usize>ASYNC_VALUE_SIZE);
+  memory.fill(ptr, 0, <usize>ASYNC_VALUE_SIZE);
+  return toFatPtr(ptr, ASYNC_VALUE_SIZE);
+}
+
+/**
+ * Passes the encoded result of an async export to the host.
+ */
+export function resolveAsyncValue(asyncValue: AsyncValue, resultPtr: FatPtr): void {
+  __fp_host_resolve_async_value(asyncValue, resultPtr);
+}
+
+/**
+ * Invokes the handler once the given async value, as returned by an async
+ * import, has been resolved by the host.
+ */
+export function awaitAsyncValue(asyncValuePtr: FatPtr, handler: AsyncHandler): void {
+  const ptr = fromFatPtr(asyncValuePtr);
+  if (load<u32>(ptr) == ASYNC_STATUS_READY) {
+    const resultPtr = toFatPtr(<usize>load<u32>(ptr, 4), load<u32>(ptr, 8));
+    __fp_free(asyncValuePtr);
+    handler(asyncValuePtr, resultPtr);
+    return;
+  }
+
+  pendingValues.push(new PendingValue(asyncValuePtr, handler));
+}
+
+export function __fp_guest_resolve_async_value(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {
+  const ptr = fromFatPtr(asyncValuePtr);
+  store<u32>(ptr, ASYNC_STATUS_READY);
+  store<u32>(ptr, <u32>(resultPtr >> 32), 4);
+  store<u32>(ptr, <u32>resultPtr, 8);
+
+  for (let i = 0; i < pendingValues.length; i++) {
+    const pending = pendingValues[i];
+    if (pending.asyncValuePtr == asyncValuePtr) {
+      pendingValues.splice(i, 1);
+      __fp_free(asyncValuePtr);
+      const handler = pending.handler;
+      handler(asyncValuePtr, resultPtr);
+      return;
+    }
+  }
+}
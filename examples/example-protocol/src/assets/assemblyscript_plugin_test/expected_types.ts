@@ -0,0 +1,2842 @@
+// ============================================= //
+// Types for AssemblyScript plugins              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {
+  Box,
+  RawValue,
+  Reader,
+  Unit,
+  Writer,
+  decode_Bytes,
+  decode_RawValue,
+  decode_String,
+  decode_Unit,
+  decode_bool,
+  decode_f32,
+  decode_f64,
+  decode_i16,
+  decode_i32,
+  decode_i64,
+  decode_i8,
+  decode_u16,
+  decode_u32,
+  decode_u64,
+  decode_u8,
+  encode_Bytes,
+  encode_RawValue,
+  encode_String,
+  encode_Unit,
+  encode_bool,
+  encode_f32,
+  encode_f64,
+  encode_i16,
+  encode_i32,
+  encode_i64,
+  encode_i8,
+  encode_u16,
+  encode_u32,
+  encode_u64,
+  encode_u8,
+} from "./support";
+
+export type Body = Uint8Array;
+
+export enum DocExampleEnumTag {
+  Variant1,
+  Variant2,
+}
+
+/**
+ * # This is an enum with doc comments.
+ */
+export class DocExampleEnum {
+  readonly tag: DocExampleEnumTag;
+
+  constructor(tag: DocExampleEnumTag) {
+    this.tag = tag;
+  }
+}
+
+/**
+ * Multi-line doc comment with complex characters
+ * & " , \ ! '
+ */
+export class DocExampleEnum_Variant1 extends DocExampleEnum {
+  value: string;
+
+  constructor(value: string) {
+    super(DocExampleEnumTag.Variant1);
+    this.value = value;
+  }
+}
+
+/**
+ * Raw identifiers are supported too.
+ */
+export class DocExampleEnum_Variant2 extends DocExampleEnum {
+  /**
+   * Variant property.
+   */
+  inner: i8;
+
+  constructor(inner: i8) {
+    super(DocExampleEnumTag.Variant2);
+    this.inner = inner;
+  }
+}
+
+/**
+ * # This is a struct with doc comments.
+ */
+export class DocExampleStruct {
+  /**
+   * Multi-line doc comment with complex characters
+   * & " , \ ! '
+   */
+  multiLine: string;
+  /**
+   * Raw identifiers are supported too.
+   */
+  type_: string;
+
+  constructor(multiLine: string, type_: string) {
+    this.multiLine = multiLine;
+    this.type_ = type_;
+  }
+}
+
+/**
+ * This struct is also not referenced by any function or data structure, but
+ * it will show up because there is an explicit `use` statement for it in the
+ * `fp_import!` macro.
+ */
+export class ExplicitedlyImportedType {
+  youWillSeeThis: bool;
+
+  constructor(youWillSeeThis: bool) {
+    this.youWillSeeThis = youWillSeeThis;
+  }
+}
+
+export class FlattenedStruct {
+  foo: string;
+  bar: i64;
+
+  constructor(foo: string, bar: i64) {
+    this.foo = foo;
+    this.bar = bar;
+  }
+}
+
+/**
+ * A point of an arbitrary type.
+ */
+export class Point_f64 {
+  value: f64;
+
+  constructor(value: f64) {
+    this.value = value;
+  }
+}
+
+export type FloatingPoint = Point_f64;
+
+export enum FpAdjacentlyTaggedTag {
+  Foo,
+  Bar,
+  Baz,
+}
+
+export class FpAdjacentlyTagged {
+  readonly tag: FpAdjacentlyTaggedTag;
+
+  constructor(tag: FpAdjacentlyTaggedTag) {
+    this.tag = tag;
+  }
+}
+
+export class FpAdjacentlyTagged_Foo extends FpAdjacentlyTagged {
+  constructor() {
+    super(FpAdjacentlyTaggedTag.Foo);
+  }
+}
+
+export class FpAdjacentlyTagged_Bar extends FpAdjacentlyTagged {
+  value: string;
+
+  constructor(value: string) {
+    super(FpAdjacentlyTaggedTag.Bar);
+    this.value = value;
+  }
+}
+
+export class FpAdjacentlyTagged_Baz extends FpAdjacentlyTagged {
+  a: i8;
+  b: u64;
+
+  constructor(a: i8, b: u64) {
+    super(FpAdjacentlyTaggedTag.Baz);
+    this.a = a;
+    this.b = b;
+  }
+}
+
+export class FpFlatten {
+  flattened: FlattenedStruct;
+
+  constructor(flattened: FlattenedStruct) {
+    this.flattened = flattened;
+  }
+}
+
+export enum FpInternallyTaggedTag {
+  Foo,
+  Baz,
+}
+
+export class FpInternallyTagged {
+  readonly tag: FpInternallyTaggedTag;
+
+  constructor(tag: FpInternallyTaggedTag) {
+    this.tag = tag;
+  }
+}
+
+export class FpInternallyTagged_Foo extends FpInternallyTagged {
+  constructor() {
+    super(FpInternallyTaggedTag.Foo);
+  }
+}
+
+export class FpInternallyTagged_Baz extends FpInternallyTagged {
+  a: i8;
+  b: u64;
+
+  constructor(a: i8, b: u64) {
+    super(FpInternallyTaggedTag.Baz);
+    this.a = a;
+    this.b = b;
+  }
+}
+
+export class FpPropertyRenaming {
+  fooBar: string;
+  quxBaz: f64;
+  rawStruct: i32;
+
+  constructor(fooBar: string, quxBaz: f64, rawStruct: i32) {
+    this.fooBar = fooBar;
+    this.quxBaz = quxBaz;
+    this.rawStruct = rawStruct;
+  }
+}
+
+export enum FpUntaggedTag {
+  Bar,
+  Baz,
+}
+
+export class FpUntagged {
+  readonly tag: FpUntaggedTag;
+
+  constructor(tag: FpUntaggedTag) {
+    this.tag = tag;
+  }
+}
+
+export class FpUntagged_Bar extends FpUntagged {
+  value: string;
+
+  constructor(value: string) {
+    super(FpUntaggedTag.Bar);
+    this.value = value;
+  }
+}
+
+export class FpUntagged_Baz extends FpUntagged {
+  a: i8;
+  b: u64;
+
+  constructor(a: i8, b: u64) {
+    super(FpUntaggedTag.Baz);
+    this.a = a;
+    this.b = b;
+  }
+}
+
+export enum FpVariantRenamingTag {
+  FooBar,
+  QuxBaz,
+}
+
+export class FpVariantRenaming {
+  readonly tag: FpVariantRenamingTag;
+
+  constructor(tag: FpVariantRenamingTag) {
+    this.tag = tag;
+  }
+}
+
+export class FpVariantRenaming_FooBar extends FpVariantRenaming {
+  constructor() {
+    super(FpVariantRenamingTag.FooBar);
+  }
+}
+
+export class FpVariantRenaming_QuxBaz extends FpVariantRenaming {
+  /**
+   * Will be renamed to "FOO_BAR" because of the `rename_all` on the
+   * variant.
+   */
+  fooBar: string;
+  quxBaz: f64;
+
+  constructor(fooBar: string, quxBaz: f64) {
+    super(FpVariantRenamingTag.QuxBaz);
+    this.fooBar = fooBar;
+    this.quxBaz = quxBaz;
+  }
+}
+
+export class GroupImportedType1 {
+  youWillSeeThis: bool;
+
+  constructor(youWillSeeThis: bool) {
+    this.youWillSeeThis = youWillSeeThis;
+  }
+}
+
+export class GroupImportedType2 {
+  youWillSeeThis: bool;
+
+  constructor(youWillSeeThis: bool) {
+    this.youWillSeeThis = youWillSeeThis;
+  }
+}
+
+/**
+ * Represents an HTTP response we received.
+ *
+ * Please note we currently do not support streaming responses.
+ */
+export class Response {
+  /**
+   * The response body. May be empty.
+   */
+  body: Body;
+  /**
+   * HTTP headers that were part of the response.
+   */
+  headers: RawValue;
+  /**
+   * HTTP status code.
+   */
+  statusCode: u16;
+
+  constructor(body: Body, headers: RawValue, statusCode: u16) {
+    this.body = body;
+    this.headers = headers;
+    this.statusCode = statusCode;
+  }
+}
+
+export enum RequestErrorTag {
+  Offline,
+  NoRoute,
+  ConnectionRefused,
+  Timeout,
+  ServerError,
+  Other,
+}
+
+/**
+ * Represents an error that occurred while attempting to submit the request.
+ */
+export class RequestError {
+  readonly tag: RequestErrorTag;
+
+  constructor(tag: RequestErrorTag) {
+    this.tag = tag;
+  }
+}
+
+/**
+ * Used when we know we don't have an active network connection.
+ */
+export class RequestError_Offline extends RequestError {
+  constructor() {
+    super(RequestErrorTag.Offline);
+  }
+}
+
+export class RequestError_NoRoute extends RequestError {
+  constructor() {
+    super(RequestErrorTag.NoRoute);
+  }
+}
+
+export class RequestError_ConnectionRefused extends RequestError {
+  constructor() {
+    super(RequestErrorTag.ConnectionRefused);
+  }
+}
+
+export class RequestError_Timeout extends RequestError {
+  constructor() {
+    super(RequestErrorTag.Timeout);
+  }
+}
+
+export class RequestError_ServerError extends RequestError {
+  /**
+   * HTTP status code.
+   */
+  statusCode: u16;
+  /**
+   * Response body.
+   */
+  response: Body;
+
+  constructor(statusCode: u16, response: Body) {
+    super(RequestErrorTag.ServerError);
+    this.statusCode = statusCode;
+    this.response = response;
+  }
+}
+
+/**
+ * Misc.
+ */
+export class RequestError_Other extends RequestError {
+  reason: string;
+
+  constructor(reason: string) {
+    super(RequestErrorTag.Other);
+    this.reason = reason;
+  }
+}
+
+export enum Result_Response_RequestErrorTag {
+  Ok,
+  Err,
+}
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+export class Result_Response_RequestError {
+  readonly tag: Result_Response_RequestErrorTag;
+
+  constructor(tag: Result_Response_RequestErrorTag) {
+    this.tag = tag;
+  }
+}
+
+/**
+ * Represents a successful result.
+ */
+export class Result_Response_RequestError_Ok extends Result_Response_RequestError {
+  value: Response;
+
+  constructor(value: Response) {
+    super(Result_Response_RequestErrorTag.Ok);
+    this.value = value;
+  }
+}
+
+/**
+ * Represents an error.
+ */
+export class Result_Response_RequestError_Err extends Result_Response_RequestError {
+  value: RequestError;
+
+  constructor(value: RequestError) {
+    super(Result_Response_RequestErrorTag.Err);
+    this.value = value;
+  }
+}
+
+export type HttpResult = Result_Response_RequestError;
+
+export type Int64 = u64;
+
+/**
+ * Our struct for passing date time instances.
+ *
+ * We wrap the `OffsetDateTime` type in a new struct so that the Serde
+ * attributes can be inserted. These are necessary to enable RFC3339
+ * formatting. Without a wrapper type like this, we would not be able to pass
+ * date time instances directly to function arguments and we might run into
+ * trouble embedding them into certain generic types.
+ */
+export type MyDateTime = string;
+
+export enum ReduxActionTag {
+  ClearTitle,
+  UpdateTitle,
+}
+
+/**
+ * Example for representing Redux actions.
+ */
+export class ReduxAction {
+  readonly tag: ReduxActionTag;
+
+  constructor(tag: ReduxActionTag) {
+    this.tag = tag;
+  }
+}
+
+export class ReduxAction_ClearTitle extends ReduxAction {
+  constructor() {
+    super(ReduxActionTag.ClearTitle);
+  }
+}
+
+export class ReduxAction_UpdateTitle extends ReduxAction {
+  title: string;
+
+  constructor(title: string) {
+    super(ReduxActionTag.UpdateTitle);
+    this.title = title;
+  }
+}
+
+/**
+ * Represents an HTTP request to be sent.
+ */
+export class Request {
+  /**
+   * The URI to submit the request to.
+   */
+  url: string;
+  /**
+   * HTTP method to use for the request.
+   */
+  method: RawValue;
+  /**
+   * HTTP headers to submit with the request.
+   */
+  headers: RawValue;
+  /**
+   * The body to submit with the request.
+   */
+  body: Body | null;
+
+  constructor(url: string, method: RawValue, headers: RawValue, body: Body | null) {
+    this.url = url;
+    this.method = method;
+    this.headers = headers;
+    this.body = body;
+  }
+}
+
+export enum SerdeAdjacentlyTaggedTag {
+  Foo,
+  Bar,
+  Baz,
+}
+
+export class SerdeAdjacentlyTagged {
+  readonly tag: SerdeAdjacentlyTaggedTag;
+
+  constructor(tag: SerdeAdjacentlyTaggedTag) {
+    this.tag = tag;
+  }
+}
+
+export class SerdeAdjacentlyTagged_Foo extends SerdeAdjacentlyTagged {
+  constructor() {
+    super(SerdeAdjacentlyTaggedTag.Foo);
+  }
+}
+
+export class SerdeAdjacentlyTagged_Bar extends SerdeAdjacentlyTagged {
+  value: string;
+
+  constructor(value: string) {
+    super(SerdeAdjacentlyTaggedTag.Bar);
+    this.value = value;
+  }
+}
+
+export class SerdeAdjacentlyTagged_Baz extends SerdeAdjacentlyTagged {
+  a: i8;
+  b: u64;
+
+  constructor(a: i8, b: u64) {
+    super(SerdeAdjacentlyTaggedTag.Baz);
+    this.a = a;
+    this.b = b;
+  }
+}
+
+export class SerdeFlatten {
+  flattened: FlattenedStruct;
+
+  constructor(flattened: FlattenedStruct) {
+    this.flattened = flattened;
+  }
+}
+
+export enum SerdeInternallyTaggedTag {
+  Foo,
+  Baz,
+}
+
+export class SerdeInternallyTagged {
+  readonly tag: SerdeInternallyTaggedTag;
+
+  constructor(tag: SerdeInternallyTaggedTag) {
+    this.tag = tag;
+  }
+}
+
+export class SerdeInternallyTagged_Foo extends SerdeInternallyTagged {
+  constructor() {
+    super(SerdeInternallyTaggedTag.Foo);
+  }
+}
+
+export class SerdeInternallyTagged_Baz extends SerdeInternallyTagged {
+  a: i8;
+  b: u64;
+
+  constructor(a: i8, b: u64) {
+    super(SerdeInternallyTaggedTag.Baz);
+    this.a = a;
+    this.b = b;
+  }
+}
+
+export class SerdePropertyRenaming {
+  fooBar: string;
+  quxBaz: f64;
+  rawStruct: i32;
+
+  constructor(fooBar: string, quxBaz: f64, rawStruct: i32) {
+    this.fooBar = fooBar;
+    this.quxBaz = quxBaz;
+    this.rawStruct = rawStruct;
+  }
+}
+
+export enum SerdeUntaggedTag {
+  Bar,
+  Baz,
+}
+
+export class SerdeUntagged {
+  readonly tag: SerdeUntaggedTag;
+
+  constructor(tag: SerdeUntaggedTag) {
+    this.tag = tag;
+  }
+}
+
+export class SerdeUntagged_Bar extends SerdeUntagged {
+  value: string;
+
+  constructor(value: string) {
+    super(SerdeUntaggedTag.Bar);
+    this.value = value;
+  }
+}
+
+export class SerdeUntagged_Baz extends SerdeUntagged {
+  a: i8;
+  b: u64;
+
+  constructor(a: i8, b: u64) {
+    super(SerdeUntaggedTag.Baz);
+    this.a = a;
+    this.b = b;
+  }
+}
+
+export enum SerdeVariantRenamingTag {
+  FooBar,
+  QuxBaz,
+}
+
+export class SerdeVariantRenaming {
+  readonly tag: SerdeVariantRenamingTag;
+
+  constructor(tag: SerdeVariantRenamingTag) {
+    this.tag = tag;
+  }
+}
+
+export class SerdeVariantRenaming_FooBar extends SerdeVariantRenaming {
+  constructor() {
+    super(SerdeVariantRenamingTag.FooBar);
+  }
+}
+
+export class SerdeVariantRenaming_QuxBaz extends SerdeVariantRenaming {
+  /**
+   * Will be renamed to "FooBar" because of the `rename_all` on the
+   * variant.
+   */
+  fooBar: string;
+  quxBaz: f64;
+
+  constructor(fooBar: string, quxBaz: f64) {
+    super(SerdeVariantRenamingTag.QuxBaz);
+    this.fooBar = fooBar;
+    this.quxBaz = quxBaz;
+  }
+}
+
+/**
+ * A state update to communicate to the Redux host.
+ *
+ * Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+ * changed.
+ */
+export class StateUpdate {
+  title: string | null;
+  revision: Box<u16> | null;
+
+  constructor(title: string | null, revision: Box<u16> | null) {
+    this.title = title;
+    this.revision = revision;
+  }
+}
+
+export class StructWithOptions {
+  filledString: string;
+  emptyString: string;
+  filledOptionString: string | null;
+  emptyOptionString: string | null;
+  neverSkippedFilledOptionString: string | null;
+  neverSkippedEmptyOptionString: string | null;
+
+  constructor(filledString: string, emptyString: string, filledOptionString: string | null, emptyOptionString: string | null, neverSkippedFilledOptionString: string | null, neverSkippedEmptyOptionString: string | null) {
+    this.filledString = filledString;
+    this.emptyString = emptyString;
+    this.filledOptionString = filledOptionString;
+    this.emptyOptionString = emptyOptionString;
+    this.neverSkippedFilledOptionString = neverSkippedFilledOptionString;
+    this.neverSkippedEmptyOptionString = neverSkippedEmptyOptionString;
+  }
+}
+
+/**
+ * A point of an arbitrary type, with explicit trait bounds.
+ */
+export class ExplicitBoundPoint_u64 {
+  value: u64;
+
+  constructor(value: u64) {
+    this.value = value;
+  }
+}
+
+/**
+ * A point of an arbitrary type.
+ */
+export class Point_u64 {
+  value: u64;
+
+  constructor(value: u64) {
+    this.value = value;
+  }
+}
+
+/**
+ * A point of an arbitrary type.
+ */
+export class Point_Point_u64 {
+  value: Point_u64;
+
+  constructor(value: Point_u64) {
+    this.value = value;
+  }
+}
+
+export class StructWithGenerics_u64 {
+  list: Array<u64>;
+  points: Array<Point_u64>;
+  recursive: Array<Point_Point_u64>;
+  complexNested: Map<string, Array<FloatingPoint>> | null;
+  optionalTimestamp: MyDateTime | null;
+
+  constructor(list: Array<u64>, points: Array<Point_u64>, recursive: Array<Point_Point_u64>, complexNested: Map<string, Array<FloatingPoint>> | null, optionalTimestamp: MyDateTime | null) {
+    this.list = list;
+    this.points = points;
+    this.recursive = recursive;
+    this.complexNested = complexNested;
+    this.optionalTimestamp = optionalTimestamp;
+  }
+}
+
+export enum Result_Bytes_StringTag {
+  Ok,
+  Err,
+}
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+export class Result_Bytes_String {
+  readonly tag: Result_Bytes_StringTag;
+
+  constructor(tag: Result_Bytes_StringTag) {
+    this.tag = tag;
+  }
+}
+
+/**
+ * Represents a successful result.
+ */
+export class Result_Bytes_String_Ok extends Result_Bytes_String {
+  value: Uint8Array;
+
+  constructor(value: Uint8Array) {
+    super(Result_Bytes_StringTag.Ok);
+    this.value = value;
+  }
+}
+
+/**
+ * Represents an error.
+ */
+export class Result_Bytes_String_Err extends Result_Bytes_String {
+  value: string;
+
+  constructor(value: string) {
+    super(Result_Bytes_StringTag.Err);
+    this.value = value;
+  }
+}
+
+export enum Result_Unit_u32Tag {
+  Ok,
+  Err,
+}
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+export class Result_Unit_u32 {
+  readonly tag: Result_Unit_u32Tag;
+
+  constructor(tag: Result_Unit_u32Tag) {
+    this.tag = tag;
+  }
+}
+
+/**
+ * Represents a successful result.
+ */
+export class Result_Unit_u32_Ok extends Result_Unit_u32 {
+  value: Unit;
+
+  constructor(value: Unit) {
+    super(Result_Unit_u32Tag.Ok);
+    this.value = value;
+  }
+}
+
+/**
+ * Represents an error.
+ */
+export class Result_Unit_u32_Err extends Result_Unit_u32 {
+  value: u32;
+
+  constructor(value: u32) {
+    super(Result_Unit_u32Tag.Err);
+    this.value = value;
+  }
+}
+
+export enum Result_String_StringTag {
+  Ok,
+  Err,
+}
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+export class Result_String_String {
+  readonly tag: Result_String_StringTag;
+
+  constructor(tag: Result_String_StringTag) {
+    this.tag = tag;
+  }
+}
+
+/**
+ * Represents a successful result.
+ */
+export class Result_String_String_Ok extends Result_String_String {
+  value: string;
+
+  constructor(value: string) {
+    super(Result_String_StringTag.Ok);
+    this.value = value;
+  }
+}
+
+/**
+ * Represents an error.
+ */
+export class Result_String_String_Err extends Result_String_String {
+  value: string;
+
+  constructor(value: string) {
+    super(Result_String_StringTag.Err);
+    this.value = value;
+  }
+}
+
+export function encode_DocExampleEnum_Variant1(writer: Writer, value: DocExampleEnum_Variant1): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_DocExampleEnum_Variant1(reader: Reader): DocExampleEnum_Variant1 {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<DocExampleEnum_Variant1>() : new DocExampleEnum_Variant1(value);
+}
+
+export function encode_DocExampleEnum_Variant2(writer: Writer, value: DocExampleEnum_Variant2): void {
+  writer.writeMap(1);
+  writer.writeString("inner");
+  encode_i8(writer, value.inner);
+}
+
+export function decode_DocExampleEnum_Variant2(reader: Reader): DocExampleEnum_Variant2 {
+  let inner: i8 = 0;
+  let has_inner = false;
+  const fields = reader.readStruct(["inner"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "inner") {
+      inner = decode_i8(reader);
+      has_inner = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_inner) {
+    return reader.fail<DocExampleEnum_Variant2>();
+  }
+  return new DocExampleEnum_Variant2(inner);
+}
+
+export function encode_DocExampleEnum(writer: Writer, value: DocExampleEnum): void {
+  switch (value.tag) {
+    case DocExampleEnumTag.Variant1: {
+      writer.writeMap(1);
+      writer.writeString("Variant1");
+      encode_DocExampleEnum_Variant1(writer, changetype<DocExampleEnum_Variant1>(value));
+      break;
+    }
+    case DocExampleEnumTag.Variant2: {
+      writer.writeMap(1);
+      writer.writeString("Variant2");
+      encode_DocExampleEnum_Variant2(writer, changetype<DocExampleEnum_Variant2>(value));
+      break;
+    }
+  }
+}
+
+export function decode_DocExampleEnum(reader: Reader): DocExampleEnum {
+  const variant = reader.readVariant();
+  if (variant.name == "Variant1") {
+    const result = decode_DocExampleEnum_Variant1(variant.content);
+    return variant.content.error ? reader.fail<DocExampleEnum>() : result;
+  }
+  if (variant.name == "Variant2") {
+    const result = decode_DocExampleEnum_Variant2(variant.content);
+    return variant.content.error ? reader.fail<DocExampleEnum>() : result;
+  }
+  return reader.fail<DocExampleEnum>();
+}
+
+export function encode_DocExampleStruct(writer: Writer, value: DocExampleStruct): void {
+  writer.writeMap(2);
+  writer.writeString("multi_line");
+  encode_String(writer, value.multiLine);
+  writer.writeString("type");
+  encode_String(writer, value.type_);
+}
+
+export function decode_DocExampleStruct(reader: Reader): DocExampleStruct {
+  let multiLine: string = "";
+  let has_multiLine = false;
+  let type_: string = "";
+  let has_type_ = false;
+  const fields = reader.readStruct(["multi_line", "type"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "multi_line") {
+      multiLine = decode_String(reader);
+      has_multiLine = true;
+    } else if (key == "type") {
+      type_ = decode_String(reader);
+      has_type_ = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_multiLine || !has_type_) {
+    return reader.fail<DocExampleStruct>();
+  }
+  return new DocExampleStruct(multiLine, type_);
+}
+
+export function encode_ExplicitedlyImportedType(writer: Writer, value: ExplicitedlyImportedType): void {
+  writer.writeMap(1);
+  writer.writeString("you_will_see_this");
+  encode_bool(writer, value.youWillSeeThis);
+}
+
+export function decode_ExplicitedlyImportedType(reader: Reader): ExplicitedlyImportedType {
+  let youWillSeeThis: bool = false;
+  let has_youWillSeeThis = false;
+  const fields = reader.readStruct(["you_will_see_this"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "you_will_see_this") {
+      youWillSeeThis = decode_bool(reader);
+      has_youWillSeeThis = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_youWillSeeThis) {
+    return reader.fail<ExplicitedlyImportedType>();
+  }
+  return new ExplicitedlyImportedType(youWillSeeThis);
+}
+
+export function encode_FlattenedStruct(writer: Writer, value: FlattenedStruct): void {
+  writer.writeMap(2);
+  writer.writeString("foo");
+  encode_String(writer, value.foo);
+  writer.writeString("bar");
+  encode_i64(writer, value.bar);
+}
+
+export function decode_FlattenedStruct(reader: Reader): FlattenedStruct {
+  let foo: string = "";
+  let has_foo = false;
+  let bar: i64 = 0;
+  let has_bar = false;
+  const fields = reader.readStruct(["foo", "bar"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "foo") {
+      foo = decode_String(reader);
+      has_foo = true;
+    } else if (key == "bar") {
+      bar = decode_i64(reader);
+      has_bar = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_foo || !has_bar) {
+    return reader.fail<FlattenedStruct>();
+  }
+  return new FlattenedStruct(foo, bar);
+}
+
+export function encode_Point_f64(writer: Writer, value: Point_f64): void {
+  writer.writeMap(1);
+  writer.writeString("value");
+  encode_f64(writer, value.value);
+}
+
+export function decode_Point_f64(reader: Reader): Point_f64 {
+  let value_: f64 = 0;
+  let has_value_ = false;
+  const fields = reader.readStruct(["value"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "value") {
+      value_ = decode_f64(reader);
+      has_value_ = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_value_) {
+    return reader.fail<Point_f64>();
+  }
+  return new Point_f64(value_);
+}
+
+export function encode_FpAdjacentlyTagged_Bar(writer: Writer, value: FpAdjacentlyTagged_Bar): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_FpAdjacentlyTagged_Bar(reader: Reader): FpAdjacentlyTagged_Bar {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<FpAdjacentlyTagged_Bar>() : new FpAdjacentlyTagged_Bar(value);
+}
+
+export function encode_FpAdjacentlyTagged_Baz(writer: Writer, value: FpAdjacentlyTagged_Baz): void {
+  writer.writeMap(2);
+  writer.writeString("a");
+  encode_i8(writer, value.a);
+  writer.writeString("b");
+  encode_u64(writer, value.b);
+}
+
+export function decode_FpAdjacentlyTagged_Baz(reader: Reader): FpAdjacentlyTagged_Baz {
+  let a: i8 = 0;
+  let has_a = false;
+  let b: u64 = 0;
+  let has_b = false;
+  const fields = reader.readStruct(["a", "b"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "a") {
+      a = decode_i8(reader);
+      has_a = true;
+    } else if (key == "b") {
+      b = decode_u64(reader);
+      has_b = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_a || !has_b) {
+    return reader.fail<FpAdjacentlyTagged_Baz>();
+  }
+  return new FpAdjacentlyTagged_Baz(a, b);
+}
+
+export function encode_FpAdjacentlyTagged(writer: Writer, value: FpAdjacentlyTagged): void {
+  switch (value.tag) {
+    case FpAdjacentlyTaggedTag.Foo: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("Foo");
+      break;
+    }
+    case FpAdjacentlyTaggedTag.Bar: {
+      writer.writeMap(2);
+      writer.writeString("type");
+      writer.writeString("Bar");
+      writer.writeString("payload");
+      encode_FpAdjacentlyTagged_Bar(writer, changetype<FpAdjacentlyTagged_Bar>(value));
+      break;
+    }
+    case FpAdjacentlyTaggedTag.Baz: {
+      writer.writeMap(2);
+      writer.writeString("type");
+      writer.writeString("Baz");
+      writer.writeString("payload");
+      encode_FpAdjacentlyTagged_Baz(writer, changetype<FpAdjacentlyTagged_Baz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_FpAdjacentlyTagged(reader: Reader): FpAdjacentlyTagged {
+  const variant = reader.readAdjacent("type", "payload");
+  if (variant.name == "Foo") {
+    return new FpAdjacentlyTagged_Foo();
+  }
+  if (variant.name == "Bar") {
+    const result = decode_FpAdjacentlyTagged_Bar(variant.content);
+    return variant.content.error ? reader.fail<FpAdjacentlyTagged>() : result;
+  }
+  if (variant.name == "Baz") {
+    const result = decode_FpAdjacentlyTagged_Baz(variant.content);
+    return variant.content.error ? reader.fail<FpAdjacentlyTagged>() : result;
+  }
+  return reader.fail<FpAdjacentlyTagged>();
+}
+
+export function encode_FpFlatten(writer: Writer, value: FpFlatten): void {
+  writer.writeMap(2);
+  writer.writeString("foo");
+  encode_String(writer, value.flattened.foo);
+  writer.writeString("bar");
+  encode_i64(writer, value.flattened.bar);
+}
+
+export function decode_FpFlatten(reader: Reader): FpFlatten {
+  let flattened_foo: string = "";
+  let has_flattened_foo = false;
+  let flattened_bar: i64 = 0;
+  let has_flattened_bar = false;
+  const fields = reader.readStruct([]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "foo") {
+      flattened_foo = decode_String(reader);
+      has_flattened_foo = true;
+    } else if (key == "bar") {
+      flattened_bar = decode_i64(reader);
+      has_flattened_bar = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_flattened_foo || !has_flattened_bar) {
+    return reader.fail<FpFlatten>();
+  }
+  return new FpFlatten(new FlattenedStruct(flattened_foo, flattened_bar));
+}
+
+export function encode_FpInternallyTagged_Baz(writer: Writer, value: FpInternallyTagged_Baz): void {
+  writer.writeMap(3);
+  writer.writeString("type");
+  writer.writeString("Baz");
+  writer.writeString("a");
+  encode_i8(writer, value.a);
+  writer.writeString("b");
+  encode_u64(writer, value.b);
+}
+
+export function decode_FpInternallyTagged_Baz(reader: Reader): FpInternallyTagged_Baz {
+  let a: i8 = 0;
+  let has_a = false;
+  let b: u64 = 0;
+  let has_b = false;
+  const fields = reader.readStruct(["type", "a", "b"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "a") {
+      a = decode_i8(reader);
+      has_a = true;
+    } else if (key == "b") {
+      b = decode_u64(reader);
+      has_b = true;
+    } else if (key == "type") {
+      reader.skip();
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_a || !has_b) {
+    return reader.fail<FpInternallyTagged_Baz>();
+  }
+  return new FpInternallyTagged_Baz(a, b);
+}
+
+export function encode_FpInternallyTagged(writer: Writer, value: FpInternallyTagged): void {
+  switch (value.tag) {
+    case FpInternallyTaggedTag.Foo: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("Foo");
+      break;
+    }
+    case FpInternallyTaggedTag.Baz: {
+      encode_FpInternallyTagged_Baz(writer, changetype<FpInternallyTagged_Baz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_FpInternallyTagged(reader: Reader): FpInternallyTagged {
+  const tag = reader.readTag("type");
+  if (tag == "Foo") {
+    reader.skip();
+    return new FpInternallyTagged_Foo();
+  }
+  if (tag == "Baz") {
+    return decode_FpInternallyTagged_Baz(reader);
+  }
+  return reader.fail<FpInternallyTagged>();
+}
+
+export function encode_FpPropertyRenaming(writer: Writer, value: FpPropertyRenaming): void {
+  writer.writeMap(3);
+  writer.writeString("fooBar");
+  encode_String(writer, value.fooBar);
+  writer.writeString("QUX_BAZ");
+  encode_f64(writer, value.quxBaz);
+  writer.writeString("rawStruct");
+  encode_i32(writer, value.rawStruct);
+}
+
+export function decode_FpPropertyRenaming(reader: Reader): FpPropertyRenaming {
+  let fooBar: string = "";
+  let has_fooBar = false;
+  let quxBaz: f64 = 0;
+  let has_quxBaz = false;
+  let rawStruct: i32 = 0;
+  let has_rawStruct = false;
+  const fields = reader.readStruct(["fooBar", "QUX_BAZ", "rawStruct"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "fooBar") {
+      fooBar = decode_String(reader);
+      has_fooBar = true;
+    } else if (key == "QUX_BAZ") {
+      quxBaz = decode_f64(reader);
+      has_quxBaz = true;
+    } else if (key == "rawStruct") {
+      rawStruct = decode_i32(reader);
+      has_rawStruct = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_fooBar || !has_quxBaz || !has_rawStruct) {
+    return reader.fail<FpPropertyRenaming>();
+  }
+  return new FpPropertyRenaming(fooBar, quxBaz, rawStruct);
+}
+
+export function encode_FpUntagged_Bar(writer: Writer, value: FpUntagged_Bar): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_FpUntagged_Bar(reader: Reader): FpUntagged_Bar {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<FpUntagged_Bar>() : new FpUntagged_Bar(value);
+}
+
+export function encode_FpUntagged_Baz(writer: Writer, value: FpUntagged_Baz): void {
+  writer.writeMap(2);
+  writer.writeString("a");
+  encode_i8(writer, value.a);
+  writer.writeString("b");
+  encode_u64(writer, value.b);
+}
+
+export function decode_FpUntagged_Baz(reader: Reader): FpUntagged_Baz {
+  let a: i8 = 0;
+  let has_a = false;
+  let b: u64 = 0;
+  let has_b = false;
+  const fields = reader.readStruct(["a", "b"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "a") {
+      a = decode_i8(reader);
+      has_a = true;
+    } else if (key == "b") {
+      b = decode_u64(reader);
+      has_b = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_a || !has_b) {
+    return reader.fail<FpUntagged_Baz>();
+  }
+  return new FpUntagged_Baz(a, b);
+}
+
+export function encode_FpUntagged(writer: Writer, value: FpUntagged): void {
+  switch (value.tag) {
+    case FpUntaggedTag.Bar: {
+      encode_FpUntagged_Bar(writer, changetype<FpUntagged_Bar>(value));
+      break;
+    }
+    case FpUntaggedTag.Baz: {
+      encode_FpUntagged_Baz(writer, changetype<FpUntagged_Baz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_FpUntagged(reader: Reader): FpUntagged {
+  let attempt = reader.attempt();
+  const result_Bar = decode_FpUntagged_Bar(attempt);
+  if (!attempt.error) {
+    reader.commit(attempt);
+    return result_Bar;
+  }
+  attempt = reader.attempt();
+  const result_Baz = decode_FpUntagged_Baz(attempt);
+  if (!attempt.error) {
+    reader.commit(attempt);
+    return result_Baz;
+  }
+  return reader.fail<FpUntagged>();
+}
+
+export function encode_FpVariantRenaming_QuxBaz(writer: Writer, value: FpVariantRenaming_QuxBaz): void {
+  writer.writeMap(2);
+  writer.writeString("FOO_BAR");
+  encode_String(writer, value.fooBar);
+  writer.writeString("qux_baz");
+  encode_f64(writer, value.quxBaz);
+}
+
+export function decode_FpVariantRenaming_QuxBaz(reader: Reader): FpVariantRenaming_QuxBaz {
+  let fooBar: string = "";
+  let has_fooBar = false;
+  let quxBaz: f64 = 0;
+  let has_quxBaz = false;
+  const fields = reader.readStruct(["FOO_BAR", "qux_baz"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "FOO_BAR") {
+      fooBar = decode_String(reader);
+      has_fooBar = true;
+    } else if (key == "qux_baz") {
+      quxBaz = decode_f64(reader);
+      has_quxBaz = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_fooBar || !has_quxBaz) {
+    return reader.fail<FpVariantRenaming_QuxBaz>();
+  }
+  return new FpVariantRenaming_QuxBaz(fooBar, quxBaz);
+}
+
+export function encode_FpVariantRenaming(writer: Writer, value: FpVariantRenaming): void {
+  switch (value.tag) {
+    case FpVariantRenamingTag.FooBar: {
+      writer.writeString("foo_bar");
+      break;
+    }
+    case FpVariantRenamingTag.QuxBaz: {
+      writer.writeMap(1);
+      writer.writeString("QUX_BAZ");
+      encode_FpVariantRenaming_QuxBaz(writer, changetype<FpVariantRenaming_QuxBaz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_FpVariantRenaming(reader: Reader): FpVariantRenaming {
+  const variant = reader.readVariant();
+  if (variant.name == "foo_bar") {
+    return new FpVariantRenaming_FooBar();
+  }
+  if (variant.name == "QUX_BAZ") {
+    const result = decode_FpVariantRenaming_QuxBaz(variant.content);
+    return variant.content.error ? reader.fail<FpVariantRenaming>() : result;
+  }
+  return reader.fail<FpVariantRenaming>();
+}
+
+export function encode_GroupImportedType1(writer: Writer, value: GroupImportedType1): void {
+  writer.writeMap(1);
+  writer.writeString("you_will_see_this");
+  encode_bool(writer, value.youWillSeeThis);
+}
+
+export function decode_GroupImportedType1(reader: Reader): GroupImportedType1 {
+  let youWillSeeThis: bool = false;
+  let has_youWillSeeThis = false;
+  const fields = reader.readStruct(["you_will_see_this"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "you_will_see_this") {
+      youWillSeeThis = decode_bool(reader);
+      has_youWillSeeThis = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_youWillSeeThis) {
+    return reader.fail<GroupImportedType1>();
+  }
+  return new GroupImportedType1(youWillSeeThis);
+}
+
+export function encode_GroupImportedType2(writer: Writer, value: GroupImportedType2): void {
+  writer.writeMap(1);
+  writer.writeString("you_will_see_this");
+  encode_bool(writer, value.youWillSeeThis);
+}
+
+export function decode_GroupImportedType2(reader: Reader): GroupImportedType2 {
+  let youWillSeeThis: bool = false;
+  let has_youWillSeeThis = false;
+  const fields = reader.readStruct(["you_will_see_this"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "you_will_see_this") {
+      youWillSeeThis = decode_bool(reader);
+      has_youWillSeeThis = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_youWillSeeThis) {
+    return reader.fail<GroupImportedType2>();
+  }
+  return new GroupImportedType2(youWillSeeThis);
+}
+
+export function encode_Response(writer: Writer, value: Response): void {
+  writer.writeMap(3);
+  writer.writeString("body");
+  encode_Bytes(writer, value.body);
+  writer.writeString("headers");
+  encode_RawValue(writer, value.headers);
+  writer.writeString("status_code");
+  encode_u16(writer, value.statusCode);
+}
+
+export function decode_Response(reader: Reader): Response {
+  let body: Body = new Uint8Array(0);
+  let has_body = false;
+  let headers: RawValue = new RawValue();
+  let has_headers = false;
+  let statusCode: u16 = 0;
+  let has_statusCode = false;
+  const fields = reader.readStruct(["body", "headers", "status_code"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "body") {
+      body = decode_Bytes(reader);
+      has_body = true;
+    } else if (key == "headers") {
+      headers = decode_RawValue(reader);
+      has_headers = true;
+    } else if (key == "status_code") {
+      statusCode = decode_u16(reader);
+      has_statusCode = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_body || !has_headers || !has_statusCode) {
+    return reader.fail<Response>();
+  }
+  return new Response(body, headers, statusCode);
+}
+
+export function encode_RequestError_ServerError(writer: Writer, value: RequestError_ServerError): void {
+  writer.writeMap(3);
+  writer.writeString("type");
+  writer.writeString("server_error");
+  writer.writeString("status_code");
+  encode_u16(writer, value.statusCode);
+  writer.writeString("response");
+  encode_Bytes(writer, value.response);
+}
+
+export function decode_RequestError_ServerError(reader: Reader): RequestError_ServerError {
+  let statusCode: u16 = 0;
+  let has_statusCode = false;
+  let response: Body = new Uint8Array(0);
+  let has_response = false;
+  const fields = reader.readStruct(["type", "status_code", "response"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "status_code") {
+      statusCode = decode_u16(reader);
+      has_statusCode = true;
+    } else if (key == "response") {
+      response = decode_Bytes(reader);
+      has_response = true;
+    } else if (key == "type") {
+      reader.skip();
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_statusCode || !has_response) {
+    return reader.fail<RequestError_ServerError>();
+  }
+  return new RequestError_ServerError(statusCode, response);
+}
+
+export function encode_RequestError_Other(writer: Writer, value: RequestError_Other): void {
+  writer.writeMap(2);
+  writer.writeString("type");
+  writer.writeString("other/misc");
+  writer.writeString("reason");
+  encode_String(writer, value.reason);
+}
+
+export function decode_RequestError_Other(reader: Reader): RequestError_Other {
+  let reason: string = "";
+  let has_reason = false;
+  const fields = reader.readStruct(["type", "reason"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "reason") {
+      reason = decode_String(reader);
+      has_reason = true;
+    } else if (key == "type") {
+      reader.skip();
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_reason) {
+    return reader.fail<RequestError_Other>();
+  }
+  return new RequestError_Other(reason);
+}
+
+export function encode_RequestError(writer: Writer, value: RequestError): void {
+  switch (value.tag) {
+    case RequestErrorTag.Offline: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("offline");
+      break;
+    }
+    case RequestErrorTag.NoRoute: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("no_route");
+      break;
+    }
+    case RequestErrorTag.ConnectionRefused: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("connection_refused");
+      break;
+    }
+    case RequestErrorTag.Timeout: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("timeout");
+      break;
+    }
+    case RequestErrorTag.ServerError: {
+      encode_RequestError_ServerError(writer, changetype<RequestError_ServerError>(value));
+      break;
+    }
+    case RequestErrorTag.Other: {
+      encode_RequestError_Other(writer, changetype<RequestError_Other>(value));
+      break;
+    }
+  }
+}
+
+export function decode_RequestError(reader: Reader): RequestError {
+  const tag = reader.readTag("type");
+  if (tag == "offline") {
+    reader.skip();
+    return new RequestError_Offline();
+  }
+  if (tag == "no_route") {
+    reader.skip();
+    return new RequestError_NoRoute();
+  }
+  if (tag == "connection_refused") {
+    reader.skip();
+    return new RequestError_ConnectionRefused();
+  }
+  if (tag == "timeout") {
+    reader.skip();
+    return new RequestError_Timeout();
+  }
+  if (tag == "server_error") {
+    return decode_RequestError_ServerError(reader);
+  }
+  if (tag == "other/misc") {
+    return decode_RequestError_Other(reader);
+  }
+  return reader.fail<RequestError>();
+}
+
+export function encode_Result_Response_RequestError_Ok(writer: Writer, value: Result_Response_RequestError_Ok): void {
+  encode_Response(writer, value.value);
+}
+
+export function decode_Result_Response_RequestError_Ok(reader: Reader): Result_Response_RequestError_Ok {
+  const value = decode_Response(reader);
+  return reader.error ? reader.fail<Result_Response_RequestError_Ok>() : new Result_Response_RequestError_Ok(value);
+}
+
+export function encode_Result_Response_RequestError_Err(writer: Writer, value: Result_Response_RequestError_Err): void {
+  encode_RequestError(writer, value.value);
+}
+
+export function decode_Result_Response_RequestError_Err(reader: Reader): Result_Response_RequestError_Err {
+  const value = decode_RequestError(reader);
+  return reader.error ? reader.fail<Result_Response_RequestError_Err>() : new Result_Response_RequestError_Err(value);
+}
+
+export function encode_Result_Response_RequestError(writer: Writer, value: Result_Response_RequestError): void {
+  switch (value.tag) {
+    case Result_Response_RequestErrorTag.Ok: {
+      writer.writeMap(1);
+      writer.writeString("Ok");
+      encode_Result_Response_RequestError_Ok(writer, changetype<Result_Response_RequestError_Ok>(value));
+      break;
+    }
+    case Result_Response_RequestErrorTag.Err: {
+      writer.writeMap(1);
+      writer.writeString("Err");
+      encode_Result_Response_RequestError_Err(writer, changetype<Result_Response_RequestError_Err>(value));
+      break;
+    }
+  }
+}
+
+export function decode_Result_Response_RequestError(reader: Reader): Result_Response_RequestError {
+  const variant = reader.readVariant();
+  if (variant.name == "Ok") {
+    const result = decode_Result_Response_RequestError_Ok(variant.content);
+    return variant.content.error ? reader.fail<Result_Response_RequestError>() : result;
+  }
+  if (variant.name == "Err") {
+    const result = decode_Result_Response_RequestError_Err(variant.content);
+    return variant.content.error ? reader.fail<Result_Response_RequestError>() : result;
+  }
+  return reader.fail<Result_Response_RequestError>();
+}
+
+export function encode_ReduxAction_UpdateTitle(writer: Writer, value: ReduxAction_UpdateTitle): void {
+  writer.writeMap(1);
+  writer.writeString("title");
+  encode_String(writer, value.title);
+}
+
+export function decode_ReduxAction_UpdateTitle(reader: Reader): ReduxAction_UpdateTitle {
+  let title: string = "";
+  let has_title = false;
+  const fields = reader.readStruct(["title"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "title") {
+      title = decode_String(reader);
+      has_title = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_title) {
+    return reader.fail<ReduxAction_UpdateTitle>();
+  }
+  return new ReduxAction_UpdateTitle(title);
+}
+
+export function encode_ReduxAction(writer: Writer, value: ReduxAction): void {
+  switch (value.tag) {
+    case ReduxActionTag.ClearTitle: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("clear_title");
+      break;
+    }
+    case ReduxActionTag.UpdateTitle: {
+      writer.writeMap(2);
+      writer.writeString("type");
+      writer.writeString("update_title");
+      writer.writeString("payload");
+      encode_ReduxAction_UpdateTitle(writer, changetype<ReduxAction_UpdateTitle>(value));
+      break;
+    }
+  }
+}
+
+export function decode_ReduxAction(reader: Reader): ReduxAction {
+  const variant = reader.readAdjacent("type", "payload");
+  if (variant.name == "clear_title") {
+    return new ReduxAction_ClearTitle();
+  }
+  if (variant.name == "update_title") {
+    const result = decode_ReduxAction_UpdateTitle(variant.content);
+    return variant.content.error ? reader.fail<ReduxAction>() : result;
+  }
+  return reader.fail<ReduxAction>();
+}
+
+export function encode_Option_Body(writer: Writer, value: Body | null): void {
+  if (value !== null) {
+    encode_Bytes(writer, value!);
+  } else {
+    writer.writeNil();
+  }
+}
+
+export function decode_Option_Body(reader: Reader): Body | null {
+  if (reader.readNil()) {
+    return null;
+  }
+  return decode_Bytes(reader);
+}
+
+export function encode_Request(writer: Writer, value: Request): void {
+  let count: u32 = 3;
+  if (value.body !== null) {
+    count++;
+  }
+  writer.writeMap(count);
+  writer.writeString("url");
+  encode_String(writer, value.url);
+  writer.writeString("method");
+  encode_RawValue(writer, value.method);
+  writer.writeString("headers");
+  encode_RawValue(writer, value.headers);
+  if (value.body !== null) {
+    writer.writeString("body");
+    encode_Option_Body(writer, value.body);
+  }
+}
+
+export function decode_Request(reader: Reader): Request {
+  let url: string = "";
+  let has_url = false;
+  let method: RawValue = new RawValue();
+  let has_method = false;
+  let headers: RawValue = new RawValue();
+  let has_headers = false;
+  let body: Body | null = null;
+  const fields = reader.readStruct(["url", "method", "headers", "body"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "url") {
+      url = decode_String(reader);
+      has_url = true;
+    } else if (key == "method") {
+      method = decode_RawValue(reader);
+      has_method = true;
+    } else if (key == "headers") {
+      headers = decode_RawValue(reader);
+      has_headers = true;
+    } else if (key == "body") {
+      body = decode_Option_Body(reader);
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_url || !has_method || !has_headers) {
+    return reader.fail<Request>();
+  }
+  return new Request(url, method, headers, body);
+}
+
+export function encode_SerdeAdjacentlyTagged_Bar(writer: Writer, value: SerdeAdjacentlyTagged_Bar): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_SerdeAdjacentlyTagged_Bar(reader: Reader): SerdeAdjacentlyTagged_Bar {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<SerdeAdjacentlyTagged_Bar>() : new SerdeAdjacentlyTagged_Bar(value);
+}
+
+export function encode_SerdeAdjacentlyTagged_Baz(writer: Writer, value: SerdeAdjacentlyTagged_Baz): void {
+  writer.writeMap(2);
+  writer.writeString("a");
+  encode_i8(writer, value.a);
+  writer.writeString("b");
+  encode_u64(writer, value.b);
+}
+
+export function decode_SerdeAdjacentlyTagged_Baz(reader: Reader): SerdeAdjacentlyTagged_Baz {
+  let a: i8 = 0;
+  let has_a = false;
+  let b: u64 = 0;
+  let has_b = false;
+  const fields = reader.readStruct(["a", "b"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "a") {
+      a = decode_i8(reader);
+      has_a = true;
+    } else if (key == "b") {
+      b = decode_u64(reader);
+      has_b = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_a || !has_b) {
+    return reader.fail<SerdeAdjacentlyTagged_Baz>();
+  }
+  return new SerdeAdjacentlyTagged_Baz(a, b);
+}
+
+export function encode_SerdeAdjacentlyTagged(writer: Writer, value: SerdeAdjacentlyTagged): void {
+  switch (value.tag) {
+    case SerdeAdjacentlyTaggedTag.Foo: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("Foo");
+      break;
+    }
+    case SerdeAdjacentlyTaggedTag.Bar: {
+      writer.writeMap(2);
+      writer.writeString("type");
+      writer.writeString("Bar");
+      writer.writeString("payload");
+      encode_SerdeAdjacentlyTagged_Bar(writer, changetype<SerdeAdjacentlyTagged_Bar>(value));
+      break;
+    }
+    case SerdeAdjacentlyTaggedTag.Baz: {
+      writer.writeMap(2);
+      writer.writeString("type");
+      writer.writeString("Baz");
+      writer.writeString("payload");
+      encode_SerdeAdjacentlyTagged_Baz(writer, changetype<SerdeAdjacentlyTagged_Baz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_SerdeAdjacentlyTagged(reader: Reader): SerdeAdjacentlyTagged {
+  const variant = reader.readAdjacent("type", "payload");
+  if (variant.name == "Foo") {
+    return new SerdeAdjacentlyTagged_Foo();
+  }
+  if (variant.name == "Bar") {
+    const result = decode_SerdeAdjacentlyTagged_Bar(variant.content);
+    return variant.content.error ? reader.fail<SerdeAdjacentlyTagged>() : result;
+  }
+  if (variant.name == "Baz") {
+    const result = decode_SerdeAdjacentlyTagged_Baz(variant.content);
+    return variant.content.error ? reader.fail<SerdeAdjacentlyTagged>() : result;
+  }
+  return reader.fail<SerdeAdjacentlyTagged>();
+}
+
+export function encode_SerdeFlatten(writer: Writer, value: SerdeFlatten): void {
+  writer.writeMap(2);
+  writer.writeString("foo");
+  encode_String(writer, value.flattened.foo);
+  writer.writeString("bar");
+  encode_i64(writer, value.flattened.bar);
+}
+
+export function decode_SerdeFlatten(reader: Reader): SerdeFlatten {
+  let flattened_foo: string = "";
+  let has_flattened_foo = false;
+  let flattened_bar: i64 = 0;
+  let has_flattened_bar = false;
+  const fields = reader.readStruct([]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "foo") {
+      flattened_foo = decode_String(reader);
+      has_flattened_foo = true;
+    } else if (key == "bar") {
+      flattened_bar = decode_i64(reader);
+      has_flattened_bar = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_flattened_foo || !has_flattened_bar) {
+    return reader.fail<SerdeFlatten>();
+  }
+  return new SerdeFlatten(new FlattenedStruct(flattened_foo, flattened_bar));
+}
+
+export function encode_SerdeInternallyTagged_Baz(writer: Writer, value: SerdeInternallyTagged_Baz): void {
+  writer.writeMap(3);
+  writer.writeString("type");
+  writer.writeString("Baz");
+  writer.writeString("a");
+  encode_i8(writer, value.a);
+  writer.writeString("b");
+  encode_u64(writer, value.b);
+}
+
+export function decode_SerdeInternallyTagged_Baz(reader: Reader): SerdeInternallyTagged_Baz {
+  let a: i8 = 0;
+  let has_a = false;
+  let b: u64 = 0;
+  let has_b = false;
+  const fields = reader.readStruct(["type", "a", "b"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "a") {
+      a = decode_i8(reader);
+      has_a = true;
+    } else if (key == "b") {
+      b = decode_u64(reader);
+      has_b = true;
+    } else if (key == "type") {
+      reader.skip();
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_a || !has_b) {
+    return reader.fail<SerdeInternallyTagged_Baz>();
+  }
+  return new SerdeInternallyTagged_Baz(a, b);
+}
+
+export function encode_SerdeInternallyTagged(writer: Writer, value: SerdeInternallyTagged): void {
+  switch (value.tag) {
+    case SerdeInternallyTaggedTag.Foo: {
+      writer.writeMap(1);
+      writer.writeString("type");
+      writer.writeString("Foo");
+      break;
+    }
+    case SerdeInternallyTaggedTag.Baz: {
+      encode_SerdeInternallyTagged_Baz(writer, changetype<SerdeInternallyTagged_Baz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_SerdeInternallyTagged(reader: Reader): SerdeInternallyTagged {
+  const tag = reader.readTag("type");
+  if (tag == "Foo") {
+    reader.skip();
+    return new SerdeInternallyTagged_Foo();
+  }
+  if (tag == "Baz") {
+    return decode_SerdeInternallyTagged_Baz(reader);
+  }
+  return reader.fail<SerdeInternallyTagged>();
+}
+
+export function encode_SerdePropertyRenaming(writer: Writer, value: SerdePropertyRenaming): void {
+  writer.writeMap(3);
+  writer.writeString("fooBar");
+  encode_String(writer, value.fooBar);
+  writer.writeString("QUX_BAZ");
+  encode_f64(writer, value.quxBaz);
+  writer.writeString("rawStruct");
+  encode_i32(writer, value.rawStruct);
+}
+
+export function decode_SerdePropertyRenaming(reader: Reader): SerdePropertyRenaming {
+  let fooBar: string = "";
+  let has_fooBar = false;
+  let quxBaz: f64 = 0;
+  let has_quxBaz = false;
+  let rawStruct: i32 = 0;
+  let has_rawStruct = false;
+  const fields = reader.readStruct(["fooBar", "QUX_BAZ", "rawStruct"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "fooBar") {
+      fooBar = decode_String(reader);
+      has_fooBar = true;
+    } else if (key == "QUX_BAZ") {
+      quxBaz = decode_f64(reader);
+      has_quxBaz = true;
+    } else if (key == "rawStruct") {
+      rawStruct = decode_i32(reader);
+      has_rawStruct = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_fooBar || !has_quxBaz || !has_rawStruct) {
+    return reader.fail<SerdePropertyRenaming>();
+  }
+  return new SerdePropertyRenaming(fooBar, quxBaz, rawStruct);
+}
+
+export function encode_SerdeUntagged_Bar(writer: Writer, value: SerdeUntagged_Bar): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_SerdeUntagged_Bar(reader: Reader): SerdeUntagged_Bar {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<SerdeUntagged_Bar>() : new SerdeUntagged_Bar(value);
+}
+
+export function encode_SerdeUntagged_Baz(writer: Writer, value: SerdeUntagged_Baz): void {
+  writer.writeMap(2);
+  writer.writeString("a");
+  encode_i8(writer, value.a);
+  writer.writeString("b");
+  encode_u64(writer, value.b);
+}
+
+export function decode_SerdeUntagged_Baz(reader: Reader): SerdeUntagged_Baz {
+  let a: i8 = 0;
+  let has_a = false;
+  let b: u64 = 0;
+  let has_b = false;
+  const fields = reader.readStruct(["a", "b"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "a") {
+      a = decode_i8(reader);
+      has_a = true;
+    } else if (key == "b") {
+      b = decode_u64(reader);
+      has_b = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_a || !has_b) {
+    return reader.fail<SerdeUntagged_Baz>();
+  }
+  return new SerdeUntagged_Baz(a, b);
+}
+
+export function encode_SerdeUntagged(writer: Writer, value: SerdeUntagged): void {
+  switch (value.tag) {
+    case SerdeUntaggedTag.Bar: {
+      encode_SerdeUntagged_Bar(writer, changetype<SerdeUntagged_Bar>(value));
+      break;
+    }
+    case SerdeUntaggedTag.Baz: {
+      encode_SerdeUntagged_Baz(writer, changetype<SerdeUntagged_Baz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_SerdeUntagged(reader: Reader): SerdeUntagged {
+  let attempt = reader.attempt();
+  const result_Bar = decode_SerdeUntagged_Bar(attempt);
+  if (!attempt.error) {
+    reader.commit(attempt);
+    return result_Bar;
+  }
+  attempt = reader.attempt();
+  const result_Baz = decode_SerdeUntagged_Baz(attempt);
+  if (!attempt.error) {
+    reader.commit(attempt);
+    return result_Baz;
+  }
+  return reader.fail<SerdeUntagged>();
+}
+
+export function encode_SerdeVariantRenaming_QuxBaz(writer: Writer, value: SerdeVariantRenaming_QuxBaz): void {
+  writer.writeMap(2);
+  writer.writeString("FooBar");
+  encode_String(writer, value.fooBar);
+  writer.writeString("qux_baz");
+  encode_f64(writer, value.quxBaz);
+}
+
+export function decode_SerdeVariantRenaming_QuxBaz(reader: Reader): SerdeVariantRenaming_QuxBaz {
+  let fooBar: string = "";
+  let has_fooBar = false;
+  let quxBaz: f64 = 0;
+  let has_quxBaz = false;
+  const fields = reader.readStruct(["FooBar", "qux_baz"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "FooBar") {
+      fooBar = decode_String(reader);
+      has_fooBar = true;
+    } else if (key == "qux_baz") {
+      quxBaz = decode_f64(reader);
+      has_quxBaz = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_fooBar || !has_quxBaz) {
+    return reader.fail<SerdeVariantRenaming_QuxBaz>();
+  }
+  return new SerdeVariantRenaming_QuxBaz(fooBar, quxBaz);
+}
+
+export function encode_SerdeVariantRenaming(writer: Writer, value: SerdeVariantRenaming): void {
+  switch (value.tag) {
+    case SerdeVariantRenamingTag.FooBar: {
+      writer.writeString("foo_bar");
+      break;
+    }
+    case SerdeVariantRenamingTag.QuxBaz: {
+      writer.writeMap(1);
+      writer.writeString("QUX_BAZ");
+      encode_SerdeVariantRenaming_QuxBaz(writer, changetype<SerdeVariantRenaming_QuxBaz>(value));
+      break;
+    }
+  }
+}
+
+export function decode_SerdeVariantRenaming(reader: Reader): SerdeVariantRenaming {
+  const variant = reader.readVariant();
+  if (variant.name == "foo_bar") {
+    return new SerdeVariantRenaming_FooBar();
+  }
+  if (variant.name == "QUX_BAZ") {
+    const result = decode_SerdeVariantRenaming_QuxBaz(variant.content);
+    return variant.content.error ? reader.fail<SerdeVariantRenaming>() : result;
+  }
+  return reader.fail<SerdeVariantRenaming>();
+}
+
+export function encode_Option_String(writer: Writer, value: string | null): void {
+  if (value !== null) {
+    encode_String(writer, value!);
+  } else {
+    writer.writeNil();
+  }
+}
+
+export function decode_Option_String(reader: Reader): string | null {
+  if (reader.readNil()) {
+    return null;
+  }
+  return decode_String(reader);
+}
+
+export function encode_Option_u16(writer: Writer, value: Box<u16> | null): void {
+  if (value !== null) {
+    encode_u16(writer, value!.value);
+  } else {
+    writer.writeNil();
+  }
+}
+
+export function decode_Option_u16(reader: Reader): Box<u16> | null {
+  if (reader.readNil()) {
+    return null;
+  }
+  return new Box<u16>(decode_u16(reader));
+}
+
+export function encode_StateUpdate(writer: Writer, value: StateUpdate): void {
+  writer.writeMap(2);
+  writer.writeString("title");
+  encode_Option_String(writer, value.title);
+  writer.writeString("revision");
+  encode_Option_u16(writer, value.revision);
+}
+
+export function decode_StateUpdate(reader: Reader): StateUpdate {
+  let title: string | null = null;
+  let revision: Box<u16> | null = null;
+  const fields = reader.readStruct(["title", "revision"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "title") {
+      title = decode_Option_String(reader);
+    } else if (key == "revision") {
+      revision = decode_Option_u16(reader);
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error) {
+    return reader.fail<StateUpdate>();
+  }
+  return new StateUpdate(title, revision);
+}
+
+export function encode_StructWithOptions(writer: Writer, value: StructWithOptions): void {
+  let count: u32 = 2;
+  if (value.filledString.length > 0) {
+    count++;
+  }
+  if (value.emptyString.length > 0) {
+    count++;
+  }
+  if (value.filledOptionString !== null) {
+    count++;
+  }
+  if (value.emptyOptionString !== null) {
+    count++;
+  }
+  writer.writeMap(count);
+  if (value.filledString.length > 0) {
+    writer.writeString("filledString");
+    encode_String(writer, value.filledString);
+  }
+  if (value.emptyString.length > 0) {
+    writer.writeString("emptyString");
+    encode_String(writer, value.emptyString);
+  }
+  if (value.filledOptionString !== null) {
+    writer.writeString("filledOptionString");
+    encode_Option_String(writer, value.filledOptionString);
+  }
+  if (value.emptyOptionString !== null) {
+    writer.writeString("emptyOptionString");
+    encode_Option_String(writer, value.emptyOptionString);
+  }
+  writer.writeString("neverSkippedFilledOptionString");
+  encode_Option_String(writer, value.neverSkippedFilledOptionString);
+  writer.writeString("neverSkippedEmptyOptionString");
+  encode_Option_String(writer, value.neverSkippedEmptyOptionString);
+}
+
+export function decode_StructWithOptions(reader: Reader): StructWithOptions {
+  let filledString: string = "";
+  let emptyString: string = "";
+  let filledOptionString: string | null = null;
+  let emptyOptionString: string | null = null;
+  let neverSkippedFilledOptionString: string | null = null;
+  let neverSkippedEmptyOptionString: string | null = null;
+  const fields = reader.readStruct(["filledString", "emptyString", "filledOptionString", "emptyOptionString", "neverSkippedFilledOptionString", "neverSkippedEmptyOptionString"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "filledString") {
+      filledString = decode_String(reader);
+    } else if (key == "emptyString") {
+      emptyString = decode_String(reader);
+    } else if (key == "filledOptionString") {
+      filledOptionString = decode_Option_String(reader);
+    } else if (key == "emptyOptionString") {
+      emptyOptionString = decode_Option_String(reader);
+    } else if (key == "neverSkippedFilledOptionString") {
+      neverSkippedFilledOptionString = decode_Option_String(reader);
+    } else if (key == "neverSkippedEmptyOptionString") {
+      neverSkippedEmptyOptionString = decode_Option_String(reader);
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error) {
+    return reader.fail<StructWithOptions>();
+  }
+  return new StructWithOptions(filledString, emptyString, filledOptionString, emptyOptionString, neverSkippedFilledOptionString, neverSkippedEmptyOptionString);
+}
+
+export function encode_Array_f32_3(writer: Writer, value: StaticArray<f32>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_f32(writer, value[i]);
+  }
+}
+
+export function decode_Array_f32_3(reader: Reader): StaticArray<f32> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<f32>>();
+  }
+  const value = new StaticArray<f32>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_f32(reader);
+  }
+  return value;
+}
+
+export function encode_Array_f64_3(writer: Writer, value: StaticArray<f64>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_f64(writer, value[i]);
+  }
+}
+
+export function decode_Array_f64_3(reader: Reader): StaticArray<f64> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<f64>>();
+  }
+  const value = new StaticArray<f64>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_f64(reader);
+  }
+  return value;
+}
+
+export function encode_Array_i16_3(writer: Writer, value: StaticArray<i16>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_i16(writer, value[i]);
+  }
+}
+
+export function decode_Array_i16_3(reader: Reader): StaticArray<i16> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<i16>>();
+  }
+  const value = new StaticArray<i16>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_i16(reader);
+  }
+  return value;
+}
+
+export function encode_Array_i32_3(writer: Writer, value: StaticArray<i32>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_i32(writer, value[i]);
+  }
+}
+
+export function decode_Array_i32_3(reader: Reader): StaticArray<i32> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<i32>>();
+  }
+  const value = new StaticArray<i32>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_i32(reader);
+  }
+  return value;
+}
+
+export function encode_Array_i8_3(writer: Writer, value: StaticArray<i8>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_i8(writer, value[i]);
+  }
+}
+
+export function decode_Array_i8_3(reader: Reader): StaticArray<i8> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<i8>>();
+  }
+  const value = new StaticArray<i8>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_i8(reader);
+  }
+  return value;
+}
+
+export function encode_Array_u16_3(writer: Writer, value: StaticArray<u16>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_u16(writer, value[i]);
+  }
+}
+
+export function decode_Array_u16_3(reader: Reader): StaticArray<u16> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<u16>>();
+  }
+  const value = new StaticArray<u16>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_u16(reader);
+  }
+  return value;
+}
+
+export function encode_Array_u32_3(writer: Writer, value: StaticArray<u32>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_u32(writer, value[i]);
+  }
+}
+
+export function decode_Array_u32_3(reader: Reader): StaticArray<u32> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<u32>>();
+  }
+  const value = new StaticArray<u32>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_u32(reader);
+  }
+  return value;
+}
+
+export function encode_Array_u8_3(writer: Writer, value: StaticArray<u8>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_u8(writer, value[i]);
+  }
+}
+
+export function decode_Array_u8_3(reader: Reader): StaticArray<u8> {
+  if (reader.readArray() != 3) {
+    return reader.fail<StaticArray<u8>>();
+  }
+  const value = new StaticArray<u8>(3);
+  for (let i = 0; i < 3; i++) {
+    value[i] = decode_u8(reader);
+  }
+  return value;
+}
+
+export function encode_ExplicitBoundPoint_u64(writer: Writer, value: ExplicitBoundPoint_u64): void {
+  writer.writeMap(1);
+  writer.writeString("value");
+  encode_u64(writer, value.value);
+}
+
+export function decode_ExplicitBoundPoint_u64(reader: Reader): ExplicitBoundPoint_u64 {
+  let value_: u64 = 0;
+  let has_value_ = false;
+  const fields = reader.readStruct(["value"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "value") {
+      value_ = decode_u64(reader);
+      has_value_ = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_value_) {
+    return reader.fail<ExplicitBoundPoint_u64>();
+  }
+  return new ExplicitBoundPoint_u64(value_);
+}
+
+export function encode_Vec_u64(writer: Writer, value: Array<u64>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_u64(writer, value[i]);
+  }
+}
+
+export function decode_Vec_u64(reader: Reader): Array<u64> {
+  const len = reader.readArray();
+  const value = new Array<u64>();
+  for (let i: u32 = 0; i < len && !reader.error; i++) {
+    value.push(decode_u64(reader));
+  }
+  return value;
+}
+
+export function encode_Point_u64(writer: Writer, value: Point_u64): void {
+  writer.writeMap(1);
+  writer.writeString("value");
+  encode_u64(writer, value.value);
+}
+
+export function decode_Point_u64(reader: Reader): Point_u64 {
+  let value_: u64 = 0;
+  let has_value_ = false;
+  const fields = reader.readStruct(["value"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "value") {
+      value_ = decode_u64(reader);
+      has_value_ = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_value_) {
+    return reader.fail<Point_u64>();
+  }
+  return new Point_u64(value_);
+}
+
+export function encode_Vec_Point_u64(writer: Writer, value: Array<Point_u64>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_Point_u64(writer, value[i]);
+  }
+}
+
+export function decode_Vec_Point_u64(reader: Reader): Array<Point_u64> {
+  const len = reader.readArray();
+  const value = new Array<Point_u64>();
+  for (let i: u32 = 0; i < len && !reader.error; i++) {
+    value.push(decode_Point_u64(reader));
+  }
+  return value;
+}
+
+export function encode_Point_Point_u64(writer: Writer, value: Point_Point_u64): void {
+  writer.writeMap(1);
+  writer.writeString("value");
+  encode_Point_u64(writer, value.value);
+}
+
+export function decode_Point_Point_u64(reader: Reader): Point_Point_u64 {
+  let value_: Point_u64 = changetype<Point_u64>(0);
+  let has_value_ = false;
+  const fields = reader.readStruct(["value"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "value") {
+      value_ = decode_Point_u64(reader);
+      has_value_ = true;
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_value_) {
+    return reader.fail<Point_Point_u64>();
+  }
+  return new Point_Point_u64(value_);
+}
+
+export function encode_Vec_Point_Point_u64(writer: Writer, value: Array<Point_Point_u64>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_Point_Point_u64(writer, value[i]);
+  }
+}
+
+export function decode_Vec_Point_Point_u64(reader: Reader): Array<Point_Point_u64> {
+  const len = reader.readArray();
+  const value = new Array<Point_Point_u64>();
+  for (let i: u32 = 0; i < len && !reader.error; i++) {
+    value.push(decode_Point_Point_u64(reader));
+  }
+  return value;
+}
+
+export function encode_Vec_FloatingPoint(writer: Writer, value: Array<FloatingPoint>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_Point_f64(writer, value[i]);
+  }
+}
+
+export function decode_Vec_FloatingPoint(reader: Reader): Array<FloatingPoint> {
+  const len = reader.readArray();
+  const value = new Array<FloatingPoint>();
+  for (let i: u32 = 0; i < len && !reader.error; i++) {
+    value.push(decode_Point_f64(reader));
+  }
+  return value;
+}
+
+export function encode_Map_String_Vec_FloatingPoint(writer: Writer, value: Map<string, Array<FloatingPoint>>): void {
+  const keys = value.keys();
+  writer.writeMap(<u32>keys.length);
+  for (let i = 0; i < keys.length; i++) {
+    encode_String(writer, keys[i]);
+    encode_Vec_FloatingPoint(writer, value.get(keys[i]));
+  }
+}
+
+export function decode_Map_String_Vec_FloatingPoint(reader: Reader): Map<string, Array<FloatingPoint>> {
+  const len = reader.readMap();
+  const value = new Map<string, Array<FloatingPoint>>();
+  for (let i: u32 = 0; i < len && !reader.error; i++) {
+    const key = decode_String(reader);
+    value.set(key, decode_Vec_FloatingPoint(reader));
+  }
+  return value;
+}
+
+export function encode_Option_Map_String_Vec_FloatingPoint(writer: Writer, value: Map<string, Array<FloatingPoint>> | null): void {
+  if (value !== null) {
+    encode_Map_String_Vec_FloatingPoint(writer, value!);
+  } else {
+    writer.writeNil();
+  }
+}
+
+export function decode_Option_Map_String_Vec_FloatingPoint(reader: Reader): Map<string, Array<FloatingPoint>> | null {
+  if (reader.readNil()) {
+    return null;
+  }
+  return decode_Map_String_Vec_FloatingPoint(reader);
+}
+
+export function encode_Option_MyDateTime(writer: Writer, value: MyDateTime | null): void {
+  if (value !== null) {
+    encode_String(writer, value!);
+  } else {
+    writer.writeNil();
+  }
+}
+
+export function decode_Option_MyDateTime(reader: Reader): MyDateTime | null {
+  if (reader.readNil()) {
+    return null;
+  }
+  return decode_String(reader);
+}
+
+export function encode_StructWithGenerics_u64(writer: Writer, value: StructWithGenerics_u64): void {
+  writer.writeMap(5);
+  writer.writeString("list");
+  encode_Vec_u64(writer, value.list);
+  writer.writeString("points");
+  encode_Vec_Point_u64(writer, value.points);
+  writer.writeString("recursive");
+  encode_Vec_Point_Point_u64(writer, value.recursive);
+  writer.writeString("complex_nested");
+  encode_Option_Map_String_Vec_FloatingPoint(writer, value.complexNested);
+  writer.writeString("optional_timestamp");
+  encode_Option_MyDateTime(writer, value.optionalTimestamp);
+}
+
+export function decode_StructWithGenerics_u64(reader: Reader): StructWithGenerics_u64 {
+  let list: Array<u64> = [];
+  let has_list = false;
+  let points: Array<Point_u64> = [];
+  let has_points = false;
+  let recursive: Array<Point_Point_u64> = [];
+  let has_recursive = false;
+  let complexNested: Map<string, Array<FloatingPoint>> | null = null;
+  let optionalTimestamp: MyDateTime | null = null;
+  const fields = reader.readStruct(["list", "points", "recursive", "complex_nested", "optional_timestamp"]);
+  while (fields.next()) {
+    const key = fields.key;
+    if (key == "list") {
+      list = decode_Vec_u64(reader);
+      has_list = true;
+    } else if (key == "points") {
+      points = decode_Vec_Point_u64(reader);
+      has_points = true;
+    } else if (key == "recursive") {
+      recursive = decode_Vec_Point_Point_u64(reader);
+      has_recursive = true;
+    } else if (key == "complex_nested") {
+      complexNested = decode_Option_Map_String_Vec_FloatingPoint(reader);
+    } else if (key == "optional_timestamp") {
+      optionalTimestamp = decode_Option_MyDateTime(reader);
+    } else {
+      fields.skip();
+    }
+  }
+  if (reader.error || !has_list || !has_points || !has_recursive) {
+    return reader.fail<StructWithGenerics_u64>();
+  }
+  return new StructWithGenerics_u64(list, points, recursive, complexNested, optionalTimestamp);
+}
+
+export function encode_Result_Bytes_String_Ok(writer: Writer, value: Result_Bytes_String_Ok): void {
+  encode_Bytes(writer, value.value);
+}
+
+export function decode_Result_Bytes_String_Ok(reader: Reader): Result_Bytes_String_Ok {
+  const value = decode_Bytes(reader);
+  return reader.error ? reader.fail<Result_Bytes_String_Ok>() : new Result_Bytes_String_Ok(value);
+}
+
+export function encode_Result_Bytes_String_Err(writer: Writer, value: Result_Bytes_String_Err): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_Result_Bytes_String_Err(reader: Reader): Result_Bytes_String_Err {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<Result_Bytes_String_Err>() : new Result_Bytes_String_Err(value);
+}
+
+export function encode_Result_Bytes_String(writer: Writer, value: Result_Bytes_String): void {
+  switch (value.tag) {
+    case Result_Bytes_StringTag.Ok: {
+      writer.writeMap(1);
+      writer.writeString("Ok");
+      encode_Result_Bytes_String_Ok(writer, changetype<Result_Bytes_String_Ok>(value));
+      break;
+    }
+    case Result_Bytes_StringTag.Err: {
+      writer.writeMap(1);
+      writer.writeString("Err");
+      encode_Result_Bytes_String_Err(writer, changetype<Result_Bytes_String_Err>(value));
+      break;
+    }
+  }
+}
+
+export function decode_Result_Bytes_String(reader: Reader): Result_Bytes_String {
+  const variant = reader.readVariant();
+  if (variant.name == "Ok") {
+    const result = decode_Result_Bytes_String_Ok(variant.content);
+    return variant.content.error ? reader.fail<Result_Bytes_String>() : result;
+  }
+  if (variant.name == "Err") {
+    const result = decode_Result_Bytes_String_Err(variant.content);
+    return variant.content.error ? reader.fail<Result_Bytes_String>() : result;
+  }
+  return reader.fail<Result_Bytes_String>();
+}
+
+export function encode_Array_f32_1(writer: Writer, value: StaticArray<f32>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_f32(writer, value[i]);
+  }
+}
+
+export function decode_Array_f32_1(reader: Reader): StaticArray<f32> {
+  if (reader.readArray() != 1) {
+    return reader.fail<StaticArray<f32>>();
+  }
+  const value = new StaticArray<f32>(1);
+  for (let i = 0; i < 1; i++) {
+    value[i] = decode_f32(reader);
+  }
+  return value;
+}
+
+export function encode_Array_f64_1(writer: Writer, value: StaticArray<f64>): void {
+  writer.writeArray(<u32>value.length);
+  for (let i = 0; i < value.length; i++) {
+    encode_f64(writer, value[i]);
+  }
+}
+
+export function decode_Array_f64_1(reader: Reader): StaticArray<f64> {
+  if (reader.readArray() != 1) {
+    return reader.fail<StaticArray<f64>>();
+  }
+  const value = new StaticArray<f64>(1);
+  for (let i = 0; i < 1; i++) {
+    value[i] = decode_f64(reader);
+  }
+  return value;
+}
+
+export function encode_Result_Unit_u32_Ok(writer: Writer, value: Result_Unit_u32_Ok): void {
+  encode_Unit(writer, value.value);
+}
+
+export function decode_Result_Unit_u32_Ok(reader: Reader): Result_Unit_u32_Ok {
+  const value = decode_Unit(reader);
+  return reader.error ? reader.fail<Result_Unit_u32_Ok>() : new Result_Unit_u32_Ok(value);
+}
+
+export function encode_Result_Unit_u32_Err(writer: Writer, value: Result_Unit_u32_Err): void {
+  encode_u32(writer, value.value);
+}
+
+export function decode_Result_Unit_u32_Err(reader: Reader): Result_Unit_u32_Err {
+  const value = decode_u32(reader);
+  return reader.error ? reader.fail<Result_Unit_u32_Err>() : new Result_Unit_u32_Err(value);
+}
+
+export function encode_Result_Unit_u32(writer: Writer, value: Result_Unit_u32): void {
+  switch (value.tag) {
+    case Result_Unit_u32Tag.Ok: {
+      writer.writeMap(1);
+      writer.writeString("Ok");
+      encode_Result_Unit_u32_Ok(writer, changetype<Result_Unit_u32_Ok>(value));
+      break;
+    }
+    case Result_Unit_u32Tag.Err: {
+      writer.writeMap(1);
+      writer.writeString("Err");
+      encode_Result_Unit_u32_Err(writer, changetype<Result_Unit_u32_Err>(value));
+      break;
+    }
+  }
+}
+
+export function decode_Result_Unit_u32(reader: Reader): Result_Unit_u32 {
+  const variant = reader.readVariant();
+  if (variant.name == "Ok") {
+    const result = decode_Result_Unit_u32_Ok(variant.content);
+    return variant.content.error ? reader.fail<Result_Unit_u32>() : result;
+  }
+  if (variant.name == "Err") {
+    const result = decode_Result_Unit_u32_Err(variant.content);
+    return variant.content.error ? reader.fail<Result_Unit_u32>() : result;
+  }
+  return reader.fail<Result_Unit_u32>();
+}
+
+export function encode_Result_String_String_Ok(writer: Writer, value: Result_String_String_Ok): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_Result_String_String_Ok(reader: Reader): Result_String_String_Ok {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<Result_String_String_Ok>() : new Result_String_String_Ok(value);
+}
+
+export function encode_Result_String_String_Err(writer: Writer, value: Result_String_String_Err): void {
+  encode_String(writer, value.value);
+}
+
+export function decode_Result_String_String_Err(reader: Reader): Result_String_String_Err {
+  const value = decode_String(reader);
+  return reader.error ? reader.fail<Result_String_String_Err>() : new Result_String_String_Err(value);
+}
+
+export function encode_Result_String_String(writer: Writer, value: Result_String_String): void {
+  switch (value.tag) {
+    case Result_String_StringTag.Ok: {
+      writer.writeMap(1);
+      writer.writeString("Ok");
+      encode_Result_String_String_Ok(writer, changetype<Result_String_String_Ok>(value));
+      break;
+    }
+    case Result_String_StringTag.Err: {
+      writer.writeMap(1);
+      writer.writeString("Err");
+      encode_Result_String_String_Err(writer, changetype<Result_String_String_Err>(value));
+      break;
+    }
+  }
+}
+
+export function decode_Result_String_String(reader: Reader): Result_String_String {
+  const variant = reader.readVariant();
+  if (variant.name == "Ok") {
+    const result = decode_Result_String_String_Ok(variant.content);
+    return variant.content.error ? reader.fail<Result_String_String>() : result;
+  }
+  if (variant.name == "Err") {
+    const result = decode_Result_String_String_Err(variant.content);
+    return variant.content.error ? reader.fail<Result_String_String>() : result;
+  }
+  return reader.fail<Result_String_String>();
+}
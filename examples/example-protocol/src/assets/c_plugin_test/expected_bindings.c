@@ -0,0 +1,1542 @@
+// ============================================= //
+// Bindings for WebAssembly plugins in C         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#include "bindings.h"
+
+FP_IMPORT("__fp_gen_import_array_f32")
+extern FatPtr __fp_gen_import_array_f32(FatPtr arg);
+
+FpArray_f32_3 import_array_f32(const FpArray_f32_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_f32_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_f32(fp_writer_finish(&arg_writer));
+    FpArray_f32_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_f32_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_f32()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_array_f64")
+extern FatPtr __fp_gen_import_array_f64(FatPtr arg);
+
+FpArray_f64_3 import_array_f64(const FpArray_f64_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_f64_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_f64(fp_writer_finish(&arg_writer));
+    FpArray_f64_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_f64_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_f64()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_array_i16")
+extern FatPtr __fp_gen_import_array_i16(FatPtr arg);
+
+FpArray_i16_3 import_array_i16(const FpArray_i16_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_i16_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_i16(fp_writer_finish(&arg_writer));
+    FpArray_i16_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_i16_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_i16()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_array_i32")
+extern FatPtr __fp_gen_import_array_i32(FatPtr arg);
+
+FpArray_i32_3 import_array_i32(const FpArray_i32_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_i32_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_i32(fp_writer_finish(&arg_writer));
+    FpArray_i32_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_i32_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_i32()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_array_i8")
+extern FatPtr __fp_gen_import_array_i8(FatPtr arg);
+
+FpArray_i8_3 import_array_i8(const FpArray_i8_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_i8_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_i8(fp_writer_finish(&arg_writer));
+    FpArray_i8_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_i8_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_i8()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_array_u16")
+extern FatPtr __fp_gen_import_array_u16(FatPtr arg);
+
+FpArray_u16_3 import_array_u16(const FpArray_u16_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_u16_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_u16(fp_writer_finish(&arg_writer));
+    FpArray_u16_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_u16_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_u16()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_array_u32")
+extern FatPtr __fp_gen_import_array_u32(FatPtr arg);
+
+FpArray_u32_3 import_array_u32(const FpArray_u32_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_u32_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_u32(fp_writer_finish(&arg_writer));
+    FpArray_u32_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_u32_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_u32()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_array_u8")
+extern FatPtr __fp_gen_import_array_u8(FatPtr arg);
+
+FpArray_u8_3 import_array_u8(const FpArray_u8_3 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_u8_3(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_array_u8(fp_writer_finish(&arg_writer));
+    FpArray_u8_3 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpArray_u8_3(&reader, &result)) {
+        fp_panic("Could not decode result of `import_array_u8()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_explicit_bound_point")
+extern void __fp_gen_import_explicit_bound_point(FatPtr arg);
+
+void import_explicit_bound_point(const ExplicitBoundPoint_u64 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_ExplicitBoundPoint_u64(&arg_writer, arg);
+    __fp_gen_import_explicit_bound_point(fp_writer_finish(&arg_writer));
+}
+
+FP_IMPORT("__fp_gen_import_fp_adjacently_tagged")
+extern FatPtr __fp_gen_import_fp_adjacently_tagged(FatPtr arg);
+
+FpAdjacentlyTagged import_fp_adjacently_tagged(const FpAdjacentlyTagged *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpAdjacentlyTagged(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_fp_adjacently_tagged(fp_writer_finish(&arg_writer));
+    FpAdjacentlyTagged result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpAdjacentlyTagged(&reader, &result)) {
+        fp_panic("Could not decode result of `import_fp_adjacently_tagged()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_fp_enum")
+extern FatPtr __fp_gen_import_fp_enum(FatPtr arg);
+
+FpVariantRenaming import_fp_enum(const FpVariantRenaming *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpVariantRenaming(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_fp_enum(fp_writer_finish(&arg_writer));
+    FpVariantRenaming result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpVariantRenaming(&reader, &result)) {
+        fp_panic("Could not decode result of `import_fp_enum()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_fp_flatten")
+extern FatPtr __fp_gen_import_fp_flatten(FatPtr arg);
+
+FpFlatten import_fp_flatten(const FpFlatten *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpFlatten(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_fp_flatten(fp_writer_finish(&arg_writer));
+    FpFlatten result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpFlatten(&reader, &result)) {
+        fp_panic("Could not decode result of `import_fp_flatten()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_fp_internally_tagged")
+extern FatPtr __fp_gen_import_fp_internally_tagged(FatPtr arg);
+
+FpInternallyTagged import_fp_internally_tagged(const FpInternallyTagged *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpInternallyTagged(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_fp_internally_tagged(fp_writer_finish(&arg_writer));
+    FpInternallyTagged result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpInternallyTagged(&reader, &result)) {
+        fp_panic("Could not decode result of `import_fp_internally_tagged()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_fp_struct")
+extern FatPtr __fp_gen_import_fp_struct(FatPtr arg);
+
+FpPropertyRenaming import_fp_struct(const FpPropertyRenaming *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpPropertyRenaming(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_fp_struct(fp_writer_finish(&arg_writer));
+    FpPropertyRenaming result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpPropertyRenaming(&reader, &result)) {
+        fp_panic("Could not decode result of `import_fp_struct()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_fp_untagged")
+extern FatPtr __fp_gen_import_fp_untagged(FatPtr arg);
+
+FpUntagged import_fp_untagged(const FpUntagged *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpUntagged(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_fp_untagged(fp_writer_finish(&arg_writer));
+    FpUntagged result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_FpUntagged(&reader, &result)) {
+        fp_panic("Could not decode result of `import_fp_untagged()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_generics")
+extern FatPtr __fp_gen_import_generics(FatPtr arg);
+
+StructWithGenerics_u64 import_generics(const StructWithGenerics_u64 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_StructWithGenerics_u64(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_generics(fp_writer_finish(&arg_writer));
+    StructWithGenerics_u64 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_StructWithGenerics_u64(&reader, &result)) {
+        fp_panic("Could not decode result of `import_generics()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_get_bytes")
+extern FatPtr __fp_gen_import_get_bytes(void);
+
+Result_Bytes_String import_get_bytes(void) {
+    FatPtr result_ptr = __fp_gen_import_get_bytes();
+    Result_Bytes_String result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_Result_Bytes_String(&reader, &result)) {
+        fp_panic("Could not decode result of `import_get_bytes()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_get_serde_bytes")
+extern FatPtr __fp_gen_import_get_serde_bytes(void);
+
+Result_Bytes_String import_get_serde_bytes(void) {
+    FatPtr result_ptr = __fp_gen_import_get_serde_bytes();
+    Result_Bytes_String result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_Result_Bytes_String(&reader, &result)) {
+        fp_panic("Could not decode result of `import_get_serde_bytes()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_increment_global_state")
+extern FatPtr __fp_gen_import_increment_global_state(void);
+
+static void import_increment_global_state_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    FpReader reader = fp_reader_open(result_ptr);
+    fp_reader_close(&reader);
+    ((import_increment_global_state_callback)callback)(user_data);
+}
+
+void import_increment_global_state(import_increment_global_state_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_increment_global_state();
+    fp_await_async_value(async_value_ptr, import_increment_global_state_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_multiple_primitives")
+extern int64_t __fp_gen_import_multiple_primitives(int8_t arg_1, FatPtr arg_2);
+
+int64_t import_multiple_primitives(int8_t arg_1, const FpString *arg_2) {
+    FpWriter arg_2_writer = {0};
+    fp_encode_String(&arg_2_writer, arg_2);
+    return __fp_gen_import_multiple_primitives(arg_1, fp_writer_finish(&arg_2_writer));
+}
+
+FP_IMPORT("__fp_gen_import_primitive_bool_negate")
+extern bool __fp_gen_import_primitive_bool_negate(bool arg);
+
+bool import_primitive_bool_negate(bool arg) {
+    return __fp_gen_import_primitive_bool_negate(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_bool_negate_async")
+extern FatPtr __fp_gen_import_primitive_bool_negate_async(bool arg);
+
+static void import_primitive_bool_negate_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    bool result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_bool(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_bool_negate_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_bool_negate_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_bool_negate_async(bool arg, import_primitive_bool_negate_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_bool_negate_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_bool_negate_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_f32_add_one")
+extern float __fp_gen_import_primitive_f32_add_one(float arg);
+
+float import_primitive_f32_add_one(float arg) {
+    return __fp_gen_import_primitive_f32_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_f32_add_one_async")
+extern FatPtr __fp_gen_import_primitive_f32_add_one_async(float arg);
+
+static void import_primitive_f32_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    float result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_f32(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_f32_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_f32_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_f32_add_one_async(float arg, import_primitive_f32_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_f32_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_f32_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_f32_add_one_wasmer2")
+extern float __fp_gen_import_primitive_f32_add_one_wasmer2(FatPtr arg);
+
+float import_primitive_f32_add_one_wasmer2(const FpArray_f32_1 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_f32_1(&arg_writer, arg);
+    return __fp_gen_import_primitive_f32_add_one_wasmer2(fp_writer_finish(&arg_writer));
+}
+
+FP_IMPORT("__fp_gen_import_primitive_f64_add_one")
+extern double __fp_gen_import_primitive_f64_add_one(double arg);
+
+double import_primitive_f64_add_one(double arg) {
+    return __fp_gen_import_primitive_f64_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_f64_add_one_async")
+extern FatPtr __fp_gen_import_primitive_f64_add_one_async(double arg);
+
+static void import_primitive_f64_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    double result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_f64(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_f64_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_f64_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_f64_add_one_async(double arg, import_primitive_f64_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_f64_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_f64_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_f64_add_one_wasmer2")
+extern double __fp_gen_import_primitive_f64_add_one_wasmer2(FatPtr arg);
+
+double import_primitive_f64_add_one_wasmer2(const FpArray_f64_1 *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_FpArray_f64_1(&arg_writer, arg);
+    return __fp_gen_import_primitive_f64_add_one_wasmer2(fp_writer_finish(&arg_writer));
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i16_add_one")
+extern int16_t __fp_gen_import_primitive_i16_add_one(int16_t arg);
+
+int16_t import_primitive_i16_add_one(int16_t arg) {
+    return __fp_gen_import_primitive_i16_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i16_add_one_async")
+extern FatPtr __fp_gen_import_primitive_i16_add_one_async(int16_t arg);
+
+static void import_primitive_i16_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    int16_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_i16(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_i16_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_i16_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_i16_add_one_async(int16_t arg, import_primitive_i16_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_i16_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_i16_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i32_add_one")
+extern int32_t __fp_gen_import_primitive_i32_add_one(int32_t arg);
+
+int32_t import_primitive_i32_add_one(int32_t arg) {
+    return __fp_gen_import_primitive_i32_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i32_add_one_async")
+extern FatPtr __fp_gen_import_primitive_i32_add_one_async(int32_t arg);
+
+static void import_primitive_i32_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    int32_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_i32(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_i32_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_i32_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_i32_add_one_async(int32_t arg, import_primitive_i32_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_i32_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_i32_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i64_add_one")
+extern int64_t __fp_gen_import_primitive_i64_add_one(int64_t arg);
+
+int64_t import_primitive_i64_add_one(int64_t arg) {
+    return __fp_gen_import_primitive_i64_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i64_add_one_async")
+extern FatPtr __fp_gen_import_primitive_i64_add_one_async(int64_t arg);
+
+static void import_primitive_i64_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    int64_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_i64(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_i64_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_i64_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_i64_add_one_async(int64_t arg, import_primitive_i64_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_i64_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_i64_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i8_add_one")
+extern int8_t __fp_gen_import_primitive_i8_add_one(int8_t arg);
+
+int8_t import_primitive_i8_add_one(int8_t arg) {
+    return __fp_gen_import_primitive_i8_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_i8_add_one_async")
+extern FatPtr __fp_gen_import_primitive_i8_add_one_async(int8_t arg);
+
+static void import_primitive_i8_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    int8_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_i8(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_i8_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_i8_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_i8_add_one_async(int8_t arg, import_primitive_i8_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_i8_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_i8_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u16_add_one")
+extern uint16_t __fp_gen_import_primitive_u16_add_one(uint16_t arg);
+
+uint16_t import_primitive_u16_add_one(uint16_t arg) {
+    return __fp_gen_import_primitive_u16_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u16_add_one_async")
+extern FatPtr __fp_gen_import_primitive_u16_add_one_async(uint16_t arg);
+
+static void import_primitive_u16_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    uint16_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_u16(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_u16_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_u16_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_u16_add_one_async(uint16_t arg, import_primitive_u16_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_u16_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_u16_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u32_add_one")
+extern uint32_t __fp_gen_import_primitive_u32_add_one(uint32_t arg);
+
+uint32_t import_primitive_u32_add_one(uint32_t arg) {
+    return __fp_gen_import_primitive_u32_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u32_add_one_async")
+extern FatPtr __fp_gen_import_primitive_u32_add_one_async(uint32_t arg);
+
+static void import_primitive_u32_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    uint32_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_u32(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_u32_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_u32_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_u32_add_one_async(uint32_t arg, import_primitive_u32_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_u32_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_u32_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u64_add_one")
+extern uint64_t __fp_gen_import_primitive_u64_add_one(uint64_t arg);
+
+uint64_t import_primitive_u64_add_one(uint64_t arg) {
+    return __fp_gen_import_primitive_u64_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u64_add_one_async")
+extern FatPtr __fp_gen_import_primitive_u64_add_one_async(uint64_t arg);
+
+static void import_primitive_u64_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    uint64_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_u64(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_u64_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_u64_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_u64_add_one_async(uint64_t arg, import_primitive_u64_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_u64_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_u64_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u8_add_one")
+extern uint8_t __fp_gen_import_primitive_u8_add_one(uint8_t arg);
+
+uint8_t import_primitive_u8_add_one(uint8_t arg) {
+    return __fp_gen_import_primitive_u8_add_one(arg);
+}
+
+FP_IMPORT("__fp_gen_import_primitive_u8_add_one_async")
+extern FatPtr __fp_gen_import_primitive_u8_add_one_async(uint8_t arg);
+
+static void import_primitive_u8_add_one_async_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    uint8_t result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_u8(&reader, &result)) {
+        fp_panic("Could not decode result of `import_primitive_u8_add_one_async()`");
+    }
+    fp_reader_close(&reader);
+    ((import_primitive_u8_add_one_async_callback)callback)(result, user_data);
+}
+
+void import_primitive_u8_add_one_async(uint8_t arg, import_primitive_u8_add_one_async_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_primitive_u8_add_one_async(arg);
+    fp_await_async_value(async_value_ptr, import_primitive_u8_add_one_async_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_reset_global_state")
+extern FatPtr __fp_gen_import_reset_global_state(void);
+
+static void import_reset_global_state_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    FpReader reader = fp_reader_open(result_ptr);
+    fp_reader_close(&reader);
+    ((import_reset_global_state_callback)callback)(user_data);
+}
+
+void import_reset_global_state(import_reset_global_state_callback callback, void *user_data) {
+    FatPtr async_value_ptr = __fp_gen_import_reset_global_state();
+    fp_await_async_value(async_value_ptr, import_reset_global_state_ready, (void (*)(void))callback, user_data);
+}
+
+FP_IMPORT("__fp_gen_import_serde_adjacently_tagged")
+extern FatPtr __fp_gen_import_serde_adjacently_tagged(FatPtr arg);
+
+SerdeAdjacentlyTagged import_serde_adjacently_tagged(const SerdeAdjacentlyTagged *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_SerdeAdjacentlyTagged(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_serde_adjacently_tagged(fp_writer_finish(&arg_writer));
+    SerdeAdjacentlyTagged result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_SerdeAdjacentlyTagged(&reader, &result)) {
+        fp_panic("Could not decode result of `import_serde_adjacently_tagged()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_serde_enum")
+extern FatPtr __fp_gen_import_serde_enum(FatPtr arg);
+
+SerdeVariantRenaming import_serde_enum(const SerdeVariantRenaming *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_SerdeVariantRenaming(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_serde_enum(fp_writer_finish(&arg_writer));
+    SerdeVariantRenaming result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_SerdeVariantRenaming(&reader, &result)) {
+        fp_panic("Could not decode result of `import_serde_enum()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_serde_flatten")
+extern FatPtr __fp_gen_import_serde_flatten(FatPtr arg);
+
+SerdeFlatten import_serde_flatten(const SerdeFlatten *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_SerdeFlatten(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_serde_flatten(fp_writer_finish(&arg_writer));
+    SerdeFlatten result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_SerdeFlatten(&reader, &result)) {
+        fp_panic("Could not decode result of `import_serde_flatten()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_serde_internally_tagged")
+extern FatPtr __fp_gen_import_serde_internally_tagged(FatPtr arg);
+
+SerdeInternallyTagged import_serde_internally_tagged(const SerdeInternallyTagged *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_SerdeInternallyTagged(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_serde_internally_tagged(fp_writer_finish(&arg_writer));
+    SerdeInternallyTagged result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_SerdeInternallyTagged(&reader, &result)) {
+        fp_panic("Could not decode result of `import_serde_internally_tagged()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_serde_struct")
+extern FatPtr __fp_gen_import_serde_struct(FatPtr arg);
+
+SerdePropertyRenaming import_serde_struct(const SerdePropertyRenaming *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_SerdePropertyRenaming(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_serde_struct(fp_writer_finish(&arg_writer));
+    SerdePropertyRenaming result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_SerdePropertyRenaming(&reader, &result)) {
+        fp_panic("Could not decode result of `import_serde_struct()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_serde_untagged")
+extern FatPtr __fp_gen_import_serde_untagged(FatPtr arg);
+
+SerdeUntagged import_serde_untagged(const SerdeUntagged *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_SerdeUntagged(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_serde_untagged(fp_writer_finish(&arg_writer));
+    SerdeUntagged result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_SerdeUntagged(&reader, &result)) {
+        fp_panic("Could not decode result of `import_serde_untagged()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_string")
+extern FatPtr __fp_gen_import_string(FatPtr arg);
+
+FpString import_string(const FpString *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_String(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_string(fp_writer_finish(&arg_writer));
+    FpString result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_String(&reader, &result)) {
+        fp_panic("Could not decode result of `import_string()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_struct_with_options")
+extern FatPtr __fp_gen_import_struct_with_options(FatPtr arg);
+
+StructWithOptions import_struct_with_options(const StructWithOptions *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_StructWithOptions(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_struct_with_options(fp_writer_finish(&arg_writer));
+    StructWithOptions result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_StructWithOptions(&reader, &result)) {
+        fp_panic("Could not decode result of `import_struct_with_options()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_timestamp")
+extern FatPtr __fp_gen_import_timestamp(FatPtr arg);
+
+MyDateTime import_timestamp(const MyDateTime *arg) {
+    FpWriter arg_writer = {0};
+    fp_encode_String(&arg_writer, arg);
+    FatPtr result_ptr = __fp_gen_import_timestamp(fp_writer_finish(&arg_writer));
+    MyDateTime result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_String(&reader, &result)) {
+        fp_panic("Could not decode result of `import_timestamp()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_void_function")
+extern void __fp_gen_import_void_function(void);
+
+void import_void_function(void) {
+    __fp_gen_import_void_function();
+}
+
+FP_IMPORT("__fp_gen_import_void_function_empty_result")
+extern FatPtr __fp_gen_import_void_function_empty_result(void);
+
+Result_Unit_u32 import_void_function_empty_result(void) {
+    FatPtr result_ptr = __fp_gen_import_void_function_empty_result();
+    Result_Unit_u32 result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_Result_Unit_u32(&reader, &result)) {
+        fp_panic("Could not decode result of `import_void_function_empty_result()`");
+    }
+    fp_reader_close(&reader);
+    return result;
+}
+
+FP_IMPORT("__fp_gen_import_void_function_empty_return")
+extern void __fp_gen_import_void_function_empty_return(void);
+
+void import_void_function_empty_return(void) {
+    __fp_gen_import_void_function_empty_return();
+}
+
+FP_IMPORT("__fp_gen_log")
+extern void __fp_gen_log(FatPtr message);
+
+void log_(const FpString *message) {
+    FpWriter message_writer = {0};
+    fp_encode_String(&message_writer, message);
+    __fp_gen_log(fp_writer_finish(&message_writer));
+}
+
+FP_IMPORT("__fp_gen_make_http_request")
+extern FatPtr __fp_gen_make_http_request(FatPtr request);
+
+static void make_http_request_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {
+    HttpResult result;
+    FpReader reader = fp_reader_open(result_ptr);
+    if (!fp_decode_Result_Response_RequestError(&reader, &result)) {
+        fp_panic("Could not decode result of `make_http_request()`");
+    }
+    fp_reader_close(&reader);
+    ((make_http_request_callback)callback)(result, user_data);
+}
+
+void make_http_request(const Request *request, make_http_request_callback callback, void *user_data) {
+    FpWriter request_writer = {0};
+    fp_encode_Request(&request_writer, request);
+    FatPtr async_value_ptr = __fp_gen_make_http_request(fp_writer_finish(&request_writer));
+    fp_await_async_value(async_value_ptr, make_http_request_ready, (void (*)(void))callback, user_data);
+}
+
+FP_EXPORT("__fp_gen_export_array_f32")
+FatPtr __fp_gen_export_array_f32(FatPtr arg_ptr) {
+    FpArray_f32_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_f32_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_f32()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_f32_3 result = export_array_f32(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_f32_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_array_f64")
+FatPtr __fp_gen_export_array_f64(FatPtr arg_ptr) {
+    FpArray_f64_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_f64_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_f64()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_f64_3 result = export_array_f64(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_f64_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_array_i16")
+FatPtr __fp_gen_export_array_i16(FatPtr arg_ptr) {
+    FpArray_i16_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_i16_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_i16()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_i16_3 result = export_array_i16(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_i16_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_array_i32")
+FatPtr __fp_gen_export_array_i32(FatPtr arg_ptr) {
+    FpArray_i32_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_i32_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_i32()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_i32_3 result = export_array_i32(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_i32_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_array_i8")
+FatPtr __fp_gen_export_array_i8(FatPtr arg_ptr) {
+    FpArray_i8_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_i8_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_i8()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_i8_3 result = export_array_i8(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_i8_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_array_u16")
+FatPtr __fp_gen_export_array_u16(FatPtr arg_ptr) {
+    FpArray_u16_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_u16_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_u16()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_u16_3 result = export_array_u16(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_u16_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_array_u32")
+FatPtr __fp_gen_export_array_u32(FatPtr arg_ptr) {
+    FpArray_u32_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_u32_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_u32()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_u32_3 result = export_array_u32(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_u32_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_array_u8")
+FatPtr __fp_gen_export_array_u8(FatPtr arg_ptr) {
+    FpArray_u8_3 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpArray_u8_3(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_array_u8()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpArray_u8_3 result = export_array_u8(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpArray_u8_3(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_async_struct")
+FatPtr __fp_gen_export_async_struct(FatPtr arg_1_ptr, uint64_t arg_2) {
+    FpPropertyRenaming arg_1;
+    FpReader arg_1_reader = fp_reader_open(arg_1_ptr);
+    if (!fp_decode_FpPropertyRenaming(&arg_1_reader, &arg_1)) {
+        fp_panic("Could not decode argument `arg1` of `export_async_struct()`");
+    }
+    fp_reader_close(&arg_1_reader);
+    FpAsyncValue async_value = fp_async_value_new();
+    export_async_struct(&arg_1, arg_2, async_value);
+    fp_free_FpPropertyRenaming(&arg_1);
+    return async_value;
+}
+
+void export_async_struct_resolve(FpAsyncValue async_value, FpPropertyRenaming result) {
+    FpWriter writer = {0};
+    fp_encode_FpPropertyRenaming(&writer, &result);
+    fp_free_FpPropertyRenaming(&result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_fp_adjacently_tagged")
+FatPtr __fp_gen_export_fp_adjacently_tagged(FatPtr arg_ptr) {
+    FpAdjacentlyTagged arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpAdjacentlyTagged(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_fp_adjacently_tagged()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpAdjacentlyTagged result = export_fp_adjacently_tagged(&arg);
+    fp_free_FpAdjacentlyTagged(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpAdjacentlyTagged(&writer, &result);
+    fp_free_FpAdjacentlyTagged(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_fp_enum")
+FatPtr __fp_gen_export_fp_enum(FatPtr arg_ptr) {
+    FpVariantRenaming arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpVariantRenaming(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_fp_enum()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpVariantRenaming result = export_fp_enum(&arg);
+    fp_free_FpVariantRenaming(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpVariantRenaming(&writer, &result);
+    fp_free_FpVariantRenaming(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_fp_flatten")
+FatPtr __fp_gen_export_fp_flatten(FatPtr arg_ptr) {
+    FpFlatten arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpFlatten(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_fp_flatten()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpFlatten result = export_fp_flatten(&arg);
+    fp_free_FpFlatten(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpFlatten(&writer, &result);
+    fp_free_FpFlatten(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_fp_internally_tagged")
+FatPtr __fp_gen_export_fp_internally_tagged(FatPtr arg_ptr) {
+    FpInternallyTagged arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpInternallyTagged(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_fp_internally_tagged()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpInternallyTagged result = export_fp_internally_tagged(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpInternallyTagged(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_fp_struct")
+FatPtr __fp_gen_export_fp_struct(FatPtr arg_ptr) {
+    FpPropertyRenaming arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpPropertyRenaming(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_fp_struct()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpPropertyRenaming result = export_fp_struct(&arg);
+    fp_free_FpPropertyRenaming(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpPropertyRenaming(&writer, &result);
+    fp_free_FpPropertyRenaming(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_fp_untagged")
+FatPtr __fp_gen_export_fp_untagged(FatPtr arg_ptr) {
+    FpUntagged arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_FpUntagged(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_fp_untagged()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpUntagged result = export_fp_untagged(&arg);
+    fp_free_FpUntagged(&arg);
+    FpWriter writer = {0};
+    fp_encode_FpUntagged(&writer, &result);
+    fp_free_FpUntagged(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_generics")
+FatPtr __fp_gen_export_generics(FatPtr arg_ptr) {
+    StructWithGenerics_u64 arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_StructWithGenerics_u64(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_generics()`");
+    }
+    fp_reader_close(&arg_reader);
+    StructWithGenerics_u64 result = export_generics(&arg);
+    fp_free_StructWithGenerics_u64(&arg);
+    FpWriter writer = {0};
+    fp_encode_StructWithGenerics_u64(&writer, &result);
+    fp_free_StructWithGenerics_u64(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_get_bytes")
+FatPtr __fp_gen_export_get_bytes(void) {
+    Result_Bytes_String result = export_get_bytes();
+    FpWriter writer = {0};
+    fp_encode_Result_Bytes_String(&writer, &result);
+    fp_free_Result_Bytes_String(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_get_serde_bytes")
+FatPtr __fp_gen_export_get_serde_bytes(void) {
+    Result_Bytes_String result = export_get_serde_bytes();
+    FpWriter writer = {0};
+    fp_encode_Result_Bytes_String(&writer, &result);
+    fp_free_Result_Bytes_String(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_increment_global_state")
+FatPtr __fp_gen_export_increment_global_state(void) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_increment_global_state(async_value);
+    return async_value;
+}
+
+void export_increment_global_state_resolve(FpAsyncValue async_value) {
+    FpWriter writer = {0};
+    fp_write_nil(&writer);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_multiple_primitives")
+int64_t __fp_gen_export_multiple_primitives(int8_t arg_1, FatPtr arg_2_ptr) {
+    FpString arg_2;
+    FpReader arg_2_reader = fp_reader_open(arg_2_ptr);
+    if (!fp_decode_String(&arg_2_reader, &arg_2)) {
+        fp_panic("Could not decode argument `arg2` of `export_multiple_primitives()`");
+    }
+    fp_reader_close(&arg_2_reader);
+    int64_t result = export_multiple_primitives(arg_1, &arg_2);
+    fp_free_String(&arg_2);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_panic")
+void __fp_gen_export_panic(FatPtr message_ptr) {
+    FpString message;
+    FpReader message_reader = fp_reader_open(message_ptr);
+    if (!fp_decode_String(&message_reader, &message)) {
+        fp_panic("Could not decode argument `message` of `export_panic()`");
+    }
+    fp_reader_close(&message_reader);
+    export_panic(&message);
+    fp_free_String(&message);
+}
+
+FP_EXPORT("__fp_gen_export_primitive_bool_negate")
+bool __fp_gen_export_primitive_bool_negate(bool arg) {
+    bool result = export_primitive_bool_negate(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_bool_negate_async")
+FatPtr __fp_gen_export_primitive_bool_negate_async(bool arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_bool_negate_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_bool_negate_async_resolve(FpAsyncValue async_value, bool result) {
+    FpWriter writer = {0};
+    fp_encode_bool(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_f32_add_three")
+float __fp_gen_export_primitive_f32_add_three(float arg) {
+    float result = export_primitive_f32_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_f32_add_three_async")
+FatPtr __fp_gen_export_primitive_f32_add_three_async(float arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_f32_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_f32_add_three_async_resolve(FpAsyncValue async_value, float result) {
+    FpWriter writer = {0};
+    fp_encode_f32(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_f32_add_three_wasmer2")
+float __fp_gen_export_primitive_f32_add_three_wasmer2(float arg) {
+    float result = export_primitive_f32_add_three_wasmer2(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_f64_add_three")
+double __fp_gen_export_primitive_f64_add_three(double arg) {
+    double result = export_primitive_f64_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_f64_add_three_async")
+FatPtr __fp_gen_export_primitive_f64_add_three_async(double arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_f64_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_f64_add_three_async_resolve(FpAsyncValue async_value, double result) {
+    FpWriter writer = {0};
+    fp_encode_f64(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_f64_add_three_wasmer2")
+double __fp_gen_export_primitive_f64_add_three_wasmer2(double arg) {
+    double result = export_primitive_f64_add_three_wasmer2(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i16_add_three")
+int16_t __fp_gen_export_primitive_i16_add_three(int16_t arg) {
+    int16_t result = export_primitive_i16_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i16_add_three_async")
+FatPtr __fp_gen_export_primitive_i16_add_three_async(int16_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_i16_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_i16_add_three_async_resolve(FpAsyncValue async_value, int16_t result) {
+    FpWriter writer = {0};
+    fp_encode_i16(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i32_add_three")
+int32_t __fp_gen_export_primitive_i32_add_three(int32_t arg) {
+    int32_t result = export_primitive_i32_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i32_add_three_async")
+FatPtr __fp_gen_export_primitive_i32_add_three_async(int32_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_i32_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_i32_add_three_async_resolve(FpAsyncValue async_value, int32_t result) {
+    FpWriter writer = {0};
+    fp_encode_i32(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i64_add_three")
+int64_t __fp_gen_export_primitive_i64_add_three(int64_t arg) {
+    int64_t result = export_primitive_i64_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i64_add_three_async")
+FatPtr __fp_gen_export_primitive_i64_add_three_async(int64_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_i64_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_i64_add_three_async_resolve(FpAsyncValue async_value, int64_t result) {
+    FpWriter writer = {0};
+    fp_encode_i64(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i8_add_three")
+int8_t __fp_gen_export_primitive_i8_add_three(int8_t arg) {
+    int8_t result = export_primitive_i8_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_i8_add_three_async")
+FatPtr __fp_gen_export_primitive_i8_add_three_async(int8_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_i8_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_i8_add_three_async_resolve(FpAsyncValue async_value, int8_t result) {
+    FpWriter writer = {0};
+    fp_encode_i8(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u16_add_three")
+uint16_t __fp_gen_export_primitive_u16_add_three(uint16_t arg) {
+    uint16_t result = export_primitive_u16_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u16_add_three_async")
+FatPtr __fp_gen_export_primitive_u16_add_three_async(uint16_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_u16_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_u16_add_three_async_resolve(FpAsyncValue async_value, uint16_t result) {
+    FpWriter writer = {0};
+    fp_encode_u16(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u32_add_three")
+uint32_t __fp_gen_export_primitive_u32_add_three(uint32_t arg) {
+    uint32_t result = export_primitive_u32_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u32_add_three_async")
+FatPtr __fp_gen_export_primitive_u32_add_three_async(uint32_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_u32_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_u32_add_three_async_resolve(FpAsyncValue async_value, uint32_t result) {
+    FpWriter writer = {0};
+    fp_encode_u32(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u64_add_three")
+uint64_t __fp_gen_export_primitive_u64_add_three(uint64_t arg) {
+    uint64_t result = export_primitive_u64_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u64_add_three_async")
+FatPtr __fp_gen_export_primitive_u64_add_three_async(uint64_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_u64_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_u64_add_three_async_resolve(FpAsyncValue async_value, uint64_t result) {
+    FpWriter writer = {0};
+    fp_encode_u64(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u8_add_three")
+uint8_t __fp_gen_export_primitive_u8_add_three(uint8_t arg) {
+    uint8_t result = export_primitive_u8_add_three(arg);
+    return result;
+}
+
+FP_EXPORT("__fp_gen_export_primitive_u8_add_three_async")
+FatPtr __fp_gen_export_primitive_u8_add_three_async(uint8_t arg) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_primitive_u8_add_three_async(arg, async_value);
+    return async_value;
+}
+
+void export_primitive_u8_add_three_async_resolve(FpAsyncValue async_value, uint8_t result) {
+    FpWriter writer = {0};
+    fp_encode_u8(&writer, &result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_reset_global_state")
+FatPtr __fp_gen_export_reset_global_state(void) {
+    FpAsyncValue async_value = fp_async_value_new();
+    export_reset_global_state(async_value);
+    return async_value;
+}
+
+void export_reset_global_state_resolve(FpAsyncValue async_value) {
+    FpWriter writer = {0};
+    fp_write_nil(&writer);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_export_serde_adjacently_tagged")
+FatPtr __fp_gen_export_serde_adjacently_tagged(FatPtr arg_ptr) {
+    SerdeAdjacentlyTagged arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_SerdeAdjacentlyTagged(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_serde_adjacently_tagged()`");
+    }
+    fp_reader_close(&arg_reader);
+    SerdeAdjacentlyTagged result = export_serde_adjacently_tagged(&arg);
+    fp_free_SerdeAdjacentlyTagged(&arg);
+    FpWriter writer = {0};
+    fp_encode_SerdeAdjacentlyTagged(&writer, &result);
+    fp_free_SerdeAdjacentlyTagged(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_serde_enum")
+FatPtr __fp_gen_export_serde_enum(FatPtr arg_ptr) {
+    SerdeVariantRenaming arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_SerdeVariantRenaming(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_serde_enum()`");
+    }
+    fp_reader_close(&arg_reader);
+    SerdeVariantRenaming result = export_serde_enum(&arg);
+    fp_free_SerdeVariantRenaming(&arg);
+    FpWriter writer = {0};
+    fp_encode_SerdeVariantRenaming(&writer, &result);
+    fp_free_SerdeVariantRenaming(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_serde_flatten")
+FatPtr __fp_gen_export_serde_flatten(FatPtr arg_ptr) {
+    SerdeFlatten arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_SerdeFlatten(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_serde_flatten()`");
+    }
+    fp_reader_close(&arg_reader);
+    SerdeFlatten result = export_serde_flatten(&arg);
+    fp_free_SerdeFlatten(&arg);
+    FpWriter writer = {0};
+    fp_encode_SerdeFlatten(&writer, &result);
+    fp_free_SerdeFlatten(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_serde_internally_tagged")
+FatPtr __fp_gen_export_serde_internally_tagged(FatPtr arg_ptr) {
+    SerdeInternallyTagged arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_SerdeInternallyTagged(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_serde_internally_tagged()`");
+    }
+    fp_reader_close(&arg_reader);
+    SerdeInternallyTagged result = export_serde_internally_tagged(&arg);
+    FpWriter writer = {0};
+    fp_encode_SerdeInternallyTagged(&writer, &result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_serde_struct")
+FatPtr __fp_gen_export_serde_struct(FatPtr arg_ptr) {
+    SerdePropertyRenaming arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_SerdePropertyRenaming(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_serde_struct()`");
+    }
+    fp_reader_close(&arg_reader);
+    SerdePropertyRenaming result = export_serde_struct(&arg);
+    fp_free_SerdePropertyRenaming(&arg);
+    FpWriter writer = {0};
+    fp_encode_SerdePropertyRenaming(&writer, &result);
+    fp_free_SerdePropertyRenaming(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_serde_untagged")
+FatPtr __fp_gen_export_serde_untagged(FatPtr arg_ptr) {
+    SerdeUntagged arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_SerdeUntagged(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_serde_untagged()`");
+    }
+    fp_reader_close(&arg_reader);
+    SerdeUntagged result = export_serde_untagged(&arg);
+    fp_free_SerdeUntagged(&arg);
+    FpWriter writer = {0};
+    fp_encode_SerdeUntagged(&writer, &result);
+    fp_free_SerdeUntagged(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_string")
+FatPtr __fp_gen_export_string(FatPtr arg_ptr) {
+    FpString arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_String(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_string()`");
+    }
+    fp_reader_close(&arg_reader);
+    FpString result = export_string(&arg);
+    fp_free_String(&arg);
+    FpWriter writer = {0};
+    fp_encode_String(&writer, &result);
+    fp_free_String(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_struct_with_options")
+FatPtr __fp_gen_export_struct_with_options(FatPtr arg_ptr) {
+    StructWithOptions arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_StructWithOptions(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_struct_with_options()`");
+    }
+    fp_reader_close(&arg_reader);
+    StructWithOptions result = export_struct_with_options(&arg);
+    fp_free_StructWithOptions(&arg);
+    FpWriter writer = {0};
+    fp_encode_StructWithOptions(&writer, &result);
+    fp_free_StructWithOptions(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_timestamp")
+FatPtr __fp_gen_export_timestamp(FatPtr arg_ptr) {
+    MyDateTime arg;
+    FpReader arg_reader = fp_reader_open(arg_ptr);
+    if (!fp_decode_String(&arg_reader, &arg)) {
+        fp_panic("Could not decode argument `arg` of `export_timestamp()`");
+    }
+    fp_reader_close(&arg_reader);
+    MyDateTime result = export_timestamp(&arg);
+    fp_free_String(&arg);
+    FpWriter writer = {0};
+    fp_encode_String(&writer, &result);
+    fp_free_String(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_gen_export_void_function")
+void __fp_gen_export_void_function(void) {
+    export_void_function();
+}
+
+FP_EXPORT("__fp_gen_fetch_data")
+FatPtr __fp_gen_fetch_data(FatPtr type_ptr) {
+    FpString type;
+    FpReader type_reader = fp_reader_open(type_ptr);
+    if (!fp_decode_String(&type_reader, &type)) {
+        fp_panic("Could not decode argument `r#type` of `fetch_data()`");
+    }
+    fp_reader_close(&type_reader);
+    FpAsyncValue async_value = fp_async_value_new();
+    fetch_data(&type, async_value);
+    fp_free_String(&type);
+    return async_value;
+}
+
+void fetch_data_resolve(FpAsyncValue async_value, Result_String_String result) {
+    FpWriter writer = {0};
+    fp_encode_Result_String_String(&writer, &result);
+    fp_free_Result_String_String(&result);
+    __fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));
+}
+
+FP_EXPORT("__fp_gen_init")
+void __fp_gen_init(void) {
+    init();
+}
+
+FP_EXPORT("__fp_gen_reducer_bridge")
+FatPtr __fp_gen_reducer_bridge(FatPtr action_ptr) {
+    ReduxAction action;
+    FpReader action_reader = fp_reader_open(action_ptr);
+    if (!fp_decode_ReduxAction(&action_reader, &action)) {
+        fp_panic("Could not decode argument `action` of `reducer_bridge()`");
+    }
+    fp_reader_close(&action_reader);
+    StateUpdate result = reducer_bridge(&action);
+    fp_free_ReduxAction(&action);
+    FpWriter writer = {0};
+    fp_encode_StateUpdate(&writer, &result);
+    fp_free_StateUpdate(&result);
+    return fp_writer_finish(&writer);
+}
+
+FP_EXPORT("__fp_protocol_version")
+uint64_t __fp_protocol_version(void) {
+    return 0x1e72896d627cc76eull;
+}
@@ -0,0 +1,378 @@
+// ============================================= //
+// Bindings for WebAssembly plugins in C         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#ifndef FP_BINDINGS_H
+#define FP_BINDINGS_H
+
+#include "types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Functions imported from the host.
+//
+// Arguments are borrowed, while results are owned by the caller. Async imports
+// invoke their callback once the result is available, passing ownership of the
+// result to the callback.
+
+FpArray_f32_3 import_array_f32(const FpArray_f32_3 *arg);
+
+FpArray_f64_3 import_array_f64(const FpArray_f64_3 *arg);
+
+FpArray_i16_3 import_array_i16(const FpArray_i16_3 *arg);
+
+FpArray_i32_3 import_array_i32(const FpArray_i32_3 *arg);
+
+FpArray_i8_3 import_array_i8(const FpArray_i8_3 *arg);
+
+FpArray_u16_3 import_array_u16(const FpArray_u16_3 *arg);
+
+FpArray_u32_3 import_array_u32(const FpArray_u32_3 *arg);
+
+FpArray_u8_3 import_array_u8(const FpArray_u8_3 *arg);
+
+void import_explicit_bound_point(const ExplicitBoundPoint_u64 *arg);
+
+FpAdjacentlyTagged import_fp_adjacently_tagged(const FpAdjacentlyTagged *arg);
+
+FpVariantRenaming import_fp_enum(const FpVariantRenaming *arg);
+
+FpFlatten import_fp_flatten(const FpFlatten *arg);
+
+FpInternallyTagged import_fp_internally_tagged(const FpInternallyTagged *arg);
+
+FpPropertyRenaming import_fp_struct(const FpPropertyRenaming *arg);
+
+FpUntagged import_fp_untagged(const FpUntagged *arg);
+
+StructWithGenerics_u64 import_generics(const StructWithGenerics_u64 *arg);
+
+Result_Bytes_String import_get_bytes(void);
+
+Result_Bytes_String import_get_serde_bytes(void);
+
+typedef void (*import_increment_global_state_callback)(void *user_data);
+void import_increment_global_state(import_increment_global_state_callback callback, void *user_data);
+
+int64_t import_multiple_primitives(int8_t arg_1, const FpString *arg_2);
+
+bool import_primitive_bool_negate(bool arg);
+
+typedef void (*import_primitive_bool_negate_async_callback)(bool result, void *user_data);
+void import_primitive_bool_negate_async(bool arg, import_primitive_bool_negate_async_callback callback, void *user_data);
+
+float import_primitive_f32_add_one(float arg);
+
+typedef void (*import_primitive_f32_add_one_async_callback)(float result, void *user_data);
+void import_primitive_f32_add_one_async(float arg, import_primitive_f32_add_one_async_callback callback, void *user_data);
+
+float import_primitive_f32_add_one_wasmer2(const FpArray_f32_1 *arg);
+
+double import_primitive_f64_add_one(double arg);
+
+typedef void (*import_primitive_f64_add_one_async_callback)(double result, void *user_data);
+void import_primitive_f64_add_one_async(double arg, import_primitive_f64_add_one_async_callback callback, void *user_data);
+
+double import_primitive_f64_add_one_wasmer2(const FpArray_f64_1 *arg);
+
+int16_t import_primitive_i16_add_one(int16_t arg);
+
+typedef void (*import_primitive_i16_add_one_async_callback)(int16_t result, void *user_data);
+void import_primitive_i16_add_one_async(int16_t arg, import_primitive_i16_add_one_async_callback callback, void *user_data);
+
+int32_t import_primitive_i32_add_one(int32_t arg);
+
+typedef void (*import_primitive_i32_add_one_async_callback)(int32_t result, void *user_data);
+void import_primitive_i32_add_one_async(int32_t arg, import_primitive_i32_add_one_async_callback callback, void *user_data);
+
+int64_t import_primitive_i64_add_one(int64_t arg);
+
+typedef void (*import_primitive_i64_add_one_async_callback)(int64_t result, void *user_data);
+void import_primitive_i64_add_one_async(int64_t arg, import_primitive_i64_add_one_async_callback callback, void *user_data);
+
+int8_t import_primitive_i8_add_one(int8_t arg);
+
+typedef void (*import_primitive_i8_add_one_async_callback)(int8_t result, void *user_data);
+void import_primitive_i8_add_one_async(int8_t arg, import_primitive_i8_add_one_async_callback callback, void *user_data);
+
+uint16_t import_primitive_u16_add_one(uint16_t arg);
+
+typedef void (*import_primitive_u16_add_one_async_callback)(uint16_t result, void *user_data);
+void import_primitive_u16_add_one_async(uint16_t arg, import_primitive_u16_add_one_async_callback callback, void *user_data);
+
+uint32_t import_primitive_u32_add_one(uint32_t arg);
+
+typedef void (*import_primitive_u32_add_one_async_callback)(uint32_t result, void *user_data);
+void import_primitive_u32_add_one_async(uint32_t arg, import_primitive_u32_add_one_async_callback callback, void *user_data);
+
+uint64_t import_primitive_u64_add_one(uint64_t arg);
+
+typedef void (*import_primitive_u64_add_one_async_callback)(uint64_t result, void *user_data);
+void import_primitive_u64_add_one_async(uint64_t arg, import_primitive_u64_add_one_async_callback callback, void *user_data);
+
+uint8_t import_primitive_u8_add_one(uint8_t arg);
+
+typedef void (*import_primitive_u8_add_one_async_callback)(uint8_t result, void *user_data);
+void import_primitive_u8_add_one_async(uint8_t arg, import_primitive_u8_add_one_async_callback callback, void *user_data);
+
+typedef void (*import_reset_global_state_callback)(void *user_data);
+void import_reset_global_state(import_reset_global_state_callback callback, void *user_data);
+
+SerdeAdjacentlyTagged import_serde_adjacently_tagged(const SerdeAdjacentlyTagged *arg);
+
+SerdeVariantRenaming import_serde_enum(const SerdeVariantRenaming *arg);
+
+SerdeFlatten import_serde_flatten(const SerdeFlatten *arg);
+
+SerdeInternallyTagged import_serde_internally_tagged(const SerdeInternallyTagged *arg);
+
+SerdePropertyRenaming import_serde_struct(const SerdePropertyRenaming *arg);
+
+SerdeUntagged import_serde_untagged(const SerdeUntagged *arg);
+
+FpString import_string(const FpString *arg);
+
+StructWithOptions import_struct_with_options(const StructWithOptions *arg);
+
+MyDateTime import_timestamp(const MyDateTime *arg);
+
+void import_void_function(void);
+
+Result_Unit_u32 import_void_function_empty_result(void);
+
+void import_void_function_empty_return(void);
+
+/**
+ * Logs a message to the (development) console.
+ */
+void log_(const FpString *message);
+
+/**
+ * Example how a runtime could expose a `Fetch`-like function to plugins.
+ *
+ * See `types/http.rs` for more info.
+ */
+typedef void (*make_http_request_callback)(HttpResult result, void *user_data);
+void make_http_request(const Request *request, make_http_request_callback callback, void *user_data);
+
+// Functions exported to the host, which should be implemented by the plugin.
+//
+// Arguments are borrowed, while results are owned by the caller. Async exports
+// receive an async value, which should be resolved through the corresponding
+// `_resolve()` function.
+
+FpArray_f32_3 export_array_f32(const FpArray_f32_3 *arg);
+
+FpArray_f64_3 export_array_f64(const FpArray_f64_3 *arg);
+
+FpArray_i16_3 export_array_i16(const FpArray_i16_3 *arg);
+
+FpArray_i32_3 export_array_i32(const FpArray_i32_3 *arg);
+
+FpArray_i8_3 export_array_i8(const FpArray_i8_3 *arg);
+
+FpArray_u16_3 export_array_u16(const FpArray_u16_3 *arg);
+
+FpArray_u32_3 export_array_u32(const FpArray_u32_3 *arg);
+
+FpArray_u8_3 export_array_u8(const FpArray_u8_3 *arg);
+
+void export_async_struct(const FpPropertyRenaming *arg_1, uint64_t arg_2, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_async_struct()`. Takes ownership of the result.
+ */
+void export_async_struct_resolve(FpAsyncValue async_value, FpPropertyRenaming result);
+
+FpAdjacentlyTagged export_fp_adjacently_tagged(const FpAdjacentlyTagged *arg);
+
+FpVariantRenaming export_fp_enum(const FpVariantRenaming *arg);
+
+FpFlatten export_fp_flatten(const FpFlatten *arg);
+
+FpInternallyTagged export_fp_internally_tagged(const FpInternallyTagged *arg);
+
+FpPropertyRenaming export_fp_struct(const FpPropertyRenaming *arg);
+
+FpUntagged export_fp_untagged(const FpUntagged *arg);
+
+StructWithGenerics_u64 export_generics(const StructWithGenerics_u64 *arg);
+
+Result_Bytes_String export_get_bytes(void);
+
+Result_Bytes_String export_get_serde_bytes(void);
+
+void export_increment_global_state(FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_increment_global_state()`. Takes ownership of the result.
+ */
+void export_increment_global_state_resolve(FpAsyncValue async_value);
+
+int64_t export_multiple_primitives(int8_t arg_1, const FpString *arg_2);
+
+/**
+ * Panics with the given message, so the runtimes can test guest panics
+ * are reported to them.
+ */
+void export_panic(const FpString *message);
+
+bool export_primitive_bool_negate(bool arg);
+
+void export_primitive_bool_negate_async(bool arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_bool_negate_async()`. Takes ownership of the result.
+ */
+void export_primitive_bool_negate_async_resolve(FpAsyncValue async_value, bool result);
+
+float export_primitive_f32_add_three(float arg);
+
+void export_primitive_f32_add_three_async(float arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_f32_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_f32_add_three_async_resolve(FpAsyncValue async_value, float result);
+
+float export_primitive_f32_add_three_wasmer2(float arg);
+
+double export_primitive_f64_add_three(double arg);
+
+void export_primitive_f64_add_three_async(double arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_f64_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_f64_add_three_async_resolve(FpAsyncValue async_value, double result);
+
+double export_primitive_f64_add_three_wasmer2(double arg);
+
+int16_t export_primitive_i16_add_three(int16_t arg);
+
+void export_primitive_i16_add_three_async(int16_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_i16_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_i16_add_three_async_resolve(FpAsyncValue async_value, int16_t result);
+
+int32_t export_primitive_i32_add_three(int32_t arg);
+
+void export_primitive_i32_add_three_async(int32_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_i32_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_i32_add_three_async_resolve(FpAsyncValue async_value, int32_t result);
+
+int64_t export_primitive_i64_add_three(int64_t arg);
+
+void export_primitive_i64_add_three_async(int64_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_i64_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_i64_add_three_async_resolve(FpAsyncValue async_value, int64_t result);
+
+int8_t export_primitive_i8_add_three(int8_t arg);
+
+void export_primitive_i8_add_three_async(int8_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_i8_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_i8_add_three_async_resolve(FpAsyncValue async_value, int8_t result);
+
+uint16_t export_primitive_u16_add_three(uint16_t arg);
+
+void export_primitive_u16_add_three_async(uint16_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_u16_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_u16_add_three_async_resolve(FpAsyncValue async_value, uint16_t result);
+
+uint32_t export_primitive_u32_add_three(uint32_t arg);
+
+void export_primitive_u32_add_three_async(uint32_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_u32_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_u32_add_three_async_resolve(FpAsyncValue async_value, uint32_t result);
+
+uint64_t export_primitive_u64_add_three(uint64_t arg);
+
+void export_primitive_u64_add_three_async(uint64_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_u64_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_u64_add_three_async_resolve(FpAsyncValue async_value, uint64_t result);
+
+uint8_t export_primitive_u8_add_three(uint8_t arg);
+
+void export_primitive_u8_add_three_async(uint8_t arg, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_primitive_u8_add_three_async()`. Takes ownership of the result.
+ */
+void export_primitive_u8_add_three_async_resolve(FpAsyncValue async_value, uint8_t result);
+
+void export_reset_global_state(FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `export_reset_global_state()`. Takes ownership of the result.
+ */
+void export_reset_global_state_resolve(FpAsyncValue async_value);
+
+SerdeAdjacentlyTagged export_serde_adjacently_tagged(const SerdeAdjacentlyTagged *arg);
+
+SerdeVariantRenaming export_serde_enum(const SerdeVariantRenaming *arg);
+
+SerdeFlatten export_serde_flatten(const SerdeFlatten *arg);
+
+SerdeInternallyTagged export_serde_internally_tagged(const SerdeInternallyTagged *arg);
+
+SerdePropertyRenaming export_serde_struct(const SerdePropertyRenaming *arg);
+
+SerdeUntagged export_serde_untagged(const SerdeUntagged *arg);
+
+FpString export_string(const FpString *arg);
+
+StructWithOptions export_struct_with_options(const StructWithOptions *arg);
+
+MyDateTime export_timestamp(const MyDateTime *arg);
+
+void export_void_function(void);
+
+/**
+ * Example how plugin could expose async data-fetching capabilities.
+ */
+void fetch_data(const FpString *type, FpAsyncValue async_value);
+
+/**
+ * Resolves the result of `fetch_data()`. Takes ownership of the result.
+ */
+void fetch_data_resolve(FpAsyncValue async_value, Result_String_String result);
+
+/**
+ * Called on the plugin to give it a chance to initialize.
+ */
+void init(void);
+
+/**
+ * Example how plugin could expose a reducer.
+ */
+StateUpdate reducer_bridge(const ReduxAction *action);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
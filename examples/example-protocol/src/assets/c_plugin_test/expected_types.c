@@ -0,0 +1,2471 @@
+// ============================================= //
+// Types for WebAssembly plugins in C            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#include "types.h"
+
+#include <stdlib.h>
+#include <string.h>
+
+static uint32_t fp_count_fields_DocExampleEnumVariant2(const DocExampleEnumVariant2 *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_DocExampleEnumVariant2(FpWriter *writer, const DocExampleEnumVariant2 *value) {
+    fp_write_cstr(writer, "inner");
+    fp_encode_i8(writer, &value->inner);
+}
+
+static int fp_decode_field_DocExampleEnumVariant2(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    DocExampleEnumVariant2 *value = object;
+    if (fp_key_eq(key, key_len, "inner")) {
+        return fp_decode_i8(reader, &value->inner) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_DocExampleEnumVariant2[] = {"inner"};
+
+void fp_encode_DocExampleEnumVariant2(FpWriter *writer, const DocExampleEnumVariant2 *value) {
+    fp_write_map(writer, fp_count_fields_DocExampleEnumVariant2(value));
+    fp_encode_fields_DocExampleEnumVariant2(writer, value);
+}
+
+bool fp_decode_DocExampleEnumVariant2(FpReader *reader, DocExampleEnumVariant2 *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_DocExampleEnumVariant2, fp_fields_DocExampleEnumVariant2, 1);
+}
+
+void fp_encode_DocExampleEnum(FpWriter *writer, const DocExampleEnum *value) {
+    switch (value->tag) {
+    case DocExampleEnum_Variant1:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Variant1");
+        fp_encode_String(writer, &value->variant_1);
+        break;
+    case DocExampleEnum_Variant2:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Variant2");
+        fp_encode_DocExampleEnumVariant2(writer, &value->variant_2);
+        break;
+    }
+}
+
+bool fp_decode_DocExampleEnum(FpReader *reader, DocExampleEnum *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_variant(reader, &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Variant1")) {
+        value->tag = DocExampleEnum_Variant1;
+        return fp_decode_String(&content, &value->variant_1);
+    }
+    if (fp_key_eq(name, name_len, "Variant2")) {
+        value->tag = DocExampleEnum_Variant2;
+        return fp_decode_DocExampleEnumVariant2(&content, &value->variant_2);
+    }
+    return false;
+}
+
+void fp_free_DocExampleEnum(DocExampleEnum *value) {
+    switch (value->tag) {
+    case DocExampleEnum_Variant1:
+        fp_free_String(&value->variant_1);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_DocExampleStruct(const DocExampleStruct *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_DocExampleStruct(FpWriter *writer, const DocExampleStruct *value) {
+    fp_write_cstr(writer, "multi_line");
+    fp_encode_String(writer, &value->multi_line);
+    fp_write_cstr(writer, "type");
+    fp_encode_String(writer, &value->type);
+}
+
+static int fp_decode_field_DocExampleStruct(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    DocExampleStruct *value = object;
+    if (fp_key_eq(key, key_len, "multi_line")) {
+        return fp_decode_String(reader, &value->multi_line) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "type")) {
+        return fp_decode_String(reader, &value->type) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_DocExampleStruct[] = {"multi_line", "type"};
+
+void fp_encode_DocExampleStruct(FpWriter *writer, const DocExampleStruct *value) {
+    fp_write_map(writer, fp_count_fields_DocExampleStruct(value));
+    fp_encode_fields_DocExampleStruct(writer, value);
+}
+
+bool fp_decode_DocExampleStruct(FpReader *reader, DocExampleStruct *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_DocExampleStruct, fp_fields_DocExampleStruct, 2)) {
+        fp_free_DocExampleStruct(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_DocExampleStruct(DocExampleStruct *value) {
+    fp_free_String(&value->multi_line);
+    fp_free_String(&value->type);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_ExplicitedlyImportedType(const ExplicitedlyImportedType *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_ExplicitedlyImportedType(FpWriter *writer, const ExplicitedlyImportedType *value) {
+    fp_write_cstr(writer, "you_will_see_this");
+    fp_encode_bool(writer, &value->you_will_see_this);
+}
+
+static int fp_decode_field_ExplicitedlyImportedType(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    ExplicitedlyImportedType *value = object;
+    if (fp_key_eq(key, key_len, "you_will_see_this")) {
+        return fp_decode_bool(reader, &value->you_will_see_this) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_ExplicitedlyImportedType[] = {"you_will_see_this"};
+
+void fp_encode_ExplicitedlyImportedType(FpWriter *writer, const ExplicitedlyImportedType *value) {
+    fp_write_map(writer, fp_count_fields_ExplicitedlyImportedType(value));
+    fp_encode_fields_ExplicitedlyImportedType(writer, value);
+}
+
+bool fp_decode_ExplicitedlyImportedType(FpReader *reader, ExplicitedlyImportedType *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_ExplicitedlyImportedType, fp_fields_ExplicitedlyImportedType, 1);
+}
+
+static uint32_t fp_count_fields_FlattenedStruct(const FlattenedStruct *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_FlattenedStruct(FpWriter *writer, const FlattenedStruct *value) {
+    fp_write_cstr(writer, "foo");
+    fp_encode_String(writer, &value->foo);
+    fp_write_cstr(writer, "bar");
+    fp_encode_i64(writer, &value->bar);
+}
+
+static int fp_decode_field_FlattenedStruct(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    FlattenedStruct *value = object;
+    if (fp_key_eq(key, key_len, "foo")) {
+        return fp_decode_String(reader, &value->foo) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "bar")) {
+        return fp_decode_i64(reader, &value->bar) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_FlattenedStruct[] = {"foo", "bar"};
+
+void fp_encode_FlattenedStruct(FpWriter *writer, const FlattenedStruct *value) {
+    fp_write_map(writer, fp_count_fields_FlattenedStruct(value));
+    fp_encode_fields_FlattenedStruct(writer, value);
+}
+
+bool fp_decode_FlattenedStruct(FpReader *reader, FlattenedStruct *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_FlattenedStruct, fp_fields_FlattenedStruct, 2)) {
+        fp_free_FlattenedStruct(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_FlattenedStruct(FlattenedStruct *value) {
+    fp_free_String(&value->foo);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_Point_f64(const Point_f64 *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_Point_f64(FpWriter *writer, const Point_f64 *value) {
+    fp_write_cstr(writer, "value");
+    fp_encode_f64(writer, &value->value);
+}
+
+static int fp_decode_field_Point_f64(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    Point_f64 *value = object;
+    if (fp_key_eq(key, key_len, "value")) {
+        return fp_decode_f64(reader, &value->value) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_Point_f64[] = {"value"};
+
+void fp_encode_Point_f64(FpWriter *writer, const Point_f64 *value) {
+    fp_write_map(writer, fp_count_fields_Point_f64(value));
+    fp_encode_fields_Point_f64(writer, value);
+}
+
+bool fp_decode_Point_f64(FpReader *reader, Point_f64 *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_Point_f64, fp_fields_Point_f64, 1);
+}
+
+static uint32_t fp_count_fields_FpAdjacentlyTaggedBaz(const FpAdjacentlyTaggedBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_FpAdjacentlyTaggedBaz(FpWriter *writer, const FpAdjacentlyTaggedBaz *value) {
+    fp_write_cstr(writer, "a");
+    fp_encode_i8(writer, &value->a);
+    fp_write_cstr(writer, "b");
+    fp_encode_u64(writer, &value->b);
+}
+
+static int fp_decode_field_FpAdjacentlyTaggedBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    FpAdjacentlyTaggedBaz *value = object;
+    if (fp_key_eq(key, key_len, "a")) {
+        return fp_decode_i8(reader, &value->a) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "b")) {
+        return fp_decode_u64(reader, &value->b) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_FpAdjacentlyTaggedBaz[] = {"a", "b"};
+
+void fp_encode_FpAdjacentlyTaggedBaz(FpWriter *writer, const FpAdjacentlyTaggedBaz *value) {
+    fp_write_map(writer, fp_count_fields_FpAdjacentlyTaggedBaz(value));
+    fp_encode_fields_FpAdjacentlyTaggedBaz(writer, value);
+}
+
+bool fp_decode_FpAdjacentlyTaggedBaz(FpReader *reader, FpAdjacentlyTaggedBaz *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_FpAdjacentlyTaggedBaz, fp_fields_FpAdjacentlyTaggedBaz, 2);
+}
+
+void fp_encode_FpAdjacentlyTagged(FpWriter *writer, const FpAdjacentlyTagged *value) {
+    switch (value->tag) {
+    case FpAdjacentlyTagged_Foo:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Foo");
+        break;
+    case FpAdjacentlyTagged_Bar:
+        fp_write_map(writer, 2);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Bar");
+        fp_write_cstr(writer, "payload");
+        fp_encode_String(writer, &value->bar);
+        break;
+    case FpAdjacentlyTagged_Baz:
+        fp_write_map(writer, 2);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Baz");
+        fp_write_cstr(writer, "payload");
+        fp_encode_FpAdjacentlyTaggedBaz(writer, &value->baz);
+        break;
+    }
+}
+
+bool fp_decode_FpAdjacentlyTagged(FpReader *reader, FpAdjacentlyTagged *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_adjacent(reader, "type", "payload", &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Foo")) {
+        value->tag = FpAdjacentlyTagged_Foo;
+        return true;
+    }
+    if (fp_key_eq(name, name_len, "Bar")) {
+        value->tag = FpAdjacentlyTagged_Bar;
+        return fp_decode_String(&content, &value->bar);
+    }
+    if (fp_key_eq(name, name_len, "Baz")) {
+        value->tag = FpAdjacentlyTagged_Baz;
+        return fp_decode_FpAdjacentlyTaggedBaz(&content, &value->baz);
+    }
+    return false;
+}
+
+void fp_free_FpAdjacentlyTagged(FpAdjacentlyTagged *value) {
+    switch (value->tag) {
+    case FpAdjacentlyTagged_Bar:
+        fp_free_String(&value->bar);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_FpFlatten(const FpFlatten *value) {
+    uint32_t count = 0;
+    count += fp_count_fields_FlattenedStruct(&value->flattened);
+    return count;
+}
+
+static void fp_encode_fields_FpFlatten(FpWriter *writer, const FpFlatten *value) {
+    fp_encode_fields_FlattenedStruct(writer, &value->flattened);
+}
+
+static int fp_decode_field_FpFlatten(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    FpFlatten *value = object;
+    int result;
+    result = fp_decode_field_FlattenedStruct(reader, &value->flattened, key, key_len);
+    if (result != 0) {
+        return result;
+    }
+    return 0;
+}
+
+void fp_encode_FpFlatten(FpWriter *writer, const FpFlatten *value) {
+    fp_write_map(writer, fp_count_fields_FpFlatten(value));
+    fp_encode_fields_FpFlatten(writer, value);
+}
+
+bool fp_decode_FpFlatten(FpReader *reader, FpFlatten *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_FpFlatten, NULL, 0)) {
+        fp_free_FpFlatten(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_FpFlatten(FpFlatten *value) {
+    fp_free_FlattenedStruct(&value->flattened);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_FpInternallyTaggedBaz(const FpInternallyTaggedBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_FpInternallyTaggedBaz(FpWriter *writer, const FpInternallyTaggedBaz *value) {
+    fp_write_cstr(writer, "a");
+    fp_encode_i8(writer, &value->a);
+    fp_write_cstr(writer, "b");
+    fp_encode_u64(writer, &value->b);
+}
+
+static int fp_decode_field_FpInternallyTaggedBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    FpInternallyTaggedBaz *value = object;
+    if (fp_key_eq(key, key_len, "a")) {
+        return fp_decode_i8(reader, &value->a) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "b")) {
+        return fp_decode_u64(reader, &value->b) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_FpInternallyTaggedBaz[] = {"a", "b"};
+
+void fp_encode_FpInternallyTaggedBaz(FpWriter *writer, const FpInternallyTaggedBaz *value) {
+    fp_write_map(writer, fp_count_fields_FpInternallyTaggedBaz(value));
+    fp_encode_fields_FpInternallyTaggedBaz(writer, value);
+}
+
+bool fp_decode_FpInternallyTaggedBaz(FpReader *reader, FpInternallyTaggedBaz *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_FpInternallyTaggedBaz, fp_fields_FpInternallyTaggedBaz, 2);
+}
+
+void fp_encode_FpInternallyTagged(FpWriter *writer, const FpInternallyTagged *value) {
+    switch (value->tag) {
+    case FpInternallyTagged_Foo:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Foo");
+        break;
+    case FpInternallyTagged_Baz:
+        fp_write_map(writer, 1 + fp_count_fields_FpInternallyTaggedBaz(&value->baz));
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Baz");
+        fp_encode_fields_FpInternallyTaggedBaz(writer, &value->baz);
+        break;
+    }
+}
+
+bool fp_decode_FpInternallyTagged(FpReader *reader, FpInternallyTagged *value) {
+    const char *name;
+    uint32_t name_len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_tag(reader, "type", &name, &name_len)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Foo")) {
+        value->tag = FpInternallyTagged_Foo;
+        return fp_skip(reader);
+    }
+    if (fp_key_eq(name, name_len, "Baz")) {
+        value->tag = FpInternallyTagged_Baz;
+        return fp_decode_tagged_struct(reader, "type", &value->baz, fp_decode_field_FpInternallyTaggedBaz, fp_fields_FpInternallyTaggedBaz, 2);
+    }
+    return false;
+}
+
+static uint32_t fp_count_fields_FpPropertyRenaming(const FpPropertyRenaming *value) {
+    (void)value;
+    return 3;
+}
+
+static void fp_encode_fields_FpPropertyRenaming(FpWriter *writer, const FpPropertyRenaming *value) {
+    fp_write_cstr(writer, "fooBar");
+    fp_encode_String(writer, &value->foo_bar);
+    fp_write_cstr(writer, "QUX_BAZ");
+    fp_encode_f64(writer, &value->qux_baz);
+    fp_write_cstr(writer, "rawStruct");
+    fp_encode_i32(writer, &value->raw_struct);
+}
+
+static int fp_decode_field_FpPropertyRenaming(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    FpPropertyRenaming *value = object;
+    if (fp_key_eq(key, key_len, "fooBar")) {
+        return fp_decode_String(reader, &value->foo_bar) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "QUX_BAZ")) {
+        return fp_decode_f64(reader, &value->qux_baz) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "rawStruct")) {
+        return fp_decode_i32(reader, &value->raw_struct) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_FpPropertyRenaming[] = {"fooBar", "QUX_BAZ", "rawStruct"};
+
+void fp_encode_FpPropertyRenaming(FpWriter *writer, const FpPropertyRenaming *value) {
+    fp_write_map(writer, fp_count_fields_FpPropertyRenaming(value));
+    fp_encode_fields_FpPropertyRenaming(writer, value);
+}
+
+bool fp_decode_FpPropertyRenaming(FpReader *reader, FpPropertyRenaming *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_FpPropertyRenaming, fp_fields_FpPropertyRenaming, 3)) {
+        fp_free_FpPropertyRenaming(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_FpPropertyRenaming(FpPropertyRenaming *value) {
+    fp_free_String(&value->foo_bar);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_FpUntaggedBaz(const FpUntaggedBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_FpUntaggedBaz(FpWriter *writer, const FpUntaggedBaz *value) {
+    fp_write_cstr(writer, "a");
+    fp_encode_i8(writer, &value->a);
+    fp_write_cstr(writer, "b");
+    fp_encode_u64(writer, &value->b);
+}
+
+static int fp_decode_field_FpUntaggedBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    FpUntaggedBaz *value = object;
+    if (fp_key_eq(key, key_len, "a")) {
+        return fp_decode_i8(reader, &value->a) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "b")) {
+        return fp_decode_u64(reader, &value->b) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_FpUntaggedBaz[] = {"a", "b"};
+
+void fp_encode_FpUntaggedBaz(FpWriter *writer, const FpUntaggedBaz *value) {
+    fp_write_map(writer, fp_count_fields_FpUntaggedBaz(value));
+    fp_encode_fields_FpUntaggedBaz(writer, value);
+}
+
+bool fp_decode_FpUntaggedBaz(FpReader *reader, FpUntaggedBaz *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_FpUntaggedBaz, fp_fields_FpUntaggedBaz, 2);
+}
+
+void fp_encode_FpUntagged(FpWriter *writer, const FpUntagged *value) {
+    switch (value->tag) {
+    case FpUntagged_Bar:
+        fp_encode_String(writer, &value->bar);
+        break;
+    case FpUntagged_Baz:
+        fp_encode_FpUntaggedBaz(writer, &value->baz);
+        break;
+    }
+}
+
+bool fp_decode_FpUntagged(FpReader *reader, FpUntagged *value) {
+    FpReader attempt;
+    memset(value, 0, sizeof(*value));
+    attempt = fp_attempt(reader);
+    if (fp_decode_String(&attempt, &value->bar)) {
+        value->tag = FpUntagged_Bar;
+        return fp_commit(reader, &attempt);
+    }
+    attempt = fp_attempt(reader);
+    if (fp_decode_FpUntaggedBaz(&attempt, &value->baz)) {
+        value->tag = FpUntagged_Baz;
+        return fp_commit(reader, &attempt);
+    }
+    return false;
+}
+
+void fp_free_FpUntagged(FpUntagged *value) {
+    switch (value->tag) {
+    case FpUntagged_Bar:
+        fp_free_String(&value->bar);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_FpVariantRenamingQuxBaz(const FpVariantRenamingQuxBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_FpVariantRenamingQuxBaz(FpWriter *writer, const FpVariantRenamingQuxBaz *value) {
+    fp_write_cstr(writer, "FOO_BAR");
+    fp_encode_String(writer, &value->foo_bar);
+    fp_write_cstr(writer, "qux_baz");
+    fp_encode_f64(writer, &value->qux_baz);
+}
+
+static int fp_decode_field_FpVariantRenamingQuxBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    FpVariantRenamingQuxBaz *value = object;
+    if (fp_key_eq(key, key_len, "FOO_BAR")) {
+        return fp_decode_String(reader, &value->foo_bar) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "qux_baz")) {
+        return fp_decode_f64(reader, &value->qux_baz) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_FpVariantRenamingQuxBaz[] = {"FOO_BAR", "qux_baz"};
+
+void fp_encode_FpVariantRenamingQuxBaz(FpWriter *writer, const FpVariantRenamingQuxBaz *value) {
+    fp_write_map(writer, fp_count_fields_FpVariantRenamingQuxBaz(value));
+    fp_encode_fields_FpVariantRenamingQuxBaz(writer, value);
+}
+
+bool fp_decode_FpVariantRenamingQuxBaz(FpReader *reader, FpVariantRenamingQuxBaz *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_FpVariantRenamingQuxBaz, fp_fields_FpVariantRenamingQuxBaz, 2)) {
+        fp_free_FpVariantRenamingQuxBaz(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_FpVariantRenamingQuxBaz(FpVariantRenamingQuxBaz *value) {
+    fp_free_String(&value->foo_bar);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpVariantRenaming(FpWriter *writer, const FpVariantRenaming *value) {
+    switch (value->tag) {
+    case FpVariantRenaming_FooBar:
+        fp_write_cstr(writer, "foo_bar");
+        break;
+    case FpVariantRenaming_QuxBaz:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "QUX_BAZ");
+        fp_encode_FpVariantRenamingQuxBaz(writer, &value->qux_baz);
+        break;
+    }
+}
+
+bool fp_decode_FpVariantRenaming(FpReader *reader, FpVariantRenaming *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_variant(reader, &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "foo_bar")) {
+        value->tag = FpVariantRenaming_FooBar;
+        return true;
+    }
+    if (fp_key_eq(name, name_len, "QUX_BAZ")) {
+        value->tag = FpVariantRenaming_QuxBaz;
+        return fp_decode_FpVariantRenamingQuxBaz(&content, &value->qux_baz);
+    }
+    return false;
+}
+
+void fp_free_FpVariantRenaming(FpVariantRenaming *value) {
+    switch (value->tag) {
+    case FpVariantRenaming_QuxBaz:
+        fp_free_FpVariantRenamingQuxBaz(&value->qux_baz);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_GroupImportedType1(const GroupImportedType1 *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_GroupImportedType1(FpWriter *writer, const GroupImportedType1 *value) {
+    fp_write_cstr(writer, "you_will_see_this");
+    fp_encode_bool(writer, &value->you_will_see_this);
+}
+
+static int fp_decode_field_GroupImportedType1(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    GroupImportedType1 *value = object;
+    if (fp_key_eq(key, key_len, "you_will_see_this")) {
+        return fp_decode_bool(reader, &value->you_will_see_this) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_GroupImportedType1[] = {"you_will_see_this"};
+
+void fp_encode_GroupImportedType1(FpWriter *writer, const GroupImportedType1 *value) {
+    fp_write_map(writer, fp_count_fields_GroupImportedType1(value));
+    fp_encode_fields_GroupImportedType1(writer, value);
+}
+
+bool fp_decode_GroupImportedType1(FpReader *reader, GroupImportedType1 *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_GroupImportedType1, fp_fields_GroupImportedType1, 1);
+}
+
+static uint32_t fp_count_fields_GroupImportedType2(const GroupImportedType2 *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_GroupImportedType2(FpWriter *writer, const GroupImportedType2 *value) {
+    fp_write_cstr(writer, "you_will_see_this");
+    fp_encode_bool(writer, &value->you_will_see_this);
+}
+
+static int fp_decode_field_GroupImportedType2(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    GroupImportedType2 *value = object;
+    if (fp_key_eq(key, key_len, "you_will_see_this")) {
+        return fp_decode_bool(reader, &value->you_will_see_this) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_GroupImportedType2[] = {"you_will_see_this"};
+
+void fp_encode_GroupImportedType2(FpWriter *writer, const GroupImportedType2 *value) {
+    fp_write_map(writer, fp_count_fields_GroupImportedType2(value));
+    fp_encode_fields_GroupImportedType2(writer, value);
+}
+
+bool fp_decode_GroupImportedType2(FpReader *reader, GroupImportedType2 *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_GroupImportedType2, fp_fields_GroupImportedType2, 1);
+}
+
+static uint32_t fp_count_fields_Response(const Response *value) {
+    (void)value;
+    return 3;
+}
+
+static void fp_encode_fields_Response(FpWriter *writer, const Response *value) {
+    fp_write_cstr(writer, "body");
+    fp_encode_Bytes(writer, &value->body);
+    fp_write_cstr(writer, "headers");
+    fp_encode_Value(writer, &value->headers);
+    fp_write_cstr(writer, "status_code");
+    fp_encode_u16(writer, &value->status_code);
+}
+
+static int fp_decode_field_Response(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    Response *value = object;
+    if (fp_key_eq(key, key_len, "body")) {
+        return fp_decode_Bytes(reader, &value->body) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "headers")) {
+        return fp_decode_Value(reader, &value->headers) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "status_code")) {
+        return fp_decode_u16(reader, &value->status_code) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_Response[] = {"body", "headers", "status_code"};
+
+void fp_encode_Response(FpWriter *writer, const Response *value) {
+    fp_write_map(writer, fp_count_fields_Response(value));
+    fp_encode_fields_Response(writer, value);
+}
+
+bool fp_decode_Response(FpReader *reader, Response *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_Response, fp_fields_Response, 3)) {
+        fp_free_Response(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_Response(Response *value) {
+    fp_free_Bytes(&value->body);
+    fp_free_Value(&value->headers);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_RequestErrorServerError(const RequestErrorServerError *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_RequestErrorServerError(FpWriter *writer, const RequestErrorServerError *value) {
+    fp_write_cstr(writer, "status_code");
+    fp_encode_u16(writer, &value->status_code);
+    fp_write_cstr(writer, "response");
+    fp_encode_Bytes(writer, &value->response);
+}
+
+static int fp_decode_field_RequestErrorServerError(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    RequestErrorServerError *value = object;
+    if (fp_key_eq(key, key_len, "status_code")) {
+        return fp_decode_u16(reader, &value->status_code) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "response")) {
+        return fp_decode_Bytes(reader, &value->response) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_RequestErrorServerError[] = {"status_code", "response"};
+
+void fp_encode_RequestErrorServerError(FpWriter *writer, const RequestErrorServerError *value) {
+    fp_write_map(writer, fp_count_fields_RequestErrorServerError(value));
+    fp_encode_fields_RequestErrorServerError(writer, value);
+}
+
+bool fp_decode_RequestErrorServerError(FpReader *reader, RequestErrorServerError *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_RequestErrorServerError, fp_fields_RequestErrorServerError, 2)) {
+        fp_free_RequestErrorServerError(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_RequestErrorServerError(RequestErrorServerError *value) {
+    fp_free_Bytes(&value->response);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_RequestErrorOther(const RequestErrorOther *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_RequestErrorOther(FpWriter *writer, const RequestErrorOther *value) {
+    fp_write_cstr(writer, "reason");
+    fp_encode_String(writer, &value->reason);
+}
+
+static int fp_decode_field_RequestErrorOther(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    RequestErrorOther *value = object;
+    if (fp_key_eq(key, key_len, "reason")) {
+        return fp_decode_String(reader, &value->reason) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_RequestErrorOther[] = {"reason"};
+
+void fp_encode_RequestErrorOther(FpWriter *writer, const RequestErrorOther *value) {
+    fp_write_map(writer, fp_count_fields_RequestErrorOther(value));
+    fp_encode_fields_RequestErrorOther(writer, value);
+}
+
+bool fp_decode_RequestErrorOther(FpReader *reader, RequestErrorOther *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_RequestErrorOther, fp_fields_RequestErrorOther, 1)) {
+        fp_free_RequestErrorOther(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_RequestErrorOther(RequestErrorOther *value) {
+    fp_free_String(&value->reason);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_RequestError(FpWriter *writer, const RequestError *value) {
+    switch (value->tag) {
+    case RequestError_Offline:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "offline");
+        break;
+    case RequestError_NoRoute:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "no_route");
+        break;
+    case RequestError_ConnectionRefused:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "connection_refused");
+        break;
+    case RequestError_Timeout:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "timeout");
+        break;
+    case RequestError_ServerError:
+        fp_write_map(writer, 1 + fp_count_fields_RequestErrorServerError(&value->server_error));
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "server_error");
+        fp_encode_fields_RequestErrorServerError(writer, &value->server_error);
+        break;
+    case RequestError_Other:
+        fp_write_map(writer, 1 + fp_count_fields_RequestErrorOther(&value->other));
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "other/misc");
+        fp_encode_fields_RequestErrorOther(writer, &value->other);
+        break;
+    }
+}
+
+bool fp_decode_RequestError(FpReader *reader, RequestError *value) {
+    const char *name;
+    uint32_t name_len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_tag(reader, "type", &name, &name_len)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "offline")) {
+        value->tag = RequestError_Offline;
+        return fp_skip(reader);
+    }
+    if (fp_key_eq(name, name_len, "no_route")) {
+        value->tag = RequestError_NoRoute;
+        return fp_skip(reader);
+    }
+    if (fp_key_eq(name, name_len, "connection_refused")) {
+        value->tag = RequestError_ConnectionRefused;
+        return fp_skip(reader);
+    }
+    if (fp_key_eq(name, name_len, "timeout")) {
+        value->tag = RequestError_Timeout;
+        return fp_skip(reader);
+    }
+    if (fp_key_eq(name, name_len, "server_error")) {
+        value->tag = RequestError_ServerError;
+        if (!fp_decode_tagged_struct(reader, "type", &value->server_error, fp_decode_field_RequestErrorServerError, fp_fields_RequestErrorServerError, 2)) {
+            fp_free_RequestErrorServerError(&value->server_error);
+            return false;
+        }
+        return true;
+    }
+    if (fp_key_eq(name, name_len, "other/misc")) {
+        value->tag = RequestError_Other;
+        if (!fp_decode_tagged_struct(reader, "type", &value->other, fp_decode_field_RequestErrorOther, fp_fields_RequestErrorOther, 1)) {
+            fp_free_RequestErrorOther(&value->other);
+            return false;
+        }
+        return true;
+    }
+    return false;
+}
+
+void fp_free_RequestError(RequestError *value) {
+    switch (value->tag) {
+    case RequestError_ServerError:
+        fp_free_RequestErrorServerError(&value->server_error);
+        break;
+    case RequestError_Other:
+        fp_free_RequestErrorOther(&value->other);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_Result_Response_RequestError(FpWriter *writer, const Result_Response_RequestError *value) {
+    switch (value->tag) {
+    case Result_Response_RequestError_Ok:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Ok");
+        fp_encode_Response(writer, &value->ok);
+        break;
+    case Result_Response_RequestError_Err:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Err");
+        fp_encode_RequestError(writer, &value->err);
+        break;
+    }
+}
+
+bool fp_decode_Result_Response_RequestError(FpReader *reader, Result_Response_RequestError *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_variant(reader, &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Ok")) {
+        value->tag = Result_Response_RequestError_Ok;
+        return fp_decode_Response(&content, &value->ok);
+    }
+    if (fp_key_eq(name, name_len, "Err")) {
+        value->tag = Result_Response_RequestError_Err;
+        return fp_decode_RequestError(&content, &value->err);
+    }
+    return false;
+}
+
+void fp_free_Result_Response_RequestError(Result_Response_RequestError *value) {
+    switch (value->tag) {
+    case Result_Response_RequestError_Ok:
+        fp_free_Response(&value->ok);
+        break;
+    case Result_Response_RequestError_Err:
+        fp_free_RequestError(&value->err);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_ReduxActionUpdateTitle(const ReduxActionUpdateTitle *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_ReduxActionUpdateTitle(FpWriter *writer, const ReduxActionUpdateTitle *value) {
+    fp_write_cstr(writer, "title");
+    fp_encode_String(writer, &value->title);
+}
+
+static int fp_decode_field_ReduxActionUpdateTitle(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    ReduxActionUpdateTitle *value = object;
+    if (fp_key_eq(key, key_len, "title")) {
+        return fp_decode_String(reader, &value->title) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_ReduxActionUpdateTitle[] = {"title"};
+
+void fp_encode_ReduxActionUpdateTitle(FpWriter *writer, const ReduxActionUpdateTitle *value) {
+    fp_write_map(writer, fp_count_fields_ReduxActionUpdateTitle(value));
+    fp_encode_fields_ReduxActionUpdateTitle(writer, value);
+}
+
+bool fp_decode_ReduxActionUpdateTitle(FpReader *reader, ReduxActionUpdateTitle *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_ReduxActionUpdateTitle, fp_fields_ReduxActionUpdateTitle, 1)) {
+        fp_free_ReduxActionUpdateTitle(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_ReduxActionUpdateTitle(ReduxActionUpdateTitle *value) {
+    fp_free_String(&value->title);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_ReduxAction(FpWriter *writer, const ReduxAction *value) {
+    switch (value->tag) {
+    case ReduxAction_ClearTitle:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "clear_title");
+        break;
+    case ReduxAction_UpdateTitle:
+        fp_write_map(writer, 2);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "update_title");
+        fp_write_cstr(writer, "payload");
+        fp_encode_ReduxActionUpdateTitle(writer, &value->update_title);
+        break;
+    }
+}
+
+bool fp_decode_ReduxAction(FpReader *reader, ReduxAction *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_adjacent(reader, "type", "payload", &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "clear_title")) {
+        value->tag = ReduxAction_ClearTitle;
+        return true;
+    }
+    if (fp_key_eq(name, name_len, "update_title")) {
+        value->tag = ReduxAction_UpdateTitle;
+        return fp_decode_ReduxActionUpdateTitle(&content, &value->update_title);
+    }
+    return false;
+}
+
+void fp_free_ReduxAction(ReduxAction *value) {
+    switch (value->tag) {
+    case ReduxAction_UpdateTitle:
+        fp_free_ReduxActionUpdateTitle(&value->update_title);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpOption_Body(FpWriter *writer, const FpOption_Body *value) {
+    if (value->is_some) {
+        fp_encode_Bytes(writer, &value->value);
+    } else {
+        fp_write_nil(writer);
+    }
+}
+
+bool fp_decode_FpOption_Body(FpReader *reader, FpOption_Body *value) {
+    memset(value, 0, sizeof(*value));
+    if (fp_read_nil(reader)) {
+        return true;
+    }
+    value->is_some = fp_decode_Bytes(reader, &value->value);
+    return value->is_some;
+}
+
+void fp_free_FpOption_Body(FpOption_Body *value) {
+    if (value->is_some) {
+        fp_free_Bytes(&value->value);
+        value->is_some = false;
+    }
+}
+
+static uint32_t fp_count_fields_Request(const Request *value) {
+    uint32_t count = 3;
+    if (value->body.is_some) {
+        count++;
+    }
+    return count;
+}
+
+static void fp_encode_fields_Request(FpWriter *writer, const Request *value) {
+    fp_write_cstr(writer, "url");
+    fp_encode_String(writer, &value->url);
+    fp_write_cstr(writer, "method");
+    fp_encode_Value(writer, &value->method);
+    fp_write_cstr(writer, "headers");
+    fp_encode_Value(writer, &value->headers);
+    if (value->body.is_some) {
+        fp_write_cstr(writer, "body");
+        fp_encode_FpOption_Body(writer, &value->body);
+    }
+}
+
+static int fp_decode_field_Request(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    Request *value = object;
+    if (fp_key_eq(key, key_len, "url")) {
+        return fp_decode_String(reader, &value->url) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "method")) {
+        return fp_decode_Value(reader, &value->method) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "headers")) {
+        return fp_decode_Value(reader, &value->headers) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "body")) {
+        return fp_decode_FpOption_Body(reader, &value->body) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_Request[] = {"url", "method", "headers", "body"};
+
+void fp_encode_Request(FpWriter *writer, const Request *value) {
+    fp_write_map(writer, fp_count_fields_Request(value));
+    fp_encode_fields_Request(writer, value);
+}
+
+bool fp_decode_Request(FpReader *reader, Request *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_Request, fp_fields_Request, 4)) {
+        fp_free_Request(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_Request(Request *value) {
+    fp_free_String(&value->url);
+    fp_free_Value(&value->method);
+    fp_free_Value(&value->headers);
+    fp_free_FpOption_Body(&value->body);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_SerdeAdjacentlyTaggedBaz(const SerdeAdjacentlyTaggedBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_SerdeAdjacentlyTaggedBaz(FpWriter *writer, const SerdeAdjacentlyTaggedBaz *value) {
+    fp_write_cstr(writer, "a");
+    fp_encode_i8(writer, &value->a);
+    fp_write_cstr(writer, "b");
+    fp_encode_u64(writer, &value->b);
+}
+
+static int fp_decode_field_SerdeAdjacentlyTaggedBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    SerdeAdjacentlyTaggedBaz *value = object;
+    if (fp_key_eq(key, key_len, "a")) {
+        return fp_decode_i8(reader, &value->a) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "b")) {
+        return fp_decode_u64(reader, &value->b) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_SerdeAdjacentlyTaggedBaz[] = {"a", "b"};
+
+void fp_encode_SerdeAdjacentlyTaggedBaz(FpWriter *writer, const SerdeAdjacentlyTaggedBaz *value) {
+    fp_write_map(writer, fp_count_fields_SerdeAdjacentlyTaggedBaz(value));
+    fp_encode_fields_SerdeAdjacentlyTaggedBaz(writer, value);
+}
+
+bool fp_decode_SerdeAdjacentlyTaggedBaz(FpReader *reader, SerdeAdjacentlyTaggedBaz *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_SerdeAdjacentlyTaggedBaz, fp_fields_SerdeAdjacentlyTaggedBaz, 2);
+}
+
+void fp_encode_SerdeAdjacentlyTagged(FpWriter *writer, const SerdeAdjacentlyTagged *value) {
+    switch (value->tag) {
+    case SerdeAdjacentlyTagged_Foo:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Foo");
+        break;
+    case SerdeAdjacentlyTagged_Bar:
+        fp_write_map(writer, 2);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Bar");
+        fp_write_cstr(writer, "payload");
+        fp_encode_String(writer, &value->bar);
+        break;
+    case SerdeAdjacentlyTagged_Baz:
+        fp_write_map(writer, 2);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Baz");
+        fp_write_cstr(writer, "payload");
+        fp_encode_SerdeAdjacentlyTaggedBaz(writer, &value->baz);
+        break;
+    }
+}
+
+bool fp_decode_SerdeAdjacentlyTagged(FpReader *reader, SerdeAdjacentlyTagged *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_adjacent(reader, "type", "payload", &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Foo")) {
+        value->tag = SerdeAdjacentlyTagged_Foo;
+        return true;
+    }
+    if (fp_key_eq(name, name_len, "Bar")) {
+        value->tag = SerdeAdjacentlyTagged_Bar;
+        return fp_decode_String(&content, &value->bar);
+    }
+    if (fp_key_eq(name, name_len, "Baz")) {
+        value->tag = SerdeAdjacentlyTagged_Baz;
+        return fp_decode_SerdeAdjacentlyTaggedBaz(&content, &value->baz);
+    }
+    return false;
+}
+
+void fp_free_SerdeAdjacentlyTagged(SerdeAdjacentlyTagged *value) {
+    switch (value->tag) {
+    case SerdeAdjacentlyTagged_Bar:
+        fp_free_String(&value->bar);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_SerdeFlatten(const SerdeFlatten *value) {
+    uint32_t count = 0;
+    count += fp_count_fields_FlattenedStruct(&value->flattened);
+    return count;
+}
+
+static void fp_encode_fields_SerdeFlatten(FpWriter *writer, const SerdeFlatten *value) {
+    fp_encode_fields_FlattenedStruct(writer, &value->flattened);
+}
+
+static int fp_decode_field_SerdeFlatten(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    SerdeFlatten *value = object;
+    int result;
+    result = fp_decode_field_FlattenedStruct(reader, &value->flattened, key, key_len);
+    if (result != 0) {
+        return result;
+    }
+    return 0;
+}
+
+void fp_encode_SerdeFlatten(FpWriter *writer, const SerdeFlatten *value) {
+    fp_write_map(writer, fp_count_fields_SerdeFlatten(value));
+    fp_encode_fields_SerdeFlatten(writer, value);
+}
+
+bool fp_decode_SerdeFlatten(FpReader *reader, SerdeFlatten *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_SerdeFlatten, NULL, 0)) {
+        fp_free_SerdeFlatten(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_SerdeFlatten(SerdeFlatten *value) {
+    fp_free_FlattenedStruct(&value->flattened);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_SerdeInternallyTaggedBaz(const SerdeInternallyTaggedBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_SerdeInternallyTaggedBaz(FpWriter *writer, const SerdeInternallyTaggedBaz *value) {
+    fp_write_cstr(writer, "a");
+    fp_encode_i8(writer, &value->a);
+    fp_write_cstr(writer, "b");
+    fp_encode_u64(writer, &value->b);
+}
+
+static int fp_decode_field_SerdeInternallyTaggedBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    SerdeInternallyTaggedBaz *value = object;
+    if (fp_key_eq(key, key_len, "a")) {
+        return fp_decode_i8(reader, &value->a) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "b")) {
+        return fp_decode_u64(reader, &value->b) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_SerdeInternallyTaggedBaz[] = {"a", "b"};
+
+void fp_encode_SerdeInternallyTaggedBaz(FpWriter *writer, const SerdeInternallyTaggedBaz *value) {
+    fp_write_map(writer, fp_count_fields_SerdeInternallyTaggedBaz(value));
+    fp_encode_fields_SerdeInternallyTaggedBaz(writer, value);
+}
+
+bool fp_decode_SerdeInternallyTaggedBaz(FpReader *reader, SerdeInternallyTaggedBaz *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_SerdeInternallyTaggedBaz, fp_fields_SerdeInternallyTaggedBaz, 2);
+}
+
+void fp_encode_SerdeInternallyTagged(FpWriter *writer, const SerdeInternallyTagged *value) {
+    switch (value->tag) {
+    case SerdeInternallyTagged_Foo:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Foo");
+        break;
+    case SerdeInternallyTagged_Baz:
+        fp_write_map(writer, 1 + fp_count_fields_SerdeInternallyTaggedBaz(&value->baz));
+        fp_write_cstr(writer, "type");
+        fp_write_cstr(writer, "Baz");
+        fp_encode_fields_SerdeInternallyTaggedBaz(writer, &value->baz);
+        break;
+    }
+}
+
+bool fp_decode_SerdeInternallyTagged(FpReader *reader, SerdeInternallyTagged *value) {
+    const char *name;
+    uint32_t name_len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_tag(reader, "type", &name, &name_len)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Foo")) {
+        value->tag = SerdeInternallyTagged_Foo;
+        return fp_skip(reader);
+    }
+    if (fp_key_eq(name, name_len, "Baz")) {
+        value->tag = SerdeInternallyTagged_Baz;
+        return fp_decode_tagged_struct(reader, "type", &value->baz, fp_decode_field_SerdeInternallyTaggedBaz, fp_fields_SerdeInternallyTaggedBaz, 2);
+    }
+    return false;
+}
+
+static uint32_t fp_count_fields_SerdePropertyRenaming(const SerdePropertyRenaming *value) {
+    (void)value;
+    return 3;
+}
+
+static void fp_encode_fields_SerdePropertyRenaming(FpWriter *writer, const SerdePropertyRenaming *value) {
+    fp_write_cstr(writer, "fooBar");
+    fp_encode_String(writer, &value->foo_bar);
+    fp_write_cstr(writer, "QUX_BAZ");
+    fp_encode_f64(writer, &value->qux_baz);
+    fp_write_cstr(writer, "rawStruct");
+    fp_encode_i32(writer, &value->raw_struct);
+}
+
+static int fp_decode_field_SerdePropertyRenaming(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    SerdePropertyRenaming *value = object;
+    if (fp_key_eq(key, key_len, "fooBar")) {
+        return fp_decode_String(reader, &value->foo_bar) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "QUX_BAZ")) {
+        return fp_decode_f64(reader, &value->qux_baz) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "rawStruct")) {
+        return fp_decode_i32(reader, &value->raw_struct) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_SerdePropertyRenaming[] = {"fooBar", "QUX_BAZ", "rawStruct"};
+
+void fp_encode_SerdePropertyRenaming(FpWriter *writer, const SerdePropertyRenaming *value) {
+    fp_write_map(writer, fp_count_fields_SerdePropertyRenaming(value));
+    fp_encode_fields_SerdePropertyRenaming(writer, value);
+}
+
+bool fp_decode_SerdePropertyRenaming(FpReader *reader, SerdePropertyRenaming *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_SerdePropertyRenaming, fp_fields_SerdePropertyRenaming, 3)) {
+        fp_free_SerdePropertyRenaming(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_SerdePropertyRenaming(SerdePropertyRenaming *value) {
+    fp_free_String(&value->foo_bar);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_SerdeUntaggedBaz(const SerdeUntaggedBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_SerdeUntaggedBaz(FpWriter *writer, const SerdeUntaggedBaz *value) {
+    fp_write_cstr(writer, "a");
+    fp_encode_i8(writer, &value->a);
+    fp_write_cstr(writer, "b");
+    fp_encode_u64(writer, &value->b);
+}
+
+static int fp_decode_field_SerdeUntaggedBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    SerdeUntaggedBaz *value = object;
+    if (fp_key_eq(key, key_len, "a")) {
+        return fp_decode_i8(reader, &value->a) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "b")) {
+        return fp_decode_u64(reader, &value->b) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_SerdeUntaggedBaz[] = {"a", "b"};
+
+void fp_encode_SerdeUntaggedBaz(FpWriter *writer, const SerdeUntaggedBaz *value) {
+    fp_write_map(writer, fp_count_fields_SerdeUntaggedBaz(value));
+    fp_encode_fields_SerdeUntaggedBaz(writer, value);
+}
+
+bool fp_decode_SerdeUntaggedBaz(FpReader *reader, SerdeUntaggedBaz *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_SerdeUntaggedBaz, fp_fields_SerdeUntaggedBaz, 2);
+}
+
+void fp_encode_SerdeUntagged(FpWriter *writer, const SerdeUntagged *value) {
+    switch (value->tag) {
+    case SerdeUntagged_Bar:
+        fp_encode_String(writer, &value->bar);
+        break;
+    case SerdeUntagged_Baz:
+        fp_encode_SerdeUntaggedBaz(writer, &value->baz);
+        break;
+    }
+}
+
+bool fp_decode_SerdeUntagged(FpReader *reader, SerdeUntagged *value) {
+    FpReader attempt;
+    memset(value, 0, sizeof(*value));
+    attempt = fp_attempt(reader);
+    if (fp_decode_String(&attempt, &value->bar)) {
+        value->tag = SerdeUntagged_Bar;
+        return fp_commit(reader, &attempt);
+    }
+    attempt = fp_attempt(reader);
+    if (fp_decode_SerdeUntaggedBaz(&attempt, &value->baz)) {
+        value->tag = SerdeUntagged_Baz;
+        return fp_commit(reader, &attempt);
+    }
+    return false;
+}
+
+void fp_free_SerdeUntagged(SerdeUntagged *value) {
+    switch (value->tag) {
+    case SerdeUntagged_Bar:
+        fp_free_String(&value->bar);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_SerdeVariantRenamingQuxBaz(const SerdeVariantRenamingQuxBaz *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_SerdeVariantRenamingQuxBaz(FpWriter *writer, const SerdeVariantRenamingQuxBaz *value) {
+    fp_write_cstr(writer, "FooBar");
+    fp_encode_String(writer, &value->foo_bar);
+    fp_write_cstr(writer, "qux_baz");
+    fp_encode_f64(writer, &value->qux_baz);
+}
+
+static int fp_decode_field_SerdeVariantRenamingQuxBaz(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    SerdeVariantRenamingQuxBaz *value = object;
+    if (fp_key_eq(key, key_len, "FooBar")) {
+        return fp_decode_String(reader, &value->foo_bar) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "qux_baz")) {
+        return fp_decode_f64(reader, &value->qux_baz) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_SerdeVariantRenamingQuxBaz[] = {"FooBar", "qux_baz"};
+
+void fp_encode_SerdeVariantRenamingQuxBaz(FpWriter *writer, const SerdeVariantRenamingQuxBaz *value) {
+    fp_write_map(writer, fp_count_fields_SerdeVariantRenamingQuxBaz(value));
+    fp_encode_fields_SerdeVariantRenamingQuxBaz(writer, value);
+}
+
+bool fp_decode_SerdeVariantRenamingQuxBaz(FpReader *reader, SerdeVariantRenamingQuxBaz *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_SerdeVariantRenamingQuxBaz, fp_fields_SerdeVariantRenamingQuxBaz, 2)) {
+        fp_free_SerdeVariantRenamingQuxBaz(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_SerdeVariantRenamingQuxBaz(SerdeVariantRenamingQuxBaz *value) {
+    fp_free_String(&value->foo_bar);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_SerdeVariantRenaming(FpWriter *writer, const SerdeVariantRenaming *value) {
+    switch (value->tag) {
+    case SerdeVariantRenaming_FooBar:
+        fp_write_cstr(writer, "foo_bar");
+        break;
+    case SerdeVariantRenaming_QuxBaz:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "QUX_BAZ");
+        fp_encode_SerdeVariantRenamingQuxBaz(writer, &value->qux_baz);
+        break;
+    }
+}
+
+bool fp_decode_SerdeVariantRenaming(FpReader *reader, SerdeVariantRenaming *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_variant(reader, &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "foo_bar")) {
+        value->tag = SerdeVariantRenaming_FooBar;
+        return true;
+    }
+    if (fp_key_eq(name, name_len, "QUX_BAZ")) {
+        value->tag = SerdeVariantRenaming_QuxBaz;
+        return fp_decode_SerdeVariantRenamingQuxBaz(&content, &value->qux_baz);
+    }
+    return false;
+}
+
+void fp_free_SerdeVariantRenaming(SerdeVariantRenaming *value) {
+    switch (value->tag) {
+    case SerdeVariantRenaming_QuxBaz:
+        fp_free_SerdeVariantRenamingQuxBaz(&value->qux_baz);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpBox_String(FpWriter *writer, FpString *const *value) {
+    fp_encode_String(writer, *value);
+}
+
+bool fp_decode_FpBox_String(FpReader *reader, FpString **value) {
+    *value = fp_alloc(1, sizeof(FpString));
+    if (!fp_decode_String(reader, *value)) {
+        free(*value);
+        *value = NULL;
+        return false;
+    }
+    return true;
+}
+
+void fp_free_FpBox_String(FpString **value) {
+    if (*value != NULL) {
+        fp_free_String(*value);
+        free(*value);
+        *value = NULL;
+    }
+}
+
+void fp_encode_FpOption_FpBox_String(FpWriter *writer, const FpOption_FpBox_String *value) {
+    if (value->is_some) {
+        fp_encode_FpBox_String(writer, &value->value);
+    } else {
+        fp_write_nil(writer);
+    }
+}
+
+bool fp_decode_FpOption_FpBox_String(FpReader *reader, FpOption_FpBox_String *value) {
+    memset(value, 0, sizeof(*value));
+    if (fp_read_nil(reader)) {
+        return true;
+    }
+    value->is_some = fp_decode_FpBox_String(reader, &value->value);
+    return value->is_some;
+}
+
+void fp_free_FpOption_FpBox_String(FpOption_FpBox_String *value) {
+    if (value->is_some) {
+        fp_free_FpBox_String(&value->value);
+        value->is_some = false;
+    }
+}
+
+void fp_encode_FpOption_u16(FpWriter *writer, const FpOption_u16 *value) {
+    if (value->is_some) {
+        fp_encode_u16(writer, &value->value);
+    } else {
+        fp_write_nil(writer);
+    }
+}
+
+bool fp_decode_FpOption_u16(FpReader *reader, FpOption_u16 *value) {
+    memset(value, 0, sizeof(*value));
+    if (fp_read_nil(reader)) {
+        return true;
+    }
+    value->is_some = fp_decode_u16(reader, &value->value);
+    return value->is_some;
+}
+
+static uint32_t fp_count_fields_StateUpdate(const StateUpdate *value) {
+    (void)value;
+    return 2;
+}
+
+static void fp_encode_fields_StateUpdate(FpWriter *writer, const StateUpdate *value) {
+    fp_write_cstr(writer, "title");
+    fp_encode_FpOption_FpBox_String(writer, &value->title);
+    fp_write_cstr(writer, "revision");
+    fp_encode_FpOption_u16(writer, &value->revision);
+}
+
+static int fp_decode_field_StateUpdate(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    StateUpdate *value = object;
+    if (fp_key_eq(key, key_len, "title")) {
+        return fp_decode_FpOption_FpBox_String(reader, &value->title) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "revision")) {
+        return fp_decode_FpOption_u16(reader, &value->revision) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_StateUpdate[] = {"title", "revision"};
+
+void fp_encode_StateUpdate(FpWriter *writer, const StateUpdate *value) {
+    fp_write_map(writer, fp_count_fields_StateUpdate(value));
+    fp_encode_fields_StateUpdate(writer, value);
+}
+
+bool fp_decode_StateUpdate(FpReader *reader, StateUpdate *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_StateUpdate, fp_fields_StateUpdate, 2)) {
+        fp_free_StateUpdate(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_StateUpdate(StateUpdate *value) {
+    fp_free_FpOption_FpBox_String(&value->title);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpOption_String(FpWriter *writer, const FpOption_String *value) {
+    if (value->is_some) {
+        fp_encode_String(writer, &value->value);
+    } else {
+        fp_write_nil(writer);
+    }
+}
+
+bool fp_decode_FpOption_String(FpReader *reader, FpOption_String *value) {
+    memset(value, 0, sizeof(*value));
+    if (fp_read_nil(reader)) {
+        return true;
+    }
+    value->is_some = fp_decode_String(reader, &value->value);
+    return value->is_some;
+}
+
+void fp_free_FpOption_String(FpOption_String *value) {
+    if (value->is_some) {
+        fp_free_String(&value->value);
+        value->is_some = false;
+    }
+}
+
+static uint32_t fp_count_fields_StructWithOptions(const StructWithOptions *value) {
+    uint32_t count = 2;
+    if (value->filled_string.len > 0) {
+        count++;
+    }
+    if (value->empty_string.len > 0) {
+        count++;
+    }
+    if (value->filled_option_string.is_some) {
+        count++;
+    }
+    if (value->empty_option_string.is_some) {
+        count++;
+    }
+    return count;
+}
+
+static void fp_encode_fields_StructWithOptions(FpWriter *writer, const StructWithOptions *value) {
+    if (value->filled_string.len > 0) {
+        fp_write_cstr(writer, "filledString");
+        fp_encode_String(writer, &value->filled_string);
+    }
+    if (value->empty_string.len > 0) {
+        fp_write_cstr(writer, "emptyString");
+        fp_encode_String(writer, &value->empty_string);
+    }
+    if (value->filled_option_string.is_some) {
+        fp_write_cstr(writer, "filledOptionString");
+        fp_encode_FpOption_String(writer, &value->filled_option_string);
+    }
+    if (value->empty_option_string.is_some) {
+        fp_write_cstr(writer, "emptyOptionString");
+        fp_encode_FpOption_String(writer, &value->empty_option_string);
+    }
+    fp_write_cstr(writer, "neverSkippedFilledOptionString");
+    fp_encode_FpOption_String(writer, &value->never_skipped_filled_option_string);
+    fp_write_cstr(writer, "neverSkippedEmptyOptionString");
+    fp_encode_FpOption_String(writer, &value->never_skipped_empty_option_string);
+}
+
+static int fp_decode_field_StructWithOptions(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    StructWithOptions *value = object;
+    if (fp_key_eq(key, key_len, "filledString")) {
+        return fp_decode_String(reader, &value->filled_string) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "emptyString")) {
+        return fp_decode_String(reader, &value->empty_string) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "filledOptionString")) {
+        return fp_decode_FpOption_String(reader, &value->filled_option_string) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "emptyOptionString")) {
+        return fp_decode_FpOption_String(reader, &value->empty_option_string) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "neverSkippedFilledOptionString")) {
+        return fp_decode_FpOption_String(reader, &value->never_skipped_filled_option_string) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "neverSkippedEmptyOptionString")) {
+        return fp_decode_FpOption_String(reader, &value->never_skipped_empty_option_string) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_StructWithOptions[] = {"filledString", "emptyString", "filledOptionString", "emptyOptionString", "neverSkippedFilledOptionString", "neverSkippedEmptyOptionString"};
+
+void fp_encode_StructWithOptions(FpWriter *writer, const StructWithOptions *value) {
+    fp_write_map(writer, fp_count_fields_StructWithOptions(value));
+    fp_encode_fields_StructWithOptions(writer, value);
+}
+
+bool fp_decode_StructWithOptions(FpReader *reader, StructWithOptions *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_StructWithOptions, fp_fields_StructWithOptions, 6)) {
+        fp_free_StructWithOptions(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_StructWithOptions(StructWithOptions *value) {
+    fp_free_String(&value->filled_string);
+    fp_free_String(&value->empty_string);
+    fp_free_FpOption_String(&value->filled_option_string);
+    fp_free_FpOption_String(&value->empty_option_string);
+    fp_free_FpOption_String(&value->never_skipped_filled_option_string);
+    fp_free_FpOption_String(&value->never_skipped_empty_option_string);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpArray_f32_3(FpWriter *writer, const FpArray_f32_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_f32(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_f32_3(FpReader *reader, FpArray_f32_3 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_f32(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_f64_3(FpWriter *writer, const FpArray_f64_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_f64(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_f64_3(FpReader *reader, FpArray_f64_3 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_f64(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_i16_3(FpWriter *writer, const FpArray_i16_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_i16(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_i16_3(FpReader *reader, FpArray_i16_3 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_i16(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_i32_3(FpWriter *writer, const FpArray_i32_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_i32(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_i32_3(FpReader *reader, FpArray_i32_3 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_i32(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_i8_3(FpWriter *writer, const FpArray_i8_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_i8(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_i8_3(FpReader *reader, FpArray_i8_3 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_i8(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_u16_3(FpWriter *writer, const FpArray_u16_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_u16(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_u16_3(FpReader *reader, FpArray_u16_3 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_u16(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_u32_3(FpWriter *writer, const FpArray_u32_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_u32(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_u32_3(FpReader *reader, FpArray_u32_3 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_u32(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_u8_3(FpWriter *writer, const FpArray_u8_3 *value) {
+    fp_write_array(writer, 3);
+    for (uint32_t i = 0; i < 3; i++) {
+        fp_encode_u8(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_u8_3(FpReader *reader, FpArray_u8_3 *value) {
+    uint32_t len;
+    const uint8_t *data;
+    if (fp_read_bin(reader, &data, &len)) {
+        if (len != 3) {
+            return false;
+        }
+        memcpy(value->items, data, len);
+        return true;
+    }
+    if (!fp_read_array(reader, &len) || len != 3) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 3; i++) {
+        if (!fp_decode_u8(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+static uint32_t fp_count_fields_ExplicitBoundPoint_u64(const ExplicitBoundPoint_u64 *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_ExplicitBoundPoint_u64(FpWriter *writer, const ExplicitBoundPoint_u64 *value) {
+    fp_write_cstr(writer, "value");
+    fp_encode_u64(writer, &value->value);
+}
+
+static int fp_decode_field_ExplicitBoundPoint_u64(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    ExplicitBoundPoint_u64 *value = object;
+    if (fp_key_eq(key, key_len, "value")) {
+        return fp_decode_u64(reader, &value->value) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_ExplicitBoundPoint_u64[] = {"value"};
+
+void fp_encode_ExplicitBoundPoint_u64(FpWriter *writer, const ExplicitBoundPoint_u64 *value) {
+    fp_write_map(writer, fp_count_fields_ExplicitBoundPoint_u64(value));
+    fp_encode_fields_ExplicitBoundPoint_u64(writer, value);
+}
+
+bool fp_decode_ExplicitBoundPoint_u64(FpReader *reader, ExplicitBoundPoint_u64 *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_ExplicitBoundPoint_u64, fp_fields_ExplicitBoundPoint_u64, 1);
+}
+
+void fp_encode_FpVec_u64(FpWriter *writer, const FpVec_u64 *value) {
+    fp_write_array(writer, value->len);
+    for (uint32_t i = 0; i < value->len; i++) {
+        fp_encode_u64(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpVec_u64(FpReader *reader, FpVec_u64 *value) {
+    uint32_t len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_array(reader, &len)) {
+        return false;
+    }
+    value->items = fp_alloc(len, sizeof(uint64_t));
+    for (; value->len < len; value->len++) {
+        if (!fp_decode_u64(reader, &value->items[value->len])) {
+            fp_free_FpVec_u64(value);
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_free_FpVec_u64(FpVec_u64 *value) {
+    free(value->items);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpVec_Point_u64(FpWriter *writer, const FpVec_Point_u64 *value) {
+    fp_write_array(writer, value->len);
+    for (uint32_t i = 0; i < value->len; i++) {
+        fp_encode_Point_u64(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpVec_Point_u64(FpReader *reader, FpVec_Point_u64 *value) {
+    uint32_t len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_array(reader, &len)) {
+        return false;
+    }
+    value->items = fp_alloc(len, sizeof(Point_u64));
+    for (; value->len < len; value->len++) {
+        if (!fp_decode_Point_u64(reader, &value->items[value->len])) {
+            fp_free_FpVec_Point_u64(value);
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_free_FpVec_Point_u64(FpVec_Point_u64 *value) {
+    free(value->items);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_Point_u64(const Point_u64 *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_Point_u64(FpWriter *writer, const Point_u64 *value) {
+    fp_write_cstr(writer, "value");
+    fp_encode_u64(writer, &value->value);
+}
+
+static int fp_decode_field_Point_u64(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    Point_u64 *value = object;
+    if (fp_key_eq(key, key_len, "value")) {
+        return fp_decode_u64(reader, &value->value) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_Point_u64[] = {"value"};
+
+void fp_encode_Point_u64(FpWriter *writer, const Point_u64 *value) {
+    fp_write_map(writer, fp_count_fields_Point_u64(value));
+    fp_encode_fields_Point_u64(writer, value);
+}
+
+bool fp_decode_Point_u64(FpReader *reader, Point_u64 *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_Point_u64, fp_fields_Point_u64, 1);
+}
+
+void fp_encode_FpVec_Point_Point_u64(FpWriter *writer, const FpVec_Point_Point_u64 *value) {
+    fp_write_array(writer, value->len);
+    for (uint32_t i = 0; i < value->len; i++) {
+        fp_encode_Point_Point_u64(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpVec_Point_Point_u64(FpReader *reader, FpVec_Point_Point_u64 *value) {
+    uint32_t len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_array(reader, &len)) {
+        return false;
+    }
+    value->items = fp_alloc(len, sizeof(Point_Point_u64));
+    for (; value->len < len; value->len++) {
+        if (!fp_decode_Point_Point_u64(reader, &value->items[value->len])) {
+            fp_free_FpVec_Point_Point_u64(value);
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_free_FpVec_Point_Point_u64(FpVec_Point_Point_u64 *value) {
+    free(value->items);
+    memset(value, 0, sizeof(*value));
+}
+
+static uint32_t fp_count_fields_Point_Point_u64(const Point_Point_u64 *value) {
+    (void)value;
+    return 1;
+}
+
+static void fp_encode_fields_Point_Point_u64(FpWriter *writer, const Point_Point_u64 *value) {
+    fp_write_cstr(writer, "value");
+    fp_encode_Point_u64(writer, &value->value);
+}
+
+static int fp_decode_field_Point_Point_u64(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    Point_Point_u64 *value = object;
+    if (fp_key_eq(key, key_len, "value")) {
+        return fp_decode_Point_u64(reader, &value->value) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_Point_Point_u64[] = {"value"};
+
+void fp_encode_Point_Point_u64(FpWriter *writer, const Point_Point_u64 *value) {
+    fp_write_map(writer, fp_count_fields_Point_Point_u64(value));
+    fp_encode_fields_Point_Point_u64(writer, value);
+}
+
+bool fp_decode_Point_Point_u64(FpReader *reader, Point_Point_u64 *value) {
+    memset(value, 0, sizeof(*value));
+    return fp_decode_struct(reader, value, fp_decode_field_Point_Point_u64, fp_fields_Point_Point_u64, 1);
+}
+
+void fp_encode_FpMap_String_FpVec_FloatingPoint(FpWriter *writer, const FpMap_String_FpVec_FloatingPoint *value) {
+    fp_write_map(writer, value->len);
+    for (uint32_t i = 0; i < value->len; i++) {
+        fp_encode_String(writer, &value->keys[i]);
+        fp_encode_FpVec_FloatingPoint(writer, &value->values[i]);
+    }
+}
+
+bool fp_decode_FpMap_String_FpVec_FloatingPoint(FpReader *reader, FpMap_String_FpVec_FloatingPoint *value) {
+    uint32_t len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_map(reader, &len)) {
+        return false;
+    }
+    value->keys = fp_alloc(len, sizeof(FpString));
+    value->values = fp_alloc(len, sizeof(FpVec_FloatingPoint));
+    for (; value->len < len; value->len++) {
+        if (!fp_decode_String(reader, &value->keys[value->len])) {
+            fp_free_FpMap_String_FpVec_FloatingPoint(value);
+            return false;
+        }
+        if (!fp_decode_FpVec_FloatingPoint(reader, &value->values[value->len])) {
+            fp_free_String(&value->keys[value->len]);
+            fp_free_FpMap_String_FpVec_FloatingPoint(value);
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_free_FpMap_String_FpVec_FloatingPoint(FpMap_String_FpVec_FloatingPoint *value) {
+    for (uint32_t i = 0; i < value->len; i++) {
+        fp_free_String(&value->keys[i]);
+        fp_free_FpVec_FloatingPoint(&value->values[i]);
+    }
+    free(value->keys);
+    free(value->values);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpVec_FloatingPoint(FpWriter *writer, const FpVec_FloatingPoint *value) {
+    fp_write_array(writer, value->len);
+    for (uint32_t i = 0; i < value->len; i++) {
+        fp_encode_Point_f64(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpVec_FloatingPoint(FpReader *reader, FpVec_FloatingPoint *value) {
+    uint32_t len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_array(reader, &len)) {
+        return false;
+    }
+    value->items = fp_alloc(len, sizeof(FloatingPoint));
+    for (; value->len < len; value->len++) {
+        if (!fp_decode_Point_f64(reader, &value->items[value->len])) {
+            fp_free_FpVec_FloatingPoint(value);
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_free_FpVec_FloatingPoint(FpVec_FloatingPoint *value) {
+    free(value->items);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpOption_FpMap_String_FpVec_FloatingPoint(FpWriter *writer, const FpOption_FpMap_String_FpVec_FloatingPoint *value) {
+    if (value->is_some) {
+        fp_encode_FpMap_String_FpVec_FloatingPoint(writer, &value->value);
+    } else {
+        fp_write_nil(writer);
+    }
+}
+
+bool fp_decode_FpOption_FpMap_String_FpVec_FloatingPoint(FpReader *reader, FpOption_FpMap_String_FpVec_FloatingPoint *value) {
+    memset(value, 0, sizeof(*value));
+    if (fp_read_nil(reader)) {
+        return true;
+    }
+    value->is_some = fp_decode_FpMap_String_FpVec_FloatingPoint(reader, &value->value);
+    return value->is_some;
+}
+
+void fp_free_FpOption_FpMap_String_FpVec_FloatingPoint(FpOption_FpMap_String_FpVec_FloatingPoint *value) {
+    if (value->is_some) {
+        fp_free_FpMap_String_FpVec_FloatingPoint(&value->value);
+        value->is_some = false;
+    }
+}
+
+void fp_encode_FpOption_MyDateTime(FpWriter *writer, const FpOption_MyDateTime *value) {
+    if (value->is_some) {
+        fp_encode_String(writer, &value->value);
+    } else {
+        fp_write_nil(writer);
+    }
+}
+
+bool fp_decode_FpOption_MyDateTime(FpReader *reader, FpOption_MyDateTime *value) {
+    memset(value, 0, sizeof(*value));
+    if (fp_read_nil(reader)) {
+        return true;
+    }
+    value->is_some = fp_decode_String(reader, &value->value);
+    return value->is_some;
+}
+
+void fp_free_FpOption_MyDateTime(FpOption_MyDateTime *value) {
+    if (value->is_some) {
+        fp_free_String(&value->value);
+        value->is_some = false;
+    }
+}
+
+static uint32_t fp_count_fields_StructWithGenerics_u64(const StructWithGenerics_u64 *value) {
+    (void)value;
+    return 5;
+}
+
+static void fp_encode_fields_StructWithGenerics_u64(FpWriter *writer, const StructWithGenerics_u64 *value) {
+    fp_write_cstr(writer, "list");
+    fp_encode_FpVec_u64(writer, &value->list);
+    fp_write_cstr(writer, "points");
+    fp_encode_FpVec_Point_u64(writer, &value->points);
+    fp_write_cstr(writer, "recursive");
+    fp_encode_FpVec_Point_Point_u64(writer, &value->recursive);
+    fp_write_cstr(writer, "complex_nested");
+    fp_encode_FpOption_FpMap_String_FpVec_FloatingPoint(writer, &value->complex_nested);
+    fp_write_cstr(writer, "optional_timestamp");
+    fp_encode_FpOption_MyDateTime(writer, &value->optional_timestamp);
+}
+
+static int fp_decode_field_StructWithGenerics_u64(FpReader *reader, void *object, const char *key, uint32_t key_len) {
+    StructWithGenerics_u64 *value = object;
+    if (fp_key_eq(key, key_len, "list")) {
+        return fp_decode_FpVec_u64(reader, &value->list) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "points")) {
+        return fp_decode_FpVec_Point_u64(reader, &value->points) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "recursive")) {
+        return fp_decode_FpVec_Point_Point_u64(reader, &value->recursive) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "complex_nested")) {
+        return fp_decode_FpOption_FpMap_String_FpVec_FloatingPoint(reader, &value->complex_nested) ? 1 : -1;
+    }
+    if (fp_key_eq(key, key_len, "optional_timestamp")) {
+        return fp_decode_FpOption_MyDateTime(reader, &value->optional_timestamp) ? 1 : -1;
+    }
+    return 0;
+}
+
+static const char *const fp_fields_StructWithGenerics_u64[] = {"list", "points", "recursive", "complex_nested", "optional_timestamp"};
+
+void fp_encode_StructWithGenerics_u64(FpWriter *writer, const StructWithGenerics_u64 *value) {
+    fp_write_map(writer, fp_count_fields_StructWithGenerics_u64(value));
+    fp_encode_fields_StructWithGenerics_u64(writer, value);
+}
+
+bool fp_decode_StructWithGenerics_u64(FpReader *reader, StructWithGenerics_u64 *value) {
+    memset(value, 0, sizeof(*value));
+    if (!fp_decode_struct(reader, value, fp_decode_field_StructWithGenerics_u64, fp_fields_StructWithGenerics_u64, 5)) {
+        fp_free_StructWithGenerics_u64(value);
+        return false;
+    }
+    return true;
+}
+
+void fp_free_StructWithGenerics_u64(StructWithGenerics_u64 *value) {
+    fp_free_FpVec_u64(&value->list);
+    fp_free_FpVec_Point_u64(&value->points);
+    fp_free_FpVec_Point_Point_u64(&value->recursive);
+    fp_free_FpOption_FpMap_String_FpVec_FloatingPoint(&value->complex_nested);
+    fp_free_FpOption_MyDateTime(&value->optional_timestamp);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_Result_Bytes_String(FpWriter *writer, const Result_Bytes_String *value) {
+    switch (value->tag) {
+    case Result_Bytes_String_Ok:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Ok");
+        fp_encode_Bytes(writer, &value->ok);
+        break;
+    case Result_Bytes_String_Err:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Err");
+        fp_encode_String(writer, &value->err);
+        break;
+    }
+}
+
+bool fp_decode_Result_Bytes_String(FpReader *reader, Result_Bytes_String *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_variant(reader, &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Ok")) {
+        value->tag = Result_Bytes_String_Ok;
+        return fp_decode_Bytes(&content, &value->ok);
+    }
+    if (fp_key_eq(name, name_len, "Err")) {
+        value->tag = Result_Bytes_String_Err;
+        return fp_decode_String(&content, &value->err);
+    }
+    return false;
+}
+
+void fp_free_Result_Bytes_String(Result_Bytes_String *value) {
+    switch (value->tag) {
+    case Result_Bytes_String_Ok:
+        fp_free_Bytes(&value->ok);
+        break;
+    case Result_Bytes_String_Err:
+        fp_free_String(&value->err);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_FpArray_f32_1(FpWriter *writer, const FpArray_f32_1 *value) {
+    fp_write_array(writer, 1);
+    for (uint32_t i = 0; i < 1; i++) {
+        fp_encode_f32(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_f32_1(FpReader *reader, FpArray_f32_1 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 1) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 1; i++) {
+        if (!fp_decode_f32(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_FpArray_f64_1(FpWriter *writer, const FpArray_f64_1 *value) {
+    fp_write_array(writer, 1);
+    for (uint32_t i = 0; i < 1; i++) {
+        fp_encode_f64(writer, &value->items[i]);
+    }
+}
+
+bool fp_decode_FpArray_f64_1(FpReader *reader, FpArray_f64_1 *value) {
+    uint32_t len;
+    if (!fp_read_array(reader, &len) || len != 1) {
+        return false;
+    }
+    for (uint32_t i = 0; i < 1; i++) {
+        if (!fp_decode_f64(reader, &value->items[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+void fp_encode_Result_Unit_u32(FpWriter *writer, const Result_Unit_u32 *value) {
+    switch (value->tag) {
+    case Result_Unit_u32_Ok:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Ok");
+        fp_encode_Unit(writer, &value->ok);
+        break;
+    case Result_Unit_u32_Err:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Err");
+        fp_encode_u32(writer, &value->err);
+        break;
+    }
+}
+
+bool fp_decode_Result_Unit_u32(FpReader *reader, Result_Unit_u32 *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_variant(reader, &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Ok")) {
+        value->tag = Result_Unit_u32_Ok;
+        return fp_decode_Unit(&content, &value->ok);
+    }
+    if (fp_key_eq(name, name_len, "Err")) {
+        value->tag = Result_Unit_u32_Err;
+        return fp_decode_u32(&content, &value->err);
+    }
+    return false;
+}
+
+void fp_encode_Result_String_String(FpWriter *writer, const Result_String_String *value) {
+    switch (value->tag) {
+    case Result_String_String_Ok:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Ok");
+        fp_encode_String(writer, &value->ok);
+        break;
+    case Result_String_String_Err:
+        fp_write_map(writer, 1);
+        fp_write_cstr(writer, "Err");
+        fp_encode_String(writer, &value->err);
+        break;
+    }
+}
+
+bool fp_decode_Result_String_String(FpReader *reader, Result_String_String *value) {
+    const char *name;
+    uint32_t name_len;
+    FpReader content;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_variant(reader, &name, &name_len, &content)) {
+        return false;
+    }
+    if (fp_key_eq(name, name_len, "Ok")) {
+        value->tag = Result_String_String_Ok;
+        return fp_decode_String(&content, &value->ok);
+    }
+    if (fp_key_eq(name, name_len, "Err")) {
+        value->tag = Result_String_String_Err;
+        return fp_decode_String(&content, &value->err);
+    }
+    return false;
+}
+
+void fp_free_Result_String_String(Result_String_String *value) {
+    switch (value->tag) {
+    case Result_String_String_Ok:
+        fp_free_String(&value->ok);
+        break;
+    case Result_String_String_Err:
+        fp_free_String(&value->err);
+        break;
+    default:
+        break;
+    }
+    memset(value, 0, sizeof(*value));
+}
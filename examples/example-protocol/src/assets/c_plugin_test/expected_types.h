@@ -0,0 +1,922 @@
+// ============================================= //
+// Types for WebAssembly plugins in C            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#ifndef FP_TYPES_H
+#define FP_TYPES_H
+
+#include "fp_support.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct DocExampleEnumVariant2 DocExampleEnumVariant2;
+typedef struct DocExampleEnum DocExampleEnum;
+typedef struct DocExampleStruct DocExampleStruct;
+typedef struct ExplicitedlyImportedType ExplicitedlyImportedType;
+typedef struct FlattenedStruct FlattenedStruct;
+typedef struct Point_f64 Point_f64;
+typedef struct FpAdjacentlyTaggedBaz FpAdjacentlyTaggedBaz;
+typedef struct FpAdjacentlyTagged FpAdjacentlyTagged;
+typedef struct FpFlatten FpFlatten;
+typedef struct FpInternallyTaggedBaz FpInternallyTaggedBaz;
+typedef struct FpInternallyTagged FpInternallyTagged;
+typedef struct FpPropertyRenaming FpPropertyRenaming;
+typedef struct FpUntaggedBaz FpUntaggedBaz;
+typedef struct FpUntagged FpUntagged;
+typedef struct FpVariantRenamingQuxBaz FpVariantRenamingQuxBaz;
+typedef struct FpVariantRenaming FpVariantRenaming;
+typedef struct GroupImportedType1 GroupImportedType1;
+typedef struct GroupImportedType2 GroupImportedType2;
+typedef struct Response Response;
+typedef struct RequestErrorServerError RequestErrorServerError;
+typedef struct RequestErrorOther RequestErrorOther;
+typedef struct RequestError RequestError;
+typedef struct Result_Response_RequestError Result_Response_RequestError;
+typedef struct ReduxActionUpdateTitle ReduxActionUpdateTitle;
+typedef struct ReduxAction ReduxAction;
+typedef struct FpOption_Body FpOption_Body;
+typedef struct Request Request;
+typedef struct SerdeAdjacentlyTaggedBaz SerdeAdjacentlyTaggedBaz;
+typedef struct SerdeAdjacentlyTagged SerdeAdjacentlyTagged;
+typedef struct SerdeFlatten SerdeFlatten;
+typedef struct SerdeInternallyTaggedBaz SerdeInternallyTaggedBaz;
+typedef struct SerdeInternallyTagged SerdeInternallyTagged;
+typedef struct SerdePropertyRenaming SerdePropertyRenaming;
+typedef struct SerdeUntaggedBaz SerdeUntaggedBaz;
+typedef struct SerdeUntagged SerdeUntagged;
+typedef struct SerdeVariantRenamingQuxBaz SerdeVariantRenamingQuxBaz;
+typedef struct SerdeVariantRenaming SerdeVariantRenaming;
+typedef struct FpOption_FpBox_String FpOption_FpBox_String;
+typedef struct FpOption_u16 FpOption_u16;
+typedef struct StateUpdate StateUpdate;
+typedef struct FpOption_String FpOption_String;
+typedef struct StructWithOptions StructWithOptions;
+typedef struct FpArray_f32_3 FpArray_f32_3;
+typedef struct FpArray_f64_3 FpArray_f64_3;
+typedef struct FpArray_i16_3 FpArray_i16_3;
+typedef struct FpArray_i32_3 FpArray_i32_3;
+typedef struct FpArray_i8_3 FpArray_i8_3;
+typedef struct FpArray_u16_3 FpArray_u16_3;
+typedef struct FpArray_u32_3 FpArray_u32_3;
+typedef struct FpArray_u8_3 FpArray_u8_3;
+typedef struct ExplicitBoundPoint_u64 ExplicitBoundPoint_u64;
+typedef struct FpVec_u64 FpVec_u64;
+typedef struct FpVec_Point_u64 FpVec_Point_u64;
+typedef struct Point_u64 Point_u64;
+typedef struct FpVec_Point_Point_u64 FpVec_Point_Point_u64;
+typedef struct Point_Point_u64 Point_Point_u64;
+typedef struct FpMap_String_FpVec_FloatingPoint FpMap_String_FpVec_FloatingPoint;
+typedef struct FpVec_FloatingPoint FpVec_FloatingPoint;
+typedef struct FpOption_FpMap_String_FpVec_FloatingPoint FpOption_FpMap_String_FpVec_FloatingPoint;
+typedef struct FpOption_MyDateTime FpOption_MyDateTime;
+typedef struct StructWithGenerics_u64 StructWithGenerics_u64;
+typedef struct Result_Bytes_String Result_Bytes_String;
+typedef struct FpArray_f32_1 FpArray_f32_1;
+typedef struct FpArray_f64_1 FpArray_f64_1;
+typedef struct Result_Unit_u32 Result_Unit_u32;
+typedef struct Result_String_String Result_String_String;
+
+typedef FpBytes Body;
+
+typedef Point_f64 FloatingPoint;
+
+typedef Result_Response_RequestError HttpResult;
+
+typedef uint64_t Int64;
+
+/**
+ * Our struct for passing date time instances.
+ *
+ * We wrap the `OffsetDateTime` type in a new struct so that the Serde
+ * attributes can be inserted. These are necessary to enable RFC3339
+ * formatting. Without a wrapper type like this, we would not be able to pass
+ * date time instances directly to function arguments and we might run into
+ * trouble embedding them into certain generic types.
+ */
+typedef FpString MyDateTime;
+
+struct DocExampleEnumVariant2 {
+    /**
+     * Variant property.
+     */
+    int8_t inner;
+};
+
+typedef enum DocExampleEnumTag {
+    DocExampleEnum_Variant1,
+    DocExampleEnum_Variant2,
+} DocExampleEnumTag;
+
+/**
+ * # This is an enum with doc comments.
+ */
+struct DocExampleEnum {
+    DocExampleEnumTag tag;
+    union {
+        /**
+         * Multi-line doc comment with complex characters
+         * & " , \ ! '
+         */
+        FpString variant_1;
+        /**
+         * Raw identifiers are supported too.
+         */
+        DocExampleEnumVariant2 variant_2;
+    };
+};
+
+/**
+ * # This is a struct with doc comments.
+ */
+struct DocExampleStruct {
+    /**
+     * Multi-line doc comment with complex characters
+     * & " , \ ! '
+     */
+    FpString multi_line;
+    /**
+     * Raw identifiers are supported too.
+     */
+    FpString type;
+};
+
+/**
+ * This struct is also not referenced by any function or data structure, but
+ * it will show up because there is an explicit `use` statement for it in the
+ * `fp_import!` macro.
+ */
+struct ExplicitedlyImportedType {
+    bool you_will_see_this;
+};
+
+struct FlattenedStruct {
+    FpString foo;
+    int64_t bar;
+};
+
+/**
+ * A point of an arbitrary type.
+ */
+struct Point_f64 {
+    double value;
+};
+
+struct FpAdjacentlyTaggedBaz {
+    int8_t a;
+    uint64_t b;
+};
+
+typedef enum FpAdjacentlyTaggedTag {
+    FpAdjacentlyTagged_Foo,
+    FpAdjacentlyTagged_Bar,
+    FpAdjacentlyTagged_Baz,
+} FpAdjacentlyTaggedTag;
+
+struct FpAdjacentlyTagged {
+    FpAdjacentlyTaggedTag tag;
+    union {
+        FpString bar;
+        FpAdjacentlyTaggedBaz baz;
+    };
+};
+
+struct FpFlatten {
+    FlattenedStruct flattened;
+};
+
+struct FpInternallyTaggedBaz {
+    int8_t a;
+    uint64_t b;
+};
+
+typedef enum FpInternallyTaggedTag {
+    FpInternallyTagged_Foo,
+    FpInternallyTagged_Baz,
+} FpInternallyTaggedTag;
+
+struct FpInternallyTagged {
+    FpInternallyTaggedTag tag;
+    union {
+        FpInternallyTaggedBaz baz;
+    };
+};
+
+struct FpPropertyRenaming {
+    FpString foo_bar;
+    double qux_baz;
+    int32_t raw_struct;
+};
+
+struct FpUntaggedBaz {
+    int8_t a;
+    uint64_t b;
+};
+
+typedef enum FpUntaggedTag {
+    FpUntagged_Bar,
+    FpUntagged_Baz,
+} FpUntaggedTag;
+
+struct FpUntagged {
+    FpUntaggedTag tag;
+    union {
+        FpString bar;
+        FpUntaggedBaz baz;
+    };
+};
+
+struct FpVariantRenamingQuxBaz {
+    /**
+     * Will be renamed to "FOO_BAR" because of the `rename_all` on the
+     * variant.
+     */
+    FpString foo_bar;
+    double qux_baz;
+};
+
+typedef enum FpVariantRenamingTag {
+    FpVariantRenaming_FooBar,
+    FpVariantRenaming_QuxBaz,
+} FpVariantRenamingTag;
+
+struct FpVariantRenaming {
+    FpVariantRenamingTag tag;
+    union {
+        FpVariantRenamingQuxBaz qux_baz;
+    };
+};
+
+struct GroupImportedType1 {
+    bool you_will_see_this;
+};
+
+struct GroupImportedType2 {
+    bool you_will_see_this;
+};
+
+/**
+ * Represents an HTTP response we received.
+ *
+ * Please note we currently do not support streaming responses.
+ */
+struct Response {
+    /**
+     * The response body. May be empty.
+     */
+    Body body;
+    /**
+     * HTTP headers that were part of the response.
+     */
+    FpValue headers;
+    /**
+     * HTTP status code.
+     */
+    uint16_t status_code;
+};
+
+struct RequestErrorServerError {
+    /**
+     * HTTP status code.
+     */
+    uint16_t status_code;
+    /**
+     * Response body.
+     */
+    Body response;
+};
+
+struct RequestErrorOther {
+    FpString reason;
+};
+
+typedef enum RequestErrorTag {
+    RequestError_Offline,
+    RequestError_NoRoute,
+    RequestError_ConnectionRefused,
+    RequestError_Timeout,
+    RequestError_ServerError,
+    RequestError_Other,
+} RequestErrorTag;
+
+/**
+ * Represents an error that occurred while attempting to submit the request.
+ */
+struct RequestError {
+    RequestErrorTag tag;
+    union {
+        RequestErrorServerError server_error;
+        /**
+         * Misc.
+         */
+        RequestErrorOther other;
+    };
+};
+
+typedef enum Result_Response_RequestErrorTag {
+    Result_Response_RequestError_Ok,
+    Result_Response_RequestError_Err,
+} Result_Response_RequestErrorTag;
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+struct Result_Response_RequestError {
+    Result_Response_RequestErrorTag tag;
+    union {
+        /**
+         * Represents a successful result.
+         */
+        Response ok;
+        /**
+         * Represents an error.
+         */
+        RequestError err;
+    };
+};
+
+struct ReduxActionUpdateTitle {
+    FpString title;
+};
+
+typedef enum ReduxActionTag {
+    ReduxAction_ClearTitle,
+    ReduxAction_UpdateTitle,
+} ReduxActionTag;
+
+/**
+ * Example for representing Redux actions.
+ */
+struct ReduxAction {
+    ReduxActionTag tag;
+    union {
+        ReduxActionUpdateTitle update_title;
+    };
+};
+
+struct FpOption_Body {
+    bool is_some;
+    Body value;
+};
+
+/**
+ * Represents an HTTP request to be sent.
+ */
+struct Request {
+    /**
+     * The URI to submit the request to.
+     */
+    FpString url;
+    /**
+     * HTTP method to use for the request.
+     */
+    FpValue method;
+    /**
+     * HTTP headers to submit with the request.
+     */
+    FpValue headers;
+    /**
+     * The body to submit with the request.
+     */
+    FpOption_Body body;
+};
+
+struct SerdeAdjacentlyTaggedBaz {
+    int8_t a;
+    uint64_t b;
+};
+
+typedef enum SerdeAdjacentlyTaggedTag {
+    SerdeAdjacentlyTagged_Foo,
+    SerdeAdjacentlyTagged_Bar,
+    SerdeAdjacentlyTagged_Baz,
+} SerdeAdjacentlyTaggedTag;
+
+struct SerdeAdjacentlyTagged {
+    SerdeAdjacentlyTaggedTag tag;
+    union {
+        FpString bar;
+        SerdeAdjacentlyTaggedBaz baz;
+    };
+};
+
+struct SerdeFlatten {
+    FlattenedStruct flattened;
+};
+
+struct SerdeInternallyTaggedBaz {
+    int8_t a;
+    uint64_t b;
+};
+
+typedef enum SerdeInternallyTaggedTag {
+    SerdeInternallyTagged_Foo,
+    SerdeInternallyTagged_Baz,
+} SerdeInternallyTaggedTag;
+
+struct SerdeInternallyTagged {
+    SerdeInternallyTaggedTag tag;
+    union {
+        SerdeInternallyTaggedBaz baz;
+    };
+};
+
+struct SerdePropertyRenaming {
+    FpString foo_bar;
+    double qux_baz;
+    int32_t raw_struct;
+};
+
+struct SerdeUntaggedBaz {
+    int8_t a;
+    uint64_t b;
+};
+
+typedef enum SerdeUntaggedTag {
+    SerdeUntagged_Bar,
+    SerdeUntagged_Baz,
+} SerdeUntaggedTag;
+
+struct SerdeUntagged {
+    SerdeUntaggedTag tag;
+    union {
+        FpString bar;
+        SerdeUntaggedBaz baz;
+    };
+};
+
+struct SerdeVariantRenamingQuxBaz {
+    /**
+     * Will be renamed to "FooBar" because of the `rename_all` on the
+     * variant.
+     */
+    FpString foo_bar;
+    double qux_baz;
+};
+
+typedef enum SerdeVariantRenamingTag {
+    SerdeVariantRenaming_FooBar,
+    SerdeVariantRenaming_QuxBaz,
+} SerdeVariantRenamingTag;
+
+struct SerdeVariantRenaming {
+    SerdeVariantRenamingTag tag;
+    union {
+        SerdeVariantRenamingQuxBaz qux_baz;
+    };
+};
+
+struct FpOption_FpBox_String {
+    bool is_some;
+    FpString *value;
+};
+
+struct FpOption_u16 {
+    bool is_some;
+    uint16_t value;
+};
+
+/**
+ * A state update to communicate to the Redux host.
+ *
+ * Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+ * changed.
+ */
+struct StateUpdate {
+    FpOption_FpBox_String title;
+    FpOption_u16 revision;
+};
+
+struct FpOption_String {
+    bool is_some;
+    FpString value;
+};
+
+struct StructWithOptions {
+    FpString filled_string;
+    FpString empty_string;
+    FpOption_String filled_option_string;
+    FpOption_String empty_option_string;
+    FpOption_String never_skipped_filled_option_string;
+    FpOption_String never_skipped_empty_option_string;
+};
+
+struct FpArray_f32_3 {
+    float items[3];
+};
+
+struct FpArray_f64_3 {
+    double items[3];
+};
+
+struct FpArray_i16_3 {
+    int16_t items[3];
+};
+
+struct FpArray_i32_3 {
+    int32_t items[3];
+};
+
+struct FpArray_i8_3 {
+    int8_t items[3];
+};
+
+struct FpArray_u16_3 {
+    uint16_t items[3];
+};
+
+struct FpArray_u32_3 {
+    uint32_t items[3];
+};
+
+struct FpArray_u8_3 {
+    uint8_t items[3];
+};
+
+/**
+ * A point of an arbitrary type, with explicit trait bounds.
+ */
+struct ExplicitBoundPoint_u64 {
+    uint64_t value;
+};
+
+struct FpVec_u64 {
+    uint64_t *items;
+    uint32_t len;
+};
+
+struct FpVec_Point_u64 {
+    Point_u64 *items;
+    uint32_t len;
+};
+
+/**
+ * A point of an arbitrary type.
+ */
+struct Point_u64 {
+    uint64_t value;
+};
+
+struct FpVec_Point_Point_u64 {
+    Point_Point_u64 *items;
+    uint32_t len;
+};
+
+/**
+ * A point of an arbitrary type.
+ */
+struct Point_Point_u64 {
+    Point_u64 value;
+};
+
+struct FpMap_String_FpVec_FloatingPoint {
+    FpString *keys;
+    FpVec_FloatingPoint *values;
+    uint32_t len;
+};
+
+struct FpVec_FloatingPoint {
+    FloatingPoint *items;
+    uint32_t len;
+};
+
+struct FpOption_FpMap_String_FpVec_FloatingPoint {
+    bool is_some;
+    FpMap_String_FpVec_FloatingPoint value;
+};
+
+struct FpOption_MyDateTime {
+    bool is_some;
+    MyDateTime value;
+};
+
+struct StructWithGenerics_u64 {
+    FpVec_u64 list;
+    FpVec_Point_u64 points;
+    FpVec_Point_Point_u64 recursive;
+    FpOption_FpMap_String_FpVec_FloatingPoint complex_nested;
+    FpOption_MyDateTime optional_timestamp;
+};
+
+typedef enum Result_Bytes_StringTag {
+    Result_Bytes_String_Ok,
+    Result_Bytes_String_Err,
+} Result_Bytes_StringTag;
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+struct Result_Bytes_String {
+    Result_Bytes_StringTag tag;
+    union {
+        /**
+         * Represents a successful result.
+         */
+        FpBytes ok;
+        /**
+         * Represents an error.
+         */
+        FpString err;
+    };
+};
+
+struct FpArray_f32_1 {
+    float items[1];
+};
+
+struct FpArray_f64_1 {
+    double items[1];
+};
+
+typedef enum Result_Unit_u32Tag {
+    Result_Unit_u32_Ok,
+    Result_Unit_u32_Err,
+} Result_Unit_u32Tag;
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+struct Result_Unit_u32 {
+    Result_Unit_u32Tag tag;
+    union {
+        /**
+         * Represents a successful result.
+         */
+        FpUnit ok;
+        /**
+         * Represents an error.
+         */
+        uint32_t err;
+    };
+};
+
+typedef enum Result_String_StringTag {
+    Result_String_String_Ok,
+    Result_String_String_Err,
+} Result_String_StringTag;
+
+/**
+ * A result that can be either successful (`Ok`) or represent an error (`Err`).
+ */
+struct Result_String_String {
+    Result_String_StringTag tag;
+    union {
+        /**
+         * Represents a successful result.
+         */
+        FpString ok;
+        /**
+         * Represents an error.
+         */
+        FpString err;
+    };
+};
+
+void fp_encode_DocExampleEnumVariant2(FpWriter *writer, const DocExampleEnumVariant2 *value);
+bool fp_decode_DocExampleEnumVariant2(FpReader *reader, DocExampleEnumVariant2 *value);
+
+void fp_encode_DocExampleEnum(FpWriter *writer, const DocExampleEnum *value);
+bool fp_decode_DocExampleEnum(FpReader *reader, DocExampleEnum *value);
+void fp_free_DocExampleEnum(DocExampleEnum *value);
+
+void fp_encode_DocExampleStruct(FpWriter *writer, const DocExampleStruct *value);
+bool fp_decode_DocExampleStruct(FpReader *reader, DocExampleStruct *value);
+void fp_free_DocExampleStruct(DocExampleStruct *value);
+
+void fp_encode_ExplicitedlyImportedType(FpWriter *writer, const ExplicitedlyImportedType *value);
+bool fp_decode_ExplicitedlyImportedType(FpReader *reader, ExplicitedlyImportedType *value);
+
+void fp_encode_FlattenedStruct(FpWriter *writer, const FlattenedStruct *value);
+bool fp_decode_FlattenedStruct(FpReader *reader, FlattenedStruct *value);
+void fp_free_FlattenedStruct(FlattenedStruct *value);
+
+void fp_encode_Point_f64(FpWriter *writer, const Point_f64 *value);
+bool fp_decode_Point_f64(FpReader *reader, Point_f64 *value);
+
+void fp_encode_FpAdjacentlyTaggedBaz(FpWriter *writer, const FpAdjacentlyTaggedBaz *value);
+bool fp_decode_FpAdjacentlyTaggedBaz(FpReader *reader, FpAdjacentlyTaggedBaz *value);
+
+void fp_encode_FpAdjacentlyTagged(FpWriter *writer, const FpAdjacentlyTagged *value);
+bool fp_decode_FpAdjacentlyTagged(FpReader *reader, FpAdjacentlyTagged *value);
+void fp_free_FpAdjacentlyTagged(FpAdjacentlyTagged *value);
+
+void fp_encode_FpFlatten(FpWriter *writer, const FpFlatten *value);
+bool fp_decode_FpFlatten(FpReader *reader, FpFlatten *value);
+void fp_free_FpFlatten(FpFlatten *value);
+
+void fp_encode_FpInternallyTaggedBaz(FpWriter *writer, const FpInternallyTaggedBaz *value);
+bool fp_decode_FpInternallyTaggedBaz(FpReader *reader, FpInternallyTaggedBaz *value);
+
+void fp_encode_FpInternallyTagged(FpWriter *writer, const FpInternallyTagged *value);
+bool fp_decode_FpInternallyTagged(FpReader *reader, FpInternallyTagged *value);
+
+void fp_encode_FpPropertyRenaming(FpWriter *writer, const FpPropertyRenaming *value);
+bool fp_decode_FpPropertyRenaming(FpReader *reader, FpPropertyRenaming *value);
+void fp_free_FpPropertyRenaming(FpPropertyRenaming *value);
+
+void fp_encode_FpUntaggedBaz(FpWriter *writer, const FpUntaggedBaz *value);
+bool fp_decode_FpUntaggedBaz(FpReader *reader, FpUntaggedBaz *value);
+
+void fp_encode_FpUntagged(FpWriter *writer, const FpUntagged *value);
+bool fp_decode_FpUntagged(FpReader *reader, FpUntagged *value);
+void fp_free_FpUntagged(FpUntagged *value);
+
+void fp_encode_FpVariantRenamingQuxBaz(FpWriter *writer, const FpVariantRenamingQuxBaz *value);
+bool fp_decode_FpVariantRenamingQuxBaz(FpReader *reader, FpVariantRenamingQuxBaz *value);
+void fp_free_FpVariantRenamingQuxBaz(FpVariantRenamingQuxBaz *value);
+
+void fp_encode_FpVariantRenaming(FpWriter *writer, const FpVariantRenaming *value);
+bool fp_decode_FpVariantRenaming(FpReader *reader, FpVariantRenaming *value);
+void fp_free_FpVariantRenaming(FpVariantRenaming *value);
+
+void fp_encode_GroupImportedType1(FpWriter *writer, const GroupImportedType1 *value);
+bool fp_decode_GroupImportedType1(FpReader *reader, GroupImportedType1 *value);
+
+void fp_encode_GroupImportedType2(FpWriter *writer, const GroupImportedType2 *value);
+bool fp_decode_GroupImportedType2(FpReader *reader, GroupImportedType2 *value);
+
+void fp_encode_Response(FpWriter *writer, const Response *value);
+bool fp_decode_Response(FpReader *reader, Response *value);
+void fp_free_Response(Response *value);
+
+void fp_encode_RequestErrorServerError(FpWriter *writer, const RequestErrorServerError *value);
+bool fp_decode_RequestErrorServerError(FpReader *reader, RequestErrorServerError *value);
+void fp_free_RequestErrorServerError(RequestErrorServerError *value);
+
+void fp_encode_RequestErrorOther(FpWriter *writer, const RequestErrorOther *value);
+bool fp_decode_RequestErrorOther(FpReader *reader, RequestErrorOther *value);
+void fp_free_RequestErrorOther(RequestErrorOther *value);
+
+void fp_encode_RequestError(FpWriter *writer, const RequestError *value);
+bool fp_decode_RequestError(FpReader *reader, RequestError *value);
+void fp_free_RequestError(RequestError *value);
+
+void fp_encode_Result_Response_RequestError(FpWriter *writer, const Result_Response_RequestError *value);
+bool fp_decode_Result_Response_RequestError(FpReader *reader, Result_Response_RequestError *value);
+void fp_free_Result_Response_RequestError(Result_Response_RequestError *value);
+
+void fp_encode_ReduxActionUpdateTitle(FpWriter *writer, const ReduxActionUpdateTitle *value);
+bool fp_decode_ReduxActionUpdateTitle(FpReader *reader, ReduxActionUpdateTitle *value);
+void fp_free_ReduxActionUpdateTitle(ReduxActionUpdateTitle *value);
+
+void fp_encode_ReduxAction(FpWriter *writer, const ReduxAction *value);
+bool fp_decode_ReduxAction(FpReader *reader, ReduxAction *value);
+void fp_free_ReduxAction(ReduxAction *value);
+
+void fp_encode_FpOption_Body(FpWriter *writer, const FpOption_Body *value);
+bool fp_decode_FpOption_Body(FpReader *reader, FpOption_Body *value);
+void fp_free_FpOption_Body(FpOption_Body *value);
+
+void fp_encode_Request(FpWriter *writer, const Request *value);
+bool fp_decode_Request(FpReader *reader, Request *value);
+void fp_free_Request(Request *value);
+
+void fp_encode_SerdeAdjacentlyTaggedBaz(FpWriter *writer, const SerdeAdjacentlyTaggedBaz *value);
+bool fp_decode_SerdeAdjacentlyTaggedBaz(FpReader *reader, SerdeAdjacentlyTaggedBaz *value);
+
+void fp_encode_SerdeAdjacentlyTagged(FpWriter *writer, const SerdeAdjacentlyTagged *value);
+bool fp_decode_SerdeAdjacentlyTagged(FpReader *reader, SerdeAdjacentlyTagged *value);
+void fp_free_SerdeAdjacentlyTagged(SerdeAdjacentlyTagged *value);
+
+void fp_encode_SerdeFlatten(FpWriter *writer, const SerdeFlatten *value);
+bool fp_decode_SerdeFlatten(FpReader *reader, SerdeFlatten *value);
+void fp_free_SerdeFlatten(SerdeFlatten *value);
+
+void fp_encode_SerdeInternallyTaggedBaz(FpWriter *writer, const SerdeInternallyTaggedBaz *value);
+bool fp_decode_SerdeInternallyTaggedBaz(FpReader *reader, SerdeInternallyTaggedBaz *value);
+
+void fp_encode_SerdeInternallyTagged(FpWriter *writer, const SerdeInternallyTagged *value);
+bool fp_decode_SerdeInternallyTagged(FpReader *reader, SerdeInternallyTagged *value);
+
+void fp_encode_SerdePropertyRenaming(FpWriter *writer, const SerdePropertyRenaming *value);
+bool fp_decode_SerdePropertyRenaming(FpReader *reader, SerdePropertyRenaming *value);
+void fp_free_SerdePropertyRenaming(SerdePropertyRenaming *value);
+
+void fp_encode_SerdeUntaggedBaz(FpWriter *writer, const SerdeUntaggedBaz *value);
+bool fp_decode_SerdeUntaggedBaz(FpReader *reader, SerdeUntaggedBaz *value);
+
+void fp_encode_SerdeUntagged(FpWriter *writer, const SerdeUntagged *value);
+bool fp_decode_SerdeUntagged(FpReader *reader, SerdeUntagged *value);
+void fp_free_SerdeUntagged(SerdeUntagged *value);
+
+void fp_encode_SerdeVariantRenamingQuxBaz(FpWriter *writer, const SerdeVariantRenamingQuxBaz *value);
+bool fp_decode_SerdeVariantRenamingQuxBaz(FpReader *reader, SerdeVariantRenamingQuxBaz *value);
+void fp_free_SerdeVariantRenamingQuxBaz(SerdeVariantRenamingQuxBaz *value);
+
+void fp_encode_SerdeVariantRenaming(FpWriter *writer, const SerdeVariantRenaming *value);
+bool fp_decode_SerdeVariantRenaming(FpReader *reader, SerdeVariantRenaming *value);
+void fp_free_SerdeVariantRenaming(SerdeVariantRenaming *value);
+
+void fp_encode_FpBox_String(FpWriter *writer, FpString *const *value);
+bool fp_decode_FpBox_String(FpReader *reader, FpString **value);
+void fp_free_FpBox_String(FpString **value);
+
+void fp_encode_FpOption_FpBox_String(FpWriter *writer, const FpOption_FpBox_String *value);
+bool fp_decode_FpOption_FpBox_String(FpReader *reader, FpOption_FpBox_String *value);
+void fp_free_FpOption_FpBox_String(FpOption_FpBox_String *value);
+
+void fp_encode_FpOption_u16(FpWriter *writer, const FpOption_u16 *value);
+bool fp_decode_FpOption_u16(FpReader *reader, FpOption_u16 *value);
+
+void fp_encode_StateUpdate(FpWriter *writer, const StateUpdate *value);
+bool fp_decode_StateUpdate(FpReader *reader, StateUpdate *value);
+void fp_free_StateUpdate(StateUpdate *value);
+
+void fp_encode_FpOption_String(FpWriter *writer, const FpOption_String *value);
+bool fp_decode_FpOption_String(FpReader *reader, FpOption_String *value);
+void fp_free_FpOption_String(FpOption_String *value);
+
+void fp_encode_StructWithOptions(FpWriter *writer, const StructWithOptions *value);
+bool fp_decode_StructWithOptions(FpReader *reader, StructWithOptions *value);
+void fp_free_StructWithOptions(StructWithOptions *value);
+
+void fp_encode_FpArray_f32_3(FpWriter *writer, const FpArray_f32_3 *value);
+bool fp_decode_FpArray_f32_3(FpReader *reader, FpArray_f32_3 *value);
+
+void fp_encode_FpArray_f64_3(FpWriter *writer, const FpArray_f64_3 *value);
+bool fp_decode_FpArray_f64_3(FpReader *reader, FpArray_f64_3 *value);
+
+void fp_encode_FpArray_i16_3(FpWriter *writer, const FpArray_i16_3 *value);
+bool fp_decode_FpArray_i16_3(FpReader *reader, FpArray_i16_3 *value);
+
+void fp_encode_FpArray_i32_3(FpWriter *writer, const FpArray_i32_3 *value);
+bool fp_decode_FpArray_i32_3(FpReader *reader, FpArray_i32_3 *value);
+
+void fp_encode_FpArray_i8_3(FpWriter *writer, const FpArray_i8_3 *value);
+bool fp_decode_FpArray_i8_3(FpReader *reader, FpArray_i8_3 *value);
+
+void fp_encode_FpArray_u16_3(FpWriter *writer, const FpArray_u16_3 *value);
+bool fp_decode_FpArray_u16_3(FpReader *reader, FpArray_u16_3 *value);
+
+void fp_encode_FpArray_u32_3(FpWriter *writer, const FpArray_u32_3 *value);
+bool fp_decode_FpArray_u32_3(FpReader *reader, FpArray_u32_3 *value);
+
+void fp_encode_FpArray_u8_3(FpWriter *writer, const FpArray_u8_3 *value);
+bool fp_decode_FpArray_u8_3(FpReader *reader, FpArray_u8_3 *value);
+
+void fp_encode_ExplicitBoundPoint_u64(FpWriter *writer, const ExplicitBoundPoint_u64 *value);
+bool fp_decode_ExplicitBoundPoint_u64(FpReader *reader, ExplicitBoundPoint_u64 *value);
+
+void fp_encode_FpVec_u64(FpWriter *writer, const FpVec_u64 *value);
+bool fp_decode_FpVec_u64(FpReader *reader, FpVec_u64 *value);
+void fp_free_FpVec_u64(FpVec_u64 *value);
+
+void fp_encode_FpVec_Point_u64(FpWriter *writer, const FpVec_Point_u64 *value);
+bool fp_decode_FpVec_Point_u64(FpReader *reader, FpVec_Point_u64 *value);
+void fp_free_FpVec_Point_u64(FpVec_Point_u64 *value);
+
+void fp_encode_Point_u64(FpWriter *writer, const Point_u64 *value);
+bool fp_decode_Point_u64(FpReader *reader, Point_u64 *value);
+
+void fp_encode_FpVec_Point_Point_u64(FpWriter *writer, const FpVec_Point_Point_u64 *value);
+bool fp_decode_FpVec_Point_Point_u64(FpReader *reader, FpVec_Point_Point_u64 *value);
+void fp_free_FpVec_Point_Point_u64(FpVec_Point_Point_u64 *value);
+
+void fp_encode_Point_Point_u64(FpWriter *writer, const Point_Point_u64 *value);
+bool fp_decode_Point_Point_u64(FpReader *reader, Point_Point_u64 *value);
+
+void fp_encode_FpMap_String_FpVec_FloatingPoint(FpWriter *writer, const FpMap_String_FpVec_FloatingPoint *value);
+bool fp_decode_FpMap_String_FpVec_FloatingPoint(FpReader *reader, FpMap_String_FpVec_FloatingPoint *value);
+void fp_free_FpMap_String_FpVec_FloatingPoint(FpMap_String_FpVec_FloatingPoint *value);
+
+void fp_encode_FpVec_FloatingPoint(FpWriter *writer, const FpVec_FloatingPoint *value);
+bool fp_decode_FpVec_FloatingPoint(FpReader *reader, FpVec_FloatingPoint *value);
+void fp_free_FpVec_FloatingPoint(FpVec_FloatingPoint *value);
+
+void fp_encode_FpOption_FpMap_String_FpVec_FloatingPoint(FpWriter *writer, const FpOption_FpMap_String_FpVec_FloatingPoint *value);
+bool fp_decode_FpOption_FpMap_String_FpVec_FloatingPoint(FpReader *reader, FpOption_FpMap_String_FpVec_FloatingPoint *value);
+void fp_free_FpOption_FpMap_String_FpVec_FloatingPoint(FpOption_FpMap_String_FpVec_FloatingPoint *value);
+
+void fp_encode_FpOption_MyDateTime(FpWriter *writer, const FpOption_MyDateTime *value);
+bool fp_decode_FpOption_MyDateTime(FpReader *reader, FpOption_MyDateTime *value);
+void fp_free_FpOption_MyDateTime(FpOption_MyDateTime *value);
+
+void fp_encode_StructWithGenerics_u64(FpWriter *writer, const StructWithGenerics_u64 *value);
+bool fp_decode_StructWithGenerics_u64(FpReader *reader, StructWithGenerics_u64 *value);
+void fp_free_StructWithGenerics_u64(StructWithGenerics_u64 *value);
+
+void fp_encode_Result_Bytes_String(FpWriter *writer, const Result_Bytes_String *value);
+bool fp_decode_Result_Bytes_String(FpReader *reader, Result_Bytes_String *value);
+void fp_free_Result_Bytes_String(Result_Bytes_String *value);
+
+void fp_encode_FpArray_f32_1(FpWriter *writer, const FpArray_f32_1 *value);
+bool fp_decode_FpArray_f32_1(FpReader *reader, FpArray_f32_1 *value);
+
+void fp_encode_FpArray_f64_1(FpWriter *writer, const FpArray_f64_1 *value);
+bool fp_decode_FpArray_f64_1(FpReader *reader, FpArray_f64_1 *value);
+
+void fp_encode_Result_Unit_u32(FpWriter *writer, const Result_Unit_u32 *value);
+bool fp_decode_Result_Unit_u32(FpReader *reader, Result_Unit_u32 *value);
+
+void fp_encode_Result_String_String(FpWriter *writer, const Result_String_String *value);
+bool fp_decode_Result_String_String(FpReader *reader, Result_String_String *value);
+void fp_free_Result_String_String(Result_String_String *value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,2678 @@
+// ============================================= //
+// WebAssembly runtime for Go                    //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package bindings
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"log"
+	"sync"
+
+	"github.com/tetratelabs/wazero"
+	"github.com/tetratelabs/wazero/api"
+	"github.com/vmihailenco/msgpack/v5"
+)
+
+// FatPtr points to data in the memory of the plugin. The upper 32 bits contain
+// the address, while the lower 32 bits contain the length of the data.
+type FatPtr = uint64
+
+const (
+	// Extension bit in the length of a fat pointer, which indicates the length
+	// is stored in a 4-byte little-endian header, directly followed by the data.
+	fatPtrLenHeader = 0x0100_0000
+	fatPtrMaxLen    = 0x00FF_FFFF
+)
+
+// ProtocolVersion is the fingerprint of the protocol these bindings were
+// generated from.
+//
+// Plugins built against a different protocol are rejected by NewRuntime().
+const ProtocolVersion uint64 = 0x1e72896d627cc76e
+
+// Imports contains the host functions that may be imported by the plugin.
+//
+// Async functions are called on a separate goroutine, so they may block until
+// their result is available.
+type Imports interface {
+	ImportArrayF32(ctx context.Context, arg [3]float32) [3]float32
+
+	ImportArrayF64(ctx context.Context, arg [3]float64) [3]float64
+
+	ImportArrayI16(ctx context.Context, arg [3]int16) [3]int16
+
+	ImportArrayI32(ctx context.Context, arg [3]int32) [3]int32
+
+	ImportArrayI8(ctx context.Context, arg [3]int8) [3]int8
+
+	ImportArrayU16(ctx context.Context, arg [3]uint16) [3]uint16
+
+	ImportArrayU32(ctx context.Context, arg [3]uint32) [3]uint32
+
+	ImportArrayU8(ctx context.Context, arg [3]uint8) [3]uint8
+
+	ImportExplicitBoundPoint(ctx context.Context, arg ExplicitBoundPoint[uint64])
+
+	ImportFpAdjacentlyTagged(ctx context.Context, arg FpAdjacentlyTagged) FpAdjacentlyTagged
+
+	ImportFpEnum(ctx context.Context, arg FpVariantRenaming) FpVariantRenaming
+
+	ImportFpFlatten(ctx context.Context, arg FpFlatten) FpFlatten
+
+	ImportFpInternallyTagged(ctx context.Context, arg FpInternallyTagged) FpInternallyTagged
+
+	ImportFpStruct(ctx context.Context, arg FpPropertyRenaming) FpPropertyRenaming
+
+	ImportFpUntagged(ctx context.Context, arg FpUntagged) FpUntagged
+
+	ImportGenerics(ctx context.Context, arg StructWithGenerics[uint64]) StructWithGenerics[uint64]
+
+	ImportGetBytes(ctx context.Context) Result[[]byte, string]
+
+	ImportGetSerdeBytes(ctx context.Context) Result[[]byte, string]
+
+	ImportIncrementGlobalState(ctx context.Context)
+
+	ImportMultiplePrimitives(ctx context.Context, arg1 int8, arg2 string) int64
+
+	ImportPrimitiveBoolNegate(ctx context.Context, arg bool) bool
+
+	ImportPrimitiveBoolNegateAsync(ctx context.Context, arg bool) bool
+
+	ImportPrimitiveF32AddOne(ctx context.Context, arg float32) float32
+
+	ImportPrimitiveF32AddOneAsync(ctx context.Context, arg float32) float32
+
+	ImportPrimitiveF32AddOneWasmer2(ctx context.Context, arg [1]float32) float32
+
+	ImportPrimitiveF64AddOne(ctx context.Context, arg float64) float64
+
+	ImportPrimitiveF64AddOneAsync(ctx context.Context, arg float64) float64
+
+	ImportPrimitiveF64AddOneWasmer2(ctx context.Context, arg [1]float64) float64
+
+	ImportPrimitiveI16AddOne(ctx context.Context, arg int16) int16
+
+	ImportPrimitiveI16AddOneAsync(ctx context.Context, arg int16) int16
+
+	ImportPrimitiveI32AddOne(ctx context.Context, arg int32) int32
+
+	ImportPrimitiveI32AddOneAsync(ctx context.Context, arg int32) int32
+
+	ImportPrimitiveI64AddOne(ctx context.Context, arg int64) int64
+
+	ImportPrimitiveI64AddOneAsync(ctx context.Context, arg int64) int64
+
+	ImportPrimitiveI8AddOne(ctx context.Context, arg int8) int8
+
+	ImportPrimitiveI8AddOneAsync(ctx context.Context, arg int8) int8
+
+	ImportPrimitiveU16AddOne(ctx context.Context, arg uint16) uint16
+
+	ImportPrimitiveU16AddOneAsync(ctx context.Context, arg uint16) uint16
+
+	ImportPrimitiveU32AddOne(ctx context.Context, arg uint32) uint32
+
+	ImportPrimitiveU32AddOneAsync(ctx context.Context, arg uint32) uint32
+
+	ImportPrimitiveU64AddOne(ctx context.Context, arg uint64) uint64
+
+	ImportPrimitiveU64AddOneAsync(ctx context.Context, arg uint64) uint64
+
+	ImportPrimitiveU8AddOne(ctx context.Context, arg uint8) uint8
+
+	ImportPrimitiveU8AddOneAsync(ctx context.Context, arg uint8) uint8
+
+	ImportResetGlobalState(ctx context.Context)
+
+	ImportSerdeAdjacentlyTagged(ctx context.Context, arg SerdeAdjacentlyTagged) SerdeAdjacentlyTagged
+
+	ImportSerdeEnum(ctx context.Context, arg SerdeVariantRenaming) SerdeVariantRenaming
+
+	ImportSerdeFlatten(ctx context.Context, arg SerdeFlatten) SerdeFlatten
+
+	ImportSerdeInternallyTagged(ctx context.Context, arg SerdeInternallyTagged) SerdeInternallyTagged
+
+	ImportSerdeStruct(ctx context.Context, arg SerdePropertyRenaming) SerdePropertyRenaming
+
+	ImportSerdeUntagged(ctx context.Context, arg SerdeUntagged) SerdeUntagged
+
+	ImportString(ctx context.Context, arg string) string
+
+	ImportStructWithOptions(ctx context.Context, arg StructWithOptions) StructWithOptions
+
+	ImportTimestamp(ctx context.Context, arg MyDateTime) MyDateTime
+
+	ImportVoidFunction(ctx context.Context)
+
+	ImportVoidFunctionEmptyResult(ctx context.Context) Result[Unit, uint32]
+
+	ImportVoidFunctionEmptyReturn(ctx context.Context)
+
+	// Logs a message to the (development) console.
+	Log(ctx context.Context, message string)
+
+	// Example how a runtime could expose a `Fetch`-like function to plugins.
+	//
+	// See `types/http.rs` for more info.
+	MakeHttpRequest(ctx context.Context, request Request) HttpResult
+}
+
+// GuestPanicError is returned when a panic occurred inside the plugin.
+type GuestPanicError struct {
+	Message  string  `msgpack:"message"`
+	Location *string `msgpack:"location"`
+}
+
+func (e *GuestPanicError) Error() string {
+	if e.Location != nil {
+		return fmt.Sprintf("plugin panicked at %s: %s", *e.Location, e.Message)
+	}
+	return "plugin panicked: " + e.Message
+}
+
+// ProtocolMismatchError indicates the plugin was built against a different
+// protocol than the runtime.
+type ProtocolMismatchError struct {
+	Expected uint64
+	Found    uint64
+}
+
+func (e *ProtocolMismatchError) Error() string {
+	return fmt.Sprintf(
+		"Plugin was built against a different protocol (expected version %#016x, found %#016x)",
+		e.Expected,
+		e.Found,
+	)
+}
+
+// FunctionNotExportedError indicates the plugin does not implement the
+// exported function that was called.
+type FunctionNotExportedError struct {
+	Name string
+}
+
+func (e *FunctionNotExportedError) Error() string {
+	return fmt.Sprintf("Plugin did not export expected function: %q", e.Name)
+}
+
+// Unit represents Rust's unit type, which is serialized as nil.
+type Unit struct{}
+
+func (Unit) EncodeMsgpack(enc *msgpack.Encoder) error {
+	return enc.EncodeNil()
+}
+
+func (*Unit) DecodeMsgpack(dec *msgpack.Decoder) error {
+	return dec.Skip()
+}
+
+// Runtime for executing a plugin.
+//
+// Calls into the plugin are serialized, so a runtime may be shared between
+// goroutines.
+type Runtime struct {
+	runtime wazero.Runtime
+	module  api.Module
+	imports Imports
+	memory  api.Memory
+	malloc  api.Function
+	free    api.Function
+
+	// mu guards all calls into the plugin, since a module may not be called
+	// from multiple goroutines concurrently.
+	mu sync.Mutex
+
+	pendingMu sync.Mutex
+	pending   map[FatPtr]chan FatPtr
+}
+
+// NewRuntime compiles and instantiates the given plugin, using the given
+// implementation of the host functions it may import.
+func NewRuntime(ctx context.Context, wasmModule []byte, imports Imports) (*Runtime, error) {
+	return NewRuntimeWithConfig(ctx, wasmModule, imports, wazero.NewRuntimeConfig())
+}
+
+// NewRuntimeWithConfig is like NewRuntime(), but uses the given configuration
+// for the underlying wazero runtime.
+func NewRuntimeWithConfig(
+	ctx context.Context,
+	wasmModule []byte,
+	imports Imports,
+	config wazero.RuntimeConfig,
+) (*Runtime, error) {
+	r := &Runtime{
+		runtime: wazero.NewRuntimeWithConfig(ctx, config),
+		imports: imports,
+		pending: make(map[FatPtr]chan FatPtr),
+	}
+	if err := r.instantiate(ctx, wasmModule); err != nil {
+		_ = r.runtime.Close(ctx)
+		return nil, err
+	}
+	return r, nil
+}
+
+// Close releases all resources held by the runtime.
+func (r *Runtime) Close(ctx context.Context) error {
+	return r.runtime.Close(ctx)
+}
+
+func (r *Runtime) instantiate(ctx context.Context, wasmModule []byte) error {
+	builder := r.runtime.NewHostModuleBuilder("fp")
+	r.defineImports(builder)
+	if _, err := builder.Instantiate(ctx); err != nil {
+		return err
+	}
+
+	module, err := r.runtime.Instantiate(ctx, wasmModule)
+	if err != nil {
+		return err
+	}
+	r.module = module
+
+	if r.memory = module.Memory(); r.memory == nil {
+		return errors.New("Plugin did not export expected symbol: \"memory\"")
+	}
+	if r.malloc, err = r.exportedFunction("__fp_malloc"); err != nil {
+		return err
+	}
+	if r.free, err = r.exportedFunction("__fp_free"); err != nil {
+		return err
+	}
+
+	// Reactor modules, such as plugins written in TinyGo, need to initialize
+	// themselves before any of their other exports may be called.
+	if initialize := module.ExportedFunction("_initialize"); initialize != nil {
+		if _, err := initialize.Call(ctx); err != nil {
+			return err
+		}
+	}
+
+	// Plugins built with older bindings don't export their protocol version, so
+	// they cannot be verified.
+	protocolVersion := module.ExportedFunction("__fp_protocol_version")
+	if protocolVersion == nil {
+		return nil
+	}
+	results, err := protocolVersion.Call(ctx)
+	if err != nil {
+		return err
+	}
+	if results[0] != ProtocolVersion {
+		return &ProtocolMismatchError{Expected: ProtocolVersion, Found: results[0]}
+	}
+	return nil
+}
+
+func (r *Runtime) ExportArrayF32(ctx context.Context, arg [3]float32) (result [3]float32, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_f32")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportArrayF64(ctx context.Context, arg [3]float64) (result [3]float64, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_f64")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportArrayI16(ctx context.Context, arg [3]int16) (result [3]int16, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_i16")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportArrayI32(ctx context.Context, arg [3]int32) (result [3]int32, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_i32")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportArrayI8(ctx context.Context, arg [3]int8) (result [3]int8, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_i8")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportArrayU16(ctx context.Context, arg [3]uint16) (result [3]uint16, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_u16")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportArrayU32(ctx context.Context, arg [3]uint32) (result [3]uint32, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_u32")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportArrayU8(ctx context.Context, arg [3]uint8) (result [3]uint8, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_array_u8")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, byteArrayToList(arg[:]))
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseByteArray(ctx, results[0], result[:])
+	})
+	return
+}
+
+func (r *Runtime) ExportAsyncStruct(ctx context.Context, arg1 FpPropertyRenaming, arg2 uint64) (result FpPropertyRenaming, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_async_struct")
+		if err != nil {
+			return err
+		}
+		arg1Ptr, err := r.serializeObject(ctx, arg1)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, arg1Ptr, arg2)
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportFpAdjacentlyTagged(ctx context.Context, arg FpAdjacentlyTagged) (result FpAdjacentlyTagged, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_fp_adjacently_tagged")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportFpEnum(ctx context.Context, arg FpVariantRenaming) (result FpVariantRenaming, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_fp_enum")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportFpFlatten(ctx context.Context, arg FpFlatten) (result FpFlatten, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_fp_flatten")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportFpInternallyTagged(ctx context.Context, arg FpInternallyTagged) (result FpInternallyTagged, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_fp_internally_tagged")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportFpStruct(ctx context.Context, arg FpPropertyRenaming) (result FpPropertyRenaming, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_fp_struct")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportFpUntagged(ctx context.Context, arg FpUntagged) (result FpUntagged, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_fp_untagged")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportGenerics(ctx context.Context, arg StructWithGenerics[uint64]) (result StructWithGenerics[uint64], err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_generics")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportGetBytes(ctx context.Context) (result Result[[]byte, string], err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_get_bytes")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportGetSerdeBytes(ctx context.Context) (result Result[[]byte, string], err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_get_serde_bytes")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportIncrementGlobalState(ctx context.Context) (err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_increment_global_state")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx)
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, nil)
+	})
+	return
+}
+
+func (r *Runtime) ExportMultiplePrimitives(ctx context.Context, arg1 int8, arg2 string) (result int64, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_multiple_primitives")
+		if err != nil {
+			return err
+		}
+		arg2Ptr, err := r.serializeObject(ctx, arg2)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI32(int32(arg1)), arg2Ptr)
+		if err != nil {
+			return err
+		}
+		result = int64(results[0])
+		return nil
+	})
+	return
+}
+
+// Panics with the given message, so the runtimes can test guest panics
+// are reported to them.
+func (r *Runtime) ExportPanic(ctx context.Context, message string) (err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_panic")
+		if err != nil {
+			return err
+		}
+		messagePtr, err := r.serializeObject(ctx, message)
+		if err != nil {
+			return err
+		}
+		_, err = fn.Call(ctx, messagePtr)
+		return err
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveBoolNegate(ctx context.Context, arg bool) (result bool, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_bool_negate")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, encodeBool(arg))
+		if err != nil {
+			return err
+		}
+		result = uint32(results[0]) != 0
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveBoolNegateAsync(ctx context.Context, arg bool) (result bool, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_bool_negate_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, encodeBool(arg))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveF32AddThree(ctx context.Context, arg float32) (result float32, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_f32_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeF32(arg))
+		if err != nil {
+			return err
+		}
+		result = api.DecodeF32(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveF32AddThreeAsync(ctx context.Context, arg float32) (result float32, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_f32_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeF32(arg))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveF32AddThreeWasmer2(ctx context.Context, arg float32) (result float32, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_f32_add_three_wasmer2")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeF32(arg))
+		if err != nil {
+			return err
+		}
+		result = api.DecodeF32(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveF64AddThree(ctx context.Context, arg float64) (result float64, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_f64_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeF64(arg))
+		if err != nil {
+			return err
+		}
+		result = api.DecodeF64(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveF64AddThreeAsync(ctx context.Context, arg float64) (result float64, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_f64_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeF64(arg))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveF64AddThreeWasmer2(ctx context.Context, arg float64) (result float64, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_f64_add_three_wasmer2")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeF64(arg))
+		if err != nil {
+			return err
+		}
+		result = api.DecodeF64(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI16AddThree(ctx context.Context, arg int16) (result int16, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i16_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI32(int32(arg)))
+		if err != nil {
+			return err
+		}
+		result = int16(api.DecodeI32(results[0]))
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI16AddThreeAsync(ctx context.Context, arg int16) (result int16, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i16_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI32(int32(arg)))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI32AddThree(ctx context.Context, arg int32) (result int32, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i32_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI32(arg))
+		if err != nil {
+			return err
+		}
+		result = api.DecodeI32(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI32AddThreeAsync(ctx context.Context, arg int32) (result int32, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i32_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI32(arg))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI64AddThree(ctx context.Context, arg int64) (result int64, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i64_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI64(arg))
+		if err != nil {
+			return err
+		}
+		result = int64(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI64AddThreeAsync(ctx context.Context, arg int64) (result int64, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i64_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI64(arg))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI8AddThree(ctx context.Context, arg int8) (result int8, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i8_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI32(int32(arg)))
+		if err != nil {
+			return err
+		}
+		result = int8(api.DecodeI32(results[0]))
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveI8AddThreeAsync(ctx context.Context, arg int8) (result int8, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_i8_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeI32(int32(arg)))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU16AddThree(ctx context.Context, arg uint16) (result uint16, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u16_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeU32(uint32(arg)))
+		if err != nil {
+			return err
+		}
+		result = uint16(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU16AddThreeAsync(ctx context.Context, arg uint16) (result uint16, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u16_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeU32(uint32(arg)))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU32AddThree(ctx context.Context, arg uint32) (result uint32, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u32_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeU32(arg))
+		if err != nil {
+			return err
+		}
+		result = api.DecodeU32(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU32AddThreeAsync(ctx context.Context, arg uint32) (result uint32, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u32_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeU32(arg))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU64AddThree(ctx context.Context, arg uint64) (result uint64, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u64_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, arg)
+		if err != nil {
+			return err
+		}
+		result = results[0]
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU64AddThreeAsync(ctx context.Context, arg uint64) (result uint64, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u64_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, arg)
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU8AddThree(ctx context.Context, arg uint8) (result uint8, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u8_add_three")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeU32(uint32(arg)))
+		if err != nil {
+			return err
+		}
+		result = uint8(results[0])
+		return nil
+	})
+	return
+}
+
+func (r *Runtime) ExportPrimitiveU8AddThreeAsync(ctx context.Context, arg uint8) (result uint8, err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_primitive_u8_add_three_async")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, api.EncodeU32(uint32(arg)))
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportResetGlobalState(ctx context.Context) (err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("export_reset_global_state")
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx)
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, nil)
+	})
+	return
+}
+
+func (r *Runtime) ExportSerdeAdjacentlyTagged(ctx context.Context, arg SerdeAdjacentlyTagged) (result SerdeAdjacentlyTagged, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_serde_adjacently_tagged")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportSerdeEnum(ctx context.Context, arg SerdeVariantRenaming) (result SerdeVariantRenaming, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_serde_enum")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportSerdeFlatten(ctx context.Context, arg SerdeFlatten) (result SerdeFlatten, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_serde_flatten")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportSerdeInternallyTagged(ctx context.Context, arg SerdeInternallyTagged) (result SerdeInternallyTagged, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_serde_internally_tagged")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportSerdeStruct(ctx context.Context, arg SerdePropertyRenaming) (result SerdePropertyRenaming, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_serde_struct")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportSerdeUntagged(ctx context.Context, arg SerdeUntagged) (result SerdeUntagged, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_serde_untagged")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportString(ctx context.Context, arg string) (result string, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_string")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportStructWithOptions(ctx context.Context, arg StructWithOptions) (result StructWithOptions, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_struct_with_options")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportTimestamp(ctx context.Context, arg MyDateTime) (result MyDateTime, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_timestamp")
+		if err != nil {
+			return err
+		}
+		argPtr, err := r.serializeObject(ctx, arg)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, argPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) ExportVoidFunction(ctx context.Context) (err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("export_void_function")
+		if err != nil {
+			return err
+		}
+		_, err = fn.Call(ctx)
+		return err
+	})
+	return
+}
+
+// Example how plugin could expose async data-fetching capabilities.
+func (r *Runtime) FetchData(ctx context.Context, type_ string) (result Result[string, string], err error) {
+	var asyncValuePtr FatPtr
+	err = r.withLock(func() error {
+		fn, err := r.function("fetch_data")
+		if err != nil {
+			return err
+		}
+		typePtr, err := r.serializeObject(ctx, type_)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, typePtr)
+		if err != nil {
+			return err
+		}
+		asyncValuePtr = results[0]
+		return nil
+	})
+	if err != nil {
+		return
+	}
+	resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)
+	if err != nil {
+		return
+	}
+	err = r.withLock(func() error {
+		return r.parseObject(ctx, resultPtr, &result)
+	})
+	return
+}
+
+// Called on the plugin to give it a chance to initialize.
+func (r *Runtime) Init(ctx context.Context) (err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("init")
+		if err != nil {
+			return err
+		}
+		_, err = fn.Call(ctx)
+		return err
+	})
+	return
+}
+
+// Example how plugin could expose a reducer.
+func (r *Runtime) ReducerBridge(ctx context.Context, action ReduxAction) (result StateUpdate, err error) {
+	err = r.withLock(func() error {
+		fn, err := r.function("reducer_bridge")
+		if err != nil {
+			return err
+		}
+		actionPtr, err := r.serializeObject(ctx, action)
+		if err != nil {
+			return err
+		}
+		results, err := fn.Call(ctx, actionPtr)
+		if err != nil {
+			return err
+		}
+		return r.parseObject(ctx, results[0], &result)
+	})
+	return
+}
+
+func (r *Runtime) defineImports(builder wazero.HostModuleBuilder) {
+	r.defineImport(builder, "__fp_host_panic", r.hostPanic, []api.ValueType{api.ValueTypeI64}, nil)
+	r.defineImport(builder, "__fp_host_resolve_async_value", r.hostResolveAsyncValue, []api.ValueType{api.ValueTypeI64, api.ValueTypeI64}, nil)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_f32",
+		r.fpGenImportArrayF32,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_f64",
+		r.fpGenImportArrayF64,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_i16",
+		r.fpGenImportArrayI16,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_i32",
+		r.fpGenImportArrayI32,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_i8",
+		r.fpGenImportArrayI8,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_u16",
+		r.fpGenImportArrayU16,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_u32",
+		r.fpGenImportArrayU32,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_array_u8",
+		r.fpGenImportArrayU8,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_explicit_bound_point",
+		r.fpGenImportExplicitBoundPoint,
+		[]api.ValueType{api.ValueTypeI64},
+		nil,
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_fp_adjacently_tagged",
+		r.fpGenImportFpAdjacentlyTagged,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_fp_enum",
+		r.fpGenImportFpEnum,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_fp_flatten",
+		r.fpGenImportFpFlatten,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_fp_internally_tagged",
+		r.fpGenImportFpInternallyTagged,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_fp_struct",
+		r.fpGenImportFpStruct,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_fp_untagged",
+		r.fpGenImportFpUntagged,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_generics",
+		r.fpGenImportGenerics,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_get_bytes",
+		r.fpGenImportGetBytes,
+		nil,
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_get_serde_bytes",
+		r.fpGenImportGetSerdeBytes,
+		nil,
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_increment_global_state",
+		r.fpGenImportIncrementGlobalState,
+		nil,
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_multiple_primitives",
+		r.fpGenImportMultiplePrimitives,
+		[]api.ValueType{api.ValueTypeI32, api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_bool_negate",
+		r.fpGenImportPrimitiveBoolNegate,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_bool_negate_async",
+		r.fpGenImportPrimitiveBoolNegateAsync,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_f32_add_one",
+		r.fpGenImportPrimitiveF32AddOne,
+		[]api.ValueType{api.ValueTypeF32},
+		[]api.ValueType{api.ValueTypeF32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_f32_add_one_async",
+		r.fpGenImportPrimitiveF32AddOneAsync,
+		[]api.ValueType{api.ValueTypeF32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_f32_add_one_wasmer2",
+		r.fpGenImportPrimitiveF32AddOneWasmer2,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeF32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_f64_add_one",
+		r.fpGenImportPrimitiveF64AddOne,
+		[]api.ValueType{api.ValueTypeF64},
+		[]api.ValueType{api.ValueTypeF64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_f64_add_one_async",
+		r.fpGenImportPrimitiveF64AddOneAsync,
+		[]api.ValueType{api.ValueTypeF64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_f64_add_one_wasmer2",
+		r.fpGenImportPrimitiveF64AddOneWasmer2,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeF64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i16_add_one",
+		r.fpGenImportPrimitiveI16AddOne,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i16_add_one_async",
+		r.fpGenImportPrimitiveI16AddOneAsync,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i32_add_one",
+		r.fpGenImportPrimitiveI32AddOne,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i32_add_one_async",
+		r.fpGenImportPrimitiveI32AddOneAsync,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i64_add_one",
+		r.fpGenImportPrimitiveI64AddOne,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i64_add_one_async",
+		r.fpGenImportPrimitiveI64AddOneAsync,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i8_add_one",
+		r.fpGenImportPrimitiveI8AddOne,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_i8_add_one_async",
+		r.fpGenImportPrimitiveI8AddOneAsync,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u16_add_one",
+		r.fpGenImportPrimitiveU16AddOne,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u16_add_one_async",
+		r.fpGenImportPrimitiveU16AddOneAsync,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u32_add_one",
+		r.fpGenImportPrimitiveU32AddOne,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u32_add_one_async",
+		r.fpGenImportPrimitiveU32AddOneAsync,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u64_add_one",
+		r.fpGenImportPrimitiveU64AddOne,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u64_add_one_async",
+		r.fpGenImportPrimitiveU64AddOneAsync,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u8_add_one",
+		r.fpGenImportPrimitiveU8AddOne,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI32},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_primitive_u8_add_one_async",
+		r.fpGenImportPrimitiveU8AddOneAsync,
+		[]api.ValueType{api.ValueTypeI32},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_reset_global_state",
+		r.fpGenImportResetGlobalState,
+		nil,
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_serde_adjacently_tagged",
+		r.fpGenImportSerdeAdjacentlyTagged,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_serde_enum",
+		r.fpGenImportSerdeEnum,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_serde_flatten",
+		r.fpGenImportSerdeFlatten,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_serde_internally_tagged",
+		r.fpGenImportSerdeInternallyTagged,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_serde_struct",
+		r.fpGenImportSerdeStruct,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_serde_untagged",
+		r.fpGenImportSerdeUntagged,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_string",
+		r.fpGenImportString,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_struct_with_options",
+		r.fpGenImportStructWithOptions,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_timestamp",
+		r.fpGenImportTimestamp,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_void_function",
+		r.fpGenImportVoidFunction,
+		nil,
+		nil,
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_void_function_empty_result",
+		r.fpGenImportVoidFunctionEmptyResult,
+		nil,
+		[]api.ValueType{api.ValueTypeI64},
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_import_void_function_empty_return",
+		r.fpGenImportVoidFunctionEmptyReturn,
+		nil,
+		nil,
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_log",
+		r.fpGenLog,
+		[]api.ValueType{api.ValueTypeI64},
+		nil,
+	)
+	r.defineImport(
+		builder,
+		"__fp_gen_make_http_request",
+		r.fpGenMakeHttpRequest,
+		[]api.ValueType{api.ValueTypeI64},
+		[]api.ValueType{api.ValueTypeI64},
+	)
+}
+
+func (r *Runtime) defineImport(
+	builder wazero.HostModuleBuilder,
+	name string,
+	fn api.GoModuleFunc,
+	params, results []api.ValueType,
+) {
+	builder.NewFunctionBuilder().WithGoModuleFunction(fn, params, results).Export(name)
+}
+
+func (r *Runtime) fpGenImportArrayF32(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]float32
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportArrayF32(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportArrayF64(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]float64
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportArrayF64(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportArrayI16(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]int16
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportArrayI16(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportArrayI32(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]int32
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportArrayI32(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportArrayI8(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]int8
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportArrayI8(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportArrayU16(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]uint16
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportArrayU16(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportArrayU32(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]uint32
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportArrayU32(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportArrayU8(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [3]uint8
+	check(r.parseByteArray(ctx, stack[0], arg[:]))
+	result := r.imports.ImportArrayU8(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, byteArrayToList(result[:]))
+}
+
+func (r *Runtime) fpGenImportExplicitBoundPoint(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg ExplicitBoundPoint[uint64]
+	check(r.parseObject(ctx, stack[0], &arg))
+	r.imports.ImportExplicitBoundPoint(ctx, arg)
+}
+
+func (r *Runtime) fpGenImportFpAdjacentlyTagged(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg FpAdjacentlyTagged
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportFpAdjacentlyTagged(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportFpEnum(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg FpVariantRenaming
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportFpEnum(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportFpFlatten(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg FpFlatten
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportFpFlatten(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportFpInternallyTagged(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg FpInternallyTagged
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportFpInternallyTagged(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportFpStruct(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg FpPropertyRenaming
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportFpStruct(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportFpUntagged(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg FpUntagged
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportFpUntagged(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportGenerics(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg StructWithGenerics[uint64]
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportGenerics(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportGetBytes(ctx context.Context, _ api.Module, stack []uint64) {
+	result := r.imports.ImportGetBytes(ctx)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportGetSerdeBytes(ctx context.Context, _ api.Module, stack []uint64) {
+	result := r.imports.ImportGetSerdeBytes(ctx)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportIncrementGlobalState(ctx context.Context, _ api.Module, stack []uint64) {
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_increment_global_state", asyncValuePtr, func() interface{} {
+		r.imports.ImportIncrementGlobalState(ctx)
+		return nil
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportMultiplePrimitives(ctx context.Context, _ api.Module, stack []uint64) {
+	arg1 := int8(api.DecodeI32(stack[0]))
+	var arg2 string
+	check(r.parseObject(ctx, stack[1], &arg2))
+	result := r.imports.ImportMultiplePrimitives(ctx, arg1, arg2)
+	stack[0] = api.EncodeI64(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveBoolNegate(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := uint32(stack[0]) != 0
+	result := r.imports.ImportPrimitiveBoolNegate(ctx, arg)
+	stack[0] = encodeBool(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveBoolNegateAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := uint32(stack[0]) != 0
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_bool_negate_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveBoolNegateAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveF32AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeF32(stack[0])
+	result := r.imports.ImportPrimitiveF32AddOne(ctx, arg)
+	stack[0] = api.EncodeF32(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveF32AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeF32(stack[0])
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_f32_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveF32AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveF32AddOneWasmer2(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [1]float32
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportPrimitiveF32AddOneWasmer2(ctx, arg)
+	stack[0] = api.EncodeF32(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveF64AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeF64(stack[0])
+	result := r.imports.ImportPrimitiveF64AddOne(ctx, arg)
+	stack[0] = api.EncodeF64(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveF64AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeF64(stack[0])
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_f64_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveF64AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveF64AddOneWasmer2(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg [1]float64
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportPrimitiveF64AddOneWasmer2(ctx, arg)
+	stack[0] = api.EncodeF64(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveI16AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := int16(api.DecodeI32(stack[0]))
+	result := r.imports.ImportPrimitiveI16AddOne(ctx, arg)
+	stack[0] = api.EncodeI32(int32(result))
+}
+
+func (r *Runtime) fpGenImportPrimitiveI16AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := int16(api.DecodeI32(stack[0]))
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_i16_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveI16AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveI32AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeI32(stack[0])
+	result := r.imports.ImportPrimitiveI32AddOne(ctx, arg)
+	stack[0] = api.EncodeI32(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveI32AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeI32(stack[0])
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_i32_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveI32AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveI64AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := int64(stack[0])
+	result := r.imports.ImportPrimitiveI64AddOne(ctx, arg)
+	stack[0] = api.EncodeI64(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveI64AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := int64(stack[0])
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_i64_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveI64AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveI8AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := int8(api.DecodeI32(stack[0]))
+	result := r.imports.ImportPrimitiveI8AddOne(ctx, arg)
+	stack[0] = api.EncodeI32(int32(result))
+}
+
+func (r *Runtime) fpGenImportPrimitiveI8AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := int8(api.DecodeI32(stack[0]))
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_i8_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveI8AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveU16AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := uint16(stack[0])
+	result := r.imports.ImportPrimitiveU16AddOne(ctx, arg)
+	stack[0] = api.EncodeU32(uint32(result))
+}
+
+func (r *Runtime) fpGenImportPrimitiveU16AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := uint16(stack[0])
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_u16_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveU16AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveU32AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeU32(stack[0])
+	result := r.imports.ImportPrimitiveU32AddOne(ctx, arg)
+	stack[0] = api.EncodeU32(result)
+}
+
+func (r *Runtime) fpGenImportPrimitiveU32AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := api.DecodeU32(stack[0])
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_u32_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveU32AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveU64AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := stack[0]
+	result := r.imports.ImportPrimitiveU64AddOne(ctx, arg)
+	stack[0] = result
+}
+
+func (r *Runtime) fpGenImportPrimitiveU64AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := stack[0]
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_u64_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveU64AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportPrimitiveU8AddOne(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := uint8(stack[0])
+	result := r.imports.ImportPrimitiveU8AddOne(ctx, arg)
+	stack[0] = api.EncodeU32(uint32(result))
+}
+
+func (r *Runtime) fpGenImportPrimitiveU8AddOneAsync(ctx context.Context, _ api.Module, stack []uint64) {
+	arg := uint8(stack[0])
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_primitive_u8_add_one_async", asyncValuePtr, func() interface{} {
+		return r.imports.ImportPrimitiveU8AddOneAsync(ctx, arg)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportResetGlobalState(ctx context.Context, _ api.Module, stack []uint64) {
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "import_reset_global_state", asyncValuePtr, func() interface{} {
+		r.imports.ImportResetGlobalState(ctx)
+		return nil
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) fpGenImportSerdeAdjacentlyTagged(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg SerdeAdjacentlyTagged
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportSerdeAdjacentlyTagged(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportSerdeEnum(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg SerdeVariantRenaming
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportSerdeEnum(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportSerdeFlatten(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg SerdeFlatten
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportSerdeFlatten(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportSerdeInternallyTagged(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg SerdeInternallyTagged
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportSerdeInternallyTagged(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportSerdeStruct(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg SerdePropertyRenaming
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportSerdeStruct(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportSerdeUntagged(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg SerdeUntagged
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportSerdeUntagged(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportString(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg string
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportString(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportStructWithOptions(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg StructWithOptions
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportStructWithOptions(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportTimestamp(ctx context.Context, _ api.Module, stack []uint64) {
+	var arg MyDateTime
+	check(r.parseObject(ctx, stack[0], &arg))
+	result := r.imports.ImportTimestamp(ctx, arg)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportVoidFunction(ctx context.Context, _ api.Module, _ []uint64) {
+	r.imports.ImportVoidFunction(ctx)
+}
+
+func (r *Runtime) fpGenImportVoidFunctionEmptyResult(ctx context.Context, _ api.Module, stack []uint64) {
+	result := r.imports.ImportVoidFunctionEmptyResult(ctx)
+	stack[0] = r.mustSerializeObject(ctx, result)
+}
+
+func (r *Runtime) fpGenImportVoidFunctionEmptyReturn(ctx context.Context, _ api.Module, _ []uint64) {
+	r.imports.ImportVoidFunctionEmptyReturn(ctx)
+}
+
+func (r *Runtime) fpGenLog(ctx context.Context, _ api.Module, stack []uint64) {
+	var message string
+	check(r.parseObject(ctx, stack[0], &message))
+	r.imports.Log(ctx, message)
+}
+
+func (r *Runtime) fpGenMakeHttpRequest(ctx context.Context, _ api.Module, stack []uint64) {
+	var request Request
+	check(r.parseObject(ctx, stack[0], &request))
+	asyncValuePtr := r.mustCreateAsyncValue(ctx)
+	go r.resolveAsyncImport(ctx, "make_http_request", asyncValuePtr, func() interface{} {
+		return r.imports.MakeHttpRequest(ctx, request)
+	})
+	stack[0] = asyncValuePtr
+}
+
+func (r *Runtime) hostPanic(ctx context.Context, _ api.Module, stack []uint64) {
+	var guestPanic GuestPanicError
+	check(r.parseObject(ctx, stack[0], &guestPanic))
+	panic(&guestPanic)
+}
+
+func (r *Runtime) createAsyncValue(ctx context.Context) (FatPtr, error) {
+	const length = 12 // std::mem::size_of::<AsyncValue>()
+	results, err := r.malloc.Call(ctx, length)
+	if err != nil {
+		return 0, err
+	}
+	fatPtr := results[0]
+	ptr, _ := fromFatPtr(fatPtr)
+	if !r.memory.Write(ptr, make([]byte, length)) {
+		return 0, fmt.Errorf("invalid fat pointer: %#x", fatPtr)
+	}
+	return fatPtr, nil
+}
+
+func (r *Runtime) mustCreateAsyncValue(ctx context.Context) FatPtr {
+	fatPtr, err := r.createAsyncValue(ctx)
+	check(err)
+	return fatPtr
+}
+
+// resolveAsyncImport calls an async host function and passes its result to the
+// plugin. It is run on its own goroutine.
+func (r *Runtime) resolveAsyncImport(
+	ctx context.Context,
+	name string,
+	asyncValuePtr FatPtr,
+	call func() interface{},
+) {
+	result := call()
+	err := r.withLock(func() error {
+		resolveFuture, err := r.exportedFunction("__fp_guest_resolve_async_value")
+		if err != nil {
+			return err
+		}
+		resultPtr, err := r.serializeObject(ctx, result)
+		if err != nil {
+			return err
+		}
+		_, err = resolveFuture.Call(ctx, asyncValuePtr, resultPtr)
+		return err
+	})
+	if err != nil {
+		log.Printf("Unrecoverable error trying to call async host function %q: %v", name, err)
+	}
+}
+
+func (r *Runtime) awaitAsyncValue(ctx context.Context, asyncValuePtr FatPtr) (FatPtr, error) {
+	select {
+	case resultPtr := <-r.asyncValueChannel(asyncValuePtr):
+		r.pendingMu.Lock()
+		delete(r.pending, asyncValuePtr)
+		r.pendingMu.Unlock()
+		return resultPtr, nil
+	case <-ctx.Done():
+		return 0, ctx.Err()
+	}
+}
+
+func (r *Runtime) hostResolveAsyncValue(_ context.Context, _ api.Module, stack []uint64) {
+	r.asyncValueChannel(stack[0]) <- stack[1]
+}
+
+// asyncValueChannel returns the channel through which the result of the given
+// async value is passed, regardless of whether the plugin resolves it before
+// or after we start waiting for it.
+func (r *Runtime) asyncValueChannel(asyncValuePtr FatPtr) chan FatPtr {
+	r.pendingMu.Lock()
+	defer r.pendingMu.Unlock()
+	ch, ok := r.pending[asyncValuePtr]
+	if !ok {
+		ch = make(chan FatPtr, 1)
+		r.pending[asyncValuePtr] = ch
+	}
+	return ch
+}
+
+func (r *Runtime) withLock(f func() error) error {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+	return f()
+}
+
+func (r *Runtime) exportedFunction(name string) (api.Function, error) {
+	fn := r.module.ExportedFunction(name)
+	if fn == nil {
+		return nil, fmt.Errorf("Plugin did not export expected symbol: %q", name)
+	}
+	return fn, nil
+}
+
+func (r *Runtime) function(name string) (api.Function, error) {
+	fn := r.module.ExportedFunction("__fp_gen_" + name)
+	if fn == nil {
+		return nil, &FunctionNotExportedError{Name: name}
+	}
+	return fn, nil
+}
+
+func (r *Runtime) parseObject(ctx context.Context, fatPtr FatPtr, v interface{}) error {
+	data, err := r.importFromMemory(ctx, fatPtr)
+	if err != nil || v == nil || len(data) == 0 {
+		return err
+	}
+	return msgpack.Unmarshal(data, v)
+}
+
+// parseByteArray parses a serialized array of bytes into the given slice.
+func (r *Runtime) parseByteArray(ctx context.Context, fatPtr FatPtr, array []byte) error {
+	var list []uint16
+	if err := r.parseObject(ctx, fatPtr, &list); err != nil {
+		return err
+	}
+	for i := range array {
+		if i < len(list) {
+			array[i] = uint8(list[i])
+		}
+	}
+	return nil
+}
+
+func (r *Runtime) serializeObject(ctx context.Context, v interface{}) (FatPtr, error) {
+	data, err := msgpack.Marshal(v)
+	if err != nil {
+		return 0, err
+	}
+	return r.exportToMemory(ctx, data)
+}
+
+func (r *Runtime) mustSerializeObject(ctx context.Context, v interface{}) FatPtr {
+	fatPtr, err := r.serializeObject(ctx, v)
+	check(err)
+	return fatPtr
+}
+
+func (r *Runtime) exportToMemory(ctx context.Context, data []byte) (FatPtr, error) {
+	results, err := r.malloc.Call(ctx, uint64(len(data)))
+	if err != nil {
+		return 0, err
+	}
+	fatPtr := results[0]
+	ptr, _, err := r.resolveFatPtr(fatPtr)
+	if err != nil {
+		return 0, err
+	}
+	if !r.memory.Write(ptr, data) {
+		return 0, fmt.Errorf("invalid fat pointer: %#x", fatPtr)
+	}
+	return fatPtr, nil
+}
+
+func (r *Runtime) importFromMemory(ctx context.Context, fatPtr FatPtr) ([]byte, error) {
+	if fatPtr == 0 {
+		// This may happen with async calls that don't return a result:
+		return nil, nil
+	}
+
+	ptr, length, err := r.resolveFatPtr(fatPtr)
+	if err != nil {
+		return nil, err
+	}
+	data, ok := r.memory.Read(ptr, length)
+	if !ok {
+		return nil, fmt.Errorf("invalid fat pointer: %#x", fatPtr)
+	}
+
+	// Copy the data, because the memory it refers to is freed below:
+	data = append([]byte(nil), data...)
+	if _, err := r.free.Call(ctx, fatPtr); err != nil {
+		return nil, err
+	}
+	return data, nil
+}
+
+func (r *Runtime) resolveFatPtr(fatPtr FatPtr) (uint32, uint32, error) {
+	ptr, length := fromFatPtr(fatPtr)
+	if length == fatPtrLenHeader {
+		header, ok := r.memory.ReadUint32Le(ptr)
+		if !ok {
+			return 0, 0, fmt.Errorf("invalid fat pointer: %#x", fatPtr)
+		}
+		return ptr + 4, header, nil
+	}
+	if length > fatPtrMaxLen {
+		return 0, 0, fmt.Errorf("unknown extension bits in fat pointer: %#x", fatPtr)
+	}
+	return ptr, length, nil
+}
+
+func fromFatPtr(fatPtr FatPtr) (uint32, uint32) {
+	return uint32(fatPtr >> 32), uint32(fatPtr)
+}
+
+// byteArrayToList converts a byte array to a slice that is serialized as a
+// MessagePack array, because Rust arrays cannot be deserialized from the
+// binary data byte slices are serialized as.
+func byteArrayToList(array []byte) []uint16 {
+	list := make([]uint16, len(array))
+	for i, b := range array {
+		list[i] = uint16(b)
+	}
+	return list
+}
+
+func encodeBool(value bool) uint64 {
+	if value {
+		return 1
+	}
+	return 0
+}
+
+// check is used by the import wrappers, which cannot return errors. Wazero
+// recovers the panic and returns the error from the call into the plugin.
+func check(err error) {
+	if err != nil {
+		panic(err)
+	}
+}
+
+// enumRepr describes how the variants of an enum are represented, following
+// the enum representations supported by Serde.
+type enumRepr struct {
+	tag      string
+	content  string
+	untagged bool
+}
+
+func encodeVariant(enc *msgpack.Encoder, repr enumRepr, name string, value interface{}) error {
+	switch {
+	case repr.untagged:
+		return enc.Encode(value)
+	case repr.tag == "":
+		if value == nil {
+			return enc.EncodeString(name)
+		}
+		return enc.Encode(map[string]interface{}{name: value})
+	case repr.content != "":
+		if value == nil {
+			return enc.Encode(map[string]interface{}{repr.tag: name})
+		}
+		return enc.Encode(map[string]interface{}{repr.tag: name, repr.content: value})
+	default:
+		fields := map[string]interface{}{}
+		if value != nil {
+			data, err := msgpack.Marshal(value)
+			if err != nil {
+				return err
+			}
+			if err := msgpack.Unmarshal(data, &fields); err != nil {
+				return err
+			}
+		}
+		fields[repr.tag] = name
+		return enc.Encode(fields)
+	}
+}
+
+// decodeVariant decodes the name of a variant of a tagged enum, along with its
+// serialized content.
+func decodeVariant(dec *msgpack.Decoder, repr enumRepr) (string, msgpack.RawMessage, error) {
+	raw, err := dec.DecodeRaw()
+	if err != nil {
+		return "", nil, err
+	}
+
+	if repr.tag == "" {
+		var name string
+		if err := msgpack.Unmarshal(raw, &name); err == nil {
+			return name, nil, nil
+		}
+		var variants map[string]msgpack.RawMessage
+		if err := msgpack.Unmarshal(raw, &variants); err != nil {
+			return "", nil, err
+		}
+		if len(variants) == 1 {
+			for name, content := range variants {
+				return name, content, nil
+			}
+		}
+		return "", nil, fmt.Errorf("expected a single variant, found %d", len(variants))
+	}
+
+	var fields map[string]msgpack.RawMessage
+	if err := msgpack.Unmarshal(raw, &fields); err != nil {
+		return "", nil, err
+	}
+	var name string
+	if err := msgpack.Unmarshal(fields[repr.tag], &name); err != nil {
+		return "", nil, fmt.Errorf("invalid tag %q: %w", repr.tag, err)
+	}
+	if repr.content != "" {
+		return name, fields[repr.content], nil
+	}
+	return name, raw, nil
+}
+
+// decodeStrict decodes the given data, while rejecting unknown fields. This is
+// used for trying the variants of untagged enums.
+func decodeStrict(raw msgpack.RawMessage, v interface{}) error {
+	if isNil(raw) {
+		return errors.New("unexpected nil value")
+	}
+	dec := msgpack.NewDecoder(bytes.NewReader(raw))
+	dec.DisallowUnknownFields(true)
+	return dec.Decode(v)
+}
+
+func isNil(raw msgpack.RawMessage) bool {
+	return len(raw) == 1 && raw[0] == 0xc0
+}
+
+func noVariantSet(enum string) error {
+	return fmt.Errorf("no variant set for enum %s", enum)
+}
+
+func unknownVariant(enum, name string) error {
+	return fmt.Errorf("unknown variant %q for enum %s", name, enum)
+}
+
+func noMatchingVariant(enum string) error {
+	return fmt.Errorf("data did not match any variant of untagged enum %s", enum)
+}
@@ -0,0 +1,632 @@
+// ============================================= //
+// Types for WebAssembly runtime                 //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package bindings
+
+import "github.com/vmihailenco/msgpack/v5"
+
+type Body = []byte
+
+type DocExampleEnumVariant2 struct {
+	// Variant property.
+	Inner int8 `msgpack:"inner"`
+}
+
+// # This is an enum with doc comments.
+type DocExampleEnum struct {
+	// Multi-line doc comment with complex characters
+	// & " , \ ! '
+	Variant1 *string
+	// Raw identifiers are supported too.
+	Variant2 *DocExampleEnumVariant2
+}
+
+func (v DocExampleEnum) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Variant1 != nil:
+		return encodeVariant(enc, enumRepr{}, "Variant1", v.Variant1)
+	case v.Variant2 != nil:
+		return encodeVariant(enc, enumRepr{}, "Variant2", v.Variant2)
+	}
+	return noVariantSet("DocExampleEnum")
+}
+
+func (v *DocExampleEnum) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{})
+	if err != nil {
+		return err
+	}
+	*v = DocExampleEnum{}
+	switch name {
+	case "Variant1":
+		v.Variant1 = new(string)
+		return msgpack.Unmarshal(content, v.Variant1)
+	case "Variant2":
+		v.Variant2 = new(DocExampleEnumVariant2)
+		return msgpack.Unmarshal(content, v.Variant2)
+	}
+	return unknownVariant("DocExampleEnum", name)
+}
+
+// # This is a struct with doc comments.
+type DocExampleStruct struct {
+	// Multi-line doc comment with complex characters
+	// & " , \ ! '
+	MultiLine string `msgpack:"multi_line"`
+	// Raw identifiers are supported too.
+	Type      string `msgpack:"type"`
+}
+
+// A point of an arbitrary type, with explicit trait bounds.
+type ExplicitBoundPoint[T any] struct {
+	Value T `msgpack:"value"`
+}
+
+// This struct is also not referenced by any function or data structure, but
+// it will show up because there is an explicit `use` statement for it in the
+// `fp_import!` macro.
+type ExplicitedlyImportedType struct {
+	YouWillSeeThis bool `msgpack:"you_will_see_this"`
+}
+
+type FlattenedStruct struct {
+	Foo string `msgpack:"foo"`
+	Bar int64  `msgpack:"bar"`
+}
+
+type FloatingPoint = Point[float64]
+
+type FpAdjacentlyTaggedBaz struct {
+	A int8   `msgpack:"a"`
+	B uint64 `msgpack:"b"`
+}
+
+type FpAdjacentlyTagged struct {
+	Foo *struct{}
+	Bar *string
+	Baz *FpAdjacentlyTaggedBaz
+}
+
+func (v FpAdjacentlyTagged) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Foo != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "Foo", nil)
+	case v.Bar != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "Bar", v.Bar)
+	case v.Baz != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "Baz", v.Baz)
+	}
+	return noVariantSet("FpAdjacentlyTagged")
+}
+
+func (v *FpAdjacentlyTagged) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{tag: "type", content: "payload"})
+	if err != nil {
+		return err
+	}
+	*v = FpAdjacentlyTagged{}
+	switch name {
+	case "Foo":
+		v.Foo = &struct{}{}
+		return nil
+	case "Bar":
+		v.Bar = new(string)
+		return msgpack.Unmarshal(content, v.Bar)
+	case "Baz":
+		v.Baz = new(FpAdjacentlyTaggedBaz)
+		return msgpack.Unmarshal(content, v.Baz)
+	}
+	return unknownVariant("FpAdjacentlyTagged", name)
+}
+
+type FpFlatten struct {
+	FlattenedStruct
+}
+
+type FpInternallyTaggedBaz struct {
+	A int8   `msgpack:"a"`
+	B uint64 `msgpack:"b"`
+}
+
+type FpInternallyTagged struct {
+	Foo *struct{}
+	Baz *FpInternallyTaggedBaz
+}
+
+func (v FpInternallyTagged) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Foo != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "Foo", nil)
+	case v.Baz != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "Baz", v.Baz)
+	}
+	return noVariantSet("FpInternallyTagged")
+}
+
+func (v *FpInternallyTagged) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{tag: "type"})
+	if err != nil {
+		return err
+	}
+	*v = FpInternallyTagged{}
+	switch name {
+	case "Foo":
+		v.Foo = &struct{}{}
+		return nil
+	case "Baz":
+		v.Baz = new(FpInternallyTaggedBaz)
+		return msgpack.Unmarshal(content, v.Baz)
+	}
+	return unknownVariant("FpInternallyTagged", name)
+}
+
+type FpPropertyRenaming struct {
+	FooBar    string  `msgpack:"fooBar"`
+	QuxBaz    float64 `msgpack:"QUX_BAZ"`
+	RawStruct int32   `msgpack:"rawStruct"`
+}
+
+type FpUntaggedBaz struct {
+	A int8   `msgpack:"a"`
+	B uint64 `msgpack:"b"`
+}
+
+type FpUntagged struct {
+	Bar *string
+	Baz *FpUntaggedBaz
+}
+
+func (v FpUntagged) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Bar != nil:
+		return encodeVariant(enc, enumRepr{untagged: true}, "Bar", v.Bar)
+	case v.Baz != nil:
+		return encodeVariant(enc, enumRepr{untagged: true}, "Baz", v.Baz)
+	}
+	return noVariantSet("FpUntagged")
+}
+
+func (v *FpUntagged) DecodeMsgpack(dec *msgpack.Decoder) error {
+	raw, err := dec.DecodeRaw()
+	if err != nil {
+		return err
+	}
+	*v = FpUntagged{}
+	if value := new(string); decodeStrict(raw, value) == nil {
+		v.Bar = value
+		return nil
+	}
+	if value := new(FpUntaggedBaz); decodeStrict(raw, value) == nil {
+		v.Baz = value
+		return nil
+	}
+	return noMatchingVariant("FpUntagged")
+}
+
+type FpVariantRenamingQuxBaz struct {
+	// Will be renamed to "FOO_BAR" because of the `rename_all` on the
+	// variant.
+	FooBar string  `msgpack:"FOO_BAR"`
+	QuxBaz float64 `msgpack:"qux_baz"`
+}
+
+type FpVariantRenaming struct {
+	FooBar *struct{}
+	QuxBaz *FpVariantRenamingQuxBaz
+}
+
+func (v FpVariantRenaming) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.FooBar != nil:
+		return encodeVariant(enc, enumRepr{}, "foo_bar", nil)
+	case v.QuxBaz != nil:
+		return encodeVariant(enc, enumRepr{}, "QUX_BAZ", v.QuxBaz)
+	}
+	return noVariantSet("FpVariantRenaming")
+}
+
+func (v *FpVariantRenaming) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{})
+	if err != nil {
+		return err
+	}
+	*v = FpVariantRenaming{}
+	switch name {
+	case "foo_bar":
+		v.FooBar = &struct{}{}
+		return nil
+	case "QUX_BAZ":
+		v.QuxBaz = new(FpVariantRenamingQuxBaz)
+		return msgpack.Unmarshal(content, v.QuxBaz)
+	}
+	return unknownVariant("FpVariantRenaming", name)
+}
+
+type GroupImportedType1 struct {
+	YouWillSeeThis bool `msgpack:"you_will_see_this"`
+}
+
+type GroupImportedType2 struct {
+	YouWillSeeThis bool `msgpack:"you_will_see_this"`
+}
+
+type HttpResult = Result[Response, RequestError]
+
+type Int64 = uint64
+
+// Our struct for passing date time instances.
+//
+// We wrap the `OffsetDateTime` type in a new struct so that the Serde
+// attributes can be inserted. These are necessary to enable RFC3339
+// formatting. Without a wrapper type like this, we would not be able to pass
+// date time instances directly to function arguments and we might run into
+// trouble embedding them into certain generic types.
+type MyDateTime string
+
+// A point of an arbitrary type.
+type Point[T any] struct {
+	Value T `msgpack:"value"`
+}
+
+type ReduxActionUpdateTitle struct {
+	Title string `msgpack:"title"`
+}
+
+// Example for representing Redux actions.
+type ReduxAction struct {
+	ClearTitle  *struct{}
+	UpdateTitle *ReduxActionUpdateTitle
+}
+
+func (v ReduxAction) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.ClearTitle != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "clear_title", nil)
+	case v.UpdateTitle != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "update_title", v.UpdateTitle)
+	}
+	return noVariantSet("ReduxAction")
+}
+
+func (v *ReduxAction) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{tag: "type", content: "payload"})
+	if err != nil {
+		return err
+	}
+	*v = ReduxAction{}
+	switch name {
+	case "clear_title":
+		v.ClearTitle = &struct{}{}
+		return nil
+	case "update_title":
+		v.UpdateTitle = new(ReduxActionUpdateTitle)
+		return msgpack.Unmarshal(content, v.UpdateTitle)
+	}
+	return unknownVariant("ReduxAction", name)
+}
+
+// Represents an HTTP request to be sent.
+type Request struct {
+	// The URI to submit the request to.
+	Url     string      `msgpack:"url"`
+	// HTTP method to use for the request.
+	Method  interface{} `msgpack:"method"`
+	// HTTP headers to submit with the request.
+	Headers interface{} `msgpack:"headers"`
+	// The body to submit with the request.
+	Body    *Body       `msgpack:"body,omitempty"`
+}
+
+type RequestErrorServerError struct {
+	// HTTP status code.
+	StatusCode uint16 `msgpack:"status_code"`
+	// Response body.
+	Response   Body   `msgpack:"response"`
+}
+
+type RequestErrorOther struct {
+	Reason string `msgpack:"reason"`
+}
+
+// Represents an error that occurred while attempting to submit the request.
+type RequestError struct {
+	// Used when we know we don't have an active network connection.
+	Offline           *struct{}
+	NoRoute           *struct{}
+	ConnectionRefused *struct{}
+	Timeout           *struct{}
+	ServerError       *RequestErrorServerError
+	// Misc.
+	Other             *RequestErrorOther
+}
+
+func (v RequestError) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Offline != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "offline", nil)
+	case v.NoRoute != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "no_route", nil)
+	case v.ConnectionRefused != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "connection_refused", nil)
+	case v.Timeout != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "timeout", nil)
+	case v.ServerError != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "server_error", v.ServerError)
+	case v.Other != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "other/misc", v.Other)
+	}
+	return noVariantSet("RequestError")
+}
+
+func (v *RequestError) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{tag: "type"})
+	if err != nil {
+		return err
+	}
+	*v = RequestError{}
+	switch name {
+	case "offline":
+		v.Offline = &struct{}{}
+		return nil
+	case "no_route":
+		v.NoRoute = &struct{}{}
+		return nil
+	case "connection_refused":
+		v.ConnectionRefused = &struct{}{}
+		return nil
+	case "timeout":
+		v.Timeout = &struct{}{}
+		return nil
+	case "server_error":
+		v.ServerError = new(RequestErrorServerError)
+		return msgpack.Unmarshal(content, v.ServerError)
+	case "other/misc":
+		v.Other = new(RequestErrorOther)
+		return msgpack.Unmarshal(content, v.Other)
+	}
+	return unknownVariant("RequestError", name)
+}
+
+// Represents an HTTP response we received.
+//
+// Please note we currently do not support streaming responses.
+type Response struct {
+	// The response body. May be empty.
+	Body       Body        `msgpack:"body"`
+	// HTTP headers that were part of the response.
+	Headers    interface{} `msgpack:"headers"`
+	// HTTP status code.
+	StatusCode uint16      `msgpack:"status_code"`
+}
+
+// A result that can be either successful (`Ok`) or represent an error (`Err`).
+type Result[T any, E any] struct {
+	// Represents a successful result.
+	Ok  *T
+	// Represents an error.
+	Err *E
+}
+
+func (v Result[T, E]) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Ok != nil:
+		return encodeVariant(enc, enumRepr{}, "Ok", v.Ok)
+	case v.Err != nil:
+		return encodeVariant(enc, enumRepr{}, "Err", v.Err)
+	}
+	return noVariantSet("Result")
+}
+
+func (v *Result[T, E]) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{})
+	if err != nil {
+		return err
+	}
+	*v = Result[T, E]{}
+	switch name {
+	case "Ok":
+		v.Ok = new(T)
+		return msgpack.Unmarshal(content, v.Ok)
+	case "Err":
+		v.Err = new(E)
+		return msgpack.Unmarshal(content, v.Err)
+	}
+	return unknownVariant("Result", name)
+}
+
+type SerdeAdjacentlyTaggedBaz struct {
+	A int8   `msgpack:"a"`
+	B uint64 `msgpack:"b"`
+}
+
+type SerdeAdjacentlyTagged struct {
+	Foo *struct{}
+	Bar *string
+	Baz *SerdeAdjacentlyTaggedBaz
+}
+
+func (v SerdeAdjacentlyTagged) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Foo != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "Foo", nil)
+	case v.Bar != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "Bar", v.Bar)
+	case v.Baz != nil:
+		return encodeVariant(enc, enumRepr{tag: "type", content: "payload"}, "Baz", v.Baz)
+	}
+	return noVariantSet("SerdeAdjacentlyTagged")
+}
+
+func (v *SerdeAdjacentlyTagged) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{tag: "type", content: "payload"})
+	if err != nil {
+		return err
+	}
+	*v = SerdeAdjacentlyTagged{}
+	switch name {
+	case "Foo":
+		v.Foo = &struct{}{}
+		return nil
+	case "Bar":
+		v.Bar = new(string)
+		return msgpack.Unmarshal(content, v.Bar)
+	case "Baz":
+		v.Baz = new(SerdeAdjacentlyTaggedBaz)
+		return msgpack.Unmarshal(content, v.Baz)
+	}
+	return unknownVariant("SerdeAdjacentlyTagged", name)
+}
+
+type SerdeFlatten struct {
+	FlattenedStruct
+}
+
+type SerdeInternallyTaggedBaz struct {
+	A int8   `msgpack:"a"`
+	B uint64 `msgpack:"b"`
+}
+
+type SerdeInternallyTagged struct {
+	Foo *struct{}
+	Baz *SerdeInternallyTaggedBaz
+}
+
+func (v SerdeInternallyTagged) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Foo != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "Foo", nil)
+	case v.Baz != nil:
+		return encodeVariant(enc, enumRepr{tag: "type"}, "Baz", v.Baz)
+	}
+	return noVariantSet("SerdeInternallyTagged")
+}
+
+func (v *SerdeInternallyTagged) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{tag: "type"})
+	if err != nil {
+		return err
+	}
+	*v = SerdeInternallyTagged{}
+	switch name {
+	case "Foo":
+		v.Foo = &struct{}{}
+		return nil
+	case "Baz":
+		v.Baz = new(SerdeInternallyTaggedBaz)
+		return msgpack.Unmarshal(content, v.Baz)
+	}
+	return unknownVariant("SerdeInternallyTagged", name)
+}
+
+type SerdePropertyRenaming struct {
+	FooBar    string  `msgpack:"fooBar"`
+	QuxBaz    float64 `msgpack:"QUX_BAZ"`
+	RawStruct int32   `msgpack:"rawStruct"`
+}
+
+type SerdeUntaggedBaz struct {
+	A int8   `msgpack:"a"`
+	B uint64 `msgpack:"b"`
+}
+
+type SerdeUntagged struct {
+	Bar *string
+	Baz *SerdeUntaggedBaz
+}
+
+func (v SerdeUntagged) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.Bar != nil:
+		return encodeVariant(enc, enumRepr{untagged: true}, "Bar", v.Bar)
+	case v.Baz != nil:
+		return encodeVariant(enc, enumRepr{untagged: true}, "Baz", v.Baz)
+	}
+	return noVariantSet("SerdeUntagged")
+}
+
+func (v *SerdeUntagged) DecodeMsgpack(dec *msgpack.Decoder) error {
+	raw, err := dec.DecodeRaw()
+	if err != nil {
+		return err
+	}
+	*v = SerdeUntagged{}
+	if value := new(string); decodeStrict(raw, value) == nil {
+		v.Bar = value
+		return nil
+	}
+	if value := new(SerdeUntaggedBaz); decodeStrict(raw, value) == nil {
+		v.Baz = value
+		return nil
+	}
+	return noMatchingVariant("SerdeUntagged")
+}
+
+type SerdeVariantRenamingQuxBaz struct {
+	// Will be renamed to "FooBar" because of the `rename_all` on the
+	// variant.
+	FooBar string  `msgpack:"FooBar"`
+	QuxBaz float64 `msgpack:"qux_baz"`
+}
+
+type SerdeVariantRenaming struct {
+	FooBar *struct{}
+	QuxBaz *SerdeVariantRenamingQuxBaz
+}
+
+func (v SerdeVariantRenaming) EncodeMsgpack(enc *msgpack.Encoder) error {
+	switch {
+	case v.FooBar != nil:
+		return encodeVariant(enc, enumRepr{}, "foo_bar", nil)
+	case v.QuxBaz != nil:
+		return encodeVariant(enc, enumRepr{}, "QUX_BAZ", v.QuxBaz)
+	}
+	return noVariantSet("SerdeVariantRenaming")
+}
+
+func (v *SerdeVariantRenaming) DecodeMsgpack(dec *msgpack.Decoder) error {
+	name, content, err := decodeVariant(dec, enumRepr{})
+	if err != nil {
+		return err
+	}
+	*v = SerdeVariantRenaming{}
+	switch name {
+	case "foo_bar":
+		v.FooBar = &struct{}{}
+		return nil
+	case "QUX_BAZ":
+		v.QuxBaz = new(SerdeVariantRenamingQuxBaz)
+		return msgpack.Unmarshal(content, v.QuxBaz)
+	}
+	return unknownVariant("SerdeVariantRenaming", name)
+}
+
+// A state update to communicate to the Redux host.
+//
+// Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+// changed.
+type StateUpdate struct {
+	Title    *string `msgpack:"title"`
+	Revision *uint16 `msgpack:"revision"`
+}
+
+type StructWithGenerics[T any] struct {
+	List              []T                         `msgpack:"list"`
+	Points            []Point[T]                  `msgpack:"points"`
+	Recursive         []Point[Point[T]]           `msgpack:"recursive"`
+	ComplexNested     *map[string][]FloatingPoint `msgpack:"complex_nested"`
+	OptionalTimestamp *MyDateTime                 `msgpack:"optional_timestamp"`
+}
+
+type StructWithOptions struct {
+	FilledString                   string  `msgpack:"filledString,omitempty"`
+	EmptyString                    string  `msgpack:"emptyString,omitempty"`
+	FilledOptionString             *string `msgpack:"filledOptionString,omitempty"`
+	EmptyOptionString              *string `msgpack:"emptyOptionString,omitempty"`
+	NeverSkippedFilledOptionString *string `msgpack:"neverSkippedFilledOptionString"`
+	NeverSkippedEmptyOptionString  *string `msgpack:"neverSkippedEmptyOptionString"`
+}
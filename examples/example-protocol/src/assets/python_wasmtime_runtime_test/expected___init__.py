@@ -0,0 +1,16 @@
+# ============================================= #
+# WebAssembly runtime for Python                #
+#                                               #
+# This file is generated. PLEASE DO NOT MODIFY. #
+# ============================================= #
+
+from . import types
+from .runtime import (
+    PROTOCOL_VERSION,
+    FPFunctionNotExported,
+    FPGuestPanic,
+    FPProtocolMismatch,
+    FPRuntimeError,
+    Imports,
+    Runtime,
+)
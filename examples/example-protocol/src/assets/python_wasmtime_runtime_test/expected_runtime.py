@@ -0,0 +1,1305 @@
+# ============================================= #
+# WebAssembly runtime for Python                #
+#                                               #
+# This file is generated. PLEASE DO NOT MODIFY. #
+# ============================================= #
+# pylint: disable=unused-argument,unused-variable
+
+from __future__ import annotations
+
+import asyncio
+import logging
+from typing import Any, Coroutine, Dict, Optional, Protocol, Set, Tuple, Union
+
+import msgpack
+from wasmtime import Engine, FuncType, Linker, Module, Store, ValType
+
+from . import types
+
+FatPtr = int
+
+# Extension bit in the length of a fat pointer, which indicates the length is
+# stored in a 4-byte little-endian header, directly followed by the data.
+FAT_PTR_LEN_HEADER = 0x0100_0000
+FAT_PTR_MAX_LEN = 0x00FF_FFFF
+
+# Fingerprint of the protocol these bindings were generated from.
+#
+# Plugins built against a different protocol are rejected by `Runtime()`.
+PROTOCOL_VERSION = 0x1e72896d627cc76e
+
+logger = logging.getLogger(__name__)
+
+
+class Imports(Protocol):
+    """The host functions that may be imported by the plugin."""
+
+    def import_array_f32(self, arg: List[float]) -> List[float]:
+        ...
+
+    def import_array_f64(self, arg: List[float]) -> List[float]:
+        ...
+
+    def import_array_i16(self, arg: List[int]) -> List[int]:
+        ...
+
+    def import_array_i32(self, arg: List[int]) -> List[int]:
+        ...
+
+    def import_array_i8(self, arg: List[int]) -> List[int]:
+        ...
+
+    def import_array_u16(self, arg: List[int]) -> List[int]:
+        ...
+
+    def import_array_u32(self, arg: List[int]) -> List[int]:
+        ...
+
+    def import_array_u8(self, arg: List[int]) -> List[int]:
+        ...
+
+    def import_explicit_bound_point(self, arg: types.ExplicitBoundPoint) -> None:
+        ...
+
+    def import_fp_adjacently_tagged(self, arg: types.FpAdjacentlyTagged) -> types.FpAdjacentlyTagged:
+        ...
+
+    def import_fp_enum(self, arg: types.FpVariantRenaming) -> types.FpVariantRenaming:
+        ...
+
+    def import_fp_flatten(self, arg: types.FpFlatten) -> types.FpFlatten:
+        ...
+
+    def import_fp_internally_tagged(self, arg: types.FpInternallyTagged) -> types.FpInternallyTagged:
+        ...
+
+    def import_fp_struct(self, arg: types.FpPropertyRenaming) -> types.FpPropertyRenaming:
+        ...
+
+    def import_fp_untagged(self, arg: types.FpUntagged) -> types.FpUntagged:
+        ...
+
+    def import_generics(self, arg: types.StructWithGenerics) -> types.StructWithGenerics:
+        ...
+
+    def import_get_bytes(self) -> types.Result:
+        ...
+
+    def import_get_serde_bytes(self) -> types.Result:
+        ...
+
+    async def import_increment_global_state(self) -> None:
+        ...
+
+    def import_multiple_primitives(self, arg1: int, arg2: str) -> int:
+        ...
+
+    def import_primitive_bool_negate(self, arg: bool) -> bool:
+        ...
+
+    async def import_primitive_bool_negate_async(self, arg: bool) -> bool:
+        ...
+
+    def import_primitive_f32_add_one(self, arg: float) -> float:
+        ...
+
+    async def import_primitive_f32_add_one_async(self, arg: float) -> float:
+        ...
+
+    def import_primitive_f32_add_one_wasmer2(self, arg: List[float]) -> float:
+        ...
+
+    def import_primitive_f64_add_one(self, arg: float) -> float:
+        ...
+
+    async def import_primitive_f64_add_one_async(self, arg: float) -> float:
+        ...
+
+    def import_primitive_f64_add_one_wasmer2(self, arg: List[float]) -> float:
+        ...
+
+    def import_primitive_i16_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_i16_add_one_async(self, arg: int) -> int:
+        ...
+
+    def import_primitive_i32_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_i32_add_one_async(self, arg: int) -> int:
+        ...
+
+    def import_primitive_i64_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_i64_add_one_async(self, arg: int) -> int:
+        ...
+
+    def import_primitive_i8_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_i8_add_one_async(self, arg: int) -> int:
+        ...
+
+    def import_primitive_u16_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_u16_add_one_async(self, arg: int) -> int:
+        ...
+
+    def import_primitive_u32_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_u32_add_one_async(self, arg: int) -> int:
+        ...
+
+    def import_primitive_u64_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_u64_add_one_async(self, arg: int) -> int:
+        ...
+
+    def import_primitive_u8_add_one(self, arg: int) -> int:
+        ...
+
+    async def import_primitive_u8_add_one_async(self, arg: int) -> int:
+        ...
+
+    async def import_reset_global_state(self) -> None:
+        ...
+
+    def import_serde_adjacently_tagged(self, arg: types.SerdeAdjacentlyTagged) -> types.SerdeAdjacentlyTagged:
+        ...
+
+    def import_serde_enum(self, arg: types.SerdeVariantRenaming) -> types.SerdeVariantRenaming:
+        ...
+
+    def import_serde_flatten(self, arg: types.SerdeFlatten) -> types.SerdeFlatten:
+        ...
+
+    def import_serde_internally_tagged(self, arg: types.SerdeInternallyTagged) -> types.SerdeInternallyTagged:
+        ...
+
+    def import_serde_struct(self, arg: types.SerdePropertyRenaming) -> types.SerdePropertyRenaming:
+        ...
+
+    def import_serde_untagged(self, arg: types.SerdeUntagged) -> types.SerdeUntagged:
+        ...
+
+    def import_string(self, arg: str) -> str:
+        ...
+
+    def import_struct_with_options(self, arg: types.StructWithOptions) -> types.StructWithOptions:
+        ...
+
+    def import_timestamp(self, arg: types.MyDateTime) -> types.MyDateTime:
+        ...
+
+    def import_void_function(self) -> None:
+        ...
+
+    def import_void_function_empty_result(self) -> types.Result:
+        ...
+
+    def import_void_function_empty_return(self) -> None:
+        ...
+
+    def log(self, message: str) -> None:
+        """
+        Logs a message to the (development) console.
+        """
+        ...
+
+    async def make_http_request(self, request: types.Request) -> types.HttpResult:
+        """
+        Example how a runtime could expose a `Fetch`-like function to plugins.
+
+        See `types/http.rs` for more info.
+        """
+        ...
+
+
+class FPRuntimeError(Exception):
+    """
+    Represents an unrecoverable error in the FP runtime.
+
+    After this, your only recourse is to create a new runtime, probably with a
+    different WASM plugin.
+    """
+
+
+class FPGuestPanic(FPRuntimeError):
+    """Represents a panic that occurred inside the WASM plugin."""
+
+    def __init__(self, message: str, location: Optional[str]):
+        super().__init__(message)
+        self.message = message
+        self.location = location
+
+
+class FPProtocolMismatch(FPRuntimeError):
+    """Indicates the plugin was built against a different protocol than the runtime."""
+
+    def __init__(self, expected: int, found: int):
+        super().__init__(
+            "Plugin was built against a different protocol "
+            f"(expected version {expected:#018x}, found {found:#018x})"
+        )
+        self.expected = expected
+        self.found = found
+
+
+class FPFunctionNotExported(FPRuntimeError):
+    """Indicates the plugin does not implement the exported function that was called."""
+
+    def __init__(self, name: str):
+        super().__init__(f'Plugin did not export expected function: "{name}"')
+        self.name = name
+
+
+class Runtime:
+    """
+    Runtime for executing a plugin.
+
+    Every runtime holds its own instance of the plugin, along with the `Imports`
+    implementation it was created with.
+    """
+
+    def __init__(self, wasm_module: bytes, imports: Imports, engine: Optional[Engine] = None):
+        """
+        Creates a runtime for executing the given plugin.
+
+        :param wasm_module: The raw WASM plugin.
+        :param imports: The host functions that may be imported by the plugin.
+        :param engine: The Wasmtime engine to compile the plugin with. A new
+            engine is created if none is given.
+        """
+        engine = engine or Engine()
+        self._imports = imports
+        self._store = Store(engine)
+        self._pending: Dict[FatPtr, Union[asyncio.Future, FatPtr]] = {}
+        self._tasks: Set[asyncio.Task] = set()
+
+        linker = Linker(engine)
+        self._define_imports(linker)
+        instance = linker.instantiate(self._store, Module(engine, wasm_module))
+        self._exports = instance.exports(self._store)
+
+        self._memory = self._get_export("memory")
+        self._malloc = self._get_export("__fp_malloc")
+        self._free = self._get_export("__fp_free")
+
+        # Reactor modules, such as plugins written in TinyGo, need to initialize
+        # themselves before any of their other exports may be called.
+        initialize = self._exports.get("_initialize")
+        if initialize:
+            initialize(self._store)
+
+        # Plugins built with older bindings don't export their protocol version,
+        # so they cannot be verified.
+        protocol_version = self._exports.get("__fp_protocol_version")
+        if protocol_version:
+            found_version = _to_unsigned(protocol_version(self._store), 64)
+            if found_version != PROTOCOL_VERSION:
+                raise FPProtocolMismatch(PROTOCOL_VERSION, found_version)
+
+    def export_array_f32(self, arg: List[float]) -> List[float]:
+        export_fn = self._get_function("export_array_f32")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_array_f64(self, arg: List[float]) -> List[float]:
+        export_fn = self._get_function("export_array_f64")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_array_i16(self, arg: List[int]) -> List[int]:
+        export_fn = self._get_function("export_array_i16")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_array_i32(self, arg: List[int]) -> List[int]:
+        export_fn = self._get_function("export_array_i32")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_array_i8(self, arg: List[int]) -> List[int]:
+        export_fn = self._get_function("export_array_i8")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_array_u16(self, arg: List[int]) -> List[int]:
+        export_fn = self._get_function("export_array_u16")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_array_u32(self, arg: List[int]) -> List[int]:
+        export_fn = self._get_function("export_array_u32")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_array_u8(self, arg: List[int]) -> List[int]:
+        export_fn = self._get_function("export_array_u8")
+        arg_ptr = self._serialize_object(list(arg))
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    async def export_async_struct(self, arg1: types.FpPropertyRenaming, arg2: int) -> types.FpPropertyRenaming:
+        export_fn = self._get_function("export_async_struct")
+        arg1_ptr = self._serialize_object(arg1)
+        async_value_ptr = export_fn(self._store, arg1_ptr, _to_signed(arg2, 64))
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_fp_adjacently_tagged(self, arg: types.FpAdjacentlyTagged) -> types.FpAdjacentlyTagged:
+        export_fn = self._get_function("export_fp_adjacently_tagged")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_fp_enum(self, arg: types.FpVariantRenaming) -> types.FpVariantRenaming:
+        export_fn = self._get_function("export_fp_enum")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_fp_flatten(self, arg: types.FpFlatten) -> types.FpFlatten:
+        export_fn = self._get_function("export_fp_flatten")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_fp_internally_tagged(self, arg: types.FpInternallyTagged) -> types.FpInternallyTagged:
+        export_fn = self._get_function("export_fp_internally_tagged")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_fp_struct(self, arg: types.FpPropertyRenaming) -> types.FpPropertyRenaming:
+        export_fn = self._get_function("export_fp_struct")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_fp_untagged(self, arg: types.FpUntagged) -> types.FpUntagged:
+        export_fn = self._get_function("export_fp_untagged")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_generics(self, arg: types.StructWithGenerics) -> types.StructWithGenerics:
+        export_fn = self._get_function("export_generics")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_get_bytes(self) -> types.Result:
+        export_fn = self._get_function("export_get_bytes")
+        return self._parse_object(export_fn(self._store))
+
+    def export_get_serde_bytes(self) -> types.Result:
+        export_fn = self._get_function("export_get_serde_bytes")
+        return self._parse_object(export_fn(self._store))
+
+    async def export_increment_global_state(self) -> None:
+        export_fn = self._get_function("export_increment_global_state")
+        async_value_ptr = export_fn(self._store)
+        self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_multiple_primitives(self, arg1: int, arg2: str) -> int:
+        export_fn = self._get_function("export_multiple_primitives")
+        arg2_ptr = self._serialize_object(arg2)
+        return export_fn(self._store, arg1, arg2_ptr)
+
+    def export_panic(self, message: str) -> None:
+        """
+        Panics with the given message, so the runtimes can test guest panics
+        are reported to them.
+        """
+        export_fn = self._get_function("export_panic")
+        message_ptr = self._serialize_object(message)
+        export_fn(self._store, message_ptr)
+
+    def export_primitive_bool_negate(self, arg: bool) -> bool:
+        export_fn = self._get_function("export_primitive_bool_negate")
+        return _to_unsigned(export_fn(self._store, int(arg)), 8) != 0
+
+    async def export_primitive_bool_negate_async(self, arg: bool) -> bool:
+        export_fn = self._get_function("export_primitive_bool_negate_async")
+        async_value_ptr = export_fn(self._store, int(arg))
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_f32_add_three(self, arg: float) -> float:
+        export_fn = self._get_function("export_primitive_f32_add_three")
+        return export_fn(self._store, arg)
+
+    async def export_primitive_f32_add_three_async(self, arg: float) -> float:
+        export_fn = self._get_function("export_primitive_f32_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_f32_add_three_wasmer2(self, arg: float) -> float:
+        export_fn = self._get_function("export_primitive_f32_add_three_wasmer2")
+        return export_fn(self._store, arg)
+
+    def export_primitive_f64_add_three(self, arg: float) -> float:
+        export_fn = self._get_function("export_primitive_f64_add_three")
+        return export_fn(self._store, arg)
+
+    async def export_primitive_f64_add_three_async(self, arg: float) -> float:
+        export_fn = self._get_function("export_primitive_f64_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_f64_add_three_wasmer2(self, arg: float) -> float:
+        export_fn = self._get_function("export_primitive_f64_add_three_wasmer2")
+        return export_fn(self._store, arg)
+
+    def export_primitive_i16_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i16_add_three")
+        return _to_signed(export_fn(self._store, arg), 16)
+
+    async def export_primitive_i16_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i16_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_i32_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i32_add_three")
+        return export_fn(self._store, arg)
+
+    async def export_primitive_i32_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i32_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_i64_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i64_add_three")
+        return export_fn(self._store, arg)
+
+    async def export_primitive_i64_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i64_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_i8_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i8_add_three")
+        return _to_signed(export_fn(self._store, arg), 8)
+
+    async def export_primitive_i8_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_i8_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_u16_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u16_add_three")
+        return _to_unsigned(export_fn(self._store, arg), 16)
+
+    async def export_primitive_u16_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u16_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_u32_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u32_add_three")
+        return _to_unsigned(export_fn(self._store, _to_signed(arg, 32)), 32)
+
+    async def export_primitive_u32_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u32_add_three_async")
+        async_value_ptr = export_fn(self._store, _to_signed(arg, 32))
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_u64_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u64_add_three")
+        return _to_unsigned(export_fn(self._store, _to_signed(arg, 64)), 64)
+
+    async def export_primitive_u64_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u64_add_three_async")
+        async_value_ptr = export_fn(self._store, _to_signed(arg, 64))
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_primitive_u8_add_three(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u8_add_three")
+        return _to_unsigned(export_fn(self._store, arg), 8)
+
+    async def export_primitive_u8_add_three_async(self, arg: int) -> int:
+        export_fn = self._get_function("export_primitive_u8_add_three_async")
+        async_value_ptr = export_fn(self._store, arg)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    async def export_reset_global_state(self) -> None:
+        export_fn = self._get_function("export_reset_global_state")
+        async_value_ptr = export_fn(self._store)
+        self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def export_serde_adjacently_tagged(self, arg: types.SerdeAdjacentlyTagged) -> types.SerdeAdjacentlyTagged:
+        export_fn = self._get_function("export_serde_adjacently_tagged")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_serde_enum(self, arg: types.SerdeVariantRenaming) -> types.SerdeVariantRenaming:
+        export_fn = self._get_function("export_serde_enum")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_serde_flatten(self, arg: types.SerdeFlatten) -> types.SerdeFlatten:
+        export_fn = self._get_function("export_serde_flatten")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_serde_internally_tagged(self, arg: types.SerdeInternallyTagged) -> types.SerdeInternallyTagged:
+        export_fn = self._get_function("export_serde_internally_tagged")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_serde_struct(self, arg: types.SerdePropertyRenaming) -> types.SerdePropertyRenaming:
+        export_fn = self._get_function("export_serde_struct")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_serde_untagged(self, arg: types.SerdeUntagged) -> types.SerdeUntagged:
+        export_fn = self._get_function("export_serde_untagged")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_string(self, arg: str) -> str:
+        export_fn = self._get_function("export_string")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_struct_with_options(self, arg: types.StructWithOptions) -> types.StructWithOptions:
+        export_fn = self._get_function("export_struct_with_options")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_timestamp(self, arg: types.MyDateTime) -> types.MyDateTime:
+        export_fn = self._get_function("export_timestamp")
+        arg_ptr = self._serialize_object(arg)
+        return self._parse_object(export_fn(self._store, arg_ptr))
+
+    def export_void_function(self) -> None:
+        export_fn = self._get_function("export_void_function")
+        export_fn(self._store)
+
+    async def fetch_data(self, type: str) -> types.Result:
+        """
+        Example how plugin could expose async data-fetching capabilities.
+        """
+        export_fn = self._get_function("fetch_data")
+        type_ptr = self._serialize_object(type)
+        async_value_ptr = export_fn(self._store, type_ptr)
+        return self._parse_object(await self._await_async_value(async_value_ptr))
+
+    def init(self) -> None:
+        """
+        Called on the plugin to give it a chance to initialize.
+        """
+        export_fn = self._get_function("init")
+        export_fn(self._store)
+
+    def reducer_bridge(self, action: types.ReduxAction) -> types.StateUpdate:
+        """
+        Example how plugin could expose a reducer.
+        """
+        export_fn = self._get_function("reducer_bridge")
+        action_ptr = self._serialize_object(action)
+        return self._parse_object(export_fn(self._store, action_ptr))
+
+    def _define_imports(self, linker: Linker) -> None:
+        i32, i64, f32, f64 = ValType.i32(), ValType.i64(), ValType.f32(), ValType.f64()
+        linker.define_func("fp", "__fp_host_panic", FuncType([i64], []), self._host_panic)
+        linker.define_func(
+            "fp",
+            "__fp_host_resolve_async_value",
+            FuncType([i64, i64], []),
+            self._resolve_async_value,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_f32",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_f32,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_f64",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_f64,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_i16",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_i16,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_i32",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_i32,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_i8",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_i8,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_u16",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_u16,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_u32",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_u32,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_array_u8",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_array_u8,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_explicit_bound_point",
+            FuncType([i64], []),
+            self._fp_gen_import_explicit_bound_point,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_fp_adjacently_tagged",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_fp_adjacently_tagged,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_fp_enum",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_fp_enum,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_fp_flatten",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_fp_flatten,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_fp_internally_tagged",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_fp_internally_tagged,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_fp_struct",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_fp_struct,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_fp_untagged",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_fp_untagged,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_generics",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_generics,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_get_bytes",
+            FuncType([], [i64]),
+            self._fp_gen_import_get_bytes,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_get_serde_bytes",
+            FuncType([], [i64]),
+            self._fp_gen_import_get_serde_bytes,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_increment_global_state",
+            FuncType([], [i64]),
+            self._fp_gen_import_increment_global_state,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_multiple_primitives",
+            FuncType([i32, i64], [i64]),
+            self._fp_gen_import_multiple_primitives,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_bool_negate",
+            FuncType([i32], [i32]),
+            self._fp_gen_import_primitive_bool_negate,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_bool_negate_async",
+            FuncType([i32], [i64]),
+            self._fp_gen_import_primitive_bool_negate_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_f32_add_one",
+            FuncType([f32], [f32]),
+            self._fp_gen_import_primitive_f32_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_f32_add_one_async",
+            FuncType([f32], [i64]),
+            self._fp_gen_import_primitive_f32_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_f32_add_one_wasmer2",
+            FuncType([i64], [f32]),
+            self._fp_gen_import_primitive_f32_add_one_wasmer2,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_f64_add_one",
+            FuncType([f64], [f64]),
+            self._fp_gen_import_primitive_f64_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_f64_add_one_async",
+            FuncType([f64], [i64]),
+            self._fp_gen_import_primitive_f64_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_f64_add_one_wasmer2",
+            FuncType([i64], [f64]),
+            self._fp_gen_import_primitive_f64_add_one_wasmer2,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i16_add_one",
+            FuncType([i32], [i32]),
+            self._fp_gen_import_primitive_i16_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i16_add_one_async",
+            FuncType([i32], [i64]),
+            self._fp_gen_import_primitive_i16_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i32_add_one",
+            FuncType([i32], [i32]),
+            self._fp_gen_import_primitive_i32_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i32_add_one_async",
+            FuncType([i32], [i64]),
+            self._fp_gen_import_primitive_i32_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i64_add_one",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_primitive_i64_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i64_add_one_async",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_primitive_i64_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i8_add_one",
+            FuncType([i32], [i32]),
+            self._fp_gen_import_primitive_i8_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_i8_add_one_async",
+            FuncType([i32], [i64]),
+            self._fp_gen_import_primitive_i8_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u16_add_one",
+            FuncType([i32], [i32]),
+            self._fp_gen_import_primitive_u16_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u16_add_one_async",
+            FuncType([i32], [i64]),
+            self._fp_gen_import_primitive_u16_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u32_add_one",
+            FuncType([i32], [i32]),
+            self._fp_gen_import_primitive_u32_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u32_add_one_async",
+            FuncType([i32], [i64]),
+            self._fp_gen_import_primitive_u32_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u64_add_one",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_primitive_u64_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u64_add_one_async",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_primitive_u64_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u8_add_one",
+            FuncType([i32], [i32]),
+            self._fp_gen_import_primitive_u8_add_one,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_primitive_u8_add_one_async",
+            FuncType([i32], [i64]),
+            self._fp_gen_import_primitive_u8_add_one_async,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_reset_global_state",
+            FuncType([], [i64]),
+            self._fp_gen_import_reset_global_state,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_serde_adjacently_tagged",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_serde_adjacently_tagged,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_serde_enum",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_serde_enum,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_serde_flatten",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_serde_flatten,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_serde_internally_tagged",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_serde_internally_tagged,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_serde_struct",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_serde_struct,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_serde_untagged",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_serde_untagged,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_string",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_string,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_struct_with_options",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_struct_with_options,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_timestamp",
+            FuncType([i64], [i64]),
+            self._fp_gen_import_timestamp,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_void_function",
+            FuncType([], []),
+            self._fp_gen_import_void_function,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_void_function_empty_result",
+            FuncType([], [i64]),
+            self._fp_gen_import_void_function_empty_result,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_import_void_function_empty_return",
+            FuncType([], []),
+            self._fp_gen_import_void_function_empty_return,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_log",
+            FuncType([i64], []),
+            self._fp_gen_log,
+        )
+        linker.define_func(
+            "fp",
+            "__fp_gen_make_http_request",
+            FuncType([i64], [i64]),
+            self._fp_gen_make_http_request,
+        )
+
+    def _fp_gen_import_array_f32(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_f32(arg))
+
+    def _fp_gen_import_array_f64(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_f64(arg))
+
+    def _fp_gen_import_array_i16(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_i16(arg))
+
+    def _fp_gen_import_array_i32(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_i32(arg))
+
+    def _fp_gen_import_array_i8(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_i8(arg))
+
+    def _fp_gen_import_array_u16(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_u16(arg))
+
+    def _fp_gen_import_array_u32(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_u32(arg))
+
+    def _fp_gen_import_array_u8(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_array_u8(arg))
+
+    def _fp_gen_import_explicit_bound_point(self, arg_ptr: FatPtr) -> None:
+        arg = self._parse_object(arg_ptr)
+        self._imports.import_explicit_bound_point(arg)
+
+    def _fp_gen_import_fp_adjacently_tagged(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_fp_adjacently_tagged(arg))
+
+    def _fp_gen_import_fp_enum(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_fp_enum(arg))
+
+    def _fp_gen_import_fp_flatten(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_fp_flatten(arg))
+
+    def _fp_gen_import_fp_internally_tagged(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_fp_internally_tagged(arg))
+
+    def _fp_gen_import_fp_struct(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_fp_struct(arg))
+
+    def _fp_gen_import_fp_untagged(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_fp_untagged(arg))
+
+    def _fp_gen_import_generics(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_generics(arg))
+
+    def _fp_gen_import_get_bytes(self) -> FatPtr:
+        return self._serialize_object(self._imports.import_get_bytes())
+
+    def _fp_gen_import_get_serde_bytes(self) -> FatPtr:
+        return self._serialize_object(self._imports.import_get_serde_bytes())
+
+    def _fp_gen_import_increment_global_state(self) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_increment_global_state", async_value_ptr, self._imports.import_increment_global_state())
+        return async_value_ptr
+
+    def _fp_gen_import_multiple_primitives(self, arg1: int, arg2_ptr: FatPtr) -> int:
+        arg2 = self._parse_object(arg2_ptr)
+        return self._imports.import_multiple_primitives(_to_signed(arg1, 8), arg2)
+
+    def _fp_gen_import_primitive_bool_negate(self, arg: bool) -> bool:
+        return int(self._imports.import_primitive_bool_negate(_to_unsigned(arg, 8) != 0))
+
+    def _fp_gen_import_primitive_bool_negate_async(self, arg: bool) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_bool_negate_async", async_value_ptr, self._imports.import_primitive_bool_negate_async(_to_unsigned(arg, 8) != 0))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_f32_add_one(self, arg: float) -> float:
+        return self._imports.import_primitive_f32_add_one(arg)
+
+    def _fp_gen_import_primitive_f32_add_one_async(self, arg: float) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_f32_add_one_async", async_value_ptr, self._imports.import_primitive_f32_add_one_async(arg))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_f32_add_one_wasmer2(self, arg_ptr: FatPtr) -> float:
+        arg = self._parse_object(arg_ptr)
+        return self._imports.import_primitive_f32_add_one_wasmer2(arg)
+
+    def _fp_gen_import_primitive_f64_add_one(self, arg: float) -> float:
+        return self._imports.import_primitive_f64_add_one(arg)
+
+    def _fp_gen_import_primitive_f64_add_one_async(self, arg: float) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_f64_add_one_async", async_value_ptr, self._imports.import_primitive_f64_add_one_async(arg))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_f64_add_one_wasmer2(self, arg_ptr: FatPtr) -> float:
+        arg = self._parse_object(arg_ptr)
+        return self._imports.import_primitive_f64_add_one_wasmer2(arg)
+
+    def _fp_gen_import_primitive_i16_add_one(self, arg: int) -> int:
+        return self._imports.import_primitive_i16_add_one(_to_signed(arg, 16))
+
+    def _fp_gen_import_primitive_i16_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_i16_add_one_async", async_value_ptr, self._imports.import_primitive_i16_add_one_async(_to_signed(arg, 16)))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_i32_add_one(self, arg: int) -> int:
+        return self._imports.import_primitive_i32_add_one(arg)
+
+    def _fp_gen_import_primitive_i32_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_i32_add_one_async", async_value_ptr, self._imports.import_primitive_i32_add_one_async(arg))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_i64_add_one(self, arg: int) -> int:
+        return self._imports.import_primitive_i64_add_one(arg)
+
+    def _fp_gen_import_primitive_i64_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_i64_add_one_async", async_value_ptr, self._imports.import_primitive_i64_add_one_async(arg))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_i8_add_one(self, arg: int) -> int:
+        return self._imports.import_primitive_i8_add_one(_to_signed(arg, 8))
+
+    def _fp_gen_import_primitive_i8_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_i8_add_one_async", async_value_ptr, self._imports.import_primitive_i8_add_one_async(_to_signed(arg, 8)))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_u16_add_one(self, arg: int) -> int:
+        return self._imports.import_primitive_u16_add_one(_to_unsigned(arg, 16))
+
+    def _fp_gen_import_primitive_u16_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_u16_add_one_async", async_value_ptr, self._imports.import_primitive_u16_add_one_async(_to_unsigned(arg, 16)))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_u32_add_one(self, arg: int) -> int:
+        return _to_signed(self._imports.import_primitive_u32_add_one(_to_unsigned(arg, 32)), 32)
+
+    def _fp_gen_import_primitive_u32_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_u32_add_one_async", async_value_ptr, self._imports.import_primitive_u32_add_one_async(_to_unsigned(arg, 32)))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_u64_add_one(self, arg: int) -> int:
+        return _to_signed(self._imports.import_primitive_u64_add_one(_to_unsigned(arg, 64)), 64)
+
+    def _fp_gen_import_primitive_u64_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_u64_add_one_async", async_value_ptr, self._imports.import_primitive_u64_add_one_async(_to_unsigned(arg, 64)))
+        return async_value_ptr
+
+    def _fp_gen_import_primitive_u8_add_one(self, arg: int) -> int:
+        return self._imports.import_primitive_u8_add_one(_to_unsigned(arg, 8))
+
+    def _fp_gen_import_primitive_u8_add_one_async(self, arg: int) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_primitive_u8_add_one_async", async_value_ptr, self._imports.import_primitive_u8_add_one_async(_to_unsigned(arg, 8)))
+        return async_value_ptr
+
+    def _fp_gen_import_reset_global_state(self) -> FatPtr:
+        async_value_ptr = self._create_async_value()
+        self._spawn("import_reset_global_state", async_value_ptr, self._imports.import_reset_global_state())
+        return async_value_ptr
+
+    def _fp_gen_import_serde_adjacently_tagged(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_serde_adjacently_tagged(arg))
+
+    def _fp_gen_import_serde_enum(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_serde_enum(arg))
+
+    def _fp_gen_import_serde_flatten(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_serde_flatten(arg))
+
+    def _fp_gen_import_serde_internally_tagged(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_serde_internally_tagged(arg))
+
+    def _fp_gen_import_serde_struct(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_serde_struct(arg))
+
+    def _fp_gen_import_serde_untagged(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_serde_untagged(arg))
+
+    def _fp_gen_import_string(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_string(arg))
+
+    def _fp_gen_import_struct_with_options(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_struct_with_options(arg))
+
+    def _fp_gen_import_timestamp(self, arg_ptr: FatPtr) -> FatPtr:
+        arg = self._parse_object(arg_ptr)
+        return self._serialize_object(self._imports.import_timestamp(arg))
+
+    def _fp_gen_import_void_function(self) -> None:
+        self._imports.import_void_function()
+
+    def _fp_gen_import_void_function_empty_result(self) -> FatPtr:
+        return self._serialize_object(self._imports.import_void_function_empty_result())
+
+    def _fp_gen_import_void_function_empty_return(self) -> None:
+        self._imports.import_void_function_empty_return()
+
+    def _fp_gen_log(self, message_ptr: FatPtr) -> None:
+        message = self._parse_object(message_ptr)
+        self._imports.log(message)
+
+    def _fp_gen_make_http_request(self, request_ptr: FatPtr) -> FatPtr:
+        request = self._parse_object(request_ptr)
+        async_value_ptr = self._create_async_value()
+        self._spawn("make_http_request", async_value_ptr, self._imports.make_http_request(request))
+        return async_value_ptr
+
+    def _host_panic(self, panic_ptr: FatPtr) -> None:
+        panic = self._parse_object(panic_ptr)
+        raise FPGuestPanic(panic["message"], panic.get("location"))
+
+    def _create_async_value(self) -> FatPtr:
+        length = 12  # std::mem::size_of::<AsyncValue>()
+        fat_ptr = self._malloc(self._store, length)
+        ptr, _ = _from_fat_ptr(fat_ptr)
+        self._memory.write(self._store, bytes(length), ptr)
+        return fat_ptr
+
+    def _spawn(self, name: str, async_value_ptr: FatPtr, coroutine: Coroutine) -> None:
+        resolve_future = self._get_export("__fp_guest_resolve_async_value")
+
+        async def run() -> None:
+            try:
+                result = await coroutine
+            except Exception:  # pylint: disable=broad-except
+                logger.exception(
+                    'Unrecoverable exception trying to call async host function "%s"', name
+                )
+                return
+            resolve_future(self._store, async_value_ptr, self._serialize_object(result))
+
+        task = asyncio.get_running_loop().create_task(run())
+        self._tasks.add(task)
+        task.add_done_callback(self._tasks.discard)
+
+    async def _await_async_value(self, async_value_ptr: FatPtr) -> FatPtr:
+        result_ptr = self._pending.pop(async_value_ptr, None)
+        if isinstance(result_ptr, asyncio.Future):
+            raise FPRuntimeError("Already awaiting this value")
+        if result_ptr is not None:
+            return result_ptr
+
+        future = asyncio.get_running_loop().create_future()
+        self._pending[async_value_ptr] = future
+        return await future
+
+    def _resolve_async_value(self, async_value_ptr: FatPtr, result_ptr: FatPtr) -> None:
+        future = self._pending.pop(async_value_ptr, None)
+        if future is None:
+            self._pending[async_value_ptr] = result_ptr
+        elif isinstance(future, asyncio.Future):
+            future.set_result(result_ptr)
+        else:
+            raise FPRuntimeError("Tried to resolve invalid async value")
+
+    def _get_export(self, name: str) -> Any:
+        export = self._exports.get(name)
+        if export is None:
+            raise FPRuntimeError(f'Plugin did not export expected symbol: "{name}"')
+        return export
+
+    def _get_function(self, name: str) -> Any:
+        export = self._exports.get(f"__fp_gen_{name}")
+        if export is None:
+            raise FPFunctionNotExported(name)
+        return export
+
+    def _parse_object(self, fat_ptr: FatPtr) -> Any:
+        data = self._import_from_memory(fat_ptr)
+        if not data:
+            return None
+        return msgpack.unpackb(data, raw=False, strict_map_key=False)
+
+    def _serialize_object(self, value: Any) -> FatPtr:
+        return self._export_to_memory(msgpack.packb(value, use_bin_type=True))
+
+    def _export_to_memory(self, data: bytes) -> FatPtr:
+        fat_ptr = self._malloc(self._store, len(data))
+        ptr, _ = self._resolve_fat_ptr(fat_ptr)
+        self._memory.write(self._store, data, ptr)
+        return fat_ptr
+
+    def _import_from_memory(self, fat_ptr: FatPtr) -> bytes:
+        if fat_ptr == 0:
+            # This may happen with async calls that don't return a result:
+            return b""
+
+        ptr, length = self._resolve_fat_ptr(fat_ptr)
+        data = bytes(self._memory.read(self._store, ptr, ptr + length))
+        self._free(self._store, fat_ptr)
+        return data
+
+    def _resolve_fat_ptr(self, fat_ptr: FatPtr) -> Tuple[int, int]:
+        ptr, length = _from_fat_ptr(fat_ptr)
+        if length == FAT_PTR_LEN_HEADER:
+            header = self._memory.read(self._store, ptr, ptr + 4)
+            return ptr + 4, int.from_bytes(header, "little")
+        if length > FAT_PTR_MAX_LEN:
+            raise FPRuntimeError("Unknown extension bits")
+        return ptr, length
+
+
+def _from_fat_ptr(fat_ptr: FatPtr) -> Tuple[int, int]:
+    fat_ptr = _to_unsigned(fat_ptr, 64)
+    return fat_ptr >> 32, fat_ptr & 0xFFFF_FFFF
+
+
+def _to_signed(value: int, bits: int) -> int:
+    value = _to_unsigned(value, bits)
+    return value - (1 << bits) if value >= 1 << (bits - 1) else value
+
+
+def _to_unsigned(value: int, bits: int) -> int:
+    return value & ((1 << bits) - 1)
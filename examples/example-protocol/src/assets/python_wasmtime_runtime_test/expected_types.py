@@ -0,0 +1,513 @@
+# ============================================= #
+# Types for WebAssembly runtime                 #
+#                                               #
+# This file is generated. PLEASE DO NOT MODIFY. #
+# ============================================= #
+
+from typing import Any, Dict, List, Literal, Optional, Tuple, TypedDict, Union
+
+try:
+    from typing import NotRequired
+except ImportError:  # Python < 3.11
+    from typing_extensions import NotRequired
+
+Body = bytes
+
+# Multi-line doc comment with complex characters
+# & " , \ ! '
+DocExampleEnum_Variant1 = TypedDict(
+    "DocExampleEnum_Variant1",
+    {
+        "Variant1": str,
+    },
+)
+
+DocExampleEnum_Variant2Fields = TypedDict(
+    "DocExampleEnum_Variant2Fields",
+    {
+        # Variant property.
+        "inner": int,
+    },
+)
+
+# Raw identifiers are supported too.
+DocExampleEnum_Variant2 = TypedDict(
+    "DocExampleEnum_Variant2",
+    {
+        "Variant2": "DocExampleEnum_Variant2Fields",
+    },
+)
+
+# # This is an enum with doc comments.
+DocExampleEnum = Union[
+    "DocExampleEnum_Variant1",
+    "DocExampleEnum_Variant2",
+]
+
+# # This is a struct with doc comments.
+DocExampleStruct = TypedDict(
+    "DocExampleStruct",
+    {
+        # Multi-line doc comment with complex characters
+        # & " , \ ! '
+        "multi_line": str,
+        # Raw identifiers are supported too.
+        "type": str,
+    },
+)
+
+# A point of an arbitrary type, with explicit trait bounds.
+ExplicitBoundPoint = TypedDict(
+    "ExplicitBoundPoint",
+    {
+        "value": Any,
+    },
+)
+
+# This struct is also not referenced by any function or data structure, but
+# it will show up because there is an explicit `use` statement for it in the
+# `fp_import!` macro.
+ExplicitedlyImportedType = TypedDict(
+    "ExplicitedlyImportedType",
+    {
+        "you_will_see_this": bool,
+    },
+)
+
+FlattenedStruct = TypedDict(
+    "FlattenedStruct",
+    {
+        "foo": str,
+        "bar": int,
+    },
+)
+
+FpAdjacentlyTagged_Foo = TypedDict(
+    "FpAdjacentlyTagged_Foo",
+    {
+        "type": Literal["Foo"],
+    },
+)
+
+FpAdjacentlyTagged_Bar = TypedDict(
+    "FpAdjacentlyTagged_Bar",
+    {
+        "type": Literal["Bar"],
+        "payload": str,
+    },
+)
+
+FpAdjacentlyTagged_BazFields = TypedDict(
+    "FpAdjacentlyTagged_BazFields",
+    {
+        "a": int,
+        "b": int,
+    },
+)
+
+FpAdjacentlyTagged_Baz = TypedDict(
+    "FpAdjacentlyTagged_Baz",
+    {
+        "type": Literal["Baz"],
+        "payload": "FpAdjacentlyTagged_BazFields",
+    },
+)
+
+FpAdjacentlyTagged = Union[
+    "FpAdjacentlyTagged_Foo",
+    "FpAdjacentlyTagged_Bar",
+    "FpAdjacentlyTagged_Baz",
+]
+
+FpFlatten = TypedDict(
+    "FpFlatten",
+    {
+        "foo": str,
+        "bar": int,
+    },
+)
+
+FpInternallyTagged_Foo = TypedDict(
+    "FpInternallyTagged_Foo",
+    {
+        "type": Literal["Foo"],
+    },
+)
+
+FpInternallyTagged_Baz = TypedDict(
+    "FpInternallyTagged_Baz",
+    {
+        "type": Literal["Baz"],
+        "a": int,
+        "b": int,
+    },
+)
+
+FpInternallyTagged = Union[
+    "FpInternallyTagged_Foo",
+    "FpInternallyTagged_Baz",
+]
+
+FpPropertyRenaming = TypedDict(
+    "FpPropertyRenaming",
+    {
+        "fooBar": str,
+        "QUX_BAZ": float,
+        "rawStruct": int,
+    },
+)
+
+FpUntagged_Baz = TypedDict(
+    "FpUntagged_Baz",
+    {
+        "a": int,
+        "b": int,
+    },
+)
+
+FpUntagged = Union[
+    str,
+    "FpUntagged_Baz",
+]
+
+FpVariantRenaming_QuxBazFields = TypedDict(
+    "FpVariantRenaming_QuxBazFields",
+    {
+        # Will be renamed to "FOO_BAR" because of the `rename_all` on the
+        # variant.
+        "FOO_BAR": str,
+        "qux_baz": float,
+    },
+)
+
+FpVariantRenaming_QuxBaz = TypedDict(
+    "FpVariantRenaming_QuxBaz",
+    {
+        "QUX_BAZ": "FpVariantRenaming_QuxBazFields",
+    },
+)
+
+FpVariantRenaming = Union[
+    Literal["foo_bar"],
+    "FpVariantRenaming_QuxBaz",
+]
+
+GroupImportedType1 = TypedDict(
+    "GroupImportedType1",
+    {
+        "you_will_see_this": bool,
+    },
+)
+
+GroupImportedType2 = TypedDict(
+    "GroupImportedType2",
+    {
+        "you_will_see_this": bool,
+    },
+)
+
+Int64 = int
+
+# Our struct for passing date time instances.
+#
+# We wrap the `OffsetDateTime` type in a new struct so that the Serde
+# attributes can be inserted. These are necessary to enable RFC3339
+# formatting. Without a wrapper type like this, we would not be able to pass
+# date time instances directly to function arguments and we might run into
+# trouble embedding them into certain generic types.
+MyDateTime = str
+
+# A point of an arbitrary type.
+Point = TypedDict(
+    "Point",
+    {
+        "value": Any,
+    },
+)
+
+ReduxAction_ClearTitle = TypedDict(
+    "ReduxAction_ClearTitle",
+    {
+        "type": Literal["clear_title"],
+    },
+)
+
+ReduxAction_UpdateTitleFields = TypedDict(
+    "ReduxAction_UpdateTitleFields",
+    {
+        "title": str,
+    },
+)
+
+ReduxAction_UpdateTitle = TypedDict(
+    "ReduxAction_UpdateTitle",
+    {
+        "type": Literal["update_title"],
+        "payload": "ReduxAction_UpdateTitleFields",
+    },
+)
+
+# Example for representing Redux actions.
+ReduxAction = Union[
+    "ReduxAction_ClearTitle",
+    "ReduxAction_UpdateTitle",
+]
+
+# Represents an HTTP request to be sent.
+Request = TypedDict(
+    "Request",
+    {
+        # The URI to submit the request to.
+        "url": str,
+        # HTTP method to use for the request.
+        "method": Any,
+        # HTTP headers to submit with the request.
+        "headers": Any,
+        # The body to submit with the request.
+        "body": NotRequired[Optional["Body"]],
+    },
+)
+
+RequestError_Offline = TypedDict(
+    "RequestError_Offline",
+    {
+        "type": Literal["offline"],
+    },
+)
+
+RequestError_NoRoute = TypedDict(
+    "RequestError_NoRoute",
+    {
+        "type": Literal["no_route"],
+    },
+)
+
+RequestError_ConnectionRefused = TypedDict(
+    "RequestError_ConnectionRefused",
+    {
+        "type": Literal["connection_refused"],
+    },
+)
+
+RequestError_Timeout = TypedDict(
+    "RequestError_Timeout",
+    {
+        "type": Literal["timeout"],
+    },
+)
+
+RequestError_ServerError = TypedDict(
+    "RequestError_ServerError",
+    {
+        "type": Literal["server_error"],
+        # HTTP status code.
+        "status_code": int,
+        # Response body.
+        "response": "Body",
+    },
+)
+
+# Misc.
+RequestError_Other = TypedDict(
+    "RequestError_Other",
+    {
+        "type": Literal["other/misc"],
+        "reason": str,
+    },
+)
+
+# Represents an error that occurred while attempting to submit the request.
+RequestError = Union[
+    "RequestError_Offline",
+    "RequestError_NoRoute",
+    "RequestError_ConnectionRefused",
+    "RequestError_Timeout",
+    "RequestError_ServerError",
+    "RequestError_Other",
+]
+
+# Represents an HTTP response we received.
+#
+# Please note we currently do not support streaming responses.
+Response = TypedDict(
+    "Response",
+    {
+        # The response body. May be empty.
+        "body": "Body",
+        # HTTP headers that were part of the response.
+        "headers": Any,
+        # HTTP status code.
+        "status_code": int,
+    },
+)
+
+# Represents a successful result.
+Result_Ok = TypedDict(
+    "Result_Ok",
+    {
+        "Ok": Any,
+    },
+)
+
+# Represents an error.
+Result_Err = TypedDict(
+    "Result_Err",
+    {
+        "Err": Any,
+    },
+)
+
+# A result that can be either successful (`Ok`) or represent an error (`Err`).
+Result = Union[
+    "Result_Ok",
+    "Result_Err",
+]
+
+SerdeAdjacentlyTagged_Foo = TypedDict(
+    "SerdeAdjacentlyTagged_Foo",
+    {
+        "type": Literal["Foo"],
+    },
+)
+
+SerdeAdjacentlyTagged_Bar = TypedDict(
+    "SerdeAdjacentlyTagged_Bar",
+    {
+        "type": Literal["Bar"],
+        "payload": str,
+    },
+)
+
+SerdeAdjacentlyTagged_BazFields = TypedDict(
+    "SerdeAdjacentlyTagged_BazFields",
+    {
+        "a": int,
+        "b": int,
+    },
+)
+
+SerdeAdjacentlyTagged_Baz = TypedDict(
+    "SerdeAdjacentlyTagged_Baz",
+    {
+        "type": Literal["Baz"],
+        "payload": "SerdeAdjacentlyTagged_BazFields",
+    },
+)
+
+SerdeAdjacentlyTagged = Union[
+    "SerdeAdjacentlyTagged_Foo",
+    "SerdeAdjacentlyTagged_Bar",
+    "SerdeAdjacentlyTagged_Baz",
+]
+
+SerdeFlatten = TypedDict(
+    "SerdeFlatten",
+    {
+        "foo": str,
+        "bar": int,
+    },
+)
+
+SerdeInternallyTagged_Foo = TypedDict(
+    "SerdeInternallyTagged_Foo",
+    {
+        "type": Literal["Foo"],
+    },
+)
+
+SerdeInternallyTagged_Baz = TypedDict(
+    "SerdeInternallyTagged_Baz",
+    {
+        "type": Literal["Baz"],
+        "a": int,
+        "b": int,
+    },
+)
+
+SerdeInternallyTagged = Union[
+    "SerdeInternallyTagged_Foo",
+    "SerdeInternallyTagged_Baz",
+]
+
+SerdePropertyRenaming = TypedDict(
+    "SerdePropertyRenaming",
+    {
+        "fooBar": str,
+        "QUX_BAZ": float,
+        "rawStruct": int,
+    },
+)
+
+SerdeUntagged_Baz = TypedDict(
+    "SerdeUntagged_Baz",
+    {
+        "a": int,
+        "b": int,
+    },
+)
+
+SerdeUntagged = Union[
+    str,
+    "SerdeUntagged_Baz",
+]
+
+SerdeVariantRenaming_QuxBazFields = TypedDict(
+    "SerdeVariantRenaming_QuxBazFields",
+    {
+        # Will be renamed to "FooBar" because of the `rename_all` on the
+        # variant.
+        "FooBar": str,
+        "qux_baz": float,
+    },
+)
+
+SerdeVariantRenaming_QuxBaz = TypedDict(
+    "SerdeVariantRenaming_QuxBaz",
+    {
+        "QUX_BAZ": "SerdeVariantRenaming_QuxBazFields",
+    },
+)
+
+SerdeVariantRenaming = Union[
+    Literal["foo_bar"],
+    "SerdeVariantRenaming_QuxBaz",
+]
+
+# A state update to communicate to the Redux host.
+#
+# Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+# changed.
+StateUpdate = TypedDict(
+    "StateUpdate",
+    {
+        "title": Optional[str],
+        "revision": Optional[int],
+    },
+)
+
+StructWithGenerics = TypedDict(
+    "StructWithGenerics",
+    {
+        "list": List[Any],
+        "points": List["Point"],
+        "recursive": List["Point"],
+        "complex_nested": Optional[Dict[str, List["FloatingPoint"]]],
+        "optional_timestamp": Optional["MyDateTime"],
+    },
+)
+
+StructWithOptions = TypedDict(
+    "StructWithOptions",
+    {
+        "filledString": NotRequired[str],
+        "emptyString": NotRequired[str],
+        "filledOptionString": NotRequired[Optional[str]],
+        "emptyOptionString": NotRequired[Optional[str]],
+        "neverSkippedFilledOptionString": Optional[str],
+        "neverSkippedEmptyOptionString": Optional[str],
+    },
+)
+
+FloatingPoint = Point
+
+HttpResult = Result
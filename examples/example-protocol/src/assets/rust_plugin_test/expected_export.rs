@@ -1,5 +1,13 @@
 use crate::types::*;
 
+/// Fingerprint of the protocol these bindings were generated from, which is
+/// verified by the runtime when the plugin is loaded.
+#[doc(hidden)]
+#[no_mangle]
+pub extern "C" fn __fp_protocol_version() -> u64 {
+    0x1e72896d627cc76e
+}
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_array_f32(arg: [f32; 3]) -> [f32; 3];
 
@@ -60,6 +68,11 @@ pub async fn export_increment_global_state();
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_multiple_primitives(arg1: i8, arg2: String) -> i64;
 
+/// Panics with the given message, so the runtimes can test guest panics
+/// are reported to them.
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_panic(message: String);
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_bool_negate(arg: bool) -> bool;
 
@@ -1,182 +1,860 @@
 use crate::types::*;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_f32(arg: [f32; 3]) -> [f32; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_f64(arg: [f64; 3]) -> [f64; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_i16(arg: [i16; 3]) -> [i16; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_i32(arg: [i32; 3]) -> [i32; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_i8(arg: [i8; 3]) -> [i8; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_u16(arg: [u16; 3]) -> [u16; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_u32(arg: [u32; 3]) -> [u32; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_array_u8(arg: [u8; 3]) -> [u8; 3];
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_explicit_bound_point(arg: ExplicitBoundPoint<u64>);
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_fp_enum(arg: FpVariantRenaming) -> FpVariantRenaming;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_fp_flatten(arg: FpFlatten) -> FpFlatten;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_fp_internally_tagged(arg: FpInternallyTagged) -> FpInternallyTagged;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_fp_struct(arg: FpPropertyRenaming) -> FpPropertyRenaming;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_fp_untagged(arg: FpUntagged) -> FpUntagged;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_generics(arg: StructWithGenerics<u64>) -> StructWithGenerics<u64>;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_get_bytes() -> Result<bytes::Bytes, String>;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_get_serde_bytes() -> Result<serde_bytes::ByteBuf, String>;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_increment_global_state();
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_multiple_primitives(arg1: i8, arg2: String) -> i64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_bool_negate(arg: bool) -> bool;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_bool_negate_async(arg: bool) -> bool;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_f32_add_one(arg: f32) -> f32;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_f32_add_one_async(arg: f32) -> f32;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_f32_add_one_wasmer2(arg: [f32; 1]) -> f32;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_f64_add_one(arg: f64) -> f64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_f64_add_one_async(arg: f64) -> f64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_f64_add_one_wasmer2(arg: [f64; 1]) -> f64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_i16_add_one(arg: i16) -> i16;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_i16_add_one_async(arg: i16) -> i16;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_i32_add_one(arg: i32) -> i32;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_i32_add_one_async(arg: i32) -> i32;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_i64_add_one(arg: i64) -> i64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_i64_add_one_async(arg: i64) -> i64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_i8_add_one(arg: i8) -> i8;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_i8_add_one_async(arg: i8) -> i8;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_u16_add_one(arg: u16) -> u16;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_u16_add_one_async(arg: u16) -> u16;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_u32_add_one(arg: u32) -> u32;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_u32_add_one_async(arg: u32) -> u32;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_u64_add_one(arg: u64) -> u64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_u64_add_one_async(arg: u64) -> u64;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_primitive_u8_add_one(arg: u8) -> u8;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_primitive_u8_add_one_async(arg: u8) -> u8;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn import_reset_global_state();
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_serde_adjacently_tagged(arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_serde_flatten(arg: SerdeFlatten) -> SerdeFlatten;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_serde_internally_tagged(arg: SerdeInternallyTagged) -> SerdeInternallyTagged;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_serde_struct(arg: SerdePropertyRenaming) -> SerdePropertyRenaming;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_string(arg: String) -> String;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_struct_with_options(arg: StructWithOptions) -> StructWithOptions;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_timestamp(arg: MyDateTime) -> MyDateTime;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_void_function();
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_void_function_empty_result() -> Result<(), u32>;
 
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_void_function_empty_return();
 
 /// Logs a message to the (development) console.
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub fn log(message: String);
 
 /// Example how a runtime could expose a `Fetch`-like function to plugins.
 ///
 /// See `types/http.rs` for more info.
+#[cfg(not(feature = "mock_host"))]
 #[fp_bindgen_support::fp_import_signature]
 pub async fn make_http_request(request: Request) -> HttpResult;
+
+/// Mock implementations of the imported functions, which are called instead of
+/// the runtime when the `mock_host` feature is enabled.
+///
+/// Functions that are not implemented panic when they are called. Use
+/// `set_mock_imports()` to register an implementation for the current thread.
+#[cfg(feature = "mock_host")]
+pub trait MockImports {
+    #[allow(unused_variables)]
+    fn import_array_f32(&self, arg: [f32; 3]) -> [f32; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_f32")
+    }
+
+    #[allow(unused_variables)]
+    fn import_array_f64(&self, arg: [f64; 3]) -> [f64; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_f64")
+    }
+
+    #[allow(unused_variables)]
+    fn import_array_i16(&self, arg: [i16; 3]) -> [i16; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_i16")
+    }
+
+    #[allow(unused_variables)]
+    fn import_array_i32(&self, arg: [i32; 3]) -> [i32; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_i32")
+    }
+
+    #[allow(unused_variables)]
+    fn import_array_i8(&self, arg: [i8; 3]) -> [i8; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_i8")
+    }
+
+    #[allow(unused_variables)]
+    fn import_array_u16(&self, arg: [u16; 3]) -> [u16; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_u16")
+    }
+
+    #[allow(unused_variables)]
+    fn import_array_u32(&self, arg: [u32; 3]) -> [u32; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_u32")
+    }
+
+    #[allow(unused_variables)]
+    fn import_array_u8(&self, arg: [u8; 3]) -> [u8; 3] {
+        fp_bindgen_support::mock_host::unimplemented_import("import_array_u8")
+    }
+
+    #[allow(unused_variables)]
+    fn import_explicit_bound_point(&self, arg: ExplicitBoundPoint<u64>) {
+        fp_bindgen_support::mock_host::unimplemented_import("import_explicit_bound_point")
+    }
+
+    #[allow(unused_variables)]
+    fn import_fp_adjacently_tagged(&self, arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged {
+        fp_bindgen_support::mock_host::unimplemented_import("import_fp_adjacently_tagged")
+    }
+
+    #[allow(unused_variables)]
+    fn import_fp_enum(&self, arg: FpVariantRenaming) -> FpVariantRenaming {
+        fp_bindgen_support::mock_host::unimplemented_import("import_fp_enum")
+    }
+
+    #[allow(unused_variables)]
+    fn import_fp_flatten(&self, arg: FpFlatten) -> FpFlatten {
+        fp_bindgen_support::mock_host::unimplemented_import("import_fp_flatten")
+    }
+
+    #[allow(unused_variables)]
+    fn import_fp_internally_tagged(&self, arg: FpInternallyTagged) -> FpInternallyTagged {
+        fp_bindgen_support::mock_host::unimplemented_import("import_fp_internally_tagged")
+    }
+
+    #[allow(unused_variables)]
+    fn import_fp_struct(&self, arg: FpPropertyRenaming) -> FpPropertyRenaming {
+        fp_bindgen_support::mock_host::unimplemented_import("import_fp_struct")
+    }
+
+    #[allow(unused_variables)]
+    fn import_fp_untagged(&self, arg: FpUntagged) -> FpUntagged {
+        fp_bindgen_support::mock_host::unimplemented_import("import_fp_untagged")
+    }
+
+    #[allow(unused_variables)]
+    fn import_generics(&self, arg: StructWithGenerics<u64>) -> StructWithGenerics<u64> {
+        fp_bindgen_support::mock_host::unimplemented_import("import_generics")
+    }
+
+    #[allow(unused_variables)]
+    fn import_get_bytes(&self) -> Result<bytes::Bytes, String> {
+        fp_bindgen_support::mock_host::unimplemented_import("import_get_bytes")
+    }
+
+    #[allow(unused_variables)]
+    fn import_get_serde_bytes(&self) -> Result<serde_bytes::ByteBuf, String> {
+        fp_bindgen_support::mock_host::unimplemented_import("import_get_serde_bytes")
+    }
+
+    #[allow(unused_variables)]
+    fn import_increment_global_state(&self) {
+        fp_bindgen_support::mock_host::unimplemented_import("import_increment_global_state")
+    }
+
+    #[allow(unused_variables)]
+    fn import_multiple_primitives(&self, arg1: i8, arg2: String) -> i64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_multiple_primitives")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_bool_negate(&self, arg: bool) -> bool {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_bool_negate")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_bool_negate_async(&self, arg: bool) -> bool {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_bool_negate_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_f32_add_one(&self, arg: f32) -> f32 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_f32_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_f32_add_one_async(&self, arg: f32) -> f32 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_f32_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_f32_add_one_wasmer2(&self, arg: [f32; 1]) -> f32 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_f32_add_one_wasmer2")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_f64_add_one(&self, arg: f64) -> f64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_f64_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_f64_add_one_async(&self, arg: f64) -> f64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_f64_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_f64_add_one_wasmer2(&self, arg: [f64; 1]) -> f64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_f64_add_one_wasmer2")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i16_add_one(&self, arg: i16) -> i16 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i16_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i16_add_one_async(&self, arg: i16) -> i16 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i16_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i32_add_one(&self, arg: i32) -> i32 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i32_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i32_add_one_async(&self, arg: i32) -> i32 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i32_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i64_add_one(&self, arg: i64) -> i64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i64_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i64_add_one_async(&self, arg: i64) -> i64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i64_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i8_add_one(&self, arg: i8) -> i8 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i8_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_i8_add_one_async(&self, arg: i8) -> i8 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_i8_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u16_add_one(&self, arg: u16) -> u16 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u16_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u16_add_one_async(&self, arg: u16) -> u16 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u16_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u32_add_one(&self, arg: u32) -> u32 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u32_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u32_add_one_async(&self, arg: u32) -> u32 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u32_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u64_add_one(&self, arg: u64) -> u64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u64_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u64_add_one_async(&self, arg: u64) -> u64 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u64_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u8_add_one(&self, arg: u8) -> u8 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u8_add_one")
+    }
+
+    #[allow(unused_variables)]
+    fn import_primitive_u8_add_one_async(&self, arg: u8) -> u8 {
+        fp_bindgen_support::mock_host::unimplemented_import("import_primitive_u8_add_one_async")
+    }
+
+    #[allow(unused_variables)]
+    fn import_reset_global_state(&self) {
+        fp_bindgen_support::mock_host::unimplemented_import("import_reset_global_state")
+    }
+
+    #[allow(unused_variables)]
+    fn import_serde_adjacently_tagged(&self, arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged {
+        fp_bindgen_support::mock_host::unimplemented_import("import_serde_adjacently_tagged")
+    }
+
+    #[allow(unused_variables)]
+    fn import_serde_enum(&self, arg: SerdeVariantRenaming) -> SerdeVariantRenaming {
+        fp_bindgen_support::mock_host::unimplemented_import("import_serde_enum")
+    }
+
+    #[allow(unused_variables)]
+    fn import_serde_flatten(&self, arg: SerdeFlatten) -> SerdeFlatten {
+        fp_bindgen_support::mock_host::unimplemented_import("import_serde_flatten")
+    }
+
+    #[allow(unused_variables)]
+    fn import_serde_internally_tagged(&self, arg: SerdeInternallyTagged) -> SerdeInternallyTagged {
+        fp_bindgen_support::mock_host::unimplemented_import("import_serde_internally_tagged")
+    }
+
+    #[allow(unused_variables)]
+    fn import_serde_struct(&self, arg: SerdePropertyRenaming) -> SerdePropertyRenaming {
+        fp_bindgen_support::mock_host::unimplemented_import("import_serde_struct")
+    }
+
+    #[allow(unused_variables)]
+    fn import_serde_untagged(&self, arg: SerdeUntagged) -> SerdeUntagged {
+        fp_bindgen_support::mock_host::unimplemented_import("import_serde_untagged")
+    }
+
+    #[allow(unused_variables)]
+    fn import_string(&self, arg: String) -> String {
+        fp_bindgen_support::mock_host::unimplemented_import("import_string")
+    }
+
+    #[allow(unused_variables)]
+    fn import_struct_with_options(&self, arg: StructWithOptions) -> StructWithOptions {
+        fp_bindgen_support::mock_host::unimplemented_import("import_struct_with_options")
+    }
+
+    #[allow(unused_variables)]
+    fn import_timestamp(&self, arg: MyDateTime) -> MyDateTime {
+        fp_bindgen_support::mock_host::unimplemented_import("import_timestamp")
+    }
+
+    #[allow(unused_variables)]
+    fn import_void_function(&self) {
+        fp_bindgen_support::mock_host::unimplemented_import("import_void_function")
+    }
+
+    #[allow(unused_variables)]
+    fn import_void_function_empty_result(&self) -> Result<(), u32> {
+        fp_bindgen_support::mock_host::unimplemented_import("import_void_function_empty_result")
+    }
+
+    #[allow(unused_variables)]
+    fn import_void_function_empty_return(&self) {
+        fp_bindgen_support::mock_host::unimplemented_import("import_void_function_empty_return")
+    }
+
+    /// Logs a message to the (development) console.
+    #[allow(unused_variables)]
+    fn log(&self, message: String) {
+        fp_bindgen_support::mock_host::unimplemented_import("log")
+    }
+
+    /// Example how a runtime could expose a `Fetch`-like function to plugins.
+    ///
+    /// See `types/http.rs` for more info.
+    #[allow(unused_variables)]
+    fn make_http_request(&self, request: Request) -> HttpResult {
+        fp_bindgen_support::mock_host::unimplemented_import("make_http_request")
+    }
+}
+
+/// Registers the mock implementations of the imported functions for the
+/// current thread.
+#[cfg(feature = "mock_host")]
+pub fn set_mock_imports(imports: impl MockImports + 'static) {
+    let imports: Box<dyn MockImports> = Box::new(imports);
+    fp_bindgen_support::mock_host::set_mock_imports(std::rc::Rc::new(imports));
+}
+
+#[cfg(feature = "mock_host")]
+fn mock_imports() -> std::rc::Rc<Box<dyn MockImports>> {
+    fp_bindgen_support::mock_host::get_mock_imports()
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_f32(arg: [f32; 3]) -> [f32; 3] {
+    mock_imports().import_array_f32(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_f64(arg: [f64; 3]) -> [f64; 3] {
+    mock_imports().import_array_f64(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_i16(arg: [i16; 3]) -> [i16; 3] {
+    mock_imports().import_array_i16(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_i32(arg: [i32; 3]) -> [i32; 3] {
+    mock_imports().import_array_i32(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_i8(arg: [i8; 3]) -> [i8; 3] {
+    mock_imports().import_array_i8(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_u16(arg: [u16; 3]) -> [u16; 3] {
+    mock_imports().import_array_u16(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_u32(arg: [u32; 3]) -> [u32; 3] {
+    mock_imports().import_array_u32(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_array_u8(arg: [u8; 3]) -> [u8; 3] {
+    mock_imports().import_array_u8(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_explicit_bound_point(arg: ExplicitBoundPoint<u64>) {
+    mock_imports().import_explicit_bound_point(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged {
+    mock_imports().import_fp_adjacently_tagged(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_fp_enum(arg: FpVariantRenaming) -> FpVariantRenaming {
+    mock_imports().import_fp_enum(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_fp_flatten(arg: FpFlatten) -> FpFlatten {
+    mock_imports().import_fp_flatten(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_fp_internally_tagged(arg: FpInternallyTagged) -> FpInternallyTagged {
+    mock_imports().import_fp_internally_tagged(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_fp_struct(arg: FpPropertyRenaming) -> FpPropertyRenaming {
+    mock_imports().import_fp_struct(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_fp_untagged(arg: FpUntagged) -> FpUntagged {
+    mock_imports().import_fp_untagged(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_generics(arg: StructWithGenerics<u64>) -> StructWithGenerics<u64> {
+    mock_imports().import_generics(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_get_bytes() -> Result<bytes::Bytes, String> {
+    mock_imports().import_get_bytes()
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_get_serde_bytes() -> Result<serde_bytes::ByteBuf, String> {
+    mock_imports().import_get_serde_bytes()
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_increment_global_state() {
+    mock_imports().import_increment_global_state()
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_multiple_primitives(arg1: i8, arg2: String) -> i64 {
+    mock_imports().import_multiple_primitives(arg1, arg2)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_bool_negate(arg: bool) -> bool {
+    mock_imports().import_primitive_bool_negate(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_bool_negate_async(arg: bool) -> bool {
+    mock_imports().import_primitive_bool_negate_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_f32_add_one(arg: f32) -> f32 {
+    mock_imports().import_primitive_f32_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_f32_add_one_async(arg: f32) -> f32 {
+    mock_imports().import_primitive_f32_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_f32_add_one_wasmer2(arg: [f32; 1]) -> f32 {
+    mock_imports().import_primitive_f32_add_one_wasmer2(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_f64_add_one(arg: f64) -> f64 {
+    mock_imports().import_primitive_f64_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_f64_add_one_async(arg: f64) -> f64 {
+    mock_imports().import_primitive_f64_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_f64_add_one_wasmer2(arg: [f64; 1]) -> f64 {
+    mock_imports().import_primitive_f64_add_one_wasmer2(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_i16_add_one(arg: i16) -> i16 {
+    mock_imports().import_primitive_i16_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_i16_add_one_async(arg: i16) -> i16 {
+    mock_imports().import_primitive_i16_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_i32_add_one(arg: i32) -> i32 {
+    mock_imports().import_primitive_i32_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_i32_add_one_async(arg: i32) -> i32 {
+    mock_imports().import_primitive_i32_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_i64_add_one(arg: i64) -> i64 {
+    mock_imports().import_primitive_i64_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_i64_add_one_async(arg: i64) -> i64 {
+    mock_imports().import_primitive_i64_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_i8_add_one(arg: i8) -> i8 {
+    mock_imports().import_primitive_i8_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_i8_add_one_async(arg: i8) -> i8 {
+    mock_imports().import_primitive_i8_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_u16_add_one(arg: u16) -> u16 {
+    mock_imports().import_primitive_u16_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_u16_add_one_async(arg: u16) -> u16 {
+    mock_imports().import_primitive_u16_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_u32_add_one(arg: u32) -> u32 {
+    mock_imports().import_primitive_u32_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_u32_add_one_async(arg: u32) -> u32 {
+    mock_imports().import_primitive_u32_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_u64_add_one(arg: u64) -> u64 {
+    mock_imports().import_primitive_u64_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_u64_add_one_async(arg: u64) -> u64 {
+    mock_imports().import_primitive_u64_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_primitive_u8_add_one(arg: u8) -> u8 {
+    mock_imports().import_primitive_u8_add_one(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_primitive_u8_add_one_async(arg: u8) -> u8 {
+    mock_imports().import_primitive_u8_add_one_async(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub async fn import_reset_global_state() {
+    mock_imports().import_reset_global_state()
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_serde_adjacently_tagged(arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged {
+    mock_imports().import_serde_adjacently_tagged(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming {
+    mock_imports().import_serde_enum(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_serde_flatten(arg: SerdeFlatten) -> SerdeFlatten {
+    mock_imports().import_serde_flatten(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_serde_internally_tagged(arg: SerdeInternallyTagged) -> SerdeInternallyTagged {
+    mock_imports().import_serde_internally_tagged(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_serde_struct(arg: SerdePropertyRenaming) -> SerdePropertyRenaming {
+    mock_imports().import_serde_struct(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged {
+    mock_imports().import_serde_untagged(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_string(arg: String) -> String {
+    mock_imports().import_string(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_struct_with_options(arg: StructWithOptions) -> StructWithOptions {
+    mock_imports().import_struct_with_options(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_timestamp(arg: MyDateTime) -> MyDateTime {
+    mock_imports().import_timestamp(arg)
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_void_function() {
+    mock_imports().import_void_function()
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_void_function_empty_result() -> Result<(), u32> {
+    mock_imports().import_void_function_empty_result()
+}
+
+#[cfg(feature = "mock_host")]
+pub fn import_void_function_empty_return() {
+    mock_imports().import_void_function_empty_return()
+}
+
+/// Logs a message to the (development) console.
+#[cfg(feature = "mock_host")]
+pub fn log(message: String) {
+    mock_imports().log(message)
+}
+
+/// Example how a runtime could expose a `Fetch`-like function to plugins.
+///
+/// See `types/http.rs` for more info.
+#[cfg(feature = "mock_host")]
+pub async fn make_http_request(request: Request) -> HttpResult {
+    mock_imports().make_http_request(request)
+}
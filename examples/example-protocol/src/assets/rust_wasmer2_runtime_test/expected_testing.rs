@@ -0,0 +1,491 @@
+#![allow(unused)]
+use super::bindings::Imports;
+use super::types::*;
+use fp_bindgen_testing::MockHost;
+
+/// Names of the functions imported by the plugin.
+pub const IMPORTED_FUNCTIONS: &[&str] = &[
+    "import_array_f32",
+    "import_array_f64",
+    "import_array_i16",
+    "import_array_i32",
+    "import_array_i8",
+    "import_array_u16",
+    "import_array_u32",
+    "import_array_u8",
+    "import_explicit_bound_point",
+    "import_fp_adjacently_tagged",
+    "import_fp_enum",
+    "import_fp_flatten",
+    "import_fp_internally_tagged",
+    "import_fp_struct",
+    "import_fp_untagged",
+    "import_generics",
+    "import_get_bytes",
+    "import_get_serde_bytes",
+    "import_increment_global_state",
+    "import_multiple_primitives",
+    "import_primitive_bool_negate",
+    "import_primitive_bool_negate_async",
+    "import_primitive_f32_add_one",
+    "import_primitive_f32_add_one_async",
+    "import_primitive_f32_add_one_wasmer2",
+    "import_primitive_f64_add_one",
+    "import_primitive_f64_add_one_async",
+    "import_primitive_f64_add_one_wasmer2",
+    "import_primitive_i16_add_one",
+    "import_primitive_i16_add_one_async",
+    "import_primitive_i32_add_one",
+    "import_primitive_i32_add_one_async",
+    "import_primitive_i64_add_one",
+    "import_primitive_i64_add_one_async",
+    "import_primitive_i8_add_one",
+    "import_primitive_i8_add_one_async",
+    "import_primitive_u16_add_one",
+    "import_primitive_u16_add_one_async",
+    "import_primitive_u32_add_one",
+    "import_primitive_u32_add_one_async",
+    "import_primitive_u64_add_one",
+    "import_primitive_u64_add_one_async",
+    "import_primitive_u8_add_one",
+    "import_primitive_u8_add_one_async",
+    "import_reset_global_state",
+    "import_serde_adjacently_tagged",
+    "import_serde_enum",
+    "import_serde_flatten",
+    "import_serde_internally_tagged",
+    "import_serde_struct",
+    "import_serde_untagged",
+    "import_string",
+    "import_struct_with_options",
+    "import_timestamp",
+    "import_void_function",
+    "import_void_function_empty_result",
+    "import_void_function_empty_return",
+    "log",
+    "make_http_request",
+];
+
+/// Implementation of the imported functions for use in tests.
+///
+/// Every call is recorded by the `MockHost`, so tests can assert on them and
+/// stub their return values. Calls that are not stubbed are forwarded to the
+/// `inner` implementation.
+pub struct RecordingImports<I> {
+    pub mock: MockHost,
+    pub inner: I,
+}
+
+impl<I: Imports> RecordingImports<I> {
+    /// Wraps the given implementation. The imported functions are registered
+    /// with the mock, so it rejects stubs and assertions for unknown functions.
+    pub fn new(mock: MockHost, inner: I) -> Self {
+        mock.register_functions(IMPORTED_FUNCTIONS);
+        Self { mock, inner }
+    }
+}
+
+impl<I: Imports> Imports for RecordingImports<I> {
+    fn import_array_f32(&self, arg: [f32; 3]) -> [f32; 3] {
+        self.mock.call("import_array_f32", (arg,), |(arg,)| {
+            self.inner.import_array_f32(arg)
+        })
+    }
+
+    fn import_array_f64(&self, arg: [f64; 3]) -> [f64; 3] {
+        self.mock.call("import_array_f64", (arg,), |(arg,)| {
+            self.inner.import_array_f64(arg)
+        })
+    }
+
+    fn import_array_i16(&self, arg: [i16; 3]) -> [i16; 3] {
+        self.mock.call("import_array_i16", (arg,), |(arg,)| {
+            self.inner.import_array_i16(arg)
+        })
+    }
+
+    fn import_array_i32(&self, arg: [i32; 3]) -> [i32; 3] {
+        self.mock.call("import_array_i32", (arg,), |(arg,)| {
+            self.inner.import_array_i32(arg)
+        })
+    }
+
+    fn import_array_i8(&self, arg: [i8; 3]) -> [i8; 3] {
+        self.mock.call("import_array_i8", (arg,), |(arg,)| {
+            self.inner.import_array_i8(arg)
+        })
+    }
+
+    fn import_array_u16(&self, arg: [u16; 3]) -> [u16; 3] {
+        self.mock.call("import_array_u16", (arg,), |(arg,)| {
+            self.inner.import_array_u16(arg)
+        })
+    }
+
+    fn import_array_u32(&self, arg: [u32; 3]) -> [u32; 3] {
+        self.mock.call("import_array_u32", (arg,), |(arg,)| {
+            self.inner.import_array_u32(arg)
+        })
+    }
+
+    fn import_array_u8(&self, arg: [u8; 3]) -> [u8; 3] {
+        self.mock.call("import_array_u8", (arg,), |(arg,)| {
+            self.inner.import_array_u8(arg)
+        })
+    }
+
+    fn import_explicit_bound_point(&self, arg: ExplicitBoundPoint<u64>) {
+        self.mock
+            .call("import_explicit_bound_point", (arg,), |(arg,)| {
+                self.inner.import_explicit_bound_point(arg)
+            })
+    }
+
+    fn import_fp_adjacently_tagged(&self, arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged {
+        self.mock
+            .call("import_fp_adjacently_tagged", (arg,), |(arg,)| {
+                self.inner.import_fp_adjacently_tagged(arg)
+            })
+    }
+
+    fn import_fp_enum(&self, arg: FpVariantRenaming) -> FpVariantRenaming {
+        self.mock.call("import_fp_enum", (arg,), |(arg,)| {
+            self.inner.import_fp_enum(arg)
+        })
+    }
+
+    fn import_fp_flatten(&self, arg: FpFlatten) -> FpFlatten {
+        self.mock.call("import_fp_flatten", (arg,), |(arg,)| {
+            self.inner.import_fp_flatten(arg)
+        })
+    }
+
+    fn import_fp_internally_tagged(&self, arg: FpInternallyTagged) -> FpInternallyTagged {
+        self.mock
+            .call("import_fp_internally_tagged", (arg,), |(arg,)| {
+                self.inner.import_fp_internally_tagged(arg)
+            })
+    }
+
+    fn import_fp_struct(&self, arg: FpPropertyRenaming) -> FpPropertyRenaming {
+        self.mock.call("import_fp_struct", (arg,), |(arg,)| {
+            self.inner.import_fp_struct(arg)
+        })
+    }
+
+    fn import_fp_untagged(&self, arg: FpUntagged) -> FpUntagged {
+        self.mock.call("import_fp_untagged", (arg,), |(arg,)| {
+            self.inner.import_fp_untagged(arg)
+        })
+    }
+
+    fn import_generics(&self, arg: StructWithGenerics<u64>) -> StructWithGenerics<u64> {
+        self.mock.call("import_generics", (arg,), |(arg,)| {
+            self.inner.import_generics(arg)
+        })
+    }
+
+    fn import_get_bytes(&self) -> Result<bytes::Bytes, String> {
+        self.mock
+            .call("import_get_bytes", (), |()| self.inner.import_get_bytes())
+    }
+
+    fn import_get_serde_bytes(&self) -> Result<serde_bytes::ByteBuf, String> {
+        self.mock.call("import_get_serde_bytes", (), |()| {
+            self.inner.import_get_serde_bytes()
+        })
+    }
+
+    async fn import_increment_global_state(&self) {
+        self.mock
+            .call_async("import_increment_global_state", (), |()| {
+                self.inner.import_increment_global_state()
+            })
+            .await
+    }
+
+    fn import_multiple_primitives(&self, arg1: i8, arg2: String) -> i64 {
+        self.mock.call(
+            "import_multiple_primitives",
+            (arg1, arg2),
+            |(arg1, arg2)| self.inner.import_multiple_primitives(arg1, arg2),
+        )
+    }
+
+    fn import_primitive_bool_negate(&self, arg: bool) -> bool {
+        self.mock
+            .call("import_primitive_bool_negate", (arg,), |(arg,)| {
+                self.inner.import_primitive_bool_negate(arg)
+            })
+    }
+
+    async fn import_primitive_bool_negate_async(&self, arg: bool) -> bool {
+        self.mock
+            .call_async("import_primitive_bool_negate_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_bool_negate_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_f32_add_one(&self, arg: f32) -> f32 {
+        self.mock
+            .call("import_primitive_f32_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_f32_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_f32_add_one_async(&self, arg: f32) -> f32 {
+        self.mock
+            .call_async("import_primitive_f32_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_f32_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_f32_add_one_wasmer2(&self, arg: [f32; 1]) -> f32 {
+        self.mock
+            .call("import_primitive_f32_add_one_wasmer2", (arg,), |(arg,)| {
+                self.inner.import_primitive_f32_add_one_wasmer2(arg)
+            })
+    }
+
+    fn import_primitive_f64_add_one(&self, arg: f64) -> f64 {
+        self.mock
+            .call("import_primitive_f64_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_f64_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_f64_add_one_async(&self, arg: f64) -> f64 {
+        self.mock
+            .call_async("import_primitive_f64_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_f64_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_f64_add_one_wasmer2(&self, arg: [f64; 1]) -> f64 {
+        self.mock
+            .call("import_primitive_f64_add_one_wasmer2", (arg,), |(arg,)| {
+                self.inner.import_primitive_f64_add_one_wasmer2(arg)
+            })
+    }
+
+    fn import_primitive_i16_add_one(&self, arg: i16) -> i16 {
+        self.mock
+            .call("import_primitive_i16_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_i16_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_i16_add_one_async(&self, arg: i16) -> i16 {
+        self.mock
+            .call_async("import_primitive_i16_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_i16_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_i32_add_one(&self, arg: i32) -> i32 {
+        self.mock
+            .call("import_primitive_i32_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_i32_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_i32_add_one_async(&self, arg: i32) -> i32 {
+        self.mock
+            .call_async("import_primitive_i32_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_i32_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_i64_add_one(&self, arg: i64) -> i64 {
+        self.mock
+            .call("import_primitive_i64_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_i64_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_i64_add_one_async(&self, arg: i64) -> i64 {
+        self.mock
+            .call_async("import_primitive_i64_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_i64_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_i8_add_one(&self, arg: i8) -> i8 {
+        self.mock
+            .call("import_primitive_i8_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_i8_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_i8_add_one_async(&self, arg: i8) -> i8 {
+        self.mock
+            .call_async("import_primitive_i8_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_i8_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_u16_add_one(&self, arg: u16) -> u16 {
+        self.mock
+            .call("import_primitive_u16_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_u16_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_u16_add_one_async(&self, arg: u16) -> u16 {
+        self.mock
+            .call_async("import_primitive_u16_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_u16_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_u32_add_one(&self, arg: u32) -> u32 {
+        self.mock
+            .call("import_primitive_u32_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_u32_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_u32_add_one_async(&self, arg: u32) -> u32 {
+        self.mock
+            .call_async("import_primitive_u32_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_u32_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_u64_add_one(&self, arg: u64) -> u64 {
+        self.mock
+            .call("import_primitive_u64_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_u64_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_u64_add_one_async(&self, arg: u64) -> u64 {
+        self.mock
+            .call_async("import_primitive_u64_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_u64_add_one_async(arg)
+            })
+            .await
+    }
+
+    fn import_primitive_u8_add_one(&self, arg: u8) -> u8 {
+        self.mock
+            .call("import_primitive_u8_add_one", (arg,), |(arg,)| {
+                self.inner.import_primitive_u8_add_one(arg)
+            })
+    }
+
+    async fn import_primitive_u8_add_one_async(&self, arg: u8) -> u8 {
+        self.mock
+            .call_async("import_primitive_u8_add_one_async", (arg,), |(arg,)| {
+                self.inner.import_primitive_u8_add_one_async(arg)
+            })
+            .await
+    }
+
+    async fn import_reset_global_state(&self) {
+        self.mock
+            .call_async("import_reset_global_state", (), |()| {
+                self.inner.import_reset_global_state()
+            })
+            .await
+    }
+
+    fn import_serde_adjacently_tagged(&self, arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged {
+        self.mock
+            .call("import_serde_adjacently_tagged", (arg,), |(arg,)| {
+                self.inner.import_serde_adjacently_tagged(arg)
+            })
+    }
+
+    fn import_serde_enum(&self, arg: SerdeVariantRenaming) -> SerdeVariantRenaming {
+        self.mock.call("import_serde_enum", (arg,), |(arg,)| {
+            self.inner.import_serde_enum(arg)
+        })
+    }
+
+    fn import_serde_flatten(&self, arg: SerdeFlatten) -> SerdeFlatten {
+        self.mock.call("import_serde_flatten", (arg,), |(arg,)| {
+            self.inner.import_serde_flatten(arg)
+        })
+    }
+
+    fn import_serde_internally_tagged(&self, arg: SerdeInternallyTagged) -> SerdeInternallyTagged {
+        self.mock
+            .call("import_serde_internally_tagged", (arg,), |(arg,)| {
+                self.inner.import_serde_internally_tagged(arg)
+            })
+    }
+
+    fn import_serde_struct(&self, arg: SerdePropertyRenaming) -> SerdePropertyRenaming {
+        self.mock.call("import_serde_struct", (arg,), |(arg,)| {
+            self.inner.import_serde_struct(arg)
+        })
+    }
+
+    fn import_serde_untagged(&self, arg: SerdeUntagged) -> SerdeUntagged {
+        self.mock.call("import_serde_untagged", (arg,), |(arg,)| {
+            self.inner.import_serde_untagged(arg)
+        })
+    }
+
+    fn import_string(&self, arg: String) -> String {
+        self.mock.call("import_string", (arg,), |(arg,)| {
+            self.inner.import_string(arg)
+        })
+    }
+
+    fn import_struct_with_options(&self, arg: StructWithOptions) -> StructWithOptions {
+        self.mock
+            .call("import_struct_with_options", (arg,), |(arg,)| {
+                self.inner.import_struct_with_options(arg)
+            })
+    }
+
+    fn import_timestamp(&self, arg: MyDateTime) -> MyDateTime {
+        self.mock.call("import_timestamp", (arg,), |(arg,)| {
+            self.inner.import_timestamp(arg)
+        })
+    }
+
+    fn import_void_function(&self) {
+        self.mock.call("import_void_function", (), |()| {
+            self.inner.import_void_function()
+        })
+    }
+
+    fn import_void_function_empty_result(&self) -> Result<(), u32> {
+        self.mock
+            .call("import_void_function_empty_result", (), |()| {
+                self.inner.import_void_function_empty_result()
+            })
+    }
+
+    fn import_void_function_empty_return(&self) {
+        self.mock
+            .call("import_void_function_empty_return", (), |()| {
+                self.inner.import_void_function_empty_return()
+            })
+    }
+
+    fn log(&self, message: String) {
+        self.mock
+            .call("log", (message,), |(message,)| self.inner.log(message))
+    }
+
+    async fn make_http_request(&self, request: Request) -> HttpResult {
+        self.mock
+            .call_async("make_http_request", (request,), |(request,)| {
+                self.inner.make_http_request(request)
+            })
+            .await
+    }
+}
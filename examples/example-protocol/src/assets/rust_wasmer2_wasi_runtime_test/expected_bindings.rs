@@ -8,13 +8,185 @@ use fp_bindgen_support::{
             deserialize_from_slice, export_to_guest, export_to_guest_raw, import_from_guest,
             import_from_guest_raw, serialize_to_vec,
         },
-        r#async::{create_future_value, future::ModuleRawFuture, resolve_async_value},
-        runtime::RuntimeInstanceData,
+        panic::host_panic,
+        r#async::{
+            create_future_value, future::ModuleRawFuture, resolve_async_value,
+            resolve_future_value, Spawner,
+        },
+        runtime::{check_protocol_version, initialize_instance, RuntimeInstanceData},
     },
 };
 use std::cell::RefCell;
 use wasmer::{imports, Function, ImportObject, Instance, Module, Store, WasmerEnv};
 
+/// Fingerprint of the protocol these bindings were generated from. Plugins must
+/// have been generated from the same protocol for the runtime to accept them.
+pub const PROTOCOL_VERSION: u64 = 0x1e72896d627cc76e;
+
+/// Functions imported by the plugin, which are to be implemented by the runtime.
+///
+/// Every runtime instance owns its own implementation, so any state that should be
+/// scoped to a single instance can be kept on the implementing type.
+pub trait Imports: Send + Sync + 'static {
+    fn import_array_f32(&self, arg: [f32; 3]) -> [f32; 3];
+
+    fn import_array_f64(&self, arg: [f64; 3]) -> [f64; 3];
+
+    fn import_array_i16(&self, arg: [i16; 3]) -> [i16; 3];
+
+    fn import_array_i32(&self, arg: [i32; 3]) -> [i32; 3];
+
+    fn import_array_i8(&self, arg: [i8; 3]) -> [i8; 3];
+
+    fn import_array_u16(&self, arg: [u16; 3]) -> [u16; 3];
+
+    fn import_array_u32(&self, arg: [u32; 3]) -> [u32; 3];
+
+    fn import_array_u8(&self, arg: [u8; 3]) -> [u8; 3];
+
+    fn import_explicit_bound_point(&self, arg: ExplicitBoundPoint<u64>);
+
+    fn import_fp_adjacently_tagged(&self, arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
+
+    fn import_fp_enum(&self, arg: FpVariantRenaming) -> FpVariantRenaming;
+
+    fn import_fp_flatten(&self, arg: FpFlatten) -> FpFlatten;
+
+    fn import_fp_internally_tagged(&self, arg: FpInternallyTagged) -> FpInternallyTagged;
+
+    fn import_fp_struct(&self, arg: FpPropertyRenaming) -> FpPropertyRenaming;
+
+    fn import_fp_untagged(&self, arg: FpUntagged) -> FpUntagged;
+
+    fn import_generics(&self, arg: StructWithGenerics<u64>) -> StructWithGenerics<u64>;
+
+    fn import_get_bytes(&self) -> Result<bytes::Bytes, String>;
+
+    fn import_get_serde_bytes(&self) -> Result<serde_bytes::ByteBuf, String>;
+
+    fn import_increment_global_state(&self) -> impl std::future::Future<Output = ()> + Send;
+
+    fn import_multiple_primitives(&self, arg1: i8, arg2: String) -> i64;
+
+    fn import_primitive_bool_negate(&self, arg: bool) -> bool;
+
+    fn import_primitive_bool_negate_async(
+        &self,
+        arg: bool,
+    ) -> impl std::future::Future<Output = bool> + Send;
+
+    fn import_primitive_f32_add_one(&self, arg: f32) -> f32;
+
+    fn import_primitive_f32_add_one_async(
+        &self,
+        arg: f32,
+    ) -> impl std::future::Future<Output = f32> + Send;
+
+    fn import_primitive_f32_add_one_wasmer2(&self, arg: [f32; 1]) -> f32;
+
+    fn import_primitive_f64_add_one(&self, arg: f64) -> f64;
+
+    fn import_primitive_f64_add_one_async(
+        &self,
+        arg: f64,
+    ) -> impl std::future::Future<Output = f64> + Send;
+
+    fn import_primitive_f64_add_one_wasmer2(&self, arg: [f64; 1]) -> f64;
+
+    fn import_primitive_i16_add_one(&self, arg: i16) -> i16;
+
+    fn import_primitive_i16_add_one_async(
+        &self,
+        arg: i16,
+    ) -> impl std::future::Future<Output = i16> + Send;
+
+    fn import_primitive_i32_add_one(&self, arg: i32) -> i32;
+
+    fn import_primitive_i32_add_one_async(
+        &self,
+        arg: i32,
+    ) -> impl std::future::Future<Output = i32> + Send;
+
+    fn import_primitive_i64_add_one(&self, arg: i64) -> i64;
+
+    fn import_primitive_i64_add_one_async(
+        &self,
+        arg: i64,
+    ) -> impl std::future::Future<Output = i64> + Send;
+
+    fn import_primitive_i8_add_one(&self, arg: i8) -> i8;
+
+    fn import_primitive_i8_add_one_async(
+        &self,
+        arg: i8,
+    ) -> impl std::future::Future<Output = i8> + Send;
+
+    fn import_primitive_u16_add_one(&self, arg: u16) -> u16;
+
+    fn import_primitive_u16_add_one_async(
+        &self,
+        arg: u16,
+    ) -> impl std::future::Future<Output = u16> + Send;
+
+    fn import_primitive_u32_add_one(&self, arg: u32) -> u32;
+
+    fn import_primitive_u32_add_one_async(
+        &self,
+        arg: u32,
+    ) -> impl std::future::Future<Output = u32> + Send;
+
+    fn import_primitive_u64_add_one(&self, arg: u64) -> u64;
+
+    fn import_primitive_u64_add_one_async(
+        &self,
+        arg: u64,
+    ) -> impl std::future::Future<Output = u64> + Send;
+
+    fn import_primitive_u8_add_one(&self, arg: u8) -> u8;
+
+    fn import_primitive_u8_add_one_async(
+        &self,
+        arg: u8,
+    ) -> impl std::future::Future<Output = u8> + Send;
+
+    fn import_reset_global_state(&self) -> impl std::future::Future<Output = ()> + Send;
+
+    fn import_serde_adjacently_tagged(&self, arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged;
+
+    fn import_serde_enum(&self, arg: SerdeVariantRenaming) -> SerdeVariantRenaming;
+
+    fn import_serde_flatten(&self, arg: SerdeFlatten) -> SerdeFlatten;
+
+    fn import_serde_internally_tagged(&self, arg: SerdeInternallyTagged) -> SerdeInternallyTagged;
+
+    fn import_serde_struct(&self, arg: SerdePropertyRenaming) -> SerdePropertyRenaming;
+
+    fn import_serde_untagged(&self, arg: SerdeUntagged) -> SerdeUntagged;
+
+    fn import_string(&self, arg: String) -> String;
+
+    fn import_struct_with_options(&self, arg: StructWithOptions) -> StructWithOptions;
+
+    fn import_timestamp(&self, arg: MyDateTime) -> MyDateTime;
+
+    fn import_void_function(&self);
+
+    fn import_void_function_empty_result(&self) -> Result<(), u32>;
+
+    fn import_void_function_empty_return(&self);
+
+    /// Logs a message to the (development) console.
+    fn log(&self, message: String);
+
+    /// Example how a runtime could expose a `Fetch`-like function to plugins.
+    ///
+    /// See `types/http.rs` for more info.
+    fn make_http_request(
+        &self,
+        request: Request,
+    ) -> impl std::future::Future<Output = HttpResult> + Send;
+}
+
 #[derive(Clone)]
 pub struct Runtime {
     instance: Instance,
@@ -22,16 +194,59 @@ pub struct Runtime {
 }
 
 impl Runtime {
-    pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {
+    /// Creates a runtime for the given plugin, which spawns the futures returned
+    /// by async imported functions on the current Tokio runtime.
+    pub fn new<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+    ) -> Result<Self, RuntimeError> {
+        Self::with_spawner(wasm_module, imports, |future| {
+            tokio::runtime::Handle::current().spawn(future);
+        })
+    }
+
+    /// Same as `new()`, but does not verify the plugin was generated from the
+    /// same protocol as the runtime.
+    pub fn new_unchecked<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+    ) -> Result<Self, RuntimeError> {
+        Self::with_spawner_unchecked(wasm_module, imports, |future| {
+            tokio::runtime::Handle::current().spawn(future);
+        })
+    }
+
+    /// Creates a runtime for the given plugin, which uses the given spawner to run
+    /// the futures returned by async imported functions. This allows the runtime
+    /// to be used with executors other than Tokio.
+    pub fn with_spawner<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+        spawner: impl Spawner,
+    ) -> Result<Self, RuntimeError> {
+        let runtime = Self::with_spawner_unchecked(wasm_module, imports, spawner)?;
+        check_protocol_version(&runtime.instance, PROTOCOL_VERSION)?;
+        Ok(runtime)
+    }
+
+    /// Same as `with_spawner()`, but does not verify the plugin was generated from
+    /// the same protocol as the runtime.
+    pub fn with_spawner_unchecked<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+        spawner: impl Spawner,
+    ) -> Result<Self, RuntimeError> {
         let store = Self::default_store();
         let module = Module::new(&store, wasm_module)?;
-        let mut env = RuntimeInstanceData::default();
+        let mut env = RuntimeInstanceData::with_context(imports);
+        env.set_spawner(spawner);
         let mut wasi_env = wasmer_wasi::WasiState::new("fp").finalize().unwrap();
         let mut import_object = wasi_env.import_object(&module).unwrap();
-        let namespace = create_import_object(module.store(), &env);
+        let namespace = create_import_object::<I>(module.store(), &env);
         import_object.register("fp", namespace);
         let instance = Instance::new(&module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        initialize_instance(&instance)?;
         Ok(Self { instance, env })
     }
 
@@ -52,11 +267,11 @@ impl Runtime {
     pub fn export_array_f32(&self, arg: [f32; 3]) -> Result<[f32; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_f32_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_f32_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -65,18 +280,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_f32".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_array_f64(&self, arg: [f64; 3]) -> Result<[f64; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_f64_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_f64_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -85,18 +300,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_f64".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_array_i16(&self, arg: [i16; 3]) -> Result<[i16; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_i16_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_i16_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -105,18 +320,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_i16".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_array_i32(&self, arg: [i32; 3]) -> Result<[i32; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_i32_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_i32_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -125,18 +340,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_i32".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_array_i8(&self, arg: [i8; 3]) -> Result<[i8; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_i8_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_i8_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -145,18 +360,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_i8".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_array_u16(&self, arg: [u16; 3]) -> Result<[u16; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_u16_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_u16_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -165,18 +380,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_u16".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_array_u32(&self, arg: [u32; 3]) -> Result<[u32; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_u32_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_u32_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -185,18 +400,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_u32".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_array_u8(&self, arg: [u8; 3]) -> Result<[u8; 3], InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_array_u8_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_array_u8_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -205,7 +420,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_array_u8".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -217,7 +432,7 @@ impl Runtime {
         let arg1 = serialize_to_vec(&arg1);
         let result = self.export_async_struct_raw(arg1, arg2);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_async_struct_raw(
@@ -225,7 +440,7 @@ impl Runtime {
         arg1: Vec<u8>,
         arg2: u64,
     ) -> Result<Vec<u8>, InvocationError> {
-        let arg1 = export_to_guest_raw(&self.env, arg1);
+        let arg1 = export_to_guest_raw(&self.env, arg1)?;
         let function = self
             .instance
             .exports
@@ -236,7 +451,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_async_struct".to_owned())
             })?;
         let result = function.call(arg1.to_abi(), arg2.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -246,14 +461,14 @@ impl Runtime {
     ) -> Result<FpAdjacentlyTagged, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_fp_adjacently_tagged_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_fp_adjacently_tagged_raw(
         &self,
         arg: Vec<u8>,
     ) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -264,7 +479,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -274,11 +489,11 @@ impl Runtime {
     ) -> Result<FpVariantRenaming, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_fp_enum_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_fp_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -287,18 +502,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_fp_enum".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_fp_flatten(&self, arg: FpFlatten) -> Result<FpFlatten, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_fp_flatten_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_fp_flatten_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -307,7 +522,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_fp_flatten".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -317,14 +532,14 @@ impl Runtime {
     ) -> Result<FpInternallyTagged, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_fp_internally_tagged_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_fp_internally_tagged_raw(
         &self,
         arg: Vec<u8>,
     ) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -335,7 +550,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -345,11 +560,11 @@ impl Runtime {
     ) -> Result<FpPropertyRenaming, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_fp_struct_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_fp_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -358,18 +573,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_fp_struct".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_fp_untagged(&self, arg: FpUntagged) -> Result<FpUntagged, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_fp_untagged_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_fp_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -378,7 +593,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_fp_untagged".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -388,11 +603,11 @@ impl Runtime {
     ) -> Result<StructWithGenerics<u64>, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_generics_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_generics_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -401,13 +616,13 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_generics".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_get_bytes(&self) -> Result<Result<bytes::Bytes, String>, InvocationError> {
         let result = self.export_get_bytes_raw();
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_get_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
@@ -419,7 +634,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_get_bytes".to_owned())
             })?;
         let result = function.call()?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -427,7 +642,7 @@ impl Runtime {
         &self,
     ) -> Result<Result<serde_bytes::ByteBuf, String>, InvocationError> {
         let result = self.export_get_serde_bytes_raw();
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_get_serde_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
@@ -439,14 +654,14 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_get_serde_bytes".to_owned())
             })?;
         let result = function.call()?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub async fn export_increment_global_state(&self) -> Result<(), InvocationError> {
         let result = self.export_increment_global_state_raw();
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_increment_global_state_raw(&self) -> Result<Vec<u8>, InvocationError> {
@@ -460,7 +675,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call()?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -478,7 +693,7 @@ impl Runtime {
         arg1: i8,
         arg2: Vec<u8>,
     ) -> Result<i64, InvocationError> {
-        let arg2 = export_to_guest_raw(&self.env, arg2);
+        let arg2 = export_to_guest_raw(&self.env, arg2)?;
         let function = self
             .instance
             .exports
@@ -495,6 +710,27 @@ impl Runtime {
         Ok(result)
     }
 
+    /// Panics with the given message, so the runtimes can test guest panics
+    /// are reported to them.
+    pub fn export_panic(&self, message: String) -> Result<(), InvocationError> {
+        let message = serialize_to_vec(&message);
+        let result = self.export_panic_raw(message);
+        result
+    }
+    pub fn export_panic_raw(&self, message: Vec<u8>) -> Result<(), InvocationError> {
+        let message = export_to_guest_raw(&self.env, message)?;
+        let function = self
+            .instance
+            .exports
+            .get_native_function::<FatPtr, ()>("__fp_gen_export_panic")
+            .map_err(|_| {
+                InvocationError::FunctionNotExported("__fp_gen_export_panic".to_owned())
+            })?;
+        let result = function.call(message.to_abi())?;
+        let result = WasmAbi::from_abi(result);
+        Ok(result)
+    }
+
     pub fn export_primitive_bool_negate(&self, arg: bool) -> Result<bool, InvocationError> {
         let result = self.export_primitive_bool_negate_raw(arg);
         result
@@ -522,7 +758,7 @@ impl Runtime {
     ) -> Result<bool, InvocationError> {
         let result = self.export_primitive_bool_negate_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_bool_negate_async_raw(
@@ -541,7 +777,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -572,7 +808,7 @@ impl Runtime {
     ) -> Result<f32, InvocationError> {
         let result = self.export_primitive_f32_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_f32_add_three_async_raw(
@@ -591,7 +827,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -646,7 +882,7 @@ impl Runtime {
     ) -> Result<f64, InvocationError> {
         let result = self.export_primitive_f64_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_f64_add_three_async_raw(
@@ -665,7 +901,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -720,7 +956,7 @@ impl Runtime {
     ) -> Result<i16, InvocationError> {
         let result = self.export_primitive_i16_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_i16_add_three_async_raw(
@@ -739,7 +975,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -770,7 +1006,7 @@ impl Runtime {
     ) -> Result<i32, InvocationError> {
         let result = self.export_primitive_i32_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_i32_add_three_async_raw(
@@ -789,7 +1025,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -820,7 +1056,7 @@ impl Runtime {
     ) -> Result<i64, InvocationError> {
         let result = self.export_primitive_i64_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_i64_add_three_async_raw(
@@ -839,7 +1075,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -870,7 +1106,7 @@ impl Runtime {
     ) -> Result<i8, InvocationError> {
         let result = self.export_primitive_i8_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_i8_add_three_async_raw(
@@ -889,7 +1125,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -920,7 +1156,7 @@ impl Runtime {
     ) -> Result<u16, InvocationError> {
         let result = self.export_primitive_u16_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_u16_add_three_async_raw(
@@ -939,7 +1175,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -970,7 +1206,7 @@ impl Runtime {
     ) -> Result<u32, InvocationError> {
         let result = self.export_primitive_u32_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_u32_add_three_async_raw(
@@ -989,7 +1225,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -1020,7 +1256,7 @@ impl Runtime {
     ) -> Result<u64, InvocationError> {
         let result = self.export_primitive_u64_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_u64_add_three_async_raw(
@@ -1039,7 +1275,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -1070,7 +1306,7 @@ impl Runtime {
     ) -> Result<u8, InvocationError> {
         let result = self.export_primitive_u8_add_three_async_raw(arg);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_primitive_u8_add_three_async_raw(
@@ -1089,14 +1325,14 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
     pub async fn export_reset_global_state(&self) -> Result<(), InvocationError> {
         let result = self.export_reset_global_state_raw();
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn export_reset_global_state_raw(&self) -> Result<Vec<u8>, InvocationError> {
@@ -1110,7 +1346,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call()?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -1120,14 +1356,14 @@ impl Runtime {
     ) -> Result<SerdeAdjacentlyTagged, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_serde_adjacently_tagged_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_serde_adjacently_tagged_raw(
         &self,
         arg: Vec<u8>,
     ) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1138,7 +1374,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -1148,11 +1384,11 @@ impl Runtime {
     ) -> Result<SerdeVariantRenaming, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_serde_enum_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_serde_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1161,18 +1397,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_serde_enum".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_serde_flatten(&self, arg: SerdeFlatten) -> Result<SerdeFlatten, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_serde_flatten_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_serde_flatten_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1181,7 +1417,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_serde_flatten".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -1191,14 +1427,14 @@ impl Runtime {
     ) -> Result<SerdeInternallyTagged, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_serde_internally_tagged_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_serde_internally_tagged_raw(
         &self,
         arg: Vec<u8>,
     ) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1209,7 +1445,7 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -1219,11 +1455,11 @@ impl Runtime {
     ) -> Result<SerdePropertyRenaming, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_serde_struct_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_serde_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1232,7 +1468,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_serde_struct".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -1242,11 +1478,11 @@ impl Runtime {
     ) -> Result<SerdeUntagged, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_serde_untagged_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_serde_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1255,18 +1491,18 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_serde_untagged".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_string(&self, arg: String) -> Result<String, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_string_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_string_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1275,7 +1511,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_string".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -1285,11 +1521,11 @@ impl Runtime {
     ) -> Result<StructWithOptions, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_struct_with_options_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_struct_with_options_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1300,18 +1536,18 @@ impl Runtime {
                 )
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
     pub fn export_timestamp(&self, arg: MyDateTime) -> Result<MyDateTime, InvocationError> {
         let arg = serialize_to_vec(&arg);
         let result = self.export_timestamp_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn export_timestamp_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let arg = export_to_guest_raw(&self.env, arg);
+        let arg = export_to_guest_raw(&self.env, arg)?;
         let function = self
             .instance
             .exports
@@ -1320,7 +1556,7 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_export_timestamp".to_owned())
             })?;
         let result = function.call(arg.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 
@@ -1349,18 +1585,18 @@ impl Runtime {
         let r#type = serialize_to_vec(&r#type);
         let result = self.fetch_data_raw(r#type);
         let result = result.await;
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub async fn fetch_data_raw(&self, r#type: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let r#type = export_to_guest_raw(&self.env, r#type);
+        let r#type = export_to_guest_raw(&self.env, r#type)?;
         let function = self
             .instance
             .exports
             .get_native_function::<FatPtr, FatPtr>("__fp_gen_fetch_data")
             .map_err(|_| InvocationError::FunctionNotExported("__fp_gen_fetch_data".to_owned()))?;
         let result = function.call(r#type.to_abi())?;
-        let result = ModuleRawFuture::new(self.env.clone(), result).await;
+        let result = ModuleRawFuture::new(self.env.clone(), result).await?;
         Ok(result)
     }
 
@@ -1384,11 +1620,11 @@ impl Runtime {
     pub fn reducer_bridge(&self, action: ReduxAction) -> Result<StateUpdate, InvocationError> {
         let action = serialize_to_vec(&action);
         let result = self.reducer_bridge_raw(action);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
         result
     }
     pub fn reducer_bridge_raw(&self, action: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
-        let action = export_to_guest_raw(&self.env, action);
+        let action = export_to_guest_raw(&self.env, action)?;
         let function = self
             .instance
             .exports
@@ -1397,729 +1633,864 @@ impl Runtime {
                 InvocationError::FunctionNotExported("__fp_gen_reducer_bridge".to_owned())
             })?;
         let result = function.call(action.to_abi())?;
-        let result = import_from_guest_raw(&self.env, result);
+        let result = import_from_guest_raw(&self.env, result)?;
         Ok(result)
     }
 }
 
-fn create_import_object(store: &Store, env: &RuntimeInstanceData) -> wasmer::Exports {
+fn create_import_object<I: Imports>(store: &Store, env: &RuntimeInstanceData) -> wasmer::Exports {
     let mut namespace = wasmer::Exports::new();
+    namespace.insert(
+        "__fp_host_panic",
+        Function::new_native_with_env(store, env.clone(), host_panic),
+    );
     namespace.insert(
         "__fp_host_resolve_async_value",
         Function::new_native_with_env(store, env.clone(), resolve_async_value),
     );
     namespace.insert(
         "__fp_gen_import_array_f32",
-        Function::new_native_with_env(store, env.clone(), _import_array_f32),
+        Function::new_native_with_env(store, env.clone(), _import_array_f32::<I>),
     );
     namespace.insert(
         "__fp_gen_import_array_f64",
-        Function::new_native_with_env(store, env.clone(), _import_array_f64),
+        Function::new_native_with_env(store, env.clone(), _import_array_f64::<I>),
     );
     namespace.insert(
         "__fp_gen_import_array_i16",
-        Function::new_native_with_env(store, env.clone(), _import_array_i16),
+        Function::new_native_with_env(store, env.clone(), _import_array_i16::<I>),
     );
     namespace.insert(
         "__fp_gen_import_array_i32",
-        Function::new_native_with_env(store, env.clone(), _import_array_i32),
+        Function::new_native_with_env(store, env.clone(), _import_array_i32::<I>),
     );
     namespace.insert(
         "__fp_gen_import_array_i8",
-        Function::new_native_with_env(store, env.clone(), _import_array_i8),
+        Function::new_native_with_env(store, env.clone(), _import_array_i8::<I>),
     );
     namespace.insert(
         "__fp_gen_import_array_u16",
-        Function::new_native_with_env(store, env.clone(), _import_array_u16),
+        Function::new_native_with_env(store, env.clone(), _import_array_u16::<I>),
     );
     namespace.insert(
         "__fp_gen_import_array_u32",
-        Function::new_native_with_env(store, env.clone(), _import_array_u32),
+        Function::new_native_with_env(store, env.clone(), _import_array_u32::<I>),
     );
     namespace.insert(
         "__fp_gen_import_array_u8",
-        Function::new_native_with_env(store, env.clone(), _import_array_u8),
+        Function::new_native_with_env(store, env.clone(), _import_array_u8::<I>),
     );
     namespace.insert(
         "__fp_gen_import_explicit_bound_point",
-        Function::new_native_with_env(store, env.clone(), _import_explicit_bound_point),
+        Function::new_native_with_env(store, env.clone(), _import_explicit_bound_point::<I>),
     );
     namespace.insert(
         "__fp_gen_import_fp_adjacently_tagged",
-        Function::new_native_with_env(store, env.clone(), _import_fp_adjacently_tagged),
+        Function::new_native_with_env(store, env.clone(), _import_fp_adjacently_tagged::<I>),
     );
     namespace.insert(
         "__fp_gen_import_fp_enum",
-        Function::new_native_with_env(store, env.clone(), _import_fp_enum),
+        Function::new_native_with_env(store, env.clone(), _import_fp_enum::<I>),
     );
     namespace.insert(
         "__fp_gen_import_fp_flatten",
-        Function::new_native_with_env(store, env.clone(), _import_fp_flatten),
+        Function::new_native_with_env(store, env.clone(), _import_fp_flatten::<I>),
     );
     namespace.insert(
         "__fp_gen_import_fp_internally_tagged",
-        Function::new_native_with_env(store, env.clone(), _import_fp_internally_tagged),
+        Function::new_native_with_env(store, env.clone(), _import_fp_internally_tagged::<I>),
     );
     namespace.insert(
         "__fp_gen_import_fp_struct",
-        Function::new_native_with_env(store, env.clone(), _import_fp_struct),
+        Function::new_native_with_env(store, env.clone(), _import_fp_struct::<I>),
     );
     namespace.insert(
         "__fp_gen_import_fp_untagged",
-        Function::new_native_with_env(store, env.clone(), _import_fp_untagged),
+        Function::new_native_with_env(store, env.clone(), _import_fp_untagged::<I>),
     );
     namespace.insert(
         "__fp_gen_import_generics",
-        Function::new_native_with_env(store, env.clone(), _import_generics),
+        Function::new_native_with_env(store, env.clone(), _import_generics::<I>),
     );
     namespace.insert(
         "__fp_gen_import_get_bytes",
-        Function::new_native_with_env(store, env.clone(), _import_get_bytes),
+        Function::new_native_with_env(store, env.clone(), _import_get_bytes::<I>),
     );
     namespace.insert(
         "__fp_gen_import_get_serde_bytes",
-        Function::new_native_with_env(store, env.clone(), _import_get_serde_bytes),
+        Function::new_native_with_env(store, env.clone(), _import_get_serde_bytes::<I>),
     );
     namespace.insert(
         "__fp_gen_import_increment_global_state",
-        Function::new_native_with_env(store, env.clone(), _import_increment_global_state),
+        Function::new_native_with_env(store, env.clone(), _import_increment_global_state::<I>),
     );
     namespace.insert(
         "__fp_gen_import_multiple_primitives",
-        Function::new_native_with_env(store, env.clone(), _import_multiple_primitives),
+        Function::new_native_with_env(store, env.clone(), _import_multiple_primitives::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_bool_negate",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_bool_negate),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_bool_negate::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_bool_negate_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_bool_negate_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_bool_negate_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_f32_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_f32_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_f32_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_f32_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_f32_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_f32_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_f32_add_one_wasmer2",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_f32_add_one_wasmer2),
+        Function::new_native_with_env(
+            store,
+            env.clone(),
+            _import_primitive_f32_add_one_wasmer2::<I>,
+        ),
     );
     namespace.insert(
         "__fp_gen_import_primitive_f64_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_f64_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_f64_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_f64_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_f64_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_f64_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_f64_add_one_wasmer2",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_f64_add_one_wasmer2),
+        Function::new_native_with_env(
+            store,
+            env.clone(),
+            _import_primitive_f64_add_one_wasmer2::<I>,
+        ),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i16_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i16_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i16_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i16_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i16_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i16_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i32_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i32_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i32_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i32_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i32_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i32_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i64_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i64_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i64_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i64_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i64_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i64_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i8_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i8_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i8_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_i8_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_i8_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_i8_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u16_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u16_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u16_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u16_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u16_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u16_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u32_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u32_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u32_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u32_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u32_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u32_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u64_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u64_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u64_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u64_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u64_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u64_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u8_add_one",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u8_add_one),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u8_add_one::<I>),
     );
     namespace.insert(
         "__fp_gen_import_primitive_u8_add_one_async",
-        Function::new_native_with_env(store, env.clone(), _import_primitive_u8_add_one_async),
+        Function::new_native_with_env(store, env.clone(), _import_primitive_u8_add_one_async::<I>),
     );
     namespace.insert(
         "__fp_gen_import_reset_global_state",
-        Function::new_native_with_env(store, env.clone(), _import_reset_global_state),
+        Function::new_native_with_env(store, env.clone(), _import_reset_global_state::<I>),
     );
     namespace.insert(
         "__fp_gen_import_serde_adjacently_tagged",
-        Function::new_native_with_env(store, env.clone(), _import_serde_adjacently_tagged),
+        Function::new_native_with_env(store, env.clone(), _import_serde_adjacently_tagged::<I>),
     );
     namespace.insert(
         "__fp_gen_import_serde_enum",
-        Function::new_native_with_env(store, env.clone(), _import_serde_enum),
+        Function::new_native_with_env(store, env.clone(), _import_serde_enum::<I>),
     );
     namespace.insert(
         "__fp_gen_import_serde_flatten",
-        Function::new_native_with_env(store, env.clone(), _import_serde_flatten),
+        Function::new_native_with_env(store, env.clone(), _import_serde_flatten::<I>),
     );
     namespace.insert(
         "__fp_gen_import_serde_internally_tagged",
-        Function::new_native_with_env(store, env.clone(), _import_serde_internally_tagged),
+        Function::new_native_with_env(store, env.clone(), _import_serde_internally_tagged::<I>),
     );
     namespace.insert(
         "__fp_gen_import_serde_struct",
-        Function::new_native_with_env(store, env.clone(), _import_serde_struct),
+        Function::new_native_with_env(store, env.clone(), _import_serde_struct::<I>),
     );
     namespace.insert(
         "__fp_gen_import_serde_untagged",
-        Function::new_native_with_env(store, env.clone(), _import_serde_untagged),
+        Function::new_native_with_env(store, env.clone(), _import_serde_untagged::<I>),
     );
     namespace.insert(
         "__fp_gen_import_string",
-        Function::new_native_with_env(store, env.clone(), _import_string),
+        Function::new_native_with_env(store, env.clone(), _import_string::<I>),
     );
     namespace.insert(
         "__fp_gen_import_struct_with_options",
-        Function::new_native_with_env(store, env.clone(), _import_struct_with_options),
+        Function::new_native_with_env(store, env.clone(), _import_struct_with_options::<I>),
     );
     namespace.insert(
         "__fp_gen_import_timestamp",
-        Function::new_native_with_env(store, env.clone(), _import_timestamp),
+        Function::new_native_with_env(store, env.clone(), _import_timestamp::<I>),
     );
     namespace.insert(
         "__fp_gen_import_void_function",
-        Function::new_native_with_env(store, env.clone(), _import_void_function),
+        Function::new_native_with_env(store, env.clone(), _import_void_function::<I>),
     );
     namespace.insert(
         "__fp_gen_import_void_function_empty_result",
-        Function::new_native_with_env(store, env.clone(), _import_void_function_empty_result),
+        Function::new_native_with_env(store, env.clone(), _import_void_function_empty_result::<I>),
     );
     namespace.insert(
         "__fp_gen_import_void_function_empty_return",
-        Function::new_native_with_env(store, env.clone(), _import_void_function_empty_return),
+        Function::new_native_with_env(store, env.clone(), _import_void_function_empty_return::<I>),
     );
     namespace.insert(
         "__fp_gen_log",
-        Function::new_native_with_env(store, env.clone(), _log),
+        Function::new_native_with_env(store, env.clone(), _log::<I>),
     );
     namespace.insert(
         "__fp_gen_make_http_request",
-        Function::new_native_with_env(store, env.clone(), _make_http_request),
+        Function::new_native_with_env(store, env.clone(), _make_http_request::<I>),
     );
     namespace
 }
 
-pub fn _import_array_f32(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[f32; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_f32(arg))
+pub fn _import_array_f32<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[f32; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_f32(arg))
 }
 
-pub fn _import_array_f64(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[f64; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_f64(arg))
+pub fn _import_array_f64<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[f64; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_f64(arg))
 }
 
-pub fn _import_array_i16(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[i16; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_i16(arg))
+pub fn _import_array_i16<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[i16; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_i16(arg))
 }
 
-pub fn _import_array_i32(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[i32; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_i32(arg))
+pub fn _import_array_i32<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[i32; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_i32(arg))
 }
 
-pub fn _import_array_i8(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[i8; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_i8(arg))
+pub fn _import_array_i8<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[i8; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_i8(arg))
 }
 
-pub fn _import_array_u16(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[u16; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_u16(arg))
+pub fn _import_array_u16<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[u16; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_u16(arg))
 }
 
-pub fn _import_array_u32(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[u32; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_u32(arg))
+pub fn _import_array_u32<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[u32; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_u32(arg))
 }
 
-pub fn _import_array_u8(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<[u8; 3]>(env, arg);
-    export_to_guest(env, &super::import_array_u8(arg))
+pub fn _import_array_u8<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<[u8; 3]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_array_u8(arg))
 }
 
-pub fn _import_explicit_bound_point(env: &RuntimeInstanceData, arg: FatPtr) {
-    let arg = import_from_guest::<ExplicitBoundPoint<u64>>(env, arg);
-    super::import_explicit_bound_point(arg)
+pub fn _import_explicit_bound_point<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<(), InvocationError> {
+    let arg = import_from_guest::<ExplicitBoundPoint<u64>>(env, arg)?;
+    let imports = env.context::<I>()?;
+    imports.import_explicit_bound_point(arg);
+    Ok(())
 }
 
-pub fn _import_fp_adjacently_tagged(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<FpAdjacentlyTagged>(env, arg);
-    export_to_guest(env, &super::import_fp_adjacently_tagged(arg))
+pub fn _import_fp_adjacently_tagged<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<FpAdjacentlyTagged>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_fp_adjacently_tagged(arg))
 }
 
-pub fn _import_fp_enum(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<FpVariantRenaming>(env, arg);
-    export_to_guest(env, &super::import_fp_enum(arg))
+pub fn _import_fp_enum<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<FpVariantRenaming>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_fp_enum(arg))
 }
 
-pub fn _import_fp_flatten(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<FpFlatten>(env, arg);
-    export_to_guest(env, &super::import_fp_flatten(arg))
+pub fn _import_fp_flatten<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<FpFlatten>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_fp_flatten(arg))
 }
 
-pub fn _import_fp_internally_tagged(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<FpInternallyTagged>(env, arg);
-    export_to_guest(env, &super::import_fp_internally_tagged(arg))
+pub fn _import_fp_internally_tagged<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<FpInternallyTagged>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_fp_internally_tagged(arg))
 }
 
-pub fn _import_fp_struct(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<FpPropertyRenaming>(env, arg);
-    export_to_guest(env, &super::import_fp_struct(arg))
+pub fn _import_fp_struct<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<FpPropertyRenaming>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_fp_struct(arg))
 }
 
-pub fn _import_fp_untagged(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<FpUntagged>(env, arg);
-    export_to_guest(env, &super::import_fp_untagged(arg))
+pub fn _import_fp_untagged<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<FpUntagged>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_fp_untagged(arg))
 }
 
-pub fn _import_generics(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<StructWithGenerics<u64>>(env, arg);
-    export_to_guest(env, &super::import_generics(arg))
+pub fn _import_generics<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<StructWithGenerics<u64>>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_generics(arg))
 }
 
-pub fn _import_get_bytes(env: &RuntimeInstanceData) -> FatPtr {
-    export_to_guest(env, &super::import_get_bytes())
+pub fn _import_get_bytes<I: Imports>(env: &RuntimeInstanceData) -> Result<FatPtr, InvocationError> {
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_get_bytes())
 }
 
-pub fn _import_get_serde_bytes(env: &RuntimeInstanceData) -> FatPtr {
-    export_to_guest(env, &super::import_get_serde_bytes())
+pub fn _import_get_serde_bytes<I: Imports>(
+    env: &RuntimeInstanceData,
+) -> Result<FatPtr, InvocationError> {
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_get_serde_bytes())
 }
 
-pub fn _import_increment_global_state(env: &RuntimeInstanceData) -> FatPtr {
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_increment_global_state().await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+pub fn _import_increment_global_state<I: Imports>(
+    env: &RuntimeInstanceData,
+) -> Result<FatPtr, InvocationError> {
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_increment_global_state().await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_multiple_primitives(
+pub fn _import_multiple_primitives<I: Imports>(
     env: &RuntimeInstanceData,
     arg1: <i8 as WasmAbi>::AbiType,
     arg2: FatPtr,
-) -> <i64 as WasmAbi>::AbiType {
+) -> Result<<i64 as WasmAbi>::AbiType, InvocationError> {
     let arg1 = WasmAbi::from_abi(arg1);
-    let arg2 = import_from_guest::<String>(env, arg2);
-    super::import_multiple_primitives(arg1, arg2).to_abi()
+    let arg2 = import_from_guest::<String>(env, arg2)?;
+    let imports = env.context::<I>()?;
+    Ok(imports.import_multiple_primitives(arg1, arg2).to_abi())
 }
 
-pub fn _import_primitive_bool_negate(
+pub fn _import_primitive_bool_negate<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <bool as WasmAbi>::AbiType,
-) -> <bool as WasmAbi>::AbiType {
+) -> Result<<bool as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_bool_negate(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_bool_negate(arg).to_abi())
 }
 
-pub fn _import_primitive_bool_negate_async(
+pub fn _import_primitive_bool_negate_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <bool as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_bool_negate_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_bool_negate_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_f32_add_one(
+pub fn _import_primitive_f32_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <f32 as WasmAbi>::AbiType,
-) -> <f32 as WasmAbi>::AbiType {
+) -> Result<<f32 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_f32_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_f32_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_f32_add_one_async(
+pub fn _import_primitive_f32_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <f32 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_f32_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_f32_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_f32_add_one_wasmer2(
+pub fn _import_primitive_f32_add_one_wasmer2<I: Imports>(
     env: &RuntimeInstanceData,
     arg: FatPtr,
-) -> <f32 as WasmAbi>::AbiType {
-    let arg = import_from_guest::<[f32; 1]>(env, arg);
-    super::import_primitive_f32_add_one_wasmer2(arg).to_abi()
+) -> Result<<f32 as WasmAbi>::AbiType, InvocationError> {
+    let arg = import_from_guest::<[f32; 1]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_f32_add_one_wasmer2(arg).to_abi())
 }
 
-pub fn _import_primitive_f64_add_one(
+pub fn _import_primitive_f64_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <f64 as WasmAbi>::AbiType,
-) -> <f64 as WasmAbi>::AbiType {
+) -> Result<<f64 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_f64_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_f64_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_f64_add_one_async(
+pub fn _import_primitive_f64_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <f64 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_f64_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_f64_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_f64_add_one_wasmer2(
+pub fn _import_primitive_f64_add_one_wasmer2<I: Imports>(
     env: &RuntimeInstanceData,
     arg: FatPtr,
-) -> <f64 as WasmAbi>::AbiType {
-    let arg = import_from_guest::<[f64; 1]>(env, arg);
-    super::import_primitive_f64_add_one_wasmer2(arg).to_abi()
+) -> Result<<f64 as WasmAbi>::AbiType, InvocationError> {
+    let arg = import_from_guest::<[f64; 1]>(env, arg)?;
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_f64_add_one_wasmer2(arg).to_abi())
 }
 
-pub fn _import_primitive_i16_add_one(
+pub fn _import_primitive_i16_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i16 as WasmAbi>::AbiType,
-) -> <i16 as WasmAbi>::AbiType {
+) -> Result<<i16 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_i16_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_i16_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_i16_add_one_async(
+pub fn _import_primitive_i16_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i16 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_i16_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_i16_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_i32_add_one(
+pub fn _import_primitive_i32_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i32 as WasmAbi>::AbiType,
-) -> <i32 as WasmAbi>::AbiType {
+) -> Result<<i32 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_i32_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_i32_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_i32_add_one_async(
+pub fn _import_primitive_i32_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i32 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_i32_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_i32_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_i64_add_one(
+pub fn _import_primitive_i64_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i64 as WasmAbi>::AbiType,
-) -> <i64 as WasmAbi>::AbiType {
+) -> Result<<i64 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_i64_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_i64_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_i64_add_one_async(
+pub fn _import_primitive_i64_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i64 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_i64_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_i64_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_i8_add_one(
+pub fn _import_primitive_i8_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i8 as WasmAbi>::AbiType,
-) -> <i8 as WasmAbi>::AbiType {
+) -> Result<<i8 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_i8_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_i8_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_i8_add_one_async(
+pub fn _import_primitive_i8_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <i8 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_i8_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_i8_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_u16_add_one(
+pub fn _import_primitive_u16_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u16 as WasmAbi>::AbiType,
-) -> <u16 as WasmAbi>::AbiType {
+) -> Result<<u16 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_u16_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_u16_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_u16_add_one_async(
+pub fn _import_primitive_u16_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u16 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_u16_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_u16_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_u32_add_one(
+pub fn _import_primitive_u32_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u32 as WasmAbi>::AbiType,
-) -> <u32 as WasmAbi>::AbiType {
+) -> Result<<u32 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_u32_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_u32_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_u32_add_one_async(
+pub fn _import_primitive_u32_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u32 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_u32_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_u32_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_u64_add_one(
+pub fn _import_primitive_u64_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u64 as WasmAbi>::AbiType,
-) -> <u64 as WasmAbi>::AbiType {
+) -> Result<<u64 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_u64_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_u64_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_u64_add_one_async(
+pub fn _import_primitive_u64_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u64 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_u64_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_u64_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_primitive_u8_add_one(
+pub fn _import_primitive_u8_add_one<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u8 as WasmAbi>::AbiType,
-) -> <u8 as WasmAbi>::AbiType {
+) -> Result<<u8 as WasmAbi>::AbiType, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    super::import_primitive_u8_add_one(arg).to_abi()
+    let imports = env.context::<I>()?;
+    Ok(imports.import_primitive_u8_add_one(arg).to_abi())
 }
 
-pub fn _import_primitive_u8_add_one_async(
+pub fn _import_primitive_u8_add_one_async<I: Imports>(
     env: &RuntimeInstanceData,
     arg: <u8 as WasmAbi>::AbiType,
-) -> FatPtr {
+) -> Result<FatPtr, InvocationError> {
     let arg = WasmAbi::from_abi(arg);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_primitive_u8_add_one_async(arg).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_primitive_u8_add_one_async(arg).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_reset_global_state(env: &RuntimeInstanceData) -> FatPtr {
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::import_reset_global_state().await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+pub fn _import_reset_global_state<I: Imports>(
+    env: &RuntimeInstanceData,
+) -> Result<FatPtr, InvocationError> {
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.import_reset_global_state().await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
 
-pub fn _import_serde_adjacently_tagged(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<SerdeAdjacentlyTagged>(env, arg);
-    export_to_guest(env, &super::import_serde_adjacently_tagged(arg))
+pub fn _import_serde_adjacently_tagged<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<SerdeAdjacentlyTagged>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_serde_adjacently_tagged(arg))
 }
 
-pub fn _import_serde_enum(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<SerdeVariantRenaming>(env, arg);
-    export_to_guest(env, &super::import_serde_enum(arg))
+pub fn _import_serde_enum<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<SerdeVariantRenaming>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_serde_enum(arg))
 }
 
-pub fn _import_serde_flatten(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<SerdeFlatten>(env, arg);
-    export_to_guest(env, &super::import_serde_flatten(arg))
+pub fn _import_serde_flatten<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<SerdeFlatten>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_serde_flatten(arg))
 }
 
-pub fn _import_serde_internally_tagged(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<SerdeInternallyTagged>(env, arg);
-    export_to_guest(env, &super::import_serde_internally_tagged(arg))
+pub fn _import_serde_internally_tagged<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<SerdeInternallyTagged>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_serde_internally_tagged(arg))
 }
 
-pub fn _import_serde_struct(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<SerdePropertyRenaming>(env, arg);
-    export_to_guest(env, &super::import_serde_struct(arg))
+pub fn _import_serde_struct<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<SerdePropertyRenaming>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_serde_struct(arg))
 }
 
-pub fn _import_serde_untagged(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<SerdeUntagged>(env, arg);
-    export_to_guest(env, &super::import_serde_untagged(arg))
+pub fn _import_serde_untagged<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<SerdeUntagged>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_serde_untagged(arg))
 }
 
-pub fn _import_string(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<String>(env, arg);
-    export_to_guest(env, &super::import_string(arg))
+pub fn _import_string<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<String>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_string(arg))
 }
 
-pub fn _import_struct_with_options(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<StructWithOptions>(env, arg);
-    export_to_guest(env, &super::import_struct_with_options(arg))
+pub fn _import_struct_with_options<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<StructWithOptions>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_struct_with_options(arg))
 }
 
-pub fn _import_timestamp(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<MyDateTime>(env, arg);
-    export_to_guest(env, &super::import_timestamp(arg))
+pub fn _import_timestamp<I: Imports>(
+    env: &RuntimeInstanceData,
+    arg: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let arg = import_from_guest::<MyDateTime>(env, arg)?;
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_timestamp(arg))
 }
 
-pub fn _import_void_function(env: &RuntimeInstanceData) {
-    super::import_void_function()
+pub fn _import_void_function<I: Imports>(env: &RuntimeInstanceData) -> Result<(), InvocationError> {
+    let imports = env.context::<I>()?;
+    imports.import_void_function();
+    Ok(())
 }
 
-pub fn _import_void_function_empty_result(env: &RuntimeInstanceData) -> FatPtr {
-    export_to_guest(env, &super::import_void_function_empty_result())
+pub fn _import_void_function_empty_result<I: Imports>(
+    env: &RuntimeInstanceData,
+) -> Result<FatPtr, InvocationError> {
+    let imports = env.context::<I>()?;
+    export_to_guest(env, &imports.import_void_function_empty_result())
 }
 
-pub fn _import_void_function_empty_return(env: &RuntimeInstanceData) {
-    super::import_void_function_empty_return()
+pub fn _import_void_function_empty_return<I: Imports>(
+    env: &RuntimeInstanceData,
+) -> Result<(), InvocationError> {
+    let imports = env.context::<I>()?;
+    imports.import_void_function_empty_return();
+    Ok(())
 }
 
-pub fn _log(env: &RuntimeInstanceData, message: FatPtr) {
-    let message = import_from_guest::<String>(env, message);
-    super::log(message)
+pub fn _log<I: Imports>(env: &RuntimeInstanceData, message: FatPtr) -> Result<(), InvocationError> {
+    let message = import_from_guest::<String>(env, message)?;
+    let imports = env.context::<I>()?;
+    imports.log(message);
+    Ok(())
 }
 
-pub fn _make_http_request(env: &RuntimeInstanceData, request: FatPtr) -> FatPtr {
-    let request = import_from_guest::<Request>(env, request);
-    let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {
-        let result = super::make_http_request(request).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    });
-    async_ptr
+pub fn _make_http_request<I: Imports>(
+    env: &RuntimeInstanceData,
+    request: FatPtr,
+) -> Result<FatPtr, InvocationError> {
+    let request = import_from_guest::<Request>(env, request)?;
+    let imports = env.context::<I>()?;
+    let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {
+        let result = imports.make_http_request(request).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
 }
@@ -0,0 +1,2746 @@
+#![allow(unused)]
+use super::types::*;
+use fp_bindgen_support::{
+    common::mem::FatPtr,
+    wasmtime_host::{
+        abi::WasmtimeAbi,
+        errors::{InvocationError, RuntimeError},
+        mem::{
+            deserialize_from_slice, export_to_guest, export_to_guest_raw, import_from_guest,
+            import_from_guest_raw, serialize_to_vec,
+        },
+        panic::host_panic,
+        r#async::{
+            create_future_value, future::ModuleRawFuture, resolve_async_value,
+            resolve_future_value, Spawner,
+        },
+        runtime::{check_protocol_version, initialize_instance, RuntimeInstanceData, RuntimeStore},
+    },
+};
+use wasmtime::{Caller, Engine, Instance, Linker, Module};
+
+/// Fingerprint of the protocol these bindings were generated from. Plugins must
+/// have been generated from the same protocol for the runtime to accept them.
+pub const PROTOCOL_VERSION: u64 = 0x1e72896d627cc76e;
+
+/// Functions imported by the plugin, which are to be implemented by the runtime.
+///
+/// Every runtime instance owns its own implementation, so any state that should be
+/// scoped to a single instance can be kept on the implementing type.
+pub trait Imports: Send + Sync + 'static {
+    fn import_array_f32(&self, arg: [f32; 3]) -> [f32; 3];
+
+    fn import_array_f64(&self, arg: [f64; 3]) -> [f64; 3];
+
+    fn import_array_i16(&self, arg: [i16; 3]) -> [i16; 3];
+
+    fn import_array_i32(&self, arg: [i32; 3]) -> [i32; 3];
+
+    fn import_array_i8(&self, arg: [i8; 3]) -> [i8; 3];
+
+    fn import_array_u16(&self, arg: [u16; 3]) -> [u16; 3];
+
+    fn import_array_u32(&self, arg: [u32; 3]) -> [u32; 3];
+
+    fn import_array_u8(&self, arg: [u8; 3]) -> [u8; 3];
+
+    fn import_explicit_bound_point(&self, arg: ExplicitBoundPoint<u64>);
+
+    fn import_fp_adjacently_tagged(&self, arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
+
+    fn import_fp_enum(&self, arg: FpVariantRenaming) -> FpVariantRenaming;
+
+    fn import_fp_flatten(&self, arg: FpFlatten) -> FpFlatten;
+
+    fn import_fp_internally_tagged(&self, arg: FpInternallyTagged) -> FpInternallyTagged;
+
+    fn import_fp_struct(&self, arg: FpPropertyRenaming) -> FpPropertyRenaming;
+
+    fn import_fp_untagged(&self, arg: FpUntagged) -> FpUntagged;
+
+    fn import_generics(&self, arg: StructWithGenerics<u64>) -> StructWithGenerics<u64>;
+
+    fn import_get_bytes(&self) -> Result<bytes::Bytes, String>;
+
+    fn import_get_serde_bytes(&self) -> Result<serde_bytes::ByteBuf, String>;
+
+    fn import_increment_global_state(&self) -> impl std::future::Future<Output = ()> + Send;
+
+    fn import_multiple_primitives(&self, arg1: i8, arg2: String) -> i64;
+
+    fn import_primitive_bool_negate(&self, arg: bool) -> bool;
+
+    fn import_primitive_bool_negate_async(
+        &self,
+        arg: bool,
+    ) -> impl std::future::Future<Output = bool> + Send;
+
+    fn import_primitive_f32_add_one(&self, arg: f32) -> f32;
+
+    fn import_primitive_f32_add_one_async(
+        &self,
+        arg: f32,
+    ) -> impl std::future::Future<Output = f32> + Send;
+
+    fn import_primitive_f32_add_one_wasmer2(&self, arg: [f32; 1]) -> f32;
+
+    fn import_primitive_f64_add_one(&self, arg: f64) -> f64;
+
+    fn import_primitive_f64_add_one_async(
+        &self,
+        arg: f64,
+    ) -> impl std::future::Future<Output = f64> + Send;
+
+    fn import_primitive_f64_add_one_wasmer2(&self, arg: [f64; 1]) -> f64;
+
+    fn import_primitive_i16_add_one(&self, arg: i16) -> i16;
+
+    fn import_primitive_i16_add_one_async(
+        &self,
+        arg: i16,
+    ) -> impl std::future::Future<Output = i16> + Send;
+
+    fn import_primitive_i32_add_one(&self, arg: i32) -> i32;
+
+    fn import_primitive_i32_add_one_async(
+        &self,
+        arg: i32,
+    ) -> impl std::future::Future<Output = i32> + Send;
+
+    fn import_primitive_i64_add_one(&self, arg: i64) -> i64;
+
+    fn import_primitive_i64_add_one_async(
+        &self,
+        arg: i64,
+    ) -> impl std::future::Future<Output = i64> + Send;
+
+    fn import_primitive_i8_add_one(&self, arg: i8) -> i8;
+
+    fn import_primitive_i8_add_one_async(
+        &self,
+        arg: i8,
+    ) -> impl std::future::Future<Output = i8> + Send;
+
+    fn import_primitive_u16_add_one(&self, arg: u16) -> u16;
+
+    fn import_primitive_u16_add_one_async(
+        &self,
+        arg: u16,
+    ) -> impl std::future::Future<Output = u16> + Send;
+
+    fn import_primitive_u32_add_one(&self, arg: u32) -> u32;
+
+    fn import_primitive_u32_add_one_async(
+        &self,
+        arg: u32,
+    ) -> impl std::future::Future<Output = u32> + Send;
+
+    fn import_primitive_u64_add_one(&self, arg: u64) -> u64;
+
+    fn import_primitive_u64_add_one_async(
+        &self,
+        arg: u64,
+    ) -> impl std::future::Future<Output = u64> + Send;
+
+    fn import_primitive_u8_add_one(&self, arg: u8) -> u8;
+
+    fn import_primitive_u8_add_one_async(
+        &self,
+        arg: u8,
+    ) -> impl std::future::Future<Output = u8> + Send;
+
+    fn import_reset_global_state(&self) -> impl std::future::Future<Output = ()> + Send;
+
+    fn import_serde_adjacently_tagged(&self, arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged;
+
+    fn import_serde_enum(&self, arg: SerdeVariantRenaming) -> SerdeVariantRenaming;
+
+    fn import_serde_flatten(&self, arg: SerdeFlatten) -> SerdeFlatten;
+
+    fn import_serde_internally_tagged(&self, arg: SerdeInternallyTagged) -> SerdeInternallyTagged;
+
+    fn import_serde_struct(&self, arg: SerdePropertyRenaming) -> SerdePropertyRenaming;
+
+    fn import_serde_untagged(&self, arg: SerdeUntagged) -> SerdeUntagged;
+
+    fn import_string(&self, arg: String) -> String;
+
+    fn import_struct_with_options(&self, arg: StructWithOptions) -> StructWithOptions;
+
+    fn import_timestamp(&self, arg: MyDateTime) -> MyDateTime;
+
+    fn import_void_function(&self);
+
+    fn import_void_function_empty_result(&self) -> Result<(), u32>;
+
+    fn import_void_function_empty_return(&self);
+
+    /// Logs a message to the (development) console.
+    fn log(&self, message: String);
+
+    /// Example how a runtime could expose a `Fetch`-like function to plugins.
+    ///
+    /// See `types/http.rs` for more info.
+    fn make_http_request(
+        &self,
+        request: Request,
+    ) -> impl std::future::Future<Output = HttpResult> + Send;
+}
+
+#[derive(Clone)]
+pub struct Runtime {
+    instance: Instance,
+    store: RuntimeStore,
+}
+
+impl Runtime {
+    /// Creates a runtime for the given plugin, which spawns the futures returned
+    /// by async imported functions on the current Tokio runtime.
+    pub fn new<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+    ) -> Result<Self, RuntimeError> {
+        Self::with_spawner(wasm_module, imports, |future| {
+            tokio::runtime::Handle::current().spawn(future);
+        })
+    }
+
+    /// Same as `new()`, but does not verify the plugin was generated from the
+    /// same protocol as the runtime.
+    pub fn new_unchecked<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+    ) -> Result<Self, RuntimeError> {
+        Self::with_spawner_unchecked(wasm_module, imports, |future| {
+            tokio::runtime::Handle::current().spawn(future);
+        })
+    }
+
+    /// Creates a runtime for the given plugin, which uses the given spawner to run
+    /// the futures returned by async imported functions. This allows the runtime
+    /// to be used with executors other than Tokio.
+    pub fn with_spawner<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+        spawner: impl Spawner,
+    ) -> Result<Self, RuntimeError> {
+        let runtime = Self::with_spawner_unchecked(wasm_module, imports, spawner)?;
+        check_protocol_version(
+            &mut *runtime.store.lock().unwrap(),
+            &runtime.instance,
+            PROTOCOL_VERSION,
+        )?;
+        Ok(runtime)
+    }
+
+    /// Same as `with_spawner()`, but does not verify the plugin was generated from
+    /// the same protocol as the runtime.
+    pub fn with_spawner_unchecked<I: Imports>(
+        wasm_module: impl AsRef<[u8]>,
+        imports: I,
+        spawner: impl Spawner,
+    ) -> Result<Self, RuntimeError> {
+        let engine = Engine::default();
+        let module = Module::new(&engine, wasm_module)?;
+        let mut linker = Linker::new(&engine);
+        link_imports::<I>(&mut linker)?;
+        let store = RuntimeInstanceData::new_store(&engine, imports);
+        let instance = {
+            let mut store = store.lock().unwrap();
+            store.data_mut().set_spawner(spawner);
+            let instance = linker.instantiate(&mut *store, &module)?;
+            RuntimeInstanceData::init_with_instance(&mut *store, &instance)?;
+            initialize_instance(&mut *store, &instance)?;
+            instance
+        };
+        Ok(Self { instance, store })
+    }
+
+    pub fn export_array_f32(&self, arg: [f32; 3]) -> Result<[f32; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_f32_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_f32_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_f32")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_f32".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_array_f64(&self, arg: [f64; 3]) -> Result<[f64; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_f64_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_f64_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_f64")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_f64".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_array_i16(&self, arg: [i16; 3]) -> Result<[i16; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i16_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_i16_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_i16")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_i16".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_array_i32(&self, arg: [i32; 3]) -> Result<[i32; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i32_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_i32_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_i32")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_i32".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_array_i8(&self, arg: [i8; 3]) -> Result<[i8; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_i8_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_i8_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_i8")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_i8".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_array_u16(&self, arg: [u16; 3]) -> Result<[u16; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u16_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_u16_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_u16")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_u16".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_array_u32(&self, arg: [u32; 3]) -> Result<[u32; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u32_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_u32_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_u32")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_u32".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_array_u8(&self, arg: [u8; 3]) -> Result<[u8; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_u8_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_u8_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_array_u8")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_array_u8".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_async_struct(
+        &self,
+        arg1: FpPropertyRenaming,
+        arg2: u64,
+    ) -> Result<FpPropertyRenaming, InvocationError> {
+        let arg1 = serialize_to_vec(&arg1);
+        let result = self.export_async_struct_raw(arg1, arg2);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_async_struct_raw(
+        &self,
+        arg1: Vec<u8>,
+        arg2: u64,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg1 = export_to_guest_raw(&mut *store, arg1)?;
+            let function = self
+                .instance
+                .get_typed_func::<(FatPtr, <u64 as WasmtimeAbi>::AbiType), FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_async_struct",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_async_struct".to_owned())
+                })?;
+            let result = function.call(&mut *store, (arg1.to_abi(), arg2.to_abi()))?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_fp_adjacently_tagged(
+        &self,
+        arg: FpAdjacentlyTagged,
+    ) -> Result<FpAdjacentlyTagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_adjacently_tagged_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fp_adjacently_tagged_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_fp_adjacently_tagged",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_fp_adjacently_tagged".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_fp_enum(
+        &self,
+        arg: FpVariantRenaming,
+    ) -> Result<FpVariantRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_enum_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fp_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_fp_enum")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_fp_enum".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_fp_flatten(&self, arg: FpFlatten) -> Result<FpFlatten, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_flatten_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fp_flatten_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_fp_flatten")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_fp_flatten".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_fp_internally_tagged(
+        &self,
+        arg: FpInternallyTagged,
+    ) -> Result<FpInternallyTagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_internally_tagged_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fp_internally_tagged_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_fp_internally_tagged",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_fp_internally_tagged".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_fp_struct(
+        &self,
+        arg: FpPropertyRenaming,
+    ) -> Result<FpPropertyRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_struct_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fp_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_fp_struct")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_fp_struct".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_fp_untagged(&self, arg: FpUntagged) -> Result<FpUntagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_untagged_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fp_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_fp_untagged")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_fp_untagged".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_generics(
+        &self,
+        arg: StructWithGenerics<u64>,
+    ) -> Result<StructWithGenerics<u64>, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_generics_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_generics_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_generics")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_generics".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_get_bytes(&self) -> Result<Result<bytes::Bytes, String>, InvocationError> {
+        let result = self.export_get_bytes_raw();
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_get_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<(), FatPtr>(&mut *store, "__fp_gen_export_get_bytes")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_get_bytes".to_owned())
+                })?;
+            let result = function.call(&mut *store, ())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_get_serde_bytes(
+        &self,
+    ) -> Result<Result<serde_bytes::ByteBuf, String>, InvocationError> {
+        let result = self.export_get_serde_bytes_raw();
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_get_serde_bytes_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<(), FatPtr>(&mut *store, "__fp_gen_export_get_serde_bytes")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_get_serde_bytes".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, ())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_increment_global_state(&self) -> Result<(), InvocationError> {
+        let result = self.export_increment_global_state_raw();
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_increment_global_state_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<(), FatPtr>(&mut *store, "__fp_gen_export_increment_global_state")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_increment_global_state".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, ())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_multiple_primitives(
+        &self,
+        arg1: i8,
+        arg2: String,
+    ) -> Result<i64, InvocationError> {
+        let arg2 = serialize_to_vec(&arg2);
+        let result = self.export_multiple_primitives_raw(arg1, arg2);
+        result
+    }
+    pub fn export_multiple_primitives_raw(
+        &self,
+        arg1: i8,
+        arg2: Vec<u8>,
+    ) -> Result<i64, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg2 = export_to_guest_raw(&mut *store, arg2)?;
+            let function = self.instance
+            .get_typed_func::<(<i8 as WasmtimeAbi>::AbiType, FatPtr), <i64 as WasmtimeAbi>::AbiType>(&mut *store, "__fp_gen_export_multiple_primitives")
+            .map_err(|_| InvocationError::FunctionNotExported("__fp_gen_export_multiple_primitives".to_owned()))?;
+            let result = function.call(&mut *store, (arg1.to_abi(), arg2.to_abi()))?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    /// Panics with the given message, so the runtimes can test guest panics
+    /// are reported to them.
+    pub fn export_panic(&self, message: String) -> Result<(), InvocationError> {
+        let message = serialize_to_vec(&message);
+        let result = self.export_panic_raw(message);
+        result
+    }
+    pub fn export_panic_raw(&self, message: Vec<u8>) -> Result<(), InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let message = export_to_guest_raw(&mut *store, message)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, ()>(&mut *store, "__fp_gen_export_panic")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_panic".to_owned())
+                })?;
+            let result = function.call(&mut *store, message.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_primitive_bool_negate(&self, arg: bool) -> Result<bool, InvocationError> {
+        let result = self.export_primitive_bool_negate_raw(arg);
+        result
+    }
+    pub fn export_primitive_bool_negate_raw(&self, arg: bool) -> Result<bool, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<bool as WasmtimeAbi>::AbiType, <bool as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_bool_negate",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_bool_negate".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_bool_negate_async(
+        &self,
+        arg: bool,
+    ) -> Result<bool, InvocationError> {
+        let result = self.export_primitive_bool_negate_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_bool_negate_async_raw(
+        &self,
+        arg: bool,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<bool as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_bool_negate_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_bool_negate_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_f32_add_three(&self, arg: f32) -> Result<f32, InvocationError> {
+        let result = self.export_primitive_f32_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_f32_add_three_raw(&self, arg: f32) -> Result<f32, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<f32 as WasmtimeAbi>::AbiType, <f32 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_f32_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_f32_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_f32_add_three_async(
+        &self,
+        arg: f32,
+    ) -> Result<f32, InvocationError> {
+        let result = self.export_primitive_f32_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_f32_add_three_async_raw(
+        &self,
+        arg: f32,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<f32 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_f32_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_f32_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_f32_add_three_wasmer2(&self, arg: f32) -> Result<f32, InvocationError> {
+        let result = self.export_primitive_f32_add_three_wasmer2_raw(arg);
+        result
+    }
+    pub fn export_primitive_f32_add_three_wasmer2_raw(
+        &self,
+        arg: f32,
+    ) -> Result<f32, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<f32 as WasmtimeAbi>::AbiType, <f32 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_f32_add_three_wasmer2",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_f32_add_three_wasmer2".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_primitive_f64_add_three(&self, arg: f64) -> Result<f64, InvocationError> {
+        let result = self.export_primitive_f64_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_f64_add_three_raw(&self, arg: f64) -> Result<f64, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<f64 as WasmtimeAbi>::AbiType, <f64 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_f64_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_f64_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_f64_add_three_async(
+        &self,
+        arg: f64,
+    ) -> Result<f64, InvocationError> {
+        let result = self.export_primitive_f64_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_f64_add_three_async_raw(
+        &self,
+        arg: f64,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<f64 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_f64_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_f64_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_f64_add_three_wasmer2(&self, arg: f64) -> Result<f64, InvocationError> {
+        let result = self.export_primitive_f64_add_three_wasmer2_raw(arg);
+        result
+    }
+    pub fn export_primitive_f64_add_three_wasmer2_raw(
+        &self,
+        arg: f64,
+    ) -> Result<f64, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<f64 as WasmtimeAbi>::AbiType, <f64 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_f64_add_three_wasmer2",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_f64_add_three_wasmer2".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_primitive_i16_add_three(&self, arg: i16) -> Result<i16, InvocationError> {
+        let result = self.export_primitive_i16_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_i16_add_three_raw(&self, arg: i16) -> Result<i16, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i16 as WasmtimeAbi>::AbiType, <i16 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i16_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i16_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_i16_add_three_async(
+        &self,
+        arg: i16,
+    ) -> Result<i16, InvocationError> {
+        let result = self.export_primitive_i16_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_i16_add_three_async_raw(
+        &self,
+        arg: i16,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i16 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i16_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i16_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i32_add_three(&self, arg: i32) -> Result<i32, InvocationError> {
+        let result = self.export_primitive_i32_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_i32_add_three_raw(&self, arg: i32) -> Result<i32, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i32 as WasmtimeAbi>::AbiType, <i32 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i32_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i32_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_i32_add_three_async(
+        &self,
+        arg: i32,
+    ) -> Result<i32, InvocationError> {
+        let result = self.export_primitive_i32_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_i32_add_three_async_raw(
+        &self,
+        arg: i32,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i32 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i32_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i32_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i64_add_three(&self, arg: i64) -> Result<i64, InvocationError> {
+        let result = self.export_primitive_i64_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_i64_add_three_raw(&self, arg: i64) -> Result<i64, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i64 as WasmtimeAbi>::AbiType, <i64 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i64_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i64_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_i64_add_three_async(
+        &self,
+        arg: i64,
+    ) -> Result<i64, InvocationError> {
+        let result = self.export_primitive_i64_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_i64_add_three_async_raw(
+        &self,
+        arg: i64,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i64 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i64_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i64_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i8_add_three(&self, arg: i8) -> Result<i8, InvocationError> {
+        let result = self.export_primitive_i8_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_i8_add_three_raw(&self, arg: i8) -> Result<i8, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i8 as WasmtimeAbi>::AbiType, <i8 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i8_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i8_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_i8_add_three_async(
+        &self,
+        arg: i8,
+    ) -> Result<i8, InvocationError> {
+        let result = self.export_primitive_i8_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_i8_add_three_async_raw(
+        &self,
+        arg: i8,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<i8 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_i8_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_i8_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u16_add_three(&self, arg: u16) -> Result<u16, InvocationError> {
+        let result = self.export_primitive_u16_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_u16_add_three_raw(&self, arg: u16) -> Result<u16, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u16 as WasmtimeAbi>::AbiType, <u16 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u16_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u16_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_u16_add_three_async(
+        &self,
+        arg: u16,
+    ) -> Result<u16, InvocationError> {
+        let result = self.export_primitive_u16_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_u16_add_three_async_raw(
+        &self,
+        arg: u16,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u16 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u16_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u16_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u32_add_three(&self, arg: u32) -> Result<u32, InvocationError> {
+        let result = self.export_primitive_u32_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_u32_add_three_raw(&self, arg: u32) -> Result<u32, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u32 as WasmtimeAbi>::AbiType, <u32 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u32_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u32_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_u32_add_three_async(
+        &self,
+        arg: u32,
+    ) -> Result<u32, InvocationError> {
+        let result = self.export_primitive_u32_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_u32_add_three_async_raw(
+        &self,
+        arg: u32,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u32 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u32_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u32_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u64_add_three(&self, arg: u64) -> Result<u64, InvocationError> {
+        let result = self.export_primitive_u64_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_u64_add_three_raw(&self, arg: u64) -> Result<u64, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u64 as WasmtimeAbi>::AbiType, <u64 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u64_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u64_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_u64_add_three_async(
+        &self,
+        arg: u64,
+    ) -> Result<u64, InvocationError> {
+        let result = self.export_primitive_u64_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_u64_add_three_async_raw(
+        &self,
+        arg: u64,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u64 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u64_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u64_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u8_add_three(&self, arg: u8) -> Result<u8, InvocationError> {
+        let result = self.export_primitive_u8_add_three_raw(arg);
+        result
+    }
+    pub fn export_primitive_u8_add_three_raw(&self, arg: u8) -> Result<u8, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u8 as WasmtimeAbi>::AbiType, <u8 as WasmtimeAbi>::AbiType>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u8_add_three",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u8_add_three".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    pub async fn export_primitive_u8_add_three_async(
+        &self,
+        arg: u8,
+    ) -> Result<u8, InvocationError> {
+        let result = self.export_primitive_u8_add_three_async_raw(arg);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_primitive_u8_add_three_async_raw(
+        &self,
+        arg: u8,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<<u8 as WasmtimeAbi>::AbiType, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_primitive_u8_add_three_async",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_primitive_u8_add_three_async".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub async fn export_reset_global_state(&self) -> Result<(), InvocationError> {
+        let result = self.export_reset_global_state_raw();
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn export_reset_global_state_raw(&self) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<(), FatPtr>(&mut *store, "__fp_gen_export_reset_global_state")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_reset_global_state".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, ())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    pub fn export_serde_adjacently_tagged(
+        &self,
+        arg: SerdeAdjacentlyTagged,
+    ) -> Result<SerdeAdjacentlyTagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_adjacently_tagged_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_serde_adjacently_tagged_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_serde_adjacently_tagged",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_serde_adjacently_tagged".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_serde_enum(
+        &self,
+        arg: SerdeVariantRenaming,
+    ) -> Result<SerdeVariantRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_enum_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_serde_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_serde_enum")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_serde_enum".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_serde_flatten(&self, arg: SerdeFlatten) -> Result<SerdeFlatten, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_flatten_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_serde_flatten_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_serde_flatten")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_serde_flatten".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_serde_internally_tagged(
+        &self,
+        arg: SerdeInternallyTagged,
+    ) -> Result<SerdeInternallyTagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_internally_tagged_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_serde_internally_tagged_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_serde_internally_tagged",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_serde_internally_tagged".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_serde_struct(
+        &self,
+        arg: SerdePropertyRenaming,
+    ) -> Result<SerdePropertyRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_struct_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_serde_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_serde_struct")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_serde_struct".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_serde_untagged(
+        &self,
+        arg: SerdeUntagged,
+    ) -> Result<SerdeUntagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_untagged_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_serde_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_serde_untagged")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_serde_untagged".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_string(&self, arg: String) -> Result<String, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_string_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_string_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_string")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_string".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_struct_with_options(
+        &self,
+        arg: StructWithOptions,
+    ) -> Result<StructWithOptions, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_struct_with_options_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_struct_with_options_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(
+                    &mut *store,
+                    "__fp_gen_export_struct_with_options",
+                )
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported(
+                        "__fp_gen_export_struct_with_options".to_owned(),
+                    )
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_timestamp(&self, arg: MyDateTime) -> Result<MyDateTime, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_timestamp_raw(arg);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_timestamp_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let arg = export_to_guest_raw(&mut *store, arg)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_export_timestamp")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_timestamp".to_owned())
+                })?;
+            let result = function.call(&mut *store, arg.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+
+    pub fn export_void_function(&self) -> Result<(), InvocationError> {
+        let result = self.export_void_function_raw();
+        result
+    }
+    pub fn export_void_function_raw(&self) -> Result<(), InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<(), ()>(&mut *store, "__fp_gen_export_void_function")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_export_void_function".to_owned())
+                })?;
+            let result = function.call(&mut *store, ())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    /// Example how plugin could expose async data-fetching capabilities.
+    pub async fn fetch_data(
+        &self,
+        r#type: String,
+    ) -> Result<Result<String, String>, InvocationError> {
+        let r#type = serialize_to_vec(&r#type);
+        let result = self.fetch_data_raw(r#type);
+        let result = result.await;
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub async fn fetch_data_raw(&self, r#type: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let r#type = export_to_guest_raw(&mut *store, r#type)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_fetch_data")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_fetch_data".to_owned())
+                })?;
+            let result = function.call(&mut *store, r#type.to_abi())?;
+            result
+        };
+        let result = ModuleRawFuture::new(self.store.clone(), result).await?;
+        Ok(result)
+    }
+
+    /// Called on the plugin to give it a chance to initialize.
+    pub fn init(&self) -> Result<(), InvocationError> {
+        let result = self.init_raw();
+        result
+    }
+    pub fn init_raw(&self) -> Result<(), InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let function = self
+                .instance
+                .get_typed_func::<(), ()>(&mut *store, "__fp_gen_init")
+                .map_err(|_| InvocationError::FunctionNotExported("__fp_gen_init".to_owned()))?;
+            let result = function.call(&mut *store, ())?;
+            let result = WasmtimeAbi::from_abi(result);
+            result
+        };
+        Ok(result)
+    }
+
+    /// Example how plugin could expose a reducer.
+    pub fn reducer_bridge(&self, action: ReduxAction) -> Result<StateUpdate, InvocationError> {
+        let action = serialize_to_vec(&action);
+        let result = self.reducer_bridge_raw(action);
+        let result = result.and_then(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn reducer_bridge_raw(&self, action: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let result = {
+            let mut store = self.store.lock().unwrap();
+            let action = export_to_guest_raw(&mut *store, action)?;
+            let function = self
+                .instance
+                .get_typed_func::<FatPtr, FatPtr>(&mut *store, "__fp_gen_reducer_bridge")
+                .map_err(|_| {
+                    InvocationError::FunctionNotExported("__fp_gen_reducer_bridge".to_owned())
+                })?;
+            let result = function.call(&mut *store, action.to_abi())?;
+            let result = import_from_guest_raw(&mut *store, result)?;
+            result
+        };
+        Ok(result)
+    }
+}
+
+fn link_imports<I: Imports>(linker: &mut Linker<RuntimeInstanceData>) -> Result<(), RuntimeError> {
+    linker.func_wrap("fp", "__fp_host_panic", host_panic)?;
+    linker.func_wrap("fp", "__fp_host_resolve_async_value", resolve_async_value)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_f32", _import_array_f32::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_f64", _import_array_f64::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_i16", _import_array_i16::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_i32", _import_array_i32::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_i8", _import_array_i8::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_u16", _import_array_u16::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_u32", _import_array_u32::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_array_u8", _import_array_u8::<I>)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_explicit_bound_point",
+        _import_explicit_bound_point::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_fp_adjacently_tagged",
+        _import_fp_adjacently_tagged::<I>,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_fp_enum", _import_fp_enum::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_fp_flatten", _import_fp_flatten::<I>)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_fp_internally_tagged",
+        _import_fp_internally_tagged::<I>,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_fp_struct", _import_fp_struct::<I>)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_fp_untagged",
+        _import_fp_untagged::<I>,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_generics", _import_generics::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_import_get_bytes", _import_get_bytes::<I>)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_get_serde_bytes",
+        _import_get_serde_bytes::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_increment_global_state",
+        _import_increment_global_state::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_multiple_primitives",
+        _import_multiple_primitives::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_bool_negate",
+        _import_primitive_bool_negate::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_bool_negate_async",
+        _import_primitive_bool_negate_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_f32_add_one",
+        _import_primitive_f32_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_f32_add_one_async",
+        _import_primitive_f32_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_f32_add_one_wasmer2",
+        _import_primitive_f32_add_one_wasmer2::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_f64_add_one",
+        _import_primitive_f64_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_f64_add_one_async",
+        _import_primitive_f64_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_f64_add_one_wasmer2",
+        _import_primitive_f64_add_one_wasmer2::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i16_add_one",
+        _import_primitive_i16_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i16_add_one_async",
+        _import_primitive_i16_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i32_add_one",
+        _import_primitive_i32_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i32_add_one_async",
+        _import_primitive_i32_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i64_add_one",
+        _import_primitive_i64_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i64_add_one_async",
+        _import_primitive_i64_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i8_add_one",
+        _import_primitive_i8_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_i8_add_one_async",
+        _import_primitive_i8_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u16_add_one",
+        _import_primitive_u16_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u16_add_one_async",
+        _import_primitive_u16_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u32_add_one",
+        _import_primitive_u32_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u32_add_one_async",
+        _import_primitive_u32_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u64_add_one",
+        _import_primitive_u64_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u64_add_one_async",
+        _import_primitive_u64_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u8_add_one",
+        _import_primitive_u8_add_one::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_primitive_u8_add_one_async",
+        _import_primitive_u8_add_one_async::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_reset_global_state",
+        _import_reset_global_state::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_adjacently_tagged",
+        _import_serde_adjacently_tagged::<I>,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_serde_enum", _import_serde_enum::<I>)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_flatten",
+        _import_serde_flatten::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_internally_tagged",
+        _import_serde_internally_tagged::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_struct",
+        _import_serde_struct::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_serde_untagged",
+        _import_serde_untagged::<I>,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_string", _import_string::<I>)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_struct_with_options",
+        _import_struct_with_options::<I>,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_import_timestamp", _import_timestamp::<I>)?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_void_function",
+        _import_void_function::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_void_function_empty_result",
+        _import_void_function_empty_result::<I>,
+    )?;
+    linker.func_wrap(
+        "fp",
+        "__fp_gen_import_void_function_empty_return",
+        _import_void_function_empty_return::<I>,
+    )?;
+    linker.func_wrap("fp", "__fp_gen_log", _log::<I>)?;
+    linker.func_wrap("fp", "__fp_gen_make_http_request", _make_http_request::<I>)?;
+    Ok(())
+}
+
+pub fn _import_array_f32<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[f32; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_array_f32(arg),
+    )?)
+}
+
+pub fn _import_array_f64<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[f64; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_array_f64(arg),
+    )?)
+}
+
+pub fn _import_array_i16<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[i16; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_array_i16(arg),
+    )?)
+}
+
+pub fn _import_array_i32<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[i32; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_array_i32(arg),
+    )?)
+}
+
+pub fn _import_array_i8<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[i8; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(&mut caller, &imports.import_array_i8(arg))?)
+}
+
+pub fn _import_array_u16<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[u16; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_array_u16(arg),
+    )?)
+}
+
+pub fn _import_array_u32<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[u32; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_array_u32(arg),
+    )?)
+}
+
+pub fn _import_array_u8<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<[u8; 3]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(&mut caller, &imports.import_array_u8(arg))?)
+}
+
+pub fn _import_explicit_bound_point<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<()> {
+    let arg = import_from_guest::<ExplicitBoundPoint<u64>>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    imports.import_explicit_bound_point(arg);
+    Ok(())
+}
+
+pub fn _import_fp_adjacently_tagged<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<FpAdjacentlyTagged>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_fp_adjacently_tagged(arg),
+    )?)
+}
+
+pub fn _import_fp_enum<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<FpVariantRenaming>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(&mut caller, &imports.import_fp_enum(arg))?)
+}
+
+pub fn _import_fp_flatten<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<FpFlatten>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_fp_flatten(arg),
+    )?)
+}
+
+pub fn _import_fp_internally_tagged<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<FpInternallyTagged>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_fp_internally_tagged(arg),
+    )?)
+}
+
+pub fn _import_fp_struct<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<FpPropertyRenaming>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_fp_struct(arg),
+    )?)
+}
+
+pub fn _import_fp_untagged<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<FpUntagged>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_fp_untagged(arg),
+    )?)
+}
+
+pub fn _import_generics<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<StructWithGenerics<u64>>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(&mut caller, &imports.import_generics(arg))?)
+}
+
+pub fn _import_get_bytes<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> wasmtime::Result<FatPtr> {
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(&mut caller, &imports.import_get_bytes())?)
+}
+
+pub fn _import_get_serde_bytes<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> wasmtime::Result<FatPtr> {
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_get_serde_bytes(),
+    )?)
+}
+
+pub fn _import_increment_global_state<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> wasmtime::Result<FatPtr> {
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_increment_global_state().await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_multiple_primitives<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg1: <i8 as WasmtimeAbi>::AbiType,
+    arg2: FatPtr,
+) -> wasmtime::Result<<i64 as WasmtimeAbi>::AbiType> {
+    let arg1 = WasmtimeAbi::from_abi(arg1);
+    let arg2 = import_from_guest::<String>(&mut caller, arg2)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_multiple_primitives(arg1, arg2).to_abi())
+}
+
+pub fn _import_primitive_bool_negate<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <bool as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<bool as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_bool_negate(arg).to_abi())
+}
+
+pub fn _import_primitive_bool_negate_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <bool as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_bool_negate_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_f32_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <f32 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<f32 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_f32_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_f32_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <f32 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_f32_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_f32_add_one_wasmer2<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<<f32 as WasmtimeAbi>::AbiType> {
+    let arg = import_from_guest::<[f32; 1]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_f32_add_one_wasmer2(arg).to_abi())
+}
+
+pub fn _import_primitive_f64_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <f64 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<f64 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_f64_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_f64_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <f64 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_f64_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_f64_add_one_wasmer2<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<<f64 as WasmtimeAbi>::AbiType> {
+    let arg = import_from_guest::<[f64; 1]>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_f64_add_one_wasmer2(arg).to_abi())
+}
+
+pub fn _import_primitive_i16_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i16 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<i16 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_i16_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_i16_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i16 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_i16_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_i32_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i32 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<i32 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_i32_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_i32_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i32 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_i32_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_i64_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i64 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<i64 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_i64_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_i64_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i64 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_i64_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_i8_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i8 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<i8 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_i8_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_i8_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <i8 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_i8_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_u16_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u16 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<u16 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_u16_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_u16_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u16 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_u16_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_u32_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u32 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<u32 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_u32_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_u32_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u32 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_u32_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_u64_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u64 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<u64 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_u64_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_u64_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u64 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_u64_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_primitive_u8_add_one<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u8 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<<u8 as WasmtimeAbi>::AbiType> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    Ok(imports.import_primitive_u8_add_one(arg).to_abi())
+}
+
+pub fn _import_primitive_u8_add_one_async<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: <u8 as WasmtimeAbi>::AbiType,
+) -> wasmtime::Result<FatPtr> {
+    let arg = WasmtimeAbi::from_abi(arg);
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_primitive_u8_add_one_async(arg).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_reset_global_state<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> wasmtime::Result<FatPtr> {
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.import_reset_global_state().await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
+
+pub fn _import_serde_adjacently_tagged<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<SerdeAdjacentlyTagged>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_serde_adjacently_tagged(arg),
+    )?)
+}
+
+pub fn _import_serde_enum<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<SerdeVariantRenaming>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_serde_enum(arg),
+    )?)
+}
+
+pub fn _import_serde_flatten<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<SerdeFlatten>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_serde_flatten(arg),
+    )?)
+}
+
+pub fn _import_serde_internally_tagged<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<SerdeInternallyTagged>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_serde_internally_tagged(arg),
+    )?)
+}
+
+pub fn _import_serde_struct<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<SerdePropertyRenaming>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_serde_struct(arg),
+    )?)
+}
+
+pub fn _import_serde_untagged<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<SerdeUntagged>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_serde_untagged(arg),
+    )?)
+}
+
+pub fn _import_string<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<String>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(&mut caller, &imports.import_string(arg))?)
+}
+
+pub fn _import_struct_with_options<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<StructWithOptions>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_struct_with_options(arg),
+    )?)
+}
+
+pub fn _import_timestamp<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    arg: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let arg = import_from_guest::<MyDateTime>(&mut caller, arg)?;
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_timestamp(arg),
+    )?)
+}
+
+pub fn _import_void_function<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> wasmtime::Result<()> {
+    let imports = caller.data().context::<I>()?;
+    imports.import_void_function();
+    Ok(())
+}
+
+pub fn _import_void_function_empty_result<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> wasmtime::Result<FatPtr> {
+    let imports = caller.data().context::<I>()?;
+    Ok(export_to_guest(
+        &mut caller,
+        &imports.import_void_function_empty_result(),
+    )?)
+}
+
+pub fn _import_void_function_empty_return<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+) -> wasmtime::Result<()> {
+    let imports = caller.data().context::<I>()?;
+    imports.import_void_function_empty_return();
+    Ok(())
+}
+
+pub fn _log<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    message: FatPtr,
+) -> wasmtime::Result<()> {
+    let message = import_from_guest::<String>(&mut caller, message)?;
+    let imports = caller.data().context::<I>()?;
+    imports.log(message);
+    Ok(())
+}
+
+pub fn _make_http_request<I: Imports>(
+    mut caller: Caller<'_, RuntimeInstanceData>,
+    request: FatPtr,
+) -> wasmtime::Result<FatPtr> {
+    let request = import_from_guest::<Request>(&mut caller, request)?;
+    let imports = caller.data().context::<I>()?;
+    let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {
+        let result = imports.make_http_request(request).await;
+        resolve_future_value(&store, async_ptr, &result);
+    })?;
+    Ok(async_ptr)
+}
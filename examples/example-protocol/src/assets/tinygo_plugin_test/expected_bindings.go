@@ -0,0 +1,1520 @@
+// ============================================= //
+// Bindings for TinyGo plugins                   //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package bindings
+
+// ProtocolVersion is the fingerprint of the protocol the bindings were
+// generated for.
+const ProtocolVersion uint64 = 0x1e72896d627cc76e
+
+// Functions imported from the host.
+
+//go:wasmimport fp __fp_gen_import_array_f32
+func __fp_gen_import_array_f32(arg FatPtr) FatPtr
+
+func ImportArrayF32(arg [3]float32) [3]float32 {
+	argWriter := writer{}
+	encode_Array_f32_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_f32(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_f32_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayF32()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_array_f64
+func __fp_gen_import_array_f64(arg FatPtr) FatPtr
+
+func ImportArrayF64(arg [3]float64) [3]float64 {
+	argWriter := writer{}
+	encode_Array_f64_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_f64(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_f64_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayF64()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_array_i16
+func __fp_gen_import_array_i16(arg FatPtr) FatPtr
+
+func ImportArrayI16(arg [3]int16) [3]int16 {
+	argWriter := writer{}
+	encode_Array_i16_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_i16(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_i16_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayI16()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_array_i32
+func __fp_gen_import_array_i32(arg FatPtr) FatPtr
+
+func ImportArrayI32(arg [3]int32) [3]int32 {
+	argWriter := writer{}
+	encode_Array_i32_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_i32(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_i32_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayI32()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_array_i8
+func __fp_gen_import_array_i8(arg FatPtr) FatPtr
+
+func ImportArrayI8(arg [3]int8) [3]int8 {
+	argWriter := writer{}
+	encode_Array_i8_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_i8(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_i8_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayI8()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_array_u16
+func __fp_gen_import_array_u16(arg FatPtr) FatPtr
+
+func ImportArrayU16(arg [3]uint16) [3]uint16 {
+	argWriter := writer{}
+	encode_Array_u16_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_u16(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_u16_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayU16()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_array_u32
+func __fp_gen_import_array_u32(arg FatPtr) FatPtr
+
+func ImportArrayU32(arg [3]uint32) [3]uint32 {
+	argWriter := writer{}
+	encode_Array_u32_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_u32(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_u32_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayU32()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_array_u8
+func __fp_gen_import_array_u8(arg FatPtr) FatPtr
+
+func ImportArrayU8(arg [3]uint8) [3]uint8 {
+	argWriter := writer{}
+	encode_Array_u8_3(&argWriter, arg)
+	resultPtr := __fp_gen_import_array_u8(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_Array_u8_3(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportArrayU8()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_explicit_bound_point
+func __fp_gen_import_explicit_bound_point(arg FatPtr)
+
+func ImportExplicitBoundPoint(arg ExplicitBoundPoint[uint64]) {
+	argWriter := writer{}
+	encode_ExplicitBoundPoint_u64(&argWriter, arg)
+	__fp_gen_import_explicit_bound_point(argWriter.finish())
+}
+
+//go:wasmimport fp __fp_gen_import_fp_adjacently_tagged
+func __fp_gen_import_fp_adjacently_tagged(arg FatPtr) FatPtr
+
+func ImportFpAdjacentlyTagged(arg FpAdjacentlyTagged) FpAdjacentlyTagged {
+	argWriter := writer{}
+	encode_FpAdjacentlyTagged(&argWriter, arg)
+	resultPtr := __fp_gen_import_fp_adjacently_tagged(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_FpAdjacentlyTagged(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportFpAdjacentlyTagged()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_fp_enum
+func __fp_gen_import_fp_enum(arg FatPtr) FatPtr
+
+func ImportFpEnum(arg FpVariantRenaming) FpVariantRenaming {
+	argWriter := writer{}
+	encode_FpVariantRenaming(&argWriter, arg)
+	resultPtr := __fp_gen_import_fp_enum(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_FpVariantRenaming(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportFpEnum()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_fp_flatten
+func __fp_gen_import_fp_flatten(arg FatPtr) FatPtr
+
+func ImportFpFlatten(arg FpFlatten) FpFlatten {
+	argWriter := writer{}
+	encode_FpFlatten(&argWriter, arg)
+	resultPtr := __fp_gen_import_fp_flatten(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_FpFlatten(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportFpFlatten()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_fp_internally_tagged
+func __fp_gen_import_fp_internally_tagged(arg FatPtr) FatPtr
+
+func ImportFpInternallyTagged(arg FpInternallyTagged) FpInternallyTagged {
+	argWriter := writer{}
+	encode_FpInternallyTagged(&argWriter, arg)
+	resultPtr := __fp_gen_import_fp_internally_tagged(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_FpInternallyTagged(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportFpInternallyTagged()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_fp_struct
+func __fp_gen_import_fp_struct(arg FatPtr) FatPtr
+
+func ImportFpStruct(arg FpPropertyRenaming) FpPropertyRenaming {
+	argWriter := writer{}
+	encode_FpPropertyRenaming(&argWriter, arg)
+	resultPtr := __fp_gen_import_fp_struct(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_FpPropertyRenaming(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportFpStruct()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_fp_untagged
+func __fp_gen_import_fp_untagged(arg FatPtr) FatPtr
+
+func ImportFpUntagged(arg FpUntagged) FpUntagged {
+	argWriter := writer{}
+	encode_FpUntagged(&argWriter, arg)
+	resultPtr := __fp_gen_import_fp_untagged(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_FpUntagged(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportFpUntagged()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_generics
+func __fp_gen_import_generics(arg FatPtr) FatPtr
+
+func ImportGenerics(arg StructWithGenerics[uint64]) StructWithGenerics[uint64] {
+	argWriter := writer{}
+	encode_StructWithGenerics_u64(&argWriter, arg)
+	resultPtr := __fp_gen_import_generics(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_StructWithGenerics_u64(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportGenerics()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_get_bytes
+func __fp_gen_import_get_bytes() FatPtr
+
+func ImportGetBytes() Result[[]byte, string] {
+	resultPtr := __fp_gen_import_get_bytes()
+	r := openReader(resultPtr)
+	result := decode_Result_Bytes_String(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportGetBytes()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_get_serde_bytes
+func __fp_gen_import_get_serde_bytes() FatPtr
+
+func ImportGetSerdeBytes() Result[[]byte, string] {
+	resultPtr := __fp_gen_import_get_serde_bytes()
+	r := openReader(resultPtr)
+	result := decode_Result_Bytes_String(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportGetSerdeBytes()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_increment_global_state
+func __fp_gen_import_increment_global_state() FatPtr
+
+func ImportIncrementGlobalState(callback func()) {
+	asyncValuePtr := __fp_gen_import_increment_global_state()
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		openReader(resultPtr).close()
+		callback()
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_multiple_primitives
+func __fp_gen_import_multiple_primitives(arg1 int32, arg2 FatPtr) int64
+
+func ImportMultiplePrimitives(arg1 int8, arg2 string) int64 {
+	arg2Writer := writer{}
+	encode_String(&arg2Writer, arg2)
+	return __fp_gen_import_multiple_primitives(int32(arg1), arg2Writer.finish())
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_bool_negate
+func __fp_gen_import_primitive_bool_negate(arg uint32) uint32
+
+func ImportPrimitiveBoolNegate(arg bool) bool {
+	return __fp_gen_import_primitive_bool_negate(encodeBool(arg)) != 0
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_bool_negate_async
+func __fp_gen_import_primitive_bool_negate_async(arg uint32) FatPtr
+
+func ImportPrimitiveBoolNegateAsync(arg bool, callback func(bool)) {
+	asyncValuePtr := __fp_gen_import_primitive_bool_negate_async(encodeBool(arg))
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_bool(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveBoolNegateAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_f32_add_one
+func __fp_gen_import_primitive_f32_add_one(arg float32) float32
+
+func ImportPrimitiveF32AddOne(arg float32) float32 {
+	return __fp_gen_import_primitive_f32_add_one(arg)
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_f32_add_one_async
+func __fp_gen_import_primitive_f32_add_one_async(arg float32) FatPtr
+
+func ImportPrimitiveF32AddOneAsync(arg float32, callback func(float32)) {
+	asyncValuePtr := __fp_gen_import_primitive_f32_add_one_async(arg)
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_f32(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveF32AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_f32_add_one_wasmer2
+func __fp_gen_import_primitive_f32_add_one_wasmer2(arg FatPtr) float32
+
+func ImportPrimitiveF32AddOneWasmer2(arg [1]float32) float32 {
+	argWriter := writer{}
+	encode_Array_f32_1(&argWriter, arg)
+	return __fp_gen_import_primitive_f32_add_one_wasmer2(argWriter.finish())
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_f64_add_one
+func __fp_gen_import_primitive_f64_add_one(arg float64) float64
+
+func ImportPrimitiveF64AddOne(arg float64) float64 {
+	return __fp_gen_import_primitive_f64_add_one(arg)
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_f64_add_one_async
+func __fp_gen_import_primitive_f64_add_one_async(arg float64) FatPtr
+
+func ImportPrimitiveF64AddOneAsync(arg float64, callback func(float64)) {
+	asyncValuePtr := __fp_gen_import_primitive_f64_add_one_async(arg)
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_f64(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveF64AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_f64_add_one_wasmer2
+func __fp_gen_import_primitive_f64_add_one_wasmer2(arg FatPtr) float64
+
+func ImportPrimitiveF64AddOneWasmer2(arg [1]float64) float64 {
+	argWriter := writer{}
+	encode_Array_f64_1(&argWriter, arg)
+	return __fp_gen_import_primitive_f64_add_one_wasmer2(argWriter.finish())
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i16_add_one
+func __fp_gen_import_primitive_i16_add_one(arg int32) int32
+
+func ImportPrimitiveI16AddOne(arg int16) int16 {
+	return int16(__fp_gen_import_primitive_i16_add_one(int32(arg)))
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i16_add_one_async
+func __fp_gen_import_primitive_i16_add_one_async(arg int32) FatPtr
+
+func ImportPrimitiveI16AddOneAsync(arg int16, callback func(int16)) {
+	asyncValuePtr := __fp_gen_import_primitive_i16_add_one_async(int32(arg))
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_i16(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveI16AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i32_add_one
+func __fp_gen_import_primitive_i32_add_one(arg int32) int32
+
+func ImportPrimitiveI32AddOne(arg int32) int32 {
+	return __fp_gen_import_primitive_i32_add_one(arg)
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i32_add_one_async
+func __fp_gen_import_primitive_i32_add_one_async(arg int32) FatPtr
+
+func ImportPrimitiveI32AddOneAsync(arg int32, callback func(int32)) {
+	asyncValuePtr := __fp_gen_import_primitive_i32_add_one_async(arg)
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_i32(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveI32AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i64_add_one
+func __fp_gen_import_primitive_i64_add_one(arg int64) int64
+
+func ImportPrimitiveI64AddOne(arg int64) int64 {
+	return __fp_gen_import_primitive_i64_add_one(arg)
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i64_add_one_async
+func __fp_gen_import_primitive_i64_add_one_async(arg int64) FatPtr
+
+func ImportPrimitiveI64AddOneAsync(arg int64, callback func(int64)) {
+	asyncValuePtr := __fp_gen_import_primitive_i64_add_one_async(arg)
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_i64(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveI64AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i8_add_one
+func __fp_gen_import_primitive_i8_add_one(arg int32) int32
+
+func ImportPrimitiveI8AddOne(arg int8) int8 {
+	return int8(__fp_gen_import_primitive_i8_add_one(int32(arg)))
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_i8_add_one_async
+func __fp_gen_import_primitive_i8_add_one_async(arg int32) FatPtr
+
+func ImportPrimitiveI8AddOneAsync(arg int8, callback func(int8)) {
+	asyncValuePtr := __fp_gen_import_primitive_i8_add_one_async(int32(arg))
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_i8(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveI8AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u16_add_one
+func __fp_gen_import_primitive_u16_add_one(arg uint32) uint32
+
+func ImportPrimitiveU16AddOne(arg uint16) uint16 {
+	return uint16(__fp_gen_import_primitive_u16_add_one(uint32(arg)))
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u16_add_one_async
+func __fp_gen_import_primitive_u16_add_one_async(arg uint32) FatPtr
+
+func ImportPrimitiveU16AddOneAsync(arg uint16, callback func(uint16)) {
+	asyncValuePtr := __fp_gen_import_primitive_u16_add_one_async(uint32(arg))
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_u16(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveU16AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u32_add_one
+func __fp_gen_import_primitive_u32_add_one(arg uint32) uint32
+
+func ImportPrimitiveU32AddOne(arg uint32) uint32 {
+	return __fp_gen_import_primitive_u32_add_one(arg)
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u32_add_one_async
+func __fp_gen_import_primitive_u32_add_one_async(arg uint32) FatPtr
+
+func ImportPrimitiveU32AddOneAsync(arg uint32, callback func(uint32)) {
+	asyncValuePtr := __fp_gen_import_primitive_u32_add_one_async(arg)
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_u32(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveU32AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u64_add_one
+func __fp_gen_import_primitive_u64_add_one(arg uint64) uint64
+
+func ImportPrimitiveU64AddOne(arg uint64) uint64 {
+	return __fp_gen_import_primitive_u64_add_one(arg)
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u64_add_one_async
+func __fp_gen_import_primitive_u64_add_one_async(arg uint64) FatPtr
+
+func ImportPrimitiveU64AddOneAsync(arg uint64, callback func(uint64)) {
+	asyncValuePtr := __fp_gen_import_primitive_u64_add_one_async(arg)
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_u64(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveU64AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u8_add_one
+func __fp_gen_import_primitive_u8_add_one(arg uint32) uint32
+
+func ImportPrimitiveU8AddOne(arg uint8) uint8 {
+	return uint8(__fp_gen_import_primitive_u8_add_one(uint32(arg)))
+}
+
+//go:wasmimport fp __fp_gen_import_primitive_u8_add_one_async
+func __fp_gen_import_primitive_u8_add_one_async(arg uint32) FatPtr
+
+func ImportPrimitiveU8AddOneAsync(arg uint8, callback func(uint8)) {
+	asyncValuePtr := __fp_gen_import_primitive_u8_add_one_async(uint32(arg))
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_u8(r)
+		if r.failed {
+			Panic("Could not decode result of `ImportPrimitiveU8AddOneAsync()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_reset_global_state
+func __fp_gen_import_reset_global_state() FatPtr
+
+func ImportResetGlobalState(callback func()) {
+	asyncValuePtr := __fp_gen_import_reset_global_state()
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		openReader(resultPtr).close()
+		callback()
+	})
+}
+
+//go:wasmimport fp __fp_gen_import_serde_adjacently_tagged
+func __fp_gen_import_serde_adjacently_tagged(arg FatPtr) FatPtr
+
+func ImportSerdeAdjacentlyTagged(arg SerdeAdjacentlyTagged) SerdeAdjacentlyTagged {
+	argWriter := writer{}
+	encode_SerdeAdjacentlyTagged(&argWriter, arg)
+	resultPtr := __fp_gen_import_serde_adjacently_tagged(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_SerdeAdjacentlyTagged(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportSerdeAdjacentlyTagged()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_serde_enum
+func __fp_gen_import_serde_enum(arg FatPtr) FatPtr
+
+func ImportSerdeEnum(arg SerdeVariantRenaming) SerdeVariantRenaming {
+	argWriter := writer{}
+	encode_SerdeVariantRenaming(&argWriter, arg)
+	resultPtr := __fp_gen_import_serde_enum(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_SerdeVariantRenaming(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportSerdeEnum()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_serde_flatten
+func __fp_gen_import_serde_flatten(arg FatPtr) FatPtr
+
+func ImportSerdeFlatten(arg SerdeFlatten) SerdeFlatten {
+	argWriter := writer{}
+	encode_SerdeFlatten(&argWriter, arg)
+	resultPtr := __fp_gen_import_serde_flatten(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_SerdeFlatten(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportSerdeFlatten()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_serde_internally_tagged
+func __fp_gen_import_serde_internally_tagged(arg FatPtr) FatPtr
+
+func ImportSerdeInternallyTagged(arg SerdeInternallyTagged) SerdeInternallyTagged {
+	argWriter := writer{}
+	encode_SerdeInternallyTagged(&argWriter, arg)
+	resultPtr := __fp_gen_import_serde_internally_tagged(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_SerdeInternallyTagged(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportSerdeInternallyTagged()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_serde_struct
+func __fp_gen_import_serde_struct(arg FatPtr) FatPtr
+
+func ImportSerdeStruct(arg SerdePropertyRenaming) SerdePropertyRenaming {
+	argWriter := writer{}
+	encode_SerdePropertyRenaming(&argWriter, arg)
+	resultPtr := __fp_gen_import_serde_struct(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_SerdePropertyRenaming(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportSerdeStruct()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_serde_untagged
+func __fp_gen_import_serde_untagged(arg FatPtr) FatPtr
+
+func ImportSerdeUntagged(arg SerdeUntagged) SerdeUntagged {
+	argWriter := writer{}
+	encode_SerdeUntagged(&argWriter, arg)
+	resultPtr := __fp_gen_import_serde_untagged(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_SerdeUntagged(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportSerdeUntagged()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_string
+func __fp_gen_import_string(arg FatPtr) FatPtr
+
+func ImportString(arg string) string {
+	argWriter := writer{}
+	encode_String(&argWriter, arg)
+	resultPtr := __fp_gen_import_string(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_String(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportString()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_struct_with_options
+func __fp_gen_import_struct_with_options(arg FatPtr) FatPtr
+
+func ImportStructWithOptions(arg StructWithOptions) StructWithOptions {
+	argWriter := writer{}
+	encode_StructWithOptions(&argWriter, arg)
+	resultPtr := __fp_gen_import_struct_with_options(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_StructWithOptions(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportStructWithOptions()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_timestamp
+func __fp_gen_import_timestamp(arg FatPtr) FatPtr
+
+func ImportTimestamp(arg MyDateTime) MyDateTime {
+	argWriter := writer{}
+	encode_MyDateTime(&argWriter, arg)
+	resultPtr := __fp_gen_import_timestamp(argWriter.finish())
+	r := openReader(resultPtr)
+	result := decode_MyDateTime(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportTimestamp()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_void_function
+func __fp_gen_import_void_function()
+
+func ImportVoidFunction() {
+	__fp_gen_import_void_function()
+}
+
+//go:wasmimport fp __fp_gen_import_void_function_empty_result
+func __fp_gen_import_void_function_empty_result() FatPtr
+
+func ImportVoidFunctionEmptyResult() Result[Unit, uint32] {
+	resultPtr := __fp_gen_import_void_function_empty_result()
+	r := openReader(resultPtr)
+	result := decode_Result_Unit_u32(r)
+	if r.failed {
+		Panic("Could not decode result of `ImportVoidFunctionEmptyResult()`")
+	}
+	r.close()
+	return result
+}
+
+//go:wasmimport fp __fp_gen_import_void_function_empty_return
+func __fp_gen_import_void_function_empty_return()
+
+func ImportVoidFunctionEmptyReturn() {
+	__fp_gen_import_void_function_empty_return()
+}
+
+//go:wasmimport fp __fp_gen_log
+func __fp_gen_log(message FatPtr)
+
+// Logs a message to the (development) console.
+func Log(message string) {
+	messageWriter := writer{}
+	encode_String(&messageWriter, message)
+	__fp_gen_log(messageWriter.finish())
+}
+
+//go:wasmimport fp __fp_gen_make_http_request
+func __fp_gen_make_http_request(request FatPtr) FatPtr
+
+// Example how a runtime could expose a `Fetch`-like function to plugins.
+//
+// See `types/http.rs` for more info.
+func MakeHttpRequest(request Request, callback func(HttpResult)) {
+	requestWriter := writer{}
+	encode_Request(&requestWriter, request)
+	asyncValuePtr := __fp_gen_make_http_request(requestWriter.finish())
+	awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {
+		r := openReader(resultPtr)
+		result := decode_Result_Response_RequestError(r)
+		if r.failed {
+			Panic("Could not decode result of `MakeHttpRequest()`")
+		}
+		r.close()
+		callback(result)
+	})
+}
+
+// Exports contains the functions the plugin exports to the host. Async functions
+// receive an additional callback, through which they should pass their result
+// once it is available.
+type Exports interface {
+	ExportArrayF32(arg [3]float32) [3]float32
+	ExportArrayF64(arg [3]float64) [3]float64
+	ExportArrayI16(arg [3]int16) [3]int16
+	ExportArrayI32(arg [3]int32) [3]int32
+	ExportArrayI8(arg [3]int8) [3]int8
+	ExportArrayU16(arg [3]uint16) [3]uint16
+	ExportArrayU32(arg [3]uint32) [3]uint32
+	ExportArrayU8(arg [3]uint8) [3]uint8
+	ExportAsyncStruct(arg1 FpPropertyRenaming, arg2 uint64, resolve func(FpPropertyRenaming))
+	ExportFpAdjacentlyTagged(arg FpAdjacentlyTagged) FpAdjacentlyTagged
+	ExportFpEnum(arg FpVariantRenaming) FpVariantRenaming
+	ExportFpFlatten(arg FpFlatten) FpFlatten
+	ExportFpInternallyTagged(arg FpInternallyTagged) FpInternallyTagged
+	ExportFpStruct(arg FpPropertyRenaming) FpPropertyRenaming
+	ExportFpUntagged(arg FpUntagged) FpUntagged
+	ExportGenerics(arg StructWithGenerics[uint64]) StructWithGenerics[uint64]
+	ExportGetBytes() Result[[]byte, string]
+	ExportGetSerdeBytes() Result[[]byte, string]
+	ExportIncrementGlobalState(resolve func())
+	ExportMultiplePrimitives(arg1 int8, arg2 string) int64
+	// Panics with the given message, so the runtimes can test guest panics
+	// are reported to them.
+	ExportPanic(message string)
+	ExportPrimitiveBoolNegate(arg bool) bool
+	ExportPrimitiveBoolNegateAsync(arg bool, resolve func(bool))
+	ExportPrimitiveF32AddThree(arg float32) float32
+	ExportPrimitiveF32AddThreeAsync(arg float32, resolve func(float32))
+	ExportPrimitiveF32AddThreeWasmer2(arg float32) float32
+	ExportPrimitiveF64AddThree(arg float64) float64
+	ExportPrimitiveF64AddThreeAsync(arg float64, resolve func(float64))
+	ExportPrimitiveF64AddThreeWasmer2(arg float64) float64
+	ExportPrimitiveI16AddThree(arg int16) int16
+	ExportPrimitiveI16AddThreeAsync(arg int16, resolve func(int16))
+	ExportPrimitiveI32AddThree(arg int32) int32
+	ExportPrimitiveI32AddThreeAsync(arg int32, resolve func(int32))
+	ExportPrimitiveI64AddThree(arg int64) int64
+	ExportPrimitiveI64AddThreeAsync(arg int64, resolve func(int64))
+	ExportPrimitiveI8AddThree(arg int8) int8
+	ExportPrimitiveI8AddThreeAsync(arg int8, resolve func(int8))
+	ExportPrimitiveU16AddThree(arg uint16) uint16
+	ExportPrimitiveU16AddThreeAsync(arg uint16, resolve func(uint16))
+	ExportPrimitiveU32AddThree(arg uint32) uint32
+	ExportPrimitiveU32AddThreeAsync(arg uint32, resolve func(uint32))
+	ExportPrimitiveU64AddThree(arg uint64) uint64
+	ExportPrimitiveU64AddThreeAsync(arg uint64, resolve func(uint64))
+	ExportPrimitiveU8AddThree(arg uint8) uint8
+	ExportPrimitiveU8AddThreeAsync(arg uint8, resolve func(uint8))
+	ExportResetGlobalState(resolve func())
+	ExportSerdeAdjacentlyTagged(arg SerdeAdjacentlyTagged) SerdeAdjacentlyTagged
+	ExportSerdeEnum(arg SerdeVariantRenaming) SerdeVariantRenaming
+	ExportSerdeFlatten(arg SerdeFlatten) SerdeFlatten
+	ExportSerdeInternallyTagged(arg SerdeInternallyTagged) SerdeInternallyTagged
+	ExportSerdeStruct(arg SerdePropertyRenaming) SerdePropertyRenaming
+	ExportSerdeUntagged(arg SerdeUntagged) SerdeUntagged
+	ExportString(arg string) string
+	ExportStructWithOptions(arg StructWithOptions) StructWithOptions
+	ExportTimestamp(arg MyDateTime) MyDateTime
+	ExportVoidFunction()
+	// Example how plugin could expose async data-fetching capabilities.
+	FetchData(type_ string, resolve func(Result[string, string]))
+	// Called on the plugin to give it a chance to initialize.
+	Init()
+	// Example how plugin could expose a reducer.
+	ReducerBridge(action ReduxAction) StateUpdate
+}
+
+var exports Exports
+
+// SetExports registers the implementation of the exported functions. It should
+// be called from an init() function, since main() is not called for plugins.
+func SetExports(implementation Exports) {
+	exports = implementation
+}
+
+//go:wasmexport __fp_protocol_version
+func __fp_protocol_version() uint64 {
+	return ProtocolVersion
+}
+
+//go:wasmexport __fp_gen_export_array_f32
+func __fp_gen_export_array_f32(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_f32_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayF32()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayF32(arg)
+	w := writer{}
+	encode_Array_f32_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_array_f64
+func __fp_gen_export_array_f64(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_f64_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayF64()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayF64(arg)
+	w := writer{}
+	encode_Array_f64_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_array_i16
+func __fp_gen_export_array_i16(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_i16_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayI16()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayI16(arg)
+	w := writer{}
+	encode_Array_i16_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_array_i32
+func __fp_gen_export_array_i32(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_i32_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayI32()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayI32(arg)
+	w := writer{}
+	encode_Array_i32_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_array_i8
+func __fp_gen_export_array_i8(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_i8_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayI8()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayI8(arg)
+	w := writer{}
+	encode_Array_i8_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_array_u16
+func __fp_gen_export_array_u16(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_u16_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayU16()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayU16(arg)
+	w := writer{}
+	encode_Array_u16_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_array_u32
+func __fp_gen_export_array_u32(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_u32_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayU32()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayU32(arg)
+	w := writer{}
+	encode_Array_u32_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_array_u8
+func __fp_gen_export_array_u8(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_Array_u8_3(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportArrayU8()`")
+	}
+	argReader.close()
+	result := exports.ExportArrayU8(arg)
+	w := writer{}
+	encode_Array_u8_3(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_async_struct
+func __fp_gen_export_async_struct(arg1Ptr FatPtr, arg2 uint64) FatPtr {
+	arg1Reader := openReader(arg1Ptr)
+	arg1 := decode_FpPropertyRenaming(arg1Reader)
+	if arg1Reader.failed {
+		Panic("Could not decode argument `arg1` of `ExportAsyncStruct()`")
+	}
+	arg1Reader.close()
+	asyncValuePtr := createAsyncValue()
+	exports.ExportAsyncStruct(arg1, arg2, func(result FpPropertyRenaming) {
+		w := writer{}
+		encode_FpPropertyRenaming(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_fp_adjacently_tagged
+func __fp_gen_export_fp_adjacently_tagged(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_FpAdjacentlyTagged(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportFpAdjacentlyTagged()`")
+	}
+	argReader.close()
+	result := exports.ExportFpAdjacentlyTagged(arg)
+	w := writer{}
+	encode_FpAdjacentlyTagged(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_fp_enum
+func __fp_gen_export_fp_enum(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_FpVariantRenaming(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportFpEnum()`")
+	}
+	argReader.close()
+	result := exports.ExportFpEnum(arg)
+	w := writer{}
+	encode_FpVariantRenaming(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_fp_flatten
+func __fp_gen_export_fp_flatten(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_FpFlatten(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportFpFlatten()`")
+	}
+	argReader.close()
+	result := exports.ExportFpFlatten(arg)
+	w := writer{}
+	encode_FpFlatten(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_fp_internally_tagged
+func __fp_gen_export_fp_internally_tagged(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_FpInternallyTagged(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportFpInternallyTagged()`")
+	}
+	argReader.close()
+	result := exports.ExportFpInternallyTagged(arg)
+	w := writer{}
+	encode_FpInternallyTagged(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_fp_struct
+func __fp_gen_export_fp_struct(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_FpPropertyRenaming(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportFpStruct()`")
+	}
+	argReader.close()
+	result := exports.ExportFpStruct(arg)
+	w := writer{}
+	encode_FpPropertyRenaming(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_fp_untagged
+func __fp_gen_export_fp_untagged(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_FpUntagged(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportFpUntagged()`")
+	}
+	argReader.close()
+	result := exports.ExportFpUntagged(arg)
+	w := writer{}
+	encode_FpUntagged(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_generics
+func __fp_gen_export_generics(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_StructWithGenerics_u64(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportGenerics()`")
+	}
+	argReader.close()
+	result := exports.ExportGenerics(arg)
+	w := writer{}
+	encode_StructWithGenerics_u64(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_get_bytes
+func __fp_gen_export_get_bytes() FatPtr {
+	result := exports.ExportGetBytes()
+	w := writer{}
+	encode_Result_Bytes_String(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_get_serde_bytes
+func __fp_gen_export_get_serde_bytes() FatPtr {
+	result := exports.ExportGetSerdeBytes()
+	w := writer{}
+	encode_Result_Bytes_String(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_increment_global_state
+func __fp_gen_export_increment_global_state() FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportIncrementGlobalState(func() {
+		w := writer{}
+		w.writeNil()
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_multiple_primitives
+func __fp_gen_export_multiple_primitives(arg1 int32, arg2Ptr FatPtr) int64 {
+	arg2Reader := openReader(arg2Ptr)
+	arg2 := decode_String(arg2Reader)
+	if arg2Reader.failed {
+		Panic("Could not decode argument `arg2` of `ExportMultiplePrimitives()`")
+	}
+	arg2Reader.close()
+	return exports.ExportMultiplePrimitives(int8(arg1), arg2)
+}
+
+//go:wasmexport __fp_gen_export_panic
+func __fp_gen_export_panic(messagePtr FatPtr) {
+	messageReader := openReader(messagePtr)
+	message := decode_String(messageReader)
+	if messageReader.failed {
+		Panic("Could not decode argument `message` of `ExportPanic()`")
+	}
+	messageReader.close()
+	exports.ExportPanic(message)
+}
+
+//go:wasmexport __fp_gen_export_primitive_bool_negate
+func __fp_gen_export_primitive_bool_negate(arg uint32) uint32 {
+	return encodeBool(exports.ExportPrimitiveBoolNegate(arg != 0))
+}
+
+//go:wasmexport __fp_gen_export_primitive_bool_negate_async
+func __fp_gen_export_primitive_bool_negate_async(arg uint32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveBoolNegateAsync(arg != 0, func(result bool) {
+		w := writer{}
+		encode_bool(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_f32_add_three
+func __fp_gen_export_primitive_f32_add_three(arg float32) float32 {
+	return exports.ExportPrimitiveF32AddThree(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_f32_add_three_async
+func __fp_gen_export_primitive_f32_add_three_async(arg float32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveF32AddThreeAsync(arg, func(result float32) {
+		w := writer{}
+		encode_f32(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_f32_add_three_wasmer2
+func __fp_gen_export_primitive_f32_add_three_wasmer2(arg float32) float32 {
+	return exports.ExportPrimitiveF32AddThreeWasmer2(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_f64_add_three
+func __fp_gen_export_primitive_f64_add_three(arg float64) float64 {
+	return exports.ExportPrimitiveF64AddThree(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_f64_add_three_async
+func __fp_gen_export_primitive_f64_add_three_async(arg float64) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveF64AddThreeAsync(arg, func(result float64) {
+		w := writer{}
+		encode_f64(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_f64_add_three_wasmer2
+func __fp_gen_export_primitive_f64_add_three_wasmer2(arg float64) float64 {
+	return exports.ExportPrimitiveF64AddThreeWasmer2(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_i16_add_three
+func __fp_gen_export_primitive_i16_add_three(arg int32) int32 {
+	return int32(exports.ExportPrimitiveI16AddThree(int16(arg)))
+}
+
+//go:wasmexport __fp_gen_export_primitive_i16_add_three_async
+func __fp_gen_export_primitive_i16_add_three_async(arg int32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveI16AddThreeAsync(int16(arg), func(result int16) {
+		w := writer{}
+		encode_i16(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_i32_add_three
+func __fp_gen_export_primitive_i32_add_three(arg int32) int32 {
+	return exports.ExportPrimitiveI32AddThree(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_i32_add_three_async
+func __fp_gen_export_primitive_i32_add_three_async(arg int32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveI32AddThreeAsync(arg, func(result int32) {
+		w := writer{}
+		encode_i32(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_i64_add_three
+func __fp_gen_export_primitive_i64_add_three(arg int64) int64 {
+	return exports.ExportPrimitiveI64AddThree(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_i64_add_three_async
+func __fp_gen_export_primitive_i64_add_three_async(arg int64) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveI64AddThreeAsync(arg, func(result int64) {
+		w := writer{}
+		encode_i64(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_i8_add_three
+func __fp_gen_export_primitive_i8_add_three(arg int32) int32 {
+	return int32(exports.ExportPrimitiveI8AddThree(int8(arg)))
+}
+
+//go:wasmexport __fp_gen_export_primitive_i8_add_three_async
+func __fp_gen_export_primitive_i8_add_three_async(arg int32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveI8AddThreeAsync(int8(arg), func(result int8) {
+		w := writer{}
+		encode_i8(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_u16_add_three
+func __fp_gen_export_primitive_u16_add_three(arg uint32) uint32 {
+	return uint32(exports.ExportPrimitiveU16AddThree(uint16(arg)))
+}
+
+//go:wasmexport __fp_gen_export_primitive_u16_add_three_async
+func __fp_gen_export_primitive_u16_add_three_async(arg uint32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveU16AddThreeAsync(uint16(arg), func(result uint16) {
+		w := writer{}
+		encode_u16(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_u32_add_three
+func __fp_gen_export_primitive_u32_add_three(arg uint32) uint32 {
+	return exports.ExportPrimitiveU32AddThree(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_u32_add_three_async
+func __fp_gen_export_primitive_u32_add_three_async(arg uint32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveU32AddThreeAsync(arg, func(result uint32) {
+		w := writer{}
+		encode_u32(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_u64_add_three
+func __fp_gen_export_primitive_u64_add_three(arg uint64) uint64 {
+	return exports.ExportPrimitiveU64AddThree(arg)
+}
+
+//go:wasmexport __fp_gen_export_primitive_u64_add_three_async
+func __fp_gen_export_primitive_u64_add_three_async(arg uint64) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveU64AddThreeAsync(arg, func(result uint64) {
+		w := writer{}
+		encode_u64(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_primitive_u8_add_three
+func __fp_gen_export_primitive_u8_add_three(arg uint32) uint32 {
+	return uint32(exports.ExportPrimitiveU8AddThree(uint8(arg)))
+}
+
+//go:wasmexport __fp_gen_export_primitive_u8_add_three_async
+func __fp_gen_export_primitive_u8_add_three_async(arg uint32) FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportPrimitiveU8AddThreeAsync(uint8(arg), func(result uint8) {
+		w := writer{}
+		encode_u8(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_reset_global_state
+func __fp_gen_export_reset_global_state() FatPtr {
+	asyncValuePtr := createAsyncValue()
+	exports.ExportResetGlobalState(func() {
+		w := writer{}
+		w.writeNil()
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_export_serde_adjacently_tagged
+func __fp_gen_export_serde_adjacently_tagged(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_SerdeAdjacentlyTagged(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportSerdeAdjacentlyTagged()`")
+	}
+	argReader.close()
+	result := exports.ExportSerdeAdjacentlyTagged(arg)
+	w := writer{}
+	encode_SerdeAdjacentlyTagged(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_serde_enum
+func __fp_gen_export_serde_enum(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_SerdeVariantRenaming(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportSerdeEnum()`")
+	}
+	argReader.close()
+	result := exports.ExportSerdeEnum(arg)
+	w := writer{}
+	encode_SerdeVariantRenaming(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_serde_flatten
+func __fp_gen_export_serde_flatten(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_SerdeFlatten(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportSerdeFlatten()`")
+	}
+	argReader.close()
+	result := exports.ExportSerdeFlatten(arg)
+	w := writer{}
+	encode_SerdeFlatten(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_serde_internally_tagged
+func __fp_gen_export_serde_internally_tagged(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_SerdeInternallyTagged(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportSerdeInternallyTagged()`")
+	}
+	argReader.close()
+	result := exports.ExportSerdeInternallyTagged(arg)
+	w := writer{}
+	encode_SerdeInternallyTagged(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_serde_struct
+func __fp_gen_export_serde_struct(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_SerdePropertyRenaming(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportSerdeStruct()`")
+	}
+	argReader.close()
+	result := exports.ExportSerdeStruct(arg)
+	w := writer{}
+	encode_SerdePropertyRenaming(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_serde_untagged
+func __fp_gen_export_serde_untagged(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_SerdeUntagged(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportSerdeUntagged()`")
+	}
+	argReader.close()
+	result := exports.ExportSerdeUntagged(arg)
+	w := writer{}
+	encode_SerdeUntagged(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_string
+func __fp_gen_export_string(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_String(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportString()`")
+	}
+	argReader.close()
+	result := exports.ExportString(arg)
+	w := writer{}
+	encode_String(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_struct_with_options
+func __fp_gen_export_struct_with_options(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_StructWithOptions(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportStructWithOptions()`")
+	}
+	argReader.close()
+	result := exports.ExportStructWithOptions(arg)
+	w := writer{}
+	encode_StructWithOptions(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_timestamp
+func __fp_gen_export_timestamp(argPtr FatPtr) FatPtr {
+	argReader := openReader(argPtr)
+	arg := decode_MyDateTime(argReader)
+	if argReader.failed {
+		Panic("Could not decode argument `arg` of `ExportTimestamp()`")
+	}
+	argReader.close()
+	result := exports.ExportTimestamp(arg)
+	w := writer{}
+	encode_MyDateTime(&w, result)
+	return w.finish()
+}
+
+//go:wasmexport __fp_gen_export_void_function
+func __fp_gen_export_void_function() {
+	exports.ExportVoidFunction()
+}
+
+//go:wasmexport __fp_gen_fetch_data
+func __fp_gen_fetch_data(type_Ptr FatPtr) FatPtr {
+	type_Reader := openReader(type_Ptr)
+	type_ := decode_String(type_Reader)
+	if type_Reader.failed {
+		Panic("Could not decode argument `type` of `FetchData()`")
+	}
+	type_Reader.close()
+	asyncValuePtr := createAsyncValue()
+	exports.FetchData(type_, func(result Result[string, string]) {
+		w := writer{}
+		encode_Result_String_String(&w, result)
+		resolveAsyncValue(asyncValuePtr, w.finish())
+	})
+	return asyncValuePtr
+}
+
+//go:wasmexport __fp_gen_init
+func __fp_gen_init() {
+	exports.Init()
+}
+
+//go:wasmexport __fp_gen_reducer_bridge
+func __fp_gen_reducer_bridge(actionPtr FatPtr) FatPtr {
+	actionReader := openReader(actionPtr)
+	action := decode_ReduxAction(actionReader)
+	if actionReader.failed {
+		Panic("Could not decode argument `action` of `ReducerBridge()`")
+	}
+	actionReader.close()
+	result := exports.ReducerBridge(action)
+	w := writer{}
+	encode_StateUpdate(&w, result)
+	return w.finish()
+}
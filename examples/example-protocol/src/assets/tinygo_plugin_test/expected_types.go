@@ -0,0 +1,2114 @@
+// ============================================= //
+// Types for TinyGo plugins                      //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package bindings
+
+type Body = []byte
+
+type DocExampleEnumVariant2 struct {
+	// Variant property.
+	Inner int8
+}
+
+// # This is an enum with doc comments.
+type DocExampleEnum struct {
+	// Multi-line doc comment with complex characters
+	// & " , \ ! '
+	Variant1 *string
+	// Raw identifiers are supported too.
+	Variant2 *DocExampleEnumVariant2
+}
+
+// # This is a struct with doc comments.
+type DocExampleStruct struct {
+	// Multi-line doc comment with complex characters
+	// & " , \ ! '
+	MultiLine string
+	// Raw identifiers are supported too.
+	Type      string
+}
+
+// A point of an arbitrary type, with explicit trait bounds.
+type ExplicitBoundPoint[T any] struct {
+	Value T
+}
+
+// This struct is also not referenced by any function or data structure, but
+// it will show up because there is an explicit `use` statement for it in the
+// `fp_import!` macro.
+type ExplicitedlyImportedType struct {
+	YouWillSeeThis bool
+}
+
+type FlattenedStruct struct {
+	Foo string
+	Bar int64
+}
+
+type FloatingPoint = Point[float64]
+
+type FpAdjacentlyTaggedBaz struct {
+	A int8
+	B uint64
+}
+
+type FpAdjacentlyTagged struct {
+	Foo *struct{}
+	Bar *string
+	Baz *FpAdjacentlyTaggedBaz
+}
+
+type FpFlatten struct {
+	FlattenedStruct
+}
+
+type FpInternallyTaggedBaz struct {
+	A int8
+	B uint64
+}
+
+type FpInternallyTagged struct {
+	Foo *struct{}
+	Baz *FpInternallyTaggedBaz
+}
+
+type FpPropertyRenaming struct {
+	FooBar    string
+	QuxBaz    float64
+	RawStruct int32
+}
+
+type FpUntaggedBaz struct {
+	A int8
+	B uint64
+}
+
+type FpUntagged struct {
+	Bar *string
+	Baz *FpUntaggedBaz
+}
+
+type FpVariantRenamingQuxBaz struct {
+	// Will be renamed to "FOO_BAR" because of the `rename_all` on the
+	// variant.
+	FooBar string
+	QuxBaz float64
+}
+
+type FpVariantRenaming struct {
+	FooBar *struct{}
+	QuxBaz *FpVariantRenamingQuxBaz
+}
+
+type GroupImportedType1 struct {
+	YouWillSeeThis bool
+}
+
+type GroupImportedType2 struct {
+	YouWillSeeThis bool
+}
+
+type HttpResult = Result[Response, RequestError]
+
+type Int64 = uint64
+
+// Our struct for passing date time instances.
+//
+// We wrap the `OffsetDateTime` type in a new struct so that the Serde
+// attributes can be inserted. These are necessary to enable RFC3339
+// formatting. Without a wrapper type like this, we would not be able to pass
+// date time instances directly to function arguments and we might run into
+// trouble embedding them into certain generic types.
+type MyDateTime string
+
+// A point of an arbitrary type.
+type Point[T any] struct {
+	Value T
+}
+
+type ReduxActionUpdateTitle struct {
+	Title string
+}
+
+// Example for representing Redux actions.
+type ReduxAction struct {
+	ClearTitle  *struct{}
+	UpdateTitle *ReduxActionUpdateTitle
+}
+
+// Represents an HTTP request to be sent.
+type Request struct {
+	// The URI to submit the request to.
+	Url     string
+	// HTTP method to use for the request.
+	Method  RawValue
+	// HTTP headers to submit with the request.
+	Headers RawValue
+	// The body to submit with the request.
+	Body    *Body
+}
+
+type RequestErrorServerError struct {
+	// HTTP status code.
+	StatusCode uint16
+	// Response body.
+	Response   Body
+}
+
+type RequestErrorOther struct {
+	Reason string
+}
+
+// Represents an error that occurred while attempting to submit the request.
+type RequestError struct {
+	// Used when we know we don't have an active network connection.
+	Offline           *struct{}
+	NoRoute           *struct{}
+	ConnectionRefused *struct{}
+	Timeout           *struct{}
+	ServerError       *RequestErrorServerError
+	// Misc.
+	Other             *RequestErrorOther
+}
+
+// Represents an HTTP response we received.
+//
+// Please note we currently do not support streaming responses.
+type Response struct {
+	// The response body. May be empty.
+	Body       Body
+	// HTTP headers that were part of the response.
+	Headers    RawValue
+	// HTTP status code.
+	StatusCode uint16
+}
+
+// A result that can be either successful (`Ok`) or represent an error (`Err`).
+type Result[T any, E any] struct {
+	// Represents a successful result.
+	Ok  *T
+	// Represents an error.
+	Err *E
+}
+
+type SerdeAdjacentlyTaggedBaz struct {
+	A int8
+	B uint64
+}
+
+type SerdeAdjacentlyTagged struct {
+	Foo *struct{}
+	Bar *string
+	Baz *SerdeAdjacentlyTaggedBaz
+}
+
+type SerdeFlatten struct {
+	FlattenedStruct
+}
+
+type SerdeInternallyTaggedBaz struct {
+	A int8
+	B uint64
+}
+
+type SerdeInternallyTagged struct {
+	Foo *struct{}
+	Baz *SerdeInternallyTaggedBaz
+}
+
+type SerdePropertyRenaming struct {
+	FooBar    string
+	QuxBaz    float64
+	RawStruct int32
+}
+
+type SerdeUntaggedBaz struct {
+	A int8
+	B uint64
+}
+
+type SerdeUntagged struct {
+	Bar *string
+	Baz *SerdeUntaggedBaz
+}
+
+type SerdeVariantRenamingQuxBaz struct {
+	// Will be renamed to "FooBar" because of the `rename_all` on the
+	// variant.
+	FooBar string
+	QuxBaz float64
+}
+
+type SerdeVariantRenaming struct {
+	FooBar *struct{}
+	QuxBaz *SerdeVariantRenamingQuxBaz
+}
+
+// A state update to communicate to the Redux host.
+//
+// Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+// changed.
+type StateUpdate struct {
+	Title    *string
+	Revision *uint16
+}
+
+type StructWithGenerics[T any] struct {
+	List              []T
+	Points            []Point[T]
+	Recursive         []Point[Point[T]]
+	ComplexNested     *map[string][]FloatingPoint
+	OptionalTimestamp *MyDateTime
+}
+
+type StructWithOptions struct {
+	FilledString                   string
+	EmptyString                    string
+	FilledOptionString             *string
+	EmptyOptionString              *string
+	NeverSkippedFilledOptionString *string
+	NeverSkippedEmptyOptionString  *string
+}
+
+func encode_DocExampleEnum_Variant2(w *writer, value DocExampleEnumVariant2) {
+	w.writeMap(1)
+	w.writeString("inner")
+	encode_i8(w, value.Inner)
+}
+
+func decode_DocExampleEnum_Variant2(r *reader) DocExampleEnumVariant2 {
+	var value DocExampleEnumVariant2
+	var hasInner bool
+	fields := r.readStruct([]string{"inner"})
+	for fields.next() {
+		switch fields.key {
+		case "inner":
+			value.Inner = decode_i8(r)
+			hasInner = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasInner {
+		r.fail()
+	}
+	return value
+}
+
+func encode_DocExampleEnum(w *writer, value DocExampleEnum) {
+	switch {
+	case value.Variant1 != nil:
+		w.writeMap(1)
+		w.writeString("Variant1")
+		encode_String(w, *value.Variant1)
+	case value.Variant2 != nil:
+		w.writeMap(1)
+		w.writeString("Variant2")
+		encode_DocExampleEnum_Variant2(w, *value.Variant2)
+	default:
+		Panic("No variant set for enum DocExampleEnum")
+	}
+}
+
+func decode_DocExampleEnum(r *reader) DocExampleEnum {
+	var value DocExampleEnum
+	name, content := r.readVariant()
+	switch name {
+	case "Variant1":
+		payload := decode_String(content)
+		value.Variant1 = &payload
+		if content.failed {
+			r.fail()
+		}
+	case "Variant2":
+		payload := decode_DocExampleEnum_Variant2(content)
+		value.Variant2 = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_DocExampleStruct(w *writer, value DocExampleStruct) {
+	w.writeMap(2)
+	w.writeString("multi_line")
+	encode_String(w, value.MultiLine)
+	w.writeString("type")
+	encode_String(w, value.Type)
+}
+
+func decode_DocExampleStruct(r *reader) DocExampleStruct {
+	var value DocExampleStruct
+	var hasMultiLine, hasType bool
+	fields := r.readStruct([]string{"multi_line", "type"})
+	for fields.next() {
+		switch fields.key {
+		case "multi_line":
+			value.MultiLine = decode_String(r)
+			hasMultiLine = true
+		case "type":
+			value.Type = decode_String(r)
+			hasType = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasMultiLine || !hasType {
+		r.fail()
+	}
+	return value
+}
+
+func encode_ExplicitedlyImportedType(w *writer, value ExplicitedlyImportedType) {
+	w.writeMap(1)
+	w.writeString("you_will_see_this")
+	encode_bool(w, value.YouWillSeeThis)
+}
+
+func decode_ExplicitedlyImportedType(r *reader) ExplicitedlyImportedType {
+	var value ExplicitedlyImportedType
+	var hasYouWillSeeThis bool
+	fields := r.readStruct([]string{"you_will_see_this"})
+	for fields.next() {
+		switch fields.key {
+		case "you_will_see_this":
+			value.YouWillSeeThis = decode_bool(r)
+			hasYouWillSeeThis = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasYouWillSeeThis {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FlattenedStruct(w *writer, value FlattenedStruct) {
+	w.writeMap(2)
+	w.writeString("foo")
+	encode_String(w, value.Foo)
+	w.writeString("bar")
+	encode_i64(w, value.Bar)
+}
+
+func decode_FlattenedStruct(r *reader) FlattenedStruct {
+	var value FlattenedStruct
+	var hasFoo, hasBar bool
+	fields := r.readStruct([]string{"foo", "bar"})
+	for fields.next() {
+		switch fields.key {
+		case "foo":
+			value.Foo = decode_String(r)
+			hasFoo = true
+		case "bar":
+			value.Bar = decode_i64(r)
+			hasBar = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasFoo || !hasBar {
+		r.fail()
+	}
+	return value
+}
+
+func encode_Point_f64(w *writer, value Point[float64]) {
+	w.writeMap(1)
+	w.writeString("value")
+	encode_f64(w, value.Value)
+}
+
+func decode_Point_f64(r *reader) Point[float64] {
+	var value Point[float64]
+	var hasValue bool
+	fields := r.readStruct([]string{"value"})
+	for fields.next() {
+		switch fields.key {
+		case "value":
+			value.Value = decode_f64(r)
+			hasValue = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasValue {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpAdjacentlyTagged_Baz(w *writer, value FpAdjacentlyTaggedBaz) {
+	w.writeMap(2)
+	w.writeString("a")
+	encode_i8(w, value.A)
+	w.writeString("b")
+	encode_u64(w, value.B)
+}
+
+func decode_FpAdjacentlyTagged_Baz(r *reader) FpAdjacentlyTaggedBaz {
+	var value FpAdjacentlyTaggedBaz
+	var hasA, hasB bool
+	fields := r.readStruct([]string{"a", "b"})
+	for fields.next() {
+		switch fields.key {
+		case "a":
+			value.A = decode_i8(r)
+			hasA = true
+		case "b":
+			value.B = decode_u64(r)
+			hasB = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasA || !hasB {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpAdjacentlyTagged(w *writer, value FpAdjacentlyTagged) {
+	switch {
+	case value.Foo != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("Foo")
+	case value.Bar != nil:
+		w.writeMap(2)
+		w.writeString("type")
+		w.writeString("Bar")
+		w.writeString("payload")
+		encode_String(w, *value.Bar)
+	case value.Baz != nil:
+		w.writeMap(2)
+		w.writeString("type")
+		w.writeString("Baz")
+		w.writeString("payload")
+		encode_FpAdjacentlyTagged_Baz(w, *value.Baz)
+	default:
+		Panic("No variant set for enum FpAdjacentlyTagged")
+	}
+}
+
+func decode_FpAdjacentlyTagged(r *reader) FpAdjacentlyTagged {
+	var value FpAdjacentlyTagged
+	name, content := r.readAdjacent("type", "payload")
+	switch name {
+	case "Foo":
+		value.Foo = &struct{}{}
+	case "Bar":
+		payload := decode_String(content)
+		value.Bar = &payload
+		if content.failed {
+			r.fail()
+		}
+	case "Baz":
+		payload := decode_FpAdjacentlyTagged_Baz(content)
+		value.Baz = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpFlatten(w *writer, value FpFlatten) {
+	w.writeMap(2)
+	w.writeString("foo")
+	encode_String(w, value.FlattenedStruct.Foo)
+	w.writeString("bar")
+	encode_i64(w, value.FlattenedStruct.Bar)
+}
+
+func decode_FpFlatten(r *reader) FpFlatten {
+	var value FpFlatten
+	var hasFlattenedStructFoo, hasFlattenedStructBar bool
+	fields := r.readStruct(nil)
+	for fields.next() {
+		switch fields.key {
+		case "foo":
+			value.FlattenedStruct.Foo = decode_String(r)
+			hasFlattenedStructFoo = true
+		case "bar":
+			value.FlattenedStruct.Bar = decode_i64(r)
+			hasFlattenedStructBar = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasFlattenedStructFoo || !hasFlattenedStructBar {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpInternallyTagged_Baz(w *writer, value FpInternallyTaggedBaz) {
+	w.writeMap(3)
+	w.writeString("type")
+	w.writeString("Baz")
+	w.writeString("a")
+	encode_i8(w, value.A)
+	w.writeString("b")
+	encode_u64(w, value.B)
+}
+
+func decode_FpInternallyTagged_Baz(r *reader) FpInternallyTaggedBaz {
+	var value FpInternallyTaggedBaz
+	var hasA, hasB bool
+	fields := r.readStruct([]string{"type", "a", "b"})
+	for fields.next() {
+		switch fields.key {
+		case "a":
+			value.A = decode_i8(r)
+			hasA = true
+		case "b":
+			value.B = decode_u64(r)
+			hasB = true
+		case "type":
+			r.skip()
+		default:
+			fields.skip()
+		}
+	}
+	if !hasA || !hasB {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpInternallyTagged(w *writer, value FpInternallyTagged) {
+	switch {
+	case value.Foo != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("Foo")
+	case value.Baz != nil:
+		encode_FpInternallyTagged_Baz(w, *value.Baz)
+	default:
+		Panic("No variant set for enum FpInternallyTagged")
+	}
+}
+
+func decode_FpInternallyTagged(r *reader) FpInternallyTagged {
+	var value FpInternallyTagged
+	switch r.readTag("type") {
+	case "Foo":
+		r.skip()
+		value.Foo = &struct{}{}
+	case "Baz":
+		payload := decode_FpInternallyTagged_Baz(r)
+		value.Baz = &payload
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpPropertyRenaming(w *writer, value FpPropertyRenaming) {
+	w.writeMap(3)
+	w.writeString("fooBar")
+	encode_String(w, value.FooBar)
+	w.writeString("QUX_BAZ")
+	encode_f64(w, value.QuxBaz)
+	w.writeString("rawStruct")
+	encode_i32(w, value.RawStruct)
+}
+
+func decode_FpPropertyRenaming(r *reader) FpPropertyRenaming {
+	var value FpPropertyRenaming
+	var hasFooBar, hasQuxBaz, hasRawStruct bool
+	fields := r.readStruct([]string{"fooBar", "QUX_BAZ", "rawStruct"})
+	for fields.next() {
+		switch fields.key {
+		case "fooBar":
+			value.FooBar = decode_String(r)
+			hasFooBar = true
+		case "QUX_BAZ":
+			value.QuxBaz = decode_f64(r)
+			hasQuxBaz = true
+		case "rawStruct":
+			value.RawStruct = decode_i32(r)
+			hasRawStruct = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasFooBar || !hasQuxBaz || !hasRawStruct {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpUntagged_Baz(w *writer, value FpUntaggedBaz) {
+	w.writeMap(2)
+	w.writeString("a")
+	encode_i8(w, value.A)
+	w.writeString("b")
+	encode_u64(w, value.B)
+}
+
+func decode_FpUntagged_Baz(r *reader) FpUntaggedBaz {
+	var value FpUntaggedBaz
+	var hasA, hasB bool
+	fields := r.readStruct([]string{"a", "b"})
+	for fields.next() {
+		switch fields.key {
+		case "a":
+			value.A = decode_i8(r)
+			hasA = true
+		case "b":
+			value.B = decode_u64(r)
+			hasB = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasA || !hasB {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpUntagged(w *writer, value FpUntagged) {
+	switch {
+	case value.Bar != nil:
+		encode_String(w, *value.Bar)
+	case value.Baz != nil:
+		encode_FpUntagged_Baz(w, *value.Baz)
+	default:
+		Panic("No variant set for enum FpUntagged")
+	}
+}
+
+func decode_FpUntagged(r *reader) FpUntagged {
+	var value FpUntagged
+	attempt := r.attempt()
+	if payload := decode_String(attempt); !attempt.failed {
+		r.commit(attempt)
+		value.Bar = &payload
+		return value
+	}
+	attempt = r.attempt()
+	if payload := decode_FpUntagged_Baz(attempt); !attempt.failed {
+		r.commit(attempt)
+		value.Baz = &payload
+		return value
+	}
+	r.fail()
+	return value
+}
+
+func encode_FpVariantRenaming_QuxBaz(w *writer, value FpVariantRenamingQuxBaz) {
+	w.writeMap(2)
+	w.writeString("FOO_BAR")
+	encode_String(w, value.FooBar)
+	w.writeString("qux_baz")
+	encode_f64(w, value.QuxBaz)
+}
+
+func decode_FpVariantRenaming_QuxBaz(r *reader) FpVariantRenamingQuxBaz {
+	var value FpVariantRenamingQuxBaz
+	var hasFooBar, hasQuxBaz bool
+	fields := r.readStruct([]string{"FOO_BAR", "qux_baz"})
+	for fields.next() {
+		switch fields.key {
+		case "FOO_BAR":
+			value.FooBar = decode_String(r)
+			hasFooBar = true
+		case "qux_baz":
+			value.QuxBaz = decode_f64(r)
+			hasQuxBaz = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasFooBar || !hasQuxBaz {
+		r.fail()
+	}
+	return value
+}
+
+func encode_FpVariantRenaming(w *writer, value FpVariantRenaming) {
+	switch {
+	case value.FooBar != nil:
+		w.writeString("foo_bar")
+	case value.QuxBaz != nil:
+		w.writeMap(1)
+		w.writeString("QUX_BAZ")
+		encode_FpVariantRenaming_QuxBaz(w, *value.QuxBaz)
+	default:
+		Panic("No variant set for enum FpVariantRenaming")
+	}
+}
+
+func decode_FpVariantRenaming(r *reader) FpVariantRenaming {
+	var value FpVariantRenaming
+	name, content := r.readVariant()
+	switch name {
+	case "foo_bar":
+		value.FooBar = &struct{}{}
+	case "QUX_BAZ":
+		payload := decode_FpVariantRenaming_QuxBaz(content)
+		value.QuxBaz = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_GroupImportedType1(w *writer, value GroupImportedType1) {
+	w.writeMap(1)
+	w.writeString("you_will_see_this")
+	encode_bool(w, value.YouWillSeeThis)
+}
+
+func decode_GroupImportedType1(r *reader) GroupImportedType1 {
+	var value GroupImportedType1
+	var hasYouWillSeeThis bool
+	fields := r.readStruct([]string{"you_will_see_this"})
+	for fields.next() {
+		switch fields.key {
+		case "you_will_see_this":
+			value.YouWillSeeThis = decode_bool(r)
+			hasYouWillSeeThis = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasYouWillSeeThis {
+		r.fail()
+	}
+	return value
+}
+
+func encode_GroupImportedType2(w *writer, value GroupImportedType2) {
+	w.writeMap(1)
+	w.writeString("you_will_see_this")
+	encode_bool(w, value.YouWillSeeThis)
+}
+
+func decode_GroupImportedType2(r *reader) GroupImportedType2 {
+	var value GroupImportedType2
+	var hasYouWillSeeThis bool
+	fields := r.readStruct([]string{"you_will_see_this"})
+	for fields.next() {
+		switch fields.key {
+		case "you_will_see_this":
+			value.YouWillSeeThis = decode_bool(r)
+			hasYouWillSeeThis = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasYouWillSeeThis {
+		r.fail()
+	}
+	return value
+}
+
+func encode_Response(w *writer, value Response) {
+	w.writeMap(3)
+	w.writeString("body")
+	encode_Bytes(w, value.Body)
+	w.writeString("headers")
+	encode_RawValue(w, value.Headers)
+	w.writeString("status_code")
+	encode_u16(w, value.StatusCode)
+}
+
+func decode_Response(r *reader) Response {
+	var value Response
+	var hasBody, hasHeaders, hasStatusCode bool
+	fields := r.readStruct([]string{"body", "headers", "status_code"})
+	for fields.next() {
+		switch fields.key {
+		case "body":
+			value.Body = decode_Bytes(r)
+			hasBody = true
+		case "headers":
+			value.Headers = decode_RawValue(r)
+			hasHeaders = true
+		case "status_code":
+			value.StatusCode = decode_u16(r)
+			hasStatusCode = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasBody || !hasHeaders || !hasStatusCode {
+		r.fail()
+	}
+	return value
+}
+
+func encode_RequestError_ServerError(w *writer, value RequestErrorServerError) {
+	w.writeMap(3)
+	w.writeString("type")
+	w.writeString("server_error")
+	w.writeString("status_code")
+	encode_u16(w, value.StatusCode)
+	w.writeString("response")
+	encode_Bytes(w, value.Response)
+}
+
+func decode_RequestError_ServerError(r *reader) RequestErrorServerError {
+	var value RequestErrorServerError
+	var hasStatusCode, hasResponse bool
+	fields := r.readStruct([]string{"type", "status_code", "response"})
+	for fields.next() {
+		switch fields.key {
+		case "status_code":
+			value.StatusCode = decode_u16(r)
+			hasStatusCode = true
+		case "response":
+			value.Response = decode_Bytes(r)
+			hasResponse = true
+		case "type":
+			r.skip()
+		default:
+			fields.skip()
+		}
+	}
+	if !hasStatusCode || !hasResponse {
+		r.fail()
+	}
+	return value
+}
+
+func encode_RequestError_Other(w *writer, value RequestErrorOther) {
+	w.writeMap(2)
+	w.writeString("type")
+	w.writeString("other/misc")
+	w.writeString("reason")
+	encode_String(w, value.Reason)
+}
+
+func decode_RequestError_Other(r *reader) RequestErrorOther {
+	var value RequestErrorOther
+	var hasReason bool
+	fields := r.readStruct([]string{"type", "reason"})
+	for fields.next() {
+		switch fields.key {
+		case "reason":
+			value.Reason = decode_String(r)
+			hasReason = true
+		case "type":
+			r.skip()
+		default:
+			fields.skip()
+		}
+	}
+	if !hasReason {
+		r.fail()
+	}
+	return value
+}
+
+func encode_RequestError(w *writer, value RequestError) {
+	switch {
+	case value.Offline != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("offline")
+	case value.NoRoute != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("no_route")
+	case value.ConnectionRefused != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("connection_refused")
+	case value.Timeout != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("timeout")
+	case value.ServerError != nil:
+		encode_RequestError_ServerError(w, *value.ServerError)
+	case value.Other != nil:
+		encode_RequestError_Other(w, *value.Other)
+	default:
+		Panic("No variant set for enum RequestError")
+	}
+}
+
+func decode_RequestError(r *reader) RequestError {
+	var value RequestError
+	switch r.readTag("type") {
+	case "offline":
+		r.skip()
+		value.Offline = &struct{}{}
+	case "no_route":
+		r.skip()
+		value.NoRoute = &struct{}{}
+	case "connection_refused":
+		r.skip()
+		value.ConnectionRefused = &struct{}{}
+	case "timeout":
+		r.skip()
+		value.Timeout = &struct{}{}
+	case "server_error":
+		payload := decode_RequestError_ServerError(r)
+		value.ServerError = &payload
+	case "other/misc":
+		payload := decode_RequestError_Other(r)
+		value.Other = &payload
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_Result_Response_RequestError(w *writer, value Result[Response, RequestError]) {
+	switch {
+	case value.Ok != nil:
+		w.writeMap(1)
+		w.writeString("Ok")
+		encode_Response(w, *value.Ok)
+	case value.Err != nil:
+		w.writeMap(1)
+		w.writeString("Err")
+		encode_RequestError(w, *value.Err)
+	default:
+		Panic("No variant set for enum Result")
+	}
+}
+
+func decode_Result_Response_RequestError(r *reader) Result[Response, RequestError] {
+	var value Result[Response, RequestError]
+	name, content := r.readVariant()
+	switch name {
+	case "Ok":
+		payload := decode_Response(content)
+		value.Ok = &payload
+		if content.failed {
+			r.fail()
+		}
+	case "Err":
+		payload := decode_RequestError(content)
+		value.Err = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_MyDateTime(w *writer, value MyDateTime) {
+	encode_String(w, string(value))
+}
+
+func decode_MyDateTime(r *reader) MyDateTime {
+	return MyDateTime(decode_String(r))
+}
+
+func encode_ReduxAction_UpdateTitle(w *writer, value ReduxActionUpdateTitle) {
+	w.writeMap(1)
+	w.writeString("title")
+	encode_String(w, value.Title)
+}
+
+func decode_ReduxAction_UpdateTitle(r *reader) ReduxActionUpdateTitle {
+	var value ReduxActionUpdateTitle
+	var hasTitle bool
+	fields := r.readStruct([]string{"title"})
+	for fields.next() {
+		switch fields.key {
+		case "title":
+			value.Title = decode_String(r)
+			hasTitle = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasTitle {
+		r.fail()
+	}
+	return value
+}
+
+func encode_ReduxAction(w *writer, value ReduxAction) {
+	switch {
+	case value.ClearTitle != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("clear_title")
+	case value.UpdateTitle != nil:
+		w.writeMap(2)
+		w.writeString("type")
+		w.writeString("update_title")
+		w.writeString("payload")
+		encode_ReduxAction_UpdateTitle(w, *value.UpdateTitle)
+	default:
+		Panic("No variant set for enum ReduxAction")
+	}
+}
+
+func decode_ReduxAction(r *reader) ReduxAction {
+	var value ReduxAction
+	name, content := r.readAdjacent("type", "payload")
+	switch name {
+	case "clear_title":
+		value.ClearTitle = &struct{}{}
+	case "update_title":
+		payload := decode_ReduxAction_UpdateTitle(content)
+		value.UpdateTitle = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_Option_Body(w *writer, value *Body) {
+	if value != nil {
+		encode_Bytes(w, *value)
+	} else {
+		w.writeNil()
+	}
+}
+
+func decode_Option_Body(r *reader) *Body {
+	if r.readNil() {
+		return nil
+	}
+	value := decode_Bytes(r)
+	return &value
+}
+
+func encode_Request(w *writer, value Request) {
+	count := 3
+	if value.Body != nil {
+		count++
+	}
+	w.writeMap(count)
+	w.writeString("url")
+	encode_String(w, value.Url)
+	w.writeString("method")
+	encode_RawValue(w, value.Method)
+	w.writeString("headers")
+	encode_RawValue(w, value.Headers)
+	if value.Body != nil {
+		w.writeString("body")
+		encode_Option_Body(w, value.Body)
+	}
+}
+
+func decode_Request(r *reader) Request {
+	var value Request
+	var hasUrl, hasMethod, hasHeaders bool
+	fields := r.readStruct([]string{"url", "method", "headers", "body"})
+	for fields.next() {
+		switch fields.key {
+		case "url":
+			value.Url = decode_String(r)
+			hasUrl = true
+		case "method":
+			value.Method = decode_RawValue(r)
+			hasMethod = true
+		case "headers":
+			value.Headers = decode_RawValue(r)
+			hasHeaders = true
+		case "body":
+			value.Body = decode_Option_Body(r)
+		default:
+			fields.skip()
+		}
+	}
+	if !hasUrl || !hasMethod || !hasHeaders {
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeAdjacentlyTagged_Baz(w *writer, value SerdeAdjacentlyTaggedBaz) {
+	w.writeMap(2)
+	w.writeString("a")
+	encode_i8(w, value.A)
+	w.writeString("b")
+	encode_u64(w, value.B)
+}
+
+func decode_SerdeAdjacentlyTagged_Baz(r *reader) SerdeAdjacentlyTaggedBaz {
+	var value SerdeAdjacentlyTaggedBaz
+	var hasA, hasB bool
+	fields := r.readStruct([]string{"a", "b"})
+	for fields.next() {
+		switch fields.key {
+		case "a":
+			value.A = decode_i8(r)
+			hasA = true
+		case "b":
+			value.B = decode_u64(r)
+			hasB = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasA || !hasB {
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeAdjacentlyTagged(w *writer, value SerdeAdjacentlyTagged) {
+	switch {
+	case value.Foo != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("Foo")
+	case value.Bar != nil:
+		w.writeMap(2)
+		w.writeString("type")
+		w.writeString("Bar")
+		w.writeString("payload")
+		encode_String(w, *value.Bar)
+	case value.Baz != nil:
+		w.writeMap(2)
+		w.writeString("type")
+		w.writeString("Baz")
+		w.writeString("payload")
+		encode_SerdeAdjacentlyTagged_Baz(w, *value.Baz)
+	default:
+		Panic("No variant set for enum SerdeAdjacentlyTagged")
+	}
+}
+
+func decode_SerdeAdjacentlyTagged(r *reader) SerdeAdjacentlyTagged {
+	var value SerdeAdjacentlyTagged
+	name, content := r.readAdjacent("type", "payload")
+	switch name {
+	case "Foo":
+		value.Foo = &struct{}{}
+	case "Bar":
+		payload := decode_String(content)
+		value.Bar = &payload
+		if content.failed {
+			r.fail()
+		}
+	case "Baz":
+		payload := decode_SerdeAdjacentlyTagged_Baz(content)
+		value.Baz = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeFlatten(w *writer, value SerdeFlatten) {
+	w.writeMap(2)
+	w.writeString("foo")
+	encode_String(w, value.FlattenedStruct.Foo)
+	w.writeString("bar")
+	encode_i64(w, value.FlattenedStruct.Bar)
+}
+
+func decode_SerdeFlatten(r *reader) SerdeFlatten {
+	var value SerdeFlatten
+	var hasFlattenedStructFoo, hasFlattenedStructBar bool
+	fields := r.readStruct(nil)
+	for fields.next() {
+		switch fields.key {
+		case "foo":
+			value.FlattenedStruct.Foo = decode_String(r)
+			hasFlattenedStructFoo = true
+		case "bar":
+			value.FlattenedStruct.Bar = decode_i64(r)
+			hasFlattenedStructBar = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasFlattenedStructFoo || !hasFlattenedStructBar {
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeInternallyTagged_Baz(w *writer, value SerdeInternallyTaggedBaz) {
+	w.writeMap(3)
+	w.writeString("type")
+	w.writeString("Baz")
+	w.writeString("a")
+	encode_i8(w, value.A)
+	w.writeString("b")
+	encode_u64(w, value.B)
+}
+
+func decode_SerdeInternallyTagged_Baz(r *reader) SerdeInternallyTaggedBaz {
+	var value SerdeInternallyTaggedBaz
+	var hasA, hasB bool
+	fields := r.readStruct([]string{"type", "a", "b"})
+	for fields.next() {
+		switch fields.key {
+		case "a":
+			value.A = decode_i8(r)
+			hasA = true
+		case "b":
+			value.B = decode_u64(r)
+			hasB = true
+		case "type":
+			r.skip()
+		default:
+			fields.skip()
+		}
+	}
+	if !hasA || !hasB {
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeInternallyTagged(w *writer, value SerdeInternallyTagged) {
+	switch {
+	case value.Foo != nil:
+		w.writeMap(1)
+		w.writeString("type")
+		w.writeString("Foo")
+	case value.Baz != nil:
+		encode_SerdeInternallyTagged_Baz(w, *value.Baz)
+	default:
+		Panic("No variant set for enum SerdeInternallyTagged")
+	}
+}
+
+func decode_SerdeInternallyTagged(r *reader) SerdeInternallyTagged {
+	var value SerdeInternallyTagged
+	switch r.readTag("type") {
+	case "Foo":
+		r.skip()
+		value.Foo = &struct{}{}
+	case "Baz":
+		payload := decode_SerdeInternallyTagged_Baz(r)
+		value.Baz = &payload
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdePropertyRenaming(w *writer, value SerdePropertyRenaming) {
+	w.writeMap(3)
+	w.writeString("fooBar")
+	encode_String(w, value.FooBar)
+	w.writeString("QUX_BAZ")
+	encode_f64(w, value.QuxBaz)
+	w.writeString("rawStruct")
+	encode_i32(w, value.RawStruct)
+}
+
+func decode_SerdePropertyRenaming(r *reader) SerdePropertyRenaming {
+	var value SerdePropertyRenaming
+	var hasFooBar, hasQuxBaz, hasRawStruct bool
+	fields := r.readStruct([]string{"fooBar", "QUX_BAZ", "rawStruct"})
+	for fields.next() {
+		switch fields.key {
+		case "fooBar":
+			value.FooBar = decode_String(r)
+			hasFooBar = true
+		case "QUX_BAZ":
+			value.QuxBaz = decode_f64(r)
+			hasQuxBaz = true
+		case "rawStruct":
+			value.RawStruct = decode_i32(r)
+			hasRawStruct = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasFooBar || !hasQuxBaz || !hasRawStruct {
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeUntagged_Baz(w *writer, value SerdeUntaggedBaz) {
+	w.writeMap(2)
+	w.writeString("a")
+	encode_i8(w, value.A)
+	w.writeString("b")
+	encode_u64(w, value.B)
+}
+
+func decode_SerdeUntagged_Baz(r *reader) SerdeUntaggedBaz {
+	var value SerdeUntaggedBaz
+	var hasA, hasB bool
+	fields := r.readStruct([]string{"a", "b"})
+	for fields.next() {
+		switch fields.key {
+		case "a":
+			value.A = decode_i8(r)
+			hasA = true
+		case "b":
+			value.B = decode_u64(r)
+			hasB = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasA || !hasB {
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeUntagged(w *writer, value SerdeUntagged) {
+	switch {
+	case value.Bar != nil:
+		encode_String(w, *value.Bar)
+	case value.Baz != nil:
+		encode_SerdeUntagged_Baz(w, *value.Baz)
+	default:
+		Panic("No variant set for enum SerdeUntagged")
+	}
+}
+
+func decode_SerdeUntagged(r *reader) SerdeUntagged {
+	var value SerdeUntagged
+	attempt := r.attempt()
+	if payload := decode_String(attempt); !attempt.failed {
+		r.commit(attempt)
+		value.Bar = &payload
+		return value
+	}
+	attempt = r.attempt()
+	if payload := decode_SerdeUntagged_Baz(attempt); !attempt.failed {
+		r.commit(attempt)
+		value.Baz = &payload
+		return value
+	}
+	r.fail()
+	return value
+}
+
+func encode_SerdeVariantRenaming_QuxBaz(w *writer, value SerdeVariantRenamingQuxBaz) {
+	w.writeMap(2)
+	w.writeString("FooBar")
+	encode_String(w, value.FooBar)
+	w.writeString("qux_baz")
+	encode_f64(w, value.QuxBaz)
+}
+
+func decode_SerdeVariantRenaming_QuxBaz(r *reader) SerdeVariantRenamingQuxBaz {
+	var value SerdeVariantRenamingQuxBaz
+	var hasFooBar, hasQuxBaz bool
+	fields := r.readStruct([]string{"FooBar", "qux_baz"})
+	for fields.next() {
+		switch fields.key {
+		case "FooBar":
+			value.FooBar = decode_String(r)
+			hasFooBar = true
+		case "qux_baz":
+			value.QuxBaz = decode_f64(r)
+			hasQuxBaz = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasFooBar || !hasQuxBaz {
+		r.fail()
+	}
+	return value
+}
+
+func encode_SerdeVariantRenaming(w *writer, value SerdeVariantRenaming) {
+	switch {
+	case value.FooBar != nil:
+		w.writeString("foo_bar")
+	case value.QuxBaz != nil:
+		w.writeMap(1)
+		w.writeString("QUX_BAZ")
+		encode_SerdeVariantRenaming_QuxBaz(w, *value.QuxBaz)
+	default:
+		Panic("No variant set for enum SerdeVariantRenaming")
+	}
+}
+
+func decode_SerdeVariantRenaming(r *reader) SerdeVariantRenaming {
+	var value SerdeVariantRenaming
+	name, content := r.readVariant()
+	switch name {
+	case "foo_bar":
+		value.FooBar = &struct{}{}
+	case "QUX_BAZ":
+		payload := decode_SerdeVariantRenaming_QuxBaz(content)
+		value.QuxBaz = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_Option_String(w *writer, value *string) {
+	if value != nil {
+		encode_String(w, *value)
+	} else {
+		w.writeNil()
+	}
+}
+
+func decode_Option_String(r *reader) *string {
+	if r.readNil() {
+		return nil
+	}
+	value := decode_String(r)
+	return &value
+}
+
+func encode_Option_u16(w *writer, value *uint16) {
+	if value != nil {
+		encode_u16(w, *value)
+	} else {
+		w.writeNil()
+	}
+}
+
+func decode_Option_u16(r *reader) *uint16 {
+	if r.readNil() {
+		return nil
+	}
+	value := decode_u16(r)
+	return &value
+}
+
+func encode_StateUpdate(w *writer, value StateUpdate) {
+	w.writeMap(2)
+	w.writeString("title")
+	encode_Option_String(w, value.Title)
+	w.writeString("revision")
+	encode_Option_u16(w, value.Revision)
+}
+
+func decode_StateUpdate(r *reader) StateUpdate {
+	var value StateUpdate
+	fields := r.readStruct([]string{"title", "revision"})
+	for fields.next() {
+		switch fields.key {
+		case "title":
+			value.Title = decode_Option_String(r)
+		case "revision":
+			value.Revision = decode_Option_u16(r)
+		default:
+			fields.skip()
+		}
+	}
+	return value
+}
+
+func encode_StructWithOptions(w *writer, value StructWithOptions) {
+	count := 2
+	if len(value.FilledString) > 0 {
+		count++
+	}
+	if len(value.EmptyString) > 0 {
+		count++
+	}
+	if value.FilledOptionString != nil {
+		count++
+	}
+	if value.EmptyOptionString != nil {
+		count++
+	}
+	w.writeMap(count)
+	if len(value.FilledString) > 0 {
+		w.writeString("filledString")
+		encode_String(w, value.FilledString)
+	}
+	if len(value.EmptyString) > 0 {
+		w.writeString("emptyString")
+		encode_String(w, value.EmptyString)
+	}
+	if value.FilledOptionString != nil {
+		w.writeString("filledOptionString")
+		encode_Option_String(w, value.FilledOptionString)
+	}
+	if value.EmptyOptionString != nil {
+		w.writeString("emptyOptionString")
+		encode_Option_String(w, value.EmptyOptionString)
+	}
+	w.writeString("neverSkippedFilledOptionString")
+	encode_Option_String(w, value.NeverSkippedFilledOptionString)
+	w.writeString("neverSkippedEmptyOptionString")
+	encode_Option_String(w, value.NeverSkippedEmptyOptionString)
+}
+
+func decode_StructWithOptions(r *reader) StructWithOptions {
+	var value StructWithOptions
+	fields := r.readStruct([]string{"filledString", "emptyString", "filledOptionString", "emptyOptionString", "neverSkippedFilledOptionString", "neverSkippedEmptyOptionString"})
+	for fields.next() {
+		switch fields.key {
+		case "filledString":
+			value.FilledString = decode_String(r)
+		case "emptyString":
+			value.EmptyString = decode_String(r)
+		case "filledOptionString":
+			value.FilledOptionString = decode_Option_String(r)
+		case "emptyOptionString":
+			value.EmptyOptionString = decode_Option_String(r)
+		case "neverSkippedFilledOptionString":
+			value.NeverSkippedFilledOptionString = decode_Option_String(r)
+		case "neverSkippedEmptyOptionString":
+			value.NeverSkippedEmptyOptionString = decode_Option_String(r)
+		default:
+			fields.skip()
+		}
+	}
+	return value
+}
+
+func encode_Array_f32_3(w *writer, value [3]float32) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_f32(w, item)
+	}
+}
+
+func decode_Array_f32_3(r *reader) [3]float32 {
+	var value [3]float32
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_f32(r)
+	}
+	return value
+}
+
+func encode_Array_f64_3(w *writer, value [3]float64) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_f64(w, item)
+	}
+}
+
+func decode_Array_f64_3(r *reader) [3]float64 {
+	var value [3]float64
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_f64(r)
+	}
+	return value
+}
+
+func encode_Array_i16_3(w *writer, value [3]int16) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_i16(w, item)
+	}
+}
+
+func decode_Array_i16_3(r *reader) [3]int16 {
+	var value [3]int16
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_i16(r)
+	}
+	return value
+}
+
+func encode_Array_i32_3(w *writer, value [3]int32) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_i32(w, item)
+	}
+}
+
+func decode_Array_i32_3(r *reader) [3]int32 {
+	var value [3]int32
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_i32(r)
+	}
+	return value
+}
+
+func encode_Array_i8_3(w *writer, value [3]int8) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_i8(w, item)
+	}
+}
+
+func decode_Array_i8_3(r *reader) [3]int8 {
+	var value [3]int8
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_i8(r)
+	}
+	return value
+}
+
+func encode_Array_u16_3(w *writer, value [3]uint16) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_u16(w, item)
+	}
+}
+
+func decode_Array_u16_3(r *reader) [3]uint16 {
+	var value [3]uint16
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_u16(r)
+	}
+	return value
+}
+
+func encode_Array_u32_3(w *writer, value [3]uint32) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_u32(w, item)
+	}
+}
+
+func decode_Array_u32_3(r *reader) [3]uint32 {
+	var value [3]uint32
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_u32(r)
+	}
+	return value
+}
+
+func encode_Array_u8_3(w *writer, value [3]uint8) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_u8(w, item)
+	}
+}
+
+func decode_Array_u8_3(r *reader) [3]uint8 {
+	var value [3]uint8
+	if r.readArray() != 3 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_u8(r)
+	}
+	return value
+}
+
+func encode_ExplicitBoundPoint_u64(w *writer, value ExplicitBoundPoint[uint64]) {
+	w.writeMap(1)
+	w.writeString("value")
+	encode_u64(w, value.Value)
+}
+
+func decode_ExplicitBoundPoint_u64(r *reader) ExplicitBoundPoint[uint64] {
+	var value ExplicitBoundPoint[uint64]
+	var hasValue bool
+	fields := r.readStruct([]string{"value"})
+	for fields.next() {
+		switch fields.key {
+		case "value":
+			value.Value = decode_u64(r)
+			hasValue = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasValue {
+		r.fail()
+	}
+	return value
+}
+
+func encode_Vec_u64(w *writer, value []uint64) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_u64(w, item)
+	}
+}
+
+func decode_Vec_u64(r *reader) []uint64 {
+	length := r.readArray()
+	value := make([]uint64, 0, length)
+	for i := 0; i < length && !r.failed; i++ {
+		value = append(value, decode_u64(r))
+	}
+	return value
+}
+
+func encode_Point_u64(w *writer, value Point[uint64]) {
+	w.writeMap(1)
+	w.writeString("value")
+	encode_u64(w, value.Value)
+}
+
+func decode_Point_u64(r *reader) Point[uint64] {
+	var value Point[uint64]
+	var hasValue bool
+	fields := r.readStruct([]string{"value"})
+	for fields.next() {
+		switch fields.key {
+		case "value":
+			value.Value = decode_u64(r)
+			hasValue = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasValue {
+		r.fail()
+	}
+	return value
+}
+
+func encode_Vec_Point_u64(w *writer, value []Point[uint64]) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_Point_u64(w, item)
+	}
+}
+
+func decode_Vec_Point_u64(r *reader) []Point[uint64] {
+	length := r.readArray()
+	value := make([]Point[uint64], 0, length)
+	for i := 0; i < length && !r.failed; i++ {
+		value = append(value, decode_Point_u64(r))
+	}
+	return value
+}
+
+func encode_Point_Point_u64(w *writer, value Point[Point[uint64]]) {
+	w.writeMap(1)
+	w.writeString("value")
+	encode_Point_u64(w, value.Value)
+}
+
+func decode_Point_Point_u64(r *reader) Point[Point[uint64]] {
+	var value Point[Point[uint64]]
+	var hasValue bool
+	fields := r.readStruct([]string{"value"})
+	for fields.next() {
+		switch fields.key {
+		case "value":
+			value.Value = decode_Point_u64(r)
+			hasValue = true
+		default:
+			fields.skip()
+		}
+	}
+	if !hasValue {
+		r.fail()
+	}
+	return value
+}
+
+func encode_Vec_Point_Point_u64(w *writer, value []Point[Point[uint64]]) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_Point_Point_u64(w, item)
+	}
+}
+
+func decode_Vec_Point_Point_u64(r *reader) []Point[Point[uint64]] {
+	length := r.readArray()
+	value := make([]Point[Point[uint64]], 0, length)
+	for i := 0; i < length && !r.failed; i++ {
+		value = append(value, decode_Point_Point_u64(r))
+	}
+	return value
+}
+
+func encode_Vec_FloatingPoint(w *writer, value []FloatingPoint) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_Point_f64(w, item)
+	}
+}
+
+func decode_Vec_FloatingPoint(r *reader) []FloatingPoint {
+	length := r.readArray()
+	value := make([]FloatingPoint, 0, length)
+	for i := 0; i < length && !r.failed; i++ {
+		value = append(value, decode_Point_f64(r))
+	}
+	return value
+}
+
+func encode_Map_String_Vec_FloatingPoint(w *writer, value map[string][]FloatingPoint) {
+	w.writeMap(len(value))
+	for key, item := range value {
+		encode_String(w, key)
+		encode_Vec_FloatingPoint(w, item)
+	}
+}
+
+func decode_Map_String_Vec_FloatingPoint(r *reader) map[string][]FloatingPoint {
+	length := r.readMap()
+	value := make(map[string][]FloatingPoint, length)
+	for i := 0; i < length && !r.failed; i++ {
+		key := decode_String(r)
+		value[key] = decode_Vec_FloatingPoint(r)
+	}
+	return value
+}
+
+func encode_Option_Map_String_Vec_FloatingPoint(w *writer, value *map[string][]FloatingPoint) {
+	if value != nil {
+		encode_Map_String_Vec_FloatingPoint(w, *value)
+	} else {
+		w.writeNil()
+	}
+}
+
+func decode_Option_Map_String_Vec_FloatingPoint(r *reader) *map[string][]FloatingPoint {
+	if r.readNil() {
+		return nil
+	}
+	value := decode_Map_String_Vec_FloatingPoint(r)
+	return &value
+}
+
+func encode_Option_MyDateTime(w *writer, value *MyDateTime) {
+	if value != nil {
+		encode_MyDateTime(w, *value)
+	} else {
+		w.writeNil()
+	}
+}
+
+func decode_Option_MyDateTime(r *reader) *MyDateTime {
+	if r.readNil() {
+		return nil
+	}
+	value := decode_MyDateTime(r)
+	return &value
+}
+
+func encode_StructWithGenerics_u64(w *writer, value StructWithGenerics[uint64]) {
+	w.writeMap(5)
+	w.writeString("list")
+	encode_Vec_u64(w, value.List)
+	w.writeString("points")
+	encode_Vec_Point_u64(w, value.Points)
+	w.writeString("recursive")
+	encode_Vec_Point_Point_u64(w, value.Recursive)
+	w.writeString("complex_nested")
+	encode_Option_Map_String_Vec_FloatingPoint(w, value.ComplexNested)
+	w.writeString("optional_timestamp")
+	encode_Option_MyDateTime(w, value.OptionalTimestamp)
+}
+
+func decode_StructWithGenerics_u64(r *reader) StructWithGenerics[uint64] {
+	var value StructWithGenerics[uint64]
+	var hasList, hasPoints, hasRecursive bool
+	fields := r.readStruct([]string{"list", "points", "recursive", "complex_nested", "optional_timestamp"})
+	for fields.next() {
+		switch fields.key {
+		case "list":
+			value.List = decode_Vec_u64(r)
+			hasList = true
+		case "points":
+			value.Points = decode_Vec_Point_u64(r)
+			hasPoints = true
+		case "recursive":
+			value.Recursive = decode_Vec_Point_Point_u64(r)
+			hasRecursive = true
+		case "complex_nested":
+			value.ComplexNested = decode_Option_Map_String_Vec_FloatingPoint(r)
+		case "optional_timestamp":
+			value.OptionalTimestamp = decode_Option_MyDateTime(r)
+		default:
+			fields.skip()
+		}
+	}
+	if !hasList || !hasPoints || !hasRecursive {
+		r.fail()
+	}
+	return value
+}
+
+func encode_Result_Bytes_String(w *writer, value Result[[]byte, string]) {
+	switch {
+	case value.Ok != nil:
+		w.writeMap(1)
+		w.writeString("Ok")
+		encode_Bytes(w, *value.Ok)
+	case value.Err != nil:
+		w.writeMap(1)
+		w.writeString("Err")
+		encode_String(w, *value.Err)
+	default:
+		Panic("No variant set for enum Result")
+	}
+}
+
+func decode_Result_Bytes_String(r *reader) Result[[]byte, string] {
+	var value Result[[]byte, string]
+	name, content := r.readVariant()
+	switch name {
+	case "Ok":
+		payload := decode_Bytes(content)
+		value.Ok = &payload
+		if content.failed {
+			r.fail()
+		}
+	case "Err":
+		payload := decode_String(content)
+		value.Err = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_Array_f32_1(w *writer, value [1]float32) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_f32(w, item)
+	}
+}
+
+func decode_Array_f32_1(r *reader) [1]float32 {
+	var value [1]float32
+	if r.readArray() != 1 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_f32(r)
+	}
+	return value
+}
+
+func encode_Array_f64_1(w *writer, value [1]float64) {
+	w.writeArray(len(value))
+	for _, item := range value {
+		encode_f64(w, item)
+	}
+}
+
+func decode_Array_f64_1(r *reader) [1]float64 {
+	var value [1]float64
+	if r.readArray() != 1 {
+		r.fail()
+		return value
+	}
+	for i := range value {
+		value[i] = decode_f64(r)
+	}
+	return value
+}
+
+func encode_Result_Unit_u32(w *writer, value Result[Unit, uint32]) {
+	switch {
+	case value.Ok != nil:
+		w.writeMap(1)
+		w.writeString("Ok")
+		encode_Unit(w, *value.Ok)
+	case value.Err != nil:
+		w.writeMap(1)
+		w.writeString("Err")
+		encode_u32(w, *value.Err)
+	default:
+		Panic("No variant set for enum Result")
+	}
+}
+
+func decode_Result_Unit_u32(r *reader) Result[Unit, uint32] {
+	var value Result[Unit, uint32]
+	name, content := r.readVariant()
+	switch name {
+	case "Ok":
+		payload := decode_Unit(content)
+		value.Ok = &payload
+		if content.failed {
+			r.fail()
+		}
+	case "Err":
+		payload := decode_u32(content)
+		value.Err = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
+
+func encode_Result_String_String(w *writer, value Result[string, string]) {
+	switch {
+	case value.Ok != nil:
+		w.writeMap(1)
+		w.writeString("Ok")
+		encode_String(w, *value.Ok)
+	case value.Err != nil:
+		w.writeMap(1)
+		w.writeString("Err")
+		encode_String(w, *value.Err)
+	default:
+		Panic("No variant set for enum Result")
+	}
+}
+
+func decode_Result_String_String(r *reader) Result[string, string] {
+	var value Result[string, string]
+	name, content := r.readVariant()
+	switch name {
+	case "Ok":
+		payload := decode_String(content)
+		value.Ok = &payload
+		if content.failed {
+			r.fail()
+		}
+	case "Err":
+		payload := decode_String(content)
+		value.Err = &payload
+		if content.failed {
+			r.fail()
+		}
+	default:
+		r.fail()
+	}
+	return value
+}
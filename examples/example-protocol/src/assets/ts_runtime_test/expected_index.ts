@@ -11,6 +11,19 @@ import type * as types from "./types.ts";
 
 type FatPtr = bigint;
 
+// Extension bit in the length of a fat pointer, which indicates the length is
+// stored in a 4-byte little-endian header, directly followed by the data.
+const FAT_PTR_LEN_HEADER = 0x0100_0000;
+const FAT_PTR_MAX_LEN = 0x00ff_ffff;
+
+/**
+ * Fingerprint of the protocol these bindings were generated from.
+ *
+ * Plugins built against a different protocol are rejected by `createRuntime()`,
+ * unless the `skipProtocolCheck` option is set.
+ */
+export const PROTOCOL_VERSION = 0x1e72896d627cc76en;
+
 export type Imports = {
     importArrayF32: (arg: Float32Array) => Float32Array;
     importArrayF64: (arg: Float64Array) => Float64Array;
@@ -94,6 +107,7 @@ export type Exports = {
     exportGetSerdeBytes?: () => types.Result<ArrayBuffer, string>;
     exportIncrementGlobalState?: () => Promise<void>;
     exportMultiplePrimitives?: (arg1: number, arg2: string) => bigint;
+    exportPanic?: (message: string) => void;
     exportPrimitiveBoolNegate?: (arg: boolean) => boolean;
     exportPrimitiveBoolNegateAsync?: (arg: boolean) => Promise<boolean>;
     exportPrimitiveF32AddThree?: (arg: number) => number;
@@ -152,6 +166,7 @@ export type Exports = {
     exportGetSerdeBytesRaw?: () => Uint8Array;
     exportIncrementGlobalStateRaw?: () => Promise<Uint8Array>;
     exportMultiplePrimitivesRaw?: (arg1: number, arg2: Uint8Array) => bigint;
+    exportPanicRaw?: (message: Uint8Array) => void;
     exportPrimitiveBoolNegateRaw?: (arg: boolean) => boolean;
     exportPrimitiveBoolNegateAsyncRaw?: (arg: boolean) => Promise<Uint8Array>;
     exportPrimitiveF32AddThreeAsyncRaw?: (arg: number) => Promise<Uint8Array>;
@@ -193,16 +208,54 @@ export class FPRuntimeError extends Error {
     }
 }
 
+/**
+ * Represents a panic that occurred inside the WASM plugin.
+ */
+export class FPGuestPanic extends FPRuntimeError {
+    constructor(message: string, public location: string | null) {
+        super(message);
+    }
+}
+
+/**
+ * Indicates the plugin was built against a different protocol than the runtime.
+ */
+export class FPProtocolMismatch extends FPRuntimeError {
+    constructor(public expected: bigint, public found: bigint) {
+        super(
+            `Plugin was built against a different protocol (expected version ${formatProtocolVersion(expected)}, found ${formatProtocolVersion(found)})`
+        );
+    }
+}
+
+/**
+ * Options for `createRuntime()`.
+ */
+export type RuntimeOptions = {
+    /**
+     * Accept the plugin without verifying it was built against the same protocol
+     * as the runtime.
+     */
+    skipProtocolCheck?: boolean;
+};
+
+type GuestPanic = {
+    message: string;
+    location: string | null;
+};
+
 /**
  * Creates a runtime for executing the given plugin.
  *
  * @param source The response for fetching the WASM plugin.
  * @param importFunctions The host functions that may be imported by the plugin.
+ * @param options Options for loading the plugin.
  * @returns The functions that may be exported by the plugin.
  */
 export async function createRuntime(
     source: Response | Promise<Response>,
-    importFunctions: Imports
+    importFunctions: Imports,
+    options: RuntimeOptions = {}
 ): Promise<Exports> {
     const promises = new Map<FatPtr, ((result: FatPtr) => void) | FatPtr>();
 
@@ -232,7 +285,7 @@ export async function createRuntime(
     }
 
     function parseObject<T>(fatPtr: FatPtr): T {
-        const [ptr, len] = fromFatPtr(fatPtr);
+        const [ptr, len] = resolveFatPtr(fatPtr);
         const buffer = new Uint8Array(memory.buffer, ptr, len);
         // Without creating a copy of the memory, we risk corruption of any
         // embedded `Uint8Array` objects returned from `decode()` after `free()`
@@ -244,6 +297,17 @@ export async function createRuntime(
         return object;
     }
 
+    function resolveFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {
+        const [ptr, len] = fromFatPtr(fatPtr);
+        if (len === FAT_PTR_LEN_HEADER) {
+            const header = new DataView(memory.buffer, ptr, 4);
+            return [ptr + 4, header.getUint32(0, true)];
+        } else if (len > FAT_PTR_MAX_LEN) {
+            throw new FPRuntimeError("Unknown extension bits");
+        }
+        return [ptr, len];
+    }
+
     function promiseFromPtr(ptr: FatPtr): Promise<FatPtr> {
         const resultPtr = promises.get(ptr);
         if (resultPtr) {
@@ -280,14 +344,14 @@ export async function createRuntime(
 
     function exportToMemory(serialized: Uint8Array): FatPtr {
         const fatPtr = malloc(serialized.length);
-        const [ptr, len] = fromFatPtr(fatPtr);
+        const [ptr, len] = resolveFatPtr(fatPtr);
         const buffer = new Uint8Array(memory.buffer, ptr, len);
         buffer.set(serialized);
         return fatPtr;
     }
 
     function importFromMemory(fatPtr: FatPtr): Uint8Array {
-        const [ptr, len] = fromFatPtr(fatPtr);
+        const [ptr, len] = resolveFatPtr(fatPtr);
         const buffer = new Uint8Array(memory.buffer, ptr, len);
         const copy = new Uint8Array(len);
         copy.set(buffer);
@@ -659,6 +723,10 @@ export async function createRuntime(
                 return _async_result_ptr;
             },
             __fp_host_resolve_async_value: resolvePromise,
+            __fp_host_panic: (panicPtr: FatPtr) => {
+                const { message, location } = parseObject<GuestPanic>(panicPtr);
+                throw new FPGuestPanic(message, location);
+            },
         },
     });
 
@@ -673,6 +741,23 @@ export async function createRuntime(
     const memory = getExport<WebAssembly.Memory>("memory");
     const malloc = getExport<(len: number) => FatPtr>("__fp_malloc");
     const free = getExport<(ptr: FatPtr) => void>("__fp_free");
+
+    // Reactor modules, such as plugins written in TinyGo, need to initialize
+    // themselves before any of their other exports may be called.
+    const initialize = instance.exports._initialize as (() => void) | undefined;
+    if (initialize) {
+        initialize();
+    }
+
+    // Plugins built with older bindings don't export their protocol version, so
+    // they cannot be verified.
+    const protocolVersion = instance.exports.__fp_protocol_version as (() => bigint) | undefined;
+    if (protocolVersion && !options.skipProtocolCheck) {
+        const foundVersion = BigInt.asUintN(64, protocolVersion());
+        if (foundVersion !== PROTOCOL_VERSION) {
+            throw new FPProtocolMismatch(PROTOCOL_VERSION, foundVersion);
+        }
+    }
     const resolveFuture = getExport<(asyncValuePtr: FatPtr, resultPtr: FatPtr) => void>("__fp_guest_resolve_async_value");
 
     return {
@@ -847,6 +932,15 @@ export async function createRuntime(
                 return interpretBigSign(export_fn(arg1, arg2_ptr), 9223372036854775808n);
             };
         })(),
+        exportPanic: (() => {
+            const export_fn = instance.exports.__fp_gen_export_panic as any;
+            if (!export_fn) return;
+
+            return (message: string) => {
+                const message_ptr = serializeObject(message);
+                export_fn(message_ptr);
+            };
+        })(),
         exportPrimitiveBoolNegate: (() => {
             const export_fn = instance.exports.__fp_gen_export_primitive_bool_negate as any;
             if (!export_fn) return;
@@ -1229,6 +1323,15 @@ export async function createRuntime(
                 return interpretBigSign(export_fn(arg1, arg2_ptr), 9223372036854775808n);
             };
         })(),
+        exportPanicRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_panic as any;
+            if (!export_fn) return;
+
+            return (message: Uint8Array) => {
+                const message_ptr = exportToMemory(message);
+                export_fn(message_ptr);
+            };
+        })(),
         exportPrimitiveBoolNegateRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_primitive_bool_negate as any;
             if (!export_fn) return;
@@ -1440,3 +1543,7 @@ function fromFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {
 function toFatPtr(ptr: number, len: number): FatPtr {
     return (BigInt(ptr) << 32n) | BigInt(len);
 }
+
+function formatProtocolVersion(version: bigint): string {
+    return `0x${version.toString(16).padStart(16, "0")}`;
+}
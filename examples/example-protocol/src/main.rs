@@ -54,7 +54,9 @@ fp_import! {
 
     // NOTICE: This is a workaround for a bug in wasmer 2.3, where imported functions
     // receive 0.0 instead of the the float value they were called with.
-    // This bug is fixed in wasmer 3, but in the meantime, you can use this workaround.
+    // This bug is fixed in wasmer 3, so the functions above work as expected with the
+    // `RustWasmer3Runtime` and `RustWasmtimeRuntime` bindings. The workaround remains here only
+    // for as long as we keep testing against wasmer 2.
     // See https://github.com/fiberplane/fp-bindgen/issues/180
     fn import_primitive_f32_add_one_wasmer2(arg: [f32; 1]) -> f32;
     fn import_primitive_f64_add_one_wasmer2(arg: [f64; 1]) -> f64;
@@ -259,6 +261,10 @@ fp_export! {
     /// Called on the plugin to give it a chance to initialize.
     fn init();
 
+    /// Panics with the given message, so the runtimes can test guest panics
+    /// are reported to them.
+    fn export_panic(message: String);
+
     /// Example how plugin could expose a reducer.
     fn reducer_bridge(action: ReduxAction) -> StateUpdate;
 }
@@ -294,6 +300,13 @@ static PLUGIN_DEPENDENCIES: Lazy<BTreeMap<&str, CargoDependency>> = Lazy::new(||
 
 fn main() {
     for bindings_type in [
+        BindingsType::ApiReference(ApiReferenceConfig::new().with_title("Example protocol")),
+        BindingsType::AssemblyScriptPlugin,
+        BindingsType::CPlugin,
+        BindingsType::GoWazeroRuntime(GoWazeroRuntimeConfig::new()),
+        BindingsType::JsonSchema,
+        BindingsType::ProtocolJson,
+        BindingsType::PythonWasmtimeRuntime,
         BindingsType::RustPlugin(
             RustPluginConfig::builder()
                 .name(NAME)
@@ -306,12 +319,16 @@ fn main() {
         ),
         BindingsType::RustWasmer2Runtime,
         BindingsType::RustWasmer2WasiRuntime,
+        BindingsType::RustWasmer3Runtime,
+        BindingsType::RustWasmtimeRuntime,
+        BindingsType::TinyGoPlugin(TinyGoPluginConfig::new()),
         BindingsType::TsRuntime(
             TsRuntimeConfig::new()
                 .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
                 .with_raw_export_wrappers()
                 .without_streaming_instantiation(),
         ),
+        BindingsType::WitWorld(WitWorldConfig::new().with_package_name("example:protocol")),
     ] {
         let output_path = format!("bindings/{bindings_type}");
 
@@ -323,6 +340,261 @@ fn main() {
     }
 }
 
+#[test]
+fn test_generate_api_reference() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/api-reference/index.md",
+            include_bytes!("assets/api_reference_test/expected_index.md"),
+        ),
+        (
+            "bindings/api-reference/types/Request.md",
+            include_bytes!("assets/api_reference_test/expected_Request.md"),
+        ),
+        (
+            "bindings/api-reference/types/SerdeInternallyTagged.md",
+            include_bytes!("assets/api_reference_test/expected_SerdeInternallyTagged.md"),
+        ),
+        (
+            "bindings/api-reference/types/StructWithGenerics.md",
+            include_bytes!("assets/api_reference_test/expected_StructWithGenerics.md"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::ApiReference(
+            ApiReferenceConfig::new().with_title("Example protocol")
+        ),
+        path: "bindings/api-reference",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_assemblyscript_plugin() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/assemblyscript-plugin/exports.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_exports.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/imports.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_imports.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/support.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_support.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/types.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_types.ts"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::AssemblyScriptPlugin,
+        path: "bindings/assemblyscript-plugin",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_c_plugin() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/c-plugin/bindings.c",
+            include_bytes!("assets/c_plugin_test/expected_bindings.c"),
+        ),
+        (
+            "bindings/c-plugin/bindings.h",
+            include_bytes!("assets/c_plugin_test/expected_bindings.h"),
+        ),
+        (
+            "bindings/c-plugin/types.c",
+            include_bytes!("assets/c_plugin_test/expected_types.c"),
+        ),
+        (
+            "bindings/c-plugin/types.h",
+            include_bytes!("assets/c_plugin_test/expected_types.h"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::CPlugin,
+        path: "bindings/c-plugin",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_go_wazero_runtime() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/go-wazero-runtime/runtime.go",
+            include_bytes!("assets/go_wazero_runtime_test/expected_runtime.go"),
+        ),
+        (
+            "bindings/go-wazero-runtime/types.go",
+            include_bytes!("assets/go_wazero_runtime_test/expected_types.go"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::GoWazeroRuntime(GoWazeroRuntimeConfig::new()),
+        path: "bindings/go-wazero-runtime",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_json_schema() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/json-schema/FpFlatten.schema.json",
+            include_bytes!("assets/json_schema_test/expected_FpFlatten.schema.json"),
+        ),
+        (
+            "bindings/json-schema/Request.schema.json",
+            include_bytes!("assets/json_schema_test/expected_Request.schema.json"),
+        ),
+        (
+            "bindings/json-schema/RequestError.schema.json",
+            include_bytes!("assets/json_schema_test/expected_RequestError.schema.json"),
+        ),
+        (
+            "bindings/json-schema/SerdeAdjacentlyTagged.schema.json",
+            include_bytes!("assets/json_schema_test/expected_SerdeAdjacentlyTagged.schema.json"),
+        ),
+        (
+            "bindings/json-schema/SerdeInternallyTagged.schema.json",
+            include_bytes!("assets/json_schema_test/expected_SerdeInternallyTagged.schema.json"),
+        ),
+        (
+            "bindings/json-schema/SerdeUntagged.schema.json",
+            include_bytes!("assets/json_schema_test/expected_SerdeUntagged.schema.json"),
+        ),
+        (
+            "bindings/json-schema/SerdeVariantRenaming.schema.json",
+            include_bytes!("assets/json_schema_test/expected_SerdeVariantRenaming.schema.json"),
+        ),
+        (
+            "bindings/json-schema/StructWithOptions.schema.json",
+            include_bytes!("assets/json_schema_test/expected_StructWithOptions.schema.json"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::JsonSchema,
+        path: "bindings/json-schema",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_protocol_json() {
+    static FILES: &[(&str, &[u8])] = &[(
+        "bindings/protocol-json/protocol.json",
+        include_bytes!("assets/protocol_json_test/expected_protocol.json"),
+    )];
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::ProtocolJson,
+        path: "bindings/protocol-json",
+    });
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+
+    let json = std::fs::read_to_string("bindings/protocol-json/protocol.json").unwrap();
+    let protocol = fp_bindgen::protocol_from_json(&json).expect("Cannot parse protocol JSON");
+    assert_eq!(protocol.to_json(), json);
+    assert_eq!(fp_protocol!().to_json(), json);
+}
+
+#[test]
+fn test_check_bindings() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().to_str().unwrap();
+
+    let protocol = fp_protocol!();
+    fp_bindgen::generate_bindings(
+        protocol.import_functions.clone(),
+        protocol.export_functions.clone(),
+        protocol.types.clone(),
+        BindingConfig {
+            bindings_type: BindingsType::ProtocolJson,
+            path,
+        },
+    );
+    let check = |protocol: fp_bindgen::Protocol| {
+        fp_bindgen::check_bindings(
+            protocol.import_functions,
+            protocol.export_functions,
+            protocol.types,
+            BindingConfig {
+                bindings_type: BindingsType::ProtocolJson,
+                path,
+            },
+        )
+    };
+
+    check(protocol.clone()).expect("Freshly generated bindings should be up-to-date");
+
+    let mut changed_protocol = protocol;
+    changed_protocol.import_functions = FunctionList::new();
+    let outdated = match check(changed_protocol) {
+        Err(fp_bindgen::BindgenError::OutdatedBindings(outdated)) => outdated,
+        other => panic!("Changed protocol should be detected, got: {:?}", other),
+    };
+    assert_eq!(outdated.files.len(), 1);
+    assert_eq!(
+        outdated.files[0].path,
+        std::path::Path::new(path).join("protocol.json")
+    );
+    assert!(outdated.files[0]
+        .diff
+        .contains("-      \"name\": \"import_array_f32\","));
+}
+
+#[test]
+fn test_render_bindings() {
+    let protocol = fp_protocol!();
+    let files = fp_bindgen::render_bindings(
+        protocol.import_functions.clone(),
+        protocol.export_functions.clone(),
+        protocol.types.clone(),
+        BindingsType::TsRuntime(TsRuntimeConfig::new()),
+    )
+    .expect("Cannot render bindings");
+
+    let paths: Vec<_> = files.keys().collect();
+    assert_eq!(
+        paths,
+        vec![
+            std::path::Path::new("index.ts"),
+            std::path::Path::new("types.ts")
+        ]
+    );
+    assert_eq!(
+        files[std::path::Path::new("types.ts")],
+        String::from_utf8_lossy(include_bytes!("assets/ts_runtime_test/expected_types.ts"))
+    );
+}
+
 #[test]
 fn test_generate_rust_plugin() {
     static FILES: &[(&str, &[u8])] = &[
@@ -399,6 +671,10 @@ fn test_generate_rust_wasmer2_runtime() {
             "bindings/rust-wasmer2-runtime/types.rs",
             include_bytes!("assets/rust_wasmer2_runtime_test/expected_types.rs"),
         ),
+        (
+            "bindings/rust-wasmer2-runtime/testing.rs",
+            include_bytes!("assets/rust_wasmer2_runtime_test/expected_testing.rs"),
+        ),
     ];
     fp_bindgen!(BindingConfig {
         bindings_type: BindingsType::RustWasmer2Runtime,
@@ -420,6 +696,10 @@ fn test_generate_rust_wasmer2_wasi_runtime() {
             "bindings/rust-wasmer2-wasi-runtime/types.rs",
             include_bytes!("assets/rust_wasmer2_wasi_runtime_test/expected_types.rs"),
         ),
+        (
+            "bindings/rust-wasmer2-wasi-runtime/testing.rs",
+            include_bytes!("assets/rust_wasmer2_runtime_test/expected_testing.rs"),
+        ),
     ];
     fp_bindgen!(BindingConfig {
         bindings_type: BindingsType::RustWasmer2WasiRuntime,
@@ -430,6 +710,110 @@ fn test_generate_rust_wasmer2_wasi_runtime() {
     }
 }
 
+#[test]
+fn test_generate_rust_wasmer3_runtime() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/rust-wasmer3-runtime/bindings.rs",
+            include_bytes!("assets/rust_wasmer3_runtime_test/expected_bindings.rs"),
+        ),
+        (
+            "bindings/rust-wasmer3-runtime/types.rs",
+            include_bytes!("assets/rust_wasmer3_runtime_test/expected_types.rs"),
+        ),
+        (
+            "bindings/rust-wasmer3-runtime/testing.rs",
+            include_bytes!("assets/rust_wasmer2_runtime_test/expected_testing.rs"),
+        ),
+    ];
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustWasmer3Runtime,
+        path: "bindings/rust-wasmer3-runtime",
+    });
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_rust_wasmtime_runtime() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/rust-wasmtime-runtime/bindings.rs",
+            include_bytes!("assets/rust_wasmtime_runtime_test/expected_bindings.rs"),
+        ),
+        (
+            "bindings/rust-wasmtime-runtime/types.rs",
+            include_bytes!("assets/rust_wasmtime_runtime_test/expected_types.rs"),
+        ),
+        (
+            "bindings/rust-wasmtime-runtime/testing.rs",
+            include_bytes!("assets/rust_wasmer2_runtime_test/expected_testing.rs"),
+        ),
+    ];
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::RustWasmtimeRuntime,
+        path: "bindings/rust-wasmtime-runtime",
+    });
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_python_wasmtime_runtime() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/python-wasmtime-runtime/__init__.py",
+            include_bytes!("assets/python_wasmtime_runtime_test/expected___init__.py"),
+        ),
+        (
+            "bindings/python-wasmtime-runtime/runtime.py",
+            include_bytes!("assets/python_wasmtime_runtime_test/expected_runtime.py"),
+        ),
+        (
+            "bindings/python-wasmtime-runtime/types.py",
+            include_bytes!("assets/python_wasmtime_runtime_test/expected_types.py"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::PythonWasmtimeRuntime,
+        path: "bindings/python-wasmtime-runtime",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_tinygo_plugin() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/tinygo-plugin/bindings.go",
+            include_bytes!("assets/tinygo_plugin_test/expected_bindings.go"),
+        ),
+        (
+            "bindings/tinygo-plugin/support.go",
+            include_bytes!("assets/tinygo_plugin_test/expected_support.go"),
+        ),
+        (
+            "bindings/tinygo-plugin/types.go",
+            include_bytes!("assets/tinygo_plugin_test/expected_types.go"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::TinyGoPlugin(TinyGoPluginConfig::new()),
+        path: "bindings/tinygo-plugin",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
 #[test]
 fn test_generate_ts_runtime() {
     static FILES: &[(&str, &[u8])] = &[
@@ -457,6 +841,31 @@ fn test_generate_ts_runtime() {
     }
 }
 
+#[test]
+fn test_generate_wit_world() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/wit-world/world.wit",
+            include_bytes!("assets/wit_world_test/expected_world.wit"),
+        ),
+        (
+            "bindings/wit-world/report.md",
+            include_bytes!("assets/wit_world_test/expected_report.md"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::WitWorld(
+            WitWorldConfig::new().with_package_name("example:protocol")
+        ),
+        path: "bindings/wit-world",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -0,0 +1,26 @@
+import importlib.util
+import sys
+from pathlib import Path
+
+BINDINGS_PATH = Path(__file__).parent / "../example-protocol/bindings/python-wasmtime-runtime"
+
+
+def load_bindings():
+    # The bindings directory is not a valid Python package name, so we import
+    # it under a different name. In your own project you would typically
+    # generate the bindings straight into a package of your choosing.
+    spec = importlib.util.spec_from_file_location(
+        "example_bindings",
+        BINDINGS_PATH / "__init__.py",
+        submodule_search_locations=[str(BINDINGS_PATH)],
+    )
+    module = importlib.util.module_from_spec(spec)
+    sys.modules[spec.name] = module
+    spec.loader.exec_module(module)
+    return module
+
+
+def load_plugin(path, imports):
+    bindings = load_bindings()
+    with open(path, "rb") as f:
+        return bindings.Runtime(f.read(), imports)
@@ -0,0 +1,185 @@
+import unittest
+from pathlib import Path
+
+from loader import load_plugin
+
+PLUGIN_PATH = (
+    Path(__file__).parent
+    / "../example-plugin/target/wasm32-unknown-unknown/debug/example_plugin.wasm"
+)
+
+global_state = 0
+
+
+class Imports:
+    def import_multiple_primitives(self, arg1, arg2):
+        assert arg1 == -8
+        assert arg2 == "Hello, 🇳🇱!"
+        return -64
+
+    def import_primitive_bool_negate(self, arg):
+        return not arg
+
+    def import_primitive_f32_add_one(self, arg):
+        return arg + 1.0
+
+    def import_primitive_f64_add_one(self, arg):
+        return arg + 1.0
+
+    def import_primitive_f32_add_one_wasmer2(self, arg):
+        return arg[0] + 1.0
+
+    def import_primitive_f64_add_one_wasmer2(self, arg):
+        return arg[0] + 1.0
+
+    def import_primitive_i8_add_one(self, arg):
+        return arg + 1
+
+    def import_primitive_i16_add_one(self, arg):
+        return arg + 1
+
+    def import_primitive_i32_add_one(self, arg):
+        return arg + 1
+
+    def import_primitive_i64_add_one(self, arg):
+        return arg + 1
+
+    def import_primitive_u8_add_one(self, arg):
+        return arg + 1
+
+    def import_primitive_u16_add_one(self, arg):
+        return arg + 1
+
+    def import_primitive_u32_add_one(self, arg):
+        return arg + 1
+
+    def import_primitive_u64_add_one(self, arg):
+        return arg + 1
+
+    def import_string(self, arg):
+        assert arg == "Hello, world!"
+        return "Hello, plugin!"
+
+    async def import_primitive_bool_negate_async(self, arg):
+        return not arg
+
+    async def import_primitive_f32_add_one_async(self, arg):
+        return arg + 1.0
+
+    async def import_primitive_f64_add_one_async(self, arg):
+        return arg + 1.0
+
+    async def import_primitive_i8_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_primitive_i16_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_primitive_i32_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_primitive_i64_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_primitive_u8_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_primitive_u16_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_primitive_u32_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_primitive_u64_add_one_async(self, arg):
+        return arg + 1
+
+    async def import_increment_global_state(self):
+        global global_state
+        global_state += 1
+
+    async def import_reset_global_state(self):
+        global global_state
+        global_state = 0
+
+    def log(self, message):
+        print(f"Plugin log: {message}")
+
+
+class PrimitivesTest(unittest.TestCase):
+    def setUp(self):
+        self.plugin = load_plugin(PLUGIN_PATH, Imports())
+
+    def test_primitives(self):
+        plugin = self.plugin
+        self.assertEqual(plugin.export_primitive_bool_negate(True), False)
+        self.assertEqual(plugin.export_primitive_bool_negate(False), True)
+
+        self.assertEqual(plugin.export_primitive_u8_add_three(8), 8 + 3)
+        self.assertEqual(plugin.export_primitive_u16_add_three(16), 16 + 3)
+        self.assertEqual(plugin.export_primitive_u32_add_three(32), 32 + 3)
+        self.assertEqual(plugin.export_primitive_u64_add_three(64), 64 + 3)
+        self.assertEqual(plugin.export_primitive_i8_add_three(-8), -8 + 3)
+        self.assertEqual(plugin.export_primitive_i16_add_three(-16), -16 + 3)
+        self.assertEqual(plugin.export_primitive_i32_add_three(-32), -32 + 3)
+        self.assertEqual(plugin.export_primitive_i64_add_three(-64), -64 + 3)
+
+        # Values that don't fit in the signed representation used by Wasmtime:
+        self.assertEqual(plugin.export_primitive_u32_add_three(2**31), 2**31 + 3)
+        self.assertEqual(plugin.export_primitive_u64_add_three(2**63), 2**63 + 3)
+
+        self.assertEqual(plugin.export_multiple_primitives(-8, "Hello, 🇳🇱!"), -64)
+
+        # Precise float comparison is fine as long as the denominator is a power of two
+        self.assertEqual(plugin.export_primitive_f32_add_three(3.5), 3.5 + 3.0)
+        self.assertEqual(plugin.export_primitive_f64_add_three(2.5), 2.5 + 3.0)
+        self.assertEqual(plugin.export_primitive_f32_add_three_wasmer2(13.5), 13.5 + 3.0)
+        self.assertEqual(plugin.export_primitive_f64_add_three_wasmer2(12.5), 12.5 + 3.0)
+
+    def test_arrays(self):
+        plugin = self.plugin
+        self.assertEqual(plugin.export_array_u8([1, 2, 3]), [1, 2, 3])
+        self.assertEqual(plugin.export_array_u16([1, 2, 3]), [1, 2, 3])
+        self.assertEqual(plugin.export_array_u32([1, 2, 3]), [1, 2, 3])
+        self.assertEqual(plugin.export_array_i8([1, 2, 3]), [1, 2, 3])
+        self.assertEqual(plugin.export_array_i16([1, 2, 3]), [1, 2, 3])
+        self.assertEqual(plugin.export_array_i32([1, 2, 3]), [1, 2, 3])
+        self.assertEqual(plugin.export_array_f32([1.0, 2.0, 3.0]), [1.0, 2.0, 3.0])
+        self.assertEqual(plugin.export_array_f64([1.0, 2.0, 3.0]), [1.0, 2.0, 3.0])
+
+    def test_string(self):
+        self.assertEqual(self.plugin.export_string("Hello, plugin!"), "Hello, world!")
+
+
+class AsyncTest(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.plugin = load_plugin(PLUGIN_PATH, Imports())
+
+    async def test_async_primitives(self):
+        plugin = self.plugin
+        self.assertEqual(await plugin.export_primitive_bool_negate_async(True), False)
+        self.assertEqual(await plugin.export_primitive_bool_negate_async(False), True)
+
+        self.assertEqual(await plugin.export_primitive_f32_add_three_async(3.5), 3.5 + 3.0)
+        self.assertEqual(await plugin.export_primitive_f64_add_three_async(2.5), 2.5 + 3.0)
+
+        self.assertEqual(await plugin.export_primitive_u8_add_three_async(8), 8 + 3)
+        self.assertEqual(await plugin.export_primitive_u16_add_three_async(16), 16 + 3)
+        self.assertEqual(await plugin.export_primitive_u32_add_three_async(32), 32 + 3)
+        self.assertEqual(await plugin.export_primitive_u64_add_three_async(64), 64 + 3)
+        self.assertEqual(await plugin.export_primitive_i8_add_three_async(-8), -8 + 3)
+        self.assertEqual(await plugin.export_primitive_i16_add_three_async(-16), -16 + 3)
+        self.assertEqual(await plugin.export_primitive_i32_add_three_async(-32), -32 + 3)
+        self.assertEqual(await plugin.export_primitive_i64_add_three_async(-64), -64 + 3)
+
+        await plugin.export_reset_global_state()
+        await plugin.export_increment_global_state()
+        self.assertEqual(global_state, 1)
+
+        await plugin.export_reset_global_state()
+        await plugin.export_increment_global_state()
+        await plugin.export_increment_global_state()
+        self.assertEqual(global_state, 2)
+
+
+if __name__ == "__main__":
+    unittest.main()
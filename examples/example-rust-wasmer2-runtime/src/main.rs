@@ -3,10 +3,6 @@ mod spec;
 mod test;
 mod wasi_spec;
 
-use std::sync::Mutex;
-
-pub static GLOBAL_STATE: Mutex<u32> = Mutex::new(0);
-
 fn main() {
     println!("Hello, world!");
 }
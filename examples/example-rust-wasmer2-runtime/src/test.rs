@@ -1,58 +1,86 @@
-#[cfg(not(feature="wasi"))]
+#[cfg(not(feature = "wasi"))]
 use crate::spec::types::*;
-#[cfg(feature="wasi")]
+#[cfg(not(feature = "wasi"))]
+use crate::spec::{
+    bindings::{Runtime, PROTOCOL_VERSION},
+    testing::RecordingImports,
+    Host,
+};
+#[cfg(feature = "wasi")]
 use crate::wasi_spec::types::*;
-#[cfg(not(feature="wasi"))]
-use crate::spec::bindings::Runtime;
-#[cfg(feature="wasi")]
-use crate::wasi_spec::bindings::Runtime;
+#[cfg(feature = "wasi")]
+use crate::wasi_spec::{bindings::Runtime, testing::RecordingImports, Host};
 use anyhow::Result;
 use bytes::Bytes;
+use fp_bindgen_support::wasmer2_host::errors::InvocationError;
+#[cfg(not(feature = "wasi"))]
+use fp_bindgen_support::wasmer2_host::errors::RuntimeError;
+use fp_bindgen_testing::{expect, PluginTest};
+use futures::executor::{block_on, ThreadPool};
 use serde_bytes::ByteBuf;
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
 use time::{macros::datetime, OffsetDateTime};
-use super::GLOBAL_STATE;
 
-#[cfg(not(feature="wasi"))]
+#[cfg(not(feature = "wasi"))]
 const WASM_BYTES: &'static [u8] =
     include_bytes!("../../example-plugin/target/wasm32-unknown-unknown/debug/example_plugin.wasm");
-#[cfg(feature="wasi")]
+#[cfg(feature = "wasi")]
 const WASM_BYTES: &'static [u8] =
     include_bytes!("../../example-plugin/target/wasm32-wasi/debug/example_plugin.wasm");
 
-
 #[test]
 fn primitives() -> Result<()> {
     let rt = new_runtime()?;
 
-    assert_eq!(rt.export_primitive_bool_negate(true)?, false);
-    assert_eq!(rt.export_primitive_bool_negate(false)?, true);
-
-    assert_eq!(rt.export_primitive_u8_add_three(8)?, 8 + 3);
-    assert_eq!(rt.export_primitive_u16_add_three(16)?, 16 + 3);
-    assert_eq!(rt.export_primitive_u32_add_three(32)?, 32 + 3);
-    assert_eq!(rt.export_primitive_u64_add_three(64)?, 64 + 3);
-    assert_eq!(rt.export_primitive_i8_add_three(-8)?, -8 + 3);
-    assert_eq!(rt.export_primitive_i16_add_three(-16)?, -16 + 3);
-    assert_eq!(rt.export_primitive_i32_add_three(-32)?, -32 + 3);
-    assert_eq!(rt.export_primitive_i64_add_three(-64)?, -64 + 3);
-
-    assert_eq!(
-        rt.export_multiple_primitives(-8, "Hello, 🇳🇱!".to_string())?,
-        -64
-    );
+    expect(rt.export_primitive_bool_negate(true)).to_return(false);
+    expect(rt.export_primitive_bool_negate(false)).to_return(true);
+
+    expect(rt.export_primitive_u8_add_three(8)).to_return(8 + 3);
+    expect(rt.export_primitive_u16_add_three(16)).to_return(16 + 3);
+    expect(rt.export_primitive_u32_add_three(32)).to_return(32 + 3);
+    expect(rt.export_primitive_u64_add_three(64)).to_return(64 + 3);
+    expect(rt.export_primitive_i8_add_three(-8)).to_return(-8 + 3);
+    expect(rt.export_primitive_i16_add_three(-16)).to_return(-16 + 3);
+    expect(rt.export_primitive_i32_add_three(-32)).to_return(-32 + 3);
+    expect(rt.export_primitive_i64_add_three(-64)).to_return(-64 + 3);
+
+    expect(rt.export_multiple_primitives(-8, "Hello, 🇳🇱!".to_string())).to_return(-64);
 
     // FIXME: because of a bug in wasmer 2, we must use a workaround to pass float values to host.
     // Uncomment these tests in the wasmer3 branch, since the bug is fixed there.
-    // assert_eq!(rt.export_primitive_f32_add_three(3.5)?, 3.5 + 3.0);
-    // assert_eq!(
-    //     rt.export_primitive_f64_add_three(2.5)?,
-    //     2.5 + 3.0
-    // );
+    // expect(rt.export_primitive_f32_add_three(3.5)).to_return(3.5 + 3.0);
+    // expect(rt.export_primitive_f64_add_three(2.5)).to_return(2.5 + 3.0);
 
     // Precise float comparison is fine as long as the denominator is a power of two
-    assert_eq!(rt.export_primitive_f32_add_three_wasmer2(3.5)?, 3.5 + 3.0);
-    assert_eq!(rt.export_primitive_f64_add_three_wasmer2(2.5)?, 2.5 + 3.0);
+    expect(rt.export_primitive_f32_add_three_wasmer2(3.5)).to_return(3.5 + 3.0);
+    expect(rt.export_primitive_f64_add_three_wasmer2(2.5)).to_return(2.5 + 3.0);
+
+    rt.imports()
+        .assert_called_with("import_primitive_u8_add_one", (9u8,));
+    rt.imports()
+        .assert_called_with("import_primitive_f32_add_one_wasmer2", ([4.5f32],));
+
+    Ok(())
+}
+
+#[test]
+fn stubbed_imports() -> Result<()> {
+    let rt = new_runtime()?;
+
+    rt.imports().returns("import_primitive_u32_add_one", 100u32);
+    expect(rt.export_primitive_u32_add_three(32)).to_return(101);
+    rt.imports()
+        .assert_called_with("import_primitive_u32_add_one", (33u32,));
+
+    rt.imports()
+        .stub_with("import_primitive_bool_negate", |(arg,): (bool,)| arg);
+    expect(rt.export_primitive_bool_negate(true)).to_return(true);
+
+    rt.imports().clear_stubs();
+    expect(rt.export_primitive_u32_add_three(32)).to_return(35);
 
     Ok(())
 }
@@ -61,24 +89,21 @@ fn primitives() -> Result<()> {
 fn arrays() -> Result<()> {
     let rt = new_runtime()?;
 
-    assert_eq!(rt.export_array_u8([1u8, 2u8, 3u8])?, [1u8, 2u8, 3u8]);
-    assert_eq!(rt.export_array_u16([1u16, 2u16, 3u16])?, [1u16, 2u16, 3u16]);
-    assert_eq!(rt.export_array_u32([1u32, 2u32, 3u32])?, [1u32, 2u32, 3u32]);
-    assert_eq!(rt.export_array_i8([1i8, 2i8, 3i8])?, [1i8, 2i8, 3i8]);
-    assert_eq!(rt.export_array_i16([1i16, 2i16, 3i16])?, [1i16, 2i16, 3i16]);
-    assert_eq!(rt.export_array_i32([1i32, 2i32, 3i32])?, [1i32, 2i32, 3i32]);
-    assert_eq!(rt.export_array_f32([1f32, 2f32, 3f32])?, [1f32, 2f32, 3f32]);
-    assert_eq!(rt.export_array_f64([1f64, 2f64, 3f64])?, [1f64, 2f64, 3f64]);
+    expect(rt.export_array_u8([1u8, 2u8, 3u8])).to_return([1u8, 2u8, 3u8]);
+    expect(rt.export_array_u16([1u16, 2u16, 3u16])).to_return([1u16, 2u16, 3u16]);
+    expect(rt.export_array_u32([1u32, 2u32, 3u32])).to_return([1u32, 2u32, 3u32]);
+    expect(rt.export_array_i8([1i8, 2i8, 3i8])).to_return([1i8, 2i8, 3i8]);
+    expect(rt.export_array_i16([1i16, 2i16, 3i16])).to_return([1i16, 2i16, 3i16]);
+    expect(rt.export_array_i32([1i32, 2i32, 3i32])).to_return([1i32, 2i32, 3i32]);
+    expect(rt.export_array_f32([1f32, 2f32, 3f32])).to_return([1f32, 2f32, 3f32]);
+    expect(rt.export_array_f64([1f64, 2f64, 3f64])).to_return([1f64, 2f64, 3f64]);
     Ok(())
 }
 
 #[test]
 fn string() -> Result<()> {
     let rt = new_runtime()?;
-    assert_eq!(
-        rt.export_string("Hello, plugin!".to_string())?,
-        "Hello, world!"
-    );
+    expect(rt.export_string("Hello, plugin!".to_string())).to_return("Hello, world!".to_owned());
 
     Ok(())
 }
@@ -86,52 +111,42 @@ fn string() -> Result<()> {
 #[test]
 fn timestamp() -> Result<()> {
     let rt = new_runtime()?;
-    assert_eq!(
-        rt.export_timestamp(MyDateTime(datetime!(2022-04-12 19:10 UTC)))?,
-        MyDateTime(datetime!(2022-04-13 12:37 UTC))
-    );
+    expect(rt.export_timestamp(MyDateTime(datetime!(2022-04-12 19:10 UTC))))
+        .to_return(MyDateTime(datetime!(2022-04-13 12:37 UTC)));
     Ok(())
 }
 
 #[test]
 fn flattened_structs() -> Result<()> {
     let rt = new_runtime()?;
-    assert_eq!(
-        rt.export_fp_struct(FpPropertyRenaming {
-            foo_bar: "foo_bar".to_string(),
-            qux_baz: 64.0,
-            raw_struct: -32,
-        })?,
-        FpPropertyRenaming {
-            foo_bar: "fooBar".to_string(),
-            qux_baz: -64.0,
-            raw_struct: 32,
-        }
-    );
-
-    assert_eq!(
-        rt.export_fp_enum(FpVariantRenaming::FooBar)?,
-        FpVariantRenaming::QuxBaz {
-            foo_bar: "foo_bar".to_string(),
-            qux_baz: 64.0
-        }
-    );
-
-    assert_eq!(
-        rt.export_serde_struct(SerdePropertyRenaming {
-            foo_bar: "foo_bar".to_string(),
-            qux_baz: 64.0,
-            raw_struct: -32
-        })?,
-        SerdePropertyRenaming {
-            foo_bar: "fooBar".to_string(),
-            qux_baz: -64.0,
-            raw_struct: 32,
-        }
-    );
-
-    assert_eq!(
-        rt.export_serde_enum(SerdeVariantRenaming::FooBar)?,
+    expect(rt.export_fp_struct(FpPropertyRenaming {
+        foo_bar: "foo_bar".to_string(),
+        qux_baz: 64.0,
+        raw_struct: -32,
+    }))
+    .to_return(FpPropertyRenaming {
+        foo_bar: "fooBar".to_string(),
+        qux_baz: -64.0,
+        raw_struct: 32,
+    });
+
+    expect(rt.export_fp_enum(FpVariantRenaming::FooBar)).to_return(FpVariantRenaming::QuxBaz {
+        foo_bar: "foo_bar".to_string(),
+        qux_baz: 64.0,
+    });
+
+    expect(rt.export_serde_struct(SerdePropertyRenaming {
+        foo_bar: "foo_bar".to_string(),
+        qux_baz: 64.0,
+        raw_struct: -32,
+    }))
+    .to_return(SerdePropertyRenaming {
+        foo_bar: "fooBar".to_string(),
+        qux_baz: -64.0,
+        raw_struct: 32,
+    });
+
+    expect(rt.export_serde_enum(SerdeVariantRenaming::FooBar)).to_return(
         SerdeVariantRenaming::QuxBaz {
             foo_bar: "foo_bar".to_string(),
             qux_baz: 64.0,
@@ -145,32 +160,30 @@ fn flattened_structs() -> Result<()> {
 fn generics() -> Result<()> {
     let rt = new_runtime()?;
 
-    assert_eq!(
-        rt.export_generics(StructWithGenerics {
-            list: vec![0, 64],
-            points: vec![Point { value: 64 }],
-            recursive: vec![Point {
-                value: Point { value: 64 }
-            }],
-            complex_nested: Some(BTreeMap::from([
-                ("one".to_owned(), vec![Point { value: 1.0 }]),
-                ("two".to_owned(), vec![Point { value: 2.0 }])
-            ])),
-            optional_timestamp: Some(MyDateTime(OffsetDateTime::UNIX_EPOCH))
-        })?,
-        StructWithGenerics {
-            list: vec![0, 64],
-            points: vec![Point { value: 64 }],
-            recursive: vec![Point {
-                value: Point { value: 64 },
-            }],
-            complex_nested: Some(BTreeMap::from([
-                ("een".to_owned(), vec![Point { value: 1.0 }]),
-                ("twee".to_owned(), vec![Point { value: 2.0 }]),
-            ])),
-            optional_timestamp: Some(MyDateTime(OffsetDateTime::UNIX_EPOCH)),
-        }
-    );
+    expect(rt.export_generics(StructWithGenerics {
+        list: vec![0, 64],
+        points: vec![Point { value: 64 }],
+        recursive: vec![Point {
+            value: Point { value: 64 },
+        }],
+        complex_nested: Some(BTreeMap::from([
+            ("one".to_owned(), vec![Point { value: 1.0 }]),
+            ("two".to_owned(), vec![Point { value: 2.0 }]),
+        ])),
+        optional_timestamp: Some(MyDateTime(OffsetDateTime::UNIX_EPOCH)),
+    }))
+    .to_return(StructWithGenerics {
+        list: vec![0, 64],
+        points: vec![Point { value: 64 }],
+        recursive: vec![Point {
+            value: Point { value: 64 },
+        }],
+        complex_nested: Some(BTreeMap::from([
+            ("een".to_owned(), vec![Point { value: 1.0 }]),
+            ("twee".to_owned(), vec![Point { value: 2.0 }]),
+        ])),
+        optional_timestamp: Some(MyDateTime(OffsetDateTime::UNIX_EPOCH)),
+    });
     Ok(())
 }
 
@@ -178,35 +191,31 @@ fn generics() -> Result<()> {
 fn property_renaming() -> Result<()> {
     let rt = new_runtime()?;
 
-    assert_eq!(
-        rt.export_fp_flatten(FpFlatten {
-            flattened: FlattenedStruct {
-                foo: "Hello, 🇳🇱!".to_owned(),
-                bar: -64,
-            }
-        })?,
-        FpFlatten {
-            flattened: FlattenedStruct {
-                foo: "Hello, 🇩🇪!".to_owned(),
-                bar: -64,
-            },
-        }
-    );
+    expect(rt.export_fp_flatten(FpFlatten {
+        flattened: FlattenedStruct {
+            foo: "Hello, 🇳🇱!".to_owned(),
+            bar: -64,
+        },
+    }))
+    .to_return(FpFlatten {
+        flattened: FlattenedStruct {
+            foo: "Hello, 🇩🇪!".to_owned(),
+            bar: -64,
+        },
+    });
 
-    assert_eq!(
-        rt.export_serde_flatten(SerdeFlatten {
-            flattened: FlattenedStruct {
-                foo: "Hello, 🇳🇱!".to_owned(),
-                bar: -64,
-            }
-        })?,
-        SerdeFlatten {
-            flattened: FlattenedStruct {
-                foo: "Hello, 🇩🇪!".to_owned(),
-                bar: -64,
-            },
-        }
-    );
+    expect(rt.export_serde_flatten(SerdeFlatten {
+        flattened: FlattenedStruct {
+            foo: "Hello, 🇳🇱!".to_owned(),
+            bar: -64,
+        },
+    }))
+    .to_return(SerdeFlatten {
+        flattened: FlattenedStruct {
+            foo: "Hello, 🇩🇪!".to_owned(),
+            bar: -64,
+        },
+    });
 
     Ok(())
 }
@@ -214,30 +223,20 @@ fn property_renaming() -> Result<()> {
 #[test]
 fn tagged_enums() -> Result<()> {
     let rt = new_runtime()?;
-    assert_eq!(
-        rt.export_fp_adjacently_tagged(FpAdjacentlyTagged::Bar("Hello, plugin!".to_owned()))?,
-        FpAdjacentlyTagged::Baz { a: -8, b: 64 }
-    );
-    assert_eq!(
-        rt.export_fp_internally_tagged(FpInternallyTagged::Foo)?,
-        FpInternallyTagged::Baz { a: -8, b: 64 }
-    );
-    assert_eq!(
-        rt.export_fp_untagged(FpUntagged::Bar("Hello, plugin!".to_owned()))?,
-        FpUntagged::Baz { a: -8, b: 64 }
-    );
-    assert_eq!(
-        rt.export_serde_adjacently_tagged(SerdeAdjacentlyTagged::Bar("Hello, plugin!".to_owned()))?,
-        SerdeAdjacentlyTagged::Baz { a: -8, b: 64 }
-    );
-    assert_eq!(
-        rt.export_serde_internally_tagged(SerdeInternallyTagged::Foo)?,
-        SerdeInternallyTagged::Baz { a: -8, b: 64 }
-    );
-    assert_eq!(
-        rt.export_serde_untagged(SerdeUntagged::Bar("Hello, plugin!".to_owned()))?,
-        SerdeUntagged::Baz { a: -8, b: 64 }
-    );
+    expect(rt.export_fp_adjacently_tagged(FpAdjacentlyTagged::Bar("Hello, plugin!".to_owned())))
+        .to_return(FpAdjacentlyTagged::Baz { a: -8, b: 64 });
+    expect(rt.export_fp_internally_tagged(FpInternallyTagged::Foo))
+        .to_return(FpInternallyTagged::Baz { a: -8, b: 64 });
+    expect(rt.export_fp_untagged(FpUntagged::Bar("Hello, plugin!".to_owned())))
+        .to_return(FpUntagged::Baz { a: -8, b: 64 });
+    expect(
+        rt.export_serde_adjacently_tagged(SerdeAdjacentlyTagged::Bar("Hello, plugin!".to_owned())),
+    )
+    .to_return(SerdeAdjacentlyTagged::Baz { a: -8, b: 64 });
+    expect(rt.export_serde_internally_tagged(SerdeInternallyTagged::Foo))
+        .to_return(SerdeInternallyTagged::Baz { a: -8, b: 64 });
+    expect(rt.export_serde_untagged(SerdeUntagged::Bar("Hello, plugin!".to_owned())))
+        .to_return(SerdeUntagged::Baz { a: -8, b: 64 });
     Ok(())
 }
 
@@ -245,32 +244,54 @@ fn tagged_enums() -> Result<()> {
 async fn async_primitives() -> Result<()> {
     let rt = new_runtime()?;
 
-    assert_eq!(rt.export_primitive_bool_negate_async(true).await?, false);
-    assert_eq!(rt.export_primitive_bool_negate_async(false).await?, true);
+    expect(rt.export_primitive_bool_negate_async(true).await).to_return(false);
+    expect(rt.export_primitive_bool_negate_async(false).await).to_return(true);
 
     // FIXME: Imported functions get passed 0.0 instead of the float argument when called from a plugin.
     // See https://github.com/fiberplane/fp-bindgen/issues/180
-    // assert_eq!(rt.export_primitive_f32_add_three_async(3.5).await?, 3.5 + 3.0);
-    // assert_eq!(rt.export_primitive_f64_add_three_async(2.5).await?, 2.5 + 3.0);
-
-    assert_eq!(rt.export_primitive_u8_add_three_async(8).await?, 8 + 3);
-    assert_eq!(rt.export_primitive_u16_add_three_async(16).await?, 16 + 3);
-    assert_eq!(rt.export_primitive_u32_add_three_async(32).await?, 32 + 3);
-    assert_eq!(rt.export_primitive_u64_add_three_async(64).await?, 64 + 3);
-    assert_eq!(rt.export_primitive_i8_add_three_async(-8).await?, -8 + 3);
-    assert_eq!(rt.export_primitive_i16_add_three_async(-16).await?, -16 + 3);
-    assert_eq!(rt.export_primitive_i32_add_three_async(-32).await?, -32 + 3);
-    assert_eq!(rt.export_primitive_i64_add_three_async(-64).await?, -64 + 3);
+    // expect(rt.export_primitive_f32_add_three_async(3.5).await).to_return(3.5 + 3.0);
+    // expect(rt.export_primitive_f64_add_three_async(2.5).await).to_return(2.5 + 3.0);
+
+    expect(rt.export_primitive_u8_add_three_async(8).await).to_return(8 + 3);
+    expect(rt.export_primitive_u16_add_three_async(16).await).to_return(16 + 3);
+    expect(rt.export_primitive_u32_add_three_async(32).await).to_return(32 + 3);
+    expect(rt.export_primitive_u64_add_three_async(64).await).to_return(64 + 3);
+    expect(rt.export_primitive_i8_add_three_async(-8).await).to_return(-8 + 3);
+    expect(rt.export_primitive_i16_add_three_async(-16).await).to_return(-16 + 3);
+    expect(rt.export_primitive_i32_add_three_async(-32).await).to_return(-32 + 3);
+    expect(rt.export_primitive_i64_add_three_async(-64).await).to_return(-64 + 3);
 
     // Test void primitive return as well
-    rt.export_reset_global_state().await?;
-    rt.export_increment_global_state().await?;
-    assert_eq!(*GLOBAL_STATE.lock().unwrap(), 1);
-
-    rt.export_reset_global_state().await?;
-    rt.export_increment_global_state().await?;
-    rt.export_increment_global_state().await?;
-    assert_eq!(*GLOBAL_STATE.lock().unwrap(), 2);
+    let global_state = Arc::new(Mutex::new(0));
+    let rt = new_runtime_with_state(global_state.clone())?;
+
+    expect(rt.export_reset_global_state().await).to_succeed();
+    expect(rt.export_increment_global_state().await).to_succeed();
+    assert_eq!(*global_state.lock().unwrap(), 1);
+
+    expect(rt.export_reset_global_state().await).to_succeed();
+    expect(rt.export_increment_global_state().await).to_succeed();
+    expect(rt.export_increment_global_state().await).to_succeed();
+    assert_eq!(*global_state.lock().unwrap(), 2);
+    rt.imports()
+        .assert_call_count("import_increment_global_state", 3);
+
+    // Every runtime instance has its own state
+    let other_global_state = Arc::new(Mutex::new(0));
+    let other_rt = new_runtime_with_state(other_global_state.clone())?;
+
+    expect(rt.export_increment_global_state().await).to_succeed();
+    assert_eq!(*global_state.lock().unwrap(), 3);
+    assert_eq!(*other_global_state.lock().unwrap(), 0);
+
+    expect(other_rt.export_increment_global_state().await).to_succeed();
+    assert_eq!(*global_state.lock().unwrap(), 3);
+    assert_eq!(*other_global_state.lock().unwrap(), 1);
+    rt.imports()
+        .assert_call_count("import_increment_global_state", 4);
+    other_rt
+        .imports()
+        .assert_call_count("import_increment_global_state", 1);
 
     Ok(())
 }
@@ -279,22 +300,22 @@ async fn async_primitives() -> Result<()> {
 async fn async_struct() -> Result<()> {
     let rt = new_runtime()?;
 
-    assert_eq!(
+    expect(
         rt.export_async_struct(
             FpPropertyRenaming {
                 foo_bar: "foo_bar".to_owned(),
                 qux_baz: 64.0,
-                raw_struct: -32
+                raw_struct: -32,
             },
-            64
+            64,
         )
-        .await?,
-        FpPropertyRenaming {
-            foo_bar: "fooBar".to_owned(),
-            qux_baz: -64.0,
-            raw_struct: 32,
-        }
-    );
+        .await,
+    )
+    .to_return(FpPropertyRenaming {
+        foo_bar: "fooBar".to_owned(),
+        qux_baz: -64.0,
+        raw_struct: 32,
+    });
     Ok(())
 }
 
@@ -302,9 +323,27 @@ async fn async_struct() -> Result<()> {
 async fn fetch_async_data() -> Result<()> {
     let rt = new_runtime()?;
 
-    let response = rt.fetch_data("sign-up".to_string()).await?;
+    expect(rt.fetch_data("sign-up".to_string()).await)
+        .to_return(Ok(r#"{"status":"confirmed"}"#.to_string()));
+    rt.imports().assert_call_count("make_http_request", 1);
+
+    Ok(())
+}
+
+#[test]
+fn fetch_async_data_without_tokio() -> Result<()> {
+    let pool = ThreadPool::new()?;
+    let rt = PluginTest::new(WASM_BYTES, |wasm, mock| -> Result<Runtime> {
+        let host = RecordingImports::new(mock, Host::default());
+        let rt = Runtime::with_spawner(wasm, host, move |future| pool.spawn_ok(future))?;
+        rt.init()?;
+        Ok(rt)
+    })?;
+
+    expect(block_on(rt.fetch_data("sign-up".to_string())))
+        .to_return(Ok(r#"{"status":"confirmed"}"#.to_string()));
+    rt.imports().assert_call_count("make_http_request", 1);
 
-    assert_eq!(response, Ok(r#"{"status":"confirmed"}"#.to_string()));
     Ok(())
 }
 
@@ -312,14 +351,122 @@ async fn fetch_async_data() -> Result<()> {
 fn bytes() -> Result<()> {
     let rt = new_runtime()?;
 
-    assert_eq!(rt.export_get_bytes()?, Ok(Bytes::from("hello, world")));
-    assert_eq!(rt.export_get_serde_bytes()?, Ok(ByteBuf::from("hello, world")));
+    expect(rt.export_get_bytes()).to_return(Ok(Bytes::from("hello, world")));
+    expect(rt.export_get_serde_bytes()).to_return(Ok(ByteBuf::from("hello, world")));
 
     Ok(())
 }
 
-fn new_runtime() -> Result<Runtime> {
-    let rt = Runtime::new(WASM_BYTES)?;
-    rt.init()?;
-    Ok(rt)
+#[test]
+fn guest_panic() -> Result<()> {
+    let rt = new_runtime()?;
+
+    // The plugin replaces the panic hook in `init()`, which should not keep
+    // the panic from being reported:
+    match expect(rt.export_panic("Oh no!".to_owned())).to_fail() {
+        InvocationError::GuestPanic { message, location } => {
+            assert_eq!(message, "Oh no!");
+            assert!(location.unwrap().contains("lib.rs"));
+        }
+        error => panic!("Expected a guest panic, got: {:?}", error),
+    }
+
+    // The hook of the plugin is still called as well:
+    let logged = rt.imports().calls_to("log");
+    assert!(logged
+        .iter()
+        .any(|args| args[0].as_str().unwrap().contains("Oh no!")));
+
+    Ok(())
+}
+
+// The WASI runtime requires plugins to import WASI.
+#[cfg(not(feature = "wasi"))]
+#[test]
+fn initialize() -> Result<()> {
+    // The protocol version of this plugin is only correct once its
+    // `_initialize` export was called:
+    let plugin = plugin_with_fields(&format!(
+        r#"(global $protocol_version (mut i64) (i64.const 0))
+        (func (export "_initialize")
+            (global.set $protocol_version (i64.const {PROTOCOL_VERSION})))
+        (func (export "__fp_protocol_version") (result i64)
+            (global.get $protocol_version))"#
+    ));
+    Runtime::new(&plugin, Host::default())?;
+
+    // Plugins that fail to initialize are rejected:
+    let plugin = plugin_with_fields(r#"(func (export "_initialize") (unreachable))"#);
+    assert!(matches!(
+        Runtime::new_unchecked(&plugin, Host::default()),
+        Err(RuntimeError::InitializationFailed(_))
+    ));
+
+    Ok(())
+}
+
+#[cfg(not(feature = "wasi"))]
+#[test]
+fn protocol_check() -> Result<()> {
+    // A plugin that was built against another version of the protocol:
+    let plugin = plugin_with_protocol_version("(result i64) (i64.const 1)");
+    match Runtime::new(&plugin, Host::default()) {
+        Err(RuntimeError::ProtocolMismatch { expected, found }) => {
+            assert_eq!(expected, PROTOCOL_VERSION);
+            assert_eq!(found, 1);
+        }
+        other => panic!("Expected a protocol mismatch, got: {:?}", other.err()),
+    }
+    Runtime::new_unchecked(&plugin, Host::default())?;
+
+    // Plugins with a protocol version that cannot be determined are rejected as
+    // well:
+    let plugin = plugin_with_protocol_version("(result i32) (i32.const 1)");
+    assert!(matches!(
+        Runtime::new(&plugin, Host::default()),
+        Err(RuntimeError::ProtocolCheckFailed(_))
+    ));
+    let plugin = plugin_with_protocol_version("(result i64) (unreachable)");
+    assert!(matches!(
+        Runtime::new(&plugin, Host::default()),
+        Err(RuntimeError::ProtocolCheckFailed(_))
+    ));
+
+    Ok(())
+}
+
+fn new_runtime() -> Result<PluginTest<Runtime>> {
+    new_runtime_with_state(Default::default())
+}
+
+/// Loads the plugin with a `Host` that uses the given global state, so tests
+/// can inspect it.
+fn new_runtime_with_state(global_state: Arc<Mutex<u32>>) -> Result<PluginTest<Runtime>> {
+    PluginTest::new(WASM_BYTES, |wasm, mock| {
+        let rt = Runtime::new(wasm, RecordingImports::new(mock, Host { global_state }))?;
+        rt.init()?;
+        Ok(rt)
+    })
+}
+
+/// Returns a minimal plugin in the WebAssembly text format, which exports a
+/// `__fp_protocol_version` function with the given signature and body.
+#[cfg(not(feature = "wasi"))]
+fn plugin_with_protocol_version(function: &str) -> String {
+    plugin_with_fields(&format!(
+        r#"(func (export "__fp_protocol_version") {function})"#
+    ))
+}
+
+/// Returns a minimal plugin in the WebAssembly text format, which exports the
+/// functions a runtime relies on, in addition to the given fields.
+#[cfg(not(feature = "wasi"))]
+fn plugin_with_fields(fields: &str) -> String {
+    format!(
+        r#"(module
+            (memory (export "memory") 1)
+            (func (export "__fp_malloc") (param i32) (result i64) (i64.const 0))
+            (func (export "__fp_free") (param i64))
+            {fields})"#
+    )
 }
@@ -0,0 +1 @@
+../../../example-protocol/bindings/rust-wasmer2-wasi-runtime/testing.rs
\ No newline at end of file
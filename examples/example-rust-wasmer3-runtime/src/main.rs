@@ -0,0 +1,10 @@
+mod spec;
+#[cfg(test)]
+mod test;
+
+#[cfg(test)]
+use fp_bindgen_support::wasmer3_host::errors::{InvocationError, RuntimeError};
+
+fn main() {
+    println!("Hello, world!");
+}
@@ -0,0 +1 @@
+../../../example-protocol/bindings/rust-wasmer3-runtime/bindings.rs
\ No newline at end of file
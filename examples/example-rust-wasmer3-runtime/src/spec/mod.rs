@@ -0,0 +1 @@
+../../../example-rust-wasmer2-runtime/src/spec/mod.rs
\ No newline at end of file
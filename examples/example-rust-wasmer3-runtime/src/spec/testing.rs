@@ -0,0 +1 @@
+../../../example-protocol/bindings/rust-wasmer3-runtime/testing.rs
\ No newline at end of file
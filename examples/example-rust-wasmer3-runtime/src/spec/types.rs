@@ -0,0 +1 @@
+../../../example-protocol/bindings/rust-wasmer3-runtime/types.rs
\ No newline at end of file
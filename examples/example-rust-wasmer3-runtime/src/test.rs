@@ -0,0 +1,435 @@
+use crate::spec::{bindings::{Runtime, PROTOCOL_VERSION}, testing::RecordingImports, Host};
+use crate::spec::types::*;
+use crate::{InvocationError, RuntimeError};
+use anyhow::Result;
+use bytes::Bytes;
+use fp_bindgen_testing::MockHost;
+use futures::executor::{block_on, ThreadPool};
+use serde_bytes::ByteBuf;
+use std::collections::BTreeMap;
+use time::{macros::datetime, OffsetDateTime};
+
+const WASM_BYTES: &'static [u8] =
+    include_bytes!("../../example-plugin/target/wasm32-unknown-unknown/debug/example_plugin.wasm");
+
+
+#[test]
+fn primitives() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(rt.export_primitive_bool_negate(true)?, false);
+    assert_eq!(rt.export_primitive_bool_negate(false)?, true);
+
+    assert_eq!(rt.export_primitive_u8_add_three(8)?, 8 + 3);
+    assert_eq!(rt.export_primitive_u16_add_three(16)?, 16 + 3);
+    assert_eq!(rt.export_primitive_u32_add_three(32)?, 32 + 3);
+    assert_eq!(rt.export_primitive_u64_add_three(64)?, 64 + 3);
+    assert_eq!(rt.export_primitive_i8_add_three(-8)?, -8 + 3);
+    assert_eq!(rt.export_primitive_i16_add_three(-16)?, -16 + 3);
+    assert_eq!(rt.export_primitive_i32_add_three(-32)?, -32 + 3);
+    assert_eq!(rt.export_primitive_i64_add_three(-64)?, -64 + 3);
+
+    assert_eq!(
+        rt.export_multiple_primitives(-8, "Hello, 🇳🇱!".to_string())?,
+        -64
+    );
+
+    // Precise float comparison is fine as long as the denominator is a power of two
+    assert_eq!(rt.export_primitive_f32_add_three(3.5)?, 3.5 + 3.0);
+    assert_eq!(rt.export_primitive_f64_add_three(2.5)?, 2.5 + 3.0);
+
+    Ok(())
+}
+
+#[test]
+fn arrays() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(rt.export_array_u8([1u8, 2u8, 3u8])?, [1u8, 2u8, 3u8]);
+    assert_eq!(rt.export_array_u16([1u16, 2u16, 3u16])?, [1u16, 2u16, 3u16]);
+    assert_eq!(rt.export_array_u32([1u32, 2u32, 3u32])?, [1u32, 2u32, 3u32]);
+    assert_eq!(rt.export_array_i8([1i8, 2i8, 3i8])?, [1i8, 2i8, 3i8]);
+    assert_eq!(rt.export_array_i16([1i16, 2i16, 3i16])?, [1i16, 2i16, 3i16]);
+    assert_eq!(rt.export_array_i32([1i32, 2i32, 3i32])?, [1i32, 2i32, 3i32]);
+    assert_eq!(rt.export_array_f32([1f32, 2f32, 3f32])?, [1f32, 2f32, 3f32]);
+    assert_eq!(rt.export_array_f64([1f64, 2f64, 3f64])?, [1f64, 2f64, 3f64]);
+    Ok(())
+}
+
+#[test]
+fn string() -> Result<()> {
+    let rt = new_runtime()?;
+    assert_eq!(
+        rt.export_string("Hello, plugin!".to_string())?,
+        "Hello, world!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn timestamp() -> Result<()> {
+    let rt = new_runtime()?;
+    assert_eq!(
+        rt.export_timestamp(MyDateTime(datetime!(2022-04-12 19:10 UTC)))?,
+        MyDateTime(datetime!(2022-04-13 12:37 UTC))
+    );
+    Ok(())
+}
+
+#[test]
+fn flattened_structs() -> Result<()> {
+    let rt = new_runtime()?;
+    assert_eq!(
+        rt.export_fp_struct(FpPropertyRenaming {
+            foo_bar: "foo_bar".to_string(),
+            qux_baz: 64.0,
+            raw_struct: -32,
+        })?,
+        FpPropertyRenaming {
+            foo_bar: "fooBar".to_string(),
+            qux_baz: -64.0,
+            raw_struct: 32,
+        }
+    );
+
+    assert_eq!(
+        rt.export_fp_enum(FpVariantRenaming::FooBar)?,
+        FpVariantRenaming::QuxBaz {
+            foo_bar: "foo_bar".to_string(),
+            qux_baz: 64.0
+        }
+    );
+
+    assert_eq!(
+        rt.export_serde_struct(SerdePropertyRenaming {
+            foo_bar: "foo_bar".to_string(),
+            qux_baz: 64.0,
+            raw_struct: -32
+        })?,
+        SerdePropertyRenaming {
+            foo_bar: "fooBar".to_string(),
+            qux_baz: -64.0,
+            raw_struct: 32,
+        }
+    );
+
+    assert_eq!(
+        rt.export_serde_enum(SerdeVariantRenaming::FooBar)?,
+        SerdeVariantRenaming::QuxBaz {
+            foo_bar: "foo_bar".to_string(),
+            qux_baz: 64.0,
+        },
+    );
+
+    Ok(())
+}
+
+#[test]
+fn generics() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(
+        rt.export_generics(StructWithGenerics {
+            list: vec![0, 64],
+            points: vec![Point { value: 64 }],
+            recursive: vec![Point {
+                value: Point { value: 64 }
+            }],
+            complex_nested: Some(BTreeMap::from([
+                ("one".to_owned(), vec![Point { value: 1.0 }]),
+                ("two".to_owned(), vec![Point { value: 2.0 }])
+            ])),
+            optional_timestamp: Some(MyDateTime(OffsetDateTime::UNIX_EPOCH))
+        })?,
+        StructWithGenerics {
+            list: vec![0, 64],
+            points: vec![Point { value: 64 }],
+            recursive: vec![Point {
+                value: Point { value: 64 },
+            }],
+            complex_nested: Some(BTreeMap::from([
+                ("een".to_owned(), vec![Point { value: 1.0 }]),
+                ("twee".to_owned(), vec![Point { value: 2.0 }]),
+            ])),
+            optional_timestamp: Some(MyDateTime(OffsetDateTime::UNIX_EPOCH)),
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn property_renaming() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(
+        rt.export_fp_flatten(FpFlatten {
+            flattened: FlattenedStruct {
+                foo: "Hello, 🇳🇱!".to_owned(),
+                bar: -64,
+            }
+        })?,
+        FpFlatten {
+            flattened: FlattenedStruct {
+                foo: "Hello, 🇩🇪!".to_owned(),
+                bar: -64,
+            },
+        }
+    );
+
+    assert_eq!(
+        rt.export_serde_flatten(SerdeFlatten {
+            flattened: FlattenedStruct {
+                foo: "Hello, 🇳🇱!".to_owned(),
+                bar: -64,
+            }
+        })?,
+        SerdeFlatten {
+            flattened: FlattenedStruct {
+                foo: "Hello, 🇩🇪!".to_owned(),
+                bar: -64,
+            },
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn tagged_enums() -> Result<()> {
+    let rt = new_runtime()?;
+    assert_eq!(
+        rt.export_fp_adjacently_tagged(FpAdjacentlyTagged::Bar("Hello, plugin!".to_owned()))?,
+        FpAdjacentlyTagged::Baz { a: -8, b: 64 }
+    );
+    assert_eq!(
+        rt.export_fp_internally_tagged(FpInternallyTagged::Foo)?,
+        FpInternallyTagged::Baz { a: -8, b: 64 }
+    );
+    assert_eq!(
+        rt.export_fp_untagged(FpUntagged::Bar("Hello, plugin!".to_owned()))?,
+        FpUntagged::Baz { a: -8, b: 64 }
+    );
+    assert_eq!(
+        rt.export_serde_adjacently_tagged(SerdeAdjacentlyTagged::Bar("Hello, plugin!".to_owned()))?,
+        SerdeAdjacentlyTagged::Baz { a: -8, b: 64 }
+    );
+    assert_eq!(
+        rt.export_serde_internally_tagged(SerdeInternallyTagged::Foo)?,
+        SerdeInternallyTagged::Baz { a: -8, b: 64 }
+    );
+    assert_eq!(
+        rt.export_serde_untagged(SerdeUntagged::Bar("Hello, plugin!".to_owned()))?,
+        SerdeUntagged::Baz { a: -8, b: 64 }
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_primitives() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(rt.export_primitive_bool_negate_async(true).await?, false);
+    assert_eq!(rt.export_primitive_bool_negate_async(false).await?, true);
+
+    assert_eq!(rt.export_primitive_f32_add_three_async(3.5).await?, 3.5 + 3.0);
+    assert_eq!(rt.export_primitive_f64_add_three_async(2.5).await?, 2.5 + 3.0);
+
+    assert_eq!(rt.export_primitive_u8_add_three_async(8).await?, 8 + 3);
+    assert_eq!(rt.export_primitive_u16_add_three_async(16).await?, 16 + 3);
+    assert_eq!(rt.export_primitive_u32_add_three_async(32).await?, 32 + 3);
+    assert_eq!(rt.export_primitive_u64_add_three_async(64).await?, 64 + 3);
+    assert_eq!(rt.export_primitive_i8_add_three_async(-8).await?, -8 + 3);
+    assert_eq!(rt.export_primitive_i16_add_three_async(-16).await?, -16 + 3);
+    assert_eq!(rt.export_primitive_i32_add_three_async(-32).await?, -32 + 3);
+    assert_eq!(rt.export_primitive_i64_add_three_async(-64).await?, -64 + 3);
+
+    // Test void primitive return as well
+    let host = Host::default();
+    let rt = Runtime::new(WASM_BYTES, host.clone())?;
+    rt.init()?;
+
+    rt.export_reset_global_state().await?;
+    rt.export_increment_global_state().await?;
+    assert_eq!(*host.global_state.lock().unwrap(), 1);
+
+    rt.export_reset_global_state().await?;
+    rt.export_increment_global_state().await?;
+    rt.export_increment_global_state().await?;
+    assert_eq!(*host.global_state.lock().unwrap(), 2);
+
+    // Every runtime instance has its own state
+    let other_host = Host::default();
+    let other_rt = Runtime::new(WASM_BYTES, other_host.clone())?;
+    other_rt.init()?;
+
+    rt.export_increment_global_state().await?;
+    assert_eq!(*host.global_state.lock().unwrap(), 3);
+    assert_eq!(*other_host.global_state.lock().unwrap(), 0);
+
+    other_rt.export_increment_global_state().await?;
+    assert_eq!(*host.global_state.lock().unwrap(), 3);
+    assert_eq!(*other_host.global_state.lock().unwrap(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn async_struct() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(
+        rt.export_async_struct(
+            FpPropertyRenaming {
+                foo_bar: "foo_bar".to_owned(),
+                qux_baz: 64.0,
+                raw_struct: -32
+            },
+            64
+        )
+        .await?,
+        FpPropertyRenaming {
+            foo_bar: "fooBar".to_owned(),
+            qux_baz: -64.0,
+            raw_struct: 32,
+        }
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn fetch_async_data() -> Result<()> {
+    let rt = new_runtime()?;
+
+    let response = rt.fetch_data("sign-up".to_string()).await?;
+
+    assert_eq!(response, Ok(r#"{"status":"confirmed"}"#.to_string()));
+    Ok(())
+}
+
+#[test]
+fn fetch_async_data_without_tokio() -> Result<()> {
+    let pool = ThreadPool::new()?;
+    let rt = Runtime::with_spawner(WASM_BYTES, Host::default(), move |future| {
+        pool.spawn_ok(future)
+    })?;
+    rt.init()?;
+
+    let response = block_on(rt.fetch_data("sign-up".to_string()))?;
+
+    assert_eq!(response, Ok(r#"{"status":"confirmed"}"#.to_string()));
+    Ok(())
+}
+
+#[test]
+fn bytes() -> Result<()> {
+    let rt = new_runtime()?;
+
+    assert_eq!(rt.export_get_bytes()?, Ok(Bytes::from("hello, world")));
+    assert_eq!(rt.export_get_serde_bytes()?, Ok(ByteBuf::from("hello, world")));
+
+    Ok(())
+}
+
+#[test]
+fn guest_panic() -> Result<()> {
+    let mock = MockHost::new();
+    let rt = Runtime::new(WASM_BYTES, RecordingImports::new(mock.clone(), Host::default()))?;
+    rt.init()?;
+
+    // The plugin replaces the panic hook in `init()`, which should not keep
+    // the panic from being reported:
+    match rt.export_panic("Oh no!".to_owned()) {
+        Err(InvocationError::GuestPanic { message, location }) => {
+            assert_eq!(message, "Oh no!");
+            assert!(location.unwrap().contains("lib.rs"));
+        }
+        other => panic!("Expected a guest panic, got: {:?}", other),
+    }
+
+    // The hook of the plugin is still called as well:
+    let logged = mock.calls_to("log");
+    assert!(logged
+        .iter()
+        .any(|args| args[0].as_str().unwrap().contains("Oh no!")));
+
+    Ok(())
+}
+
+#[test]
+fn protocol_check() -> Result<()> {
+    // A plugin that was built against another version of the protocol:
+    let plugin = plugin_with_protocol_version("(result i64) (i64.const 1)");
+    match Runtime::new(&plugin, Host::default()) {
+        Err(RuntimeError::ProtocolMismatch { expected, found }) => {
+            assert_eq!(expected, PROTOCOL_VERSION);
+            assert_eq!(found, 1);
+        }
+        other => panic!("Expected a protocol mismatch, got: {:?}", other.err()),
+    }
+    Runtime::new_unchecked(&plugin, Host::default())?;
+
+    // Plugins with a protocol version that cannot be determined are rejected as
+    // well:
+    let plugin = plugin_with_protocol_version("(result i32) (i32.const 1)");
+    assert!(matches!(
+        Runtime::new(&plugin, Host::default()),
+        Err(RuntimeError::ProtocolCheckFailed(_))
+    ));
+    let plugin = plugin_with_protocol_version("(result i64) (unreachable)");
+    assert!(matches!(
+        Runtime::new(&plugin, Host::default()),
+        Err(RuntimeError::ProtocolCheckFailed(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn initialize() -> Result<()> {
+    // The protocol version of this plugin is only correct once its
+    // `_initialize` export was called:
+    let plugin = plugin_with_fields(&format!(
+        r#"(global $protocol_version (mut i64) (i64.const 0))
+        (func (export "_initialize")
+            (global.set $protocol_version (i64.const {PROTOCOL_VERSION})))
+        (func (export "__fp_protocol_version") (result i64)
+            (global.get $protocol_version))"#
+    ));
+    Runtime::new(&plugin, Host::default())?;
+
+    // Plugins that fail to initialize are rejected:
+    let plugin = plugin_with_fields(r#"(func (export "_initialize") (unreachable))"#);
+    assert!(matches!(
+        Runtime::new_unchecked(&plugin, Host::default()),
+        Err(RuntimeError::InitializationFailed(_))
+    ));
+
+    Ok(())
+}
+
+fn new_runtime() -> Result<Runtime> {
+    let rt = Runtime::new(WASM_BYTES, Host::default())?;
+    rt.init()?;
+    Ok(rt)
+}
+
+/// Returns a minimal plugin in the WebAssembly text format, which exports a
+/// `__fp_protocol_version` function with the given signature and body.
+fn plugin_with_protocol_version(function: &str) -> String {
+    plugin_with_fields(&format!(
+        r#"(func (export "__fp_protocol_version") {function})"#
+    ))
+}
+
+/// Returns a minimal plugin in the WebAssembly text format, which exports the
+/// functions a runtime relies on, in addition to the given fields.
+fn plugin_with_fields(fields: &str) -> String {
+    format!(
+        r#"(module
+            (memory (export "memory") 1)
+            (func (export "__fp_malloc") (param i32) (result i64) (i64.const 0))
+            (func (export "__fp_free") (param i64))
+            {fields})"#
+    )
+}
@@ -0,0 +1 @@
+../../../example-protocol/bindings/rust-wasmtime-runtime/bindings.rs
\ No newline at end of file
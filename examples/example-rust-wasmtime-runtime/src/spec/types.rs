@@ -0,0 +1 @@
+../../../example-protocol/bindings/rust-wasmtime-runtime/types.rs
\ No newline at end of file
@@ -0,0 +1 @@
+../../example-rust-wasmer3-runtime/src/test.rs
\ No newline at end of file
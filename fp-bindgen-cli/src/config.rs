@@ -0,0 +1,515 @@
+use fp_bindgen::{
+    prelude::{
+        ApiReferenceConfig, ApiReferenceFormat, BindingsType, GoWazeroRuntimeConfig,
+        RustPluginConfig, RustPluginConfigValue, TinyGoPluginConfig, TsRuntimeConfig,
+        WitWorldConfig,
+    },
+    types::CargoDependency,
+};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+/// Contents of an `fp-bindgen.toml` file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Where to load the protocol from.
+    pub protocol: ProtocolSource,
+
+    /// The bindings to generate for the protocol.
+    #[serde(default)]
+    pub bindings: Vec<BindingsEntry>,
+}
+
+impl Config {
+    pub fn from_toml(toml: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml)
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProtocolSource {
+    /// Path to a JSON file, as generated by `BindingsType::ProtocolJson`.
+    Json(PathBuf),
+
+    /// Path to a protocol crate, which should expose a
+    /// `pub fn protocol() -> fp_bindgen::Protocol` from its library target.
+    Crate(PathBuf),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BindingsEntry {
+    /// Directory to write the bindings to.
+    pub path: PathBuf,
+
+    #[serde(flatten)]
+    pub bindings_type: BindingsTypeConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", tag = "type")]
+pub enum BindingsTypeConfig {
+    ApiReference(ApiReferenceSettings),
+    #[serde(rename = "assemblyscript-plugin")]
+    AssemblyScriptPlugin,
+    CPlugin,
+    GoWazeroRuntime(GoWazeroRuntimeSettings),
+    JsonSchema,
+    ProtocolJson,
+    PythonWasmtimeRuntime,
+    RustPlugin(RustPluginSettings),
+    RustWasmer2Runtime,
+    RustWasmer2WasiRuntime,
+    RustWasmer3Runtime,
+    RustWasmtimeRuntime,
+    #[serde(rename = "tinygo-plugin")]
+    TinyGoPlugin(TinyGoPluginSettings),
+    TsRuntime(TsRuntimeSettings),
+    WitWorld(WitWorldSettings),
+}
+
+impl From<BindingsTypeConfig> for BindingsType {
+    fn from(config: BindingsTypeConfig) -> Self {
+        match config {
+            BindingsTypeConfig::ApiReference(settings) => {
+                BindingsType::ApiReference(settings.into())
+            }
+            BindingsTypeConfig::AssemblyScriptPlugin => BindingsType::AssemblyScriptPlugin,
+            BindingsTypeConfig::CPlugin => BindingsType::CPlugin,
+            BindingsTypeConfig::GoWazeroRuntime(settings) => {
+                BindingsType::GoWazeroRuntime(settings.into())
+            }
+            BindingsTypeConfig::JsonSchema => BindingsType::JsonSchema,
+            BindingsTypeConfig::ProtocolJson => BindingsType::ProtocolJson,
+            BindingsTypeConfig::PythonWasmtimeRuntime => BindingsType::PythonWasmtimeRuntime,
+            BindingsTypeConfig::RustPlugin(settings) => BindingsType::RustPlugin(settings.into()),
+            BindingsTypeConfig::RustWasmer2Runtime => BindingsType::RustWasmer2Runtime,
+            BindingsTypeConfig::RustWasmer2WasiRuntime => BindingsType::RustWasmer2WasiRuntime,
+            BindingsTypeConfig::RustWasmer3Runtime => BindingsType::RustWasmer3Runtime,
+            BindingsTypeConfig::RustWasmtimeRuntime => BindingsType::RustWasmtimeRuntime,
+            BindingsTypeConfig::TinyGoPlugin(settings) => {
+                BindingsType::TinyGoPlugin(settings.into())
+            }
+            BindingsTypeConfig::TsRuntime(settings) => BindingsType::TsRuntime(settings.into()),
+            BindingsTypeConfig::WitWorld(settings) => BindingsType::WitWorld(settings.into()),
+        }
+    }
+}
+
+/// Settings for `BindingsType::ApiReference`. See `ApiReferenceConfig` for
+/// the meaning of the individual fields.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ApiReferenceSettings {
+    pub format: Option<ApiReferenceFormatSetting>,
+    pub title: Option<String>,
+}
+
+impl From<ApiReferenceSettings> for ApiReferenceConfig {
+    fn from(settings: ApiReferenceSettings) -> Self {
+        let mut config = ApiReferenceConfig::new();
+        if let Some(format) = settings.format {
+            config = config.with_format(format.into());
+        }
+        if let Some(title) = settings.title {
+            config = config.with_title(&title);
+        }
+        config
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApiReferenceFormatSetting {
+    Html,
+    Markdown,
+}
+
+impl From<ApiReferenceFormatSetting> for ApiReferenceFormat {
+    fn from(setting: ApiReferenceFormatSetting) -> Self {
+        match setting {
+            ApiReferenceFormatSetting::Html => ApiReferenceFormat::Html,
+            ApiReferenceFormatSetting::Markdown => ApiReferenceFormat::Markdown,
+        }
+    }
+}
+
+/// Settings for `BindingsType::GoWazeroRuntime`. See `GoWazeroRuntimeConfig`
+/// for the meaning of the individual fields.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct GoWazeroRuntimeSettings {
+    pub package_name: Option<String>,
+}
+
+impl From<GoWazeroRuntimeSettings> for GoWazeroRuntimeConfig {
+    fn from(settings: GoWazeroRuntimeSettings) -> Self {
+        let mut config = GoWazeroRuntimeConfig::new();
+        if let Some(package_name) = settings.package_name {
+            config = config.with_package_name(&package_name);
+        }
+        config
+    }
+}
+
+/// Settings for `BindingsType::RustPlugin`. See `RustPluginConfig` for the
+/// meaning of the individual fields.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RustPluginSettings {
+    pub name: String,
+    pub authors: Option<ConfigValue>,
+    pub version: Option<ConfigValue>,
+    pub description: Option<ConfigValue>,
+    pub readme: Option<ConfigValue>,
+    pub license: Option<ConfigValue>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, DependencySettings>,
+}
+
+impl From<RustPluginSettings> for RustPluginConfig {
+    fn from(settings: RustPluginSettings) -> Self {
+        let mut builder = RustPluginConfig::builder().name(settings.name);
+        if let Some(authors) = settings.authors {
+            builder = builder.authors(authors);
+        }
+        if let Some(version) = settings.version {
+            builder = builder.version(version);
+        }
+        if let Some(description) = settings.description {
+            builder = builder.description(description);
+        }
+        if let Some(readme) = settings.readme {
+            builder = builder.readme(readme);
+        }
+        if let Some(license) = settings.license {
+            builder = builder.license(license);
+        }
+        for (name, dependency) in settings.dependencies {
+            builder = builder.dependency(name, dependency.into());
+        }
+        builder.build()
+    }
+}
+
+/// A value for a field in the generated `Cargo.toml`, using the same syntax as
+/// Cargo itself: either a string, an array of strings or `{ workspace = true }`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ConfigValue {
+    String(String),
+    Vec(Vec<String>),
+    Workspace { workspace: bool },
+}
+
+impl From<ConfigValue> for RustPluginConfigValue {
+    fn from(value: ConfigValue) -> Self {
+        match value {
+            ConfigValue::String(value) => RustPluginConfigValue::String(value),
+            ConfigValue::Vec(values) => RustPluginConfigValue::Vec(values),
+            ConfigValue::Workspace { .. } => RustPluginConfigValue::Workspace,
+        }
+    }
+}
+
+/// A dependency for the generated `Cargo.toml`, using the same syntax as Cargo
+/// itself.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum DependencySettings {
+    Version(String),
+    Detailed(DetailedDependency),
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct DetailedDependency {
+    pub branch: Option<String>,
+    pub default_features: Option<bool>,
+    #[serde(default)]
+    pub features: BTreeSet<String>,
+    pub git: Option<String>,
+    pub path: Option<String>,
+    pub registry: Option<String>,
+    pub version: Option<String>,
+    pub workspace: Option<bool>,
+}
+
+impl From<DependencySettings> for CargoDependency {
+    fn from(settings: DependencySettings) -> Self {
+        let settings = match settings {
+            DependencySettings::Version(version) => DetailedDependency {
+                version: Some(version),
+                ..Default::default()
+            },
+            DependencySettings::Detailed(settings) => settings,
+        };
+
+        let mut dependency = CargoDependency::default();
+        dependency.branch = settings.branch.map(leak);
+        dependency.default_features = settings.default_features;
+        dependency.features = settings.features.into_iter().map(leak).collect();
+        dependency.git = settings.git.map(leak);
+        dependency.path = settings.path.map(leak);
+        dependency.registry = settings.registry.map(leak);
+        dependency.version = settings.version.map(leak);
+        dependency.workspace = settings.workspace;
+        dependency
+    }
+}
+
+/// Settings for `BindingsType::TinyGoPlugin`. See `TinyGoPluginConfig` for
+/// the meaning of the individual fields.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct TinyGoPluginSettings {
+    pub package_name: Option<String>,
+}
+
+impl From<TinyGoPluginSettings> for TinyGoPluginConfig {
+    fn from(settings: TinyGoPluginSettings) -> Self {
+        let mut config = TinyGoPluginConfig::new();
+        if let Some(package_name) = settings.package_name {
+            config = config.with_package_name(&package_name);
+        }
+        config
+    }
+}
+
+/// Settings for `BindingsType::TsRuntime`. See `TsRuntimeConfig` for the
+/// meaning of the individual fields.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct TsRuntimeSettings {
+    pub msgpack_module: Option<String>,
+    #[serde(default)]
+    pub generate_raw_export_wrappers: bool,
+    pub streaming_instantiation: Option<bool>,
+}
+
+impl From<TsRuntimeSettings> for TsRuntimeConfig {
+    fn from(settings: TsRuntimeSettings) -> Self {
+        let mut config = TsRuntimeConfig::new();
+        if let Some(msgpack_module) = settings.msgpack_module {
+            config = config.with_msgpack_module(&msgpack_module);
+        }
+        if settings.generate_raw_export_wrappers {
+            config = config.with_raw_export_wrappers();
+        }
+        if settings.streaming_instantiation == Some(false) {
+            config = config.without_streaming_instantiation();
+        }
+        config
+    }
+}
+
+/// Settings for `BindingsType::WitWorld`. See `WitWorldConfig` for the
+/// meaning of the individual fields.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct WitWorldSettings {
+    pub package_name: Option<String>,
+    pub world_name: Option<String>,
+}
+
+impl From<WitWorldSettings> for WitWorldConfig {
+    fn from(settings: WitWorldSettings) -> Self {
+        let mut config = WitWorldConfig::new();
+        if let Some(package_name) = settings.package_name {
+            config = config.with_package_name(&package_name);
+        }
+        if let Some(world_name) = settings.world_name {
+            config = config.with_world_name(&world_name);
+        }
+        config
+    }
+}
+
+/// `CargoDependency` only holds static strings, which is fine since the config
+/// lives until the process exits anyway.
+fn leak(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::from_toml(
+            r#"
+            [protocol]
+            json = "protocol.json"
+
+            [[bindings]]
+            type = "rust-plugin"
+            path = "bindings/rust-plugin"
+            name = "example-bindings"
+            authors = ["Fiberplane <info@fiberplane.com>"]
+            license = { workspace = true }
+
+            [bindings.dependencies]
+            bytes = { version = "1", features = ["serde"] }
+            serde = "1.0"
+
+            [[bindings]]
+            type = "rust-wasmer2-runtime"
+            path = "bindings/rust-wasmer2-runtime"
+
+            [[bindings]]
+            type = "ts-runtime"
+            path = "bindings/ts-runtime"
+            generate-raw-export-wrappers = true
+            streaming-instantiation = false
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.protocol,
+            ProtocolSource::Json("protocol.json".into())
+        );
+        assert_eq!(config.bindings.len(), 3);
+
+        match &config.bindings[0].bindings_type {
+            BindingsTypeConfig::RustPlugin(settings) => {
+                assert_eq!(settings.name, "example-bindings");
+                assert_eq!(
+                    settings.license,
+                    Some(ConfigValue::Workspace { workspace: true })
+                );
+                assert_eq!(
+                    settings.dependencies.get("serde"),
+                    Some(&DependencySettings::Version("1.0".to_owned()))
+                );
+            }
+            other => panic!("Unexpected bindings type: {:?}", other),
+        }
+
+        assert!(matches!(
+            config.bindings[1].bindings_type,
+            BindingsTypeConfig::RustWasmer2Runtime
+        ));
+
+        match config.bindings.into_iter().nth(2).unwrap().bindings_type {
+            BindingsTypeConfig::TsRuntime(settings) => {
+                let config = TsRuntimeConfig::from(settings);
+                assert_eq!(config.msgpack_module, "@msgpack/msgpack");
+                assert!(config.generate_raw_export_wrappers);
+                assert!(!config.streaming_instantiation);
+            }
+            other => panic!("Unexpected bindings type: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_api_reference() {
+        let config = Config::from_toml(
+            r#"
+            [protocol]
+            json = "protocol.json"
+
+            [[bindings]]
+            type = "api-reference"
+            path = "docs/api"
+            format = "html"
+            title = "Example protocol"
+            "#,
+        )
+        .unwrap();
+
+        match BindingsType::from(config.bindings.into_iter().next().unwrap().bindings_type) {
+            BindingsType::ApiReference(config) => {
+                assert_eq!(config.format, ApiReferenceFormat::Html);
+                assert_eq!(config.title, "Example protocol");
+            }
+            bindings_type => panic!("Unexpected bindings type: {}", bindings_type),
+        }
+    }
+
+    #[test]
+    fn test_parse_assemblyscript_plugin() {
+        let config = Config::from_toml(
+            r#"
+            [protocol]
+            json = "protocol.json"
+
+            [[bindings]]
+            type = "assemblyscript-plugin"
+            path = "bindings/assemblyscript-plugin"
+            "#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            config.bindings[0].bindings_type,
+            BindingsTypeConfig::AssemblyScriptPlugin
+        ));
+    }
+
+    #[test]
+    fn test_parse_tinygo_plugin() {
+        let config = Config::from_toml(
+            r#"
+            [protocol]
+            json = "protocol.json"
+
+            [[bindings]]
+            type = "tinygo-plugin"
+            path = "bindings/tinygo-plugin"
+            package-name = "plugin"
+            "#,
+        )
+        .unwrap();
+
+        match BindingsType::from(config.bindings.into_iter().next().unwrap().bindings_type) {
+            BindingsType::TinyGoPlugin(config) => assert_eq!(config.package_name, "plugin"),
+            bindings_type => panic!("Unexpected bindings type: {}", bindings_type),
+        }
+    }
+
+    #[test]
+    fn test_parse_wit_world() {
+        let config = Config::from_toml(
+            r#"
+            [protocol]
+            json = "protocol.json"
+
+            [[bindings]]
+            type = "wit-world"
+            path = "bindings/wit-world"
+            package-name = "example:protocol"
+            world-name = "extension"
+            "#,
+        )
+        .unwrap();
+
+        match BindingsType::from(config.bindings.into_iter().next().unwrap().bindings_type) {
+            BindingsType::WitWorld(config) => {
+                assert_eq!(config.package_name, "example:protocol");
+                assert_eq!(config.world_name, "extension");
+            }
+            bindings_type => panic!("Unexpected bindings type: {}", bindings_type),
+        }
+    }
+
+    #[test]
+    fn test_reject_unknown_bindings_type() {
+        let result = Config::from_toml(
+            r#"
+            [protocol]
+            crate = "../example-protocol"
+
+            [[bindings]]
+            type = "cobol-runtime"
+            path = "bindings/cobol-runtime"
+            "#,
+        );
+        assert!(result.is_err());
+    }
+}
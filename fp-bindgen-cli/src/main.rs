@@ -0,0 +1,100 @@
+mod config;
+mod protocol;
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use config::Config;
+use fp_bindgen::{
+    check_bindings,
+    prelude::{BindingConfig, BindingsType},
+    try_generate_bindings, BindgenError,
+};
+use protocol::load_protocol;
+use std::{fs, path::PathBuf, process};
+
+/// Generates bindings for an fp-bindgen protocol, as configured in an
+/// `fp-bindgen.toml` file.
+#[derive(Parser)]
+#[clap(version)]
+struct Cli {
+    /// Path to the config file. Relative paths inside the config file are
+    /// resolved against the directory containing it.
+    #[clap(short, long, default_value = "fp-bindgen.toml")]
+    config: PathBuf,
+
+    /// Only generate the bindings of the given types, such as `rust-plugin`.
+    /// May be repeated. By default, all configured bindings are generated.
+    #[clap(short, long = "only", value_name = "TYPE")]
+    only: Vec<String>,
+
+    /// Verifies that the bindings on disk are up-to-date instead of writing
+    /// them. Prints a diff and exits with an error for any outdated bindings.
+    #[clap(long)]
+    check: bool,
+}
+
+fn main() {
+    if let Err(error) = run(Cli::parse()) {
+        eprintln!("Error: {error:?}");
+        process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let config = fs::read_to_string(&cli.config)
+        .with_context(|| format!("Could not read config from `{}`", cli.config.display()))?;
+    let config = Config::from_toml(&config)
+        .with_context(|| format!("Could not parse config from `{}`", cli.config.display()))?;
+    let base_dir = cli.config.parent().map(PathBuf::from).unwrap_or_default();
+
+    let protocol = load_protocol(&config.protocol, &base_dir)?;
+
+    let mut num_outdated = 0;
+    for entry in config.bindings {
+        let bindings_type = BindingsType::from(entry.bindings_type);
+        if !cli.only.is_empty() && !cli.only.contains(&bindings_type.to_string()) {
+            continue;
+        }
+
+        let path = base_dir.join(&entry.path);
+        let path = path.to_string_lossy();
+        let protocol = protocol.clone();
+        let config = BindingConfig {
+            bindings_type,
+            path: &path,
+        };
+        if cli.check {
+            match check_bindings(
+                protocol.import_functions,
+                protocol.export_functions,
+                protocol.types,
+                config,
+            ) {
+                Ok(()) => println!("Bindings in `{path}/` are up-to-date."),
+                Err(BindgenError::OutdatedBindings(outdated)) => {
+                    println!("{outdated}");
+                    num_outdated += 1;
+                }
+                Err(error) => {
+                    return Err(error)
+                        .with_context(|| format!("Could not check bindings in `{path}/`"))
+                }
+            }
+        } else {
+            try_generate_bindings(
+                protocol.import_functions,
+                protocol.export_functions,
+                protocol.types,
+                config,
+            )
+            .with_context(|| format!("Could not generate bindings in `{path}/`"))?;
+            println!("Generated bindings written to `{path}/`.");
+        }
+    }
+
+    if num_outdated > 0 {
+        bail!("Found outdated bindings, run `fp-bindgen` without `--check` to regenerate them");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::config::ProtocolSource;
+use anyhow::{anyhow, bail, Context, Result};
+use fp_bindgen::{protocol_from_json, Protocol};
+use std::{
+    fs,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+/// Loads the protocol from the given source. Relative paths are resolved
+/// against `base_dir`.
+pub fn load_protocol(source: &ProtocolSource, base_dir: &Path) -> Result<Protocol> {
+    match source {
+        ProtocolSource::Json(path) => load_protocol_from_json(&base_dir.join(path)),
+        ProtocolSource::Crate(path) => load_protocol_from_crate(&base_dir.join(path)),
+    }
+}
+
+fn load_protocol_from_json(path: &Path) -> Result<Protocol> {
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("Could not read protocol from `{}`", path.display()))?;
+    protocol_from_json(&json)
+        .with_context(|| format!("Could not parse protocol from `{}`", path.display()))
+}
+
+/// Loads the protocol from a protocol crate.
+///
+/// This is done by generating a small shim crate that depends on the protocol
+/// crate and prints the result of its `protocol()` function as JSON, which we
+/// then run using Cargo.
+fn load_protocol_from_crate(path: &Path) -> Result<Protocol> {
+    let crate_path = path
+        .canonicalize()
+        .with_context(|| format!("Could not find protocol crate at `{}`", path.display()))?;
+
+    let manifest_path = crate_path.join("Cargo.toml");
+    let manifest: toml::Table = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Could not read `{}`", manifest_path.display()))?
+        .parse()
+        .with_context(|| format!("Could not parse `{}`", manifest_path.display()))?;
+    let crate_name = manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .ok_or_else(|| anyhow!("No package name in `{}`", manifest_path.display()))?;
+
+    let shim_path = crate_path.join("target").join("fp-bindgen-shim");
+    fs::create_dir_all(shim_path.join("src"))
+        .with_context(|| format!("Could not create `{}`", shim_path.display()))?;
+    fs::write(
+        shim_path.join("Cargo.toml"),
+        format!(
+            "[package]
+name = \"fp-bindgen-shim\"
+version = \"0.0.0\"
+edition = \"2018\"
+publish = false
+
+[dependencies]
+protocol = {{ package = {}, path = {} }}
+
+[workspace]
+",
+            toml::Value::from(crate_name),
+            toml::Value::from(crate_path.to_string_lossy().as_ref())
+        ),
+    )?;
+    fs::write(
+        shim_path.join("src").join("main.rs"),
+        "fn main() {
+    print!(\"{}\", protocol::protocol().to_json());
+}
+",
+    )?;
+
+    let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()))
+        .arg("run")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(shim_path.join("Cargo.toml"))
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Could not run Cargo")?;
+    if !output.status.success() {
+        bail!(
+            "Could not load protocol from crate `{crate_name}`. Make sure its library target \
+            exposes a `pub fn protocol() -> fp_bindgen::Protocol`."
+        );
+    }
+
+    let json = String::from_utf8(output.stdout).context("Protocol crate printed invalid UTF-8")?;
+    protocol_from_json(&json)
+        .with_context(|| format!("Could not parse protocol from crate `{crate_name}`"))
+}
@@ -1,4 +1,5 @@
 use super::mem::{to_fat_ptr, FatPtr};
+use std::{future::Future, pin::Pin};
 
 pub const FUTURE_STATUS_PENDING: u32 = 0;
 pub const FUTURE_STATUS_READY: u32 = 1;
@@ -31,3 +32,30 @@ impl Default for AsyncValue {
         Self::new()
     }
 }
+
+/// A future returned by an async imported function, as passed to a [`Spawner`].
+pub type SpawnedFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Runs the futures returned by async imported functions in the background, so
+/// the plugin can continue while they are pending.
+///
+/// This is implemented for any function that takes a [`SpawnedFuture`], so a
+/// closure can be used to spawn the futures on the executor of your choice:
+///
+/// ```ignore
+/// Runtime::with_spawner(wasm_module, imports, |future| {
+///     async_std::task::spawn(future);
+/// })
+/// ```
+pub trait Spawner: Send + Sync + 'static {
+    fn spawn(&self, future: SpawnedFuture);
+}
+
+impl<F> Spawner for F
+where
+    F: Fn(SpawnedFuture) + Send + Sync + 'static,
+{
+    fn spawn(&self, future: SpawnedFuture) {
+        self(future)
+    }
+}
@@ -10,3 +10,82 @@ pub fn to_fat_ptr(ptr: *const u8, len: u32) -> FatPtr {
 pub fn from_fat_ptr(ptr: FatPtr) -> (*const u8, u32) {
     ((ptr >> 32) as *const u8, (ptr & 0xffffffff) as u32)
 }
+
+/// Extension bit in the length of a fat pointer, which indicates the length is
+/// stored in a 4-byte little-endian header at the start of the pointed-to
+/// memory, directly followed by the data itself.
+#[doc(hidden)]
+pub const FAT_PTR_LEN_HEADER: u32 = 0x01000000;
+
+/// The size of the length header used with `FAT_PTR_LEN_HEADER`.
+#[doc(hidden)]
+pub const FAT_PTR_LEN_HEADER_SIZE: u32 = 4;
+
+/// The largest length that can be encoded in a fat pointer directly. Anything
+/// larger requires a length header.
+#[doc(hidden)]
+pub const FAT_PTR_MAX_LEN: u32 = 0x00ffffff;
+
+/// Returns the offset of the data a fat pointer into guest memory refers to,
+/// along with its length. If the fat pointer has a length header,
+/// `read_header` is called with the offset of the header to read it.
+///
+/// Returns `None` if the fat pointer is invalid or its header cannot be read.
+#[doc(hidden)]
+pub fn resolve_guest_fat_ptr(
+    fat_ptr: FatPtr,
+    read_header: impl FnOnce(u32) -> Option<[u8; FAT_PTR_LEN_HEADER_SIZE as usize]>,
+) -> Option<(u32, u32)> {
+    let (ptr, len) = ((fat_ptr >> 32) as u32, (fat_ptr & 0xffffffff) as u32);
+    match len {
+        FAT_PTR_LEN_HEADER => {
+            let header = read_header(ptr)?;
+            let data_ptr = ptr.checked_add(FAT_PTR_LEN_HEADER_SIZE)?;
+            Some((data_ptr, u32::from_le_bytes(header)))
+        }
+        len if len > FAT_PTR_MAX_LEN => None,
+        len => Some((ptr, len)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guest_fat_ptr(ptr: u32, len: u32) -> FatPtr {
+        (ptr as FatPtr) << 32 | (len as FatPtr)
+    }
+
+    #[test]
+    fn test_resolve_guest_fat_ptr() {
+        let fat_ptr = guest_fat_ptr(0x100, FAT_PTR_MAX_LEN);
+        let resolved = resolve_guest_fat_ptr(fat_ptr, |_| panic!("There is no header"));
+        assert_eq!(resolved, Some((0x100, FAT_PTR_MAX_LEN)));
+    }
+
+    #[test]
+    fn test_resolve_guest_fat_ptr_with_len_header() {
+        let len = FAT_PTR_MAX_LEN + 1;
+        let fat_ptr = guest_fat_ptr(0x100, FAT_PTR_LEN_HEADER);
+        let resolved = resolve_guest_fat_ptr(fat_ptr, |ptr| {
+            assert_eq!(ptr, 0x100);
+            Some(len.to_le_bytes())
+        });
+        assert_eq!(resolved, Some((0x104, len)));
+
+        // Headers that cannot be read, or that would be followed by data
+        // outside the address space, are rejected:
+        assert_eq!(resolve_guest_fat_ptr(fat_ptr, |_| None), None);
+        let fat_ptr = guest_fat_ptr(u32::MAX - 2, FAT_PTR_LEN_HEADER);
+        assert_eq!(
+            resolve_guest_fat_ptr(fat_ptr, |_| Some(len.to_le_bytes())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_guest_fat_ptr_with_unknown_extension_bits() {
+        let fat_ptr = guest_fat_ptr(0x100, 0x02000000);
+        assert_eq!(resolve_guest_fat_ptr(fat_ptr, |_| None), None);
+    }
+}
@@ -2,3 +2,4 @@ pub mod abi;
 #[cfg(feature = "async")]
 pub mod r#async;
 pub mod mem;
+pub mod panic;
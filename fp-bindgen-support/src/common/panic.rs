@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes a panic that occurred inside the guest, as it is reported to the
+/// host through the `__fp_host_panic()` import.
+#[doc(hidden)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuestPanic {
+    pub message: String,
+    pub location: Option<String>,
+}
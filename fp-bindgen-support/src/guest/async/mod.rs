@@ -52,6 +52,10 @@ impl Future for HostFuture {
 #[doc(hidden)]
 #[no_mangle]
 pub unsafe fn __fp_guest_resolve_async_value(async_value_fat_ptr: FatPtr, result_ptr: FatPtr) {
+    // Resolving the value may resume tasks, which should report their panics
+    // as well:
+    crate::guest::panic::install_panic_hook();
+
     // First assign the result ptr and mark the async value as ready:
     let (ptr, len) = from_fat_ptr(result_ptr);
     let (async_value_ptr, _) = from_fat_ptr(async_value_fat_ptr);
@@ -69,11 +73,20 @@ pub unsafe fn __fp_guest_resolve_async_value(async_value_fat_ptr: FatPtr, result
     }
 }
 
+#[cfg(not(feature = "mock_host"))]
 #[link(wasm_import_module = "fp")]
 extern "C" {
     fn __fp_host_resolve_async_value(async_value_ptr: FatPtr, result_ptr: FatPtr);
 }
 
+#[cfg(not(feature = "mock_host"))]
 pub fn host_resolve_async_value(async_value_ptr: FatPtr, result_ptr: FatPtr) {
     unsafe { __fp_host_resolve_async_value(async_value_ptr, result_ptr) }
 }
+
+/// Exported async functions can only be awaited directly when running against
+/// a mock host, since there is no runtime to resolve their values.
+#[cfg(feature = "mock_host")]
+pub fn host_resolve_async_value(_async_value_ptr: FatPtr, _result_ptr: FatPtr) {
+    panic!("Async values cannot be resolved when running against a mock host")
+}
@@ -16,6 +16,20 @@ pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
 
     let len = buffer.len();
 
+    // Make sure the length fits in the fat pointer or its length header:
+    if len > u32::MAX as usize {
+        panic!("Buffer too large ({} bytes)", len);
+    }
+
+    // Buffers that are too large to have their length encoded in the fat
+    // pointer are copied into an allocation with a length header:
+    if len as u32 > FAT_PTR_MAX_LEN {
+        let (ptr, fat_len) = allocate(len as u32);
+        let (data_ptr, _) = resolve_ptr(ptr, fat_len);
+        unsafe { std::ptr::copy_nonoverlapping(buffer.as_ptr(), data_ptr as *mut u8, len) };
+        return to_fat_ptr(ptr, fat_len);
+    }
+
     if buffer.capacity() > len {
         buffer.shrink_to_fit();
 
@@ -31,11 +45,6 @@ pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
         }
     }
 
-    // Make sure the length marker does not run into our extension bits:
-    if len & 0xff000000 != 0 {
-        panic!("Buffer too large ({} bytes)", len);
-    }
-
     let ptr = buffer.as_ptr();
     std::mem::forget(buffer);
     to_fat_ptr(ptr, len as u32)
@@ -47,10 +56,7 @@ pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
 /// host. After this call, the pointer is no longer valid.
 #[doc(hidden)]
 pub unsafe fn import_value_from_host<'de, T: Deserialize<'de>>(fat_ptr: FatPtr) -> T {
-    let (ptr, len) = from_fat_ptr(fat_ptr);
-    if len & 0xff000000 != 0 {
-        panic!("Unknown extension bits");
-    }
+    let (ptr, len) = resolve_fat_ptr(fat_ptr);
 
     let slice = std::slice::from_raw_parts(ptr, len as usize);
     let mut deserializer = Deserializer::new(slice).with_human_readable();
@@ -61,18 +67,62 @@ pub unsafe fn import_value_from_host<'de, T: Deserialize<'de>>(fat_ptr: FatPtr)
     value
 }
 
+/// Returns the pointer to the data and its length for the given fat pointer,
+/// reading the length from its header if the fat pointer has one.
+fn resolve_fat_ptr(fat_ptr: FatPtr) -> (*const u8, u32) {
+    let (ptr, len) = from_fat_ptr(fat_ptr);
+    resolve_ptr(ptr, len)
+}
+
+/// Same as `resolve_fat_ptr()`, but takes the pointer and length that are
+/// encoded in the fat pointer.
+fn resolve_ptr(ptr: *const u8, len: u32) -> (*const u8, u32) {
+    match len {
+        FAT_PTR_LEN_HEADER => {
+            let header = unsafe { std::ptr::read_unaligned(ptr as *const [u8; 4]) };
+            let data_ptr = unsafe { ptr.add(FAT_PTR_LEN_HEADER_SIZE as usize) };
+            (data_ptr, u32::from_le_bytes(header))
+        }
+        len if len > FAT_PTR_MAX_LEN => panic!("Unknown extension bits"),
+        len => (ptr, len),
+    }
+}
+
+/// Returns the size of the allocation behind a fat pointer with the given
+/// data length, including the length header if one is needed.
+fn allocation_size(len: u32) -> usize {
+    if len > FAT_PTR_MAX_LEN {
+        len as usize + FAT_PTR_LEN_HEADER_SIZE as usize
+    } else {
+        len as usize
+    }
+}
+
 const MALLOC_ALIGNMENT: usize = 16;
 
 #[doc(hidden)]
 #[no_mangle]
 pub fn __fp_malloc(len: u32) -> FatPtr {
+    let (ptr, len) = allocate(len);
+    to_fat_ptr(ptr, len)
+}
+
+/// Allocates memory for data of the given length, and returns the pointer and
+/// length to encode in the fat pointer to it.
+fn allocate(len: u32) -> (*const u8, u32) {
     let ptr = unsafe {
         std::alloc::alloc(
-            Layout::from_size_align(len as usize, MALLOC_ALIGNMENT)
+            Layout::from_size_align(allocation_size(len), MALLOC_ALIGNMENT)
                 .expect("Allocation failed unexpectedly, check requested allocation size"),
         )
     };
-    to_fat_ptr(ptr, len)
+
+    if len > FAT_PTR_MAX_LEN {
+        unsafe { std::ptr::write_unaligned(ptr as *mut [u8; 4], len.to_le_bytes()) };
+        (ptr, FAT_PTR_LEN_HEADER)
+    } else {
+        (ptr, len)
+    }
 }
 
 /// # Safety
@@ -86,18 +136,91 @@ pub fn __fp_malloc(len: u32) -> FatPtr {
 /// - When the host allocates and passes to us, we free the object.
 #[doc(hidden)]
 #[no_mangle]
-pub unsafe fn __fp_free(ptr: FatPtr) {
-    let (ptr, len) = from_fat_ptr(ptr);
+pub unsafe fn __fp_free(fat_ptr: FatPtr) {
+    let (ptr, len) = from_fat_ptr(fat_ptr);
+    deallocate(ptr, len);
+}
 
-    assert_eq!(
-        len & 0xff000000,
-        0,
-        "__fp_free() failed: unknown extension bits"
-    );
+/// Frees memory allocated by `allocate()`, given the pointer and length that
+/// are encoded in the fat pointer to it.
+unsafe fn deallocate(ptr: *const u8, len: u32) {
+    deallocate_with(ptr, len, |ptr, layout| std::alloc::dealloc(ptr, layout));
+}
 
-    std::alloc::dealloc(
+/// Same as `deallocate()`, but hands the pointer and the layout it was
+/// allocated with to the given `dealloc` function.
+unsafe fn deallocate_with(ptr: *const u8, len: u32, dealloc: impl FnOnce(*mut u8, Layout)) {
+    let (_, len) = resolve_ptr(ptr, len);
+
+    dealloc(
         ptr as *mut u8,
-        Layout::from_size_align(len as usize, MALLOC_ALIGNMENT)
+        Layout::from_size_align(allocation_size(len), MALLOC_ALIGNMENT)
             .expect("Deallocation failed unexpectedly, check the pointer is valid"),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fat pointers can only hold 32-bit pointers, so these tests use the
+    // pointer and length that would be encoded in them directly.
+
+    /// Frees the allocation using `deallocate_with()`, and returns the layout
+    /// it was freed with.
+    unsafe fn deallocate_recording_layout(ptr: *const u8, len: u32) -> Layout {
+        let mut freed_layout = None;
+        deallocate_with(ptr, len, |ptr, layout| {
+            freed_layout = Some(layout);
+            std::alloc::dealloc(ptr, layout);
+        });
+        freed_layout.expect("Allocation was not freed")
+    }
+
+    #[test]
+    fn test_len_header_round_trip() {
+        let len = FAT_PTR_MAX_LEN + 1;
+        let (ptr, fat_len) = allocate(len);
+        assert_eq!(fat_len, FAT_PTR_LEN_HEADER);
+
+        let header = unsafe { std::ptr::read_unaligned(ptr as *const [u8; 4]) };
+        assert_eq!(u32::from_le_bytes(header), len);
+
+        let (data_ptr, data_len) = resolve_ptr(ptr, fat_len);
+        assert_eq!(
+            data_ptr as usize,
+            ptr as usize + FAT_PTR_LEN_HEADER_SIZE as usize
+        );
+        assert_eq!(data_len, len);
+
+        // The data must fit in the allocation, after the header:
+        unsafe { std::ptr::write_bytes(data_ptr as *mut u8, 0xff, data_len as usize) };
+
+        let layout = unsafe { deallocate_recording_layout(ptr, fat_len) };
+        assert_eq!(
+            layout.size(),
+            len as usize + FAT_PTR_LEN_HEADER_SIZE as usize
+        );
+        assert_eq!(layout.align(), MALLOC_ALIGNMENT);
+    }
+
+    #[test]
+    fn test_len_without_header() {
+        let (ptr, fat_len) = allocate(FAT_PTR_MAX_LEN);
+        assert_eq!(fat_len, FAT_PTR_MAX_LEN);
+        assert_eq!(resolve_ptr(ptr, fat_len), (ptr, FAT_PTR_MAX_LEN));
+
+        let layout = unsafe { deallocate_recording_layout(ptr, fat_len) };
+        assert_eq!(layout.size(), FAT_PTR_MAX_LEN as usize);
+    }
+
+    #[test]
+    fn test_allocation_size() {
+        assert_eq!(allocation_size(0), 0);
+        assert_eq!(allocation_size(FAT_PTR_MAX_LEN), FAT_PTR_MAX_LEN as usize);
+        assert_eq!(
+            allocation_size(FAT_PTR_MAX_LEN + 1),
+            FAT_PTR_MAX_LEN as usize + 1 + FAT_PTR_LEN_HEADER_SIZE as usize
+        );
+    }
+}
@@ -1,3 +1,4 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 pub mod io;
+pub mod panic;
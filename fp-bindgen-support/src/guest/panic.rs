@@ -0,0 +1,69 @@
+// The host functions are not used when running against a mock host.
+#![cfg_attr(feature = "mock_host", allow(dead_code, unused_imports))]
+
+use super::io::export_value_to_host;
+use crate::common::{mem::FatPtr, panic::GuestPanic};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[link(wasm_import_module = "fp")]
+extern "C" {
+    fn __fp_host_panic(panic: FatPtr);
+}
+
+/// Whether the hook installed by [`install_panic_hook()`] is still alive.
+static PANIC_HOOK_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Owned by the installed panic hook. Once the hook is dropped, because the
+/// plugin replaced it using `std::panic::set_hook()`, this resets
+/// [`PANIC_HOOK_INSTALLED`] so the hook gets installed again.
+struct InstalledPanicHook;
+
+impl Drop for InstalledPanicHook {
+    fn drop(&mut self) {
+        PANIC_HOOK_INSTALLED.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Installs a panic hook that reports the message and location of any panic
+/// to the host, so it can be surfaced there as an error. A hook that was
+/// installed by the plugin before is still called.
+///
+/// This is called automatically by the exported functions, both before and
+/// after running them. If the plugin replaced the hook in the meantime, it is
+/// installed again, chaining to the hook of the plugin.
+#[doc(hidden)]
+#[cfg(not(feature = "mock_host"))]
+pub fn install_panic_hook() {
+    if PANIC_HOOK_INSTALLED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let previous_hook = std::panic::take_hook();
+    let installed = InstalledPanicHook;
+    std::panic::set_hook(Box::new(move |info| {
+        let _installed = &installed;
+
+        // Reporting the panic traps the guest, so nothing after it would
+        // run. That's why the previous hook is called first.
+        previous_hook(info);
+
+        let payload = info.payload();
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Box<dyn Any>".to_owned()
+        };
+        let location = info.location().map(ToString::to_string);
+
+        let panic = GuestPanic { message, location };
+        unsafe { __fp_host_panic(export_value_to_host(&panic)) };
+    }));
+}
+
+/// When running against a mock host, panics are left to the default hook, so
+/// they are reported by the test harness as usual.
+#[doc(hidden)]
+#[cfg(feature = "mock_host")]
+pub fn install_panic_hook() {}
@@ -3,7 +3,13 @@ pub mod common;
 pub mod guest;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "mock_host")]
+pub mod mock_host;
 #[cfg(feature = "wasmer2_host")]
 pub mod wasmer2_host;
+#[cfg(feature = "wasmer3_host")]
+pub mod wasmer3_host;
+#[cfg(feature = "wasmtime_host")]
+pub mod wasmtime_host;
 
 pub use fp_bindgen_macros::{fp_export_impl, fp_export_signature, fp_import_signature};
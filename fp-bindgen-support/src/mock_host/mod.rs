@@ -0,0 +1,42 @@
+/*!
+Mock host support for fp-bindgen.
+
+Allows Rust plugins to be compiled for, and tested on, the host target. The
+generated bindings dispatch imported functions to a mock that is registered
+for the current thread, instead of calling into a Wasm runtime.
+ */
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+thread_local! {
+    static MOCK_IMPORTS: RefCell<Option<Rc<dyn Any>>> = RefCell::new(None);
+}
+
+/// Registers the mock imports for the current thread, replacing any mock
+/// imports that were registered before.
+#[doc(hidden)]
+pub fn set_mock_imports<T: 'static>(imports: Rc<T>) {
+    MOCK_IMPORTS.with(|mock_imports| *mock_imports.borrow_mut() = Some(imports));
+}
+
+/// Returns the mock imports registered for the current thread.
+///
+/// Panics if no mock imports of the given type have been registered.
+#[doc(hidden)]
+pub fn get_mock_imports<T: 'static>() -> Rc<T> {
+    MOCK_IMPORTS
+        .with(|mock_imports| mock_imports.borrow().clone())
+        .expect("No mock imports have been registered for the current thread")
+        .downcast()
+        .unwrap_or_else(|_| panic!("Mock imports of unexpected type registered"))
+}
+
+/// Panics with a message saying the imported function with the given name has
+/// no mock implementation.
+#[doc(hidden)]
+pub fn unimplemented_import(name: &str) -> ! {
+    panic!(
+        "No mock implementation provided for imported function `{}`",
+        name
+    )
+}
@@ -1,13 +1,17 @@
 use super::{
     io::{from_fat_ptr, to_wasm_ptr},
+    mem::export_to_guest,
     runtime::RuntimeInstanceData,
 };
 use crate::common::{
     mem::FatPtr,
     r#async::{AsyncValue, FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
 };
+use serde::Serialize;
 use std::{mem::size_of, task::Waker};
 
+pub use crate::common::r#async::{SpawnedFuture, Spawner};
+
 pub mod future;
 
 /// Create an empty FutureValue in the linear memory and return a FatPtr to it.
@@ -27,6 +31,23 @@ pub fn create_future_value(env: &RuntimeInstanceData) -> FatPtr {
     ptr
 }
 
+/// Passes the result of an async import function to the guest, and resolves
+/// the FutureValue created for it.
+///
+/// This runs in a spawned task, so there is no caller left to return an error
+/// to. Errors are logged instead.
+pub fn resolve_future_value<T: Serialize>(
+    env: &RuntimeInstanceData,
+    async_ptr: FatPtr,
+    result: &T,
+) {
+    let resolved = export_to_guest(env, result)
+        .and_then(|result_ptr| env.guest_resolve_async_value(async_ptr, result_ptr));
+    if let Err(error) = resolved {
+        tracing::error!("Runtime error: Cannot resolve async value: {}", error);
+    }
+}
+
 /// Note: In this case we are only interested in the pointer itself, we do not
 /// want to deserialize it (which would actually free it as well).
 /// This function also doesn't call another function since everything is
@@ -1,8 +1,8 @@
-use super::{io::to_wasm_ptr, runtime::RuntimeInstanceData};
-use crate::common::mem::FatPtr;
+use super::{errors::InvocationError, runtime::RuntimeInstanceData};
+use crate::common::mem::{resolve_guest_fat_ptr, FatPtr, FAT_PTR_LEN_HEADER_SIZE};
 use rmp_serde::{decode::ReadReader, Deserializer, Serializer};
 use serde::{Deserialize, Serialize};
-use wasmer::WasmCell;
+use wasmer::{Array, WasmCell, WasmPtr};
 
 /// Serialize the given value to MessagePack
 pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
@@ -15,21 +15,23 @@ pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
 }
 
 /// Deserialize the given MessagePack-encoded slice
-pub fn deserialize_from_slice<'a, T: Deserialize<'a>>(slice: &'a [u8]) -> T {
+pub fn deserialize_from_slice<'a, T: Deserialize<'a>>(
+    slice: &'a [u8],
+) -> Result<T, InvocationError> {
     let mut deserializer = rmp_serde::Deserializer::new(slice).with_human_readable();
-    T::deserialize(&mut deserializer).unwrap()
+    Ok(T::deserialize(&mut deserializer)?)
 }
 
 /// Serialize an object from the linear memory and after that free up the memory
 pub fn import_from_guest<'de, T: Deserialize<'de>>(
     env: &RuntimeInstanceData,
     fat_ptr: FatPtr,
-) -> T {
-    let value = import_from_guest_raw(env, fat_ptr);
+) -> Result<T, InvocationError> {
+    let value = import_from_guest_raw(env, fat_ptr)?;
 
     let mut deserializer =
         Deserializer::<ReadReader<&[u8]>>::new(value.as_ref()).with_human_readable();
-    T::deserialize(&mut deserializer).unwrap()
+    Ok(T::deserialize(&mut deserializer)?)
 }
 
 /// Retrieve a serialized object from the linear memory as a Vec<u8> and free up
@@ -37,53 +39,89 @@ pub fn import_from_guest<'de, T: Deserialize<'de>>(
 ///
 /// Useful when the consumer wants to pass the result, without having the
 /// deserialize and serialize it.
-pub fn import_from_guest_raw(env: &RuntimeInstanceData, fat_ptr: FatPtr) -> Vec<u8> {
+pub fn import_from_guest_raw(
+    env: &RuntimeInstanceData,
+    fat_ptr: FatPtr,
+) -> Result<Vec<u8>, InvocationError> {
     if fat_ptr == 0 {
         // This may happen with async calls that don't return a result:
-        return Vec::new();
-    }
-
-    let memory = unsafe { env.memory.get_unchecked() };
-
-    let (ptr, len) = to_wasm_ptr::<u8>(fat_ptr);
-    if len & 0xff000000 != 0 {
-        panic!("Unknown extension bits");
+        return Ok(Vec::new());
     }
 
-    let value: Vec<u8> = {
-        let view = ptr.deref(memory, 0, len).unwrap();
-        view.iter().map(WasmCell::get).collect()
-    };
+    let value = read_fat_ptr(env, fat_ptr);
 
-    env.free(fat_ptr);
+    // The memory is freed even if it could not be read, so it isn't leaked.
+    // Freeing an invalid fat pointer may fail as well, in which case the
+    // original error is returned:
+    let freed = env.free(fat_ptr);
+    let value = value?;
+    freed?;
 
-    value
+    Ok(value)
 }
 
 /// Serialize a value and put it in linear memory.
-pub fn export_to_guest<T: Serialize>(env: &RuntimeInstanceData, value: &T) -> FatPtr {
-    export_to_guest_raw(env, rmp_serde::to_vec(value).unwrap())
+pub fn export_to_guest<T: Serialize>(
+    env: &RuntimeInstanceData,
+    value: &T,
+) -> Result<FatPtr, InvocationError> {
+    export_to_guest_raw(env, rmp_serde::to_vec(value)?)
 }
 
 /// Copy the buffer into linear memory.
-pub fn export_to_guest_raw(env: &RuntimeInstanceData, buffer: Vec<u8>) -> FatPtr {
+pub fn export_to_guest_raw(
+    env: &RuntimeInstanceData,
+    buffer: Vec<u8>,
+) -> Result<FatPtr, InvocationError> {
     let memory = unsafe { env.memory.get_unchecked() };
 
-    let len = buffer.len() as u32;
-
-    // Make sure the length marker does not run into our extension bits:
-    if len & 0xff000000 != 0 {
-        panic!("Buffer too large ({} bytes)", len);
+    // Make sure the length fits in the fat pointer or its length header:
+    if buffer.len() > u32::MAX as usize {
+        return Err(InvocationError::PayloadTooLarge(buffer.len()));
     }
 
+    let len = buffer.len() as u32;
+
     let fat_ptr = env.malloc(len);
 
-    let (ptr, len) = to_wasm_ptr(fat_ptr);
+    let (ptr, len) = resolve_fat_ptr(env, fat_ptr)?;
 
-    let values = ptr.deref(memory, 0, len).unwrap();
+    let values = WasmPtr::<u8, Array>::new(ptr)
+        .deref(memory, 0, len)
+        .ok_or(InvocationError::InvalidFatPtr(fat_ptr))?;
     for (i, val) in buffer.iter().enumerate() {
         values[i].set(*val);
     }
 
-    fat_ptr
+    Ok(fat_ptr)
+}
+
+/// Reads the data the given fat pointer points to.
+fn read_fat_ptr(env: &RuntimeInstanceData, fat_ptr: FatPtr) -> Result<Vec<u8>, InvocationError> {
+    let memory = unsafe { env.memory.get_unchecked() };
+
+    let (ptr, len) = resolve_fat_ptr(env, fat_ptr)?;
+
+    let view = WasmPtr::<u8, Array>::new(ptr)
+        .deref(memory, 0, len)
+        .ok_or(InvocationError::InvalidFatPtr(fat_ptr))?;
+    Ok(view.iter().map(WasmCell::get).collect())
+}
+
+/// Returns the offset of the data and its length for the given fat pointer,
+/// reading the length from its header if the fat pointer has one.
+fn resolve_fat_ptr(
+    env: &RuntimeInstanceData,
+    fat_ptr: FatPtr,
+) -> Result<(u32, u32), InvocationError> {
+    resolve_guest_fat_ptr(fat_ptr, |ptr| {
+        let memory = unsafe { env.memory.get_unchecked() };
+        let cells = WasmPtr::<u8, Array>::new(ptr).deref(memory, 0, FAT_PTR_LEN_HEADER_SIZE)?;
+        let mut header = [0; FAT_PTR_LEN_HEADER_SIZE as usize];
+        for (byte, cell) in header.iter_mut().zip(cells) {
+            *byte = cell.get();
+        }
+        Some(header)
+    })
+    .ok_or(InvocationError::InvalidFatPtr(fat_ptr))
 }
@@ -4,4 +4,5 @@ pub mod r#async;
 pub mod errors;
 pub mod io;
 pub mod mem;
+pub mod panic;
 pub mod runtime;
@@ -0,0 +1,10 @@
+use super::{errors::InvocationError, mem::import_from_guest, runtime::RuntimeInstanceData};
+use crate::common::{mem::FatPtr, panic::GuestPanic};
+
+/// Called by the guest when it panics. The returned error traps the guest, so
+/// the invocation that caused the panic fails with
+/// `InvocationError::GuestPanic`.
+pub fn host_panic(env: &RuntimeInstanceData, panic: FatPtr) -> Result<(), InvocationError> {
+    let GuestPanic { message, location } = import_from_guest(env, panic)?;
+    Err(InvocationError::GuestPanic { message, location })
+}
@@ -1,8 +1,14 @@
+use super::errors::{InvocationError, RuntimeError};
+#[cfg(feature = "async")]
+use super::r#async::Spawner;
 use crate::common::mem::FatPtr;
+use std::any::Any;
 use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::sync::{Arc, Mutex};
 use std::task::Waker;
-use wasmer::{LazyInit, Memory, NativeFunc, WasmerEnv};
+use wasmer::{Instance, LazyInit, Memory, NativeFunc, WasmerEnv};
 
 #[derive(Clone, Default, WasmerEnv)]
 pub struct RuntimeInstanceData {
@@ -11,6 +17,11 @@ pub struct RuntimeInstanceData {
 
     pub(crate) wakers: Arc<Mutex<HashMap<FatPtr, Waker>>>,
 
+    context: Option<Arc<dyn Any + Send + Sync>>,
+
+    #[cfg(feature = "async")]
+    spawner: Option<Arc<dyn Spawner>>,
+
     #[wasmer(export)]
     __fp_free: LazyInit<NativeFunc<FatPtr>>,
 
@@ -22,13 +33,58 @@ pub struct RuntimeInstanceData {
 }
 
 impl RuntimeInstanceData {
-    pub fn guest_resolve_async_value(&self, async_ptr: FatPtr, result_ptr: FatPtr) {
+    /// Creates instance data that holds the given context.
+    pub fn with_context<C: Any + Send + Sync>(context: C) -> Self {
+        Self {
+            context: Some(Arc::new(context)),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the context that was passed when the runtime was created.
+    pub fn context<C: Any + Send + Sync>(&self) -> Result<Arc<C>, InvocationError> {
+        self.context
+            .clone()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "no context was set",
+            ))?
+            .downcast()
+            .map_err(|_| InvocationError::InstanceDataUnavailable("context has an unexpected type"))
+    }
+
+    /// Sets the spawner that is used to run the futures returned by async
+    /// imported functions.
+    #[cfg(feature = "async")]
+    pub fn set_spawner(&mut self, spawner: impl Spawner) {
+        self.spawner = Some(Arc::new(spawner));
+    }
+
+    /// Spawns the given future using the spawner that was set for the runtime.
+    #[cfg(feature = "async")]
+    pub fn spawn(
+        &self,
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), InvocationError> {
+        self.spawner
+            .as_ref()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "no spawner was set",
+            ))?
+            .spawn(Box::pin(future));
+        Ok(())
+    }
+
+    pub fn guest_resolve_async_value(
+        &self,
+        async_ptr: FatPtr,
+        result_ptr: FatPtr,
+    ) -> Result<(), InvocationError> {
         unsafe {
             self.__fp_guest_resolve_async_value
                 .get_unchecked()
-                .call(async_ptr, result_ptr)
-                .expect("Runtime error: Cannot resolve async value");
+                .call(async_ptr, result_ptr)?;
         }
+        Ok(())
     }
 
     pub fn malloc(&self, len: u32) -> FatPtr {
@@ -40,12 +96,52 @@ impl RuntimeInstanceData {
         }
     }
 
-    pub fn free(&self, ptr: FatPtr) {
-        unsafe {
-            self.__fp_free
-                .get_unchecked()
-                .call(ptr)
-                .expect("unable to call free")
-        };
+    pub fn free(&self, ptr: FatPtr) -> Result<(), InvocationError> {
+        unsafe { self.__fp_free.get_unchecked().call(ptr)? };
+        Ok(())
+    }
+}
+
+/// Calls the `_initialize` export of the plugin, if it has one.
+///
+/// Reactor modules, such as plugins written in TinyGo, export this function to
+/// initialize themselves before any of their other exports may be called.
+pub fn initialize_instance(instance: &Instance) -> Result<(), RuntimeError> {
+    if !instance.exports.contains("_initialize") {
+        return Ok(());
+    }
+
+    let initialize = instance
+        .exports
+        .get_native_function::<(), ()>("_initialize")
+        .map_err(|error| RuntimeError::InitializationFailed(error.to_string()))?;
+    initialize
+        .call()
+        .map_err(|error| RuntimeError::InitializationFailed(error.to_string()))
+}
+
+/// Verifies that the plugin was built against the protocol with the given
+/// fingerprint, by calling its `__fp_protocol_version` export.
+///
+/// Plugins that don't export `__fp_protocol_version`, such as those built with
+/// older bindings, cannot be verified and are accepted. If the function is
+/// exported, but cannot be called, `RuntimeError::ProtocolCheckFailed` is
+/// returned.
+pub fn check_protocol_version(instance: &Instance, expected: u64) -> Result<(), RuntimeError> {
+    if !instance.exports.contains("__fp_protocol_version") {
+        return Ok(());
+    }
+
+    let protocol_version = instance
+        .exports
+        .get_native_function::<(), u64>("__fp_protocol_version")
+        .map_err(|error| RuntimeError::ProtocolCheckFailed(error.to_string()))?;
+    let found = protocol_version
+        .call()
+        .map_err(|error| RuntimeError::ProtocolCheckFailed(error.to_string()))?;
+    if found == expected {
+        Ok(())
+    } else {
+        Err(RuntimeError::ProtocolMismatch { expected, found })
     }
 }
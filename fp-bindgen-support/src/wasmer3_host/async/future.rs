@@ -0,0 +1,58 @@
+use crate::common::{
+    mem::FatPtr,
+    r#async::{FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
+};
+use crate::wasmer3_host::{
+    errors::InvocationError,
+    io::to_fat_ptr,
+    mem::import_from_guest_raw,
+    r#async::read_async_value,
+    runtime::{RuntimeInstanceData, RuntimeStore},
+};
+use std::{future::Future, task::Poll};
+use wasmer3::FunctionEnv;
+
+// The ModuleRawFuture implements the Future Trait to handle async Futures as
+// returned from the module.
+pub struct ModuleRawFuture {
+    ptr: FatPtr,
+    store: RuntimeStore,
+    env: FunctionEnv<RuntimeInstanceData>,
+}
+
+impl ModuleRawFuture {
+    pub fn new(store: RuntimeStore, env: FunctionEnv<RuntimeInstanceData>, ptr: FatPtr) -> Self {
+        Self { ptr, store, env }
+    }
+}
+
+impl Future for ModuleRawFuture {
+    type Output = Result<Vec<u8>, InvocationError>;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        let mut store = self.store.lock().unwrap();
+        let mut env = self.env.clone().into_mut(&mut *store);
+
+        let ptr = self.ptr;
+
+        match read_async_value(&mut env, ptr) {
+            Err(error) => Poll::Ready(Err(error)),
+            Ok((FUTURE_STATUS_PENDING, _, _)) => {
+                let mut wakers = env.data().wakers.lock().unwrap();
+                wakers.insert(ptr, cx.waker().clone());
+                Poll::Pending
+            }
+            Ok((FUTURE_STATUS_READY, result_ptr, result_len)) => {
+                let result = import_from_guest_raw(&mut env, to_fat_ptr(result_ptr, result_len));
+                Poll::Ready(result)
+            }
+            Ok((value, _, _)) => panic!(
+                "expected async value FUTURE_STATUS_PENDING ({}) or FUTURE_STATUS_READY ({}) but got: {}",
+                FUTURE_STATUS_PENDING, FUTURE_STATUS_READY, value
+            ),
+        }
+    }
+}
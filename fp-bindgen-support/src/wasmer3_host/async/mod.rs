@@ -0,0 +1,126 @@
+use super::{
+    errors::InvocationError,
+    io::from_fat_ptr,
+    mem::export_to_guest,
+    runtime::{RuntimeInstanceData, RuntimeStore},
+};
+use crate::common::{
+    mem::FatPtr,
+    r#async::{AsyncValue, FUTURE_STATUS_PENDING, FUTURE_STATUS_READY},
+};
+use serde::Serialize;
+use std::{mem::size_of, task::Waker};
+use wasmer3::{FunctionEnv, FunctionEnvMut};
+
+pub use crate::common::r#async::{SpawnedFuture, Spawner};
+
+pub mod future;
+
+/// Create an empty FutureValue in the linear memory and return a FatPtr to it.
+pub fn create_future_value(
+    env: &mut FunctionEnvMut<'_, RuntimeInstanceData>,
+) -> Result<FatPtr, InvocationError> {
+    let size = size_of::<AsyncValue>(); //TODO: Is this *actually* safe? Might be a different size in wasm land...
+    let ptr = RuntimeInstanceData::malloc(env, size as u32)?;
+
+    write_async_value(env, ptr, FUTURE_STATUS_PENDING, 0, 0)?;
+
+    Ok(ptr)
+}
+
+/// Passes the result of an async import function to the guest, and resolves
+/// the FutureValue created for it.
+///
+/// This runs in a spawned task, so there is no caller left to return an error
+/// to. Errors are logged instead.
+pub fn resolve_future_value<T: Serialize>(
+    store: &RuntimeStore,
+    env: &FunctionEnv<RuntimeInstanceData>,
+    async_ptr: FatPtr,
+    result: &T,
+) {
+    let mut store = store.lock().unwrap();
+    let mut env = env.clone().into_mut(&mut *store);
+    let resolved = export_to_guest(&mut env, result).and_then(|result_ptr| {
+        RuntimeInstanceData::guest_resolve_async_value(&mut env, async_ptr, result_ptr)
+    });
+    if let Err(error) = resolved {
+        tracing::error!("Runtime error: Cannot resolve async value: {}", error);
+    }
+}
+
+/// Note: In this case we are only interested in the pointer itself, we do not
+/// want to deserialize it (which would actually free it as well).
+/// This function also doesn't call another function since everything is
+/// contained in the env object.
+pub fn resolve_async_value(
+    mut env: FunctionEnvMut<RuntimeInstanceData>,
+    async_value_ptr: FatPtr,
+    result_ptr: FatPtr,
+) -> Result<(), InvocationError> {
+    // First assign the result ptr and mark the async value as ready:
+    let (result_ptr, result_len) = from_fat_ptr(result_ptr);
+    write_async_value(
+        &mut env,
+        async_value_ptr,
+        FUTURE_STATUS_READY,
+        result_ptr,
+        result_len,
+    )?;
+
+    env.data()
+        .wakers
+        .lock()
+        .unwrap()
+        .remove(&async_value_ptr)
+        .as_ref()
+        .map(Waker::wake_by_ref);
+    Ok(())
+}
+
+/// Reads the status, pointer and length fields of the async value at the given
+/// location.
+pub(crate) fn read_async_value(
+    env: &mut FunctionEnvMut<'_, RuntimeInstanceData>,
+    async_value_ptr: FatPtr,
+) -> Result<(u32, u32, u32), InvocationError> {
+    let memory = env.data().memory();
+    let (ptr, _) = from_fat_ptr(async_value_ptr);
+
+    let mut bytes = [0; 12];
+    memory
+        .view(&*env)
+        .read(ptr as u64, &mut bytes)
+        .map_err(|_| InvocationError::InvalidFatPtr(async_value_ptr))?;
+
+    let field = |index: usize| {
+        let offset = index * 4;
+        u32::from_le_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    };
+    Ok((field(0), field(1), field(2)))
+}
+
+fn write_async_value(
+    env: &mut FunctionEnvMut<'_, RuntimeInstanceData>,
+    async_value_ptr: FatPtr,
+    status: u32,
+    result_ptr: u32,
+    result_len: u32,
+) -> Result<(), InvocationError> {
+    let memory = env.data().memory();
+    let (ptr, _) = from_fat_ptr(async_value_ptr);
+
+    let mut bytes = [0; 12];
+    bytes[0..4].copy_from_slice(&status.to_le_bytes());
+    bytes[4..8].copy_from_slice(&result_ptr.to_le_bytes());
+    bytes[8..12].copy_from_slice(&result_len.to_le_bytes());
+    memory
+        .view(&*env)
+        .write(ptr as u64, &bytes)
+        .map_err(|_| InvocationError::InvalidFatPtr(async_value_ptr))
+}
@@ -0,0 +1,117 @@
+use super::{errors::InvocationError, runtime::RuntimeInstanceData};
+use crate::common::mem::{resolve_guest_fat_ptr, FatPtr, FAT_PTR_LEN_HEADER_SIZE};
+use rmp_serde::{decode::ReadReader, Deserializer, Serializer};
+use serde::{Deserialize, Serialize};
+use wasmer3::{FunctionEnvMut, MemoryView};
+
+/// Serialize the given value to MessagePack
+pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let mut serializer = Serializer::new(&mut buffer)
+        .with_struct_map()
+        .with_human_readable();
+    value.serialize(&mut serializer).unwrap();
+    buffer
+}
+
+/// Deserialize the given MessagePack-encoded slice
+pub fn deserialize_from_slice<'a, T: Deserialize<'a>>(
+    slice: &'a [u8],
+) -> Result<T, InvocationError> {
+    let mut deserializer = rmp_serde::Deserializer::new(slice).with_human_readable();
+    Ok(T::deserialize(&mut deserializer)?)
+}
+
+/// Serialize an object from the linear memory and after that free up the memory
+pub fn import_from_guest<'de, T: Deserialize<'de>>(
+    env: &mut FunctionEnvMut<'_, RuntimeInstanceData>,
+    fat_ptr: FatPtr,
+) -> Result<T, InvocationError> {
+    let value = import_from_guest_raw(env, fat_ptr)?;
+
+    let mut deserializer =
+        Deserializer::<ReadReader<&[u8]>>::new(value.as_ref()).with_human_readable();
+    Ok(T::deserialize(&mut deserializer)?)
+}
+
+/// Retrieve a serialized object from the linear memory as a Vec<u8> and free up
+/// the memory it was using.
+///
+/// Useful when the consumer wants to pass the result, without having the
+/// deserialize and serialize it.
+pub fn import_from_guest_raw(
+    env: &mut FunctionEnvMut<'_, RuntimeInstanceData>,
+    fat_ptr: FatPtr,
+) -> Result<Vec<u8>, InvocationError> {
+    if fat_ptr == 0 {
+        // This may happen with async calls that don't return a result:
+        return Ok(Vec::new());
+    }
+
+    let memory = env.data().memory();
+
+    let value = read_fat_ptr(&memory.view(&*env), fat_ptr);
+
+    // The memory is freed even if it could not be read, so it isn't leaked.
+    // Freeing an invalid fat pointer may fail as well, in which case the
+    // original error is returned:
+    let freed = RuntimeInstanceData::free(env, fat_ptr);
+    let value = value?;
+    freed?;
+
+    Ok(value)
+}
+
+/// Serialize a value and put it in linear memory.
+pub fn export_to_guest<T: Serialize>(
+    env: &mut FunctionEnvMut<'_, RuntimeInstanceData>,
+    value: &T,
+) -> Result<FatPtr, InvocationError> {
+    export_to_guest_raw(env, rmp_serde::to_vec(value)?)
+}
+
+/// Copy the buffer into linear memory.
+pub fn export_to_guest_raw(
+    env: &mut FunctionEnvMut<'_, RuntimeInstanceData>,
+    buffer: Vec<u8>,
+) -> Result<FatPtr, InvocationError> {
+    let memory = env.data().memory();
+
+    // Make sure the length fits in the fat pointer or its length header:
+    if buffer.len() > u32::MAX as usize {
+        return Err(InvocationError::PayloadTooLarge(buffer.len()));
+    }
+
+    let len = buffer.len() as u32;
+
+    let fat_ptr = RuntimeInstanceData::malloc(env, len)?;
+
+    let (ptr, _) = resolve_fat_ptr(&memory.view(&*env), fat_ptr)?;
+    memory
+        .view(&*env)
+        .write(ptr as u64, &buffer)
+        .map_err(|_| InvocationError::InvalidFatPtr(fat_ptr))?;
+
+    Ok(fat_ptr)
+}
+
+/// Reads the data the given fat pointer points to.
+fn read_fat_ptr(view: &MemoryView, fat_ptr: FatPtr) -> Result<Vec<u8>, InvocationError> {
+    let (ptr, len) = resolve_fat_ptr(view, fat_ptr)?;
+
+    let mut value = vec![0; len as usize];
+    view.read(ptr as u64, &mut value)
+        .map_err(|_| InvocationError::InvalidFatPtr(fat_ptr))?;
+    Ok(value)
+}
+
+/// Returns the offset of the data and its length for the given fat pointer,
+/// reading the length from its header if the fat pointer has one.
+fn resolve_fat_ptr(view: &MemoryView, fat_ptr: FatPtr) -> Result<(u32, u32), InvocationError> {
+    resolve_guest_fat_ptr(fat_ptr, |ptr| {
+        let mut header = [0; FAT_PTR_LEN_HEADER_SIZE as usize];
+        view.read(ptr as u64, &mut header).ok()?;
+        Some(header)
+    })
+    .ok_or(InvocationError::InvalidFatPtr(fat_ptr))
+}
@@ -0,0 +1,9 @@
+#[cfg(feature = "async")]
+pub mod r#async;
+
+pub mod errors;
+#[cfg(feature = "async")]
+pub mod io;
+pub mod mem;
+pub mod panic;
+pub mod runtime;
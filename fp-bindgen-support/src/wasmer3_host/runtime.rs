@@ -0,0 +1,207 @@
+use super::errors::{InvocationError, RuntimeError};
+#[cfg(feature = "async")]
+use super::r#async::Spawner;
+use crate::common::mem::FatPtr;
+use std::any::Any;
+#[cfg(feature = "async")]
+use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::sync::{Arc, Mutex, Weak};
+#[cfg(feature = "async")]
+use std::task::Waker;
+use wasmer3::{AsStoreMut, ExportError, FunctionEnvMut, Instance, Memory, Store, TypedFunction};
+
+/// Shared handle to the store that holds a single plugin instance.
+///
+/// Wasmer requires exclusive access to the store for every call into the
+/// guest, so the store is guarded by a mutex that is shared between the
+/// runtime and any tasks spawned for async imports.
+pub type RuntimeStore = Arc<Mutex<Store>>;
+
+#[derive(Default)]
+pub struct RuntimeInstanceData {
+    pub(crate) memory: Option<Memory>,
+
+    #[cfg(feature = "async")]
+    pub(crate) wakers: Arc<Mutex<HashMap<FatPtr, Waker>>>,
+
+    store: Weak<Mutex<Store>>,
+
+    context: Option<Arc<dyn Any + Send + Sync>>,
+
+    #[cfg(feature = "async")]
+    spawner: Option<Arc<dyn Spawner>>,
+
+    __fp_free: Option<TypedFunction<FatPtr, ()>>,
+
+    __fp_guest_resolve_async_value: Option<TypedFunction<(FatPtr, FatPtr), ()>>,
+
+    __fp_malloc: Option<TypedFunction<u32, FatPtr>>,
+}
+
+impl RuntimeInstanceData {
+    /// Creates instance data for an instance that lives in the given store,
+    /// holding the given context.
+    pub fn new<C: Any + Send + Sync>(store: &RuntimeStore, context: C) -> Self {
+        Self {
+            store: Arc::downgrade(store),
+            context: Some(Arc::new(context)),
+            ..Default::default()
+        }
+    }
+
+    /// Looks up the exports the runtime relies on from the given instance.
+    pub fn init_with_instance(
+        env: &mut FunctionEnvMut<'_, Self>,
+        instance: &Instance,
+    ) -> Result<(), ExportError> {
+        let (data, store) = env.data_and_store_mut();
+
+        data.memory = Some(instance.exports.get_memory("memory")?.clone());
+        data.__fp_free = Some(instance.exports.get_typed_function(&store, "__fp_free")?);
+        data.__fp_guest_resolve_async_value = instance
+            .exports
+            .get_typed_function(&store, "__fp_guest_resolve_async_value")
+            .ok();
+        data.__fp_malloc = Some(instance.exports.get_typed_function(&store, "__fp_malloc")?);
+        Ok(())
+    }
+
+    /// Returns the store this instance lives in.
+    ///
+    /// This is used by async imports, which need to call back into the guest
+    /// once their result is ready.
+    pub fn store(&self) -> Result<RuntimeStore, InvocationError> {
+        self.store
+            .upgrade()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "store was already dropped",
+            ))
+    }
+
+    /// Returns the context that was passed when the runtime was created.
+    pub fn context<C: Any + Send + Sync>(&self) -> Result<Arc<C>, InvocationError> {
+        self.context
+            .clone()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "no context was set",
+            ))?
+            .downcast()
+            .map_err(|_| InvocationError::InstanceDataUnavailable("context has an unexpected type"))
+    }
+
+    /// Sets the spawner that is used to run the futures returned by async
+    /// imported functions.
+    #[cfg(feature = "async")]
+    pub fn set_spawner(&mut self, spawner: impl Spawner) {
+        self.spawner = Some(Arc::new(spawner));
+    }
+
+    /// Spawns the given future using the spawner that was set for the runtime.
+    #[cfg(feature = "async")]
+    pub fn spawn(
+        &self,
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), InvocationError> {
+        self.spawner
+            .as_ref()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "no spawner was set",
+            ))?
+            .spawn(Box::pin(future));
+        Ok(())
+    }
+
+    pub(crate) fn memory(&self) -> Memory {
+        self.memory
+            .clone()
+            .expect("Runtime error: Instance data was not initialized")
+    }
+
+    pub fn guest_resolve_async_value(
+        env: &mut FunctionEnvMut<'_, Self>,
+        async_ptr: FatPtr,
+        result_ptr: FatPtr,
+    ) -> Result<(), InvocationError> {
+        let function = env
+            .data()
+            .__fp_guest_resolve_async_value
+            .clone()
+            .ok_or_else(|| not_exported("__fp_guest_resolve_async_value"))?;
+        Ok(function.call(env, async_ptr, result_ptr)?)
+    }
+
+    pub fn malloc(env: &mut FunctionEnvMut<'_, Self>, len: u32) -> Result<FatPtr, InvocationError> {
+        let function = env
+            .data()
+            .__fp_malloc
+            .clone()
+            .ok_or_else(|| not_exported("__fp_malloc"))?;
+        Ok(function.call(env, len)?)
+    }
+
+    pub fn free(env: &mut FunctionEnvMut<'_, Self>, ptr: FatPtr) -> Result<(), InvocationError> {
+        let function = env
+            .data()
+            .__fp_free
+            .clone()
+            .ok_or_else(|| not_exported("__fp_free"))?;
+        Ok(function.call(env, ptr)?)
+    }
+}
+
+fn not_exported(name: &str) -> InvocationError {
+    InvocationError::FunctionNotExported(name.to_owned())
+}
+
+/// Calls the `_initialize` export of the plugin, if it has one.
+///
+/// Reactor modules, such as plugins written in TinyGo, export this function to
+/// initialize themselves before any of their other exports may be called.
+pub fn initialize_instance(
+    store: &mut impl AsStoreMut,
+    instance: &Instance,
+) -> Result<(), RuntimeError> {
+    if !instance.exports.contains("_initialize") {
+        return Ok(());
+    }
+
+    let initialize = instance
+        .exports
+        .get_typed_function::<(), ()>(&*store, "_initialize")
+        .map_err(|error| RuntimeError::InitializationFailed(error.to_string()))?;
+    initialize
+        .call(store)
+        .map_err(|error| RuntimeError::InitializationFailed(error.to_string()))
+}
+
+/// Verifies that the plugin was built against the protocol with the given
+/// fingerprint, by calling its `__fp_protocol_version` export.
+///
+/// Plugins that don't export `__fp_protocol_version`, such as those built with
+/// older bindings, cannot be verified and are accepted. If the function is
+/// exported, but cannot be called, `RuntimeError::ProtocolCheckFailed` is
+/// returned.
+pub fn check_protocol_version(
+    store: &mut impl AsStoreMut,
+    instance: &Instance,
+    expected: u64,
+) -> Result<(), RuntimeError> {
+    if !instance.exports.contains("__fp_protocol_version") {
+        return Ok(());
+    }
+
+    let protocol_version = instance
+        .exports
+        .get_typed_function::<(), u64>(&*store, "__fp_protocol_version")
+        .map_err(|error| RuntimeError::ProtocolCheckFailed(error.to_string()))?;
+    let found = protocol_version
+        .call(store)
+        .map_err(|error| RuntimeError::ProtocolCheckFailed(error.to_string()))?;
+    if found == expected {
+        Ok(())
+    } else {
+        Err(RuntimeError::ProtocolMismatch { expected, found })
+    }
+}
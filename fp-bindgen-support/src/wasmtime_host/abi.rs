@@ -0,0 +1,79 @@
+/// Variant of [`crate::common::abi::WasmAbi`] for use with Wasmtime.
+///
+/// Wasmtime's typed functions only accept 32- and 64-bit values, so booleans
+/// and narrower integers are widened to 32 bits, which is also how the guest
+/// passes them.
+pub trait WasmtimeAbi {
+    type AbiType;
+
+    fn to_abi(self) -> Self::AbiType;
+    fn from_abi(value: Self::AbiType) -> Self;
+}
+
+impl WasmtimeAbi for bool {
+    type AbiType = u32;
+
+    #[inline]
+    fn to_abi(self) -> Self::AbiType {
+        if self {
+            1
+        } else {
+            0
+        }
+    }
+
+    #[inline]
+    fn from_abi(value: Self::AbiType) -> Self {
+        value != 0
+    }
+}
+
+macro_rules! widened_wasmtime_abi {
+    ($ty:ty => $abi_ty:ty) => {
+        impl WasmtimeAbi for $ty {
+            type AbiType = $abi_ty;
+
+            #[inline]
+            fn to_abi(self) -> Self::AbiType {
+                self as $abi_ty
+            }
+
+            #[inline]
+            fn from_abi(value: Self::AbiType) -> Self {
+                value as $ty
+            }
+        }
+    };
+    ($($ty:ty => $abi_ty:ty),*) => {
+        $(
+            widened_wasmtime_abi!($ty => $abi_ty);
+        )*
+    }
+}
+
+widened_wasmtime_abi!(u8 => u32, u16 => u32, i8 => i32, i16 => i32);
+
+macro_rules! identity_wasmtime_abi {
+    ($ty:ty) => {
+        impl WasmtimeAbi for $ty {
+            type AbiType = $ty;
+
+            #[inline]
+            fn to_abi(self) -> Self::AbiType {
+                self
+            }
+
+            #[inline]
+            fn from_abi(value: Self::AbiType) -> Self {
+                value
+            }
+        }
+    };
+    ($($ty:ty),*) => {
+        $(
+            identity_wasmtime_abi!($ty);
+        )*
+    }
+}
+
+identity_wasmtime_abi!((), u32, u64, i32, i64, f32, f64);
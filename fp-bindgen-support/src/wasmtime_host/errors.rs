@@ -0,0 +1,63 @@
+use crate::common::mem::FatPtr;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RuntimeError {
+    #[error(transparent)]
+    WasmtimeError(#[from] wasmtime::Error),
+
+    #[error(
+        "plugin was built against a different protocol (expected version {expected:#018x}, found {found:#018x})"
+    )]
+    ProtocolMismatch { expected: u64, found: u64 },
+
+    #[error("could not check the protocol version of the plugin: {0}")]
+    ProtocolCheckFailed(String),
+
+    #[error("plugin failed to initialize: {0}")]
+    InitializationFailed(String),
+}
+
+#[derive(Debug, Error)]
+pub enum InvocationError {
+    #[error("expected function was not exported: {0}")]
+    FunctionNotExported(String),
+
+    #[error("returned data did not match expected type")]
+    UnexpectedReturnType,
+
+    #[error("could not deserialize data: {0}")]
+    DeserializationError(#[from] rmp_serde::decode::Error),
+
+    #[error("could not serialize data: {0}")]
+    SerializationError(#[from] rmp_serde::encode::Error),
+
+    #[error("payload is too large to be passed to the guest ({0} bytes)")]
+    PayloadTooLarge(usize),
+
+    #[error("guest memory could not be accessed through pointer: {0:#x}")]
+    InvalidFatPtr(FatPtr),
+
+    #[error("runtime instance data is unavailable: {0}")]
+    InstanceDataUnavailable(&'static str),
+
+    #[error("guest panicked: {message}")]
+    GuestPanic {
+        message: String,
+        location: Option<String>,
+    },
+
+    #[error(transparent)]
+    WasmtimeError(wasmtime::Error),
+}
+
+impl From<wasmtime::Error> for InvocationError {
+    fn from(error: wasmtime::Error) -> Self {
+        // Errors returned by our own import functions, such as guest panics,
+        // are unwrapped from the trap:
+        match error.downcast::<InvocationError>() {
+            Ok(error) => error,
+            Err(error) => Self::WasmtimeError(error),
+        }
+    }
+}
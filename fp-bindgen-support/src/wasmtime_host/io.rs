@@ -0,0 +1,11 @@
+use crate::common::mem::FatPtr;
+
+/// Get a regular pointer and the length from a fat pointer
+pub(crate) fn from_fat_ptr(ptr: FatPtr) -> (u32, u32) {
+    ((ptr >> 32) as u32, (ptr & 0xffffffff) as u32)
+}
+
+/// Create a fat pointer from a ptr and length
+pub(crate) fn to_fat_ptr(ptr: u32, len: u32) -> FatPtr {
+    (ptr as FatPtr) << 32 | (len as FatPtr)
+}
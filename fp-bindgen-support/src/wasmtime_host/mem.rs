@@ -0,0 +1,217 @@
+use super::{errors::InvocationError, runtime::RuntimeInstanceData};
+use crate::common::mem::{resolve_guest_fat_ptr, FatPtr, FAT_PTR_LEN_HEADER_SIZE};
+use rmp_serde::{decode::ReadReader, Deserializer, Serializer};
+use serde::{Deserialize, Serialize};
+use wasmtime::{AsContext, AsContextMut, Memory};
+
+/// Serialize the given value to MessagePack
+pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let mut serializer = Serializer::new(&mut buffer)
+        .with_struct_map()
+        .with_human_readable();
+    value.serialize(&mut serializer).unwrap();
+    buffer
+}
+
+/// Deserialize the given MessagePack-encoded slice
+pub fn deserialize_from_slice<'a, T: Deserialize<'a>>(
+    slice: &'a [u8],
+) -> Result<T, InvocationError> {
+    let mut deserializer = rmp_serde::Deserializer::new(slice).with_human_readable();
+    Ok(T::deserialize(&mut deserializer)?)
+}
+
+/// Serialize an object from the linear memory and after that free up the memory
+pub fn import_from_guest<'de, T: Deserialize<'de>>(
+    store: impl AsContextMut<Data = RuntimeInstanceData>,
+    fat_ptr: FatPtr,
+) -> Result<T, InvocationError> {
+    let value = import_from_guest_raw(store, fat_ptr)?;
+
+    let mut deserializer =
+        Deserializer::<ReadReader<&[u8]>>::new(value.as_ref()).with_human_readable();
+    Ok(T::deserialize(&mut deserializer)?)
+}
+
+/// Retrieve a serialized object from the linear memory as a Vec<u8> and free up
+/// the memory it was using.
+///
+/// Useful when the consumer wants to pass the result, without having the
+/// deserialize and serialize it.
+pub fn import_from_guest_raw(
+    mut store: impl AsContextMut<Data = RuntimeInstanceData>,
+    fat_ptr: FatPtr,
+) -> Result<Vec<u8>, InvocationError> {
+    if fat_ptr == 0 {
+        // This may happen with async calls that don't return a result:
+        return Ok(Vec::new());
+    }
+
+    let mut store = store.as_context_mut();
+    let memory = store.data().memory();
+
+    let value = read_fat_ptr(&store, memory, fat_ptr);
+
+    // The memory is freed even if it could not be read, so it isn't leaked.
+    // Freeing an invalid fat pointer may fail as well, in which case the
+    // original error is returned:
+    let freed = RuntimeInstanceData::free(&mut store, fat_ptr);
+    let value = value?;
+    freed?;
+
+    Ok(value)
+}
+
+/// Serialize a value and put it in linear memory.
+pub fn export_to_guest<T: Serialize>(
+    store: impl AsContextMut<Data = RuntimeInstanceData>,
+    value: &T,
+) -> Result<FatPtr, InvocationError> {
+    export_to_guest_raw(store, rmp_serde::to_vec(value)?)
+}
+
+/// Copy the buffer into linear memory.
+pub fn export_to_guest_raw(
+    mut store: impl AsContextMut<Data = RuntimeInstanceData>,
+    buffer: Vec<u8>,
+) -> Result<FatPtr, InvocationError> {
+    let mut store = store.as_context_mut();
+    let memory = store.data().memory();
+
+    // Make sure the length fits in the fat pointer or its length header:
+    if buffer.len() > u32::MAX as usize {
+        return Err(InvocationError::PayloadTooLarge(buffer.len()));
+    }
+
+    let len = buffer.len() as u32;
+
+    let fat_ptr = RuntimeInstanceData::malloc(&mut store, len)?;
+
+    let (ptr, _) = resolve_fat_ptr(&store, memory, fat_ptr)?;
+    memory
+        .write(&mut store, ptr as usize, &buffer)
+        .map_err(|_| InvocationError::InvalidFatPtr(fat_ptr))?;
+
+    Ok(fat_ptr)
+}
+
+/// Reads the data the given fat pointer points to.
+fn read_fat_ptr(
+    store: impl AsContext,
+    memory: Memory,
+    fat_ptr: FatPtr,
+) -> Result<Vec<u8>, InvocationError> {
+    let (ptr, len) = resolve_fat_ptr(&store, memory, fat_ptr)?;
+
+    let mut value = vec![0; len as usize];
+    memory
+        .read(&store, ptr as usize, &mut value)
+        .map_err(|_| InvocationError::InvalidFatPtr(fat_ptr))?;
+    Ok(value)
+}
+
+/// Returns the offset of the data and its length for the given fat pointer,
+/// reading the length from its header if the fat pointer has one.
+fn resolve_fat_ptr(
+    store: impl AsContext,
+    memory: Memory,
+    fat_ptr: FatPtr,
+) -> Result<(u32, u32), InvocationError> {
+    resolve_guest_fat_ptr(fat_ptr, |ptr| {
+        let mut header = [0; FAT_PTR_LEN_HEADER_SIZE as usize];
+        memory.read(&store, ptr as usize, &mut header).ok()?;
+        Some(header)
+    })
+    .ok_or(InvocationError::InvalidFatPtr(fat_ptr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::ser::Error;
+    use wasmtime::{Engine, Instance, Module, Store};
+
+    /// A guest that hands out the same memory for every allocation, and stores
+    /// the last fat pointer that was freed in its `freed` global.
+    const GUEST: &str = r#"(module
+        (memory (export "memory") 1)
+        (global $freed (export "freed") (mut i64) (i64.const 0))
+        (func (export "__fp_malloc") (param i32) (result i64)
+            (i64.or (i64.const 0x100000000000) (i64.extend_i32_u (local.get 0))))
+        (func (export "__fp_free") (param i64)
+            (global.set $freed (local.get 0))))"#;
+
+    struct Unserializable;
+
+    impl Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+            Err(S::Error::custom("cannot be serialized"))
+        }
+    }
+
+    fn instantiate() -> (Store<RuntimeInstanceData>, Instance) {
+        let engine = Engine::default();
+        let module = Module::new(&engine, GUEST).unwrap();
+        let mut store = Store::new(&engine, RuntimeInstanceData::default());
+        let instance = Instance::new(&mut store, &module, &[]).unwrap();
+        RuntimeInstanceData::init_with_instance(&mut store, &instance).unwrap();
+        (store, instance)
+    }
+
+    fn freed(store: &mut Store<RuntimeInstanceData>, instance: &Instance) -> FatPtr {
+        let freed = instance.get_global(&mut *store, "freed").unwrap();
+        freed.get(store).unwrap_i64() as FatPtr
+    }
+
+    #[test]
+    fn test_deserialize_from_slice() {
+        let buffer = serialize_to_vec(&("foo".to_owned(), 42u32));
+        let value: (String, u32) = deserialize_from_slice(&buffer).unwrap();
+        assert_eq!(value, ("foo".to_owned(), 42));
+
+        let result = deserialize_from_slice::<(String, u32)>(&buffer[..buffer.len() - 1]);
+        assert!(matches!(
+            result,
+            Err(InvocationError::DeserializationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_import_from_guest_raw() {
+        let (mut store, instance) = instantiate();
+
+        let fat_ptr = export_to_guest(&mut store, &"foo").unwrap();
+        let value: String = import_from_guest(&mut store, fat_ptr).unwrap();
+        assert_eq!(value, "foo");
+        assert_eq!(freed(&mut store, &instance), fat_ptr);
+    }
+
+    #[test]
+    fn test_import_from_guest_raw_with_malformed_fat_ptr() {
+        let (mut store, instance) = instantiate();
+
+        // The length has an unknown extension bit:
+        let fat_ptr = 0x1000 << 32 | 0x02000000;
+        let result = import_from_guest_raw(&mut store, fat_ptr);
+        assert!(matches!(result, Err(InvocationError::InvalidFatPtr(ptr)) if ptr == fat_ptr));
+        assert_eq!(freed(&mut store, &instance), fat_ptr);
+
+        // The pointer is outside of the guest memory:
+        let fat_ptr = 0x20000 << 32 | 0x10;
+        let result = import_from_guest_raw(&mut store, fat_ptr);
+        assert!(matches!(result, Err(InvocationError::InvalidFatPtr(ptr)) if ptr == fat_ptr));
+        assert_eq!(freed(&mut store, &instance), fat_ptr);
+    }
+
+    #[test]
+    fn test_export_to_guest_with_unserializable_value() {
+        let (mut store, _) = instantiate();
+
+        let result = export_to_guest(&mut store, &Unserializable);
+        assert!(matches!(
+            result,
+            Err(InvocationError::SerializationError(_))
+        ));
+    }
+}
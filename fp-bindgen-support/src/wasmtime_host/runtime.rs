@@ -0,0 +1,222 @@
+use super::errors::{InvocationError, RuntimeError};
+#[cfg(feature = "async")]
+use super::r#async::Spawner;
+use crate::common::mem::FatPtr;
+use std::any::Any;
+#[cfg(feature = "async")]
+use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::sync::{Arc, Mutex, Weak};
+#[cfg(feature = "async")]
+use std::task::Waker;
+use wasmtime::{AsContextMut, Engine, Instance, Memory, Store, TypedFunc};
+
+/// Shared handle to the store that holds a single plugin instance.
+///
+/// Wasmtime requires exclusive access to the store for every call into the
+/// guest, so the store is guarded by a mutex that is shared between the
+/// runtime and any tasks spawned for async imports.
+pub type RuntimeStore = Arc<Mutex<Store<RuntimeInstanceData>>>;
+
+#[derive(Default)]
+pub struct RuntimeInstanceData {
+    pub(crate) memory: Option<Memory>,
+
+    #[cfg(feature = "async")]
+    pub(crate) wakers: Arc<Mutex<HashMap<FatPtr, Waker>>>,
+
+    store: Weak<Mutex<Store<RuntimeInstanceData>>>,
+
+    context: Option<Arc<dyn Any + Send + Sync>>,
+
+    #[cfg(feature = "async")]
+    spawner: Option<Arc<dyn Spawner>>,
+
+    __fp_free: Option<TypedFunc<FatPtr, ()>>,
+
+    __fp_guest_resolve_async_value: Option<TypedFunc<(FatPtr, FatPtr), ()>>,
+
+    __fp_malloc: Option<TypedFunc<u32, FatPtr>>,
+}
+
+impl RuntimeInstanceData {
+    /// Creates a new store, with instance data that holds the given context
+    /// and refers back to the store.
+    pub fn new_store<C: Any + Send + Sync>(engine: &Engine, context: C) -> RuntimeStore {
+        let context: Arc<dyn Any + Send + Sync> = Arc::new(context);
+        Arc::new_cyclic(|store| {
+            Mutex::new(Store::new(
+                engine,
+                Self {
+                    store: store.clone(),
+                    context: Some(context),
+                    ..Default::default()
+                },
+            ))
+        })
+    }
+
+    /// Looks up the exports the runtime relies on from the given instance.
+    pub fn init_with_instance(
+        mut store: impl AsContextMut<Data = Self>,
+        instance: &Instance,
+    ) -> wasmtime::Result<()> {
+        let mut store = store.as_context_mut();
+
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| wasmtime::Error::msg("module does not export its memory"))?;
+        let free = instance.get_typed_func(&mut store, "__fp_free")?;
+        let malloc = instance.get_typed_func(&mut store, "__fp_malloc")?;
+        let guest_resolve_async_value = instance
+            .get_typed_func(&mut store, "__fp_guest_resolve_async_value")
+            .ok();
+
+        let data = store.data_mut();
+        data.memory = Some(memory);
+        data.__fp_free = Some(free);
+        data.__fp_guest_resolve_async_value = guest_resolve_async_value;
+        data.__fp_malloc = Some(malloc);
+        Ok(())
+    }
+
+    /// Returns the store this instance lives in.
+    ///
+    /// This is used by async imports, which need to call back into the guest
+    /// once their result is ready.
+    pub fn store(&self) -> Result<RuntimeStore, InvocationError> {
+        self.store
+            .upgrade()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "store was already dropped",
+            ))
+    }
+
+    /// Returns the context that was passed when the runtime was created.
+    pub fn context<C: Any + Send + Sync>(&self) -> Result<Arc<C>, InvocationError> {
+        self.context
+            .clone()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "no context was set",
+            ))?
+            .downcast()
+            .map_err(|_| InvocationError::InstanceDataUnavailable("context has an unexpected type"))
+    }
+
+    /// Sets the spawner that is used to run the futures returned by async
+    /// imported functions.
+    #[cfg(feature = "async")]
+    pub fn set_spawner(&mut self, spawner: impl Spawner) {
+        self.spawner = Some(Arc::new(spawner));
+    }
+
+    /// Spawns the given future using the spawner that was set for the runtime.
+    #[cfg(feature = "async")]
+    pub fn spawn(
+        &self,
+        future: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), InvocationError> {
+        self.spawner
+            .as_ref()
+            .ok_or(InvocationError::InstanceDataUnavailable(
+                "no spawner was set",
+            ))?
+            .spawn(Box::pin(future));
+        Ok(())
+    }
+
+    pub(crate) fn memory(&self) -> Memory {
+        self.memory
+            .expect("Runtime error: Instance data was not initialized")
+    }
+
+    pub fn guest_resolve_async_value(
+        mut store: impl AsContextMut<Data = Self>,
+        async_ptr: FatPtr,
+        result_ptr: FatPtr,
+    ) -> Result<(), InvocationError> {
+        let mut store = store.as_context_mut();
+        let function = store
+            .data()
+            .__fp_guest_resolve_async_value
+            .ok_or_else(|| not_exported("__fp_guest_resolve_async_value"))?;
+        Ok(function.call(&mut store, (async_ptr, result_ptr))?)
+    }
+
+    pub fn malloc(
+        mut store: impl AsContextMut<Data = Self>,
+        len: u32,
+    ) -> Result<FatPtr, InvocationError> {
+        let mut store = store.as_context_mut();
+        let function = store
+            .data()
+            .__fp_malloc
+            .ok_or_else(|| not_exported("__fp_malloc"))?;
+        Ok(function.call(&mut store, len)?)
+    }
+
+    pub fn free(
+        mut store: impl AsContextMut<Data = Self>,
+        ptr: FatPtr,
+    ) -> Result<(), InvocationError> {
+        let mut store = store.as_context_mut();
+        let function = store
+            .data()
+            .__fp_free
+            .ok_or_else(|| not_exported("__fp_free"))?;
+        Ok(function.call(&mut store, ptr)?)
+    }
+}
+
+fn not_exported(name: &str) -> InvocationError {
+    InvocationError::FunctionNotExported(name.to_owned())
+}
+
+/// Calls the `_initialize` export of the plugin, if it has one.
+///
+/// Reactor modules, such as plugins written in TinyGo, export this function to
+/// initialize themselves before any of their other exports may be called.
+pub fn initialize_instance(
+    mut store: impl AsContextMut,
+    instance: &Instance,
+) -> Result<(), RuntimeError> {
+    if instance.get_export(&mut store, "_initialize").is_none() {
+        return Ok(());
+    }
+
+    instance
+        .get_typed_func::<(), ()>(&mut store, "_initialize")
+        .and_then(|initialize| initialize.call(&mut store, ()))
+        .map_err(|error| RuntimeError::InitializationFailed(error.to_string()))
+}
+
+/// Verifies that the plugin was built against the protocol with the given
+/// fingerprint, by calling its `__fp_protocol_version` export.
+///
+/// Plugins that don't export `__fp_protocol_version`, such as those built with
+/// older bindings, cannot be verified and are accepted. If the function is
+/// exported, but cannot be called, `RuntimeError::ProtocolCheckFailed` is
+/// returned.
+pub fn check_protocol_version(
+    mut store: impl AsContextMut,
+    instance: &Instance,
+    expected: u64,
+) -> Result<(), RuntimeError> {
+    let protocol_version = match instance.get_export(&mut store, "__fp_protocol_version") {
+        Some(protocol_version) => protocol_version,
+        None => return Ok(()),
+    };
+
+    let found = protocol_version
+        .into_func()
+        .ok_or_else(|| wasmtime::Error::msg("__fp_protocol_version is not a function"))
+        .and_then(|protocol_version| protocol_version.typed::<(), u64>(&store))
+        .and_then(|protocol_version| protocol_version.call(&mut store, ()))
+        .map_err(|error| RuntimeError::ProtocolCheckFailed(error.to_string()))?;
+    if found == expected {
+        Ok(())
+    } else {
+        Err(RuntimeError::ProtocolMismatch { expected, found })
+    }
+}
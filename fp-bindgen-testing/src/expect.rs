@@ -0,0 +1,85 @@
+use std::fmt::Debug;
+
+/// Starts an assertion on the result of calling an exported function.
+///
+/// ```ignore
+/// expect(runtime.export_primitive_u8_add_three(8)).to_return(11);
+/// expect(runtime.export_async_struct(arg1, arg2).await).to_return(expected);
+/// ```
+pub fn expect<T, E>(result: Result<T, E>) -> Expectation<T, E> {
+    Expectation { result }
+}
+
+/// Assertions on the result of calling an exported function, as created by
+/// [`expect()`].
+#[must_use = "an expectation does nothing unless one of its assertions is called"]
+pub struct Expectation<T, E> {
+    result: Result<T, E>,
+}
+
+impl<T: Debug, E: Debug> Expectation<T, E> {
+    /// Asserts the call succeeded, and returns its value.
+    #[track_caller]
+    pub fn to_succeed(self) -> T {
+        match self.result {
+            Ok(value) => value,
+            Err(error) => panic!("Expected the call to succeed, but it failed: {:?}", error),
+        }
+    }
+
+    /// Asserts the call succeeded and returned the expected value.
+    #[track_caller]
+    pub fn to_return(self, expected: T)
+    where
+        T: PartialEq,
+    {
+        let value = self.to_succeed();
+        if value != expected {
+            panic!(
+                "Expected the call to return {:?}, but it returned {:?}",
+                expected, value
+            );
+        }
+    }
+
+    /// Asserts the call succeeded and returned a value matching the given
+    /// predicate.
+    #[track_caller]
+    pub fn to_satisfy(self, predicate: impl FnOnce(&T) -> bool) {
+        let value = self.to_succeed();
+        if !predicate(&value) {
+            panic!(
+                "Expected the call to return a matching value, but it returned {:?}",
+                value
+            );
+        }
+    }
+
+    /// Asserts the call failed, for instance because the plugin panicked, and
+    /// returns the error.
+    #[track_caller]
+    pub fn to_fail(self) -> E {
+        match self.result {
+            Ok(value) => panic!("Expected the call to fail, but it returned {:?}", value),
+            Err(error) => error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expectations() {
+        expect(Ok::<_, ()>(11)).to_return(11);
+        expect(Ok::<_, ()>(vec![1, 2])).to_satisfy(|value| value.len() == 2);
+        assert_eq!(expect(Err::<(), _>("panicked")).to_fail(), "panicked");
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected the call to return 12, but it returned 11")]
+    fn test_unexpected_value() {
+        expect(Ok::<_, ()>(11)).to_return(12);
+    }
+}
@@ -0,0 +1,374 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// A call to an imported function, as recorded by the [`MockHost`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportCall {
+    /// Name of the imported function.
+    pub name: String,
+
+    /// The arguments the function was called with, serialized as a JSON array.
+    pub args: Value,
+}
+
+type StubFn<Args, Ret> = Box<dyn Fn(Args) -> Ret + Send + Sync>;
+
+#[derive(Clone)]
+enum Stub {
+    /// Returns a clone of a fixed value, regardless of the arguments.
+    Value(Arc<dyn Fn() -> Box<dyn Any> + Send + Sync>),
+
+    /// Calls a function with the arguments. Contains a `StubFn`.
+    Function(Arc<dyn Any + Send + Sync>),
+}
+
+#[derive(Default)]
+struct State {
+    calls: Vec<ImportCall>,
+    stubs: HashMap<String, Stub>,
+    functions: Option<HashSet<String>>,
+}
+
+impl State {
+    #[track_caller]
+    fn assert_known(&self, name: &str) {
+        if let Some(functions) = &self.functions {
+            if !functions.contains(name) {
+                panic!("`{}` is not an imported function of the protocol", name);
+            }
+        }
+    }
+}
+
+/// Records calls to the imported functions of a plugin, and allows their
+/// return values to be stubbed.
+///
+/// Cloning a `MockHost` is cheap, and all clones share the same recorded calls
+/// and stubs. This way a test can keep a handle to the host after it has been
+/// passed to the runtime.
+#[derive(Clone, Default)]
+pub struct MockHost {
+    state: Arc<Mutex<State>>,
+}
+
+impl MockHost {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the names of the imported functions. Once registered, stubbing
+    /// or asserting on a function with any other name panics, so a misspelled
+    /// name cannot silently fall back to the default implementation.
+    ///
+    /// The `RecordingImports` adapter generated alongside the Rust runtime
+    /// bindings registers the functions of its protocol automatically.
+    pub fn register_functions(&self, names: &[&str]) {
+        self.lock()
+            .functions
+            .get_or_insert_with(HashSet::new)
+            .extend(names.iter().map(|&name| name.to_owned()));
+    }
+
+    /// Handles a call to the imported function with the given name. This is
+    /// meant to be called from an implementation of the protocol's `Imports`
+    /// trait, such as the generated `RecordingImports` adapter.
+    ///
+    /// The call is recorded, after which the stub for the function is invoked
+    /// if there is one. Otherwise, the `default` implementation is used.
+    /// The arguments are passed as a tuple, so a function with a single
+    /// argument should pass `(arg,)`.
+    ///
+    /// Panics if the function has been stubbed with a different argument or
+    /// return type.
+    pub fn call<Args, Ret>(&self, name: &str, args: Args, default: impl FnOnce(Args) -> Ret) -> Ret
+    where
+        Args: Serialize + 'static,
+        Ret: 'static,
+    {
+        match self.call_stub(name, args) {
+            Ok(ret) => ret,
+            Err(args) => default(args),
+        }
+    }
+
+    /// Handles a call to the async imported function with the given name, the
+    /// same as [`MockHost::call()`]. Stubs return their value immediately, while
+    /// the future returned by the `default` implementation is awaited.
+    pub async fn call_async<Args, Ret, Fut>(
+        &self,
+        name: &str,
+        args: Args,
+        default: impl FnOnce(Args) -> Fut,
+    ) -> Ret
+    where
+        Args: Serialize + 'static,
+        Ret: 'static,
+        Fut: Future<Output = Ret>,
+    {
+        match self.call_stub(name, args) {
+            Ok(ret) => ret,
+            Err(args) => default(args).await,
+        }
+    }
+
+    /// Records the call and invokes the stub for the function, if there is
+    /// one. Otherwise, the arguments are handed back.
+    fn call_stub<Args, Ret>(&self, name: &str, args: Args) -> Result<Ret, Args>
+    where
+        Args: Serialize + 'static,
+        Ret: 'static,
+    {
+        let stub = {
+            let mut state = self.lock();
+            state.calls.push(ImportCall {
+                name: name.to_owned(),
+                args: serde_json::to_value(&args).unwrap_or_else(|error| {
+                    panic!("Cannot record arguments of `{}`: {}", name, error)
+                }),
+            });
+            state.stubs.get(name).cloned()
+        };
+
+        match stub {
+            Some(Stub::Value(value)) => Ok(*value().downcast().unwrap_or_else(|_| {
+                panic!("Stubbed return value of `{}` has the wrong type", name)
+            })),
+            Some(Stub::Function(function)) => {
+                let function = function
+                    .downcast_ref::<StubFn<Args, Ret>>()
+                    .unwrap_or_else(|| panic!("Stub function of `{}` has the wrong type", name));
+                Ok(function(args))
+            }
+            None => Err(args),
+        }
+    }
+
+    /// Stubs the imported function with the given name to always return the
+    /// given value.
+    #[track_caller]
+    pub fn returns<Ret>(&self, name: &str, value: Ret)
+    where
+        Ret: Clone + Send + Sync + 'static,
+    {
+        self.stub(
+            name,
+            Stub::Value(Arc::new(move || Box::new(value.clone()) as Box<dyn Any>)),
+        );
+    }
+
+    /// Stubs the imported function with the given name using a function that
+    /// receives the arguments as a tuple.
+    #[track_caller]
+    pub fn stub_with<Args, Ret>(
+        &self,
+        name: &str,
+        function: impl Fn(Args) -> Ret + Send + Sync + 'static,
+    ) where
+        Args: 'static,
+        Ret: 'static,
+    {
+        let function: StubFn<Args, Ret> = Box::new(function);
+        self.stub(name, Stub::Function(Arc::new(function)));
+    }
+
+    #[track_caller]
+    fn stub(&self, name: &str, stub: Stub) {
+        let mut state = self.lock();
+        state.assert_known(name);
+        state.stubs.insert(name.to_owned(), stub);
+    }
+
+    /// Removes all stubs, after which the default implementations are used
+    /// again.
+    pub fn clear_stubs(&self) {
+        self.lock().stubs.clear();
+    }
+
+    /// Returns all recorded calls, in the order in which they were made.
+    pub fn calls(&self) -> Vec<ImportCall> {
+        self.lock().calls.clone()
+    }
+
+    /// Returns the arguments of all recorded calls to the imported function
+    /// with the given name.
+    #[track_caller]
+    pub fn calls_to(&self, name: &str) -> Vec<Value> {
+        let state = self.lock();
+        state.assert_known(name);
+        state
+            .calls
+            .iter()
+            .filter(|call| call.name == name)
+            .map(|call| call.args.clone())
+            .collect()
+    }
+
+    /// Forgets all recorded calls.
+    pub fn clear_calls(&self) {
+        self.lock().calls.clear();
+    }
+
+    /// Asserts the imported function with the given name was called at least
+    /// once.
+    #[track_caller]
+    pub fn assert_called(&self, name: &str) {
+        if self.calls_to(name).is_empty() {
+            panic!(
+                "Expected `{}` to be called, but it wasn't.\n{}",
+                name,
+                self.format_calls()
+            );
+        }
+    }
+
+    /// Asserts the imported function with the given name was never called.
+    #[track_caller]
+    pub fn assert_not_called(&self, name: &str) {
+        if !self.calls_to(name).is_empty() {
+            panic!(
+                "Expected `{}` not to be called, but it was.\n{}",
+                name,
+                self.format_calls()
+            );
+        }
+    }
+
+    /// Asserts the imported function with the given name was called the given
+    /// number of times.
+    #[track_caller]
+    pub fn assert_call_count(&self, name: &str, count: usize) {
+        let actual = self.calls_to(name).len();
+        if actual != count {
+            panic!(
+                "Expected `{}` to be called {} time(s), but it was called {} time(s).\n{}",
+                name,
+                count,
+                actual,
+                self.format_calls()
+            );
+        }
+    }
+
+    /// Asserts the imported function with the given name was called at least
+    /// once with the given arguments. The arguments are passed as a tuple, the
+    /// same as in [`MockHost::call()`].
+    #[track_caller]
+    pub fn assert_called_with(&self, name: &str, args: impl Serialize) {
+        let args = serde_json::to_value(args).expect("Cannot serialize expected arguments");
+        if !self.calls_to(name).contains(&args) {
+            panic!(
+                "Expected `{}` to be called with {}, but it wasn't.\n{}",
+                name,
+                args,
+                self.format_calls()
+            );
+        }
+    }
+
+    fn format_calls(&self) -> String {
+        let calls = self.calls();
+        if calls.is_empty() {
+            "No imported functions were called.".to_owned()
+        } else {
+            format!(
+                "Recorded calls:\n{}",
+                calls
+                    .iter()
+                    .map(|call| format!("  {}{}", call.name, call.args))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // A panicking test should not hide the recorded calls from others.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::task::{Context, Poll, Waker};
+
+    #[test]
+    fn test_records_calls() {
+        let host = MockHost::new();
+        assert_eq!(host.call("add_one", (1u32,), |(arg,)| arg + 1), 2);
+        assert_eq!(host.call("add_one", (2u32,), |(arg,)| arg + 1), 3);
+        host.call("log", ("Hello".to_owned(), 3u8), |_| ());
+
+        assert_eq!(host.calls_to("add_one"), vec![json!([1]), json!([2])]);
+        assert_eq!(
+            host.calls().last(),
+            Some(&ImportCall {
+                name: "log".to_owned(),
+                args: json!(["Hello", 3]),
+            })
+        );
+        host.assert_call_count("add_one", 2);
+        host.assert_called_with("log", ("Hello", 3));
+        host.assert_not_called("add_two");
+    }
+
+    #[test]
+    fn test_stubs() {
+        let host = MockHost::new();
+        host.returns("add_one", 42u32);
+        assert_eq!(host.call("add_one", (1u32,), |(arg,)| arg + 1), 42);
+
+        host.stub_with("add_one", |(arg,): (u32,)| arg * 10);
+        assert_eq!(host.call("add_one", (2u32,), |(arg,)| arg + 1), 20);
+
+        host.clear_stubs();
+        assert_eq!(host.call("add_one", (3u32,), |(arg,)| arg + 1), 4);
+        host.assert_call_count("add_one", 3);
+    }
+
+    #[test]
+    fn test_call_async() {
+        let host = MockHost::new();
+        let call = |arg: u32| host.call_async("add_one", (arg,), |(arg,)| async move { arg + 1 });
+        assert_eq!(poll_ready(call(1)), 2);
+
+        host.returns("add_one", 42u32);
+        assert_eq!(poll_ready(call(2)), 42);
+        host.assert_call_count("add_one", 2);
+    }
+
+    /// Polls a future that is expected to complete immediately.
+    fn poll_ready<T>(future: impl Future<Output = T>) -> T {
+        let mut context = Context::from_waker(Waker::noop());
+        match std::pin::pin!(future).poll(&mut context) {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("Future did not complete immediately"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`add_tow` is not an imported function of the protocol")]
+    fn test_registered_functions() {
+        let host = MockHost::new();
+        host.register_functions(&["add_one", "add_two"]);
+        host.returns("add_two", 42u32);
+        host.returns("add_tow", 42u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected `add_one` to be called with [2], but it wasn't.")]
+    fn test_assert_called_with() {
+        let host = MockHost::new();
+        host.call("add_one", (1u32,), |(arg,)| arg + 1);
+        host.assert_called_with("add_one", (2u32,));
+    }
+}
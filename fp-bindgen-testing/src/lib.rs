@@ -0,0 +1,41 @@
+/*!
+Test harness for plugins built using fp-bindgen.
+
+The harness runs a compiled plugin in-process, using the runtime bindings that
+were generated for its protocol, such as those from
+`BindingsType::RustWasmer2Runtime`. Alongside the bindings, the Rust runtime
+generators emit a `testing.rs` module with a `RecordingImports` adapter. It
+implements the protocol's `Imports` trait by delegating every function to
+[`MockHost::call()`], which records the call and forwards it to the wrapped
+implementation unless the function has been stubbed:
+
+```ignore
+use fp_bindgen_testing::{expect, PluginTest};
+
+#[test]
+fn primitives() {
+    let test = PluginTest::new(WASM_BYTES, |wasm, host| {
+        let runtime = Runtime::new(wasm, RecordingImports::new(host, MyImports::default()))?;
+        runtime.init()?;
+        Ok::<_, anyhow::Error>(runtime)
+    })
+    .unwrap();
+    test.imports().returns("import_primitive_u32_add_one", 42u32);
+
+    expect(test.export_primitive_u32_add_three(32)).to_return(43);
+    test.imports().assert_called_with("import_primitive_u32_add_one", (33u32,));
+}
+```
+
+The mock host records every call, so tests can assert which imported
+functions were called with which arguments, and can stub their return values
+without resorting to global state.
+ */
+
+mod expect;
+mod host;
+mod plugin;
+
+pub use expect::{expect, Expectation};
+pub use host::{ImportCall, MockHost};
+pub use plugin::PluginTest;
@@ -0,0 +1,46 @@
+use crate::MockHost;
+use std::ops::Deref;
+
+/// A plugin loaded in a runtime for testing, along with the [`MockHost`] that
+/// handles its imported functions.
+///
+/// `PluginTest` dereferences to the runtime, so the exported functions can be
+/// called on it directly.
+pub struct PluginTest<R> {
+    runtime: R,
+    imports: MockHost,
+}
+
+impl<R> PluginTest<R> {
+    /// Loads the given plugin. The `create_runtime` function receives the
+    /// plugin's Wasm bytes and a fresh [`MockHost`], and should instantiate
+    /// the runtime bindings using an `Imports` implementation that delegates
+    /// to the host, such as the generated `RecordingImports` adapter.
+    pub fn new<E>(
+        wasm_bytes: &[u8],
+        create_runtime: impl FnOnce(&[u8], MockHost) -> Result<R, E>,
+    ) -> Result<Self, E> {
+        let imports = MockHost::new();
+        let runtime = create_runtime(wasm_bytes, imports.clone())?;
+        Ok(Self { runtime, imports })
+    }
+
+    /// Returns the host that records the calls to the imported functions, and
+    /// which can be used to stub them.
+    pub fn imports(&self) -> &MockHost {
+        &self.imports
+    }
+
+    /// Returns the runtime the plugin is loaded in.
+    pub fn runtime(&self) -> &R {
+        &self.runtime
+    }
+}
+
+impl<R> Deref for PluginTest<R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.runtime
+    }
+}
@@ -0,0 +1,620 @@
+use crate::{
+    casing::Casing,
+    functions::Function,
+    protocol::Protocol,
+    types::{Enum, Field, Struct, Type, TypeIdent},
+};
+use std::fmt::{self, Display};
+
+/// Checks whether plugins that were compiled against the `old` protocol can
+/// still be used by a runtime that is built against the `new` protocol.
+///
+/// Removing an export function or adding an import function is not considered
+/// a breaking change, since existing plugins are unaffected by it. Enum
+/// variants may also be added freely, as long as the runtime does not send
+/// them to plugins that don't know about them.
+pub fn check_compatibility(old: &Protocol, new: &Protocol) -> CompatibilityReport {
+    let mut report = CompatibilityReport::default();
+
+    for function in old.import_functions.iter() {
+        match new
+            .import_functions
+            .iter()
+            .find(|f| f.name == function.name)
+        {
+            Some(new_function) => report.check_function(function, new_function),
+            None => report.push(BreakingChange::ImportFunctionRemoved {
+                name: function.name.clone(),
+            }),
+        }
+    }
+
+    for function in new.export_functions.iter() {
+        match old
+            .export_functions
+            .iter()
+            .find(|f| f.name == function.name)
+        {
+            Some(old_function) => report.check_function(old_function, function),
+            None => report.push(BreakingChange::ExportFunctionAdded {
+                name: function.name.clone(),
+            }),
+        }
+    }
+
+    for (ident, old_ty) in old.types.iter() {
+        if let Some(new_ty) = new.types.get(ident) {
+            report.check_type(&ident.format(false), old_ty, new_ty);
+        }
+    }
+
+    report
+}
+
+/// The result of [`check_compatibility()`].
+#[derive(Clone, Debug, Default)]
+pub struct CompatibilityReport {
+    pub breaking_changes: Vec<BreakingChange>,
+}
+
+impl CompatibilityReport {
+    /// Returns whether no breaking changes were found.
+    pub fn is_compatible(&self) -> bool {
+        self.breaking_changes.is_empty()
+    }
+
+    fn push(&mut self, change: BreakingChange) {
+        self.breaking_changes.push(change);
+    }
+
+    fn check_function(&mut self, old: &Function, new: &Function) {
+        let old_signature = format_signature(old);
+        let new_signature = format_signature(new);
+        if old_signature != new_signature {
+            self.push(BreakingChange::FunctionSignatureChanged {
+                name: old.name.clone(),
+                old: old_signature,
+                new: new_signature,
+            });
+        }
+    }
+
+    fn check_type(&mut self, ty: &str, old: &Type, new: &Type) {
+        match (old, new) {
+            (Type::Struct(old), Type::Struct(new)) => self.check_struct(ty, old, new),
+            (Type::Enum(old), Type::Enum(new)) => self.check_enum(ty, old, new),
+            (Type::Alias(_, old), Type::Alias(_, new)) => {
+                if old.format(false) != new.format(false) {
+                    self.push(BreakingChange::TypeChanged {
+                        ty: ty.to_owned(),
+                        old: old.format(false),
+                        new: new.format(false),
+                    });
+                }
+            }
+            (Type::Custom(old), Type::Custom(new)) => {
+                if old.rs_ty != new.rs_ty || old.ts_ty != new.ts_ty {
+                    self.push(BreakingChange::TypeChanged {
+                        ty: ty.to_owned(),
+                        old: old.rs_ty.clone(),
+                        new: new.rs_ty.clone(),
+                    });
+                }
+            }
+            (old, new) if type_kind(old) != type_kind(new) => {
+                self.push(BreakingChange::TypeChanged {
+                    ty: ty.to_owned(),
+                    old: type_kind(old).to_owned(),
+                    new: type_kind(new).to_owned(),
+                });
+            }
+            (old, new) => {
+                if old.name() != new.name() {
+                    self.push(BreakingChange::TypeChanged {
+                        ty: ty.to_owned(),
+                        old: old.name(),
+                        new: new.name(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_struct(&mut self, ty: &str, old: &Struct, new: &Struct) {
+        self.check_attribute(
+            ty,
+            "rename_all",
+            format_casing(old.options.field_casing),
+            format_casing(new.options.field_casing),
+        );
+        self.check_fields(ty, &old.fields, &new.fields);
+    }
+
+    fn check_fields(&mut self, ty: &str, old: &[Field], new: &[Field]) {
+        for (index, old_field) in old.iter().enumerate() {
+            let name = field_name(index, old_field);
+            let new_field = new
+                .iter()
+                .enumerate()
+                .find(|(index, field)| field_name(*index, field) == name)
+                .map(|(_, field)| field);
+            match new_field {
+                Some(new_field) => {
+                    let field_ty = format!("{ty}.{name}");
+                    if old_field.ty.format(false) != new_field.ty.format(false) {
+                        self.push(BreakingChange::FieldTypeChanged {
+                            ty: ty.to_owned(),
+                            field: name,
+                            old: old_field.ty.format(false),
+                            new: new_field.ty.format(false),
+                        });
+                    }
+                    self.check_attribute(
+                        &field_ty,
+                        "rename",
+                        old_field.attrs.rename.clone(),
+                        new_field.attrs.rename.clone(),
+                    );
+                    self.check_attribute(
+                        &field_ty,
+                        "flatten",
+                        old_field.attrs.flatten.then(|| "true".to_owned()),
+                        new_field.attrs.flatten.then(|| "true".to_owned()),
+                    );
+                }
+                None if !has_default(old_field) => self.push(BreakingChange::FieldRemoved {
+                    ty: ty.to_owned(),
+                    field: name,
+                }),
+                None => {}
+            }
+        }
+
+        for (index, new_field) in new.iter().enumerate() {
+            let name = field_name(index, new_field);
+            let is_added = !old
+                .iter()
+                .enumerate()
+                .any(|(index, field)| field_name(index, field) == name);
+            if is_added && !has_default(new_field) {
+                self.push(BreakingChange::FieldAdded {
+                    ty: ty.to_owned(),
+                    field: name,
+                });
+            }
+        }
+    }
+
+    fn check_enum(&mut self, ty: &str, old: &Enum, new: &Enum) {
+        let (old_options, new_options) = (&old.options, &new.options);
+        self.check_attribute(
+            ty,
+            "tag",
+            old_options.tag_prop_name.clone(),
+            new_options.tag_prop_name.clone(),
+        );
+        self.check_attribute(
+            ty,
+            "content",
+            old_options.content_prop_name.clone(),
+            new_options.content_prop_name.clone(),
+        );
+        self.check_attribute(
+            ty,
+            "untagged",
+            old_options.untagged.then(|| "true".to_owned()),
+            new_options.untagged.then(|| "true".to_owned()),
+        );
+        self.check_attribute(
+            ty,
+            "rename_all",
+            format_casing(old_options.variant_casing),
+            format_casing(new_options.variant_casing),
+        );
+
+        for old_variant in old.variants.iter() {
+            let new_variant = new
+                .variants
+                .iter()
+                .find(|variant| variant.name == old_variant.name);
+            match new_variant {
+                Some(new_variant) => {
+                    let variant_ty = format!("{ty}::{}", old_variant.name);
+                    self.check_attribute(
+                        &variant_ty,
+                        "rename",
+                        old_variant.attrs.rename.clone(),
+                        new_variant.attrs.rename.clone(),
+                    );
+                    self.check_attribute(
+                        &variant_ty,
+                        "rename_all",
+                        format_casing(old_variant.attrs.field_casing),
+                        format_casing(new_variant.attrs.field_casing),
+                    );
+                    self.check_type(&variant_ty, &old_variant.ty, &new_variant.ty);
+                }
+                None => self.push(BreakingChange::VariantRemoved {
+                    ty: ty.to_owned(),
+                    variant: old_variant.name.clone(),
+                }),
+            }
+        }
+    }
+
+    fn check_attribute(
+        &mut self,
+        ty: &str,
+        attribute: &str,
+        old: Option<String>,
+        new: Option<String>,
+    ) {
+        if old != new {
+            self.push(BreakingChange::SerdeAttributeChanged {
+                ty: ty.to_owned(),
+                attribute: attribute.to_owned(),
+                old,
+                new,
+            });
+        }
+    }
+}
+
+impl Display for CompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_compatible() {
+            return f.write_str("No breaking changes found.");
+        }
+
+        writeln!(
+            f,
+            "Found {} breaking change(s):",
+            self.breaking_changes.len()
+        )?;
+        for change in &self.breaking_changes {
+            writeln!(f, "- {change}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A change to a protocol that may break plugins compiled against an older
+/// version of it.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BreakingChange {
+    /// A function provided by the runtime was removed or renamed.
+    ImportFunctionRemoved { name: String },
+
+    /// A function that plugins are expected to export was added or renamed.
+    ExportFunctionAdded { name: String },
+
+    /// The argument types, return type or asyncness of a function changed.
+    FunctionSignatureChanged {
+        name: String,
+        old: String,
+        new: String,
+    },
+
+    /// A type was changed into an incompatible one.
+    TypeChanged {
+        ty: String,
+        old: String,
+        new: String,
+    },
+
+    /// A field without a default value was added to a struct.
+    FieldAdded { ty: String, field: String },
+
+    /// A field without a default value was removed from a struct.
+    FieldRemoved { ty: String, field: String },
+
+    /// The type of a struct field changed.
+    FieldTypeChanged {
+        ty: String,
+        field: String,
+        old: String,
+        new: String,
+    },
+
+    /// A variant was removed from an enum.
+    VariantRemoved { ty: String, variant: String },
+
+    /// A Serde attribute that affects the serialized representation changed.
+    SerdeAttributeChanged {
+        ty: String,
+        attribute: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+}
+
+impl Display for BreakingChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ImportFunctionRemoved { name } => {
+                write!(f, "Import function `{name}` was removed")
+            }
+            Self::ExportFunctionAdded { name } => write!(
+                f,
+                "Export function `{name}` was added, but is not exported by existing plugins"
+            ),
+            Self::FunctionSignatureChanged { name, old, new } => write!(
+                f,
+                "Signature of function `{name}` changed from `{old}` to `{new}`"
+            ),
+            Self::TypeChanged { ty, old, new } => {
+                write!(f, "Type `{ty}` changed from `{old}` to `{new}`")
+            }
+            Self::FieldAdded { ty, field } => write!(
+                f,
+                "Field `{field}` was added to `{ty}` without a default value"
+            ),
+            Self::FieldRemoved { ty, field } => write!(
+                f,
+                "Field `{field}` was removed from `{ty}`, but had no default value"
+            ),
+            Self::FieldTypeChanged {
+                ty,
+                field,
+                old,
+                new,
+            } => write!(
+                f,
+                "Type of field `{ty}.{field}` changed from `{old}` to `{new}`"
+            ),
+            Self::VariantRemoved { ty, variant } => {
+                write!(f, "Variant `{variant}` was removed from `{ty}`")
+            }
+            Self::SerdeAttributeChanged {
+                ty,
+                attribute,
+                old,
+                new,
+            } => write!(
+                f,
+                "Serde attribute `{attribute}` of `{ty}` changed from {} to {}",
+                format_attribute_value(old),
+                format_attribute_value(new)
+            ),
+        }
+    }
+}
+
+fn format_attribute_value(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("`{value}`"),
+        None => "(none)".to_owned(),
+    }
+}
+
+fn format_casing(casing: Casing) -> Option<String> {
+    casing.as_maybe_str().map(ToOwned::to_owned)
+}
+
+/// Formats the parts of a function's signature that affect its ABI, which
+/// excludes the names of its arguments.
+fn format_signature(function: &Function) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| arg.ty.format(false))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = function
+        .return_type
+        .as_ref()
+        .map(|ty| format!(" -> {}", ty.format(false)))
+        .unwrap_or_default();
+    let asyncness = if function.is_async { "async " } else { "" };
+    format!("{asyncness}fn({args}){return_type}")
+}
+
+fn field_name(index: usize, field: &Field) -> String {
+    field.name.clone().unwrap_or_else(|| index.to_string())
+}
+
+/// Returns whether a field may be omitted from the serialized representation.
+fn has_default(field: &Field) -> bool {
+    field.attrs.default.is_some() || is_option(&field.ty)
+}
+
+fn is_option(ident: &TypeIdent) -> bool {
+    ident.name == "Option" && ident.array.is_none()
+}
+
+fn type_kind(ty: &Type) -> &'static str {
+    match ty {
+        Type::Alias(_, _) => "alias",
+        Type::Array(_, _) => "array",
+        Type::Container(_, _) => "container",
+        Type::Custom(_) => "custom type",
+        Type::Enum(_) => "enum",
+        Type::List(_, _) => "list",
+        Type::Map(_, _, _) => "map",
+        Type::Primitive(_) => "primitive",
+        Type::String => "string",
+        Type::Struct(_) => "struct",
+        Type::Tuple(_) => "tuple",
+        Type::Unit => "unit",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{functions::FunctionList, types::TypeMap};
+
+    fn protocol(imports: &[&str], exports: &[&str], items: &[&str]) -> Protocol {
+        let mut import_functions = FunctionList::new();
+        for decl in imports {
+            import_functions.add_function(decl);
+        }
+        let mut export_functions = FunctionList::new();
+        for decl in exports {
+            export_functions.add_function(decl);
+        }
+        let types = items
+            .iter()
+            .map(|item| {
+                let ty = Type::from_item(item);
+                let ident = match &ty {
+                    Type::Enum(ty) => ty.ident.clone(),
+                    Type::Struct(ty) => ty.ident.clone(),
+                    _ => unreachable!(),
+                };
+                (ident, ty)
+            })
+            .collect::<TypeMap>();
+
+        Protocol {
+            import_functions,
+            export_functions,
+            types,
+        }
+    }
+
+    #[test]
+    fn test_identical_protocols_are_compatible() {
+        let old = protocol(
+            &["fn log(message: String);"],
+            &["fn init(config: Config) -> bool;"],
+            &["pub struct Config { pub name: String }"],
+        );
+        let new = protocol(
+            &["fn log(message: String);"],
+            &["fn init(config: Config) -> bool;"],
+            &["pub struct Config { pub name: String }"],
+        );
+
+        let report = check_compatibility(&old, &new);
+        assert!(report.is_compatible(), "{}", report);
+    }
+
+    #[test]
+    fn test_function_changes() {
+        let old = protocol(
+            &["fn log(message: String);", "fn now() -> u64;"],
+            &["fn init(config: Config) -> bool;", "fn legacy();"],
+            &[],
+        );
+        let new = protocol(
+            &["fn log(line: String);", "fn print(message: String);"],
+            &["async fn init(config: Config) -> bool;", "fn shutdown();"],
+            &[],
+        );
+
+        let report = check_compatibility(&old, &new);
+        assert_eq!(
+            report.breaking_changes,
+            vec![
+                BreakingChange::ImportFunctionRemoved {
+                    name: "now".to_owned()
+                },
+                BreakingChange::FunctionSignatureChanged {
+                    name: "init".to_owned(),
+                    old: "fn(Config) -> bool".to_owned(),
+                    new: "async fn(Config) -> bool".to_owned(),
+                },
+                BreakingChange::ExportFunctionAdded {
+                    name: "shutdown".to_owned()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_struct_changes() {
+        let old = protocol(
+            &[],
+            &[],
+            &["pub struct Config { pub name: String, pub retries: u32, pub label: Option<String> }"],
+        );
+        let new = protocol(
+            &[],
+            &[],
+            &[r#"#[fp(rename_all = "camelCase")]
+            pub struct Config {
+                pub name: Vec<String>,
+                pub timeout: u64,
+                #[fp(default)]
+                pub verbose: bool,
+                pub comment: Option<String>,
+            }"#],
+        );
+
+        let report = check_compatibility(&old, &new);
+        assert_eq!(
+            report.breaking_changes,
+            vec![
+                BreakingChange::SerdeAttributeChanged {
+                    ty: "Config".to_owned(),
+                    attribute: "rename_all".to_owned(),
+                    old: None,
+                    new: Some("camelCase".to_owned()),
+                },
+                BreakingChange::FieldTypeChanged {
+                    ty: "Config".to_owned(),
+                    field: "name".to_owned(),
+                    old: "String".to_owned(),
+                    new: "Vec<String>".to_owned(),
+                },
+                BreakingChange::FieldRemoved {
+                    ty: "Config".to_owned(),
+                    field: "retries".to_owned(),
+                },
+                BreakingChange::FieldAdded {
+                    ty: "Config".to_owned(),
+                    field: "timeout".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enum_changes() {
+        let old = protocol(
+            &[],
+            &[],
+            &[r#"#[fp(tag = "type", content = "payload")]
+            pub enum Event {
+                Started,
+                Progress { percentage: u8 },
+                Finished(String),
+            }"#],
+        );
+        let new = protocol(
+            &[],
+            &[],
+            &[r#"#[fp(tag = "kind", content = "payload")]
+            pub enum Event {
+                Progress { percentage: f32 },
+                Finished(String),
+                Cancelled,
+            }"#],
+        );
+
+        let report = check_compatibility(&old, &new);
+        assert_eq!(
+            report.breaking_changes,
+            vec![
+                BreakingChange::SerdeAttributeChanged {
+                    ty: "Event".to_owned(),
+                    attribute: "tag".to_owned(),
+                    old: Some("type".to_owned()),
+                    new: Some("kind".to_owned()),
+                },
+                BreakingChange::VariantRemoved {
+                    ty: "Event".to_owned(),
+                    variant: "Started".to_owned(),
+                },
+                BreakingChange::FieldTypeChanged {
+                    ty: "Event::Progress".to_owned(),
+                    field: "percentage".to_owned(),
+                    old: "u8".to_owned(),
+                    new: "f32".to_owned(),
+                },
+            ]
+        );
+    }
+}
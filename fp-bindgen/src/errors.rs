@@ -0,0 +1,83 @@
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+#[cfg(feature = "generators")]
+use crate::generators::OutdatedBindings;
+
+/// Errors that can occur while collecting a protocol or generating bindings
+/// for it.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum BindgenError {
+    /// A function declaration in `fp_import!` or `fp_export!` is not
+    /// supported.
+    #[error("Invalid declaration of function `{function}`: {message}")]
+    InvalidFunction { function: String, message: String },
+
+    /// A type definition, or a reference to a type, is not supported.
+    #[error("Invalid type `{ty}`: {message}")]
+    InvalidType { ty: String, message: String },
+
+    /// A bindings file could not be written.
+    #[error("Could not write `{}`: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// Generated Rust code could not be formatted. This usually means the
+    /// generator produced invalid code for the protocol.
+    #[error("Could not format `{}`: {message}", .path.display())]
+    Rustfmt { path: PathBuf, message: String },
+
+    /// Returned by `check_bindings()` if the bindings on disk are not
+    /// up-to-date.
+    #[cfg(feature = "generators")]
+    #[error(transparent)]
+    OutdatedBindings(#[from] OutdatedBindings),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{functions::Function, types::Type};
+
+    #[test]
+    fn test_invalid_function() {
+        match Function::try_new("fn my_method(&self) -> u32;") {
+            Err(BindgenError::InvalidFunction { function, .. }) => {
+                assert_eq!(function, "my_method")
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_enum() {
+        match Type::try_from_item("enum MyEnum { A = 1, B = 2 }") {
+            Err(BindgenError::InvalidType { ty, message }) => {
+                assert_eq!(ty, "MyEnum");
+                assert!(message.contains("variant `A`"));
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_field_attrs() {
+        let result = Type::try_from_item(
+            "struct MyStruct {
+                #[serde(unknown_attr)]
+                field: u32,
+            }",
+        );
+        match result {
+            Err(BindgenError::InvalidType { ty, message }) => {
+                assert_eq!(ty, "MyStruct");
+                assert!(message.contains("unknown_attr"));
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
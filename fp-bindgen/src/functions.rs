@@ -1,11 +1,11 @@
 use crate::utils::normalize_return_type;
-use crate::{docs::get_doc_lines, types::TypeIdent};
+use crate::{docs::get_doc_lines, errors::BindgenError, types::TypeIdent};
 use quote::ToTokens;
-use std::{collections::BTreeSet, convert::TryFrom};
+use std::{collections::BTreeSet, convert::TryFrom, iter::FromIterator};
 use syn::{FnArg, ForeignItemFn};
 
 /// Maps from function name to the stringified function declaration.
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct FunctionList(BTreeSet<Function>);
 
 impl FunctionList {
@@ -13,6 +13,11 @@ impl FunctionList {
         self.0.insert(Function::new(function_decl));
     }
 
+    pub fn try_add_function(&mut self, function_decl: &str) -> Result<(), BindgenError> {
+        self.0.insert(Function::try_new(function_decl)?);
+        Ok(())
+    }
+
     pub fn iter(&self) -> std::collections::btree_set::Iter<'_, Function> {
         self.0.iter()
     }
@@ -22,6 +27,12 @@ impl FunctionList {
     }
 }
 
+impl FromIterator<Function> for FunctionList {
+    fn from_iter<T: IntoIterator<Item = Function>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 impl IntoIterator for FunctionList {
     type Item = Function;
     type IntoIter = std::collections::btree_set::IntoIter<Function>;
@@ -40,7 +51,7 @@ impl<'a> IntoIterator for &'a FunctionList {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Function {
     pub name: String,
     pub doc_lines: Vec<String>,
@@ -51,41 +62,55 @@ pub struct Function {
 
 impl Function {
     pub fn new(decl: &str) -> Self {
-        let item =
-            syn::parse_str::<ForeignItemFn>(decl).expect("Cannot parse function declaration");
+        Self::try_new(decl).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_new(decl: &str) -> Result<Self, BindgenError> {
+        let item = syn::parse_str::<ForeignItemFn>(decl).map_err(|error| {
+            BindgenError::InvalidFunction {
+                function: decl.trim().to_owned(),
+                message: format!("Cannot parse function declaration: {error}"),
+            }
+        })?;
 
         let name = item.sig.ident.to_string();
+        let invalid_function = |message: String| BindgenError::InvalidFunction {
+            function: name.clone(),
+            message,
+        };
+
         let doc_lines = get_doc_lines(&item.attrs);
         let args = item
             .sig
             .inputs
             .iter()
             .map(|input| match input {
-                FnArg::Receiver(_) => panic!(
-                    "Methods are not supported. Found `self` in function declaration: {:?}",
-                    item
-                ),
-                FnArg::Typed(arg) => FunctionArg {
+                FnArg::Receiver(_) => Err(invalid_function(
+                    "Methods are not supported, found `self` in function declaration".to_owned(),
+                )),
+                FnArg::Typed(arg) => Ok(FunctionArg {
                     name: arg.pat.to_token_stream().to_string(),
-                    ty: TypeIdent::try_from(arg.ty.as_ref()).unwrap_or_else(|e| {
-                        panic!("Invalid argument type for function {}: {}", name, e)
-                    }),
-                },
+                    ty: TypeIdent::try_from(arg.ty.as_ref()).map_err(|error| {
+                        invalid_function(format!("Invalid argument type: {error}"))
+                    })?,
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+        let return_type = normalize_return_type(&item.sig.output)
+            .map(|return_type| {
+                TypeIdent::try_from(return_type)
+                    .map_err(|error| invalid_function(format!("Invalid return type: {error}")))
             })
-            .collect();
-        let return_type = normalize_return_type(&item.sig.output).map(|return_type| {
-            TypeIdent::try_from(return_type)
-                .unwrap_or_else(|_| panic!("Invalid return type for function {}", name))
-        });
+            .transpose()?;
         let is_async = item.sig.asyncness.is_some();
 
-        Self {
+        Ok(Self {
             name,
             doc_lines,
             args,
             return_type,
             is_async,
-        }
+        })
     }
 }
 
@@ -101,7 +126,7 @@ impl PartialOrd for Function {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FunctionArg {
     pub name: String,
     pub ty: TypeIdent,
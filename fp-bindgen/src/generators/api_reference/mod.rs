@@ -0,0 +1,849 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    ApiReferenceConfig, ApiReferenceFormat,
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: ApiReferenceConfig,
+) -> BTreeMap<PathBuf, String> {
+    let extension = match config.format {
+        ApiReferenceFormat::Html => "html",
+        ApiReferenceFormat::Markdown => "md",
+    };
+
+    let mut files = BTreeMap::new();
+    files.insert(
+        PathBuf::from(format!("index.{extension}")),
+        generate_index(&import_functions, &export_functions, &types, &config),
+    );
+    for (ident, ty) in &types {
+        if has_page(ty) {
+            files.insert(
+                PathBuf::from(format!("types/{}.{extension}", get_page_name(ident))),
+                generate_type_page(ident, ty, &types, &config),
+            );
+        }
+    }
+    files
+}
+
+fn generate_index(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &ApiReferenceConfig,
+) -> String {
+    let mut page = Page::new(types, config.format, "types/");
+    page.heading(1, &page.text(&config.title));
+
+    page.heading(2, "Imports");
+    page.paragraph(
+        &page.text("Functions that are provided by the runtime and can be called by plugins."),
+    );
+    for function in import_functions {
+        format_function(&mut page, function);
+    }
+
+    page.heading(2, "Exports");
+    page.paragraph(
+        &page.text("Functions that are implemented by plugins and can be called by the runtime."),
+    );
+    for function in export_functions {
+        format_function(&mut page, function);
+    }
+
+    page.heading(2, "Types");
+    let items = types
+        .iter()
+        .filter(|(_, ty)| has_page(ty))
+        .map(|(ident, ty)| {
+            let link = page.link(
+                &page.code(&get_definition_name(ident, ty)),
+                &page.href(ident),
+            );
+            let summary = get_summary(get_doc_lines(ty));
+            if summary.is_empty() {
+                link
+            } else {
+                format!("{link}: {}", page.text(&summary))
+            }
+        })
+        .collect::<Vec<_>>();
+    page.list(&items);
+
+    page.finish(&config.title)
+}
+
+fn format_function(page: &mut Page, function: &Function) {
+    page.heading(3, &page.code(&function.name));
+    page.docs(&function.doc_lines);
+    if function.is_async {
+        page.paragraph(&format!(
+            "{} This function is asynchronous.",
+            page.strong(&page.text("Async:"))
+        ));
+    }
+
+    let args = function
+        .args
+        .iter()
+        .map(|arg| format!("{}: {}", arg.name, arg.ty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = match &function.return_type {
+        Some(ty) => format!(" -> {ty}"),
+        None => String::new(),
+    };
+    let modifier = if function.is_async { "async " } else { "" };
+    page.code_block(
+        "rust",
+        &format!("{modifier}fn {}({args}){return_type};", function.name),
+    );
+
+    if !function.args.is_empty() {
+        let rows = function
+            .args
+            .iter()
+            .map(|arg| vec![page.code(&arg.name), page.type_ref(&arg.ty)])
+            .collect::<Vec<_>>();
+        page.table(&["Argument", "Type"], &rows);
+    }
+    if let Some(ty) = &function.return_type {
+        page.paragraph(&format!(
+            "{} {}",
+            page.strong(&page.text("Returns:")),
+            page.type_ref(ty)
+        ));
+    }
+}
+
+fn generate_type_page(
+    ident: &TypeIdent,
+    ty: &Type,
+    types: &TypeMap,
+    config: &ApiReferenceConfig,
+) -> String {
+    let name = get_definition_name(ident, ty);
+    let mut page = Page::new(types, config.format, "");
+    let index = match config.format {
+        ApiReferenceFormat::Html => "../index.html",
+        ApiReferenceFormat::Markdown => "../index.md",
+    };
+    page.paragraph(&page.link(&page.text(&format!("Back to {}", config.title)), index));
+    page.heading(1, &page.code(&name));
+    page.docs(get_doc_lines(ty));
+
+    let shape = match ty {
+        Type::Alias(_, target) => {
+            page.paragraph(&format!("Alias of {}.", page.type_ref(target)));
+            format_shape(target, types)
+        }
+        Type::Custom(custom) => {
+            format_custom_type(&mut page, custom);
+            custom.ts_ty.clone()
+        }
+        Type::Enum(ty) => {
+            format_variants(&mut page, ty);
+            format_enum_shape(ty, types)
+        }
+        Type::Struct(ty) => {
+            format_fields(&mut page, &ty.fields, ty.options.field_casing);
+            format_struct_shape(ty, types)
+        }
+        _ => unreachable!("Only structs, enums, aliases and custom types have pages"),
+    };
+
+    page.heading(2, "Wire format");
+    page.paragraph(&format!(
+        "Values are serialized using MessagePack. Their shape is shown below using JSON-like \
+        notation, where {} marks properties that may be omitted.",
+        page.code("?")
+    ));
+    page.code_block("", &shape);
+
+    page.finish(&name)
+}
+
+fn format_custom_type(page: &mut Page, custom: &CustomType) {
+    page.paragraph(&format!(
+        "Custom type, which maps to {} in Rust and {} in TypeScript. It is serialized using \
+        the Serde implementation of the Rust type.",
+        page.code(&custom.rs_ty),
+        page.code(&custom.ts_ty)
+    ));
+}
+
+fn format_fields(page: &mut Page, fields: &[Field], casing: Casing) {
+    if fields.is_empty() {
+        return;
+    }
+
+    page.heading(2, "Fields");
+    if fields.iter().all(|field| field.name.is_none()) {
+        let rows = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                vec![
+                    page.code(&index.to_string()),
+                    page.type_ref(&field.ty),
+                    page.text(&join_doc_lines(&field.doc_lines)),
+                ]
+            })
+            .collect::<Vec<_>>();
+        page.table(&["Index", "Type", "Description"], &rows);
+    } else {
+        let rows = fields
+            .iter()
+            .map(|field| {
+                let serialized_name = if field.attrs.flatten {
+                    page.text("(flattened)")
+                } else {
+                    page.code(&get_field_name(field, casing))
+                };
+                vec![
+                    page.code(get_variable_name(field.name.as_deref().unwrap_or_default())),
+                    serialized_name,
+                    page.type_ref(&field.ty),
+                    page.text(&join_doc_lines(&field.doc_lines)),
+                ]
+            })
+            .collect::<Vec<_>>();
+        page.table(&["Field", "Serialized as", "Type", "Description"], &rows);
+    }
+}
+
+fn format_variants(page: &mut Page, ty: &Enum) {
+    page.heading(2, "Variants");
+    for variant in &ty.variants {
+        let name = get_variable_name(&variant.name);
+        let serialized_name = get_variant_name(variant, &ty.options);
+        if serialized_name == name {
+            page.heading(3, &page.code(name));
+        } else {
+            page.heading(
+                3,
+                &format!(
+                    "{} {}",
+                    page.code(name),
+                    page.text(&format!("(serialized as \"{serialized_name}\")"))
+                ),
+            );
+        }
+        page.docs(&variant.doc_lines);
+
+        match &variant.ty {
+            Type::Struct(variant_ty) => {
+                let rows = variant_ty
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        vec![
+                            page.code(get_variable_name(field.name.as_deref().unwrap_or_default())),
+                            page.code(&get_field_name(field, variant.attrs.field_casing)),
+                            page.type_ref(&field.ty),
+                            page.text(&join_doc_lines(&field.doc_lines)),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                if !rows.is_empty() {
+                    page.table(&["Field", "Serialized as", "Type", "Description"], &rows);
+                }
+            }
+            Type::Tuple(items) if !items.is_empty() => {
+                let rows = items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| vec![page.code(&index.to_string()), page.type_ref(item)])
+                    .collect::<Vec<_>>();
+                page.table(&["Index", "Type"], &rows);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Formats the shape of a value of the given type, as it is used inside
+/// another shape.
+fn format_shape(ident: &TypeIdent, types: &TypeMap) -> String {
+    if let Some(len) = ident.array {
+        return format!("[{}; {len}]", ident.name);
+    }
+    if ident.is_primitive() {
+        return ident.name.clone();
+    }
+
+    let arg = |index: usize| {
+        ident
+            .generic_args
+            .get(index)
+            .map(|(arg, _)| format_shape(arg, types))
+            .unwrap_or_else(|| "unknown".to_owned())
+    };
+    match types.get(ident) {
+        Some(Type::Alias(name, _)) => name.clone(),
+        Some(Type::Array(primitive, len)) => format!("[{}; {len}]", primitive.name()),
+        Some(Type::Container(name, _)) if name == "Option" => format!("{} | null", arg(0)),
+        Some(Type::Container(_, _)) => arg(0),
+        Some(Type::Custom(custom)) => custom.ident.name.clone(),
+        Some(Type::Enum(_)) | Some(Type::Struct(_)) => ident.to_string(),
+        Some(Type::List(_, _)) => {
+            let item = arg(0);
+            if item.contains(" | ") {
+                format!("({item})[]")
+            } else {
+                format!("{item}[]")
+            }
+        }
+        Some(Type::Map(_, _, _)) => format!("{{ [key: {}]: {} }}", arg(0), arg(1)),
+        Some(Type::Primitive(primitive)) => primitive.name(),
+        Some(Type::String) => "string".to_owned(),
+        Some(Type::Tuple(items)) if items.is_empty() => "null".to_owned(),
+        Some(Type::Tuple(items)) => format_tuple_shape(items, types),
+        Some(Type::Unit) => "null".to_owned(),
+        None if ident.name == "String" => "string".to_owned(),
+        None => ident.name.clone(),
+    }
+}
+
+fn format_tuple_shape(items: &[TypeIdent], types: &TypeMap) -> String {
+    format!(
+        "[{}]",
+        items
+            .iter()
+            .map(|item| format_shape(item, types))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+fn format_struct_shape(ty: &Struct, types: &TypeMap) -> String {
+    match ty.fields.as_slice() {
+        [field] if field.name.is_none() => format_shape(&field.ty, types),
+        fields if !fields.is_empty() && fields.iter().all(|field| field.name.is_none()) => {
+            let items = fields
+                .iter()
+                .map(|field| field.ty.clone())
+                .collect::<Vec<_>>();
+            format_tuple_shape(&items, types)
+        }
+        fields => {
+            let properties = format_properties(fields, ty.options.field_casing, types);
+            if properties.is_empty() {
+                "{}".to_owned()
+            } else {
+                format!("{{\n  {}\n}}", properties.join(",\n  "))
+            }
+        }
+    }
+}
+
+fn format_enum_shape(ty: &Enum, types: &TypeMap) -> String {
+    let variants = ty
+        .variants
+        .iter()
+        .map(|variant| format_variant_shape(variant, &ty.options, types))
+        .collect::<Vec<_>>();
+    format!("  {}", variants.join("\n| "))
+}
+
+fn format_variant_shape(variant: &Variant, opts: &EnumOptions, types: &TypeMap) -> String {
+    let name = get_variant_name(variant, opts);
+    let is_unit = matches!(&variant.ty, Type::Unit)
+        || matches!(&variant.ty, Type::Tuple(items) if items.is_empty());
+
+    if opts.untagged {
+        return if is_unit {
+            "null".to_owned()
+        } else {
+            format_payload_shape(variant, types)
+        };
+    }
+
+    match (&opts.tag_prop_name, &opts.content_prop_name) {
+        (Some(tag), Some(_)) if is_unit => format!("{{ \"{tag}\": \"{name}\" }}"),
+        (Some(tag), Some(content)) => format!(
+            "{{ \"{tag}\": \"{name}\", \"{content}\": {} }}",
+            format_payload_shape(variant, types)
+        ),
+        (Some(tag), None) => {
+            let mut properties = vec![format!("\"{tag}\": \"{name}\"")];
+            match &variant.ty {
+                Type::Struct(variant_ty) => properties.extend(format_properties(
+                    &variant_ty.fields,
+                    variant.attrs.field_casing,
+                    types,
+                )),
+                Type::Tuple(items) if !items.is_empty() => {
+                    properties.push(format!("...{}", format_payload_shape(variant, types)))
+                }
+                _ => {}
+            }
+            format!("{{ {} }}", properties.join(", "))
+        }
+        _ if is_unit => format!("\"{name}\""),
+        _ => format!("{{ \"{name}\": {} }}", format_payload_shape(variant, types)),
+    }
+}
+
+fn format_payload_shape(variant: &Variant, types: &TypeMap) -> String {
+    match &variant.ty {
+        Type::Struct(variant_ty) => {
+            let properties =
+                format_properties(&variant_ty.fields, variant.attrs.field_casing, types);
+            if properties.is_empty() {
+                "{}".to_owned()
+            } else {
+                format!("{{ {} }}", properties.join(", "))
+            }
+        }
+        Type::Tuple(items) if items.len() == 1 => format_shape(&items[0], types),
+        Type::Tuple(items) if !items.is_empty() => format_tuple_shape(items, types),
+        _ => "null".to_owned(),
+    }
+}
+
+/// Formats the properties of an object with the given fields. The properties
+/// of flattened structs are included directly.
+fn format_properties(fields: &[Field], casing: Casing, types: &TypeMap) -> Vec<String> {
+    let mut properties = Vec::new();
+    for field in fields {
+        if field.attrs.flatten {
+            match types.get(&field.ty) {
+                Some(Type::Struct(ty)) if ty.fields.iter().all(|field| field.name.is_some()) => {
+                    properties.extend(format_properties(
+                        &ty.fields,
+                        ty.options.field_casing,
+                        types,
+                    ));
+                }
+                Some(Type::Map(_, _, _)) => properties.push(format!(
+                    "[key: {}]: {}",
+                    field
+                        .ty
+                        .generic_args
+                        .first()
+                        .map(|(key, _)| format_shape(key, types))
+                        .unwrap_or_else(|| "string".to_owned()),
+                    field
+                        .ty
+                        .generic_args
+                        .get(1)
+                        .map(|(value, _)| format_shape(value, types))
+                        .unwrap_or_else(|| "unknown".to_owned())
+                )),
+                _ => properties.push(format!("...{}", format_shape(&field.ty, types))),
+            }
+            continue;
+        }
+
+        let is_optional = field.attrs.default.is_some()
+            || field.attrs.skip_serializing_if.is_some()
+            || is_option(&field.ty, types);
+        properties.push(format!(
+            "\"{}\"{}: {}",
+            get_field_name(field, casing),
+            if is_optional { "?" } else { "" },
+            format_shape(&field.ty, types)
+        ));
+    }
+    properties
+}
+
+fn is_option(ident: &TypeIdent, types: &TypeMap) -> bool {
+    match types.get(ident) {
+        Some(Type::Alias(_, target)) => is_option(target, types),
+        Some(Type::Container(name, _)) => name == "Option",
+        _ => false,
+    }
+}
+
+/// Collects the contents of a single page, which is rendered in the configured
+/// format. Links to type pages are prefixed with `types_path`, since the index
+/// and the type pages live in different directories.
+struct Page<'a> {
+    types: &'a TypeMap,
+    format: ApiReferenceFormat,
+    types_path: &'static str,
+    body: String,
+}
+
+impl<'a> Page<'a> {
+    fn new(types: &'a TypeMap, format: ApiReferenceFormat, types_path: &'static str) -> Self {
+        Self {
+            types,
+            format,
+            types_path,
+            body: String::new(),
+        }
+    }
+
+    fn finish(self, title: &str) -> String {
+        match self.format {
+            ApiReferenceFormat::Html => format!(
+                "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>{}</title>
+<style>
+body {{
+  font-family: sans-serif;
+  line-height: 1.5;
+  margin: 2rem auto;
+  max-width: 60rem;
+  padding: 0 1rem;
+}}
+code, pre {{ font-family: monospace; }}
+pre {{ background: #f5f5f5; overflow-x: auto; padding: 0.75rem; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #ddd; padding: 0.25rem 0.5rem; text-align: left; }}
+</style>
+</head>
+<body>
+{}</body>
+</html>
+",
+                escape_html(title),
+                self.body
+            ),
+            ApiReferenceFormat::Markdown => self.body.trim_end().to_owned() + "\n",
+        }
+    }
+
+    fn heading(&mut self, level: usize, content: &str) {
+        match self.format {
+            ApiReferenceFormat::Html => {
+                self.body += &format!("<h{level}>{content}</h{level}>\n");
+            }
+            ApiReferenceFormat::Markdown => {
+                self.body += &format!("{} {content}\n\n", "#".repeat(level));
+            }
+        }
+    }
+
+    fn paragraph(&mut self, content: &str) {
+        match self.format {
+            ApiReferenceFormat::Html => self.body += &format!("<p>{content}</p>\n"),
+            ApiReferenceFormat::Markdown => self.body += &format!("{content}\n\n"),
+        }
+    }
+
+    /// Adds the doc comments of a function or type. In Markdown, they are
+    /// included verbatim, whereas in HTML every paragraph becomes a separate
+    /// element.
+    fn docs(&mut self, doc_lines: &[String]) {
+        let lines = doc_lines
+            .iter()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+            .collect::<Vec<_>>();
+        let text = lines.join("\n");
+        if text.trim().is_empty() {
+            return;
+        }
+
+        match self.format {
+            ApiReferenceFormat::Html => {
+                for paragraph in text.split("\n\n") {
+                    if !paragraph.trim().is_empty() {
+                        self.paragraph(&escape_html(paragraph.trim()));
+                    }
+                }
+            }
+            ApiReferenceFormat::Markdown => self.paragraph(text.trim()),
+        }
+    }
+
+    fn code_block(&mut self, language: &str, code: &str) {
+        match self.format {
+            ApiReferenceFormat::Html => {
+                self.body += &format!("<pre><code>{}</code></pre>\n", escape_html(code));
+            }
+            ApiReferenceFormat::Markdown => {
+                self.body += &format!("```{language}\n{code}\n```\n\n");
+            }
+        }
+    }
+
+    fn list(&mut self, items: &[String]) {
+        if items.is_empty() {
+            return;
+        }
+
+        match self.format {
+            ApiReferenceFormat::Html => {
+                self.body += &format!(
+                    "<ul>\n{}</ul>\n",
+                    items
+                        .iter()
+                        .map(|item| format!("<li>{item}</li>\n"))
+                        .collect::<String>()
+                );
+            }
+            ApiReferenceFormat::Markdown => {
+                self.body += &items
+                    .iter()
+                    .map(|item| format!("- {item}\n"))
+                    .collect::<String>();
+                self.body += "\n";
+            }
+        }
+    }
+
+    fn table(&mut self, headers: &[&str], rows: &[Vec<String>]) {
+        match self.format {
+            ApiReferenceFormat::Html => {
+                let header = headers
+                    .iter()
+                    .map(|header| format!("<th>{header}</th>"))
+                    .collect::<String>();
+                let rows = rows
+                    .iter()
+                    .map(|row| {
+                        format!(
+                            "<tr>{}</tr>\n",
+                            row.iter()
+                                .map(|cell| format!("<td>{cell}</td>"))
+                                .collect::<String>()
+                        )
+                    })
+                    .collect::<String>();
+                self.body += &format!("<table>\n<tr>{header}</tr>\n{rows}</table>\n");
+            }
+            ApiReferenceFormat::Markdown => {
+                let format_row = |cells: Vec<&str>| format!("| {} |\n", cells.join(" | "));
+                self.body += &format_row(headers.to_vec());
+                self.body += &format_row(headers.iter().map(|_| "---").collect());
+                for row in rows {
+                    self.body += &format_row(row.iter().map(String::as_str).collect());
+                }
+                self.body += "\n";
+            }
+        }
+    }
+
+    /// Formats plain text for inclusion in the page.
+    fn text(&self, text: &str) -> String {
+        match self.format {
+            ApiReferenceFormat::Html => escape_html(text),
+            ApiReferenceFormat::Markdown => text.replace('|', "\\|"),
+        }
+    }
+
+    fn code(&self, code: &str) -> String {
+        match self.format {
+            ApiReferenceFormat::Html => format!("<code>{}</code>", escape_html(code)),
+            ApiReferenceFormat::Markdown => format!("`{code}`"),
+        }
+    }
+
+    fn strong(&self, content: &str) -> String {
+        match self.format {
+            ApiReferenceFormat::Html => format!("<strong>{content}</strong>"),
+            ApiReferenceFormat::Markdown => format!("**{content}**"),
+        }
+    }
+
+    fn link(&self, content: &str, href: &str) -> String {
+        match self.format {
+            ApiReferenceFormat::Html => format!("<a href=\"{href}\">{content}</a>"),
+            ApiReferenceFormat::Markdown => format!("[{content}]({href})"),
+        }
+    }
+
+    /// Returns the link to the page of the given type.
+    fn href(&self, ident: &TypeIdent) -> String {
+        let extension = match self.format {
+            ApiReferenceFormat::Html => "html",
+            ApiReferenceFormat::Markdown => "md",
+        };
+        format!("{}{}.{extension}", self.types_path, get_page_name(ident))
+    }
+
+    /// Formats a reference to the given type, where every type that has a page
+    /// of its own links to it.
+    fn type_ref(&self, ident: &TypeIdent) -> String {
+        let mut segments = Vec::new();
+        self.collect_segments(ident, &mut segments);
+
+        // Merge adjacent text segments, so they end up in a single code span.
+        let mut result = String::new();
+        let mut text = String::new();
+        for segment in segments {
+            match segment {
+                Segment::Text(segment) => text.push_str(&segment),
+                Segment::Link(name, href) => {
+                    if !text.is_empty() {
+                        result += &self.code(&std::mem::take(&mut text));
+                    }
+                    result += &self.link(&self.code(&name), &href);
+                }
+            }
+        }
+        if !text.is_empty() {
+            result += &self.code(&text);
+        }
+        result
+    }
+
+    fn collect_segments(&self, ident: &TypeIdent, segments: &mut Vec<Segment>) {
+        if let Some(len) = ident.array {
+            segments.push(Segment::Text(format!("[{}; {len}]", ident.name)));
+            return;
+        }
+
+        match self.types.get(ident) {
+            Some(ty) if has_page(ty) => {
+                segments.push(Segment::Link(ident.name.clone(), self.href(ident)))
+            }
+            _ => segments.push(Segment::Text(ident.name.clone())),
+        }
+        if !ident.generic_args.is_empty() {
+            segments.push(Segment::Text("<".to_owned()));
+            for (index, (arg, _)) in ident.generic_args.iter().enumerate() {
+                if index > 0 {
+                    segments.push(Segment::Text(", ".to_owned()));
+                }
+                self.collect_segments(arg, segments);
+            }
+            segments.push(Segment::Text(">".to_owned()));
+        }
+    }
+}
+
+/// Part of a type reference, which is either plain text or a link to the page
+/// of a type.
+enum Segment {
+    Text(String),
+    Link(String, String),
+}
+
+fn has_page(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Alias(_, _) | Type::Custom(_) | Type::Enum(_) | Type::Struct(_)
+    )
+}
+
+fn get_doc_lines(ty: &Type) -> &[String] {
+    match ty {
+        Type::Enum(ty) => &ty.doc_lines,
+        Type::Struct(ty) => &ty.doc_lines,
+        _ => &[],
+    }
+}
+
+/// Returns the name of a type as it is defined, including any generic
+/// parameters.
+fn get_definition_name(ident: &TypeIdent, ty: &Type) -> String {
+    match ty {
+        Type::Enum(ty) => ty.ident.format(false),
+        Type::Struct(ty) => ty.ident.format(false),
+        _ => ident.name.clone(),
+    }
+}
+
+/// Returns the file name of the page for the given type, without extension.
+fn get_page_name(ident: &TypeIdent) -> String {
+    ident.name.replace("::", "_")
+}
+
+/// Returns the first paragraph of the given doc comments, on a single line.
+fn get_summary(doc_lines: &[String]) -> String {
+    doc_lines
+        .iter()
+        .map(|line| line.trim())
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn join_doc_lines(doc_lines: &[String]) -> String {
+    doc_lines
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(field.name.as_deref().unwrap_or_default()))
+    }
+}
+
+fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_output() {
+        let mut export_functions = FunctionList::new();
+        export_functions
+            .add_function("/// Moves the <point>.\nfn move_point(point: Point) -> Point;");
+
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from("Point"),
+            Type::from_item(
+                "/// A point & its coordinates.\npub struct Point { pub x: f64, pub y: f64 }",
+            ),
+        );
+
+        let files = generate_bindings(
+            FunctionList::new(),
+            export_functions,
+            types,
+            ApiReferenceConfig::new().with_format(ApiReferenceFormat::Html),
+        );
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec![
+                &PathBuf::from("index.html"),
+                &PathBuf::from("types/Point.html")
+            ]
+        );
+
+        let index = &files[&PathBuf::from("index.html")];
+        assert!(index.contains("Moves the &lt;point&gt;."));
+        assert!(index.contains("<a href=\"types/Point.html\"><code>Point</code></a>"));
+        assert!(index.contains("A point &amp; its coordinates."));
+
+        let page = &files[&PathBuf::from("types/Point.html")];
+        assert!(page.contains("<a href=\"../index.html\">"));
+    }
+}
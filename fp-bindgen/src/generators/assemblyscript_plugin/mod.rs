@@ -0,0 +1,1857 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    generators::protocol_json::protocol_fingerprint,
+    prelude::Primitive,
+    types::{Enum, EnumOptions, Field, Type, TypeIdent, TypeMap, Variant},
+};
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    str::FromStr,
+};
+
+const TS_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "declare",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "get",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "is",
+    "keyof",
+    "let",
+    "module",
+    "namespace",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "set",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Names of local variables and parameters used by the generated functions,
+/// which arguments and decoded fields may not shadow.
+const RESERVED_NAMES: &[&str] = &[
+    "asyncValue",
+    "asyncValuePtr",
+    "attempt",
+    "callback",
+    "fields",
+    "key",
+    "reader",
+    "result",
+    "resultPtr",
+    "tag",
+    "value",
+    "variant",
+    "writer",
+];
+
+/// The support library is the same for every protocol, so it is kept in a
+/// regular AssemblyScript file that is copied into the bindings verbatim.
+const SUPPORT_SOURCE: &str = include_str!("support.ts");
+
+/// Module from which the generated exports import the functions implemented
+/// by the plugin, relative to the bindings.
+const PLUGIN_MODULE: &str = "../plugin";
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+) -> BTreeMap<PathBuf, String> {
+    let protocol_version = protocol_fingerprint(&import_functions, &export_functions, &types);
+
+    let mut generator = TypeGenerator::new(&types);
+    for (ident, ty) in &types {
+        let is_protocol_type = match ty {
+            Type::Alias(_, _) => true,
+            Type::Enum(ty) => ty.ident.generic_args.is_empty(),
+            Type::Struct(ty) => ty.ident.generic_args.is_empty(),
+            _ => false,
+        };
+        if is_protocol_type {
+            generator.visit(ident);
+        }
+    }
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        for arg in &function.args {
+            generator.visit(&arg.ty);
+        }
+        if let Some(ty) = &function.return_type {
+            generator.visit(ty);
+        }
+    }
+
+    let support_exports = get_support_exports();
+
+    BTreeMap::from([
+        (PathBuf::from("support.ts"), SUPPORT_SOURCE.to_owned()),
+        (
+            PathBuf::from("types.ts"),
+            generator.format_source(&support_exports),
+        ),
+        (
+            PathBuf::from("imports.ts"),
+            generate_imports(
+                &import_functions,
+                &export_functions,
+                &generator,
+                &support_exports,
+            ),
+        ),
+        (
+            PathBuf::from("exports.ts"),
+            generate_exports(
+                &export_functions,
+                protocol_version,
+                &generator,
+                &support_exports,
+            ),
+        ),
+    ])
+}
+
+fn generate_imports(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeGenerator,
+    support_exports: &BTreeSet<String>,
+) -> String {
+    let import_wrappers = import_functions
+        .iter()
+        .map(|function| format_import_wrapper(function, types))
+        .collect::<String>();
+    let resolvers = export_functions
+        .iter()
+        .filter(|function| function.is_async)
+        .map(|function| format_resolver(function, types))
+        .collect::<String>();
+    let body = format!(
+        "// Functions imported from the host.\n{import_wrappers}\n\
+        // Functions for resolving the results of async exports.\n{resolvers}"
+    );
+
+    format!(
+        "// ============================================= //
+// Imports for AssemblyScript plugins            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+{}
+{body}",
+        format_import_statements(&body, support_exports, &types.exports)
+    )
+}
+
+fn generate_exports(
+    export_functions: &FunctionList,
+    protocol_version: u64,
+    types: &TypeGenerator,
+    support_exports: &BTreeSet<String>,
+) -> String {
+    let export_wrappers = export_functions
+        .iter()
+        .map(|function| format_export_wrapper(function, types))
+        .collect::<String>();
+    let body = format!(
+        "export {{ __fp_free, __fp_guest_resolve_async_value, __fp_malloc }} from \"./support\";\n\
+        {export_wrappers}\n\
+        export function __fp_protocol_version(): u64 {{\n  \
+        return {protocol_version:#018x};\n\
+        }}\n"
+    );
+
+    let implementations = export_functions
+        .iter()
+        .map(|function| format!("  {},\n", get_function_name(&function.name)))
+        .collect::<String>();
+    let plugin_import = if implementations.is_empty() {
+        String::new()
+    } else {
+        format!("import {{\n{implementations}}} from \"{PLUGIN_MODULE}\";\n")
+    };
+
+    format!(
+        "// ============================================= //
+// Exports for AssemblyScript plugins            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+{plugin_import}{}
+{body}",
+        format_import_statements(&body, support_exports, &types.exports)
+    )
+}
+
+fn format_import_wrapper(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let raw_return_type = match &function.return_type {
+        _ if function.is_async => "FatPtr".to_owned(),
+        None => "void".to_owned(),
+        Some(ty) => format_raw_type(ty, types),
+    };
+    let raw_decl = format!(
+        "// @ts-ignore: decorator\n\
+        @external(\"fp\", \"__fp_gen_{name}\")\n\
+        declare function __fp_gen_{name}({}): {raw_return_type};",
+        format_raw_args(function, types)
+    );
+
+    let mut body = Vec::new();
+    for arg in function.args.iter().filter(|arg| !arg.ty.is_primitive()) {
+        let arg_name = get_arg_name(&arg.name);
+        body.push(format!("const {arg_name}Writer = new Writer();"));
+        body.push(format!(
+            "encode_{}({arg_name}Writer, {arg_name});",
+            types.fn_name(&arg.ty)
+        ));
+    }
+    let call_args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            if arg.ty.is_primitive() {
+                arg_name
+            } else {
+                format!("{arg_name}Writer.finish()")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let call = format!("__fp_gen_{name}({call_args})");
+
+    let mut handler = String::new();
+    if function.is_async {
+        handler = format_async_handler(function, types);
+        body.push(format!("const asyncValuePtr = {call};"));
+        body.push(format!("{fn_name}Callbacks.set(asyncValuePtr, callback);"));
+        body.push(format!("awaitAsyncValue(asyncValuePtr, {fn_name}Ready);"));
+    } else {
+        match &function.return_type {
+            None => body.push(format!("{call};")),
+            Some(ty) if ty.is_primitive() => body.push(format!("return {call};")),
+            Some(ty) => {
+                body.push(format!("const resultPtr = {call};"));
+                body.push(format_decode_result(ty, types, name));
+                body.push("return result;".to_owned());
+            }
+        }
+    }
+
+    let return_type = match &function.return_type {
+        _ if function.is_async => "void".to_owned(),
+        Some(ty) => types.as_type(ty),
+        None => "void".to_owned(),
+    };
+    let mut args = format_args(function, types);
+    if function.is_async {
+        args.push(format!("callback: {}Callback", fn_name.to_pascal_case()));
+    }
+
+    format!(
+        "\n{raw_decl}\n{handler}\n{}export function {fn_name}({}): {return_type} {{\n{}}}\n",
+        format_docs(&function.doc_lines, ""),
+        args.join(", "),
+        join_lines(&body, "  ")
+    )
+}
+
+/// Formats the callback type for an async import, together with the handler
+/// that is invoked once its result is available. The handler decodes the
+/// result and passes it on to the user's callback.
+fn format_async_handler(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let callback_type = format!("{}Callback", fn_name.to_pascal_case());
+    let callback_args = match &function.return_type {
+        Some(ty) => format!("result: {}", types.as_type(ty)),
+        None => String::new(),
+    };
+
+    let mut body = vec![
+        format!("const callback = {fn_name}Callbacks.get(asyncValuePtr);"),
+        format!("{fn_name}Callbacks.delete(asyncValuePtr);"),
+    ];
+    match &function.return_type {
+        Some(ty) => {
+            body.push(format_decode_result(ty, types, name));
+            body.push("callback(result);".to_owned());
+        }
+        None => {
+            body.push("Reader.open(resultPtr).close();".to_owned());
+            body.push("callback();".to_owned());
+        }
+    }
+
+    format!(
+        "\nexport type {callback_type} = ({callback_args}) => void;\n\n\
+        const {fn_name}Callbacks = new Map<FatPtr, {callback_type}>();\n\n\
+        function {fn_name}Ready(asyncValuePtr: FatPtr, resultPtr: FatPtr): void {{\n{}}}\n",
+        join_lines(&body, "  ")
+    )
+}
+
+/// Formats the function through which the plugin resolves the result of an
+/// async export.
+fn format_resolver(function: &Function, types: &TypeGenerator) -> String {
+    let fn_name = get_function_name(&function.name);
+    let mut body = vec!["const writer = new Writer();".to_owned()];
+    let args = match &function.return_type {
+        Some(ty) => {
+            body.push(format!("encode_{}(writer, result);", types.fn_name(ty)));
+            format!("asyncValue: AsyncValue, result: {}", types.as_type(ty))
+        }
+        None => {
+            body.push("writer.writeNil();".to_owned());
+            "asyncValue: AsyncValue".to_owned()
+        }
+    };
+    body.push("resolveAsyncValue(asyncValue, writer.finish());".to_owned());
+
+    format!(
+        "\n/**\n * Resolves the result of `{fn_name}()`.\n */\n\
+        export function {fn_name}Resolve({args}): void {{\n{}}}\n",
+        join_lines(&body, "  ")
+    )
+}
+
+fn format_export_wrapper(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let raw_return_type = match &function.return_type {
+        _ if function.is_async => "FatPtr".to_owned(),
+        None => "void".to_owned(),
+        Some(ty) => format_raw_type(ty, types),
+    };
+
+    let mut body = Vec::new();
+    for arg in function.args.iter().filter(|arg| !arg.ty.is_primitive()) {
+        let arg_name = get_arg_name(&arg.name);
+        body.push(format!(
+            "const {arg_name}Reader = Reader.open({arg_name}Ptr);"
+        ));
+        body.push(format!(
+            "const {arg_name} = decode_{}({arg_name}Reader);",
+            types.fn_name(&arg.ty)
+        ));
+        body.push(format!(
+            "if ({arg_name}Reader.error) {{\n  \
+            panic(\"Could not decode argument `{}` of `{fn_name}()`\");\n\
+            }}",
+            get_variable_name(&arg.name)
+        ));
+        body.push(format!("{arg_name}Reader.close();"));
+    }
+
+    let mut call_args = function
+        .args
+        .iter()
+        .map(|arg| get_arg_name(&arg.name))
+        .collect::<Vec<_>>();
+    if function.is_async {
+        call_args.push("asyncValue".to_owned());
+    }
+    let call = format!("{fn_name}({})", call_args.join(", "));
+
+    if function.is_async {
+        body.push("const asyncValue = createAsyncValue();".to_owned());
+        body.push(format!("{call};"));
+        body.push("return asyncValue;".to_owned());
+    } else {
+        match &function.return_type {
+            None => body.push(format!("{call};")),
+            Some(ty) if ty.is_primitive() => body.push(format!("return {call};")),
+            Some(ty) => {
+                body.push(format!("const result = {call};"));
+                body.push("const writer = new Writer();".to_owned());
+                body.push(format!("encode_{}(writer, result);", types.fn_name(ty)));
+                body.push("return writer.finish();".to_owned());
+            }
+        }
+    }
+
+    let raw_args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            if arg.ty.is_primitive() {
+                format!("{arg_name}: {}", types.as_type(&arg.ty))
+            } else {
+                format!("{arg_name}Ptr: FatPtr")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "\nexport function __fp_gen_{name}({raw_args}): {raw_return_type} {{\n{}}}\n",
+        join_lines(&body, "  ")
+    )
+}
+
+/// Decodes the value behind `resultPtr` into a new `result` variable.
+fn format_decode_result(ty: &TypeIdent, types: &TypeGenerator, function_name: &str) -> String {
+    format!(
+        "const reader = Reader.open(resultPtr);\n\
+        const result = decode_{}(reader);\n\
+        if (reader.error) {{\n  \
+        panic(\"Could not decode result of `{}()`\");\n\
+        }}\n\
+        reader.close();",
+        types.fn_name(ty),
+        get_function_name(function_name)
+    )
+}
+
+/// Formats the arguments of a function as it is exposed to the plugin.
+fn format_args(function: &Function, types: &TypeGenerator) -> Vec<String> {
+    function
+        .args
+        .iter()
+        .map(|arg| format!("{}: {}", get_arg_name(&arg.name), types.as_type(&arg.ty)))
+        .collect()
+}
+
+/// Formats the arguments of a raw import, where complex arguments are passed
+/// as fat pointers.
+fn format_raw_args(function: &Function, types: &TypeGenerator) -> String {
+    function
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                get_arg_name(&arg.name),
+                format_raw_type(&arg.ty, types)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_raw_type(ty: &TypeIdent, types: &TypeGenerator) -> String {
+    if ty.is_primitive() {
+        types.as_type(ty)
+    } else {
+        "FatPtr".to_owned()
+    }
+}
+
+/// Formats the import statements for all the names from the support library
+/// and the generated types that are used in the given code. Comments and
+/// re-exports are not considered usages.
+fn format_import_statements(
+    code: &str,
+    support_exports: &BTreeSet<String>,
+    type_exports: &BTreeSet<String>,
+) -> String {
+    let code = code
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            !line.starts_with("//")
+                && !line.starts_with("/*")
+                && !line.starts_with('*')
+                && !line.starts_with("export {")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    [(support_exports, "./support"), (type_exports, "./types")]
+        .iter()
+        .filter_map(|(exports, module)| {
+            let names = exports
+                .iter()
+                .filter(|name| contains_identifier(&code, name))
+                .map(|name| format!("  {name},\n"))
+                .collect::<String>();
+            if names.is_empty() {
+                None
+            } else {
+                Some(format!("import {{\n{names}}} from \"{module}\";\n"))
+            }
+        })
+        .collect()
+}
+
+/// Returns the names of the types and functions exported by the support
+/// library.
+fn get_support_exports() -> BTreeSet<String> {
+    SUPPORT_SOURCE
+        .lines()
+        .filter_map(|line| {
+            let declaration = line
+                .strip_prefix("export function ")
+                .or_else(|| line.strip_prefix("export class "))
+                .or_else(|| line.strip_prefix("export type "))?;
+            let end = declaration
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(declaration.len());
+            Some(declaration[..end].to_owned())
+        })
+        .collect()
+}
+
+/// Returns whether the given code contains the given identifier, not counting
+/// occurrences that are part of a longer identifier.
+fn contains_identifier(code: &str, name: &str) -> bool {
+    let is_identifier_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    code.match_indices(name).any(|(index, _)| {
+        let before = code[..index].chars().next_back();
+        let after = code[index + name.len()..].chars().next();
+        !before.map(is_identifier_char).unwrap_or(false)
+            && !after.map(is_identifier_char).unwrap_or(false)
+    })
+}
+
+/// How a type from the protocol is represented in AssemblyScript.
+enum AsType<'a> {
+    Primitive(Primitive),
+    String,
+    Bytes,
+    Value,
+    Unit,
+    Alias(TypeIdent),
+    Array(Primitive, usize),
+    Pointer(TypeIdent),
+    Option(TypeIdent),
+    List(TypeIdent),
+    Map(TypeIdent, TypeIdent),
+    Tuple(Vec<TypeIdent>),
+    Struct(Vec<Field>, Casing),
+    Enum(&'a Enum, TypeParams),
+}
+
+/// Maps the names of generic parameters to the arguments they are
+/// instantiated with.
+type TypeParams = Vec<(String, TypeIdent)>;
+
+/// The payload of an enum variant.
+enum Payload {
+    Unit,
+    /// A single value, which is stored in the `value` field of the variant.
+    Newtype(TypeIdent),
+    /// Multiple unnamed fields, which are stored as `f0`, `f1`, etc.
+    Tuple(Vec<TypeIdent>),
+    /// Named fields.
+    Struct(Vec<Field>, Casing),
+}
+
+/// A field as it appears in the encoded form of a struct. The fields of
+/// flattened structs are inlined into their parent.
+struct EncodedField {
+    /// Key of the field in the encoded map.
+    key: String,
+    /// Path to the field, relative to the struct being encoded.
+    path: String,
+    /// Name of the local variable that holds the field while decoding.
+    local: String,
+    field: Field,
+}
+
+/// Generates the AssemblyScript definitions for all the types that are used
+/// by the protocol. Generic types are monomorphized, so that they can be
+/// encoded without any runtime type information.
+struct TypeGenerator<'a> {
+    types: &'a TypeMap,
+    visited: BTreeSet<String>,
+
+    /// Names of all the types and functions exported by the types module.
+    exports: BTreeSet<String>,
+
+    definitions: Vec<String>,
+    functions: Vec<String>,
+}
+
+impl<'a> TypeGenerator<'a> {
+    fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            visited: BTreeSet::new(),
+            exports: BTreeSet::new(),
+            definitions: Vec::new(),
+            functions: Vec::new(),
+        }
+    }
+
+    fn format_source(&self, support_exports: &BTreeSet<String>) -> String {
+        let body = [&self.definitions, &self.functions]
+            .iter()
+            .filter(|section| !section.is_empty())
+            .map(|section| section.join("\n\n"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        format!(
+            "// ============================================= //
+// Types for AssemblyScript plugins              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+{}
+{body}
+",
+            format_import_statements(&body, support_exports, &BTreeSet::new())
+        )
+    }
+
+    fn resolve(&self, ident: &TypeIdent) -> AsType<'a> {
+        if let Some(len) = ident.array {
+            return match Primitive::from_str(&ident.name) {
+                Ok(primitive) => AsType::Array(primitive, len.get()),
+                Err(_) => AsType::Value,
+            };
+        }
+        if let Some(primitive) = ident.as_primitive() {
+            return AsType::Primitive(primitive);
+        }
+
+        let arg = |index: usize| ident.generic_args.get(index).map(|(arg, _)| arg.clone());
+        match self.types.get(ident) {
+            Some(Type::Alias(_, target)) => AsType::Alias(target.clone()),
+            Some(Type::Array(primitive, len)) => AsType::Array(*primitive, *len),
+            Some(Type::Container(name, _)) => match arg(0) {
+                Some(arg) if name == "Option" => AsType::Option(arg),
+                Some(arg) => AsType::Pointer(arg),
+                None => AsType::Value,
+            },
+            Some(Type::Custom(custom)) => match custom.ts_ty.as_str() {
+                "ArrayBuffer" | "Uint8Array" => AsType::Bytes,
+                "boolean" => AsType::Primitive(Primitive::Bool),
+                "number" => AsType::Primitive(Primitive::F64),
+                "string" => AsType::String,
+                _ => AsType::Value,
+            },
+            Some(Type::Enum(ty)) => AsType::Enum(ty, get_type_params(&ty.ident, ident)),
+            Some(Type::List(_, _)) => arg(0).map(AsType::List).unwrap_or(AsType::Value),
+            Some(Type::Map(_, _, _)) => match (arg(0), arg(1)) {
+                (Some(key), Some(value)) => AsType::Map(key, value),
+                _ => AsType::Value,
+            },
+            Some(Type::Primitive(primitive)) => AsType::Primitive(*primitive),
+            Some(Type::String) => AsType::String,
+            Some(Type::Struct(ty)) => {
+                let params = get_type_params(&ty.ident, ident);
+                let fields = ty
+                    .fields
+                    .iter()
+                    .map(|field| Field {
+                        ty: substitute(&field.ty, &params),
+                        ..field.clone()
+                    })
+                    .collect::<Vec<_>>();
+                match fields.as_slice() {
+                    [field] if field.name.is_none() => AsType::Alias(field.ty.clone()),
+                    _ if !fields.is_empty() && fields.iter().all(|field| field.name.is_none()) => {
+                        AsType::Tuple(fields.into_iter().map(|field| field.ty).collect())
+                    }
+                    _ => AsType::Struct(fields, ty.options.field_casing),
+                }
+            }
+            Some(Type::Tuple(items)) if items.is_empty() => AsType::Unit,
+            Some(Type::Tuple(items)) => AsType::Tuple(items.clone()),
+            Some(Type::Unit) => AsType::Unit,
+            None if ident.name == "String" => AsType::String,
+            None => AsType::Value,
+        }
+    }
+
+    /// Resolves the given type, following any aliases and pointers.
+    fn resolve_alias(&self, ident: &TypeIdent) -> AsType<'a> {
+        match self.resolve(ident) {
+            AsType::Alias(target) | AsType::Pointer(target) => self.resolve_alias(&target),
+            resolved => resolved,
+        }
+    }
+
+    /// Returns the type that remains after following any aliases and
+    /// pointers.
+    fn resolve_alias_ident(&self, ident: &TypeIdent) -> TypeIdent {
+        match self.resolve(ident) {
+            AsType::Alias(target) | AsType::Pointer(target) => self.resolve_alias_ident(&target),
+            _ => ident.clone(),
+        }
+    }
+
+    /// Returns the name under which the type is known in the bindings. This
+    /// name is used for named types themselves, as well as for the encoding
+    /// functions of all types.
+    fn mangle(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            AsType::Primitive(primitive) => primitive.name(),
+            AsType::String => "String".to_owned(),
+            AsType::Bytes => "Bytes".to_owned(),
+            AsType::Value => "RawValue".to_owned(),
+            AsType::Unit => "Unit".to_owned(),
+            AsType::Array(primitive, len) => format!("Array_{}_{len}", primitive.name()),
+            AsType::Pointer(inner) => self.mangle(&inner),
+            AsType::Option(inner) => format!("Option_{}", self.mangle(&inner)),
+            AsType::List(inner) => format!("Vec_{}", self.mangle(&inner)),
+            AsType::Map(key, value) => {
+                format!("Map_{}_{}", self.mangle(&key), self.mangle(&value))
+            }
+            AsType::Tuple(_) if matches!(self.types.get(ident), Some(Type::Struct(_))) => {
+                self.mangle_named(ident)
+            }
+            AsType::Tuple(items) => format!(
+                "Tuple_{}",
+                items
+                    .iter()
+                    .map(|item| self.mangle(item))
+                    .collect::<Vec<_>>()
+                    .join("_")
+            ),
+            AsType::Alias(_) | AsType::Struct(_, _) | AsType::Enum(_, _) => {
+                self.mangle_named(ident)
+            }
+        }
+    }
+
+    fn mangle_named(&self, ident: &TypeIdent) -> String {
+        std::iter::once(ident.name.clone())
+            .chain(ident.generic_args.iter().map(|(arg, _)| self.mangle(arg)))
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    /// Returns the AssemblyScript type for the given type.
+    fn as_type(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            AsType::Primitive(primitive) => primitive.name(),
+            AsType::String => "string".to_owned(),
+            AsType::Bytes => "Uint8Array".to_owned(),
+            AsType::Array(primitive, _) => format!("StaticArray<{}>", primitive.name()),
+            AsType::Pointer(inner) => self.as_type(&inner),
+            AsType::Option(inner) if self.is_reference(&inner) => {
+                format!("{} | null", self.as_type(&inner))
+            }
+            AsType::Option(inner) => format!("Box<{}> | null", self.as_type(&inner)),
+            AsType::List(inner) => format!("Array<{}>", self.as_type(&inner)),
+            AsType::Map(key, value) => {
+                format!("Map<{}, {}>", self.as_type(&key), self.as_type(&value))
+            }
+            _ => self.mangle(ident),
+        }
+    }
+
+    /// Returns the suffix of the encoding functions for the given type, which
+    /// for aliases and pointers are those of the type they refer to.
+    fn fn_name(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            AsType::Alias(target) | AsType::Pointer(target) => self.fn_name(&target),
+            _ => self.mangle(ident),
+        }
+    }
+
+    /// Returns whether values of the given type are references, which can be
+    /// made nullable directly. Value types need to be boxed instead.
+    fn is_reference(&self, ident: &TypeIdent) -> bool {
+        match self.resolve_alias(ident) {
+            AsType::Primitive(_) | AsType::Option(_) => false,
+            AsType::Enum(ty, params) => !self.is_plain_enum(ty, &params),
+            _ => true,
+        }
+    }
+
+    /// Returns whether the enum is represented as a plain AssemblyScript enum,
+    /// which is the case if none of its variants have a payload.
+    fn is_plain_enum(&self, ty: &Enum, params: &TypeParams) -> bool {
+        ty.variants
+            .iter()
+            .all(|variant| matches!(self.get_payload(variant, params), Payload::Unit))
+    }
+
+    fn get_payload(&self, variant: &Variant, params: &TypeParams) -> Payload {
+        match &variant.ty {
+            Type::Struct(variant_ty) => Payload::Struct(
+                variant_ty
+                    .fields
+                    .iter()
+                    .map(|field| Field {
+                        ty: substitute(&field.ty, params),
+                        ..field.clone()
+                    })
+                    .collect(),
+                variant.attrs.field_casing,
+            ),
+            Type::Tuple(items) if items.len() == 1 => {
+                Payload::Newtype(substitute(&items[0], params))
+            }
+            Type::Tuple(items) if !items.is_empty() => {
+                Payload::Tuple(items.iter().map(|item| substitute(item, params)).collect())
+            }
+            _ => Payload::Unit,
+        }
+    }
+
+    /// Returns the value a field of the given type takes when it is omitted,
+    /// if the type has a sensible default.
+    fn default_value(&self, ident: &TypeIdent) -> Option<String> {
+        match self.resolve_alias(ident) {
+            AsType::Primitive(Primitive::Bool) => Some("false".to_owned()),
+            AsType::Primitive(_) => Some("0".to_owned()),
+            AsType::String => Some("\"\"".to_owned()),
+            AsType::Bytes => Some("new Uint8Array(0)".to_owned()),
+            AsType::Value => Some("new RawValue()".to_owned()),
+            AsType::Unit => Some("new Unit()".to_owned()),
+            AsType::Array(primitive, len) => {
+                Some(format!("new StaticArray<{}>({len})", primitive.name()))
+            }
+            AsType::Option(_) => Some("null".to_owned()),
+            AsType::List(_) => Some("[]".to_owned()),
+            AsType::Map(key, value) => Some(format!(
+                "new Map<{}, {}>()",
+                self.as_type(&key),
+                self.as_type(&value)
+            )),
+            _ => None,
+        }
+    }
+
+    fn visit(&mut self, ident: &TypeIdent) {
+        if let AsType::Pointer(inner) = self.resolve(ident) {
+            return self.visit(&inner);
+        }
+
+        let name = self.mangle(ident);
+        if !self.visited.insert(name.clone()) {
+            return;
+        }
+
+        match self.resolve(ident) {
+            AsType::Primitive(_)
+            | AsType::String
+            | AsType::Bytes
+            | AsType::Value
+            | AsType::Unit
+            | AsType::Pointer(_) => {}
+            AsType::Alias(target) => {
+                self.visit(&target);
+                let doc_lines = match self.types.get(ident) {
+                    Some(Type::Struct(ty)) => ty.doc_lines.as_slice(),
+                    _ => &[],
+                };
+                self.definitions.push(format!(
+                    "{}export type {name} = {};",
+                    format_docs(doc_lines, ""),
+                    self.as_type(&target)
+                ));
+                self.exports.insert(name);
+            }
+            AsType::Array(primitive, len) => self.generate_array(&name, primitive, len),
+            AsType::Option(inner) => {
+                self.visit(&inner);
+                self.generate_option(&name, &inner);
+            }
+            AsType::List(inner) => {
+                self.visit(&inner);
+                self.generate_list(&name, &inner);
+            }
+            AsType::Map(key, value) => {
+                self.visit(&key);
+                self.visit(&value);
+                self.generate_map(&name, &key, &value);
+            }
+            AsType::Tuple(items) => {
+                let doc_lines = match self.types.get(ident) {
+                    Some(Type::Struct(ty)) => ty.doc_lines.clone(),
+                    _ => Vec::new(),
+                };
+                self.generate_tuple(&name, &doc_lines, &items);
+            }
+            AsType::Struct(fields, casing) => {
+                let doc_lines = match self.types.get(ident) {
+                    Some(Type::Struct(ty)) => ty.doc_lines.clone(),
+                    _ => Vec::new(),
+                };
+                self.generate_struct(&name, &doc_lines, &fields, casing);
+            }
+            AsType::Enum(ty, params) => self.generate_enum(&name, ty, &params),
+        }
+    }
+
+    /// Adds the encoding functions for the type with the given name, which
+    /// are `encode_{name}()` and `decode_{name}()`.
+    fn push_functions(&mut self, name: &str, as_type: &str, encode: &[String], decode: &[String]) {
+        self.functions.push(format!(
+            "export function encode_{name}(writer: Writer, value: {as_type}): void {{\n{}}}\n\n\
+            export function decode_{name}(reader: Reader): {as_type} {{\n{}}}",
+            join_lines(encode, "  "),
+            join_lines(decode, "  ")
+        ));
+        self.exports.insert(format!("encode_{name}"));
+        self.exports.insert(format!("decode_{name}"));
+    }
+
+    /// Adds a class with the given fields, which are passed to its constructor
+    /// in order.
+    fn push_class(
+        &mut self,
+        name: &str,
+        doc_lines: &[String],
+        base: Option<(&str, &str)>,
+        fields: &[(String, String, &[String])],
+    ) {
+        let members = fields
+            .iter()
+            .map(|(member, as_type, doc_lines)| {
+                format!("{}{member}: {as_type};", format_docs(doc_lines, ""))
+            })
+            .collect::<Vec<_>>();
+        let params = fields
+            .iter()
+            .map(|(member, as_type, _)| format!("{member}: {as_type}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut constructor = Vec::new();
+        if let Some((_, tag)) = base {
+            constructor.push(format!("super({tag});"));
+        }
+        constructor.extend(
+            fields
+                .iter()
+                .map(|(member, _, _)| format!("this.{member} = {member};")),
+        );
+
+        let extends = match base {
+            Some((base, _)) => format!(" extends {base}"),
+            None => String::new(),
+        };
+        let body = if constructor.is_empty() {
+            String::new()
+        } else {
+            let mut body = members;
+            body.push(format!(
+                "constructor({params}) {{\n{}}}",
+                join_lines(&constructor, "  ")
+            ));
+            format!(
+                "\n{}",
+                join_lines(&body, "  ").replace(";\n  constructor", ";\n\n  constructor")
+            )
+        };
+        self.definitions.push(format!(
+            "{}export class {name}{extends} {{{body}}}",
+            format_docs(doc_lines, "")
+        ));
+        self.exports.insert(name.to_owned());
+    }
+
+    fn generate_array(&mut self, name: &str, primitive: Primitive, len: usize) {
+        let prim = primitive.name();
+        let encode = vec![
+            "writer.writeArray(<u32>value.length);".to_owned(),
+            format!(
+                "for (let i = 0; i < value.length; i++) {{\n  encode_{prim}(writer, value[i]);\n}}"
+            ),
+        ];
+        let decode = vec![
+            format!("if (reader.readArray() != {len}) {{\n  return reader.fail<StaticArray<{prim}>>();\n}}"),
+            format!("const value = new StaticArray<{prim}>({len});"),
+            format!(
+                "for (let i = 0; i < {len}; i++) {{\n  value[i] = decode_{prim}(reader);\n}}"
+            ),
+            "return value;".to_owned(),
+        ];
+        self.push_functions(name, &format!("StaticArray<{prim}>"), &encode, &decode);
+    }
+
+    fn generate_option(&mut self, name: &str, inner: &TypeIdent) {
+        let inner_name = self.fn_name(inner);
+        let is_reference = self.is_reference(inner);
+        let inner_value = if is_reference {
+            "value!"
+        } else {
+            "value!.value"
+        };
+        let encode = vec![format!(
+            "if (value !== null) {{\n  \
+            encode_{inner_name}(writer, {inner_value});\n\
+            }} else {{\n  \
+            writer.writeNil();\n\
+            }}"
+        )];
+        let decoded = if is_reference {
+            format!("decode_{inner_name}(reader)")
+        } else {
+            format!(
+                "new Box<{}>(decode_{inner_name}(reader))",
+                self.as_type(inner)
+            )
+        };
+        let decode = vec![
+            "if (reader.readNil()) {\n  return null;\n}".to_owned(),
+            format!("return {decoded};"),
+        ];
+        let as_type = if is_reference {
+            format!("{} | null", self.as_type(inner))
+        } else {
+            format!("Box<{}> | null", self.as_type(inner))
+        };
+        self.push_functions(name, &as_type, &encode, &decode);
+    }
+
+    fn generate_list(&mut self, name: &str, inner: &TypeIdent) {
+        let inner_type = self.as_type(inner);
+        let inner_name = self.fn_name(inner);
+        let encode = vec![
+            "writer.writeArray(<u32>value.length);".to_owned(),
+            format!(
+                "for (let i = 0; i < value.length; i++) {{\n  encode_{inner_name}(writer, value[i]);\n}}"
+            ),
+        ];
+        let decode = vec![
+            "const len = reader.readArray();".to_owned(),
+            format!("const value = new Array<{inner_type}>();"),
+            format!(
+                "for (let i: u32 = 0; i < len && !reader.error; i++) {{\n  \
+                value.push(decode_{inner_name}(reader));\n\
+                }}"
+            ),
+            "return value;".to_owned(),
+        ];
+        self.push_functions(name, &format!("Array<{inner_type}>"), &encode, &decode);
+    }
+
+    fn generate_map(&mut self, name: &str, key: &TypeIdent, value: &TypeIdent) {
+        let map_type = format!("Map<{}, {}>", self.as_type(key), self.as_type(value));
+        let key_name = self.fn_name(key);
+        let value_name = self.fn_name(value);
+        let encode = vec![
+            "const keys = value.keys();".to_owned(),
+            "writer.writeMap(<u32>keys.length);".to_owned(),
+            format!(
+                "for (let i = 0; i < keys.length; i++) {{\n  \
+                encode_{key_name}(writer, keys[i]);\n  \
+                encode_{value_name}(writer, value.get(keys[i]));\n\
+                }}"
+            ),
+        ];
+        let decode = vec![
+            "const len = reader.readMap();".to_owned(),
+            format!("const value = new {map_type}();"),
+            format!(
+                "for (let i: u32 = 0; i < len && !reader.error; i++) {{\n  \
+                const key = decode_{key_name}(reader);\n  \
+                value.set(key, decode_{value_name}(reader));\n\
+                }}"
+            ),
+            "return value;".to_owned(),
+        ];
+        self.push_functions(name, &map_type, &encode, &decode);
+    }
+
+    /// Generates a class with unnamed fields, which is encoded as an array.
+    fn generate_tuple(&mut self, name: &str, doc_lines: &[String], items: &[TypeIdent]) {
+        for item in items {
+            self.visit(item);
+        }
+
+        let fields = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (format!("f{index}"), self.as_type(item), &[][..]))
+            .collect::<Vec<_>>();
+        self.push_class(name, doc_lines, None, &fields);
+        self.push_tuple_functions(name, items);
+    }
+
+    fn push_tuple_functions(&mut self, name: &str, items: &[TypeIdent]) {
+        let len = items.len();
+        let mut encode = vec![format!("writer.writeArray({len});")];
+        encode.extend(items.iter().enumerate().map(|(index, item)| {
+            format!("encode_{}(writer, value.f{index});", self.fn_name(item))
+        }));
+
+        let mut decode = vec![format!(
+            "if (reader.readArray() != {len}) {{\n  return reader.fail<{name}>();\n}}"
+        )];
+        decode.extend(items.iter().enumerate().map(|(index, item)| {
+            format!("const f{index} = decode_{}(reader);", self.fn_name(item))
+        }));
+        decode.push(format!(
+            "if (reader.error) {{\n  return reader.fail<{name}>();\n}}"
+        ));
+        decode.push(format!(
+            "return new {name}({});",
+            (0..len)
+                .map(|index| format!("f{index}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        self.push_functions(name, name, &encode, &decode);
+    }
+
+    /// Generates a class with named fields, which is encoded as a map.
+    fn generate_struct(
+        &mut self,
+        name: &str,
+        doc_lines: &[String],
+        fields: &[Field],
+        casing: Casing,
+    ) {
+        for field in fields {
+            self.visit(&field.ty);
+        }
+
+        let members = fields
+            .iter()
+            .map(|field| {
+                (
+                    get_member_name(field),
+                    self.as_type(&field.ty),
+                    field.doc_lines.as_slice(),
+                )
+            })
+            .collect::<Vec<_>>();
+        self.push_class(name, doc_lines, None, &members);
+        self.push_struct_functions(name, fields, casing, None, None);
+    }
+
+    /// Adds the encoding functions for a class with named fields. If a tag is
+    /// given, it is encoded together with the fields, as is done for the
+    /// variants of internally tagged enums. If a wrapper is given, the fields
+    /// are those of its `value` field.
+    fn push_struct_functions(
+        &mut self,
+        name: &str,
+        fields: &[Field],
+        casing: Casing,
+        tag: Option<(&str, &str)>,
+        wrapped: Option<&TypeIdent>,
+    ) {
+        let mut encoded_fields = Vec::new();
+        let mut construct = match wrapped {
+            Some(inner) => {
+                let inner = self.resolve_alias_ident(inner);
+                let args = self.collect_fields(fields, casing, "value.", "", &mut encoded_fields);
+                format!("new {}({args})", self.mangle(&inner))
+            }
+            None => {
+                let args = self.collect_fields(fields, casing, "", "", &mut encoded_fields);
+                format!("new {name}({args})")
+            }
+        };
+        if wrapped.is_some() {
+            construct = format!("new {name}({construct})");
+        }
+
+        let encode = self.format_struct_encoding(&encoded_fields, tag);
+        let decode = self.format_struct_decoding(
+            name,
+            &encoded_fields,
+            &construct,
+            tag.map(|(tag, _)| tag),
+            fields.iter().any(|field| self.is_flattened(field)),
+        );
+        self.push_functions(name, name, &encode, &decode);
+    }
+
+    /// Collects the encoded fields of a struct, inlining the fields of
+    /// flattened structs. Returns the arguments for constructing the struct
+    /// from the local variables holding the decoded fields.
+    fn collect_fields(
+        &self,
+        fields: &[Field],
+        casing: Casing,
+        path: &str,
+        local_prefix: &str,
+        encoded_fields: &mut Vec<EncodedField>,
+    ) -> String {
+        fields
+            .iter()
+            .map(|field| {
+                let member = get_member_name(field);
+                if self.is_flattened(field) {
+                    if let AsType::Struct(inner_fields, inner_casing) =
+                        self.resolve_alias(&field.ty)
+                    {
+                        let args = self.collect_fields(
+                            &inner_fields,
+                            inner_casing,
+                            &format!("{path}{member}."),
+                            &format!("{local_prefix}{member}_"),
+                            encoded_fields,
+                        );
+                        let inner = self.resolve_alias_ident(&field.ty);
+                        return format!("new {}({args})", self.mangle(&inner));
+                    }
+                }
+
+                let local = if local_prefix.is_empty() {
+                    escape_reserved(member.clone())
+                } else {
+                    format!("{local_prefix}{member}")
+                };
+                encoded_fields.push(EncodedField {
+                    key: get_field_name(field, casing),
+                    path: format!("{path}{member}"),
+                    local: local.clone(),
+                    field: field.clone(),
+                });
+                local
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Returns whether the fields of the given field should be flattened into
+    /// the parent struct. Only structs with named fields can be flattened;
+    /// other flattened types are treated as regular fields.
+    fn is_flattened(&self, field: &Field) -> bool {
+        field.attrs.flatten && matches!(self.resolve_alias(&field.ty), AsType::Struct(_, _))
+    }
+
+    fn format_struct_encoding(
+        &self,
+        fields: &[EncodedField],
+        tag: Option<(&str, &str)>,
+    ) -> Vec<String> {
+        let mut static_count = if tag.is_some() { 1 } else { 0 };
+        let mut count_lines = Vec::new();
+        let mut encode_lines = Vec::new();
+        if let Some((tag, variant_name)) = tag {
+            encode_lines.push(format!(
+                "writer.writeString(\"{tag}\");\nwriter.writeString(\"{variant_name}\");"
+            ));
+        }
+        for field in fields {
+            let encode = format!(
+                "writer.writeString(\"{}\");\nencode_{}(writer, value.{});",
+                field.key,
+                self.fn_name(&field.field.ty),
+                field.path
+            );
+            match self.format_serialize_condition(&field.field, &field.path) {
+                Some(condition) => {
+                    count_lines.push(format!("if ({condition}) {{\n  count++;\n}}"));
+                    encode_lines.push(format!(
+                        "if ({condition}) {{\n{}\n}}",
+                        indent_lines(&encode, "  ")
+                    ));
+                }
+                None => {
+                    static_count += 1;
+                    encode_lines.push(encode);
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        if count_lines.is_empty() {
+            lines.push(format!("writer.writeMap({static_count});"));
+        } else {
+            lines.push(format!("let count: u32 = {static_count};"));
+            lines.extend(count_lines);
+            lines.push("writer.writeMap(count);".to_owned());
+        }
+        lines.extend(encode_lines);
+        lines
+    }
+
+    fn format_struct_decoding(
+        &self,
+        name: &str,
+        fields: &[EncodedField],
+        construct: &str,
+        tag: Option<&str>,
+        has_flattened_fields: bool,
+    ) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut required = Vec::new();
+        let mut branches = Vec::new();
+        for field in fields {
+            let ty = &field.field.ty;
+            let local = &field.local;
+            let as_type = self.as_type(ty);
+            let default_value = self.default_value(ty);
+            let is_required = match self.resolve_alias(ty) {
+                AsType::Option(_) => false,
+                _ => field.field.attrs.default.is_none() || default_value.is_none(),
+            };
+            lines.push(format!(
+                "let {local}: {as_type} = {};",
+                default_value.unwrap_or_else(|| format!("changetype<{as_type}>(0)"))
+            ));
+
+            let mut branch = format!("{local} = decode_{}(reader);", self.fn_name(ty));
+            if is_required {
+                lines.push(format!("let has_{local} = false;"));
+                branch.push_str(&format!("\nhas_{local} = true;"));
+                required.push(format!("!has_{local}"));
+            }
+            branches.push((field.key.clone(), branch));
+        }
+        if let Some(tag) = tag {
+            branches.push((tag.to_owned(), "reader.skip();".to_owned()));
+        }
+
+        let names = if has_flattened_fields {
+            String::new()
+        } else {
+            tag.iter()
+                .map(|tag| format!("\"{tag}\""))
+                .chain(fields.iter().map(|field| format!("\"{}\"", field.key)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        lines.push(format!("const fields = reader.readStruct([{names}]);"));
+
+        let mut loop_body = Vec::new();
+        if branches.is_empty() {
+            loop_body.push("fields.skip();".to_owned());
+        } else {
+            loop_body.push("const key = fields.key;".to_owned());
+            let conditions = branches
+                .iter()
+                .map(|(key, branch)| {
+                    format!(
+                        "if (key == \"{key}\") {{\n{}\n}}",
+                        indent_lines(branch, "  ")
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" else ");
+            loop_body.push(format!("{conditions} else {{\n  fields.skip();\n}}"));
+        }
+        lines.push(format!(
+            "while (fields.next()) {{\n{}}}",
+            join_lines(&loop_body, "  ")
+        ));
+
+        let condition = std::iter::once("reader.error".to_owned())
+            .chain(required)
+            .collect::<Vec<_>>()
+            .join(" || ");
+        lines.push(format!(
+            "if ({condition}) {{\n  return reader.fail<{name}>();\n}}"
+        ));
+        lines.push(format!("return {construct};"));
+        lines
+    }
+
+    /// Returns the condition under which the field should be serialized, if
+    /// it has a `skip_serializing_if` attribute we can translate.
+    fn format_serialize_condition(&self, field: &Field, path: &str) -> Option<String> {
+        let skip_path = field.attrs.skip_serializing_if.as_deref()?;
+        match self.resolve_alias(&field.ty) {
+            AsType::Option(_) if skip_path.ends_with("is_none") => {
+                Some(format!("value.{path} !== null"))
+            }
+            AsType::String | AsType::Bytes | AsType::List(_) if skip_path.ends_with("is_empty") => {
+                Some(format!("value.{path}.length > 0"))
+            }
+            AsType::Map(_, _) if skip_path.ends_with("is_empty") => {
+                Some(format!("value.{path}.size > 0"))
+            }
+            _ => None,
+        }
+    }
+
+    fn generate_enum(&mut self, name: &str, ty: &Enum, params: &TypeParams) {
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| (variant, self.get_payload(variant, params)))
+            .collect::<Vec<_>>();
+        for (_, payload) in &variants {
+            match payload {
+                Payload::Unit => {}
+                Payload::Newtype(item) => self.visit(item),
+                Payload::Tuple(items) => items.iter().for_each(|item| self.visit(item)),
+                Payload::Struct(fields, _) => fields.iter().for_each(|field| self.visit(&field.ty)),
+            }
+        }
+
+        let is_plain_enum = self.is_plain_enum(ty, params);
+        let tag_type = if is_plain_enum {
+            name.to_owned()
+        } else {
+            format!("{name}Tag")
+        };
+        let tag_values = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let docs = if is_plain_enum {
+                    format_docs(&variant.doc_lines, "")
+                } else {
+                    String::new()
+                };
+                format!("{docs}{},", get_variable_name(&variant.name))
+            })
+            .collect::<Vec<_>>();
+        let tag_docs = if is_plain_enum {
+            format_docs(&ty.doc_lines, "")
+        } else {
+            String::new()
+        };
+        self.definitions.push(format!(
+            "{tag_docs}export enum {tag_type} {{\n{}}}",
+            join_lines(&tag_values, "  ")
+        ));
+        self.exports.insert(tag_type.clone());
+
+        if !is_plain_enum {
+            self.definitions.push(format!(
+                "{}export class {name} {{\n  \
+                readonly tag: {tag_type};\n\n  \
+                constructor(tag: {tag_type}) {{\n    \
+                this.tag = tag;\n  \
+                }}\n\
+                }}",
+                format_docs(&ty.doc_lines, "")
+            ));
+            self.exports.insert(name.to_owned());
+
+            for (variant, payload) in &variants {
+                self.generate_variant_class(name, variant, payload, &ty.options);
+            }
+        }
+
+        let encode_cases = variants
+            .iter()
+            .map(|(variant, payload)| {
+                let lines = self.format_variant_encoding(name, variant, payload, &ty.options);
+                format!(
+                    "case {tag_type}.{}: {{\n{}  break;\n}}",
+                    get_variable_name(&variant.name),
+                    join_lines(&lines, "  ")
+                )
+            })
+            .collect::<Vec<_>>();
+        let tag_value = if is_plain_enum { "value" } else { "value.tag" };
+        let encode = vec![format!(
+            "switch ({tag_value}) {{\n{}\n}}",
+            indent_lines(&encode_cases.join("\n"), "  ")
+        )];
+
+        let decode = self.format_enum_decoding(name, &variants, &ty.options, is_plain_enum);
+        self.push_functions(name, name, &encode, &decode);
+    }
+
+    /// Generates the class for a variant of an enum that is not a plain enum,
+    /// along with the functions for encoding its payload.
+    fn generate_variant_class(
+        &mut self,
+        enum_name: &str,
+        variant: &Variant,
+        payload: &Payload,
+        opts: &EnumOptions,
+    ) {
+        let class_name = get_variant_class(enum_name, variant);
+        let base = (
+            enum_name,
+            format!("{enum_name}Tag.{}", get_variable_name(&variant.name)),
+        );
+        let base = Some((base.0, base.1.as_str()));
+        let tag = opts
+            .tag_prop_name
+            .as_deref()
+            .filter(|_| opts.content_prop_name.is_none() && !opts.untagged);
+        let variant_name = get_variant_name(variant, opts);
+
+        match payload {
+            Payload::Unit => self.push_class(&class_name, &variant.doc_lines, base, &[]),
+            Payload::Newtype(item) => {
+                let as_type = self.as_type(item);
+                self.push_class(
+                    &class_name,
+                    &variant.doc_lines,
+                    base,
+                    &[("value".to_owned(), as_type, &[])],
+                );
+                if let Some(tag) = tag {
+                    match self.resolve_alias(item) {
+                        AsType::Struct(fields, casing) => self.push_struct_functions(
+                            &class_name,
+                            &fields,
+                            casing,
+                            Some((tag, &variant_name)),
+                            Some(item),
+                        ),
+                        _ => panic!(
+                            "Variant {}::{} cannot be internally tagged, since it does not contain a struct",
+                            enum_name, variant.name
+                        ),
+                    }
+                } else {
+                    let fn_name = self.fn_name(item);
+                    self.push_functions(
+                        &class_name,
+                        &class_name,
+                        &[format!("encode_{fn_name}(writer, value.value);")],
+                        &[
+                            format!("const value = decode_{fn_name}(reader);"),
+                            format!(
+                                "return reader.error ? reader.fail<{class_name}>() : new {class_name}(value);"
+                            ),
+                        ],
+                    );
+                }
+            }
+            Payload::Tuple(items) => {
+                if tag.is_some() {
+                    panic!(
+                        "Variant {}::{} cannot be internally tagged, since it does not contain a struct",
+                        enum_name, variant.name
+                    );
+                }
+                let fields = items
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| (format!("f{index}"), self.as_type(item), &[][..]))
+                    .collect::<Vec<_>>();
+                self.push_class(&class_name, &variant.doc_lines, base, &fields);
+                self.push_tuple_functions(&class_name, items);
+            }
+            Payload::Struct(fields, casing) => {
+                let members = fields
+                    .iter()
+                    .map(|field| {
+                        (
+                            get_member_name(field),
+                            self.as_type(&field.ty),
+                            field.doc_lines.as_slice(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                self.push_class(&class_name, &variant.doc_lines, base, &members);
+                self.push_struct_functions(
+                    &class_name,
+                    fields,
+                    *casing,
+                    tag.map(|tag| (tag, variant_name.as_str())),
+                    None,
+                );
+            }
+        }
+    }
+
+    fn format_variant_encoding(
+        &self,
+        enum_name: &str,
+        variant: &Variant,
+        payload: &Payload,
+        opts: &EnumOptions,
+    ) -> Vec<String> {
+        let variant_name = get_variant_name(variant, opts);
+        let class_name = get_variant_class(enum_name, variant);
+        let encode_payload =
+            format!("encode_{class_name}(writer, changetype<{class_name}>(value));");
+        let is_unit = matches!(payload, Payload::Unit);
+
+        if opts.untagged {
+            if is_unit {
+                vec!["writer.writeNil();".to_owned()]
+            } else {
+                vec![encode_payload]
+            }
+        } else if let Some(tag) = &opts.tag_prop_name {
+            let write_tag =
+                format!("writer.writeString(\"{tag}\");\nwriter.writeString(\"{variant_name}\");");
+            if let Some(content) = &opts.content_prop_name {
+                if is_unit {
+                    vec!["writer.writeMap(1);".to_owned(), write_tag]
+                } else {
+                    vec![
+                        "writer.writeMap(2);".to_owned(),
+                        write_tag,
+                        format!("writer.writeString(\"{content}\");"),
+                        encode_payload,
+                    ]
+                }
+            } else if is_unit {
+                vec!["writer.writeMap(1);".to_owned(), write_tag]
+            } else {
+                // The tag is encoded together with the fields of the payload:
+                vec![encode_payload]
+            }
+        } else if is_unit {
+            vec![format!("writer.writeString(\"{variant_name}\");")]
+        } else {
+            vec![
+                "writer.writeMap(1);".to_owned(),
+                format!("writer.writeString(\"{variant_name}\");"),
+                encode_payload,
+            ]
+        }
+    }
+
+    fn format_enum_decoding(
+        &self,
+        name: &str,
+        variants: &[(&Variant, Payload)],
+        opts: &EnumOptions,
+        is_plain_enum: bool,
+    ) -> Vec<String> {
+        let unit_value = |variant: &Variant| {
+            if is_plain_enum {
+                format!("{name}.{}", get_variable_name(&variant.name))
+            } else {
+                format!("new {}()", get_variant_class(name, variant))
+            }
+        };
+
+        let mut lines = Vec::new();
+        if opts.untagged {
+            lines.push("let attempt = reader.attempt();".to_owned());
+            for (index, (variant, payload)) in variants.iter().enumerate() {
+                if index > 0 {
+                    lines.push("attempt = reader.attempt();".to_owned());
+                }
+                match payload {
+                    Payload::Unit => lines.push(format!(
+                        "if (attempt.readNil()) {{\n  \
+                        reader.commit(attempt);\n  \
+                        return {};\n\
+                        }}",
+                        unit_value(variant)
+                    )),
+                    _ => {
+                        let result = format!("result_{}", get_variable_name(&variant.name));
+                        lines.push(format!(
+                            "const {result} = decode_{}(attempt);",
+                            get_variant_class(name, variant)
+                        ));
+                        lines.push(format!(
+                            "if (!attempt.error) {{\n  \
+                            reader.commit(attempt);\n  \
+                            return {result};\n\
+                            }}"
+                        ));
+                    }
+                }
+            }
+            lines.push(format!("return reader.fail<{name}>();"));
+            return lines;
+        }
+
+        let is_internally_tagged = opts.tag_prop_name.is_some() && opts.content_prop_name.is_none();
+        let name_expr = match (&opts.tag_prop_name, &opts.content_prop_name) {
+            (Some(tag), Some(content)) => {
+                lines.push(format!(
+                    "const variant = reader.readAdjacent(\"{tag}\", \"{content}\");"
+                ));
+                "variant.name"
+            }
+            (Some(tag), None) => {
+                lines.push(format!("const tag = reader.readTag(\"{tag}\");"));
+                "tag"
+            }
+            _ => {
+                lines.push("const variant = reader.readVariant();".to_owned());
+                "variant.name"
+            }
+        };
+
+        for (variant, payload) in variants {
+            let variant_name = get_variant_name(variant, opts);
+            let mut body = Vec::new();
+            match payload {
+                Payload::Unit => {
+                    if is_internally_tagged {
+                        body.push("reader.skip();".to_owned());
+                    }
+                    body.push(format!("return {};", unit_value(variant)));
+                }
+                _ if is_internally_tagged => body.push(format!(
+                    "return decode_{}(reader);",
+                    get_variant_class(name, variant)
+                )),
+                _ => {
+                    body.push(format!(
+                        "const result = decode_{}(variant.content);",
+                        get_variant_class(name, variant)
+                    ));
+                    body.push(format!(
+                        "return variant.content.error ? reader.fail<{name}>() : result;"
+                    ));
+                }
+            }
+            lines.push(format!(
+                "if ({name_expr} == \"{variant_name}\") {{\n{}}}",
+                join_lines(&body, "  ")
+            ));
+        }
+        lines.push(format!("return reader.fail<{name}>();"));
+        lines
+    }
+}
+
+fn get_type_params(definition: &TypeIdent, ident: &TypeIdent) -> TypeParams {
+    definition
+        .generic_args
+        .iter()
+        .zip(ident.generic_args.iter())
+        .map(|((param, _), (arg, _))| (param.name.clone(), arg.clone()))
+        .collect()
+}
+
+/// Replaces generic parameters in the given type with their arguments.
+fn substitute(ident: &TypeIdent, params: &[(String, TypeIdent)]) -> TypeIdent {
+    if ident.generic_args.is_empty() && ident.array.is_none() {
+        if let Some((_, arg)) = params.iter().find(|(name, _)| name == &ident.name) {
+            return arg.clone();
+        }
+    }
+
+    let mut ident = ident.clone();
+    ident.generic_args = ident
+        .generic_args
+        .iter()
+        .map(|(arg, bounds)| (substitute(arg, params), bounds.clone()))
+        .collect();
+    ident
+}
+
+fn format_docs(doc_lines: &[String], indent: &str) -> String {
+    if doc_lines.is_empty() {
+        return String::new();
+    }
+
+    let lines = doc_lines
+        .iter()
+        .map(|line| {
+            let line = line.trim_end().replace("*/", "* /");
+            if line.is_empty() {
+                format!("{indent} *\n")
+            } else {
+                format!("{indent} *{line}\n")
+            }
+        })
+        .collect::<String>();
+    format!("{indent}/**\n{lines}{indent} */\n")
+}
+
+fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(field.name.as_deref().unwrap_or_default()))
+    }
+}
+
+fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+/// Returns the name of the class that represents the given variant.
+fn get_variant_class(enum_name: &str, variant: &Variant) -> String {
+    format!("{enum_name}_{}", get_variable_name(&variant.name))
+}
+
+/// Returns the name of the class member for the given field.
+fn get_member_name(field: &Field) -> String {
+    escape_name(get_variable_name(field.name.as_deref().unwrap_or_default()).to_camel_case())
+}
+
+/// Returns the name under which a function is exposed to the plugin.
+fn get_function_name(name: &str) -> String {
+    escape_name(get_variable_name(name).to_camel_case())
+}
+
+/// Returns a name that can be used for an argument in AssemblyScript.
+fn get_arg_name(name: &str) -> String {
+    escape_reserved(escape_name(get_variable_name(name).to_camel_case()))
+}
+
+fn escape_name(name: String) -> String {
+    if TS_KEYWORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn escape_reserved(name: String) -> String {
+    if RESERVED_NAMES.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn indent_lines(lines: &str, indent: &str) -> String {
+    lines
+        .trim_end()
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn join_lines(lines: &[String], indent: &str) -> String {
+    lines
+        .iter()
+        .map(|line| format!("{}\n", indent_lines(line, indent)))
+        .collect()
+}
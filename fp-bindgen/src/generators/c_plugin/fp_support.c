@@ -0,0 +1,1024 @@
+// ============================================= //
+// Support library for WebAssembly plugins       //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#include "fp_support.h"
+
+#include <stdlib.h>
+#include <string.h>
+
+#define FP_ASYNC_STATUS_PENDING 0
+#define FP_ASYNC_STATUS_READY 1
+
+typedef struct AsyncValue {
+    uint32_t status;
+    uint32_t ptr;
+    uint32_t len;
+} AsyncValue;
+
+typedef struct PendingValue {
+    FatPtr async_value_ptr;
+    FpAsyncHandler handler;
+    void (*callback)(void);
+    void *user_data;
+    struct PendingValue *next;
+} PendingValue;
+
+static PendingValue *pending_values = NULL;
+
+static FatPtr to_fat_ptr(void *ptr, uint32_t len) {
+    return ((FatPtr)(uintptr_t)ptr << 32) | len;
+}
+
+static uint8_t *from_fat_ptr(FatPtr ptr) {
+    return (uint8_t *)(uintptr_t)(ptr >> 32);
+}
+
+static uint32_t read_le32(const uint8_t *bytes) {
+    return (uint32_t)bytes[0] | ((uint32_t)bytes[1] << 8) | ((uint32_t)bytes[2] << 16) |
+           ((uint32_t)bytes[3] << 24);
+}
+
+static uint8_t *resolve_fat_ptr(FatPtr ptr, uint32_t *len) {
+    uint8_t *data = from_fat_ptr(ptr);
+    uint32_t fat_len = (uint32_t)ptr;
+    if (fat_len == FP_FAT_PTR_LEN_HEADER) {
+        *len = read_le32(data);
+        return data + 4;
+    } else if (fat_len > FP_FAT_PTR_MAX_LEN) {
+        fp_panic("Unknown extension bits");
+    }
+    *len = fat_len;
+    return data;
+}
+
+void fp_panic_at(const char *message, const char *location) {
+    FpWriter writer = {0};
+    fp_write_map(&writer, 2);
+    fp_write_cstr(&writer, "message");
+    fp_write_cstr(&writer, message);
+    fp_write_cstr(&writer, "location");
+    if (location != NULL) {
+        fp_write_cstr(&writer, location);
+    } else {
+        fp_write_nil(&writer);
+    }
+    __fp_host_panic(fp_writer_finish(&writer));
+    abort();
+}
+
+void *fp_alloc(uint32_t count, size_t size) {
+    if (count == 0 || size == 0) {
+        return NULL;
+    }
+    void *ptr = calloc(count, size);
+    if (ptr == NULL) {
+        fp_panic("Out of memory");
+    }
+    return ptr;
+}
+
+FpString fp_string(const char *value) {
+    return fp_string_from(value, (uint32_t)strlen(value));
+}
+
+FpString fp_string_from(const char *data, uint32_t len) {
+    FpString string;
+    string.data = fp_alloc(len + 1, 1);
+    string.len = len;
+    if (len > 0) {
+        memcpy(string.data, data, len);
+    }
+    return string;
+}
+
+FpBytes fp_bytes_from(const uint8_t *data, uint32_t len) {
+    FpBytes bytes;
+    bytes.data = fp_alloc(len, 1);
+    bytes.len = len;
+    if (len > 0) {
+        memcpy(bytes.data, data, len);
+    }
+    return bytes;
+}
+
+// Memory management, as required by the spec:
+
+FP_EXPORT("__fp_malloc")
+FatPtr __fp_malloc(uint32_t len) {
+    bool has_header = len > FP_FAT_PTR_MAX_LEN;
+    uint8_t *ptr = malloc(has_header ? (size_t)len + 4 : len > 0 ? len : 1);
+    if (ptr == NULL) {
+        fp_panic("Out of memory");
+    }
+    if (has_header) {
+        ptr[0] = (uint8_t)len;
+        ptr[1] = (uint8_t)(len >> 8);
+        ptr[2] = (uint8_t)(len >> 16);
+        ptr[3] = (uint8_t)(len >> 24);
+        return to_fat_ptr(ptr, FP_FAT_PTR_LEN_HEADER);
+    }
+    return to_fat_ptr(ptr, len);
+}
+
+FP_EXPORT("__fp_free")
+void __fp_free(FatPtr ptr) {
+    free(from_fat_ptr(ptr));
+}
+
+// Encoding:
+
+static uint8_t *reserve(FpWriter *writer, uint32_t additional) {
+    if (writer->capacity - writer->len < additional) {
+        uint64_t capacity = writer->capacity > 0 ? writer->capacity : 64;
+        while (capacity - writer->len < additional) {
+            capacity *= 2;
+        }
+        if (capacity > UINT32_MAX) {
+            fp_panic("Buffer too large");
+        }
+        uint8_t *data = realloc(writer->data, (size_t)capacity);
+        if (data == NULL) {
+            fp_panic("Out of memory");
+        }
+        writer->data = data;
+        writer->capacity = (uint32_t)capacity;
+    }
+    uint8_t *ptr = writer->data + writer->len;
+    writer->len += additional;
+    return ptr;
+}
+
+static void write_type(FpWriter *writer, uint8_t type) {
+    *reserve(writer, 1) = type;
+}
+
+static void write_be(FpWriter *writer, uint8_t type, uint64_t value, uint32_t size) {
+    uint8_t *ptr = reserve(writer, size + 1);
+    ptr[0] = type;
+    for (uint32_t i = 0; i < size; i++) {
+        ptr[size - i] = (uint8_t)(value >> (8 * i));
+    }
+}
+
+FatPtr fp_writer_finish(FpWriter *writer) {
+    FatPtr ptr = __fp_malloc(writer->len);
+    uint32_t len;
+    uint8_t *data = resolve_fat_ptr(ptr, &len);
+    if (len > 0) {
+        memcpy(data, writer->data, len);
+    }
+    fp_writer_free(writer);
+    return ptr;
+}
+
+void fp_writer_free(FpWriter *writer) {
+    free(writer->data);
+    memset(writer, 0, sizeof(*writer));
+}
+
+void fp_write_nil(FpWriter *writer) {
+    write_type(writer, 0xc0);
+}
+
+void fp_write_bool(FpWriter *writer, bool value) {
+    write_type(writer, value ? 0xc3 : 0xc2);
+}
+
+void fp_write_int(FpWriter *writer, int64_t value) {
+    if (value >= 0) {
+        fp_write_uint(writer, (uint64_t)value);
+    } else if (value >= -32) {
+        write_type(writer, (uint8_t)value);
+    } else if (value >= INT8_MIN) {
+        write_be(writer, 0xd0, (uint64_t)value, 1);
+    } else if (value >= INT16_MIN) {
+        write_be(writer, 0xd1, (uint64_t)value, 2);
+    } else if (value >= INT32_MIN) {
+        write_be(writer, 0xd2, (uint64_t)value, 4);
+    } else {
+        write_be(writer, 0xd3, (uint64_t)value, 8);
+    }
+}
+
+void fp_write_uint(FpWriter *writer, uint64_t value) {
+    if (value < 0x80) {
+        write_type(writer, (uint8_t)value);
+    } else if (value <= UINT8_MAX) {
+        write_be(writer, 0xcc, value, 1);
+    } else if (value <= UINT16_MAX) {
+        write_be(writer, 0xcd, value, 2);
+    } else if (value <= UINT32_MAX) {
+        write_be(writer, 0xce, value, 4);
+    } else {
+        write_be(writer, 0xcf, value, 8);
+    }
+}
+
+void fp_write_f32(FpWriter *writer, float value) {
+    uint32_t bits;
+    memcpy(&bits, &value, sizeof(bits));
+    write_be(writer, 0xca, bits, 4);
+}
+
+void fp_write_f64(FpWriter *writer, double value) {
+    uint64_t bits;
+    memcpy(&bits, &value, sizeof(bits));
+    write_be(writer, 0xcb, bits, 8);
+}
+
+void fp_write_str(FpWriter *writer, const char *data, uint32_t len) {
+    if (len < 32) {
+        write_type(writer, (uint8_t)(0xa0 | len));
+    } else if (len <= UINT8_MAX) {
+        write_be(writer, 0xd9, len, 1);
+    } else if (len <= UINT16_MAX) {
+        write_be(writer, 0xda, len, 2);
+    } else {
+        write_be(writer, 0xdb, len, 4);
+    }
+    fp_write_raw(writer, (const uint8_t *)data, len);
+}
+
+void fp_write_cstr(FpWriter *writer, const char *value) {
+    fp_write_str(writer, value, (uint32_t)strlen(value));
+}
+
+void fp_write_bin(FpWriter *writer, const uint8_t *data, uint32_t len) {
+    if (len <= UINT8_MAX) {
+        write_be(writer, 0xc4, len, 1);
+    } else if (len <= UINT16_MAX) {
+        write_be(writer, 0xc5, len, 2);
+    } else {
+        write_be(writer, 0xc6, len, 4);
+    }
+    fp_write_raw(writer, data, len);
+}
+
+void fp_write_array(FpWriter *writer, uint32_t len) {
+    if (len < 16) {
+        write_type(writer, (uint8_t)(0x90 | len));
+    } else if (len <= UINT16_MAX) {
+        write_be(writer, 0xdc, len, 2);
+    } else {
+        write_be(writer, 0xdd, len, 4);
+    }
+}
+
+void fp_write_map(FpWriter *writer, uint32_t len) {
+    if (len < 16) {
+        write_type(writer, (uint8_t)(0x80 | len));
+    } else if (len <= UINT16_MAX) {
+        write_be(writer, 0xde, len, 2);
+    } else {
+        write_be(writer, 0xdf, len, 4);
+    }
+}
+
+void fp_write_raw(FpWriter *writer, const uint8_t *data, uint32_t len) {
+    if (len > 0) {
+        memcpy(reserve(writer, len), data, len);
+    }
+}
+
+// Decoding:
+
+FpReader fp_reader_open(FatPtr ptr) {
+    FpReader reader = {0};
+    reader.data = resolve_fat_ptr(ptr, &reader.len);
+    reader.ptr = ptr;
+    return reader;
+}
+
+void fp_reader_close(FpReader *reader) {
+    __fp_free(reader->ptr);
+    memset(reader, 0, sizeof(*reader));
+}
+
+static bool peek_type(const FpReader *reader, uint8_t *type) {
+    if (reader->pos >= reader->len) {
+        return false;
+    }
+    *type = reader->data[reader->pos];
+    return true;
+}
+
+static bool read_bytes(FpReader *reader, uint32_t count, const uint8_t **bytes) {
+    if (reader->len - reader->pos < count) {
+        return false;
+    }
+    *bytes = reader->data + reader->pos;
+    reader->pos += count;
+    return true;
+}
+
+static bool read_be(FpReader *reader, uint32_t size, uint64_t *value) {
+    const uint8_t *bytes;
+    if (!read_bytes(reader, size, &bytes)) {
+        return false;
+    }
+    *value = 0;
+    for (uint32_t i = 0; i < size; i++) {
+        *value = (*value << 8) | bytes[i];
+    }
+    return true;
+}
+
+/**
+ * Reads the length that follows a type byte of the given size.
+ */
+static bool read_len(FpReader *reader, uint32_t size, uint32_t *len) {
+    uint64_t value;
+    if (!read_be(reader, size, &value)) {
+        return false;
+    }
+    *len = (uint32_t)value;
+    return true;
+}
+
+/**
+ * Reads an integer of any format. If `negative` is set, `bits` should be
+ * interpreted as a signed integer.
+ */
+static bool read_integer(FpReader *reader, uint64_t *bits, bool *negative) {
+    uint8_t type;
+    if (!peek_type(reader, &type)) {
+        return false;
+    }
+
+    FpReader start = *reader;
+    reader->pos++;
+    *negative = false;
+    if (type <= 0x7f) {
+        *bits = type;
+        return true;
+    } else if (type >= 0xe0) {
+        *bits = (uint64_t)(int64_t)(int8_t)type;
+        *negative = true;
+        return true;
+    } else if (type >= 0xcc && type <= 0xcf) {
+        if (read_be(reader, 1u << (type - 0xcc), bits)) {
+            return true;
+        }
+    } else if (type >= 0xd0 && type <= 0xd3) {
+        uint32_t size = 1u << (type - 0xd0);
+        if (read_be(reader, size, bits)) {
+            if (size < 8 && (*bits >> (8 * size - 1)) & 1) {
+                *bits |= ~(uint64_t)0 << (8 * size);
+            }
+            *negative = (int64_t)*bits < 0;
+            return true;
+        }
+    }
+    *reader = start;
+    return false;
+}
+
+bool fp_read_nil(FpReader *reader) {
+    uint8_t type;
+    if (peek_type(reader, &type) && type == 0xc0) {
+        reader->pos++;
+        return true;
+    }
+    return false;
+}
+
+bool fp_read_bool(FpReader *reader, bool *value) {
+    uint8_t type;
+    if (peek_type(reader, &type) && (type == 0xc2 || type == 0xc3)) {
+        reader->pos++;
+        *value = type == 0xc3;
+        return true;
+    }
+    return false;
+}
+
+bool fp_read_int(FpReader *reader, int64_t *value) {
+    FpReader start = *reader;
+    uint64_t bits;
+    bool negative;
+    if (!read_integer(reader, &bits, &negative)) {
+        return false;
+    }
+    if (!negative && bits > INT64_MAX) {
+        *reader = start;
+        return false;
+    }
+    *value = (int64_t)bits;
+    return true;
+}
+
+bool fp_read_uint(FpReader *reader, uint64_t *value) {
+    FpReader start = *reader;
+    bool negative;
+    if (!read_integer(reader, value, &negative)) {
+        return false;
+    }
+    if (negative) {
+        *reader = start;
+        return false;
+    }
+    return true;
+}
+
+bool fp_read_f64(FpReader *reader, double *value) {
+    uint8_t type;
+    if (!peek_type(reader, &type)) {
+        return false;
+    }
+
+    FpReader start = *reader;
+    uint64_t bits;
+    if (type == 0xca) {
+        reader->pos++;
+        if (read_be(reader, 4, &bits)) {
+            uint32_t bits32 = (uint32_t)bits;
+            float value32;
+            memcpy(&value32, &bits32, sizeof(value32));
+            *value = value32;
+            return true;
+        }
+    } else if (type == 0xcb) {
+        reader->pos++;
+        if (read_be(reader, 8, &bits)) {
+            memcpy(value, &bits, sizeof(*value));
+            return true;
+        }
+    } else {
+        bool negative;
+        if (read_integer(reader, &bits, &negative)) {
+            *value = negative ? (double)(int64_t)bits : (double)bits;
+            return true;
+        }
+    }
+    *reader = start;
+    return false;
+}
+
+bool fp_read_f32(FpReader *reader, float *value) {
+    double value64;
+    if (!fp_read_f64(reader, &value64)) {
+        return false;
+    }
+    *value = (float)value64;
+    return true;
+}
+
+bool fp_read_str(FpReader *reader, const char **data, uint32_t *len) {
+    uint8_t type;
+    if (!peek_type(reader, &type)) {
+        return false;
+    }
+
+    FpReader start = *reader;
+    reader->pos++;
+    bool has_len;
+    if ((type & 0xe0) == 0xa0) {
+        *len = type & 0x1f;
+        has_len = true;
+    } else if (type >= 0xd9 && type <= 0xdb) {
+        has_len = read_len(reader, 1u << (type - 0xd9), len);
+    } else {
+        has_len = false;
+    }
+
+    const uint8_t *bytes;
+    if (has_len && read_bytes(reader, *len, &bytes)) {
+        *data = (const char *)bytes;
+        return true;
+    }
+    *reader = start;
+    return false;
+}
+
+bool fp_read_bin(FpReader *reader, const uint8_t **data, uint32_t *len) {
+    uint8_t type;
+    if (!peek_type(reader, &type) || type < 0xc4 || type > 0xc6) {
+        return false;
+    }
+
+    FpReader start = *reader;
+    reader->pos++;
+    if (read_len(reader, 1u << (type - 0xc4), len) && read_bytes(reader, *len, data)) {
+        return true;
+    }
+    *reader = start;
+    return false;
+}
+
+bool fp_read_array(FpReader *reader, uint32_t *len) {
+    uint8_t type;
+    if (!peek_type(reader, &type)) {
+        return false;
+    }
+
+    FpReader start = *reader;
+    reader->pos++;
+    bool has_len;
+    if ((type & 0xf0) == 0x90) {
+        *len = type & 0x0f;
+        has_len = true;
+    } else if (type == 0xdc || type == 0xdd) {
+        has_len = read_len(reader, type == 0xdc ? 2 : 4, len);
+    } else {
+        has_len = false;
+    }
+
+    // Every element takes at least one byte, which protects us against
+    // allocating memory for bogus lengths:
+    if (has_len && *len <= reader->len - reader->pos) {
+        return true;
+    }
+    *reader = start;
+    return false;
+}
+
+bool fp_read_map(FpReader *reader, uint32_t *len) {
+    uint8_t type;
+    if (!peek_type(reader, &type)) {
+        return false;
+    }
+
+    FpReader start = *reader;
+    reader->pos++;
+    bool has_len;
+    if ((type & 0xf0) == 0x80) {
+        *len = type & 0x0f;
+        has_len = true;
+    } else if (type == 0xde || type == 0xdf) {
+        has_len = read_len(reader, type == 0xde ? 2 : 4, len);
+    } else {
+        has_len = false;
+    }
+
+    if (has_len && 2 * (uint64_t)*len <= reader->len - reader->pos) {
+        return true;
+    }
+    *reader = start;
+    return false;
+}
+
+bool fp_skip(FpReader *reader) {
+    uint64_t pending = 1;
+    while (pending > 0) {
+        pending--;
+
+        const uint8_t *bytes;
+        if (!read_bytes(reader, 1, &bytes)) {
+            return false;
+        }
+
+        uint8_t type = bytes[0];
+        uint32_t len = 0;
+        uint32_t size = 0;
+        if (type <= 0x7f || type >= 0xe0 || type == 0xc0 || type == 0xc2 || type == 0xc3) {
+            continue;
+        } else if ((type & 0xe0) == 0xa0) {
+            size = type & 0x1f;
+        } else if ((type & 0xf0) == 0x90) {
+            pending += type & 0x0f;
+            continue;
+        } else if ((type & 0xf0) == 0x80) {
+            pending += 2 * (type & 0x0f);
+            continue;
+        } else {
+            switch (type) {
+            case 0xc4:
+            case 0xc5:
+            case 0xc6:
+                if (!read_len(reader, 1u << (type - 0xc4), &size)) {
+                    return false;
+                }
+                break;
+            case 0xc7:
+            case 0xc8:
+            case 0xc9:
+                if (!read_len(reader, 1u << (type - 0xc7), &size)) {
+                    return false;
+                }
+                size += 1; // Extension type.
+                break;
+            case 0xca:
+                size = 4;
+                break;
+            case 0xcb:
+                size = 8;
+                break;
+            case 0xcc:
+            case 0xcd:
+            case 0xce:
+            case 0xcf:
+                size = 1u << (type - 0xcc);
+                break;
+            case 0xd0:
+            case 0xd1:
+            case 0xd2:
+            case 0xd3:
+                size = 1u << (type - 0xd0);
+                break;
+            case 0xd4:
+            case 0xd5:
+            case 0xd6:
+            case 0xd7:
+            case 0xd8:
+                size = (1u << (type - 0xd4)) + 1;
+                break;
+            case 0xd9:
+            case 0xda:
+            case 0xdb:
+                if (!read_len(reader, 1u << (type - 0xd9), &size)) {
+                    return false;
+                }
+                break;
+            case 0xdc:
+            case 0xdd:
+                if (!read_len(reader, type == 0xdc ? 2 : 4, &len)) {
+                    return false;
+                }
+                pending += len;
+                continue;
+            case 0xde:
+            case 0xdf:
+                if (!read_len(reader, type == 0xde ? 2 : 4, &len)) {
+                    return false;
+                }
+                pending += 2 * (uint64_t)len;
+                continue;
+            default:
+                return false;
+            }
+        }
+
+        if (!read_bytes(reader, size, &bytes)) {
+            return false;
+        }
+    }
+    return true;
+}
+
+bool fp_key_eq(const char *key, uint32_t key_len, const char *expected) {
+    return strlen(expected) == key_len && memcmp(key, expected, key_len) == 0;
+}
+
+static bool decode_struct(FpReader *reader, const char *tag, void *object,
+                          FpFieldDecoder decode_field, const char *const *fields,
+                          uint32_t field_count) {
+    // Only the struct itself is strict, not any of its fields:
+    bool strict = reader->strict;
+    reader->strict = false;
+
+    uint32_t len;
+    if (fp_read_map(reader, &len)) {
+        for (uint32_t i = 0; i < len; i++) {
+            const char *key;
+            uint32_t key_len;
+            if (!fp_read_str(reader, &key, &key_len)) {
+                return false;
+            }
+
+            int result = decode_field(reader, object, key, key_len);
+            if (result < 0) {
+                return false;
+            } else if (result == 0) {
+                if (strict && (tag == NULL || !fp_key_eq(key, key_len, tag))) {
+                    return false;
+                }
+                if (!fp_skip(reader)) {
+                    return false;
+                }
+            }
+        }
+        return true;
+    }
+
+    if (fields != NULL && fp_read_array(reader, &len)) {
+        uint32_t offset = 0;
+        if (tag != NULL) {
+            if (len == 0 || !fp_skip(reader)) {
+                return false;
+            }
+            offset = 1;
+        }
+        for (uint32_t i = offset; i < len; i++) {
+            if (i - offset >= field_count) {
+                if (strict || !fp_skip(reader)) {
+                    return false;
+                }
+                continue;
+            }
+
+            const char *key = fields[i - offset];
+            if (decode_field(reader, object, key, (uint32_t)strlen(key)) <= 0) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    return false;
+}
+
+bool fp_decode_struct(FpReader *reader, void *object, FpFieldDecoder decode_field,
+                      const char *const *fields, uint32_t field_count) {
+    return decode_struct(reader, NULL, object, decode_field, fields, field_count);
+}
+
+bool fp_decode_tagged_struct(FpReader *reader, const char *tag, void *object,
+                             FpFieldDecoder decode_field, const char *const *fields,
+                             uint32_t field_count) {
+    return decode_struct(reader, tag, object, decode_field, fields, field_count);
+}
+
+bool fp_read_variant(FpReader *reader, const char **name, uint32_t *name_len,
+                     FpReader *content) {
+    memset(content, 0, sizeof(*content));
+    if (fp_read_str(reader, name, name_len)) {
+        return true;
+    }
+
+    FpReader start = *reader;
+    uint32_t len;
+    if (fp_read_map(reader, &len) && len == 1 && fp_read_str(reader, name, name_len)) {
+        *content = *reader;
+        if (fp_skip(reader)) {
+            return true;
+        }
+    }
+    *reader = start;
+    return false;
+}
+
+bool fp_read_tag(const FpReader *reader, const char *tag, const char **name,
+                 uint32_t *name_len) {
+    FpReader lookahead = *reader;
+    uint32_t len;
+    if (fp_read_map(&lookahead, &len)) {
+        for (uint32_t i = 0; i < len; i++) {
+            const char *key;
+            uint32_t key_len;
+            if (!fp_read_str(&lookahead, &key, &key_len)) {
+                return false;
+            }
+            if (fp_key_eq(key, key_len, tag)) {
+                return fp_read_str(&lookahead, name, name_len);
+            }
+            if (!fp_skip(&lookahead)) {
+                return false;
+            }
+        }
+        return false;
+    }
+
+    return fp_read_array(&lookahead, &len) && len > 0 && fp_read_str(&lookahead, name, name_len);
+}
+
+bool fp_read_adjacent(FpReader *reader, const char *tag, const char *content_key,
+                      const char **name, uint32_t *name_len, FpReader *content) {
+    memset(content, 0, sizeof(*content));
+
+    FpReader start = *reader;
+    uint32_t len;
+    if (fp_read_map(reader, &len)) {
+        bool has_tag = false;
+        for (uint32_t i = 0; i < len; i++) {
+            const char *key;
+            uint32_t key_len;
+            if (!fp_read_str(reader, &key, &key_len)) {
+                break;
+            }
+            if (fp_key_eq(key, key_len, tag)) {
+                if (!fp_read_str(reader, name, name_len)) {
+                    break;
+                }
+                has_tag = true;
+                continue;
+            }
+            if (fp_key_eq(key, key_len, content_key)) {
+                *content = *reader;
+            }
+            if (!fp_skip(reader)) {
+                break;
+            }
+        }
+        if (has_tag) {
+            return true;
+        }
+    } else if (fp_read_array(reader, &len) && (len == 1 || len == 2) &&
+               fp_read_str(reader, name, name_len)) {
+        if (len == 1) {
+            return true;
+        }
+        *content = *reader;
+        if (fp_skip(reader)) {
+            return true;
+        }
+    }
+    *reader = start;
+    return false;
+}
+
+FpReader fp_attempt(const FpReader *reader) {
+    FpReader attempt = *reader;
+    attempt.strict = true;
+    return attempt;
+}
+
+bool fp_commit(FpReader *reader, const FpReader *attempt) {
+    reader->pos = attempt->pos;
+    return true;
+}
+
+// Encoding and decoding of built-in types:
+
+#define FP_SIGNED_CODEC(name, type, min, max)                                                    \
+    void fp_encode_##name(FpWriter *writer, const type *value) {                                 \
+        fp_write_int(writer, *value);                                                            \
+    }                                                                                            \
+    bool fp_decode_##name(FpReader *reader, type *value) {                                       \
+        FpReader start = *reader;                                                                \
+        int64_t result;                                                                          \
+        if (!fp_read_int(reader, &result)) {                                                     \
+            return false;                                                                        \
+        }                                                                                        \
+        if (result < (min) || result > (max)) {                                                  \
+            *reader = start;                                                                     \
+            return false;                                                                        \
+        }                                                                                        \
+        *value = (type)result;                                                                   \
+        return true;                                                                             \
+    }
+
+#define FP_UNSIGNED_CODEC(name, type, max)                                                       \
+    void fp_encode_##name(FpWriter *writer, const type *value) {                                 \
+        fp_write_uint(writer, *value);                                                           \
+    }                                                                                            \
+    bool fp_decode_##name(FpReader *reader, type *value) {                                       \
+        FpReader start = *reader;                                                                \
+        uint64_t result;                                                                         \
+        if (!fp_read_uint(reader, &result)) {                                                    \
+            return false;                                                                        \
+        }                                                                                        \
+        if (result > (max)) {                                                                    \
+            *reader = start;                                                                     \
+            return false;                                                                        \
+        }                                                                                        \
+        *value = (type)result;                                                                   \
+        return true;                                                                             \
+    }
+
+FP_SIGNED_CODEC(i8, int8_t, INT8_MIN, INT8_MAX)
+FP_SIGNED_CODEC(i16, int16_t, INT16_MIN, INT16_MAX)
+FP_SIGNED_CODEC(i32, int32_t, INT32_MIN, INT32_MAX)
+FP_SIGNED_CODEC(i64, int64_t, INT64_MIN, INT64_MAX)
+FP_UNSIGNED_CODEC(u8, uint8_t, UINT8_MAX)
+FP_UNSIGNED_CODEC(u16, uint16_t, UINT16_MAX)
+FP_UNSIGNED_CODEC(u32, uint32_t, UINT32_MAX)
+FP_UNSIGNED_CODEC(u64, uint64_t, UINT64_MAX)
+
+void fp_encode_bool(FpWriter *writer, const bool *value) {
+    fp_write_bool(writer, *value);
+}
+
+bool fp_decode_bool(FpReader *reader, bool *value) {
+    return fp_read_bool(reader, value);
+}
+
+void fp_encode_f32(FpWriter *writer, const float *value) {
+    fp_write_f32(writer, *value);
+}
+
+bool fp_decode_f32(FpReader *reader, float *value) {
+    return fp_read_f32(reader, value);
+}
+
+void fp_encode_f64(FpWriter *writer, const double *value) {
+    fp_write_f64(writer, *value);
+}
+
+bool fp_decode_f64(FpReader *reader, double *value) {
+    return fp_read_f64(reader, value);
+}
+
+void fp_encode_Unit(FpWriter *writer, const FpUnit *value) {
+    (void)value;
+    fp_write_nil(writer);
+}
+
+bool fp_decode_Unit(FpReader *reader, FpUnit *value) {
+    *value = 0;
+    return fp_read_nil(reader);
+}
+
+void fp_encode_String(FpWriter *writer, const FpString *value) {
+    fp_write_str(writer, value->data, value->len);
+}
+
+bool fp_decode_String(FpReader *reader, FpString *value) {
+    const char *data;
+    uint32_t len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_str(reader, &data, &len)) {
+        return false;
+    }
+    *value = fp_string_from(data, len);
+    return true;
+}
+
+void fp_free_String(FpString *value) {
+    free(value->data);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_Bytes(FpWriter *writer, const FpBytes *value) {
+    fp_write_bin(writer, value->data, value->len);
+}
+
+bool fp_decode_Bytes(FpReader *reader, FpBytes *value) {
+    const uint8_t *data;
+    uint32_t len;
+    memset(value, 0, sizeof(*value));
+    if (!fp_read_bin(reader, &data, &len) &&
+        !fp_read_str(reader, (const char **)&data, &len)) {
+        return false;
+    }
+    *value = fp_bytes_from(data, len);
+    return true;
+}
+
+void fp_free_Bytes(FpBytes *value) {
+    free(value->data);
+    memset(value, 0, sizeof(*value));
+}
+
+void fp_encode_Value(FpWriter *writer, const FpValue *value) {
+    if (value->len > 0) {
+        fp_write_raw(writer, value->data, value->len);
+    } else {
+        fp_write_nil(writer);
+    }
+}
+
+bool fp_decode_Value(FpReader *reader, FpValue *value) {
+    uint32_t start = reader->pos;
+    memset(value, 0, sizeof(*value));
+    if (!fp_skip(reader)) {
+        reader->pos = start;
+        return false;
+    }
+    value->len = reader->pos - start;
+    value->data = fp_alloc(value->len, 1);
+    memcpy(value->data, reader->data + start, value->len);
+    return true;
+}
+
+void fp_free_Value(FpValue *value) {
+    free(value->data);
+    memset(value, 0, sizeof(*value));
+}
+
+// Async support:
+
+FpAsyncValue fp_async_value_new(void) {
+    AsyncValue *async_value = fp_alloc(1, sizeof(AsyncValue));
+    return to_fat_ptr(async_value, sizeof(AsyncValue));
+}
+
+void fp_await_async_value(FatPtr async_value_ptr, FpAsyncHandler handler,
+                          void (*callback)(void), void *user_data) {
+    volatile AsyncValue *async_value = (volatile AsyncValue *)from_fat_ptr(async_value_ptr);
+    if (async_value->status == FP_ASYNC_STATUS_READY) {
+        FatPtr result_ptr = ((FatPtr)async_value->ptr << 32) | async_value->len;
+        __fp_free(async_value_ptr);
+        handler(result_ptr, callback, user_data);
+        return;
+    }
+
+    PendingValue *pending = fp_alloc(1, sizeof(PendingValue));
+    pending->async_value_ptr = async_value_ptr;
+    pending->handler = handler;
+    pending->callback = callback;
+    pending->user_data = user_data;
+    pending->next = pending_values;
+    pending_values = pending;
+}
+
+FP_EXPORT("__fp_guest_resolve_async_value")
+void __fp_guest_resolve_async_value(FatPtr async_value_ptr, FatPtr result_ptr) {
+    volatile AsyncValue *async_value = (volatile AsyncValue *)from_fat_ptr(async_value_ptr);
+    async_value->status = FP_ASYNC_STATUS_READY;
+    async_value->ptr = (uint32_t)(result_ptr >> 32);
+    async_value->len = (uint32_t)result_ptr;
+
+    for (PendingValue **entry = &pending_values; *entry != NULL; entry = &(*entry)->next) {
+        PendingValue *pending = *entry;
+        if (pending->async_value_ptr == async_value_ptr) {
+            *entry = pending->next;
+            __fp_free(async_value_ptr);
+            pending->handler(result_ptr, pending->callback, pending->user_data);
+            free(pending);
+            return;
+        }
+    }
+}
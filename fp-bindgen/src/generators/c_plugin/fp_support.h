@@ -0,0 +1,323 @@
+// ============================================= //
+// Support library for WebAssembly plugins       //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#ifndef FP_SUPPORT_H
+#define FP_SUPPORT_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#if defined(__wasm__)
+#define FP_IMPORT(name) __attribute__((import_module("fp"), import_name(name)))
+#define FP_EXPORT(name) __attribute__((export_name(name)))
+#else
+#define FP_IMPORT(name)
+#define FP_EXPORT(name)
+#endif
+
+#define FP_STRINGIFY_(value) #value
+#define FP_STRINGIFY(value) FP_STRINGIFY_(value)
+
+/**
+ * A fat pointer is passed as a 64-bit integer. Its 32 most-significant bits
+ * contain the offset of the data, while its 32 least-significant bits contain
+ * the length of the data.
+ */
+typedef uint64_t FatPtr;
+
+/**
+ * Fat pointer to an async value, which is used for resolving the result of an
+ * async export at a later time.
+ */
+typedef FatPtr FpAsyncValue;
+
+/**
+ * Length of a fat pointer that points to a 4-byte length header, instead of
+ * to the data itself.
+ */
+#define FP_FAT_PTR_LEN_HEADER 0x01000000u
+
+/**
+ * Maximum length that can be encoded in the length bits of a fat pointer.
+ */
+#define FP_FAT_PTR_MAX_LEN 0x00ffffffu
+
+/**
+ * An owned, UTF-8 encoded string. `data` is always followed by a terminating
+ * NUL byte, although the string itself may contain NUL bytes as well.
+ */
+typedef struct FpString {
+    char *data;
+    uint32_t len;
+} FpString;
+
+/**
+ * An owned byte buffer.
+ */
+typedef struct FpBytes {
+    uint8_t *data;
+    uint32_t len;
+} FpBytes;
+
+/**
+ * An owned buffer containing an arbitrary MessagePack-encoded value, used for
+ * types that have no C equivalent. An empty buffer is encoded as `nil`.
+ */
+typedef struct FpValue {
+    uint8_t *data;
+    uint32_t len;
+} FpValue;
+
+/**
+ * The unit type, which is always encoded as `nil`.
+ */
+typedef uint8_t FpUnit;
+
+/**
+ * Growable buffer for encoding MessagePack. Should be initialized to zero.
+ */
+typedef struct FpWriter {
+    uint8_t *data;
+    uint32_t len;
+    uint32_t capacity;
+} FpWriter;
+
+/**
+ * Cursor for decoding MessagePack from a buffer.
+ */
+typedef struct FpReader {
+    const uint8_t *data;
+    uint32_t len;
+    uint32_t pos;
+    FatPtr ptr;
+    bool strict;
+} FpReader;
+
+/**
+ * Decodes the value for the field with the given key into the given object.
+ *
+ * Returns `1` if the field was decoded, `0` if the key is unknown and `-1` if
+ * the value could not be decoded.
+ */
+typedef int (*FpFieldDecoder)(FpReader *reader, void *object, const char *key, uint32_t key_len);
+
+/**
+ * Invoked with the result of an async import once it is available.
+ */
+typedef void (*FpAsyncHandler)(FatPtr result_ptr, void (*callback)(void), void *user_data);
+
+/**
+ * Reports a panic to the host and aborts execution.
+ */
+__attribute__((noreturn)) void fp_panic_at(const char *message, const char *location);
+
+#define fp_panic(message) fp_panic_at((message), __FILE__ ":" FP_STRINGIFY(__LINE__))
+
+/**
+ * Allocates zero-initialized memory for the given number of items, aborting if
+ * no memory is available.
+ */
+void *fp_alloc(uint32_t count, size_t size);
+
+/**
+ * Creates a string by copying the given NUL-terminated string.
+ */
+FpString fp_string(const char *value);
+
+/**
+ * Creates a string by copying `len` bytes from the given data.
+ */
+FpString fp_string_from(const char *data, uint32_t len);
+
+/**
+ * Creates a byte buffer by copying `len` bytes from the given data.
+ */
+FpBytes fp_bytes_from(const uint8_t *data, uint32_t len);
+
+/**
+ * Moves the contents of the writer into memory allocated using
+ * `__fp_malloc()`, so that it can be passed to the host. The writer is reset.
+ */
+FatPtr fp_writer_finish(FpWriter *writer);
+
+/**
+ * Releases the buffer of a writer that will not be finished.
+ */
+void fp_writer_free(FpWriter *writer);
+
+void fp_write_nil(FpWriter *writer);
+void fp_write_bool(FpWriter *writer, bool value);
+void fp_write_int(FpWriter *writer, int64_t value);
+void fp_write_uint(FpWriter *writer, uint64_t value);
+void fp_write_f32(FpWriter *writer, float value);
+void fp_write_f64(FpWriter *writer, double value);
+void fp_write_str(FpWriter *writer, const char *data, uint32_t len);
+void fp_write_cstr(FpWriter *writer, const char *value);
+void fp_write_bin(FpWriter *writer, const uint8_t *data, uint32_t len);
+void fp_write_array(FpWriter *writer, uint32_t len);
+void fp_write_map(FpWriter *writer, uint32_t len);
+void fp_write_raw(FpWriter *writer, const uint8_t *data, uint32_t len);
+
+/**
+ * Opens a reader for the data behind a fat pointer received from the host.
+ */
+FpReader fp_reader_open(FatPtr ptr);
+
+/**
+ * Frees the data behind the fat pointer the reader was opened with.
+ */
+void fp_reader_close(FpReader *reader);
+
+/**
+ * Reads a `nil` value, returning whether there was one. Nothing is consumed if
+ * the next value is not `nil`.
+ */
+bool fp_read_nil(FpReader *reader);
+
+bool fp_read_bool(FpReader *reader, bool *value);
+bool fp_read_int(FpReader *reader, int64_t *value);
+bool fp_read_uint(FpReader *reader, uint64_t *value);
+bool fp_read_f32(FpReader *reader, float *value);
+bool fp_read_f64(FpReader *reader, double *value);
+
+/**
+ * Reads a string without copying it. `data` points into the reader's buffer.
+ */
+bool fp_read_str(FpReader *reader, const char **data, uint32_t *len);
+
+/**
+ * Reads a binary value without copying it. `data` points into the reader's
+ * buffer.
+ */
+bool fp_read_bin(FpReader *reader, const uint8_t **data, uint32_t *len);
+
+bool fp_read_array(FpReader *reader, uint32_t *len);
+bool fp_read_map(FpReader *reader, uint32_t *len);
+
+/**
+ * Skips over the next value, including any nested values.
+ */
+bool fp_skip(FpReader *reader);
+
+/**
+ * Returns whether the given key equals the expected NUL-terminated string.
+ */
+bool fp_key_eq(const char *key, uint32_t key_len, const char *expected);
+
+/**
+ * Decodes a struct, which may be encoded either as a map or, if `fields` is
+ * not `NULL`, as an array containing the values of the given fields in order.
+ */
+bool fp_decode_struct(FpReader *reader, void *object, FpFieldDecoder decode_field,
+                      const char *const *fields, uint32_t field_count);
+
+/**
+ * Decodes a struct that is preceded by a tag. If it is encoded as a map, the
+ * tag is expected as one of its keys, otherwise as the first array element.
+ */
+bool fp_decode_tagged_struct(FpReader *reader, const char *tag, void *object,
+                             FpFieldDecoder decode_field, const char *const *fields,
+                             uint32_t field_count);
+
+/**
+ * Reads the name of an externally tagged enum variant. `content` is positioned
+ * at the content of the variant, if there is one.
+ */
+bool fp_read_variant(FpReader *reader, const char **name, uint32_t *name_len,
+                     FpReader *content);
+
+/**
+ * Reads the name of an internally tagged enum variant, without consuming
+ * anything.
+ */
+bool fp_read_tag(const FpReader *reader, const char *tag, const char **name,
+                 uint32_t *name_len);
+
+/**
+ * Reads the name of an adjacently tagged enum variant. `content` is positioned
+ * at the content of the variant, if there is one.
+ */
+bool fp_read_adjacent(FpReader *reader, const char *tag, const char *content_key,
+                      const char **name, uint32_t *name_len, FpReader *content);
+
+/**
+ * Creates a copy of the reader for attempting to decode an untagged enum
+ * variant, which rejects unknown fields.
+ */
+FpReader fp_attempt(const FpReader *reader);
+
+/**
+ * Advances the reader past a successful attempt.
+ */
+bool fp_commit(FpReader *reader, const FpReader *attempt);
+
+void fp_encode_bool(FpWriter *writer, const bool *value);
+bool fp_decode_bool(FpReader *reader, bool *value);
+void fp_encode_i8(FpWriter *writer, const int8_t *value);
+bool fp_decode_i8(FpReader *reader, int8_t *value);
+void fp_encode_i16(FpWriter *writer, const int16_t *value);
+bool fp_decode_i16(FpReader *reader, int16_t *value);
+void fp_encode_i32(FpWriter *writer, const int32_t *value);
+bool fp_decode_i32(FpReader *reader, int32_t *value);
+void fp_encode_i64(FpWriter *writer, const int64_t *value);
+bool fp_decode_i64(FpReader *reader, int64_t *value);
+void fp_encode_u8(FpWriter *writer, const uint8_t *value);
+bool fp_decode_u8(FpReader *reader, uint8_t *value);
+void fp_encode_u16(FpWriter *writer, const uint16_t *value);
+bool fp_decode_u16(FpReader *reader, uint16_t *value);
+void fp_encode_u32(FpWriter *writer, const uint32_t *value);
+bool fp_decode_u32(FpReader *reader, uint32_t *value);
+void fp_encode_u64(FpWriter *writer, const uint64_t *value);
+bool fp_decode_u64(FpReader *reader, uint64_t *value);
+void fp_encode_f32(FpWriter *writer, const float *value);
+bool fp_decode_f32(FpReader *reader, float *value);
+void fp_encode_f64(FpWriter *writer, const double *value);
+bool fp_decode_f64(FpReader *reader, double *value);
+void fp_encode_Unit(FpWriter *writer, const FpUnit *value);
+bool fp_decode_Unit(FpReader *reader, FpUnit *value);
+void fp_encode_String(FpWriter *writer, const FpString *value);
+bool fp_decode_String(FpReader *reader, FpString *value);
+void fp_free_String(FpString *value);
+void fp_encode_Bytes(FpWriter *writer, const FpBytes *value);
+bool fp_decode_Bytes(FpReader *reader, FpBytes *value);
+void fp_free_Bytes(FpBytes *value);
+void fp_encode_Value(FpWriter *writer, const FpValue *value);
+bool fp_decode_Value(FpReader *reader, FpValue *value);
+void fp_free_Value(FpValue *value);
+
+/**
+ * Creates an async value for an async export. Its result should be resolved
+ * through the `_resolve()` function that belongs to the export.
+ */
+FpAsyncValue fp_async_value_new(void);
+
+/**
+ * Invokes the handler once the given async value, as returned by an async
+ * import, has been resolved by the host.
+ */
+void fp_await_async_value(FatPtr async_value_ptr, FpAsyncHandler handler,
+                          void (*callback)(void), void *user_data);
+
+FatPtr __fp_malloc(uint32_t len);
+void __fp_free(FatPtr ptr);
+void __fp_guest_resolve_async_value(FatPtr async_value_ptr, FatPtr result_ptr);
+
+FP_IMPORT("__fp_host_panic")
+void __fp_host_panic(FatPtr panic);
+
+FP_IMPORT("__fp_host_resolve_async_value")
+void __fp_host_resolve_async_value(FatPtr async_value_ptr, FatPtr result_ptr);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
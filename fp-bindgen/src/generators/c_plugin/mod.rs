@@ -0,0 +1,1997 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    generators::protocol_json::protocol_fingerprint,
+    prelude::Primitive,
+    types::{Enum, EnumOptions, Field, Type, TypeIdent, TypeMap, Variant},
+};
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    str::FromStr,
+};
+
+const C_KEYWORDS: &[&str] = &[
+    "auto", "bool", "break", "case", "char", "const", "continue", "default", "do", "double",
+    "else", "enum", "extern", "false", "float", "for", "goto", "if", "inline", "int", "long",
+    "register", "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch",
+    "true", "typedef", "union", "unsigned", "void", "volatile", "while",
+];
+
+/// Functions from the C standard library, which protocol functions may not
+/// shadow.
+const C_LIBRARY_FUNCTIONS: &[&str] = &[
+    "abort", "abs", "acos", "asin", "atan", "calloc", "ceil", "clock", "cos", "exit", "exp",
+    "fabs", "floor", "fmod", "free", "labs", "log", "log10", "log2", "malloc", "memcmp", "memcpy",
+    "memset", "pow", "printf", "puts", "rand", "realloc", "round", "sin", "sqrt", "strcmp",
+    "strlen", "tan", "time", "trunc",
+];
+
+/// Names of local variables and parameters used by the generated wrappers,
+/// which arguments may not shadow.
+const RESERVED_NAMES: &[&str] = &[
+    "async_value",
+    "async_value_ptr",
+    "callback",
+    "reader",
+    "result",
+    "result_ptr",
+    "user_data",
+    "writer",
+];
+
+/// The support library is the same for every protocol, so it is kept in
+/// regular C files that are copied into the bindings verbatim.
+const SUPPORT_HEADER: &str = include_str!("fp_support.h");
+const SUPPORT_SOURCE: &str = include_str!("fp_support.c");
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+) -> BTreeMap<PathBuf, String> {
+    let protocol_version = protocol_fingerprint(&import_functions, &export_functions, &types);
+
+    let mut generator = TypeGenerator::new(&types);
+    for (ident, ty) in &types {
+        let is_protocol_type = match ty {
+            Type::Alias(_, _) => true,
+            Type::Enum(ty) => ty.ident.generic_args.is_empty(),
+            Type::Struct(ty) => ty.ident.generic_args.is_empty(),
+            _ => false,
+        };
+        if is_protocol_type {
+            generator.visit(ident);
+        }
+    }
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        for arg in &function.args {
+            generator.visit(&arg.ty);
+        }
+        if let Some(ty) = &function.return_type {
+            generator.visit(ty);
+        }
+    }
+
+    BTreeMap::from([
+        (PathBuf::from("fp_support.h"), SUPPORT_HEADER.to_owned()),
+        (PathBuf::from("fp_support.c"), SUPPORT_SOURCE.to_owned()),
+        (PathBuf::from("types.h"), generator.format_header()),
+        (PathBuf::from("types.c"), generator.format_source()),
+        (
+            PathBuf::from("bindings.h"),
+            generate_bindings_header(&import_functions, &export_functions, &generator),
+        ),
+        (
+            PathBuf::from("bindings.c"),
+            generate_bindings_source(
+                &import_functions,
+                &export_functions,
+                protocol_version,
+                &generator,
+            ),
+        ),
+    ])
+}
+
+fn generate_bindings_header(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeGenerator,
+) -> String {
+    let import_decls = import_functions
+        .iter()
+        .map(|function| {
+            let docs = format_docs(&function.doc_lines, "");
+            if function.is_async {
+                let callback_name = format!("{}_callback", get_function_name(&function.name));
+                let callback_args = match &function.return_type {
+                    Some(ty) => format!(
+                        "{}, void *user_data",
+                        format_declaration(&types.c_type(ty), "result")
+                    ),
+                    None => "void *user_data".to_owned(),
+                };
+                format!(
+                    "\n{docs}typedef void (*{callback_name})({callback_args});\n\
+                    void {}({});\n",
+                    get_function_name(&function.name),
+                    format_args(
+                        function,
+                        types,
+                        &[
+                            format!("{callback_name} callback"),
+                            "void *user_data".to_owned()
+                        ]
+                    )
+                )
+            } else {
+                format!("\n{docs}{};\n", format_signature(function, types, &[]))
+            }
+        })
+        .collect::<String>();
+
+    let export_decls = export_functions
+        .iter()
+        .map(|function| {
+            let docs = format_docs(&function.doc_lines, "");
+            if function.is_async {
+                let resolve_args = match &function.return_type {
+                    Some(ty) => format!(
+                        "FpAsyncValue async_value, {}",
+                        format_declaration(&types.c_type(ty), "result")
+                    ),
+                    None => "FpAsyncValue async_value".to_owned(),
+                };
+                format!(
+                    "\n{docs}void {}({});\n\n\
+                    /**\n \
+                    * Resolves the result of `{}()`. Takes ownership of the result.\n \
+                    */\n\
+                    void {}_resolve({resolve_args});\n",
+                    get_function_name(&function.name),
+                    format_args(function, types, &["FpAsyncValue async_value".to_owned()]),
+                    function.name,
+                    get_function_name(&function.name)
+                )
+            } else {
+                format!("\n{docs}{};\n", format_signature(function, types, &[]))
+            }
+        })
+        .collect::<String>();
+
+    format!(
+        "// ============================================= //
+// Bindings for WebAssembly plugins in C         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#ifndef FP_BINDINGS_H
+#define FP_BINDINGS_H
+
+#include \"types.h\"
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+// Functions imported from the host.
+//
+// Arguments are borrowed, while results are owned by the caller. Async imports
+// invoke their callback once the result is available, passing ownership of the
+// result to the callback.
+{import_decls}
+// Functions exported to the host, which should be implemented by the plugin.
+//
+// Arguments are borrowed, while results are owned by the caller. Async exports
+// receive an async value, which should be resolved through the corresponding
+// `_resolve()` function.
+{export_decls}
+#ifdef __cplusplus
+}}
+#endif
+
+#endif
+"
+    )
+}
+
+fn generate_bindings_source(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    protocol_version: u64,
+    types: &TypeGenerator,
+) -> String {
+    let import_wrappers = import_functions
+        .iter()
+        .map(|function| format_import_wrapper(function, types))
+        .collect::<String>();
+    let export_wrappers = export_functions
+        .iter()
+        .map(|function| format_export_wrapper(function, types))
+        .collect::<String>();
+
+    format!(
+        "// ============================================= //
+// Bindings for WebAssembly plugins in C         //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#include \"bindings.h\"
+{import_wrappers}{export_wrappers}
+FP_EXPORT(\"__fp_protocol_version\")
+uint64_t __fp_protocol_version(void) {{
+    return {protocol_version:#018x}ull;
+}}
+"
+    )
+}
+
+fn format_import_wrapper(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let raw_return_type = match &function.return_type {
+        _ if function.is_async => "FatPtr".to_owned(),
+        None => "void".to_owned(),
+        Some(ty) => format_raw_type(ty, types),
+    };
+    let raw_args = format_raw_args(function, types, "");
+    let raw_decl = format!(
+        "FP_IMPORT(\"__fp_gen_{name}\")\nextern {raw_return_type} __fp_gen_{name}({raw_args});"
+    );
+
+    let mut body = Vec::new();
+    for arg in function.args.iter().filter(|arg| !arg.ty.is_primitive()) {
+        let arg_name = get_arg_name(&arg.name);
+        body.push(format!("FpWriter {arg_name}_writer = {{0}};"));
+        body.push(format!(
+            "fp_encode_{}(&{arg_name}_writer, {arg_name});",
+            types.fn_name(&arg.ty)
+        ));
+    }
+    let call_args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            if arg.ty.is_primitive() {
+                arg_name
+            } else {
+                format!("fp_writer_finish(&{arg_name}_writer)")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let call = format!("__fp_gen_{name}({call_args})");
+
+    let mut handler = String::new();
+    if function.is_async {
+        handler = format_async_handler(function, types);
+        body.push(format!("FatPtr async_value_ptr = {call};"));
+        body.push(format!(
+            "fp_await_async_value(async_value_ptr, {fn_name}_ready, (void (*)(void))callback, user_data);"
+        ));
+    } else {
+        match &function.return_type {
+            None => body.push(format!("{call};")),
+            Some(ty) if ty.is_primitive() => body.push(format!("return {call};")),
+            Some(ty) => {
+                body.push(format!("FatPtr result_ptr = {call};"));
+                body.push(format_decode_result(ty, types, name));
+                body.push("return result;".to_owned());
+            }
+        }
+    }
+
+    let signature = if function.is_async {
+        format!(
+            "void {fn_name}({})",
+            format_args(
+                function,
+                types,
+                &[
+                    format!("{fn_name}_callback callback"),
+                    "void *user_data".to_owned()
+                ]
+            )
+        )
+    } else {
+        format_signature(function, types, &[])
+    };
+
+    format!(
+        "\n{raw_decl}\n{handler}\n{signature} {{\n{}}}\n",
+        join_lines(&body, "    ")
+    )
+}
+
+/// Formats the handler that is invoked once the result of an async import is
+/// available. It decodes the result and passes it on to the user's callback.
+fn format_async_handler(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let mut body = Vec::new();
+    match &function.return_type {
+        Some(ty) => {
+            body.push(format_decode_result(ty, types, name));
+            body.push(format!(
+                "(({fn_name}_callback)callback)(result, user_data);"
+            ));
+        }
+        None => {
+            body.push("FpReader reader = fp_reader_open(result_ptr);".to_owned());
+            body.push("fp_reader_close(&reader);".to_owned());
+            body.push(format!("(({fn_name}_callback)callback)(user_data);"));
+        }
+    }
+
+    format!(
+        "\nstatic void {fn_name}_ready(FatPtr result_ptr, void (*callback)(void), void *user_data) {{\n{}}}\n",
+        join_lines(&body, "    ")
+    )
+}
+
+fn format_export_wrapper(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let raw_return_type = match &function.return_type {
+        _ if function.is_async => "FatPtr".to_owned(),
+        None => "void".to_owned(),
+        Some(ty) => format_raw_type(ty, types),
+    };
+    let raw_args = format_raw_args(function, types, "_ptr");
+
+    let mut body = Vec::new();
+    for arg in function.args.iter().filter(|arg| !arg.ty.is_primitive()) {
+        let arg_name = get_arg_name(&arg.name);
+        body.push(format!(
+            "{};",
+            format_declaration(&types.c_type(&arg.ty), &arg_name)
+        ));
+        body.push(format!(
+            "FpReader {arg_name}_reader = fp_reader_open({arg_name}_ptr);"
+        ));
+        body.push(format!(
+            "if (!fp_decode_{}(&{arg_name}_reader, &{arg_name})) {{\n    \
+            fp_panic(\"Could not decode argument `{}` of `{name}()`\");\n}}",
+            types.fn_name(&arg.ty),
+            arg.name
+        ));
+        body.push(format!("fp_reader_close(&{arg_name}_reader);"));
+    }
+
+    let mut call_args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            if arg.ty.is_primitive() {
+                arg_name
+            } else {
+                format!("&{arg_name}")
+            }
+        })
+        .collect::<Vec<_>>();
+    if function.is_async {
+        call_args.push("async_value".to_owned());
+    }
+    let call = format!("{fn_name}({})", call_args.join(", "));
+
+    let free_args = function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive() && types.needs_free(&arg.ty))
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            format!("fp_free_{}(&{arg_name});", types.fn_name(&arg.ty))
+        })
+        .collect::<Vec<_>>();
+
+    let mut resolver = String::new();
+    if function.is_async {
+        body.push("FpAsyncValue async_value = fp_async_value_new();".to_owned());
+        body.push(format!("{call};"));
+        body.extend(free_args);
+        body.push("return async_value;".to_owned());
+
+        let mut resolve_body = vec!["FpWriter writer = {0};".to_owned()];
+        let resolve_args = match &function.return_type {
+            Some(ty) => {
+                resolve_body.push(format!(
+                    "fp_encode_{}(&writer, &result);",
+                    types.fn_name(ty)
+                ));
+                if types.needs_free(ty) {
+                    resolve_body.push(format!("fp_free_{}(&result);", types.fn_name(ty)));
+                }
+                format!(
+                    "FpAsyncValue async_value, {}",
+                    format_declaration(&types.c_type(ty), "result")
+                )
+            }
+            None => {
+                resolve_body.push("fp_write_nil(&writer);".to_owned());
+                "FpAsyncValue async_value".to_owned()
+            }
+        };
+        resolve_body.push(
+            "__fp_host_resolve_async_value(async_value, fp_writer_finish(&writer));".to_owned(),
+        );
+        resolver = format!(
+            "\nvoid {fn_name}_resolve({resolve_args}) {{\n{}}}\n",
+            join_lines(&resolve_body, "    ")
+        );
+    } else {
+        match &function.return_type {
+            None => {
+                body.push(format!("{call};"));
+                body.extend(free_args);
+            }
+            Some(ty) if ty.is_primitive() => {
+                body.push(format!(
+                    "{} = {call};",
+                    format_declaration(&types.c_type(ty), "result")
+                ));
+                body.extend(free_args);
+                body.push("return result;".to_owned());
+            }
+            Some(ty) => {
+                body.push(format!(
+                    "{} = {call};",
+                    format_declaration(&types.c_type(ty), "result")
+                ));
+                body.extend(free_args);
+                body.push("FpWriter writer = {0};".to_owned());
+                body.push(format!(
+                    "fp_encode_{}(&writer, &result);",
+                    types.fn_name(ty)
+                ));
+                if types.needs_free(ty) {
+                    body.push(format!("fp_free_{}(&result);", types.fn_name(ty)));
+                }
+                body.push("return fp_writer_finish(&writer);".to_owned());
+            }
+        }
+    }
+
+    format!(
+        "\nFP_EXPORT(\"__fp_gen_{name}\")\n{raw_return_type} __fp_gen_{name}({raw_args}) {{\n{}}}\n{resolver}",
+        join_lines(&body, "    ")
+    )
+}
+
+/// Decodes the value behind `result_ptr` into a new `result` variable.
+fn format_decode_result(ty: &TypeIdent, types: &TypeGenerator, function_name: &str) -> String {
+    format!(
+        "{};\n\
+        FpReader reader = fp_reader_open(result_ptr);\n\
+        if (!fp_decode_{}(&reader, &result)) {{\n    \
+        fp_panic(\"Could not decode result of `{function_name}()`\");\n\
+        }}\n\
+        fp_reader_close(&reader);",
+        format_declaration(&types.c_type(ty), "result"),
+        types.fn_name(ty)
+    )
+}
+
+/// Formats the signature of a function as it is exposed to the plugin, with
+/// complex arguments passed by pointer.
+fn format_signature(function: &Function, types: &TypeGenerator, extra_args: &[String]) -> String {
+    let return_type = match &function.return_type {
+        Some(ty) => types.c_type(ty),
+        None => "void".to_owned(),
+    };
+    format_declaration(
+        &return_type,
+        &format!(
+            "{}({})",
+            get_function_name(&function.name),
+            format_args(function, types, extra_args)
+        ),
+    )
+}
+
+fn format_args(function: &Function, types: &TypeGenerator, extra_args: &[String]) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            let ty = types.c_type(&arg.ty);
+            if arg.ty.is_primitive() {
+                format_declaration(&ty, &arg_name)
+            } else {
+                format_param(&ty, &arg_name, true)
+            }
+        })
+        .chain(extra_args.iter().cloned())
+        .collect::<Vec<_>>();
+    if args.is_empty() {
+        "void".to_owned()
+    } else {
+        args.join(", ")
+    }
+}
+
+/// Formats the arguments of a raw import or export, where complex arguments
+/// are passed as fat pointers.
+fn format_raw_args(function: &Function, types: &TypeGenerator, ptr_suffix: &str) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            if arg.ty.is_primitive() {
+                format_declaration(&types.c_type(&arg.ty), &arg_name)
+            } else {
+                format!("FatPtr {arg_name}{ptr_suffix}")
+            }
+        })
+        .collect::<Vec<_>>();
+    if args.is_empty() {
+        "void".to_owned()
+    } else {
+        args.join(", ")
+    }
+}
+
+fn format_raw_type(ty: &TypeIdent, types: &TypeGenerator) -> String {
+    if ty.is_primitive() {
+        types.c_type(ty)
+    } else {
+        "FatPtr".to_owned()
+    }
+}
+
+/// How a type from the protocol is represented in C.
+enum CType<'a> {
+    Primitive(Primitive),
+    String,
+    Bytes,
+    Value,
+    Unit,
+    Alias(TypeIdent),
+    Array(Primitive, usize),
+    Pointer(TypeIdent),
+    Option(TypeIdent),
+    List(TypeIdent),
+    Map(TypeIdent, TypeIdent),
+    Tuple(Vec<TypeIdent>),
+    Struct(Vec<Field>, Casing),
+    Enum(&'a Enum, TypeParams),
+}
+
+/// Maps the names of generic parameters to the arguments they are
+/// instantiated with.
+type TypeParams = Vec<(String, TypeIdent)>;
+
+/// The payload of an enum variant.
+enum Payload {
+    Unit,
+    /// A single value, which is stored in the union directly.
+    Newtype(TypeIdent),
+    /// Named fields, which are stored in an auxiliary struct of the given name.
+    Struct(String),
+    /// Multiple unnamed fields, which are stored in an auxiliary struct of the
+    /// given name.
+    Tuple(String),
+}
+
+/// Generates the C definitions for all the types that are used by the
+/// protocol. Generic types are monomorphized, because C has no generics.
+struct TypeGenerator<'a> {
+    types: &'a TypeMap,
+    visited: BTreeSet<String>,
+
+    /// Maps the names of structs with named fields to the number of fields in
+    /// their field list, if they have one. Structs containing flattened fields
+    /// have no field list, because they cannot be decoded from arrays.
+    field_lists: BTreeMap<String, Option<usize>>,
+
+    /// Names of the types for which an `fp_free_*()` function is generated.
+    freeable_types: BTreeSet<String>,
+
+    enum_definitions: Vec<String>,
+    forward_declarations: Vec<String>,
+    alias_definitions: Vec<String>,
+    struct_definitions: Vec<String>,
+    prototypes: Vec<String>,
+    implementations: Vec<String>,
+}
+
+impl<'a> TypeGenerator<'a> {
+    fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            visited: BTreeSet::new(),
+            field_lists: BTreeMap::new(),
+            freeable_types: BTreeSet::new(),
+            enum_definitions: Vec::new(),
+            forward_declarations: Vec::new(),
+            alias_definitions: Vec::new(),
+            struct_definitions: Vec::new(),
+            prototypes: Vec::new(),
+            implementations: Vec::new(),
+        }
+    }
+
+    fn format_header(&self) -> String {
+        let sections = [
+            &self.enum_definitions,
+            &self.forward_declarations,
+            &self.alias_definitions,
+            &self.struct_definitions,
+            &self.prototypes,
+        ]
+        .iter()
+        .filter(|section| !section.is_empty())
+        .map(|section| {
+            if section.iter().any(|definition| definition.contains('\n')) {
+                section.join("\n\n")
+            } else {
+                section.join("\n")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+        format!(
+            "// ============================================= //
+// Types for WebAssembly plugins in C            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#ifndef FP_TYPES_H
+#define FP_TYPES_H
+
+#include \"fp_support.h\"
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+{sections}
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif
+"
+        )
+    }
+
+    fn format_source(&self) -> String {
+        format!(
+            "// ============================================= //
+// Types for WebAssembly plugins in C            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+#include \"types.h\"
+
+#include <stdlib.h>
+#include <string.h>
+
+{}
+",
+            self.implementations.join("\n\n")
+        )
+    }
+
+    fn resolve(&self, ident: &TypeIdent) -> CType<'a> {
+        if let Some(len) = ident.array {
+            return match Primitive::from_str(&ident.name) {
+                Ok(primitive) => CType::Array(primitive, len.get()),
+                Err(_) => CType::Value,
+            };
+        }
+        if let Some(primitive) = ident.as_primitive() {
+            return CType::Primitive(primitive);
+        }
+
+        let arg = |index: usize| ident.generic_args.get(index).map(|(arg, _)| arg.clone());
+        match self.types.get(ident) {
+            Some(Type::Alias(_, target)) => CType::Alias(target.clone()),
+            Some(Type::Array(primitive, len)) => CType::Array(*primitive, *len),
+            Some(Type::Container(name, _)) => match arg(0) {
+                Some(arg) if name == "Option" => CType::Option(arg),
+                Some(arg) => CType::Pointer(arg),
+                None => CType::Value,
+            },
+            Some(Type::Custom(custom)) => match custom.ts_ty.as_str() {
+                "ArrayBuffer" | "Uint8Array" => CType::Bytes,
+                "boolean" => CType::Primitive(Primitive::Bool),
+                "number" => CType::Primitive(Primitive::F64),
+                "string" => CType::String,
+                _ => CType::Value,
+            },
+            Some(Type::Enum(ty)) => CType::Enum(ty, get_type_params(&ty.ident, ident)),
+            Some(Type::List(_, _)) => arg(0).map(CType::List).unwrap_or(CType::Value),
+            Some(Type::Map(_, _, _)) => match (arg(0), arg(1)) {
+                (Some(key), Some(value)) => CType::Map(key, value),
+                _ => CType::Value,
+            },
+            Some(Type::Primitive(primitive)) => CType::Primitive(*primitive),
+            Some(Type::String) => CType::String,
+            Some(Type::Struct(ty)) => {
+                let params = get_type_params(&ty.ident, ident);
+                let fields = ty
+                    .fields
+                    .iter()
+                    .map(|field| Field {
+                        ty: substitute(&field.ty, &params),
+                        ..field.clone()
+                    })
+                    .collect::<Vec<_>>();
+                match fields.as_slice() {
+                    [field] if field.name.is_none() => CType::Alias(field.ty.clone()),
+                    _ if !fields.is_empty() && fields.iter().all(|field| field.name.is_none()) => {
+                        CType::Tuple(fields.into_iter().map(|field| field.ty).collect())
+                    }
+                    _ => CType::Struct(fields, ty.options.field_casing),
+                }
+            }
+            Some(Type::Tuple(items)) if items.is_empty() => CType::Unit,
+            Some(Type::Tuple(items)) => CType::Tuple(items.clone()),
+            Some(Type::Unit) => CType::Unit,
+            None if ident.name == "String" => CType::String,
+            None => CType::Value,
+        }
+    }
+
+    /// Resolves the given type, following any aliases.
+    fn resolve_alias(&self, ident: &TypeIdent) -> CType<'a> {
+        match self.resolve(ident) {
+            CType::Alias(target) => self.resolve_alias(&target),
+            resolved => resolved,
+        }
+    }
+
+    /// Returns the name under which the type is known in C. This name is used
+    /// for the C type itself, as well as for its encoding functions.
+    fn mangle(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            CType::Primitive(primitive) => primitive.name(),
+            CType::String => "String".to_owned(),
+            CType::Bytes => "Bytes".to_owned(),
+            CType::Value => "Value".to_owned(),
+            CType::Unit => "Unit".to_owned(),
+            CType::Array(primitive, len) => format!("FpArray_{}_{len}", primitive.name()),
+            CType::Pointer(inner) => format!("FpBox_{}", self.mangle(&inner)),
+            CType::Option(inner) => format!("FpOption_{}", self.mangle(&inner)),
+            CType::List(inner) => format!("FpVec_{}", self.mangle(&inner)),
+            CType::Map(key, value) => {
+                format!("FpMap_{}_{}", self.mangle(&key), self.mangle(&value))
+            }
+            CType::Tuple(_) if matches!(self.types.get(ident), Some(Type::Struct(_))) => {
+                self.mangle_named(ident)
+            }
+            CType::Tuple(items) => format!(
+                "FpTuple_{}",
+                items
+                    .iter()
+                    .map(|item| self.mangle(item))
+                    .collect::<Vec<_>>()
+                    .join("_")
+            ),
+            CType::Alias(_) | CType::Struct(_, _) | CType::Enum(_, _) => self.mangle_named(ident),
+        }
+    }
+
+    fn mangle_named(&self, ident: &TypeIdent) -> String {
+        std::iter::once(ident.name.clone())
+            .chain(ident.generic_args.iter().map(|(arg, _)| self.mangle(arg)))
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    /// Returns the name of the C type for the given type.
+    fn c_type(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            CType::Primitive(primitive) => format_primitive(primitive).to_owned(),
+            CType::String => "FpString".to_owned(),
+            CType::Bytes => "FpBytes".to_owned(),
+            CType::Value => "FpValue".to_owned(),
+            CType::Unit => "FpUnit".to_owned(),
+            CType::Pointer(inner) => format_pointer(&self.c_type(&inner)),
+            _ => self.mangle(ident),
+        }
+    }
+
+    /// Returns the suffix of the encoding functions for the given type, which
+    /// for aliases are those of the aliased type.
+    fn fn_name(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            CType::Alias(target) => self.fn_name(&target),
+            _ => self.mangle(ident),
+        }
+    }
+
+    /// Returns whether values of the given type own memory that needs to be
+    /// released using an `fp_free_*()` function.
+    fn needs_free(&self, ident: &TypeIdent) -> bool {
+        match self.resolve(ident) {
+            CType::Primitive(_) | CType::Unit | CType::Array(_, _) => false,
+            CType::String
+            | CType::Bytes
+            | CType::Value
+            | CType::Pointer(_)
+            | CType::List(_)
+            | CType::Map(_, _) => true,
+            CType::Alias(target) => self.needs_free(&target),
+            CType::Option(inner) => self.needs_free(&inner),
+            CType::Tuple(items) => items.iter().any(|item| self.needs_free(item)),
+            CType::Struct(fields, _) => fields.iter().any(|field| self.needs_free(&field.ty)),
+            CType::Enum(ty, params) => ty.variants.iter().any(|variant| match &variant.ty {
+                Type::Struct(variant_ty) => variant_ty
+                    .fields
+                    .iter()
+                    .any(|field| self.needs_free(&substitute(&field.ty, &params))),
+                Type::Tuple(items) => items
+                    .iter()
+                    .any(|item| self.needs_free(&substitute(item, &params))),
+                _ => false,
+            }),
+        }
+    }
+
+    fn visit(&mut self, ident: &TypeIdent) {
+        let name = self.mangle(ident);
+        if !self.visited.insert(name.clone()) {
+            return;
+        }
+
+        match self.resolve(ident) {
+            CType::Primitive(_) | CType::String | CType::Bytes | CType::Value | CType::Unit => {}
+            CType::Alias(target) => {
+                self.visit(&target);
+                let doc_lines = match self.types.get(ident) {
+                    Some(Type::Struct(ty)) => ty.doc_lines.as_slice(),
+                    _ => &[],
+                };
+                self.alias_definitions.push(format!(
+                    "{}typedef {};",
+                    format_docs(doc_lines, ""),
+                    format_declaration(&self.c_type(&target), &name)
+                ));
+            }
+            CType::Array(primitive, len) => self.generate_array(&name, primitive, len),
+            // Types that only hold pointers to their items are generated before
+            // the items themselves, so that recursive types can refer to them.
+            CType::Pointer(inner) => {
+                self.generate_pointer(&name, &inner);
+                self.visit(&inner);
+            }
+            CType::Option(inner) => {
+                self.visit(&inner);
+                self.generate_option(&name, &inner);
+            }
+            CType::List(inner) => {
+                self.generate_list(&name, &inner);
+                self.visit(&inner);
+            }
+            CType::Map(key, value) => {
+                self.generate_map(&name, &key, &value);
+                self.visit(&key);
+                self.visit(&value);
+            }
+            CType::Tuple(items) => {
+                let doc_lines = match self.types.get(ident) {
+                    Some(Type::Struct(ty)) => ty.doc_lines.clone(),
+                    _ => Vec::new(),
+                };
+                self.generate_tuple(&name, &doc_lines, &items);
+            }
+            CType::Struct(fields, casing) => {
+                let doc_lines = match self.types.get(ident) {
+                    Some(Type::Struct(ty)) => ty.doc_lines.clone(),
+                    _ => Vec::new(),
+                };
+                self.generate_struct(&name, &doc_lines, &fields, casing);
+            }
+            CType::Enum(ty, params) => self.generate_enum(&name, ty, &params),
+        }
+    }
+
+    fn push_struct(&mut self, name: &str, doc_lines: &[String], members: &[String]) {
+        let members = if members.is_empty() {
+            "    uint8_t _unused;\n".to_owned()
+        } else {
+            join_lines(members, "    ")
+        };
+        self.forward_declarations
+            .push(format!("typedef struct {name} {name};"));
+        self.struct_definitions.push(format!(
+            "{}struct {name} {{\n{members}}};",
+            format_docs(doc_lines, "")
+        ));
+    }
+
+    fn push_prototypes(&mut self, name: &str, c_type: &str, needs_free: bool) {
+        let mut prototypes = vec![
+            format!(
+                "void fp_encode_{name}(FpWriter *writer, {});",
+                format_param(c_type, "value", true)
+            ),
+            format!(
+                "bool fp_decode_{name}(FpReader *reader, {});",
+                format_param(c_type, "value", false)
+            ),
+        ];
+        if needs_free {
+            self.freeable_types.insert(name.to_owned());
+            prototypes.push(format!(
+                "void fp_free_{name}({});",
+                format_param(c_type, "value", false)
+            ));
+        }
+        self.prototypes.push(prototypes.join("\n"));
+    }
+
+    fn push_implementations(
+        &mut self,
+        name: &str,
+        c_type: &str,
+        encode: &[String],
+        decode: &[String],
+        free: &[String],
+    ) {
+        let mut implementations = vec![
+            format!(
+                "void fp_encode_{name}(FpWriter *writer, {}) {{\n{}}}",
+                format_param(c_type, "value", true),
+                join_lines(encode, "    ")
+            ),
+            format!(
+                "bool fp_decode_{name}(FpReader *reader, {}) {{\n{}}}",
+                format_param(c_type, "value", false),
+                join_lines(decode, "    ")
+            ),
+        ];
+        if !free.is_empty() {
+            implementations.push(format!(
+                "void fp_free_{name}({}) {{\n{}}}",
+                format_param(c_type, "value", false),
+                join_lines(free, "    ")
+            ));
+        }
+        self.implementations.push(implementations.join("\n\n"));
+    }
+
+    fn generate_array(&mut self, name: &str, primitive: Primitive, len: usize) {
+        let prim = primitive.name();
+        self.push_struct(
+            name,
+            &[],
+            &[format!("{} items[{len}];", format_primitive(primitive))],
+        );
+        self.push_prototypes(name, name, false);
+
+        let encode = vec![
+            format!("fp_write_array(writer, {len});"),
+            format!(
+                "for (uint32_t i = 0; i < {len}; i++) {{\n    fp_encode_{prim}(writer, &value->items[i]);\n}}"
+            ),
+        ];
+        let mut decode = vec!["uint32_t len;".to_owned()];
+        if primitive == Primitive::U8 {
+            decode.push("const uint8_t *data;".to_owned());
+            decode.push(format!(
+                "if (fp_read_bin(reader, &data, &len)) {{\n    \
+                if (len != {len}) {{\n        return false;\n    }}\n    \
+                memcpy(value->items, data, len);\n    \
+                return true;\n\
+                }}"
+            ));
+        }
+        decode.push(format!(
+            "if (!fp_read_array(reader, &len) || len != {len}) {{\n    return false;\n}}"
+        ));
+        decode.push(format!(
+            "for (uint32_t i = 0; i < {len}; i++) {{\n    \
+            if (!fp_decode_{prim}(reader, &value->items[i])) {{\n        return false;\n    }}\n\
+            }}"
+        ));
+        decode.push("return true;".to_owned());
+        self.push_implementations(name, name, &encode, &decode, &[]);
+    }
+
+    fn generate_pointer(&mut self, name: &str, inner: &TypeIdent) {
+        let c_type = self.c_type(inner);
+        let pointer_type = format_pointer(&c_type);
+        let inner_name = self.fn_name(inner);
+        self.push_prototypes(name, &pointer_type, true);
+
+        let encode = vec![format!("fp_encode_{inner_name}(writer, *value);")];
+        let decode = vec![
+            format!("*value = fp_alloc(1, sizeof({c_type}));"),
+            format!(
+                "if (!fp_decode_{inner_name}(reader, *value)) {{\n    \
+                free(*value);\n    \
+                *value = NULL;\n    \
+                return false;\n\
+                }}"
+            ),
+            "return true;".to_owned(),
+        ];
+        let mut free_body = Vec::new();
+        if self.needs_free(inner) {
+            free_body.push(format!("    fp_free_{inner_name}(*value);"));
+        }
+        free_body.push("    free(*value);".to_owned());
+        free_body.push("    *value = NULL;".to_owned());
+        let free = vec![format!(
+            "if (*value != NULL) {{\n{}\n}}",
+            free_body.join("\n")
+        )];
+        self.push_implementations(name, &pointer_type, &encode, &decode, &free);
+    }
+
+    fn generate_option(&mut self, name: &str, inner: &TypeIdent) {
+        let inner_name = self.fn_name(inner);
+        let needs_free = self.needs_free(inner);
+        self.push_struct(
+            name,
+            &[],
+            &[
+                "bool is_some;".to_owned(),
+                format!("{};", format_declaration(&self.c_type(inner), "value")),
+            ],
+        );
+        self.push_prototypes(name, name, needs_free);
+
+        let encode = vec![format!(
+            "if (value->is_some) {{\n    \
+            fp_encode_{inner_name}(writer, &value->value);\n\
+            }} else {{\n    \
+            fp_write_nil(writer);\n\
+            }}"
+        )];
+        let decode = vec![
+            "memset(value, 0, sizeof(*value));".to_owned(),
+            "if (fp_read_nil(reader)) {\n    return true;\n}".to_owned(),
+            format!("value->is_some = fp_decode_{inner_name}(reader, &value->value);"),
+            "return value->is_some;".to_owned(),
+        ];
+        let free = if needs_free {
+            vec![format!(
+                "if (value->is_some) {{\n    \
+                fp_free_{inner_name}(&value->value);\n    \
+                value->is_some = false;\n\
+                }}"
+            )]
+        } else {
+            Vec::new()
+        };
+        self.push_implementations(name, name, &encode, &decode, &free);
+    }
+
+    fn generate_list(&mut self, name: &str, inner: &TypeIdent) {
+        let c_type = self.c_type(inner);
+        let inner_name = self.fn_name(inner);
+        self.push_struct(
+            name,
+            &[],
+            &[
+                format!("{};", format_declaration(&format_pointer(&c_type), "items")),
+                "uint32_t len;".to_owned(),
+            ],
+        );
+        self.push_prototypes(name, name, true);
+
+        let encode = vec![
+            "fp_write_array(writer, value->len);".to_owned(),
+            format!(
+                "for (uint32_t i = 0; i < value->len; i++) {{\n    \
+                fp_encode_{inner_name}(writer, &value->items[i]);\n\
+                }}"
+            ),
+        ];
+        let decode = vec![
+            "uint32_t len;".to_owned(),
+            "memset(value, 0, sizeof(*value));".to_owned(),
+            "if (!fp_read_array(reader, &len)) {\n    return false;\n}".to_owned(),
+            format!("value->items = fp_alloc(len, sizeof({c_type}));"),
+            format!(
+                "for (; value->len < len; value->len++) {{\n    \
+                if (!fp_decode_{inner_name}(reader, &value->items[value->len])) {{\n        \
+                fp_free_{name}(value);\n        \
+                return false;\n    \
+                }}\n\
+                }}"
+            ),
+            "return true;".to_owned(),
+        ];
+        let mut free = Vec::new();
+        if self.needs_free(inner) {
+            free.push(format!(
+                "for (uint32_t i = 0; i < value->len; i++) {{\n    \
+                fp_free_{inner_name}(&value->items[i]);\n\
+                }}"
+            ));
+        }
+        free.push("free(value->items);".to_owned());
+        free.push("memset(value, 0, sizeof(*value));".to_owned());
+        self.push_implementations(name, name, &encode, &decode, &free);
+    }
+
+    fn generate_map(&mut self, name: &str, key: &TypeIdent, value: &TypeIdent) {
+        let key_type = self.c_type(key);
+        let value_type = self.c_type(value);
+        let key_name = self.fn_name(key);
+        let value_name = self.fn_name(value);
+        self.push_struct(
+            name,
+            &[],
+            &[
+                format!(
+                    "{};",
+                    format_declaration(&format_pointer(&key_type), "keys")
+                ),
+                format!(
+                    "{};",
+                    format_declaration(&format_pointer(&value_type), "values")
+                ),
+                "uint32_t len;".to_owned(),
+            ],
+        );
+        self.push_prototypes(name, name, true);
+
+        let encode = vec![
+            "fp_write_map(writer, value->len);".to_owned(),
+            format!(
+                "for (uint32_t i = 0; i < value->len; i++) {{\n    \
+                fp_encode_{key_name}(writer, &value->keys[i]);\n    \
+                fp_encode_{value_name}(writer, &value->values[i]);\n\
+                }}"
+            ),
+        ];
+        let free_key = if self.needs_free(key) {
+            format!("        fp_free_{key_name}(&value->keys[value->len]);\n")
+        } else {
+            String::new()
+        };
+        let decode = vec![
+            "uint32_t len;".to_owned(),
+            "memset(value, 0, sizeof(*value));".to_owned(),
+            "if (!fp_read_map(reader, &len)) {\n    return false;\n}".to_owned(),
+            format!("value->keys = fp_alloc(len, sizeof({key_type}));"),
+            format!("value->values = fp_alloc(len, sizeof({value_type}));"),
+            format!(
+                "for (; value->len < len; value->len++) {{\n    \
+                if (!fp_decode_{key_name}(reader, &value->keys[value->len])) {{\n        \
+                fp_free_{name}(value);\n        \
+                return false;\n    \
+                }}\n    \
+                if (!fp_decode_{value_name}(reader, &value->values[value->len])) {{\n\
+                {free_key}        \
+                fp_free_{name}(value);\n        \
+                return false;\n    \
+                }}\n\
+                }}"
+            ),
+            "return true;".to_owned(),
+        ];
+        let mut free_items = Vec::new();
+        if self.needs_free(key) {
+            free_items.push(format!("    fp_free_{key_name}(&value->keys[i]);"));
+        }
+        if self.needs_free(value) {
+            free_items.push(format!("    fp_free_{value_name}(&value->values[i]);"));
+        }
+        let mut free = Vec::new();
+        if !free_items.is_empty() {
+            free.push(format!(
+                "for (uint32_t i = 0; i < value->len; i++) {{\n{}\n}}",
+                free_items.join("\n")
+            ));
+        }
+        free.push("free(value->keys);".to_owned());
+        free.push("free(value->values);".to_owned());
+        free.push("memset(value, 0, sizeof(*value));".to_owned());
+        self.push_implementations(name, name, &encode, &decode, &free);
+    }
+
+    /// Generates a struct with unnamed fields, which is encoded as an array.
+    fn generate_tuple(&mut self, name: &str, doc_lines: &[String], items: &[TypeIdent]) {
+        for item in items {
+            self.visit(item);
+        }
+
+        let members = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                format!(
+                    "{};",
+                    format_declaration(&self.c_type(item), &format!("f{index}"))
+                )
+            })
+            .collect::<Vec<_>>();
+        let needs_free = items.iter().any(|item| self.needs_free(item));
+        self.push_struct(name, doc_lines, &members);
+        self.push_prototypes(name, name, needs_free);
+
+        let len = items.len();
+        let mut encode = vec![format!("fp_write_array(writer, {len});")];
+        encode.extend(items.iter().enumerate().map(|(index, item)| {
+            format!(
+                "fp_encode_{}(writer, &value->f{index});",
+                self.fn_name(item)
+            )
+        }));
+
+        let decode_items = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                format!("fp_decode_{}(reader, &value->f{index})", self.fn_name(item))
+            })
+            .collect::<Vec<_>>();
+        let mut decode = vec![
+            "uint32_t len;".to_owned(),
+            "memset(value, 0, sizeof(*value));".to_owned(),
+            format!("if (!fp_read_array(reader, &len) || len != {len}) {{\n    return false;\n}}"),
+        ];
+        if decode_items.is_empty() {
+            decode.push("return true;".to_owned());
+        } else if needs_free {
+            decode.push(format!(
+                "if (!{}) {{\n    fp_free_{name}(value);\n    return false;\n}}",
+                decode_items.join(" ||\n    !")
+            ));
+            decode.push("return true;".to_owned());
+        } else {
+            decode.push(format!("return {};", decode_items.join(" &&\n       ")));
+        }
+
+        let mut free = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| self.needs_free(item))
+            .map(|(index, item)| format!("fp_free_{}(&value->f{index});", self.fn_name(item)))
+            .collect::<Vec<_>>();
+        if !free.is_empty() {
+            free.push("memset(value, 0, sizeof(*value));".to_owned());
+        }
+        self.push_implementations(name, name, &encode, &decode, &free);
+    }
+
+    /// Generates a struct with named fields, which is encoded as a map.
+    ///
+    /// Besides the regular encoding functions, static helpers are generated
+    /// for encoding and decoding the individual fields, so that the struct can
+    /// be flattened into other structs and internally tagged enums.
+    fn generate_struct(
+        &mut self,
+        name: &str,
+        doc_lines: &[String],
+        fields: &[Field],
+        casing: Casing,
+    ) {
+        for field in fields {
+            self.visit(&field.ty);
+        }
+
+        let members = fields
+            .iter()
+            .map(|field| {
+                format!(
+                    "{}{};",
+                    format_docs(&field.doc_lines, ""),
+                    format_declaration(&self.c_type(&field.ty), &get_member_name(field))
+                )
+            })
+            .collect::<Vec<_>>();
+        let needs_free = fields.iter().any(|field| self.needs_free(&field.ty));
+        self.push_struct(name, doc_lines, &members);
+        self.push_prototypes(name, name, needs_free);
+
+        let mut count_lines = Vec::new();
+        let mut encode_lines = Vec::new();
+        let mut decode_lines = Vec::new();
+        let mut field_names = Vec::new();
+        let mut static_count = 0;
+        let mut has_flattened_fields = false;
+        for field in fields {
+            let member = get_member_name(field);
+            if let Some(struct_name) = self.get_flattened_struct(field) {
+                has_flattened_fields = true;
+                count_lines.push(format!(
+                    "count += fp_count_fields_{struct_name}(&value->{member});"
+                ));
+                encode_lines.push(format!(
+                    "fp_encode_fields_{struct_name}(writer, &value->{member});"
+                ));
+                decode_lines.push(format!(
+                    "result = fp_decode_field_{struct_name}(reader, &value->{member}, key, key_len);\n\
+                    if (result != 0) {{\n    return result;\n}}"
+                ));
+                continue;
+            }
+
+            let field_name = get_field_name(field, casing);
+            let fn_name = self.fn_name(&field.ty);
+            let encode = format!(
+                "fp_write_cstr(writer, \"{field_name}\");\n\
+                fp_encode_{fn_name}(writer, &value->{member});"
+            );
+            match self.format_serialize_condition(field, &member) {
+                Some(condition) => {
+                    count_lines.push(format!("if ({condition}) {{\n    count++;\n}}"));
+                    encode_lines.push(format!(
+                        "if ({condition}) {{\n{}}}",
+                        indent_lines(&encode, "    ") + "\n"
+                    ));
+                }
+                None => {
+                    static_count += 1;
+                    encode_lines.push(encode);
+                }
+            }
+            decode_lines.push(format!(
+                "if (fp_key_eq(key, key_len, \"{field_name}\")) {{\n    \
+                    return fp_decode_{fn_name}(reader, &value->{member}) ? 1 : -1;\n\
+                    }}"
+            ));
+            field_names.push(field_name);
+        }
+
+        let field_list = if has_flattened_fields || field_names.is_empty() {
+            None
+        } else {
+            Some(field_names.len())
+        };
+        self.field_lists.insert(name.to_owned(), field_list);
+
+        let mut helpers = Vec::new();
+
+        let count_body = if count_lines.is_empty() {
+            vec!["(void)value;".to_owned(), format!("return {static_count};")]
+        } else {
+            let mut body = vec![format!("uint32_t count = {static_count};")];
+            body.extend(count_lines);
+            body.push("return count;".to_owned());
+            body
+        };
+        helpers.push(format!(
+            "static uint32_t fp_count_fields_{name}(const {name} *value) {{\n{}}}",
+            join_lines(&count_body, "    ")
+        ));
+
+        let encode_body = if encode_lines.is_empty() {
+            vec!["(void)writer;".to_owned(), "(void)value;".to_owned()]
+        } else {
+            encode_lines
+        };
+        helpers.push(format!(
+            "static void fp_encode_fields_{name}(FpWriter *writer, const {name} *value) {{\n{}}}",
+            join_lines(&encode_body, "    ")
+        ));
+
+        let decode_body = if decode_lines.is_empty() {
+            vec![
+                "(void)reader;".to_owned(),
+                "(void)object;".to_owned(),
+                "(void)key;".to_owned(),
+                "(void)key_len;".to_owned(),
+                "return 0;".to_owned(),
+            ]
+        } else {
+            let mut body = vec![format!("{name} *value = object;")];
+            if has_flattened_fields {
+                body.push("int result;".to_owned());
+            }
+            body.extend(decode_lines);
+            body.push("return 0;".to_owned());
+            body
+        };
+        helpers.push(format!(
+            "static int fp_decode_field_{name}(FpReader *reader, void *object, const char *key, uint32_t key_len) {{\n{}}}",
+            join_lines(&decode_body, "    ")
+        ));
+
+        if field_list.is_some() {
+            let names = field_names
+                .iter()
+                .map(|name| format!("\"{name}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            helpers.push(format!(
+                "static const char *const fp_fields_{name}[] = {{{names}}};"
+            ));
+        }
+        self.implementations.push(helpers.join("\n\n"));
+
+        let encode = vec![
+            format!("fp_write_map(writer, fp_count_fields_{name}(value));"),
+            format!("fp_encode_fields_{name}(writer, value);"),
+        ];
+        let decode_call = format!(
+            "fp_decode_struct(reader, value, fp_decode_field_{name}, {})",
+            self.format_field_list(name)
+        );
+        let decode = if needs_free {
+            vec![
+                "memset(value, 0, sizeof(*value));".to_owned(),
+                format!(
+                    "if (!{decode_call}) {{\n    fp_free_{name}(value);\n    return false;\n}}"
+                ),
+                "return true;".to_owned(),
+            ]
+        } else {
+            vec![
+                "memset(value, 0, sizeof(*value));".to_owned(),
+                format!("return {decode_call};"),
+            ]
+        };
+        let mut free = fields
+            .iter()
+            .filter(|field| self.needs_free(&field.ty))
+            .map(|field| {
+                format!(
+                    "fp_free_{}(&value->{});",
+                    self.fn_name(&field.ty),
+                    get_member_name(field)
+                )
+            })
+            .collect::<Vec<_>>();
+        if !free.is_empty() {
+            free.push("memset(value, 0, sizeof(*value));".to_owned());
+        }
+        self.push_implementations(name, name, &encode, &decode, &free);
+    }
+
+    /// Returns the name of the struct whose fields should be flattened into
+    /// the parent struct, if any. Only structs with named fields can be
+    /// flattened; other flattened types are treated as regular fields.
+    fn get_flattened_struct(&self, field: &Field) -> Option<String> {
+        if !field.attrs.flatten {
+            return None;
+        }
+        match self.resolve_alias(&field.ty) {
+            CType::Struct(_, _) => Some(self.fn_name(&field.ty)),
+            _ => None,
+        }
+    }
+
+    /// Returns the condition under which the field should be serialized, if
+    /// it has a `skip_serializing_if` attribute we can translate.
+    fn format_serialize_condition(&self, field: &Field, member: &str) -> Option<String> {
+        let path = field.attrs.skip_serializing_if.as_deref()?;
+        match self.resolve_alias(&field.ty) {
+            CType::Option(_) if path.ends_with("is_none") => {
+                Some(format!("value->{member}.is_some"))
+            }
+            CType::String | CType::Bytes | CType::List(_) | CType::Map(_, _)
+                if path.ends_with("is_empty") =>
+            {
+                Some(format!("value->{member}.len > 0"))
+            }
+            _ => None,
+        }
+    }
+
+    fn format_field_list(&self, struct_name: &str) -> String {
+        match self.field_lists.get(struct_name) {
+            Some(Some(count)) => format!("fp_fields_{struct_name}, {count}"),
+            _ => "NULL, 0".to_owned(),
+        }
+    }
+
+    fn generate_enum(&mut self, name: &str, ty: &Enum, params: &TypeParams) {
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let payload = match &variant.ty {
+                    Type::Struct(variant_ty) => {
+                        let aux_name = format!("{name}{}", get_variable_name(&variant.name));
+                        let fields = variant_ty
+                            .fields
+                            .iter()
+                            .map(|field| Field {
+                                ty: substitute(&field.ty, params),
+                                ..field.clone()
+                            })
+                            .collect::<Vec<_>>();
+                        if self.visited.insert(aux_name.clone()) {
+                            self.generate_struct(
+                                &aux_name,
+                                &[],
+                                &fields,
+                                variant.attrs.field_casing,
+                            );
+                        }
+                        Payload::Struct(aux_name)
+                    }
+                    Type::Tuple(items) if items.len() == 1 => {
+                        let item = substitute(&items[0], params);
+                        self.visit(&item);
+                        Payload::Newtype(item)
+                    }
+                    Type::Tuple(items) => {
+                        let aux_name = format!("{name}{}", get_variable_name(&variant.name));
+                        let items = items
+                            .iter()
+                            .map(|item| substitute(item, params))
+                            .collect::<Vec<_>>();
+                        if self.visited.insert(aux_name.clone()) {
+                            self.generate_tuple(&aux_name, &[], &items);
+                        }
+                        Payload::Tuple(aux_name)
+                    }
+                    _ => Payload::Unit,
+                };
+                (variant, payload)
+            })
+            .collect::<Vec<_>>();
+
+        let is_c_enum = variants
+            .iter()
+            .all(|(_, payload)| matches!(payload, Payload::Unit));
+        let tag_type = if is_c_enum {
+            name.to_owned()
+        } else {
+            format!("{name}Tag")
+        };
+        let tag_constants = ty
+            .variants
+            .iter()
+            .map(|variant| format!("{},", get_tag_constant(name, variant)))
+            .collect::<Vec<_>>();
+        let tag_definition = format!(
+            "typedef enum {tag_type} {{\n{}}} {tag_type};",
+            join_lines(&tag_constants, "    ")
+        );
+
+        let needs_free = variants
+            .iter()
+            .any(|(_, payload)| self.payload_needs_free(payload));
+        if is_c_enum {
+            self.enum_definitions.push(format!(
+                "{}{tag_definition}",
+                format_docs(&ty.doc_lines, "")
+            ));
+        } else {
+            let members = variants
+                .iter()
+                .filter_map(|(variant, payload)| {
+                    let member_type = match payload {
+                        Payload::Unit => return None,
+                        Payload::Newtype(item) => self.c_type(item),
+                        Payload::Struct(aux_name) | Payload::Tuple(aux_name) => aux_name.clone(),
+                    };
+                    Some(format!(
+                        "{}{};",
+                        format_docs(&variant.doc_lines, ""),
+                        format_declaration(&member_type, &get_variant_member_name(variant))
+                    ))
+                })
+                .collect::<Vec<_>>();
+            self.struct_definitions.push(tag_definition);
+            self.forward_declarations
+                .push(format!("typedef struct {name} {name};"));
+            self.struct_definitions.push(format!(
+                "{}struct {name} {{\n    {tag_type} tag;\n    union {{\n{}    }};\n}};",
+                format_docs(&ty.doc_lines, ""),
+                join_lines(&members, "        ")
+            ));
+        }
+        self.push_prototypes(name, name, needs_free);
+
+        let tag_value = if is_c_enum { "*value" } else { "value->tag" };
+        let encode_cases = variants
+            .iter()
+            .map(|(variant, payload)| {
+                let constant = get_tag_constant(name, variant);
+                let lines = self.format_variant_encoding(name, variant, payload, &ty.options);
+                format!("case {constant}:\n{}    break;", join_lines(&lines, "    "))
+            })
+            .collect::<Vec<_>>();
+        let encode = vec![format!(
+            "switch ({tag_value}) {{\n{}\n}}",
+            encode_cases.join("\n")
+        )];
+
+        let decode = self.format_enum_decoding(name, &variants, &ty.options, is_c_enum);
+
+        let mut free = Vec::new();
+        if needs_free {
+            let free_cases = variants
+                .iter()
+                .filter(|(_, payload)| self.payload_needs_free(payload))
+                .map(|(variant, payload)| {
+                    format!(
+                        "case {}:\n    fp_free_{}(&value->{});\n    break;",
+                        get_tag_constant(name, variant),
+                        self.payload_fn_name(payload),
+                        get_variant_member_name(variant)
+                    )
+                })
+                .collect::<Vec<_>>();
+            free.push(format!(
+                "switch (value->tag) {{\n{}\ndefault:\n    break;\n}}",
+                free_cases.join("\n")
+            ));
+            free.push("memset(value, 0, sizeof(*value));".to_owned());
+        }
+        self.push_implementations(name, name, &encode, &decode, &free);
+    }
+
+    fn payload_needs_free(&self, payload: &Payload) -> bool {
+        match payload {
+            Payload::Unit => false,
+            Payload::Newtype(item) => self.needs_free(item),
+            Payload::Struct(aux_name) | Payload::Tuple(aux_name) => {
+                self.freeable_types.contains(aux_name)
+            }
+        }
+    }
+
+    fn payload_fn_name(&self, payload: &Payload) -> String {
+        match payload {
+            Payload::Unit => String::new(),
+            Payload::Newtype(item) => self.fn_name(item),
+            Payload::Struct(aux_name) | Payload::Tuple(aux_name) => aux_name.clone(),
+        }
+    }
+
+    /// Returns the name of the struct whose fields should be merged with the
+    /// tag of an internally tagged variant.
+    fn get_tagged_struct(&self, enum_name: &str, variant: &Variant, payload: &Payload) -> String {
+        match payload {
+            Payload::Struct(aux_name) => aux_name.clone(),
+            Payload::Newtype(item) if matches!(self.resolve_alias(item), CType::Struct(_, _)) => {
+                self.fn_name(item)
+            }
+            _ => panic!(
+                "Variant {}::{} cannot be internally tagged, since it does not contain a struct",
+                enum_name, variant.name
+            ),
+        }
+    }
+
+    fn format_variant_encoding(
+        &self,
+        enum_name: &str,
+        variant: &Variant,
+        payload: &Payload,
+        opts: &EnumOptions,
+    ) -> Vec<String> {
+        let variant_name = get_variant_name(variant, opts);
+        let member = get_variant_member_name(variant);
+        let encode_payload = format!(
+            "fp_encode_{}(writer, &value->{member});",
+            self.payload_fn_name(payload)
+        );
+        let is_unit = matches!(payload, Payload::Unit);
+
+        if opts.untagged {
+            if is_unit {
+                vec!["fp_write_nil(writer);".to_owned()]
+            } else {
+                vec![encode_payload]
+            }
+        } else if let Some(tag) = &opts.tag_prop_name {
+            let write_tag = format!(
+                "fp_write_cstr(writer, \"{tag}\");\nfp_write_cstr(writer, \"{variant_name}\");"
+            );
+            if let Some(content) = &opts.content_prop_name {
+                if is_unit {
+                    vec!["fp_write_map(writer, 1);".to_owned(), write_tag]
+                } else {
+                    vec![
+                        "fp_write_map(writer, 2);".to_owned(),
+                        write_tag,
+                        format!("fp_write_cstr(writer, \"{content}\");"),
+                        encode_payload,
+                    ]
+                }
+            } else if is_unit {
+                vec!["fp_write_map(writer, 1);".to_owned(), write_tag]
+            } else {
+                let struct_name = self.get_tagged_struct(enum_name, variant, payload);
+                vec![
+                    format!(
+                        "fp_write_map(writer, 1 + fp_count_fields_{struct_name}(&value->{member}));"
+                    ),
+                    write_tag,
+                    format!("fp_encode_fields_{struct_name}(writer, &value->{member});"),
+                ]
+            }
+        } else if is_unit {
+            vec![format!("fp_write_cstr(writer, \"{variant_name}\");")]
+        } else {
+            vec![
+                "fp_write_map(writer, 1);".to_owned(),
+                format!("fp_write_cstr(writer, \"{variant_name}\");"),
+                encode_payload,
+            ]
+        }
+    }
+
+    fn format_enum_decoding(
+        &self,
+        name: &str,
+        variants: &[(&Variant, Payload)],
+        opts: &EnumOptions,
+        is_c_enum: bool,
+    ) -> Vec<String> {
+        let set_tag = |variant: &Variant| {
+            let constant = get_tag_constant(name, variant);
+            if is_c_enum {
+                format!("*value = {constant};")
+            } else {
+                format!("value->tag = {constant};")
+            }
+        };
+
+        let mut lines = Vec::new();
+        if opts.untagged {
+            lines.push("FpReader attempt;".to_owned());
+            lines.push("memset(value, 0, sizeof(*value));".to_owned());
+            for (variant, payload) in variants {
+                let condition = match payload {
+                    Payload::Unit => "fp_read_nil(&attempt)".to_owned(),
+                    _ => format!(
+                        "fp_decode_{}(&attempt, &value->{})",
+                        self.payload_fn_name(payload),
+                        get_variant_member_name(variant)
+                    ),
+                };
+                lines.push("attempt = fp_attempt(reader);".to_owned());
+                lines.push(format!(
+                    "if ({condition}) {{\n    {}\n    return fp_commit(reader, &attempt);\n}}",
+                    set_tag(variant)
+                ));
+            }
+            lines.push("return false;".to_owned());
+            return lines;
+        }
+
+        lines.push("const char *name;".to_owned());
+        lines.push("uint32_t name_len;".to_owned());
+        let is_internally_tagged = opts.tag_prop_name.is_some() && opts.content_prop_name.is_none();
+        if !is_internally_tagged {
+            lines.push("FpReader content;".to_owned());
+        }
+        lines.push("memset(value, 0, sizeof(*value));".to_owned());
+        let read_name = match (&opts.tag_prop_name, &opts.content_prop_name) {
+            (Some(tag), Some(content)) => format!(
+                "fp_read_adjacent(reader, \"{tag}\", \"{content}\", &name, &name_len, &content)"
+            ),
+            (Some(tag), None) => format!("fp_read_tag(reader, \"{tag}\", &name, &name_len)"),
+            _ => "fp_read_variant(reader, &name, &name_len, &content)".to_owned(),
+        };
+        lines.push(format!("if (!{read_name}) {{\n    return false;\n}}"));
+
+        for (variant, payload) in variants {
+            let variant_name = get_variant_name(variant, opts);
+            let member = get_variant_member_name(variant);
+            let mut body = vec![set_tag(variant)];
+            match (payload, &opts.tag_prop_name) {
+                (Payload::Unit, Some(_)) if is_internally_tagged => {
+                    body.push("return fp_skip(reader);".to_owned())
+                }
+                (Payload::Unit, _) => body.push("return true;".to_owned()),
+                (_, Some(tag)) if is_internally_tagged => {
+                    let struct_name = self.get_tagged_struct(name, variant, payload);
+                    let decode_call = format!(
+                        "fp_decode_tagged_struct(reader, \"{tag}\", &value->{member}, fp_decode_field_{struct_name}, {})",
+                        self.format_field_list(&struct_name)
+                    );
+                    if self.payload_needs_free(payload) {
+                        body.push(format!(
+                            "if (!{decode_call}) {{\n    fp_free_{struct_name}(&value->{member});\n    return false;\n}}"
+                        ));
+                        body.push("return true;".to_owned());
+                    } else {
+                        body.push(format!("return {decode_call};"));
+                    }
+                }
+                _ => body.push(format!(
+                    "return fp_decode_{}(&content, &value->{member});",
+                    self.payload_fn_name(payload)
+                )),
+            }
+            lines.push(format!(
+                "if (fp_key_eq(name, name_len, \"{variant_name}\")) {{\n{}}}",
+                join_lines(&body, "    ")
+            ));
+        }
+        lines.push("return false;".to_owned());
+        lines
+    }
+}
+
+fn get_type_params(definition: &TypeIdent, ident: &TypeIdent) -> TypeParams {
+    definition
+        .generic_args
+        .iter()
+        .zip(ident.generic_args.iter())
+        .map(|((param, _), (arg, _))| (param.name.clone(), arg.clone()))
+        .collect()
+}
+
+/// Replaces generic parameters in the given type with their arguments.
+fn substitute(ident: &TypeIdent, params: &[(String, TypeIdent)]) -> TypeIdent {
+    if ident.generic_args.is_empty() && ident.array.is_none() {
+        if let Some((_, arg)) = params.iter().find(|(name, _)| name == &ident.name) {
+            return arg.clone();
+        }
+    }
+
+    let mut ident = ident.clone();
+    ident.generic_args = ident
+        .generic_args
+        .iter()
+        .map(|(arg, bounds)| (substitute(arg, params), bounds.clone()))
+        .collect();
+    ident
+}
+
+fn format_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::F32 => "float",
+        Primitive::F64 => "double",
+        Primitive::I8 => "int8_t",
+        Primitive::I16 => "int16_t",
+        Primitive::I32 => "int32_t",
+        Primitive::I64 => "int64_t",
+        Primitive::U8 => "uint8_t",
+        Primitive::U16 => "uint16_t",
+        Primitive::U32 => "uint32_t",
+        Primitive::U64 => "uint64_t",
+    }
+}
+
+fn format_pointer(ty: &str) -> String {
+    if ty.ends_with('*') {
+        format!("{ty}*")
+    } else {
+        format!("{ty} *")
+    }
+}
+
+/// Formats the declaration of a variable with the given type.
+fn format_declaration(ty: &str, name: &str) -> String {
+    if ty.ends_with('*') {
+        format!("{ty}{name}")
+    } else {
+        format!("{ty} {name}")
+    }
+}
+
+/// Formats a parameter that points to a value of the given type.
+fn format_param(ty: &str, name: &str, is_const: bool) -> String {
+    match (ty.ends_with('*'), is_const) {
+        (true, true) => format!("{ty}const *{name}"),
+        (true, false) => format!("{ty}*{name}"),
+        (false, true) => format!("const {ty} *{name}"),
+        (false, false) => format!("{ty} *{name}"),
+    }
+}
+
+fn format_docs(doc_lines: &[String], indent: &str) -> String {
+    if doc_lines.is_empty() {
+        return String::new();
+    }
+
+    let lines = doc_lines
+        .iter()
+        .map(|line| {
+            let line = line.trim_end().replace("*/", "* /");
+            if line.is_empty() {
+                format!("{indent} *\n")
+            } else {
+                format!("{indent} *{line}\n")
+            }
+        })
+        .collect::<String>();
+    format!("{indent}/**\n{lines}{indent} */\n")
+}
+
+fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(field.name.as_deref().unwrap_or_default()))
+    }
+}
+
+fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+/// Returns the name of the constant that identifies the given variant.
+fn get_tag_constant(enum_name: &str, variant: &Variant) -> String {
+    format!("{enum_name}_{}", get_variable_name(&variant.name))
+}
+
+/// Returns the name of the struct member for the given field.
+fn get_member_name(field: &Field) -> String {
+    escape_name(get_variable_name(field.name.as_deref().unwrap_or_default()).to_snake_case())
+}
+
+/// Returns the name of the union member that holds the payload of the given
+/// variant.
+fn get_variant_member_name(variant: &Variant) -> String {
+    let name = get_variable_name(&variant.name).to_snake_case();
+    if name == "tag" {
+        "tag_".to_owned()
+    } else {
+        escape_name(name)
+    }
+}
+
+/// Returns the name under which a function is exposed to the plugin. Names of
+/// common C library functions are escaped, so they don't clash when the
+/// plugin includes the corresponding headers.
+fn get_function_name(name: &str) -> String {
+    if C_KEYWORDS.contains(&name) || C_LIBRARY_FUNCTIONS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Returns a name that can be used for an argument in C.
+fn get_arg_name(name: &str) -> String {
+    let name = escape_name(get_variable_name(name).to_snake_case());
+    if RESERVED_NAMES.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn escape_name(name: String) -> String {
+    if C_KEYWORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn indent_lines(lines: &str, indent: &str) -> String {
+    lines
+        .trim_end()
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn join_lines(lines: &[String], indent: &str) -> String {
+    lines
+        .iter()
+        .map(|line| format!("{}\n", indent_lines(line, indent)))
+        .collect()
+}
@@ -0,0 +1,276 @@
+use super::{render_bindings, BindingConfig};
+use crate::{errors::BindgenError, functions::FunctionList, types::TypeMap};
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// Number of unchanged lines to show around every change in a diff.
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// Verifies that the bindings in `config.path` are up-to-date, without writing
+/// anything to disk.
+///
+/// This renders all the files that `generate_bindings()` would write and
+/// compares them with the files that are already there. Returns an error with
+/// a diff for every file that is missing or differs from what would be
+/// generated, wrapped in `BindgenError::OutdatedBindings`. This is useful for
+/// checking in CI that committed bindings were regenerated after the protocol
+/// was changed.
+pub fn check_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: BindingConfig,
+) -> Result<(), BindgenError> {
+    let output_path = Path::new(config.path);
+    let files = render_bindings(
+        import_functions,
+        export_functions,
+        types,
+        config.bindings_type,
+    )?;
+
+    let outdated_files: Vec<_> = files
+        .into_iter()
+        .filter_map(|(file_path, expected)| {
+            let path = output_path.join(file_path);
+            let actual = fs::read_to_string(&path).ok();
+            if actual.as_ref() == Some(&expected) {
+                None
+            } else {
+                let diff = format_diff(&path, actual.as_deref(), &expected);
+                Some(OutdatedFile { path, diff })
+            }
+        })
+        .collect();
+
+    if outdated_files.is_empty() {
+        Ok(())
+    } else {
+        Err(OutdatedBindings {
+            files: outdated_files,
+        }
+        .into())
+    }
+}
+
+/// Returned by `check_bindings()` if the bindings on disk are not up-to-date.
+#[derive(Debug)]
+pub struct OutdatedBindings {
+    pub files: Vec<OutdatedFile>,
+}
+
+impl fmt::Display for OutdatedBindings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Bindings are outdated, {} file(s) would be changed by regenerating them:",
+            self.files.len()
+        )?;
+        for file in &self.files {
+            write!(f, "\n{}", file.diff)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for OutdatedBindings {}
+
+/// A file that is missing or differs from what would be generated.
+#[derive(Debug)]
+pub struct OutdatedFile {
+    /// Path of the file, including the output directory of the bindings.
+    pub path: PathBuf,
+
+    /// Unified diff from the file on disk to the file that would be generated.
+    pub diff: String,
+}
+
+/// Formats a unified diff between the `actual` contents of a file and the
+/// `expected` ones. A missing file is represented by `None`.
+///
+/// Differences in line endings and in the newline at the end of the file don't
+/// show up in the diff itself, so they are described in notes at the end.
+fn format_diff(path: &Path, actual: Option<&str>, expected: &str) -> String {
+    let path = path.display();
+    let mut diff = match actual {
+        Some(_) => format!("--- {path}\n+++ {path}\n"),
+        None => format!("--- /dev/null\n+++ {path}\n"),
+    };
+
+    // Every line of the diff, along with the number of old and new lines that
+    // precede it:
+    let mut lines = Vec::new();
+    let (mut old_count, mut new_count) = (0, 0);
+    let actual_lines: Vec<_> = actual.unwrap_or_default().lines().collect();
+    let expected_lines: Vec<_> = expected.lines().collect();
+    for line in diff::slice(&actual_lines, &expected_lines) {
+        let line = match line {
+            diff::Result::Left(line) => (Some('-'), *line),
+            diff::Result::Both(line, _) => (None, *line),
+            diff::Result::Right(line) => (Some('+'), *line),
+        };
+        lines.push((line, old_count, new_count));
+        match line.0 {
+            Some('-') => old_count += 1,
+            Some('+') => new_count += 1,
+            _ => {
+                old_count += 1;
+                new_count += 1;
+            }
+        }
+    }
+
+    let changes: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, ((marker, _), _, _))| marker.is_some())
+        .map(|(index, _)| index)
+        .collect();
+
+    // Group changes that are close together into hunks:
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for index in changes {
+        let start = index.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (index + DIFF_CONTEXT_LINES + 1).min(lines.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunks {
+        let hunk = &lines[start..end];
+        let old_len = hunk
+            .iter()
+            .filter(|((marker, _), _, _)| *marker != Some('+'));
+        let new_len = hunk
+            .iter()
+            .filter(|((marker, _), _, _)| *marker != Some('-'));
+        let (old_len, new_len) = (old_len.count(), new_len.count());
+        let (_, old_start, new_start) = hunk[0];
+        diff.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            format_range(old_start, old_len),
+            format_range(new_start, new_len)
+        ));
+        for ((marker, line), _, _) in hunk {
+            diff.push(marker.unwrap_or(' '));
+            diff.push_str(line);
+            diff.push('\n');
+        }
+    }
+
+    if let Some(actual) = actual {
+        for note in describe_line_ending_differences(actual, expected) {
+            diff.push_str(&format!("\\ {note}\n"));
+        }
+    }
+
+    diff
+}
+
+/// Describes the differences in line endings between the `actual` contents of
+/// a file and the `expected` ones.
+fn describe_line_ending_differences(actual: &str, expected: &str) -> Vec<String> {
+    let line_ending = |text: &str| if text.contains("\r\n") { "CRLF" } else { "LF" };
+
+    let mut notes = Vec::new();
+    if line_ending(actual) != line_ending(expected) {
+        notes.push(format!(
+            "Line endings differ (file uses {}, generated file uses {})",
+            line_ending(actual),
+            line_ending(expected)
+        ));
+    }
+    match (actual.ends_with('\n'), expected.ends_with('\n')) {
+        (false, true) => notes.push("No newline at end of file".to_owned()),
+        (true, false) => notes.push("No newline at end of generated file".to_owned()),
+        _ => {}
+    }
+    notes
+}
+
+/// Formats a line range for a hunk header. Line numbers are 1-based, except
+/// for empty ranges, which refer to the line before them.
+fn format_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        len => format!("{},{}", start + 1, len),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_diff() {
+        let actual = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\n";
+        let expected = "a\nb\nc\nd\nE\nf\ng\nh\ni\nj\nk\nl\nm\nn\n";
+        assert_eq!(
+            format_diff(Path::new("bindings/types.rs"), Some(actual), expected),
+            "--- bindings/types.rs
++++ bindings/types.rs
+@@ -2,7 +2,7 @@
+ b
+ c
+ d
+-e
++E
+ f
+ g
+ h
+@@ -11,3 +11,4 @@
+ k
+ l
+ m
++n
+"
+        );
+    }
+
+    #[test]
+    fn test_format_diff_for_missing_file() {
+        assert_eq!(
+            format_diff(Path::new("bindings/types.rs"), None, "a\nb\n"),
+            "--- /dev/null
++++ bindings/types.rs
+@@ -0,0 +1,2 @@
++a
++b
+"
+        );
+    }
+
+    #[test]
+    fn test_format_diff_for_line_endings() {
+        assert_eq!(
+            format_diff(Path::new("bindings/types.rs"), Some("a\r\nb\r\n"), "a\nb\n"),
+            "--- bindings/types.rs
++++ bindings/types.rs
+\\ Line endings differ (file uses CRLF, generated file uses LF)
+"
+        );
+    }
+
+    #[test]
+    fn test_format_diff_for_trailing_newline() {
+        assert_eq!(
+            format_diff(Path::new("bindings/types.rs"), Some("a\nb"), "a\nb\n"),
+            "--- bindings/types.rs
++++ bindings/types.rs
+\\ No newline at end of file
+"
+        );
+        assert_eq!(
+            format_diff(Path::new("bindings/types.rs"), Some("a\nb\n"), "a\nb"),
+            "--- bindings/types.rs
++++ bindings/types.rs
+\\ No newline at end of generated file
+"
+        );
+    }
+}
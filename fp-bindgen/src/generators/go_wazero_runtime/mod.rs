@@ -0,0 +1,1533 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    generators::protocol_json::protocol_fingerprint,
+    prelude::Primitive,
+    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    GoWazeroRuntimeConfig,
+};
+use inflector::Inflector;
+use std::{collections::BTreeMap, path::PathBuf};
+
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Names of local variables used by the generated wrappers, which arguments
+/// may not shadow.
+const RESERVED_NAMES: &[&str] = &[
+    "asyncValuePtr",
+    "ctx",
+    "err",
+    "fn",
+    "r",
+    "result",
+    "resultPtr",
+    "results",
+    "stack",
+];
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: GoWazeroRuntimeConfig,
+) -> BTreeMap<PathBuf, String> {
+    BTreeMap::from([
+        (
+            PathBuf::from("runtime.go"),
+            generate_runtime_file(&import_functions, &export_functions, &types, &config),
+        ),
+        (
+            PathBuf::from("types.go"),
+            generate_type_bindings(&types, &config),
+        ),
+    ])
+}
+
+fn generate_runtime_file(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    config: &GoWazeroRuntimeConfig,
+) -> String {
+    let protocol_version = protocol_fingerprint(import_functions, export_functions, types);
+
+    let has_async_import_functions = import_functions.iter().any(|function| function.is_async);
+    let has_async_export_functions = export_functions.iter().any(|function| function.is_async);
+
+    let import_decls = format_import_declarations(import_functions, types);
+    let export_wrappers = format_export_wrappers(export_functions, types);
+
+    let mut import_definitions = vec![
+        "r.defineImport(builder, \"__fp_host_panic\", r.hostPanic, []api.ValueType{api.ValueTypeI64}, nil)"
+            .to_owned(),
+    ];
+    if has_async_export_functions {
+        import_definitions.push(
+            "r.defineImport(builder, \"__fp_host_resolve_async_value\", r.hostResolveAsyncValue, \
+            []api.ValueType{api.ValueTypeI64, api.ValueTypeI64}, nil)"
+                .to_owned(),
+        );
+    }
+    import_definitions.extend(import_functions.iter().map(format_import_definition));
+    let import_definitions = join_lines(&import_definitions, "\t");
+    let import_wrappers = format_import_wrappers(import_functions, types);
+
+    let log_import = if has_async_import_functions {
+        "\t\"log\"\n"
+    } else {
+        ""
+    };
+    let async_helpers = if has_async_import_functions || has_async_export_functions {
+        ASYNC_HELPERS
+    } else {
+        ""
+    };
+    let spawn_helpers = if has_async_import_functions {
+        SPAWN_HELPERS
+    } else {
+        ""
+    };
+    let await_helpers = if has_async_export_functions {
+        AWAIT_HELPERS
+    } else {
+        ""
+    };
+    let package_name = &config.package_name;
+
+    format!(
+        "// ============================================= //
+// WebAssembly runtime for Go                    //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package {package_name}
+
+import (
+\t\"bytes\"
+\t\"context\"
+\t\"errors\"
+\t\"fmt\"
+{log_import}\t\"sync\"
+
+\t\"github.com/tetratelabs/wazero\"
+\t\"github.com/tetratelabs/wazero/api\"
+\t\"github.com/vmihailenco/msgpack/v5\"
+)
+
+// FatPtr points to data in the memory of the plugin. The upper 32 bits contain
+// the address, while the lower 32 bits contain the length of the data.
+type FatPtr = uint64
+
+const (
+\t// Extension bit in the length of a fat pointer, which indicates the length
+\t// is stored in a 4-byte little-endian header, directly followed by the data.
+\tfatPtrLenHeader = 0x0100_0000
+\tfatPtrMaxLen    = 0x00FF_FFFF
+)
+
+// ProtocolVersion is the fingerprint of the protocol these bindings were
+// generated from.
+//
+// Plugins built against a different protocol are rejected by NewRuntime().
+const ProtocolVersion uint64 = {protocol_version:#018x}
+
+// Imports contains the host functions that may be imported by the plugin.
+//
+// Async functions are called on a separate goroutine, so they may block until
+// their result is available.
+type Imports interface {{
+{import_decls}}}
+
+// GuestPanicError is returned when a panic occurred inside the plugin.
+type GuestPanicError struct {{
+\tMessage  string  `msgpack:\"message\"`
+\tLocation *string `msgpack:\"location\"`
+}}
+
+func (e *GuestPanicError) Error() string {{
+\tif e.Location != nil {{
+\t\treturn fmt.Sprintf(\"plugin panicked at %s: %s\", *e.Location, e.Message)
+\t}}
+\treturn \"plugin panicked: \" + e.Message
+}}
+
+// ProtocolMismatchError indicates the plugin was built against a different
+// protocol than the runtime.
+type ProtocolMismatchError struct {{
+\tExpected uint64
+\tFound    uint64
+}}
+
+func (e *ProtocolMismatchError) Error() string {{
+\treturn fmt.Sprintf(
+\t\t\"Plugin was built against a different protocol (expected version %#016x, found %#016x)\",
+\t\te.Expected,
+\t\te.Found,
+\t)
+}}
+
+// FunctionNotExportedError indicates the plugin does not implement the
+// exported function that was called.
+type FunctionNotExportedError struct {{
+\tName string
+}}
+
+func (e *FunctionNotExportedError) Error() string {{
+\treturn fmt.Sprintf(\"Plugin did not export expected function: %q\", e.Name)
+}}
+
+// Unit represents Rust's unit type, which is serialized as nil.
+type Unit struct{{}}
+
+func (Unit) EncodeMsgpack(enc *msgpack.Encoder) error {{
+\treturn enc.EncodeNil()
+}}
+
+func (*Unit) DecodeMsgpack(dec *msgpack.Decoder) error {{
+\treturn dec.Skip()
+}}
+
+// Runtime for executing a plugin.
+//
+// Calls into the plugin are serialized, so a runtime may be shared between
+// goroutines.
+type Runtime struct {{
+\truntime wazero.Runtime
+\tmodule  api.Module
+\timports Imports
+\tmemory  api.Memory
+\tmalloc  api.Function
+\tfree    api.Function
+
+\t// mu guards all calls into the plugin, since a module may not be called
+\t// from multiple goroutines concurrently.
+\tmu sync.Mutex
+
+\tpendingMu sync.Mutex
+\tpending   map[FatPtr]chan FatPtr
+}}
+
+// NewRuntime compiles and instantiates the given plugin, using the given
+// implementation of the host functions it may import.
+func NewRuntime(ctx context.Context, wasmModule []byte, imports Imports) (*Runtime, error) {{
+\treturn NewRuntimeWithConfig(ctx, wasmModule, imports, wazero.NewRuntimeConfig())
+}}
+
+// NewRuntimeWithConfig is like NewRuntime(), but uses the given configuration
+// for the underlying wazero runtime.
+func NewRuntimeWithConfig(
+\tctx context.Context,
+\twasmModule []byte,
+\timports Imports,
+\tconfig wazero.RuntimeConfig,
+) (*Runtime, error) {{
+\tr := &Runtime{{
+\t\truntime: wazero.NewRuntimeWithConfig(ctx, config),
+\t\timports: imports,
+\t\tpending: make(map[FatPtr]chan FatPtr),
+\t}}
+\tif err := r.instantiate(ctx, wasmModule); err != nil {{
+\t\t_ = r.runtime.Close(ctx)
+\t\treturn nil, err
+\t}}
+\treturn r, nil
+}}
+
+// Close releases all resources held by the runtime.
+func (r *Runtime) Close(ctx context.Context) error {{
+\treturn r.runtime.Close(ctx)
+}}
+
+func (r *Runtime) instantiate(ctx context.Context, wasmModule []byte) error {{
+\tbuilder := r.runtime.NewHostModuleBuilder(\"fp\")
+\tr.defineImports(builder)
+\tif _, err := builder.Instantiate(ctx); err != nil {{
+\t\treturn err
+\t}}
+
+\tmodule, err := r.runtime.Instantiate(ctx, wasmModule)
+\tif err != nil {{
+\t\treturn err
+\t}}
+\tr.module = module
+
+\tif r.memory = module.Memory(); r.memory == nil {{
+\t\treturn errors.New(\"Plugin did not export expected symbol: \\\"memory\\\"\")
+\t}}
+\tif r.malloc, err = r.exportedFunction(\"__fp_malloc\"); err != nil {{
+\t\treturn err
+\t}}
+\tif r.free, err = r.exportedFunction(\"__fp_free\"); err != nil {{
+\t\treturn err
+\t}}
+
+\t// Reactor modules, such as plugins written in TinyGo, need to initialize
+\t// themselves before any of their other exports may be called.
+\tif initialize := module.ExportedFunction(\"_initialize\"); initialize != nil {{
+\t\tif _, err := initialize.Call(ctx); err != nil {{
+\t\t\treturn err
+\t\t}}
+\t}}
+
+\t// Plugins built with older bindings don't export their protocol version, so
+\t// they cannot be verified.
+\tprotocolVersion := module.ExportedFunction(\"__fp_protocol_version\")
+\tif protocolVersion == nil {{
+\t\treturn nil
+\t}}
+\tresults, err := protocolVersion.Call(ctx)
+\tif err != nil {{
+\t\treturn err
+\t}}
+\tif results[0] != ProtocolVersion {{
+\t\treturn &ProtocolMismatchError{{Expected: ProtocolVersion, Found: results[0]}}
+\t}}
+\treturn nil
+}}
+{export_wrappers}
+func (r *Runtime) defineImports(builder wazero.HostModuleBuilder) {{
+{import_definitions}}}
+
+func (r *Runtime) defineImport(
+\tbuilder wazero.HostModuleBuilder,
+\tname string,
+\tfn api.GoModuleFunc,
+\tparams, results []api.ValueType,
+) {{
+\tbuilder.NewFunctionBuilder().WithGoModuleFunction(fn, params, results).Export(name)
+}}
+{import_wrappers}
+func (r *Runtime) hostPanic(ctx context.Context, _ api.Module, stack []uint64) {{
+\tvar guestPanic GuestPanicError
+\tcheck(r.parseObject(ctx, stack[0], &guestPanic))
+\tpanic(&guestPanic)
+}}
+{async_helpers}{spawn_helpers}{await_helpers}
+func (r *Runtime) withLock(f func() error) error {{
+\tr.mu.Lock()
+\tdefer r.mu.Unlock()
+\treturn f()
+}}
+
+func (r *Runtime) exportedFunction(name string) (api.Function, error) {{
+\tfn := r.module.ExportedFunction(name)
+\tif fn == nil {{
+\t\treturn nil, fmt.Errorf(\"Plugin did not export expected symbol: %q\", name)
+\t}}
+\treturn fn, nil
+}}
+
+func (r *Runtime) function(name string) (api.Function, error) {{
+\tfn := r.module.ExportedFunction(\"__fp_gen_\" + name)
+\tif fn == nil {{
+\t\treturn nil, &FunctionNotExportedError{{Name: name}}
+\t}}
+\treturn fn, nil
+}}
+
+func (r *Runtime) parseObject(ctx context.Context, fatPtr FatPtr, v interface{{}}) error {{
+\tdata, err := r.importFromMemory(ctx, fatPtr)
+\tif err != nil || v == nil || len(data) == 0 {{
+\t\treturn err
+\t}}
+\treturn msgpack.Unmarshal(data, v)
+}}
+
+// parseByteArray parses a serialized array of bytes into the given slice.
+func (r *Runtime) parseByteArray(ctx context.Context, fatPtr FatPtr, array []byte) error {{
+\tvar list []uint16
+\tif err := r.parseObject(ctx, fatPtr, &list); err != nil {{
+\t\treturn err
+\t}}
+\tfor i := range array {{
+\t\tif i < len(list) {{
+\t\t\tarray[i] = uint8(list[i])
+\t\t}}
+\t}}
+\treturn nil
+}}
+
+func (r *Runtime) serializeObject(ctx context.Context, v interface{{}}) (FatPtr, error) {{
+\tdata, err := msgpack.Marshal(v)
+\tif err != nil {{
+\t\treturn 0, err
+\t}}
+\treturn r.exportToMemory(ctx, data)
+}}
+
+func (r *Runtime) mustSerializeObject(ctx context.Context, v interface{{}}) FatPtr {{
+\tfatPtr, err := r.serializeObject(ctx, v)
+\tcheck(err)
+\treturn fatPtr
+}}
+
+func (r *Runtime) exportToMemory(ctx context.Context, data []byte) (FatPtr, error) {{
+\tresults, err := r.malloc.Call(ctx, uint64(len(data)))
+\tif err != nil {{
+\t\treturn 0, err
+\t}}
+\tfatPtr := results[0]
+\tptr, _, err := r.resolveFatPtr(fatPtr)
+\tif err != nil {{
+\t\treturn 0, err
+\t}}
+\tif !r.memory.Write(ptr, data) {{
+\t\treturn 0, fmt.Errorf(\"invalid fat pointer: %#x\", fatPtr)
+\t}}
+\treturn fatPtr, nil
+}}
+
+func (r *Runtime) importFromMemory(ctx context.Context, fatPtr FatPtr) ([]byte, error) {{
+\tif fatPtr == 0 {{
+\t\t// This may happen with async calls that don't return a result:
+\t\treturn nil, nil
+\t}}
+
+\tptr, length, err := r.resolveFatPtr(fatPtr)
+\tif err != nil {{
+\t\treturn nil, err
+\t}}
+\tdata, ok := r.memory.Read(ptr, length)
+\tif !ok {{
+\t\treturn nil, fmt.Errorf(\"invalid fat pointer: %#x\", fatPtr)
+\t}}
+
+\t// Copy the data, because the memory it refers to is freed below:
+\tdata = append([]byte(nil), data...)
+\tif _, err := r.free.Call(ctx, fatPtr); err != nil {{
+\t\treturn nil, err
+\t}}
+\treturn data, nil
+}}
+
+func (r *Runtime) resolveFatPtr(fatPtr FatPtr) (uint32, uint32, error) {{
+\tptr, length := fromFatPtr(fatPtr)
+\tif length == fatPtrLenHeader {{
+\t\theader, ok := r.memory.ReadUint32Le(ptr)
+\t\tif !ok {{
+\t\t\treturn 0, 0, fmt.Errorf(\"invalid fat pointer: %#x\", fatPtr)
+\t\t}}
+\t\treturn ptr + 4, header, nil
+\t}}
+\tif length > fatPtrMaxLen {{
+\t\treturn 0, 0, fmt.Errorf(\"unknown extension bits in fat pointer: %#x\", fatPtr)
+\t}}
+\treturn ptr, length, nil
+}}
+
+func fromFatPtr(fatPtr FatPtr) (uint32, uint32) {{
+\treturn uint32(fatPtr >> 32), uint32(fatPtr)
+}}
+
+// byteArrayToList converts a byte array to a slice that is serialized as a
+// MessagePack array, because Rust arrays cannot be deserialized from the
+// binary data byte slices are serialized as.
+func byteArrayToList(array []byte) []uint16 {{
+\tlist := make([]uint16, len(array))
+\tfor i, b := range array {{
+\t\tlist[i] = uint16(b)
+\t}}
+\treturn list
+}}
+
+func encodeBool(value bool) uint64 {{
+\tif value {{
+\t\treturn 1
+\t}}
+\treturn 0
+}}
+
+// check is used by the import wrappers, which cannot return errors. Wazero
+// recovers the panic and returns the error from the call into the plugin.
+func check(err error) {{
+\tif err != nil {{
+\t\tpanic(err)
+\t}}
+}}
+
+// enumRepr describes how the variants of an enum are represented, following
+// the enum representations supported by Serde.
+type enumRepr struct {{
+\ttag      string
+\tcontent  string
+\tuntagged bool
+}}
+
+func encodeVariant(enc *msgpack.Encoder, repr enumRepr, name string, value interface{{}}) error {{
+\tswitch {{
+\tcase repr.untagged:
+\t\treturn enc.Encode(value)
+\tcase repr.tag == \"\":
+\t\tif value == nil {{
+\t\t\treturn enc.EncodeString(name)
+\t\t}}
+\t\treturn enc.Encode(map[string]interface{{}}{{name: value}})
+\tcase repr.content != \"\":
+\t\tif value == nil {{
+\t\t\treturn enc.Encode(map[string]interface{{}}{{repr.tag: name}})
+\t\t}}
+\t\treturn enc.Encode(map[string]interface{{}}{{repr.tag: name, repr.content: value}})
+\tdefault:
+\t\tfields := map[string]interface{{}}{{}}
+\t\tif value != nil {{
+\t\t\tdata, err := msgpack.Marshal(value)
+\t\t\tif err != nil {{
+\t\t\t\treturn err
+\t\t\t}}
+\t\t\tif err := msgpack.Unmarshal(data, &fields); err != nil {{
+\t\t\t\treturn err
+\t\t\t}}
+\t\t}}
+\t\tfields[repr.tag] = name
+\t\treturn enc.Encode(fields)
+\t}}
+}}
+
+// decodeVariant decodes the name of a variant of a tagged enum, along with its
+// serialized content.
+func decodeVariant(dec *msgpack.Decoder, repr enumRepr) (string, msgpack.RawMessage, error) {{
+\traw, err := dec.DecodeRaw()
+\tif err != nil {{
+\t\treturn \"\", nil, err
+\t}}
+
+\tif repr.tag == \"\" {{
+\t\tvar name string
+\t\tif err := msgpack.Unmarshal(raw, &name); err == nil {{
+\t\t\treturn name, nil, nil
+\t\t}}
+\t\tvar variants map[string]msgpack.RawMessage
+\t\tif err := msgpack.Unmarshal(raw, &variants); err != nil {{
+\t\t\treturn \"\", nil, err
+\t\t}}
+\t\tif len(variants) == 1 {{
+\t\t\tfor name, content := range variants {{
+\t\t\t\treturn name, content, nil
+\t\t\t}}
+\t\t}}
+\t\treturn \"\", nil, fmt.Errorf(\"expected a single variant, found %d\", len(variants))
+\t}}
+
+\tvar fields map[string]msgpack.RawMessage
+\tif err := msgpack.Unmarshal(raw, &fields); err != nil {{
+\t\treturn \"\", nil, err
+\t}}
+\tvar name string
+\tif err := msgpack.Unmarshal(fields[repr.tag], &name); err != nil {{
+\t\treturn \"\", nil, fmt.Errorf(\"invalid tag %q: %w\", repr.tag, err)
+\t}}
+\tif repr.content != \"\" {{
+\t\treturn name, fields[repr.content], nil
+\t}}
+\treturn name, raw, nil
+}}
+
+// decodeStrict decodes the given data, while rejecting unknown fields. This is
+// used for trying the variants of untagged enums.
+func decodeStrict(raw msgpack.RawMessage, v interface{{}}) error {{
+\tif isNil(raw) {{
+\t\treturn errors.New(\"unexpected nil value\")
+\t}}
+\tdec := msgpack.NewDecoder(bytes.NewReader(raw))
+\tdec.DisallowUnknownFields(true)
+\treturn dec.Decode(v)
+}}
+
+func isNil(raw msgpack.RawMessage) bool {{
+\treturn len(raw) == 1 && raw[0] == 0xc0
+}}
+
+func noVariantSet(enum string) error {{
+\treturn fmt.Errorf(\"no variant set for enum %s\", enum)
+}}
+
+func unknownVariant(enum, name string) error {{
+\treturn fmt.Errorf(\"unknown variant %q for enum %s\", name, enum)
+}}
+
+func noMatchingVariant(enum string) error {{
+\treturn fmt.Errorf(\"data did not match any variant of untagged enum %s\", enum)
+}}
+"
+    )
+}
+
+const ASYNC_HELPERS: &str = "
+func (r *Runtime) createAsyncValue(ctx context.Context) (FatPtr, error) {
+\tconst length = 12 // std::mem::size_of::<AsyncValue>()
+\tresults, err := r.malloc.Call(ctx, length)
+\tif err != nil {
+\t\treturn 0, err
+\t}
+\tfatPtr := results[0]
+\tptr, _ := fromFatPtr(fatPtr)
+\tif !r.memory.Write(ptr, make([]byte, length)) {
+\t\treturn 0, fmt.Errorf(\"invalid fat pointer: %#x\", fatPtr)
+\t}
+\treturn fatPtr, nil
+}
+";
+
+const SPAWN_HELPERS: &str = "
+func (r *Runtime) mustCreateAsyncValue(ctx context.Context) FatPtr {
+\tfatPtr, err := r.createAsyncValue(ctx)
+\tcheck(err)
+\treturn fatPtr
+}
+
+// resolveAsyncImport calls an async host function and passes its result to the
+// plugin. It is run on its own goroutine.
+func (r *Runtime) resolveAsyncImport(
+\tctx context.Context,
+\tname string,
+\tasyncValuePtr FatPtr,
+\tcall func() interface{},
+) {
+\tresult := call()
+\terr := r.withLock(func() error {
+\t\tresolveFuture, err := r.exportedFunction(\"__fp_guest_resolve_async_value\")
+\t\tif err != nil {
+\t\t\treturn err
+\t\t}
+\t\tresultPtr, err := r.serializeObject(ctx, result)
+\t\tif err != nil {
+\t\t\treturn err
+\t\t}
+\t\t_, err = resolveFuture.Call(ctx, asyncValuePtr, resultPtr)
+\t\treturn err
+\t})
+\tif err != nil {
+\t\tlog.Printf(\"Unrecoverable error trying to call async host function %q: %v\", name, err)
+\t}
+}
+";
+
+const AWAIT_HELPERS: &str = "
+func (r *Runtime) awaitAsyncValue(ctx context.Context, asyncValuePtr FatPtr) (FatPtr, error) {
+\tselect {
+\tcase resultPtr := <-r.asyncValueChannel(asyncValuePtr):
+\t\tr.pendingMu.Lock()
+\t\tdelete(r.pending, asyncValuePtr)
+\t\tr.pendingMu.Unlock()
+\t\treturn resultPtr, nil
+\tcase <-ctx.Done():
+\t\treturn 0, ctx.Err()
+\t}
+}
+
+func (r *Runtime) hostResolveAsyncValue(_ context.Context, _ api.Module, stack []uint64) {
+\tr.asyncValueChannel(stack[0]) <- stack[1]
+}
+
+// asyncValueChannel returns the channel through which the result of the given
+// async value is passed, regardless of whether the plugin resolves it before
+// or after we start waiting for it.
+func (r *Runtime) asyncValueChannel(asyncValuePtr FatPtr) chan FatPtr {
+\tr.pendingMu.Lock()
+\tdefer r.pendingMu.Unlock()
+\tch, ok := r.pending[asyncValuePtr]
+\tif !ok {
+\t\tch = make(chan FatPtr, 1)
+\t\tr.pending[asyncValuePtr] = ch
+\t}
+\treturn ch
+}
+";
+
+fn format_import_declarations(import_functions: &FunctionList, types: &TypeMap) -> String {
+    import_functions
+        .iter()
+        .map(|function| {
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        ", {} {}",
+                        get_arg_name(&arg.name),
+                        format_ident(&arg.ty, types)
+                    )
+                })
+                .collect::<String>();
+            let return_type = match &function.return_type {
+                Some(ty) => format!(" {}", format_ident(ty, types)),
+                None => String::new(),
+            };
+            format!(
+                "{}\t{}(ctx context.Context{}){}\n",
+                format_comments(&function.doc_lines, "\t"),
+                function.name.to_pascal_case(),
+                args,
+                return_type
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> String {
+    export_functions
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        ", {} {}",
+                        get_arg_name(&arg.name),
+                        format_ident(&arg.ty, types)
+                    )
+                })
+                .collect::<String>();
+            let return_type = match &function.return_type {
+                Some(ty) => format!("(result {}, err error)", format_ident(ty, types)),
+                None => "(err error)".to_owned(),
+            };
+
+            let mut body = vec![
+                format!("fn, err := r.function(\"{name}\")"),
+                "if err != nil {\n\treturn err\n}".to_owned(),
+            ];
+            for arg in function.args.iter().filter(|arg| !arg.ty.is_primitive()) {
+                let arg_name = get_arg_name(&arg.name);
+                body.push(format!(
+                    "{}, err := r.serializeObject(ctx, {})",
+                    get_pointer_name(&arg.name),
+                    format_serializable_value(&arg.ty, types, &arg_name)
+                ));
+                body.push("if err != nil {\n\treturn err\n}".to_owned());
+            }
+
+            let call_args = function
+                .args
+                .iter()
+                .map(|arg| match arg.ty.as_primitive() {
+                    Some(primitive) => {
+                        format!(
+                            ", {}",
+                            encode_primitive(primitive, &get_arg_name(&arg.name))
+                        )
+                    }
+                    None => format!(", {}", get_pointer_name(&arg.name)),
+                })
+                .collect::<String>();
+            let fn_call = format!("fn.Call(ctx{call_args})");
+
+            let mut wrapper = Vec::new();
+            if function.is_async {
+                body.push(format!("results, err := {fn_call}"));
+                body.push("if err != nil {\n\treturn err\n}".to_owned());
+                body.push("asyncValuePtr = results[0]".to_owned());
+                body.push("return nil".to_owned());
+
+                wrapper.push("var asyncValuePtr FatPtr".to_owned());
+                wrapper.push(format_with_lock("err = ", &body));
+                wrapper.push("if err != nil {\n\treturn\n}".to_owned());
+                wrapper.push("resultPtr, err := r.awaitAsyncValue(ctx, asyncValuePtr)".to_owned());
+                wrapper.push("if err != nil {\n\treturn\n}".to_owned());
+                let parse = match &function.return_type {
+                    Some(ty) => format_parse_call(ty, types, "resultPtr"),
+                    None => "r.parseObject(ctx, resultPtr, nil)".to_owned(),
+                };
+                wrapper.push(format!(
+                    "err = r.withLock(func() error {{\n\treturn {parse}\n}})"
+                ));
+            } else {
+                match &function.return_type {
+                    None => {
+                        body.push(format!("_, err = {fn_call}"));
+                        body.push("return err".to_owned());
+                    }
+                    Some(ty) => {
+                        body.push(format!("results, err := {fn_call}"));
+                        body.push("if err != nil {\n\treturn err\n}".to_owned());
+                        match ty.as_primitive() {
+                            Some(primitive) => {
+                                body.push(format!(
+                                    "result = {}",
+                                    decode_primitive(primitive, "results[0]")
+                                ));
+                                body.push("return nil".to_owned());
+                            }
+                            None => body.push(format!(
+                                "return {}",
+                                format_parse_call(ty, types, "results[0]")
+                            )),
+                        }
+                    }
+                }
+                wrapper.push(format_with_lock("err = ", &body));
+            }
+            wrapper.push("return".to_owned());
+
+            format!(
+                "\n{}func (r *Runtime) {}(ctx context.Context{}) {} {{\n{}}}\n",
+                format_comments(&function.doc_lines, ""),
+                name.to_pascal_case(),
+                args,
+                return_type,
+                join_lines(&wrapper, "\t")
+            )
+        })
+        .collect()
+}
+
+fn format_with_lock(assignment: &str, body: &[String]) -> String {
+    format!(
+        "{}r.withLock(func() error {{\n{}}})",
+        assignment,
+        join_lines(body, "\t")
+    )
+}
+
+/// Returns the call for parsing the object at the given pointer into the
+/// `result` variable.
+fn format_parse_call(ty: &TypeIdent, types: &TypeMap, ptr: &str) -> String {
+    if is_byte_array(ty, types) {
+        format!("r.parseByteArray(ctx, {ptr}, result[:])")
+    } else {
+        format!("r.parseObject(ctx, {ptr}, &result)")
+    }
+}
+
+/// Returns the value that should be serialized for the given variable.
+fn format_serializable_value(ty: &TypeIdent, types: &TypeMap, variable: &str) -> String {
+    if is_byte_array(ty, types) {
+        format!("byteArrayToList({variable}[:])")
+    } else {
+        variable.to_owned()
+    }
+}
+
+fn is_byte_array(ty: &TypeIdent, types: &TypeMap) -> bool {
+    matches!(types.get(ty), Some(Type::Array(Primitive::U8, _)))
+}
+
+fn format_import_definition(function: &Function) -> String {
+    let params = function
+        .args
+        .iter()
+        .map(|arg| match arg.ty.as_primitive() {
+            Some(primitive) => format_wasm_type(primitive),
+            None => "api.ValueTypeI64",
+        })
+        .collect::<Vec<_>>();
+    let results = match &function.return_type {
+        _ if function.is_async => vec!["api.ValueTypeI64"],
+        None => vec![],
+        Some(ty) => match ty.as_primitive() {
+            Some(primitive) => vec![format_wasm_type(primitive)],
+            None => vec!["api.ValueTypeI64"],
+        },
+    };
+    let format_value_types = |value_types: Vec<&str>| {
+        if value_types.is_empty() {
+            "nil".to_owned()
+        } else {
+            format!("[]api.ValueType{{{}}}", value_types.join(", "))
+        }
+    };
+
+    format!(
+        "r.defineImport(\n\tbuilder,\n\t\"__fp_gen_{}\",\n\tr.fpGen{},\n\t{},\n\t{},\n)",
+        function.name,
+        function.name.to_pascal_case(),
+        format_value_types(params),
+        format_value_types(results)
+    )
+}
+
+fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> String {
+    import_functions
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            let mut body = Vec::new();
+            for (index, arg) in function.args.iter().enumerate() {
+                let arg_name = get_arg_name(&arg.name);
+                let value = format!("stack[{index}]");
+                match arg.ty.as_primitive() {
+                    Some(primitive) => body.push(format!(
+                        "{} := {}",
+                        arg_name,
+                        decode_primitive(primitive, &value)
+                    )),
+                    None => {
+                        body.push(format!("var {} {}", arg_name, format_ident(&arg.ty, types)));
+                        if is_byte_array(&arg.ty, types) {
+                            body.push(format!(
+                                "check(r.parseByteArray(ctx, {value}, {arg_name}[:]))"
+                            ));
+                        } else {
+                            body.push(format!(
+                                "check(r.parseObject(ctx, {value}, &{arg_name}))"
+                            ));
+                        }
+                    }
+                }
+            }
+
+            let call_args = function
+                .args
+                .iter()
+                .map(|arg| format!(", {}", get_arg_name(&arg.name)))
+                .collect::<String>();
+            let fn_call = format!(
+                "r.imports.{}(ctx{})",
+                function.name.to_pascal_case(),
+                call_args
+            );
+            if function.is_async {
+                let call = match &function.return_type {
+                    None => format!("{fn_call}\n\treturn nil"),
+                    Some(ty) if is_byte_array(ty, types) => {
+                        format!("result := {fn_call}\n\treturn byteArrayToList(result[:])")
+                    }
+                    Some(_) => format!("return {fn_call}"),
+                };
+                body.push("asyncValuePtr := r.mustCreateAsyncValue(ctx)".to_owned());
+                body.push(format!(
+                    "go r.resolveAsyncImport(ctx, \"{name}\", asyncValuePtr, func() interface{{}} {{\n\t{call}\n}})"
+                ));
+                body.push("stack[0] = asyncValuePtr".to_owned());
+            } else {
+                match &function.return_type {
+                    None => body.push(fn_call),
+                    Some(ty) => {
+                        body.push(format!("result := {fn_call}"));
+                        match ty.as_primitive() {
+                            Some(primitive) => body.push(format!(
+                                "stack[0] = {}",
+                                encode_primitive(primitive, "result")
+                            )),
+                            None => body.push(format!(
+                                "stack[0] = r.mustSerializeObject(ctx, {})",
+                                format_serializable_value(ty, types, "result")
+                            )),
+                        }
+                    }
+                }
+            }
+
+            let stack_name = if function.args.is_empty()
+                && function.return_type.is_none()
+                && !function.is_async
+            {
+                "_"
+            } else {
+                "stack"
+            };
+            format!(
+                "\nfunc (r *Runtime) fpGen{}(ctx context.Context, _ api.Module, {} []uint64) {{\n{}}}\n",
+                name.to_pascal_case(),
+                stack_name,
+                join_lines(&body, "\t")
+            )
+        })
+        .collect()
+}
+
+fn generate_type_bindings(types: &TypeMap, config: &GoWazeroRuntimeConfig) -> String {
+    let mut needs_msgpack = false;
+    let definitions = types
+        .iter()
+        .filter_map(|(ident, ty)| match ty {
+            Type::Alias(name, ident) => {
+                Some(format!("type {} = {}", name, format_ident(ident, types)))
+            }
+            Type::Custom(CustomType {
+                ts_declaration: Some(_),
+                ..
+            }) => None,
+            Type::Enum(ty) => {
+                if is_string_enum(ty) {
+                    Some(create_string_enum_definition(ty))
+                } else {
+                    needs_msgpack = true;
+                    Some(create_enum_definition(ident, ty, types))
+                }
+            }
+            Type::Struct(ty) => Some(create_struct_definition(ident, ty, types)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let imports = if needs_msgpack {
+        "import \"github.com/vmihailenco/msgpack/v5\"\n\n"
+    } else {
+        ""
+    };
+
+    format!(
+        "// ============================================= //
+// Types for WebAssembly runtime                 //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package {}
+
+{}{}\n",
+        config.package_name,
+        imports,
+        definitions.join("\n\n")
+    )
+}
+
+/// Enums with only unit variants that are represented using their name are
+/// generated as string types.
+fn is_string_enum(ty: &Enum) -> bool {
+    ty.ident.generic_args.is_empty()
+        && !ty.options.untagged
+        && ty.options.tag_prop_name.is_none()
+        && ty
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.ty, Type::Unit))
+}
+
+fn create_string_enum_definition(ty: &Enum) -> String {
+    let name = &ty.ident.name;
+    let constants = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "{}\t{}{} {} = \"{}\"\n",
+                format_comments(&variant.doc_lines, "\t"),
+                name,
+                get_variable_name(&variant.name).to_pascal_case(),
+                name,
+                get_variant_name(variant, &ty.options)
+            )
+        })
+        .collect::<String>();
+    format!(
+        "{}type {} string\n\nconst (\n{})",
+        format_comments(&ty.doc_lines, ""),
+        name,
+        constants
+    )
+}
+
+/// Generates a struct with a pointer field for every variant of the enum, of
+/// which only one should be set. The struct implements custom encoding, so
+/// that it is serialized the same way as the Rust enum.
+fn create_enum_definition(ident: &TypeIdent, ty: &Enum, types: &TypeMap) -> String {
+    let name = &ty.ident.name;
+    let type_params = format_type_params(ident);
+    let type_args = format_type_args(ident);
+    let options = &ty.options;
+    let repr = if options.untagged {
+        "enumRepr{untagged: true}".to_owned()
+    } else {
+        match (&options.tag_prop_name, &options.content_prop_name) {
+            (Some(tag), Some(content)) => {
+                format!("enumRepr{{tag: \"{tag}\", content: \"{content}\"}}")
+            }
+            (Some(tag), None) => format!("enumRepr{{tag: \"{tag}\"}}"),
+            (None, _) => "enumRepr{}".to_owned(),
+        }
+    };
+
+    let mut aux_definitions = Vec::new();
+    let mut fields = Vec::new();
+    let mut encode_cases = Vec::new();
+    let mut decode_cases = Vec::new();
+    for variant in &ty.variants {
+        let field_name = get_variable_name(&variant.name).to_pascal_case();
+        let variant_name = get_variant_name(variant, options);
+        let aux_name = format!("{name}{field_name}");
+        let field_type = match &variant.ty {
+            Type::Unit => "struct{}".to_owned(),
+            Type::Struct(variant_ty) => {
+                aux_definitions.push(format_struct(
+                    &format!("{aux_name}{type_params}"),
+                    &[],
+                    &variant_ty.fields,
+                    types,
+                    variant.attrs.field_casing,
+                ));
+                format!("{aux_name}{type_args}")
+            }
+            Type::Tuple(items) if items.len() == 1 => format_ident(&items[0], types),
+            Type::Tuple(items) => {
+                aux_definitions.push(format_tuple_struct(
+                    &format!("{aux_name}{type_params}"),
+                    &[],
+                    items,
+                    types,
+                ));
+                format!("{aux_name}{type_args}")
+            }
+            _ => continue,
+        };
+        fields.push((
+            format_comments(&variant.doc_lines, "\t"),
+            field_name.clone(),
+            format!("*{field_type}"),
+            String::new(),
+        ));
+
+        let is_unit = matches!(variant.ty, Type::Unit);
+        let value = if is_unit {
+            "nil".to_owned()
+        } else {
+            format!("v.{field_name}")
+        };
+        encode_cases.push(format!(
+            "case v.{field_name} != nil:\n\treturn encodeVariant(enc, {repr}, \"{variant_name}\", {value})"
+        ));
+
+        if options.untagged {
+            if is_unit {
+                decode_cases.push(format!(
+                    "if isNil(raw) {{\n\tv.{field_name} = &struct{{}}{{}}\n\treturn nil\n}}"
+                ));
+            } else {
+                decode_cases.push(format!(
+                    "if value := new({field_type}); decodeStrict(raw, value) == nil {{\n\tv.{field_name} = value\n\treturn nil\n}}"
+                ));
+            }
+        } else if is_unit {
+            decode_cases.push(format!(
+                "case \"{variant_name}\":\n\tv.{field_name} = &struct{{}}{{}}\n\treturn nil"
+            ));
+        } else {
+            decode_cases.push(format!(
+                "case \"{variant_name}\":\n\tv.{field_name} = new({field_type})\n\treturn msgpack.Unmarshal(content, v.{field_name})"
+            ));
+        }
+    }
+
+    let decode_body = if options.untagged {
+        format!(
+            "raw, err := dec.DecodeRaw()\nif err != nil {{\n\treturn err\n}}\n*v = {name}{type_args}{{}}\n{}\nreturn noMatchingVariant(\"{name}\")",
+            decode_cases.join("\n")
+        )
+    } else {
+        format!(
+            "name, content, err := decodeVariant(dec, {repr})\nif err != nil {{\n\treturn err\n}}\n*v = {name}{type_args}{{}}\nswitch name {{\n{}\n}}\nreturn unknownVariant(\"{name}\", name)",
+            decode_cases.join("\n")
+        )
+    };
+    // Make sure the `content` variable is used, even if there are only unit
+    // variants:
+    let decode_body = if !options.untagged
+        && ty
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.ty, Type::Unit))
+    {
+        decode_body.replace("name, content, err :=", "name, _, err :=")
+    } else {
+        decode_body
+    };
+
+    let mut definitions = aux_definitions;
+    definitions.push(format!(
+        "{}type {}{} struct {{\n{}}}",
+        format_comments(&ty.doc_lines, ""),
+        name,
+        type_params,
+        format_struct_fields(&fields)
+    ));
+    definitions.push(format!(
+        "func (v {name}{type_args}) EncodeMsgpack(enc *msgpack.Encoder) error {{\n\tswitch {{\n{}\t}}\n\treturn noVariantSet(\"{name}\")\n}}",
+        encode_cases
+            .iter()
+            .map(|case| format!("{}\n", indent_lines(case, "\t")))
+            .collect::<String>()
+    ));
+    definitions.push(format!(
+        "func (v *{name}{type_args}) DecodeMsgpack(dec *msgpack.Decoder) error {{\n{}}}",
+        join_lines(&[decode_body], "\t")
+    ));
+    definitions.join("\n\n")
+}
+
+fn create_struct_definition(ident: &TypeIdent, ty: &Struct, types: &TypeMap) -> String {
+    let name = format!("{}{}", ty.ident.name, format_type_params(ident));
+    let is_tuple_struct =
+        !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none());
+    if is_tuple_struct && ty.fields.len() == 1 {
+        format!(
+            "{}type {} {}",
+            format_comments(&ty.doc_lines, ""),
+            name,
+            format_ident(&ty.fields[0].ty, types)
+        )
+    } else if is_tuple_struct {
+        let items: Vec<_> = ty.fields.iter().map(|field| field.ty.clone()).collect();
+        format_tuple_struct(&name, &ty.doc_lines, &items, types)
+    } else {
+        format_struct(
+            &name,
+            &ty.doc_lines,
+            &ty.fields,
+            types,
+            ty.options.field_casing,
+        )
+    }
+}
+
+fn format_struct(
+    name: &str,
+    doc_lines: &[String],
+    fields: &[Field],
+    types: &TypeMap,
+    casing: Casing,
+) -> String {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let comments = format_comments(&field.doc_lines, "\t");
+            let ty = format_ident(&field.ty, types);
+            if field.attrs.flatten {
+                return match types.get(&field.ty) {
+                    // Embedded structs are inlined:
+                    Some(Type::Struct(_)) => (comments, ty, String::new(), String::new()),
+                    _ => (
+                        comments,
+                        get_field_name(field, Casing::Original).to_pascal_case(),
+                        ty,
+                        "`msgpack:\",inline\"`".to_owned(),
+                    ),
+                };
+            }
+
+            let omit_empty = if field.attrs.skip_serializing_if.is_some() {
+                ",omitempty"
+            } else {
+                ""
+            };
+            (
+                comments,
+                get_variable_name(field.name.as_deref().unwrap_or_default()).to_pascal_case(),
+                ty,
+                format!(
+                    "`msgpack:\"{}{}\"`",
+                    get_field_name(field, casing),
+                    omit_empty
+                ),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{}type {} struct {{\n{}}}",
+        format_comments(doc_lines, ""),
+        name,
+        format_struct_fields(&fields)
+    )
+}
+
+/// Tuples are serialized as arrays.
+fn format_tuple_struct(
+    name: &str,
+    doc_lines: &[String],
+    items: &[TypeIdent],
+    types: &TypeMap,
+) -> String {
+    let mut fields = vec![(
+        String::new(),
+        "_msgpack".to_owned(),
+        "struct{}".to_owned(),
+        "`msgpack:\",as_array\"`".to_owned(),
+    )];
+    fields.extend(items.iter().enumerate().map(|(index, item)| {
+        (
+            String::new(),
+            format!("F{index}"),
+            format_ident(item, types),
+            String::new(),
+        )
+    }));
+    format!(
+        "{}type {} struct {{\n{}}}",
+        format_comments(doc_lines, ""),
+        name,
+        format_struct_fields(&fields)
+    )
+}
+
+/// Formats struct fields, given as tuples of comments, name, type and tag,
+/// with their types and tags aligned the way `gofmt` does.
+fn format_struct_fields(fields: &[(String, String, String, String)]) -> String {
+    let name_width = fields
+        .iter()
+        .filter(|(_, _, ty, _)| !ty.is_empty())
+        .map(|(_, name, _, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    let type_width = fields
+        .iter()
+        .filter(|(_, _, _, tag)| !tag.is_empty())
+        .map(|(_, _, ty, _)| ty.len())
+        .max()
+        .unwrap_or_default();
+    fields
+        .iter()
+        .map(|(comments, name, ty, tag)| {
+            let line = if ty.is_empty() {
+                name.clone()
+            } else if tag.is_empty() {
+                format!("{name:name_width$} {ty}")
+            } else {
+                format!("{name:name_width$} {ty:type_width$} {tag}")
+            };
+            format!("{}\t{}\n", comments, line.trim_end())
+        })
+        .collect()
+}
+
+/// Formats a type so it's valid as a Go type.
+fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
+    match types.get(ident) {
+        Some(ty) => format_type_with_ident(ty, ident, types),
+        None => ident.name.clone(), // Must be a generic.
+    }
+}
+
+fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> String {
+    let format_arg = |index: usize| match ident.generic_args.get(index) {
+        Some((arg, _)) => format_ident(arg, types),
+        None => "interface{}".to_owned(),
+    };
+
+    match ty {
+        Type::Alias(name, _) => name.clone(),
+        Type::Array(primitive, size) => format!("[{}]{}", size, format_primitive(*primitive)),
+        Type::Container(name, _) => {
+            if name == "Option" {
+                format!("*{}", format_arg(0))
+            } else {
+                format_arg(0)
+            }
+        }
+        Type::Custom(custom) => format_custom_type(custom).to_owned(),
+        Type::Enum(_) | Type::Struct(_) => {
+            let args: Vec<_> = ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| format_ident(arg, types))
+                .collect();
+            if args.is_empty() {
+                ident.name.clone()
+            } else {
+                format!("{}[{}]", ident.name, args.join(", "))
+            }
+        }
+        Type::List(_, _) => format!("[]{}", format_arg(0)),
+        Type::Map(_, _, _) => format!("map[{}]{}", format_arg(0), format_arg(1)),
+        Type::Primitive(primitive) => format_primitive(*primitive).to_owned(),
+        Type::String => "string".to_owned(),
+        Type::Tuple(_) => "[]interface{}".to_owned(),
+        Type::Unit => "Unit".to_owned(),
+    }
+}
+
+/// Formats the type parameters for the definition of a generic type.
+fn format_type_params(ident: &TypeIdent) -> String {
+    if ident.generic_args.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "[{}]",
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| format!("{} any", arg.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Formats the type arguments for referring to a generic type from its own
+/// definition.
+fn format_type_args(ident: &TypeIdent) -> String {
+    if ident.generic_args.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "[{}]",
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| arg.name.clone())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Custom types only specify their TypeScript type, so we map the ones we
+/// recognize and fall back to `interface{}` for the rest.
+fn format_custom_type(custom: &CustomType) -> &'static str {
+    match custom.ts_ty.as_str() {
+        "ArrayBuffer" | "Uint8Array" => "[]byte",
+        "boolean" => "bool",
+        "number" => "float64",
+        "string" => "string",
+        _ => "interface{}",
+    }
+}
+
+fn format_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::F32 => "float32",
+        Primitive::F64 => "float64",
+        Primitive::I8 => "int8",
+        Primitive::I16 => "int16",
+        Primitive::I32 => "int32",
+        Primitive::I64 => "int64",
+        Primitive::U8 => "uint8",
+        Primitive::U16 => "uint16",
+        Primitive::U32 => "uint32",
+        Primitive::U64 => "uint64",
+    }
+}
+
+fn format_wasm_type(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::F32 => "api.ValueTypeF32",
+        Primitive::F64 => "api.ValueTypeF64",
+        Primitive::I64 | Primitive::U64 => "api.ValueTypeI64",
+        _ => "api.ValueTypeI32",
+    }
+}
+
+/// Encodes a primitive value the way wazero passes it to the plugin.
+fn encode_primitive(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("encodeBool({value})"),
+        Primitive::F32 => format!("api.EncodeF32({value})"),
+        Primitive::F64 => format!("api.EncodeF64({value})"),
+        Primitive::I8 | Primitive::I16 => format!("api.EncodeI32(int32({value}))"),
+        Primitive::I32 => format!("api.EncodeI32({value})"),
+        Primitive::I64 => format!("api.EncodeI64({value})"),
+        Primitive::U8 | Primitive::U16 => format!("api.EncodeU32(uint32({value}))"),
+        Primitive::U32 => format!("api.EncodeU32({value})"),
+        Primitive::U64 => value.to_owned(),
+    }
+}
+
+/// Decodes a primitive value received from the plugin through wazero.
+fn decode_primitive(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("uint32({value}) != 0"),
+        Primitive::F32 => format!("api.DecodeF32({value})"),
+        Primitive::F64 => format!("api.DecodeF64({value})"),
+        Primitive::I8 => format!("int8(api.DecodeI32({value}))"),
+        Primitive::I16 => format!("int16(api.DecodeI32({value}))"),
+        Primitive::I32 => format!("api.DecodeI32({value})"),
+        Primitive::I64 => format!("int64({value})"),
+        Primitive::U8 => format!("uint8({value})"),
+        Primitive::U16 => format!("uint16({value})"),
+        Primitive::U32 => format!("api.DecodeU32({value})"),
+        Primitive::U64 => value.to_owned(),
+    }
+}
+
+fn format_comments(doc_lines: &[String], indent: &str) -> String {
+    doc_lines
+        .iter()
+        .map(|line| format!("{}//{}\n", indent, line.trim_end()))
+        .collect()
+}
+
+fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(field.name.as_deref().unwrap_or_default()))
+    }
+}
+
+fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+/// Returns a name that can be used for an argument in Go.
+fn get_arg_name(name: &str) -> String {
+    let name = get_variable_name(name).to_camel_case();
+    if GO_KEYWORDS.contains(&name.as_str()) || RESERVED_NAMES.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn get_pointer_name(name: &str) -> String {
+    format!("{}Ptr", get_variable_name(name).to_camel_case())
+}
+
+fn indent_lines(lines: &str, indent: &str) -> String {
+    lines
+        .trim_end()
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn join_lines(lines: &[String], indent: &str) -> String {
+    lines
+        .iter()
+        .map(|line| format!("{}\n", indent_lines(line, indent)))
+        .collect()
+}
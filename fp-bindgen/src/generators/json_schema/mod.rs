@@ -0,0 +1,571 @@
+use crate::{
+    casing::Casing,
+    prelude::Primitive,
+    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+};
+use serde_json::{json, Map, Value};
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr};
+
+/// The JSON Schema dialect the generated schemas are written in.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub(crate) fn generate_bindings(types: TypeMap) -> BTreeMap<PathBuf, String> {
+    types
+        .iter()
+        .filter(|(_, ty)| match ty {
+            Type::Enum(ty) => ty.ident.generic_args.is_empty(),
+            Type::Struct(ty) => ty.ident.generic_args.is_empty(),
+            _ => false,
+        })
+        .map(|(ident, _)| {
+            let schema = SchemaGenerator::new(&types, ident).generate();
+            let mut json =
+                serde_json::to_string_pretty(&schema).expect("Could not serialize JSON Schema");
+            json.push('\n');
+            (PathBuf::from(format!("{}.schema.json", ident.name)), json)
+        })
+        .collect()
+}
+
+/// Generates a self-contained schema for a single type. Any structs and enums
+/// the type refers to are included in its `$defs`, where instantiations of
+/// generic types are defined separately, with their type arguments appended to
+/// their name.
+struct SchemaGenerator<'a> {
+    types: &'a TypeMap,
+    root: &'a TypeIdent,
+    defs: BTreeMap<String, Value>,
+}
+
+impl<'a> SchemaGenerator<'a> {
+    fn new(types: &'a TypeMap, root: &'a TypeIdent) -> Self {
+        Self {
+            types,
+            root,
+            defs: BTreeMap::new(),
+        }
+    }
+
+    fn generate(mut self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("$schema".to_owned(), json!(JSON_SCHEMA_DIALECT));
+        schema.insert("title".to_owned(), json!(self.root.name));
+        if let Value::Object(definition) = self.create_definition(self.root) {
+            schema.extend(definition);
+        }
+        if !self.defs.is_empty() {
+            schema.insert("$defs".to_owned(), json!(self.defs));
+        }
+        Value::Object(schema)
+    }
+
+    /// Returns the schema for values of the given type.
+    fn schema_for(&mut self, ident: &TypeIdent) -> Value {
+        if let Some(len) = ident.array {
+            let items = match Primitive::from_str(&ident.name) {
+                Ok(primitive) => format_primitive(primitive),
+                Err(_) => self.schema_for(&TypeIdent::from(ident.name.clone())),
+            };
+            return json!({
+                "type": "array",
+                "items": items,
+                "minItems": len.get(),
+                "maxItems": len.get(),
+            });
+        }
+        if let Some(primitive) = ident.as_primitive() {
+            return format_primitive(primitive);
+        }
+
+        let arg = |index: usize| ident.generic_args.get(index).map(|(arg, _)| arg.clone());
+        match self.types.get(ident) {
+            Some(Type::Alias(_, target)) => self.schema_for(target),
+            Some(Type::Array(primitive, len)) => json!({
+                "type": "array",
+                "items": format_primitive(*primitive),
+                "minItems": len,
+                "maxItems": len,
+            }),
+            Some(Type::Container(name, _)) => match arg(0) {
+                Some(arg) if name == "Option" => json!({
+                    "anyOf": [self.schema_for(&arg), { "type": "null" }],
+                }),
+                Some(arg) => self.schema_for(&arg),
+                None => json!({}),
+            },
+            Some(Type::Custom(custom)) => format_custom_type(custom),
+            Some(Type::Enum(_)) | Some(Type::Struct(_)) => self.reference(ident),
+            Some(Type::List(_, _)) => match arg(0) {
+                Some(arg) => json!({ "type": "array", "items": self.schema_for(&arg) }),
+                None => json!({ "type": "array" }),
+            },
+            Some(Type::Map(_, _, _)) => match arg(1) {
+                Some(arg) => json!({
+                    "type": "object",
+                    "additionalProperties": self.schema_for(&arg),
+                }),
+                None => json!({ "type": "object" }),
+            },
+            Some(Type::Primitive(primitive)) => format_primitive(*primitive),
+            Some(Type::String) => json!({ "type": "string" }),
+            Some(Type::Tuple(items)) if items.is_empty() => json!({ "type": "null" }),
+            Some(Type::Tuple(items)) => self.tuple_schema(items),
+            Some(Type::Unit) => json!({ "type": "null" }),
+            None if ident.name == "String" => json!({ "type": "string" }),
+            // Unresolved type parameters may hold any value.
+            None => json!({}),
+        }
+    }
+
+    /// Returns a reference to the definition of the given struct or enum,
+    /// adding it to the `$defs` if it is not defined yet.
+    fn reference(&mut self, ident: &TypeIdent) -> Value {
+        if ident == self.root {
+            return json!({ "$ref": "#" });
+        }
+
+        let name = self.mangle(ident);
+        if !self.defs.contains_key(&name) {
+            // Insert a placeholder first, so that recursive types terminate.
+            self.defs.insert(name.clone(), Value::Null);
+            let definition = self.create_definition(ident);
+            self.defs.insert(name.clone(), definition);
+        }
+
+        json!({ "$ref": format!("#/$defs/{name}") })
+    }
+
+    /// Returns the name under which the given type is defined in the `$defs`,
+    /// which is unique for every instantiation of a generic type.
+    fn mangle(&self, ident: &TypeIdent) -> String {
+        let name = match self.types.get(ident) {
+            Some(Type::Alias(_, target)) => return self.mangle(target),
+            Some(Type::Container(name, _)) | Some(Type::List(name, _)) => name.clone(),
+            Some(Type::Map(name, _, _)) => name.clone(),
+            Some(Type::Custom(custom)) => custom.ident.name.replace("::", "_"),
+            _ => ident.name.clone(),
+        };
+
+        std::iter::once(name)
+            .chain(ident.generic_args.iter().map(|(arg, _)| self.mangle(arg)))
+            .chain(ident.array.map(|len| len.to_string()))
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    fn create_definition(&mut self, ident: &TypeIdent) -> Value {
+        let (mut definition, doc_lines) = match self.types.get(ident) {
+            Some(Type::Enum(ty)) => (self.enum_schema(ty, ident), &ty.doc_lines),
+            Some(Type::Struct(ty)) => (self.struct_schema(ty, ident), &ty.doc_lines),
+            _ => unreachable!("Only structs and enums have definitions"),
+        };
+        with_description(&mut definition, doc_lines);
+        definition
+    }
+
+    fn struct_schema(&mut self, ty: &Struct, ident: &TypeIdent) -> Value {
+        let params = get_type_params(&ty.ident, ident);
+        let fields = substitute_fields(&ty.fields, &params);
+        match fields.as_slice() {
+            [field] if field.name.is_none() => self.schema_for(&field.ty),
+            _ if !fields.is_empty() && fields.iter().all(|field| field.name.is_none()) => {
+                let items = fields.into_iter().map(|field| field.ty).collect::<Vec<_>>();
+                self.tuple_schema(&items)
+            }
+            _ => self
+                .object_schema(&fields, ty.options.field_casing)
+                .into_value(),
+        }
+    }
+
+    fn tuple_schema(&mut self, items: &[TypeIdent]) -> Value {
+        json!({
+            "type": "array",
+            "prefixItems": items.iter().map(|item| self.schema_for(item)).collect::<Vec<_>>(),
+            "minItems": items.len(),
+            "maxItems": items.len(),
+        })
+    }
+
+    fn object_schema(&mut self, fields: &[Field], casing: Casing) -> ObjectSchema {
+        let mut object = ObjectSchema::default();
+        self.collect_fields(&mut object, fields, casing);
+        object
+    }
+
+    /// Adds the given fields to the object schema. The fields of flattened
+    /// structs are added to the object directly.
+    fn collect_fields(&mut self, object: &mut ObjectSchema, fields: &[Field], casing: Casing) {
+        for field in fields {
+            if field.attrs.flatten {
+                self.collect_flattened_field(object, &field.ty);
+                continue;
+            }
+
+            let name = get_field_name(field, casing);
+            let mut schema = self.schema_for(&field.ty);
+            with_description(&mut schema, &field.doc_lines);
+            object.properties.insert(name.clone(), schema);
+
+            let is_optional = field.attrs.default.is_some()
+                || field.attrs.skip_serializing_if.is_some()
+                || self.is_option(&field.ty);
+            if !is_optional {
+                object.required.push(name);
+            }
+        }
+    }
+
+    fn collect_flattened_field(&mut self, object: &mut ObjectSchema, ident: &TypeIdent) {
+        match self.types.get(ident) {
+            Some(Type::Alias(_, target)) => self.collect_flattened_field(object, target),
+            Some(Type::Struct(ty)) if ty.fields.iter().all(|field| field.name.is_some()) => {
+                let params = get_type_params(&ty.ident, ident);
+                let fields = substitute_fields(&ty.fields, &params);
+                self.collect_fields(object, &fields, ty.options.field_casing);
+            }
+            Some(Type::Map(_, _, _)) => {
+                if let Some((value, _)) = ident.generic_args.get(1) {
+                    object.additional_properties = Some(self.schema_for(value));
+                }
+            }
+            _ => {
+                let schema = self.schema_for(ident);
+                object.all_of.push(schema);
+            }
+        }
+    }
+
+    fn is_option(&self, ident: &TypeIdent) -> bool {
+        match self.types.get(ident) {
+            Some(Type::Alias(_, target)) => self.is_option(target),
+            Some(Type::Container(name, _)) => name == "Option",
+            _ => false,
+        }
+    }
+
+    fn enum_schema(&mut self, ty: &Enum, ident: &TypeIdent) -> Value {
+        let params = get_type_params(&ty.ident, ident);
+        let opts = &ty.options;
+
+        let is_unit_only = ty
+            .variants
+            .iter()
+            .all(|variant| is_unit_variant(&variant.ty));
+        if is_unit_only && !opts.untagged && opts.tag_prop_name.is_none() {
+            let names = ty
+                .variants
+                .iter()
+                .map(|variant| get_variant_name(variant, opts))
+                .collect::<Vec<_>>();
+            return json!({ "type": "string", "enum": names });
+        }
+
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let mut schema = self.variant_schema(variant, opts, &params);
+                with_description(&mut schema, &variant.doc_lines);
+                schema
+            })
+            .collect::<Vec<_>>();
+
+        // Untagged variants may overlap, in which case Serde picks the first
+        // one that matches.
+        if opts.untagged {
+            json!({ "anyOf": variants })
+        } else {
+            json!({ "oneOf": variants })
+        }
+    }
+
+    fn variant_schema(
+        &mut self,
+        variant: &Variant,
+        opts: &EnumOptions,
+        params: &TypeParams,
+    ) -> Value {
+        let name = get_variant_name(variant, opts);
+        let tag = |prop_name: &str| {
+            let mut object = ObjectSchema::default();
+            object
+                .properties
+                .insert(prop_name.to_owned(), json!({ "const": name }));
+            object.required.push(prop_name.to_owned());
+            object
+        };
+
+        if opts.untagged {
+            return match variant.ty {
+                Type::Unit => json!({ "type": "null" }),
+                _ => self.payload_schema(variant, params),
+            };
+        }
+
+        match (&opts.tag_prop_name, &opts.content_prop_name) {
+            (Some(tag_prop_name), Some(content_prop_name)) => {
+                let mut object = tag(tag_prop_name);
+                if !is_unit_variant(&variant.ty) {
+                    let payload = self.payload_schema(variant, params);
+                    object.properties.insert(content_prop_name.clone(), payload);
+                    object.required.push(content_prop_name.clone());
+                }
+                object.into_value()
+            }
+            (Some(tag_prop_name), None) => match &variant.ty {
+                Type::Struct(variant_ty) => {
+                    let fields = substitute_fields(&variant_ty.fields, params);
+                    let mut object = tag(tag_prop_name);
+                    self.collect_fields(&mut object, &fields, variant.attrs.field_casing);
+                    object.into_value()
+                }
+                Type::Tuple(items) if !items.is_empty() => {
+                    let mut object = tag(tag_prop_name);
+                    let payload = self.payload_schema(variant, params);
+                    object.all_of.push(payload);
+                    object.into_value()
+                }
+                _ => tag(tag_prop_name).into_value(),
+            },
+            _ if is_unit_variant(&variant.ty) => json!({ "const": name }),
+            _ => {
+                let payload = self.payload_schema(variant, params);
+                json!({
+                    "type": "object",
+                    "properties": { name.clone(): payload },
+                    "required": [name],
+                    "additionalProperties": false,
+                })
+            }
+        }
+    }
+
+    fn payload_schema(&mut self, variant: &Variant, params: &TypeParams) -> Value {
+        match &variant.ty {
+            Type::Struct(variant_ty) => {
+                let fields = substitute_fields(&variant_ty.fields, params);
+                self.object_schema(&fields, variant.attrs.field_casing)
+                    .into_value()
+            }
+            Type::Tuple(items) if items.len() == 1 => {
+                self.schema_for(&substitute(&items[0], params))
+            }
+            Type::Tuple(items) if !items.is_empty() => {
+                let items = items
+                    .iter()
+                    .map(|item| substitute(item, params))
+                    .collect::<Vec<_>>();
+                self.tuple_schema(&items)
+            }
+            _ => json!({ "type": "null" }),
+        }
+    }
+}
+
+/// The parts of a schema for an object with named properties.
+#[derive(Default)]
+struct ObjectSchema {
+    properties: Map<String, Value>,
+    required: Vec<String>,
+    all_of: Vec<Value>,
+    additional_properties: Option<Value>,
+}
+
+impl ObjectSchema {
+    fn into_value(self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".to_owned(), json!("object"));
+        if !self.properties.is_empty() {
+            schema.insert("properties".to_owned(), Value::Object(self.properties));
+        }
+        if !self.required.is_empty() {
+            schema.insert("required".to_owned(), json!(self.required));
+        }
+        if !self.all_of.is_empty() {
+            schema.insert("allOf".to_owned(), json!(self.all_of));
+        }
+        if let Some(additional_properties) = self.additional_properties {
+            schema.insert("additionalProperties".to_owned(), additional_properties);
+        }
+        Value::Object(schema)
+    }
+}
+
+/// Maps the names of generic parameters to the arguments they are
+/// instantiated with.
+type TypeParams = Vec<(String, TypeIdent)>;
+
+fn format_primitive(primitive: Primitive) -> Value {
+    match primitive {
+        Primitive::Bool => json!({ "type": "boolean" }),
+        Primitive::F32 | Primitive::F64 => json!({ "type": "number" }),
+        Primitive::I8 => format_integer(i8::MIN as i64, i8::MAX as u64),
+        Primitive::I16 => format_integer(i16::MIN as i64, i16::MAX as u64),
+        Primitive::I32 => format_integer(i32::MIN as i64, i32::MAX as u64),
+        Primitive::I64 => format_integer(i64::MIN, i64::MAX as u64),
+        Primitive::U8 => format_integer(0, u8::MAX as u64),
+        Primitive::U16 => format_integer(0, u16::MAX as u64),
+        Primitive::U32 => format_integer(0, u32::MAX as u64),
+        Primitive::U64 => format_integer(0, u64::MAX),
+    }
+}
+
+fn format_integer(minimum: i64, maximum: u64) -> Value {
+    json!({ "type": "integer", "minimum": minimum, "maximum": maximum })
+}
+
+/// Custom types are described by the TypeScript type they map to, since that
+/// is what they look like in JSON too.
+fn format_custom_type(custom: &CustomType) -> Value {
+    match custom.ts_ty.as_str() {
+        "ArrayBuffer" | "Uint8Array" => json!({
+            "type": "array",
+            "items": format_primitive(Primitive::U8),
+        }),
+        "boolean" => json!({ "type": "boolean" }),
+        "number" => json!({ "type": "number" }),
+        "string" => json!({ "type": "string" }),
+        _ => json!({}),
+    }
+}
+
+fn with_description(schema: &mut Value, doc_lines: &[String]) {
+    if doc_lines.is_empty() {
+        return;
+    }
+
+    let description = doc_lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if let Value::Object(schema) = schema {
+        schema.insert("description".to_owned(), json!(description));
+    }
+}
+
+fn is_unit_variant(ty: &Type) -> bool {
+    match ty {
+        Type::Unit => true,
+        Type::Tuple(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+fn get_type_params(definition: &TypeIdent, ident: &TypeIdent) -> TypeParams {
+    definition
+        .generic_args
+        .iter()
+        .zip(ident.generic_args.iter())
+        .map(|((param, _), (arg, _))| (param.name.clone(), arg.clone()))
+        .collect()
+}
+
+fn substitute_fields(fields: &[Field], params: &[(String, TypeIdent)]) -> Vec<Field> {
+    fields
+        .iter()
+        .map(|field| Field {
+            ty: substitute(&field.ty, params),
+            ..field.clone()
+        })
+        .collect()
+}
+
+/// Replaces generic parameters in the given type with their arguments.
+fn substitute(ident: &TypeIdent, params: &[(String, TypeIdent)]) -> TypeIdent {
+    if ident.generic_args.is_empty() && ident.array.is_none() {
+        if let Some((_, arg)) = params.iter().find(|(name, _)| name == &ident.name) {
+            return arg.clone();
+        }
+    }
+
+    let mut ident = ident.clone();
+    ident.generic_args = ident
+        .generic_args
+        .iter()
+        .map(|(arg, bounds)| (substitute(arg, params), bounds.clone()))
+        .collect();
+    ident
+}
+
+fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(field.name.as_deref().unwrap_or_default()))
+    }
+}
+
+fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generic_and_recursive_types() {
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from("Point<T>"),
+            Type::from_item(
+                "#[fp(rename_all = \"camelCase\")]\npub struct Point<T> { pub x_pos: T, #[fp(rename = \"y\")] pub y_pos: T }",
+            ),
+        );
+        types.insert(
+            TypeIdent::from("Tree"),
+            Type::from_item(
+                "pub struct Tree { pub origin: Point<f64>, #[fp(default)] pub children: Vec<Tree> }",
+            ),
+        );
+        types.insert(
+            TypeIdent::from("Vec<T>"),
+            Type::List("Vec".to_owned(), TypeIdent::from("T")),
+        );
+
+        let files = generate_bindings(types);
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec![&PathBuf::from("Tree.schema.json")]
+        );
+
+        let schema: Value = serde_json::from_str(&files[&PathBuf::from("Tree.schema.json")])
+            .expect("Could not parse generated schema");
+        assert_eq!(schema["$schema"], json!(JSON_SCHEMA_DIALECT));
+        assert_eq!(schema["required"], json!(["origin"]));
+        assert_eq!(
+            schema["properties"]["origin"],
+            json!({ "$ref": "#/$defs/Point_f64" })
+        );
+        assert_eq!(
+            schema["properties"]["children"],
+            json!({ "type": "array", "items": { "$ref": "#" } })
+        );
+        assert_eq!(
+            schema["$defs"]["Point_f64"],
+            json!({
+                "type": "object",
+                "properties": {
+                    "xPos": { "type": "number" },
+                    "y": { "type": "number" },
+                },
+                "required": ["xPos", "y"],
+            })
+        );
+    }
+}
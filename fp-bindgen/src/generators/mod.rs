@@ -1,4 +1,5 @@
 use crate::{
+    errors::BindgenError,
     functions::FunctionList,
     types::{CargoDependency, Type, TypeIdent, TypeMap},
 };
@@ -6,29 +7,67 @@ use std::{
     collections::{BTreeMap, BTreeSet},
     fmt::Display,
     fs,
+    path::{Path, PathBuf},
 };
 
+pub mod api_reference;
+pub mod assemblyscript_plugin;
+pub mod c_plugin;
+mod check;
+pub mod go_wazero_runtime;
+pub mod json_schema;
+pub mod protocol_json;
+pub mod python_wasmtime_runtime;
 pub mod rust_plugin;
 pub mod rust_wasmer2_runtime;
 pub mod rust_wasmer2_wasi_runtime;
+pub mod rust_wasmer3_runtime;
+pub mod rust_wasmtime_runtime;
+pub mod tinygo_plugin;
 pub mod ts_runtime;
+mod validation;
+pub mod wit_world;
+
+pub use check::{check_bindings, OutdatedBindings, OutdatedFile};
 
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 pub enum BindingsType {
+    ApiReference(ApiReferenceConfig),
+    AssemblyScriptPlugin,
+    CPlugin,
+    GoWazeroRuntime(GoWazeroRuntimeConfig),
+    JsonSchema,
+    ProtocolJson,
+    PythonWasmtimeRuntime,
     RustPlugin(RustPluginConfig),
     RustWasmer2Runtime,
     RustWasmer2WasiRuntime,
+    RustWasmer3Runtime,
+    RustWasmtimeRuntime,
+    TinyGoPlugin(TinyGoPluginConfig),
     TsRuntime(TsRuntimeConfig),
+    WitWorld(WitWorldConfig),
 }
 
 impl Display for BindingsType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
+            BindingsType::ApiReference { .. } => "api-reference",
+            BindingsType::AssemblyScriptPlugin => "assemblyscript-plugin",
+            BindingsType::CPlugin => "c-plugin",
+            BindingsType::GoWazeroRuntime { .. } => "go-wazero-runtime",
+            BindingsType::JsonSchema => "json-schema",
+            BindingsType::ProtocolJson => "protocol-json",
+            BindingsType::PythonWasmtimeRuntime => "python-wasmtime-runtime",
             BindingsType::RustPlugin { .. } => "rust-plugin",
-            BindingsType::RustWasmer2Runtime { .. } => "rust-wasmer2-runtime",
-            BindingsType::RustWasmer2WasiRuntime { .. } => "rust-wasmer2-wasi-runtime",
+            BindingsType::RustWasmer2Runtime => "rust-wasmer2-runtime",
+            BindingsType::RustWasmer2WasiRuntime => "rust-wasmer2-wasi-runtime",
+            BindingsType::RustWasmer3Runtime => "rust-wasmer3-runtime",
+            BindingsType::RustWasmtimeRuntime => "rust-wasmtime-runtime",
+            BindingsType::TinyGoPlugin { .. } => "tinygo-plugin",
             BindingsType::TsRuntime { .. } => "ts-runtime",
+            BindingsType::WitWorld { .. } => "wit-world",
         })
     }
 }
@@ -39,6 +78,115 @@ pub struct BindingConfig<'a> {
     pub path: &'a str,
 }
 
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ApiReferenceConfig {
+    /// Format the reference is written in.
+    ///
+    /// By default, Markdown is used.
+    pub format: ApiReferenceFormat,
+
+    /// Title of the reference, which is used as the heading of the index page.
+    ///
+    /// By default, "API reference" is used.
+    pub title: String,
+}
+
+impl ApiReferenceConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `format` setting.
+    pub fn with_format(mut self, format: ApiReferenceFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the `title` setting.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_owned();
+        self
+    }
+}
+
+impl Default for ApiReferenceConfig {
+    fn default() -> Self {
+        Self {
+            format: ApiReferenceFormat::Markdown,
+            title: "API reference".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ApiReferenceFormat {
+    Html,
+    Markdown,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct GoWazeroRuntimeConfig {
+    /// Name of the Go package the bindings are generated for.
+    ///
+    /// By default, "bindings" is used.
+    pub package_name: String,
+}
+
+impl GoWazeroRuntimeConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `package_name` setting.
+    pub fn with_package_name(mut self, package_name: &str) -> Self {
+        self.package_name = package_name.to_owned();
+        self
+    }
+}
+
+impl Default for GoWazeroRuntimeConfig {
+    fn default() -> Self {
+        Self {
+            package_name: "bindings".to_owned(),
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct TinyGoPluginConfig {
+    /// Name of the Go package the bindings are generated for.
+    ///
+    /// By default, "bindings" is used.
+    pub package_name: String,
+}
+
+impl TinyGoPluginConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `package_name` setting.
+    pub fn with_package_name(mut self, package_name: &str) -> Self {
+        self.package_name = package_name.to_owned();
+        self
+    }
+}
+
+impl Default for TinyGoPluginConfig {
+    fn default() -> Self {
+        Self {
+            package_name: "bindings".to_owned(),
+        }
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 pub struct RustPluginConfig {
@@ -264,44 +412,201 @@ impl Default for TsRuntimeConfig {
 
 impl TsRuntimeConfig {}
 
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct WitWorldConfig {
+    /// Name of the WIT package the world is generated in, including its
+    /// namespace.
+    ///
+    /// By default, "local:protocol" is used.
+    pub package_name: String,
+
+    /// Name of the generated world.
+    ///
+    /// By default, "plugin" is used.
+    pub world_name: String,
+}
+
+impl WitWorldConfig {
+    /// Returns a new config instance with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `package_name` setting.
+    pub fn with_package_name(mut self, package_name: &str) -> Self {
+        self.package_name = package_name.to_owned();
+        self
+    }
+
+    /// Sets the `world_name` setting.
+    pub fn with_world_name(mut self, world_name: &str) -> Self {
+        self.world_name = world_name.to_owned();
+        self
+    }
+}
+
+impl Default for WitWorldConfig {
+    fn default() -> Self {
+        Self {
+            package_name: "local:protocol".to_owned(),
+            world_name: "plugin".to_owned(),
+        }
+    }
+}
+
+/// Generates the bindings of the given type and writes them to `config.path`.
+///
+/// Panics if the bindings cannot be generated. See `try_generate_bindings()`
+/// for a version that returns an error instead.
 pub fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
     config: BindingConfig,
 ) {
-    fs::create_dir_all(config.path).expect("Could not create output directory");
+    try_generate_bindings(import_functions, export_functions, types, config)
+        .unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Generates the bindings of the given type and writes them to `config.path`.
+///
+/// Returns an error if the protocol contains constructs that are not supported
+/// by the bindings type, or if the bindings cannot be written.
+pub fn try_generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: BindingConfig,
+) -> Result<(), BindgenError> {
+    let files = render_bindings(
+        import_functions,
+        export_functions,
+        types,
+        config.bindings_type,
+    )?;
+
+    let output_path = Path::new(config.path);
+    create_dir_all(output_path)?;
+    for (file_path, contents) in files {
+        let file_path = output_path.join(file_path);
+        if let Some(parent) = file_path.parent() {
+            create_dir_all(parent)?;
+        }
+        fs::write(&file_path, contents).map_err(|source| BindgenError::Io {
+            path: file_path,
+            source,
+        })?;
+    }
+
+    Ok(())
+}
 
+fn create_dir_all(path: &Path) -> Result<(), BindgenError> {
+    fs::create_dir_all(path).map_err(|source| BindgenError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Renders the bindings of the given type, without writing them to disk.
+///
+/// Returns the contents of every file, keyed by their path relative to the
+/// output directory. This is the same output `generate_bindings()` writes to
+/// `config.path`, so it can be used to write the bindings to another location,
+/// such as `OUT_DIR` in a build script, or to post-process them first.
+pub fn render_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    bindings_type: BindingsType,
+) -> Result<BTreeMap<PathBuf, String>, BindgenError> {
+    validation::validate_protocol(&import_functions, &export_functions, &types, &bindings_type)?;
     display_warnings(&import_functions, &export_functions, &types);
 
-    match config.bindings_type {
-        BindingsType::RustPlugin(plugin_config) => rust_plugin::generate_bindings(
+    match bindings_type {
+        BindingsType::ApiReference(reference_config) => Ok(api_reference::generate_bindings(
             import_functions,
             export_functions,
             types,
-            plugin_config,
-            config.path,
-        ),
-        BindingsType::RustWasmer2Runtime => rust_wasmer2_runtime::generate_bindings(
+            reference_config,
+        )),
+        BindingsType::AssemblyScriptPlugin => Ok(assemblyscript_plugin::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+        )),
+        BindingsType::CPlugin => Ok(c_plugin::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+        )),
+        BindingsType::GoWazeroRuntime(runtime_config) => Ok(go_wazero_runtime::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            runtime_config,
+        )),
+        BindingsType::JsonSchema => Ok(json_schema::generate_bindings(types)),
+        BindingsType::ProtocolJson => Ok(protocol_json::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+        )),
+        BindingsType::PythonWasmtimeRuntime => Ok(python_wasmtime_runtime::generate_bindings(
             import_functions,
             export_functions,
             types,
-            config.path,
-        ),
-        BindingsType::RustWasmer2WasiRuntime => rust_wasmer2_wasi_runtime::generate_bindings(
+        )),
+        BindingsType::RustPlugin(plugin_config) => Ok(rust_plugin::generate_bindings(
             import_functions,
             export_functions,
             types,
-            config.path,
-        ),
-        BindingsType::TsRuntime(runtime_config) => ts_runtime::generate_bindings(
+            plugin_config,
+        )),
+        BindingsType::RustWasmer2Runtime => {
+            rust_wasmer2_runtime::generate_bindings(import_functions, export_functions, types)
+        }
+        BindingsType::RustWasmer2WasiRuntime => {
+            rust_wasmer2_wasi_runtime::generate_bindings(import_functions, export_functions, types)
+        }
+        BindingsType::RustWasmer3Runtime => {
+            rust_wasmer3_runtime::generate_bindings(import_functions, export_functions, types)
+        }
+        BindingsType::RustWasmtimeRuntime => {
+            rust_wasmtime_runtime::generate_bindings(import_functions, export_functions, types)
+        }
+        BindingsType::TinyGoPlugin(plugin_config) => Ok(tinygo_plugin::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            plugin_config,
+        )),
+        BindingsType::TsRuntime(runtime_config) => Ok(ts_runtime::generate_bindings(
             import_functions,
             export_functions,
             types,
             runtime_config,
-            config.path,
-        ),
-    };
+        )),
+        BindingsType::WitWorld(world_config) => Ok(wit_world::generate_bindings(
+            import_functions,
+            export_functions,
+            types,
+            world_config,
+        )),
+    }
+}
+
+/// Formats generated Rust code using `rustfmt`. `path` is only used for
+/// reporting errors.
+pub(crate) fn format_rust_code(path: &str, code: String) -> Result<String, BindgenError> {
+    rustfmt_wrapper::rustfmt(code).map_err(|error| BindgenError::Rustfmt {
+        path: PathBuf::from(path),
+        message: match error {
+            rustfmt_wrapper::Error::Rustfmt(message) => message,
+            error => error.to_string(),
+        },
+    })
 }
 
 fn display_warnings(
@@ -0,0 +1,861 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionArg, FunctionList},
+    primitives::Primitive,
+    protocol::Protocol,
+    types::{
+        CargoDependency, CustomType, Enum, EnumOptions, Field, FieldAttrs, Struct, StructOptions,
+        Type, TypeIdent, TypeMap, Variant, VariantAttrs,
+    },
+};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::{TryFrom, TryInto},
+    num::NonZeroUsize,
+    path::PathBuf,
+    str::FromStr,
+    sync::Mutex,
+};
+
+/// Version of the protocol JSON format.
+///
+/// This is bumped whenever a change is made to the format that existing
+/// consumers cannot safely ignore.
+pub const PROTOCOL_JSON_VERSION: u32 = 1;
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+) -> BTreeMap<PathBuf, String> {
+    BTreeMap::from([(
+        PathBuf::from("protocol.json"),
+        protocol_to_json(&import_functions, &export_functions, &types),
+    )])
+}
+
+/// Serializes a protocol into a versioned JSON document.
+///
+/// The document contains all the functions and types collected from the
+/// `fp_import!` and `fp_export!` blocks, so that tools written in other
+/// languages can generate bindings for the protocol without linking against
+/// `fp-bindgen`. The output is stable: functions and types are sorted by name,
+/// which makes it suitable for diffing protocols in code review.
+pub fn protocol_to_json(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> String {
+    let document = ProtocolDocument::new(import_functions, export_functions, types);
+
+    let mut json =
+        serde_json::to_string_pretty(&document).expect("Could not serialize protocol to JSON");
+    json.push('\n');
+    json
+}
+
+/// Computes a fingerprint of the protocol, which is used to verify that a
+/// plugin and a runtime were generated from the same protocol.
+///
+/// Doc comments do not affect the fingerprint, but any other change to the
+/// functions or types does.
+pub fn protocol_fingerprint(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> u64 {
+    let mut document = ProtocolDocument::new(import_functions, export_functions, types);
+    document.strip_doc_lines();
+
+    let json = serde_json::to_vec(&document).expect("Could not serialize protocol to JSON");
+
+    // 64-bit FNV-1a, which is simple enough to be stable across releases.
+    json.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Parses a JSON document produced by [`protocol_to_json()`] back into a
+/// [`Protocol`].
+///
+/// Documents with a different format version are rejected.
+pub fn protocol_from_json(json: &str) -> Result<Protocol, serde_json::Error> {
+    let document: ProtocolDocument = serde_json::from_str(json)?;
+    if document.version != PROTOCOL_JSON_VERSION {
+        return Err(serde_json::Error::custom(format!(
+            "Unsupported protocol JSON version: {} (expected {})",
+            document.version, PROTOCOL_JSON_VERSION
+        )));
+    }
+
+    let import_functions = document
+        .import_functions
+        .into_iter()
+        .map(Function::from)
+        .collect();
+    let export_functions = document
+        .export_functions
+        .into_iter()
+        .map(Function::from)
+        .collect();
+    let types = document
+        .types
+        .into_iter()
+        .map(|ty| {
+            let ty = Type::try_from(ty).map_err(serde_json::Error::custom)?;
+            let ident = match &ty {
+                Type::Custom(CustomType { ident, .. })
+                | Type::Enum(Enum { ident, .. })
+                | Type::Struct(Struct { ident, .. }) => ident.clone(),
+                ty => TypeIdent::from_str(&ty.name()).map_err(serde_json::Error::custom)?,
+            };
+            Ok((ident, ty))
+        })
+        .collect::<Result<_, serde_json::Error>>()?;
+
+    Ok(Protocol {
+        import_functions,
+        export_functions,
+        types,
+    })
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ProtocolDocument {
+    version: u32,
+    import_functions: Vec<FunctionDef>,
+    export_functions: Vec<FunctionDef>,
+    types: Vec<TypeDef>,
+}
+
+impl ProtocolDocument {
+    fn new(
+        import_functions: &FunctionList,
+        export_functions: &FunctionList,
+        types: &TypeMap,
+    ) -> Self {
+        Self {
+            version: PROTOCOL_JSON_VERSION,
+            import_functions: import_functions.iter().map(FunctionDef::from).collect(),
+            export_functions: export_functions.iter().map(FunctionDef::from).collect(),
+            types: types.values().map(TypeDef::from).collect(),
+        }
+    }
+
+    fn strip_doc_lines(&mut self) {
+        let functions = self
+            .import_functions
+            .iter_mut()
+            .chain(self.export_functions.iter_mut());
+        for function in functions {
+            function.doc_lines.clear();
+        }
+
+        for ty in self.types.iter_mut() {
+            ty.strip_doc_lines();
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FunctionDef {
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    doc_lines: Vec<String>,
+    args: Vec<FunctionArgDef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    return_type: Option<TypeIdentDef>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    is_async: bool,
+}
+
+impl From<&Function> for FunctionDef {
+    fn from(function: &Function) -> Self {
+        Self {
+            name: function.name.clone(),
+            doc_lines: function.doc_lines.clone(),
+            args: function
+                .args
+                .iter()
+                .map(|arg| FunctionArgDef {
+                    name: arg.name.clone(),
+                    ty: (&arg.ty).into(),
+                })
+                .collect(),
+            return_type: function.return_type.as_ref().map(Into::into),
+            is_async: function.is_async,
+        }
+    }
+}
+
+impl From<FunctionDef> for Function {
+    fn from(function: FunctionDef) -> Self {
+        Self {
+            name: function.name,
+            doc_lines: function.doc_lines,
+            args: function
+                .args
+                .into_iter()
+                .map(|arg| FunctionArg {
+                    name: arg.name,
+                    ty: arg.ty.into(),
+                })
+                .collect(),
+            return_type: function.return_type.map(Into::into),
+            is_async: function.is_async,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct FunctionArgDef {
+    name: String,
+    ty: TypeIdentDef,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TypeIdentDef {
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    generic_args: Vec<GenericArgDef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    array: Option<NonZeroUsize>,
+}
+
+impl From<&TypeIdent> for TypeIdentDef {
+    fn from(ident: &TypeIdent) -> Self {
+        Self {
+            name: ident.name.clone(),
+            generic_args: ident
+                .generic_args
+                .iter()
+                .map(|(arg, bounds)| GenericArgDef {
+                    ty: arg.into(),
+                    bounds: bounds.clone(),
+                })
+                .collect(),
+            array: ident.array,
+        }
+    }
+}
+
+impl From<TypeIdentDef> for TypeIdent {
+    fn from(ident: TypeIdentDef) -> Self {
+        Self {
+            name: ident.name,
+            generic_args: ident
+                .generic_args
+                .into_iter()
+                .map(|arg| (arg.ty.into(), arg.bounds))
+                .collect(),
+            array: ident.array,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct GenericArgDef {
+    ty: TypeIdentDef,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    bounds: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+enum TypeDef {
+    Alias {
+        name: String,
+        target: TypeIdentDef,
+    },
+    Array {
+        #[serde(with = "primitive")]
+        primitive: Primitive,
+        size: usize,
+    },
+    Container {
+        name: String,
+        item: TypeIdentDef,
+    },
+    #[serde(rename_all = "camelCase")]
+    Custom {
+        ident: TypeIdentDef,
+        rs_ty: String,
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        rs_dependencies: BTreeMap<String, CargoDependencyDef>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        serde_attrs: Vec<String>,
+        ts_ty: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ts_declaration: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Enum {
+        ident: TypeIdentDef,
+        variants: Vec<VariantDef>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        doc_lines: Vec<String>,
+        #[serde(default)]
+        options: EnumOptionsDef,
+    },
+    List {
+        name: String,
+        item: TypeIdentDef,
+    },
+    Map {
+        name: String,
+        key: TypeIdentDef,
+        value: TypeIdentDef,
+    },
+    Primitive {
+        #[serde(with = "primitive")]
+        primitive: Primitive,
+    },
+    String,
+    #[serde(rename_all = "camelCase")]
+    Struct {
+        ident: TypeIdentDef,
+        fields: Vec<FieldDef>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        doc_lines: Vec<String>,
+        #[serde(default)]
+        options: StructOptionsDef,
+    },
+    Tuple {
+        items: Vec<TypeIdentDef>,
+    },
+    Unit,
+}
+
+impl TypeDef {
+    fn strip_doc_lines(&mut self) {
+        match self {
+            Self::Enum {
+                variants,
+                doc_lines,
+                ..
+            } => {
+                doc_lines.clear();
+                for variant in variants {
+                    variant.doc_lines.clear();
+                    variant.ty.strip_doc_lines();
+                }
+            }
+            Self::Struct {
+                fields, doc_lines, ..
+            } => {
+                doc_lines.clear();
+                for field in fields {
+                    field.doc_lines.clear();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl From<&Type> for TypeDef {
+    fn from(ty: &Type) -> Self {
+        match ty {
+            Type::Alias(name, target) => Self::Alias {
+                name: name.clone(),
+                target: target.into(),
+            },
+            Type::Array(primitive, size) => Self::Array {
+                primitive: *primitive,
+                size: *size,
+            },
+            Type::Container(name, item) => Self::Container {
+                name: name.clone(),
+                item: item.into(),
+            },
+            Type::Custom(custom) => Self::Custom {
+                ident: (&custom.ident).into(),
+                rs_ty: custom.rs_ty.clone(),
+                rs_dependencies: custom
+                    .rs_dependencies
+                    .iter()
+                    .map(|(name, dependency)| (name.to_string(), dependency.into()))
+                    .collect(),
+                serde_attrs: custom.serde_attrs.clone(),
+                ts_ty: custom.ts_ty.clone(),
+                ts_declaration: custom.ts_declaration.clone(),
+            },
+            Type::Enum(ty) => Self::Enum {
+                ident: (&ty.ident).into(),
+                variants: ty.variants.iter().map(Into::into).collect(),
+                doc_lines: ty.doc_lines.clone(),
+                options: (&ty.options).into(),
+            },
+            Type::List(name, item) => Self::List {
+                name: name.clone(),
+                item: item.into(),
+            },
+            Type::Map(name, key, value) => Self::Map {
+                name: name.clone(),
+                key: key.into(),
+                value: value.into(),
+            },
+            Type::Primitive(primitive) => Self::Primitive {
+                primitive: *primitive,
+            },
+            Type::String => Self::String,
+            Type::Struct(ty) => Self::Struct {
+                ident: (&ty.ident).into(),
+                fields: ty.fields.iter().map(Into::into).collect(),
+                doc_lines: ty.doc_lines.clone(),
+                options: (&ty.options).into(),
+            },
+            Type::Tuple(items) => Self::Tuple {
+                items: items.iter().map(Into::into).collect(),
+            },
+            Type::Unit => Self::Unit,
+        }
+    }
+}
+
+impl TryFrom<TypeDef> for Type {
+    type Error = String;
+
+    fn try_from(ty: TypeDef) -> Result<Self, Self::Error> {
+        let ty = match ty {
+            TypeDef::Alias { name, target } => Type::Alias(name, target.into()),
+            TypeDef::Array { primitive, size } => Type::Array(primitive, size),
+            TypeDef::Container { name, item } => Type::Container(name, item.into()),
+            TypeDef::Custom {
+                ident,
+                rs_ty,
+                rs_dependencies,
+                serde_attrs,
+                ts_ty,
+                ts_declaration,
+            } => Type::Custom(CustomType {
+                ident: ident.into(),
+                rs_ty,
+                rs_dependencies: rs_dependencies
+                    .into_iter()
+                    .map(|(name, dependency)| (intern(name), dependency.into()))
+                    .collect(),
+                serde_attrs,
+                ts_ty,
+                ts_declaration,
+            }),
+            TypeDef::Enum {
+                ident,
+                variants,
+                doc_lines,
+                options,
+            } => Type::Enum(Enum {
+                ident: ident.into(),
+                variants: variants
+                    .into_iter()
+                    .map(Variant::try_from)
+                    .collect::<Result<_, _>>()?,
+                doc_lines,
+                options: options.try_into()?,
+            }),
+            TypeDef::List { name, item } => Type::List(name, item.into()),
+            TypeDef::Map { name, key, value } => Type::Map(name, key.into(), value.into()),
+            TypeDef::Primitive { primitive } => Type::Primitive(primitive),
+            TypeDef::String => Type::String,
+            TypeDef::Struct {
+                ident,
+                fields,
+                doc_lines,
+                options,
+            } => Type::Struct(Struct {
+                ident: ident.into(),
+                fields: fields
+                    .into_iter()
+                    .map(Field::try_from)
+                    .collect::<Result<_, _>>()?,
+                doc_lines,
+                options: options.try_into()?,
+            }),
+            TypeDef::Tuple { items } => Type::Tuple(items.into_iter().map(Into::into).collect()),
+            TypeDef::Unit => Type::Unit,
+        };
+        Ok(ty)
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CargoDependencyDef {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_features: Option<bool>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    features: BTreeSet<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    git: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    registry: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace: Option<bool>,
+}
+
+impl From<&CargoDependency> for CargoDependencyDef {
+    fn from(dependency: &CargoDependency) -> Self {
+        Self {
+            branch: dependency.branch.map(ToOwned::to_owned),
+            default_features: dependency.default_features,
+            features: dependency
+                .features
+                .iter()
+                .map(|feature| feature.to_string())
+                .collect(),
+            git: dependency.git.map(ToOwned::to_owned),
+            path: dependency.path.map(ToOwned::to_owned),
+            registry: dependency.registry.map(ToOwned::to_owned),
+            version: dependency.version.map(ToOwned::to_owned),
+            workspace: dependency.workspace,
+        }
+    }
+}
+
+impl From<CargoDependencyDef> for CargoDependency {
+    fn from(dependency: CargoDependencyDef) -> Self {
+        Self {
+            branch: dependency.branch.map(intern),
+            default_features: dependency.default_features,
+            features: dependency.features.into_iter().map(intern).collect(),
+            git: dependency.git.map(intern),
+            path: dependency.path.map(intern),
+            registry: dependency.registry.map(intern),
+            version: dependency.version.map(intern),
+            workspace: dependency.workspace,
+        }
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EnumOptionsDef {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    variant_casing: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_prop_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tag_prop_name: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    untagged: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rust_module: Option<String>,
+}
+
+impl From<&EnumOptions> for EnumOptionsDef {
+    fn from(options: &EnumOptions) -> Self {
+        Self {
+            variant_casing: casing_to_def(options.variant_casing),
+            content_prop_name: options.content_prop_name.clone(),
+            tag_prop_name: options.tag_prop_name.clone(),
+            untagged: options.untagged,
+            rust_module: options.rust_module.clone(),
+        }
+    }
+}
+
+impl TryFrom<EnumOptionsDef> for EnumOptions {
+    type Error = String;
+
+    fn try_from(options: EnumOptionsDef) -> Result<Self, Self::Error> {
+        Ok(Self {
+            variant_casing: casing_from_def(options.variant_casing)?,
+            content_prop_name: options.content_prop_name,
+            tag_prop_name: options.tag_prop_name,
+            untagged: options.untagged,
+            rust_module: options.rust_module,
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VariantDef {
+    name: String,
+    ty: TypeDef,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    doc_lines: Vec<String>,
+    #[serde(default)]
+    attrs: VariantAttrsDef,
+}
+
+impl From<&Variant> for VariantDef {
+    fn from(variant: &Variant) -> Self {
+        Self {
+            name: variant.name.clone(),
+            ty: (&variant.ty).into(),
+            doc_lines: variant.doc_lines.clone(),
+            attrs: VariantAttrsDef {
+                field_casing: casing_to_def(variant.attrs.field_casing),
+                rename: variant.attrs.rename.clone(),
+            },
+        }
+    }
+}
+
+impl TryFrom<VariantDef> for Variant {
+    type Error = String;
+
+    fn try_from(variant: VariantDef) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: variant.name,
+            ty: variant.ty.try_into()?,
+            doc_lines: variant.doc_lines,
+            attrs: VariantAttrs {
+                field_casing: casing_from_def(variant.attrs.field_casing)?,
+                rename: variant.attrs.rename,
+            },
+        })
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VariantAttrsDef {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    field_casing: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rename: Option<String>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StructOptionsDef {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    field_casing: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rust_module: Option<String>,
+}
+
+impl From<&StructOptions> for StructOptionsDef {
+    fn from(options: &StructOptions) -> Self {
+        Self {
+            field_casing: casing_to_def(options.field_casing),
+            rust_module: options.rust_module.clone(),
+        }
+    }
+}
+
+impl TryFrom<StructOptionsDef> for StructOptions {
+    type Error = String;
+
+    fn try_from(options: StructOptionsDef) -> Result<Self, Self::Error> {
+        Ok(Self {
+            field_casing: casing_from_def(options.field_casing)?,
+            rust_module: options.rust_module,
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldDef {
+    /// Tuple struct fields have no name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    ty: TypeIdentDef,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    doc_lines: Vec<String>,
+    #[serde(default)]
+    attrs: FieldAttrsDef,
+}
+
+impl From<&Field> for FieldDef {
+    fn from(field: &Field) -> Self {
+        Self {
+            name: field.name.clone(),
+            ty: (&field.ty).into(),
+            doc_lines: field.doc_lines.clone(),
+            attrs: FieldAttrsDef {
+                default: field.attrs.default.clone(),
+                deserialize_with: field.attrs.deserialize_with.clone(),
+                flatten: field.attrs.flatten,
+                rename: field.attrs.rename.clone(),
+                serialize_with: field.attrs.serialize_with.clone(),
+                skip_serializing_if: field.attrs.skip_serializing_if.clone(),
+            },
+        }
+    }
+}
+
+impl TryFrom<FieldDef> for Field {
+    type Error = String;
+
+    fn try_from(field: FieldDef) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: field.name,
+            ty: field.ty.into(),
+            doc_lines: field.doc_lines,
+            attrs: FieldAttrs {
+                default: field.attrs.default,
+                deserialize_with: field.attrs.deserialize_with,
+                flatten: field.attrs.flatten,
+                rename: field.attrs.rename,
+                serialize_with: field.attrs.serialize_with,
+                skip_serializing_if: field.attrs.skip_serializing_if,
+            },
+        })
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldAttrsDef {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deserialize_with: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    flatten: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rename: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    serialize_with: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    skip_serializing_if: Option<String>,
+}
+
+/// (De)serializes primitives using their Rust names, such as `"u32"`.
+mod primitive {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        primitive: &Primitive,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&primitive.name())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Primitive, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Primitive::from_str(&name).map_err(D::Error::custom)
+    }
+}
+
+fn casing_to_def(casing: Casing) -> Option<String> {
+    casing.as_maybe_str().map(ToOwned::to_owned)
+}
+
+fn casing_from_def(casing: Option<String>) -> Result<Casing, String> {
+    casing
+        .map(|casing| Casing::try_from(casing.as_str()))
+        .unwrap_or(Ok(Casing::Original))
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// `CargoDependency` only holds static strings, because it is normally
+/// constructed from literals in `Serializable` implementations. Strings read
+/// from a protocol are therefore leaked, but every distinct string is leaked
+/// only once, so parsing protocols repeatedly does not keep using more memory.
+fn intern(string: String) -> &'static str {
+    static INTERNED: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+    let mut interned = INTERNED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(existing) = interned.get(string.as_str()) {
+        return existing;
+    }
+
+    let leaked = Box::leak(string.into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protocol_json_roundtrip() {
+        let mut import_functions = FunctionList::new();
+        import_functions
+            .add_function("/// Logs a message.\nfn log(message: String, level: Option<u8>);");
+        let mut export_functions = FunctionList::new();
+        export_functions
+            .add_function("async fn fetch_points(ids: [u32; 3]) -> Result<Point<f64>, String>;");
+
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from("Point<T>"),
+            Type::from_item(
+                "/// A point.\n#[fp(rename_all = \"camelCase\")]\npub struct Point<T> { pub x_pos: T, #[fp(rename = \"y\")] pub y_pos: T }",
+            ),
+        );
+        types.insert(
+            TypeIdent::from("Shape"),
+            Type::from_item(
+                "#[fp(tag = \"type\")]\npub enum Shape { Circle { radius: f64 }, Empty }",
+            ),
+        );
+        types.insert(TypeIdent::from("String"), Type::String);
+        types.insert(TypeIdent::from("u8"), Type::Primitive(Primitive::U8));
+
+        let json = protocol_to_json(&import_functions, &export_functions, &types);
+        let parsed = protocol_from_json(&json).expect("Could not parse protocol JSON");
+
+        assert!(import_functions.iter().eq(parsed.import_functions.iter()));
+        assert!(export_functions.iter().eq(parsed.export_functions.iter()));
+        assert_eq!(types, parsed.types);
+        assert_eq!(
+            json,
+            protocol_to_json(
+                &parsed.import_functions,
+                &parsed.export_functions,
+                &parsed.types
+            )
+        );
+    }
+
+    #[test]
+    fn test_protocol_fingerprint_ignores_doc_lines() {
+        let mut documented = FunctionList::new();
+        documented.add_function("/// Logs a message.\nfn log(message: String);");
+        let mut undocumented = FunctionList::new();
+        undocumented.add_function("fn log(message: String);");
+        let mut changed = FunctionList::new();
+        changed.add_function("fn log(message: String, level: u8);");
+
+        let exports = FunctionList::new();
+        let types = TypeMap::new();
+        let fingerprint = protocol_fingerprint(&documented, &exports, &types);
+        assert_eq!(
+            fingerprint,
+            protocol_fingerprint(&undocumented, &exports, &types)
+        );
+        assert_ne!(
+            fingerprint,
+            protocol_fingerprint(&changed, &exports, &types)
+        );
+    }
+
+    #[test]
+    fn test_protocol_json_rejects_unknown_version() {
+        let json = r#"{"version":999,"importFunctions":[],"exportFunctions":[],"types":[]}"#;
+        let error = protocol_from_json(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Unsupported protocol JSON version"));
+    }
+
+    #[test]
+    fn test_intern_leaks_every_string_once() {
+        let first = intern("serde-well-known".to_owned());
+        let second = intern("serde-well-known".to_owned());
+        assert_eq!(first, "serde-well-known");
+        assert!(std::ptr::eq(first, second));
+    }
+}
@@ -0,0 +1,1148 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    generators::protocol_json::protocol_fingerprint,
+    prelude::Primitive,
+    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+) -> BTreeMap<PathBuf, String> {
+    BTreeMap::from([
+        (PathBuf::from("__init__.py"), generate_init_module()),
+        (
+            PathBuf::from("runtime.py"),
+            generate_runtime_module(&import_functions, &export_functions, &types),
+        ),
+        (PathBuf::from("types.py"), generate_type_bindings(&types)),
+    ])
+}
+
+fn generate_init_module() -> String {
+    "# ============================================= #
+# WebAssembly runtime for Python                #
+#                                               #
+# This file is generated. PLEASE DO NOT MODIFY. #
+# ============================================= #
+
+from . import types
+from .runtime import (
+    PROTOCOL_VERSION,
+    FPFunctionNotExported,
+    FPGuestPanic,
+    FPProtocolMismatch,
+    FPRuntimeError,
+    Imports,
+    Runtime,
+)
+"
+    .to_owned()
+}
+
+fn generate_runtime_module(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> String {
+    let protocol_version = protocol_fingerprint(import_functions, export_functions, types);
+
+    let has_async_import_functions = import_functions.iter().any(|function| function.is_async);
+    let has_async_export_functions = export_functions.iter().any(|function| function.is_async);
+
+    let import_decls = format_import_declarations(import_functions, types);
+    let export_wrappers = format_export_wrappers(export_functions, types);
+
+    let mut import_definitions = vec![
+        "linker.define_func(\"fp\", \"__fp_host_panic\", FuncType([i64], []), self._host_panic)"
+            .to_owned(),
+    ];
+    if has_async_export_functions {
+        import_definitions.push(
+            "linker.define_func(\n    \"fp\",\n    \"__fp_host_resolve_async_value\",\n    \
+            FuncType([i64, i64], []),\n    self._resolve_async_value,\n)"
+                .to_owned(),
+        );
+    }
+    import_definitions.extend(import_functions.iter().map(format_import_definition));
+    let import_definitions = join_lines(&import_definitions, "        ");
+    let import_wrappers = format_import_wrappers(import_functions);
+
+    let async_helpers = if has_async_import_functions || has_async_export_functions {
+        ASYNC_HELPERS
+    } else {
+        ""
+    };
+    let spawn_helper = if has_async_import_functions {
+        SPAWN_HELPER
+    } else {
+        ""
+    };
+    let await_helpers = if has_async_export_functions {
+        AWAIT_HELPERS
+    } else {
+        ""
+    };
+
+    format!(
+        "# ============================================= #
+# WebAssembly runtime for Python                #
+#                                               #
+# This file is generated. PLEASE DO NOT MODIFY. #
+# ============================================= #
+# pylint: disable=unused-argument,unused-variable
+
+from __future__ import annotations
+
+import asyncio
+import logging
+from typing import Any, Coroutine, Dict, Optional, Protocol, Set, Tuple, Union
+
+import msgpack
+from wasmtime import Engine, FuncType, Linker, Module, Store, ValType
+
+from . import types
+
+FatPtr = int
+
+# Extension bit in the length of a fat pointer, which indicates the length is
+# stored in a 4-byte little-endian header, directly followed by the data.
+FAT_PTR_LEN_HEADER = 0x0100_0000
+FAT_PTR_MAX_LEN = 0x00FF_FFFF
+
+# Fingerprint of the protocol these bindings were generated from.
+#
+# Plugins built against a different protocol are rejected by `Runtime()`.
+PROTOCOL_VERSION = {protocol_version:#018x}
+
+logger = logging.getLogger(__name__)
+
+
+class Imports(Protocol):
+    \"\"\"The host functions that may be imported by the plugin.\"\"\"
+{import_decls}
+
+class FPRuntimeError(Exception):
+    \"\"\"
+    Represents an unrecoverable error in the FP runtime.
+
+    After this, your only recourse is to create a new runtime, probably with a
+    different WASM plugin.
+    \"\"\"
+
+
+class FPGuestPanic(FPRuntimeError):
+    \"\"\"Represents a panic that occurred inside the WASM plugin.\"\"\"
+
+    def __init__(self, message: str, location: Optional[str]):
+        super().__init__(message)
+        self.message = message
+        self.location = location
+
+
+class FPProtocolMismatch(FPRuntimeError):
+    \"\"\"Indicates the plugin was built against a different protocol than the runtime.\"\"\"
+
+    def __init__(self, expected: int, found: int):
+        super().__init__(
+            \"Plugin was built against a different protocol \"
+            f\"(expected version {{expected:#018x}}, found {{found:#018x}})\"
+        )
+        self.expected = expected
+        self.found = found
+
+
+class FPFunctionNotExported(FPRuntimeError):
+    \"\"\"Indicates the plugin does not implement the exported function that was called.\"\"\"
+
+    def __init__(self, name: str):
+        super().__init__(f'Plugin did not export expected function: \"{{name}}\"')
+        self.name = name
+
+
+class Runtime:
+    \"\"\"
+    Runtime for executing a plugin.
+
+    Every runtime holds its own instance of the plugin, along with the `Imports`
+    implementation it was created with.
+    \"\"\"
+
+    def __init__(self, wasm_module: bytes, imports: Imports, engine: Optional[Engine] = None):
+        \"\"\"
+        Creates a runtime for executing the given plugin.
+
+        :param wasm_module: The raw WASM plugin.
+        :param imports: The host functions that may be imported by the plugin.
+        :param engine: The Wasmtime engine to compile the plugin with. A new
+            engine is created if none is given.
+        \"\"\"
+        engine = engine or Engine()
+        self._imports = imports
+        self._store = Store(engine)
+        self._pending: Dict[FatPtr, Union[asyncio.Future, FatPtr]] = {{}}
+        self._tasks: Set[asyncio.Task] = set()
+
+        linker = Linker(engine)
+        self._define_imports(linker)
+        instance = linker.instantiate(self._store, Module(engine, wasm_module))
+        self._exports = instance.exports(self._store)
+
+        self._memory = self._get_export(\"memory\")
+        self._malloc = self._get_export(\"__fp_malloc\")
+        self._free = self._get_export(\"__fp_free\")
+
+        # Reactor modules, such as plugins written in TinyGo, need to initialize
+        # themselves before any of their other exports may be called.
+        initialize = self._exports.get(\"_initialize\")
+        if initialize:
+            initialize(self._store)
+
+        # Plugins built with older bindings don't export their protocol version,
+        # so they cannot be verified.
+        protocol_version = self._exports.get(\"__fp_protocol_version\")
+        if protocol_version:
+            found_version = _to_unsigned(protocol_version(self._store), 64)
+            if found_version != PROTOCOL_VERSION:
+                raise FPProtocolMismatch(PROTOCOL_VERSION, found_version)
+{export_wrappers}
+    def _define_imports(self, linker: Linker) -> None:
+        i32, i64, f32, f64 = ValType.i32(), ValType.i64(), ValType.f32(), ValType.f64()
+{import_definitions}{import_wrappers}
+    def _host_panic(self, panic_ptr: FatPtr) -> None:
+        panic = self._parse_object(panic_ptr)
+        raise FPGuestPanic(panic[\"message\"], panic.get(\"location\"))
+{async_helpers}{spawn_helper}{await_helpers}
+    def _get_export(self, name: str) -> Any:
+        export = self._exports.get(name)
+        if export is None:
+            raise FPRuntimeError(f'Plugin did not export expected symbol: \"{{name}}\"')
+        return export
+
+    def _get_function(self, name: str) -> Any:
+        export = self._exports.get(f\"__fp_gen_{{name}}\")
+        if export is None:
+            raise FPFunctionNotExported(name)
+        return export
+
+    def _parse_object(self, fat_ptr: FatPtr) -> Any:
+        data = self._import_from_memory(fat_ptr)
+        if not data:
+            return None
+        return msgpack.unpackb(data, raw=False, strict_map_key=False)
+
+    def _serialize_object(self, value: Any) -> FatPtr:
+        return self._export_to_memory(msgpack.packb(value, use_bin_type=True))
+
+    def _export_to_memory(self, data: bytes) -> FatPtr:
+        fat_ptr = self._malloc(self._store, len(data))
+        ptr, _ = self._resolve_fat_ptr(fat_ptr)
+        self._memory.write(self._store, data, ptr)
+        return fat_ptr
+
+    def _import_from_memory(self, fat_ptr: FatPtr) -> bytes:
+        if fat_ptr == 0:
+            # This may happen with async calls that don't return a result:
+            return b\"\"
+
+        ptr, length = self._resolve_fat_ptr(fat_ptr)
+        data = bytes(self._memory.read(self._store, ptr, ptr + length))
+        self._free(self._store, fat_ptr)
+        return data
+
+    def _resolve_fat_ptr(self, fat_ptr: FatPtr) -> Tuple[int, int]:
+        ptr, length = _from_fat_ptr(fat_ptr)
+        if length == FAT_PTR_LEN_HEADER:
+            header = self._memory.read(self._store, ptr, ptr + 4)
+            return ptr + 4, int.from_bytes(header, \"little\")
+        if length > FAT_PTR_MAX_LEN:
+            raise FPRuntimeError(\"Unknown extension bits\")
+        return ptr, length
+
+
+def _from_fat_ptr(fat_ptr: FatPtr) -> Tuple[int, int]:
+    fat_ptr = _to_unsigned(fat_ptr, 64)
+    return fat_ptr >> 32, fat_ptr & 0xFFFF_FFFF
+
+
+def _to_signed(value: int, bits: int) -> int:
+    value = _to_unsigned(value, bits)
+    return value - (1 << bits) if value >= 1 << (bits - 1) else value
+
+
+def _to_unsigned(value: int, bits: int) -> int:
+    return value & ((1 << bits) - 1)
+"
+    )
+}
+
+const ASYNC_HELPERS: &str = "
+    def _create_async_value(self) -> FatPtr:
+        length = 12  # std::mem::size_of::<AsyncValue>()
+        fat_ptr = self._malloc(self._store, length)
+        ptr, _ = _from_fat_ptr(fat_ptr)
+        self._memory.write(self._store, bytes(length), ptr)
+        return fat_ptr
+";
+
+const SPAWN_HELPER: &str = "
+    def _spawn(self, name: str, async_value_ptr: FatPtr, coroutine: Coroutine) -> None:
+        resolve_future = self._get_export(\"__fp_guest_resolve_async_value\")
+
+        async def run() -> None:
+            try:
+                result = await coroutine
+            except Exception:  # pylint: disable=broad-except
+                logger.exception(
+                    'Unrecoverable exception trying to call async host function \"%s\"', name
+                )
+                return
+            resolve_future(self._store, async_value_ptr, self._serialize_object(result))
+
+        task = asyncio.get_running_loop().create_task(run())
+        self._tasks.add(task)
+        task.add_done_callback(self._tasks.discard)
+";
+
+const AWAIT_HELPERS: &str = "
+    async def _await_async_value(self, async_value_ptr: FatPtr) -> FatPtr:
+        result_ptr = self._pending.pop(async_value_ptr, None)
+        if isinstance(result_ptr, asyncio.Future):
+            raise FPRuntimeError(\"Already awaiting this value\")
+        if result_ptr is not None:
+            return result_ptr
+
+        future = asyncio.get_running_loop().create_future()
+        self._pending[async_value_ptr] = future
+        return await future
+
+    def _resolve_async_value(self, async_value_ptr: FatPtr, result_ptr: FatPtr) -> None:
+        future = self._pending.pop(async_value_ptr, None)
+        if future is None:
+            self._pending[async_value_ptr] = result_ptr
+        elif isinstance(future, asyncio.Future):
+            future.set_result(result_ptr)
+        else:
+            raise FPRuntimeError(\"Tried to resolve invalid async value\")
+";
+
+fn format_import_declarations(import_functions: &FunctionList, types: &TypeMap) -> String {
+    import_functions
+        .iter()
+        .map(|function| {
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        ", {}: {}",
+                        get_arg_name(&arg.name),
+                        format_ident(&arg.ty, types, Scope::RuntimeModule)
+                    )
+                })
+                .collect::<String>();
+            format!(
+                "\n    {}def {}(self{}) -> {}:\n{}        ...\n",
+                if function.is_async { "async " } else { "" },
+                get_arg_name(&function.name),
+                args,
+                format_return_type(function, types),
+                format_docstring(&function.doc_lines, "        ")
+            )
+        })
+        .collect()
+}
+
+fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> String {
+    export_functions
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            let args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        ", {}: {}",
+                        get_arg_name(&arg.name),
+                        format_ident(&arg.ty, types, Scope::RuntimeModule)
+                    )
+                })
+                .collect::<String>();
+
+            let mut body = vec![format!("export_fn = self._get_function(\"{name}\")")];
+            body.extend(
+                function
+                    .args
+                    .iter()
+                    .filter(|arg| !arg.ty.is_primitive())
+                    .map(|arg| {
+                        let arg_name = get_arg_name(&arg.name);
+                        // Arrays are passed as lists, because byte strings
+                        // cannot be deserialized to Rust arrays.
+                        let value = if arg.ty.is_array() {
+                            format!("list({arg_name})")
+                        } else {
+                            arg_name
+                        };
+                        format!(
+                            "{} = self._serialize_object({})",
+                            get_pointer_name(&arg.name),
+                            value
+                        )
+                    }),
+            );
+
+            let call_args = function
+                .args
+                .iter()
+                .map(|arg| match arg.ty.as_primitive() {
+                    Some(primitive) => {
+                        format!(
+                            ", {}",
+                            to_wasm_primitive(primitive, &get_arg_name(&arg.name))
+                        )
+                    }
+                    None => format!(", {}", get_pointer_name(&arg.name)),
+                })
+                .collect::<String>();
+            let fn_call = format!("export_fn(self._store{call_args})");
+            if function.is_async {
+                body.push(format!("async_value_ptr = {fn_call}"));
+                let result = "self._parse_object(await self._await_async_value(async_value_ptr))";
+                if function.return_type.is_some() {
+                    body.push(format!("return {result}"));
+                } else {
+                    body.push(result.to_owned());
+                }
+            } else {
+                match &function.return_type {
+                    None => body.push(fn_call),
+                    Some(ty) => match ty.as_primitive() {
+                        Some(primitive) => body.push(format!(
+                            "return {}",
+                            from_wasm_primitive(primitive, &fn_call)
+                        )),
+                        None => body.push(format!("return self._parse_object({fn_call})")),
+                    },
+                }
+            }
+
+            format!(
+                "\n    {}def {}(self{}) -> {}:\n{}{}",
+                if function.is_async { "async " } else { "" },
+                get_arg_name(name),
+                args,
+                format_return_type(function, types),
+                format_docstring(&function.doc_lines, "        "),
+                join_lines(&body, "        ")
+            )
+        })
+        .collect()
+}
+
+fn format_import_definition(function: &Function) -> String {
+    let params = function
+        .args
+        .iter()
+        .map(|arg| match arg.ty.as_primitive() {
+            Some(primitive) => format_wasm_type(primitive),
+            None => "i64",
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let results = match &function.return_type {
+        _ if function.is_async => "i64",
+        None => "",
+        Some(ty) => match ty.as_primitive() {
+            Some(primitive) => format_wasm_type(primitive),
+            None => "i64",
+        },
+    };
+    let name = &function.name;
+    format!(
+        "linker.define_func(\n    \"fp\",\n    \"__fp_gen_{name}\",\n    \
+        FuncType([{params}], [{results}]),\n    self._fp_gen_{name},\n)"
+    )
+}
+
+fn format_import_wrappers(import_functions: &FunctionList) -> String {
+    import_functions
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            let params = function
+                .args
+                .iter()
+                .map(|arg| match arg.ty.as_primitive() {
+                    Some(primitive) => format!(
+                        ", {}: {}",
+                        get_arg_name(&arg.name),
+                        format_plain_primitive(primitive)
+                    ),
+                    None => format!(", {}: FatPtr", get_pointer_name(&arg.name)),
+                })
+                .collect::<String>();
+            let return_type = match &function.return_type {
+                _ if function.is_async => "FatPtr",
+                None => "None",
+                Some(ty) => match ty.as_primitive() {
+                    Some(primitive) => format_plain_primitive(primitive),
+                    None => "FatPtr",
+                },
+            };
+
+            let mut body: Vec<_> = function
+                .args
+                .iter()
+                .filter(|arg| !arg.ty.is_primitive())
+                .map(|arg| {
+                    format!(
+                        "{} = self._parse_object({})",
+                        get_arg_name(&arg.name),
+                        get_pointer_name(&arg.name)
+                    )
+                })
+                .collect();
+
+            let call_args = function
+                .args
+                .iter()
+                .map(|arg| {
+                    let arg_name = get_arg_name(&arg.name);
+                    match arg.ty.as_primitive() {
+                        Some(primitive) => from_wasm_primitive(primitive, &arg_name),
+                        None => arg_name,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let fn_call = format!("self._imports.{}({})", get_arg_name(name), call_args);
+            if function.is_async {
+                body.push("async_value_ptr = self._create_async_value()".to_owned());
+                body.push(format!(
+                    "self._spawn(\"{name}\", async_value_ptr, {fn_call})"
+                ));
+                body.push("return async_value_ptr".to_owned());
+            } else {
+                match &function.return_type {
+                    None => body.push(fn_call),
+                    Some(ty) => match ty.as_primitive() {
+                        Some(primitive) => {
+                            body.push(format!("return {}", to_wasm_primitive(primitive, &fn_call)))
+                        }
+                        None => body.push(format!("return self._serialize_object({fn_call})")),
+                    },
+                }
+            }
+
+            format!(
+                "\n    def _fp_gen_{}(self{}) -> {}:\n{}",
+                name,
+                params,
+                return_type,
+                join_lines(&body, "        ")
+            )
+        })
+        .collect()
+}
+
+fn format_return_type(function: &Function, types: &TypeMap) -> String {
+    match &function.return_type {
+        Some(ty) => format_ident(ty, types, Scope::RuntimeModule),
+        None => "None".to_owned(),
+    }
+}
+
+fn generate_type_bindings(types: &TypeMap) -> String {
+    let mut definitions = Vec::new();
+    for ty in types.values() {
+        match ty {
+            Type::Alias(name, ident) => {
+                definitions.push(format_alias(name, &[], ident, types));
+            }
+            Type::Custom(CustomType {
+                ts_declaration: Some(_),
+                ..
+            }) => {}
+            Type::Enum(ty) => definitions.append(&mut create_enum_definitions(ty, types)),
+            Type::Struct(ty) => definitions.push(create_struct_definition(ty, types)),
+            _ => {}
+        }
+    }
+
+    // Aliases that refer to another type directly cannot use a forward
+    // reference, so they need to come after the definition they refer to:
+    let (mut ordered, mut deferred): (Vec<_>, Vec<_>) = definitions
+        .into_iter()
+        .partition(|definition| definition.dependency.is_none());
+    let mut defined: BTreeSet<_> = ordered.iter().map(|def| def.name.clone()).collect();
+    while !deferred.is_empty() {
+        let pending: BTreeSet<_> = deferred.iter().map(|def| def.name.clone()).collect();
+        let (ready, rest): (Vec<_>, Vec<_>) = deferred.into_iter().partition(|def| {
+            def.dependency
+                .as_ref()
+                .map(|dependency| defined.contains(dependency) || !pending.contains(dependency))
+                .unwrap_or(true)
+        });
+        if ready.is_empty() {
+            ordered.extend(rest);
+            break;
+        }
+        defined.extend(ready.iter().map(|def| def.name.clone()));
+        ordered.extend(ready);
+        deferred = rest;
+    }
+
+    format!(
+        "# ============================================= #
+# Types for WebAssembly runtime                 #
+#                                               #
+# This file is generated. PLEASE DO NOT MODIFY. #
+# ============================================= #
+
+from typing import Any, Dict, List, Literal, Optional, Tuple, TypedDict, Union
+
+try:
+    from typing import NotRequired
+except ImportError:  # Python < 3.11
+    from typing_extensions import NotRequired
+
+{}\n",
+        ordered
+            .into_iter()
+            .map(|definition| definition.code)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    )
+}
+
+/// A top-level definition in the `types.py` module.
+struct Definition {
+    name: String,
+
+    /// Name of another definition that needs to be defined before this one.
+    dependency: Option<String>,
+
+    code: String,
+}
+
+impl Definition {
+    fn new(name: impl Into<String>, code: String) -> Self {
+        Self {
+            name: name.into(),
+            dependency: None,
+            code,
+        }
+    }
+}
+
+fn format_alias(
+    name: &str,
+    doc_lines: &[String],
+    ident: &TypeIdent,
+    types: &TypeMap,
+) -> Definition {
+    let comments = format_comments(doc_lines, "");
+    match get_direct_reference(ident, types) {
+        Some(dependency) => Definition {
+            name: name.to_owned(),
+            code: format!("{comments}{name} = {dependency}"),
+            dependency: Some(dependency),
+        },
+        None => Definition::new(
+            name,
+            format!(
+                "{}{} = {}",
+                comments,
+                name,
+                format_ident(ident, types, Scope::TypesModule)
+            ),
+        ),
+    }
+}
+
+fn create_enum_definitions(ty: &Enum, types: &TypeMap) -> Vec<Definition> {
+    let name = &ty.ident.name;
+    let options = &ty.options;
+    let mut definitions = Vec::new();
+    let mut members = Vec::new();
+    for variant in &ty.variants {
+        let variant_name = get_variant_name(variant, options);
+        let aux_name = format!("{}_{}", name, get_variable_name(&variant.name));
+        let tag_entry = options
+            .tag_prop_name
+            .as_ref()
+            .map(|tag| format!("\"{tag}\": Literal[\"{variant_name}\"]"));
+
+        match &variant.ty {
+            Type::Unit => match &tag_entry {
+                _ if options.untagged => members.push("None".to_owned()),
+                Some(tag_entry) => {
+                    definitions.push(format_typed_dict(
+                        &aux_name,
+                        &[],
+                        std::slice::from_ref(tag_entry),
+                    ));
+                    members.push(format!("\"{aux_name}\""));
+                }
+                None => members.push(format!("Literal[\"{variant_name}\"]")),
+            },
+            Type::Struct(variant_ty) => {
+                let fields =
+                    format_struct_fields(&variant_ty.fields, types, variant.attrs.field_casing);
+                let fields = match fields {
+                    Some(fields) => fields,
+                    None => {
+                        definitions.push(Definition::new(
+                            aux_name.clone(),
+                            format!("{aux_name} = Dict[str, Any]"),
+                        ));
+                        members.push(format!("\"{aux_name}\""));
+                        continue;
+                    }
+                };
+
+                match (&tag_entry, &options.content_prop_name) {
+                    _ if options.untagged => {
+                        definitions.push(format_typed_dict(&aux_name, &variant.doc_lines, &fields));
+                    }
+                    (Some(tag_entry), Some(content)) => {
+                        let fields_name = format!("{aux_name}Fields");
+                        definitions.push(format_typed_dict(&fields_name, &[], &fields));
+                        definitions.push(format_typed_dict(
+                            &aux_name,
+                            &variant.doc_lines,
+                            &[
+                                tag_entry.clone(),
+                                format!("\"{content}\": \"{fields_name}\""),
+                            ],
+                        ));
+                    }
+                    (Some(tag_entry), None) => {
+                        let mut entries = vec![tag_entry.clone()];
+                        entries.extend(fields);
+                        definitions.push(format_typed_dict(
+                            &aux_name,
+                            &variant.doc_lines,
+                            &entries,
+                        ));
+                    }
+                    (None, _) => {
+                        let fields_name = format!("{aux_name}Fields");
+                        definitions.push(format_typed_dict(&fields_name, &[], &fields));
+                        definitions.push(format_typed_dict(
+                            &aux_name,
+                            &variant.doc_lines,
+                            &[format!("\"{variant_name}\": \"{fields_name}\"")],
+                        ));
+                    }
+                }
+                members.push(format!("\"{aux_name}\""));
+            }
+            Type::Tuple(items) => {
+                let item = if items.len() == 1 {
+                    format_ident(&items[0], types, Scope::TypesModule)
+                } else {
+                    format_tuple(items, types, Scope::TypesModule)
+                };
+
+                match (&tag_entry, &options.content_prop_name) {
+                    _ if options.untagged => {
+                        members.push(item);
+                        continue;
+                    }
+                    (Some(tag_entry), Some(content)) => {
+                        definitions.push(format_typed_dict(
+                            &aux_name,
+                            &variant.doc_lines,
+                            &[tag_entry.clone(), format!("\"{content}\": {item}")],
+                        ));
+                    }
+                    (Some(tag_entry), None) => {
+                        // The tag is inlined into the item, which we can only
+                        // express if we know its fields:
+                        let fields = match types.get(&items[0]) {
+                            Some(Type::Struct(item_ty)) => format_struct_fields(
+                                &item_ty.fields,
+                                types,
+                                item_ty.options.field_casing,
+                            ),
+                            _ => None,
+                        };
+                        match fields {
+                            Some(fields) => {
+                                let mut entries = vec![tag_entry.clone()];
+                                entries.extend(fields);
+                                definitions.push(format_typed_dict(
+                                    &aux_name,
+                                    &variant.doc_lines,
+                                    &entries,
+                                ));
+                            }
+                            None => definitions.push(Definition::new(
+                                aux_name.clone(),
+                                format!("{aux_name} = Dict[str, Any]"),
+                            )),
+                        }
+                    }
+                    (None, _) => {
+                        definitions.push(format_typed_dict(
+                            &aux_name,
+                            &variant.doc_lines,
+                            &[format!("\"{variant_name}\": {item}")],
+                        ));
+                    }
+                }
+                members.push(format!("\"{aux_name}\""));
+            }
+            _ => {}
+        }
+    }
+
+    let union = match members.len() {
+        0 => "Any".to_owned(),
+        _ => format!(
+            "Union[\n{}]",
+            members
+                .iter()
+                .map(|member| format!("    {member},\n"))
+                .collect::<String>()
+        ),
+    };
+    definitions.push(Definition::new(
+        name.clone(),
+        format!("{}{} = {}", format_comments(&ty.doc_lines, ""), name, union),
+    ));
+    definitions
+}
+
+fn create_struct_definition(ty: &Struct, types: &TypeMap) -> Definition {
+    let name = &ty.ident.name;
+    let is_newtype = ty.fields.len() == 1 && ty.fields.iter().all(|field| field.name.is_none());
+    if is_newtype {
+        return format_alias(name, &ty.doc_lines, &ty.fields[0].ty, types);
+    }
+
+    let is_tuple_struct =
+        !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none());
+    if is_tuple_struct {
+        let items: Vec<_> = ty.fields.iter().map(|field| field.ty.clone()).collect();
+        return Definition::new(
+            name.clone(),
+            format!(
+                "{}{} = {}",
+                format_comments(&ty.doc_lines, ""),
+                name,
+                format_tuple(&items, types, Scope::TypesModule)
+            ),
+        );
+    }
+
+    match format_struct_fields(&ty.fields, types, ty.options.field_casing) {
+        Some(fields) => format_typed_dict(name, &ty.doc_lines, &fields),
+        None => Definition::new(
+            name.clone(),
+            format!(
+                "{}{} = Dict[str, Any]",
+                format_comments(&ty.doc_lines, ""),
+                name
+            ),
+        ),
+    }
+}
+
+fn format_typed_dict(name: &str, doc_lines: &[String], entries: &[String]) -> Definition {
+    let entries = if entries.is_empty() {
+        "{}".to_owned()
+    } else {
+        format!(
+            "{{\n{}    }}",
+            entries
+                .iter()
+                .map(|entry| format!("{},\n", indent_lines(entry, "        ")))
+                .collect::<String>()
+        )
+    };
+    Definition::new(
+        name,
+        format!(
+            "{}{} = TypedDict(\n    \"{}\",\n    {},\n)",
+            format_comments(doc_lines, ""),
+            name,
+            name,
+            entries
+        ),
+    )
+}
+
+/// Formats the entries for a `TypedDict` with the given fields.
+///
+/// Returns `None` if the fields cannot be expressed as a `TypedDict`, which
+/// happens when a field is flattened and its type is not a struct.
+fn format_struct_fields(fields: &[Field], types: &TypeMap, casing: Casing) -> Option<Vec<String>> {
+    let mut entries = Vec::new();
+    for field in fields {
+        if field.attrs.flatten {
+            match types.get(&field.ty) {
+                Some(Type::Struct(flattened)) => entries.append(&mut format_struct_fields(
+                    &flattened.fields,
+                    types,
+                    flattened.options.field_casing,
+                )?),
+                _ => return None,
+            }
+            continue;
+        }
+
+        let has_skip_serializing_attribute = field.attrs.skip_serializing_if.is_some();
+        let ty = format_ident(&field.ty, types, Scope::TypesModule);
+        let ty = if has_skip_serializing_attribute {
+            format!("NotRequired[{ty}]")
+        } else {
+            ty
+        };
+        entries.push(format!(
+            "{}\"{}\": {}",
+            format_comments(&field.doc_lines, ""),
+            get_field_name(field, casing),
+            ty
+        ));
+    }
+    Some(entries)
+}
+
+#[derive(Clone, Copy)]
+enum Scope {
+    /// References to other types use forward references, because they may
+    /// be defined further down in the `types.py` module.
+    TypesModule,
+
+    /// References to other types use the `types.` prefix.
+    RuntimeModule,
+}
+
+/// Formats a type so it's a valid Python type annotation.
+fn format_ident(ident: &TypeIdent, types: &TypeMap, scope: Scope) -> String {
+    match types.get(ident) {
+        Some(ty) => format_type_with_ident(ty, ident, types, scope),
+        None => "Any".to_owned(), // Must be a generic.
+    }
+}
+
+fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap, scope: Scope) -> String {
+    let format_arg = |index: usize| match ident.generic_args.get(index) {
+        Some((arg, _)) => format_ident(arg, types, scope),
+        None => "Any".to_owned(),
+    };
+
+    match ty {
+        Type::Alias(name, _) => format_reference(name, scope),
+        Type::Array(primitive, _) => format!("List[{}]", format_plain_primitive(*primitive)),
+        Type::Container(name, _) => {
+            if name == "Option" {
+                format!("Optional[{}]", format_arg(0))
+            } else {
+                format_arg(0)
+            }
+        }
+        Type::Custom(custom) => format_custom_type(custom).to_owned(),
+        Type::Enum(_) | Type::Struct(_) => format_reference(&ident.name, scope),
+        Type::List(_, _) => format!("List[{}]", format_arg(0)),
+        Type::Map(_, _, _) => format!("Dict[{}, {}]", format_arg(0), format_arg(1)),
+        Type::Primitive(primitive) => format_plain_primitive(*primitive).to_owned(),
+        Type::String => "str".to_owned(),
+        Type::Tuple(items) => format_tuple(items, types, scope),
+        Type::Unit => "None".to_owned(),
+    }
+}
+
+fn format_reference(name: &str, scope: Scope) -> String {
+    match scope {
+        Scope::TypesModule => format!("\"{name}\""),
+        Scope::RuntimeModule => format!("types.{name}"),
+    }
+}
+
+fn format_tuple(items: &[TypeIdent], types: &TypeMap, scope: Scope) -> String {
+    if items.is_empty() {
+        "Tuple[()]".to_owned()
+    } else {
+        format!(
+            "Tuple[{}]",
+            items
+                .iter()
+                .map(|item| format_ident(item, types, scope))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Returns the name of the type definition the identifier refers to, if it
+/// refers to one directly (without being wrapped in another type).
+fn get_direct_reference(ident: &TypeIdent, types: &TypeMap) -> Option<String> {
+    match types.get(ident)? {
+        Type::Alias(name, _) => Some(name.clone()),
+        Type::Enum(_) | Type::Struct(_) => Some(ident.name.clone()),
+        Type::Container(name, _) if name != "Option" => {
+            get_direct_reference(&ident.generic_args.first()?.0, types)
+        }
+        _ => None,
+    }
+}
+
+/// Custom types only specify their TypeScript type, so we map the ones we
+/// recognize and fall back to `Any` for the rest.
+fn format_custom_type(custom: &CustomType) -> &'static str {
+    match custom.ts_ty.as_str() {
+        "ArrayBuffer" | "Uint8Array" => "bytes",
+        "boolean" => "bool",
+        "number" => "float",
+        "string" => "str",
+        _ => "Any",
+    }
+}
+
+fn format_plain_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::F32 | Primitive::F64 => "float",
+        _ => "int",
+    }
+}
+
+fn format_wasm_type(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::F32 => "f32",
+        Primitive::F64 => "f64",
+        Primitive::I64 | Primitive::U64 => "i64",
+        _ => "i32",
+    }
+}
+
+/// Converts a primitive value from Python to the representation Wasmtime
+/// expects, which is always signed.
+fn to_wasm_primitive(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("int({value})"),
+        Primitive::U32 => format!("_to_signed({value}, 32)"),
+        Primitive::U64 => format!("_to_signed({value}, 64)"),
+        _ => value.to_owned(),
+    }
+}
+
+/// Converts a primitive value received from Wasmtime to its Python
+/// representation.
+fn from_wasm_primitive(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("_to_unsigned({value}, 8) != 0"),
+        Primitive::I8 => format!("_to_signed({value}, 8)"),
+        Primitive::I16 => format!("_to_signed({value}, 16)"),
+        Primitive::U8 => format!("_to_unsigned({value}, 8)"),
+        Primitive::U16 => format!("_to_unsigned({value}, 16)"),
+        Primitive::U32 => format!("_to_unsigned({value}, 32)"),
+        Primitive::U64 => format!("_to_unsigned({value}, 64)"),
+        _ => value.to_owned(),
+    }
+}
+
+fn format_docstring(doc_lines: &[String], indent: &str) -> String {
+    if doc_lines.is_empty() {
+        return String::new();
+    }
+
+    let lines = doc_lines
+        .iter()
+        .map(|line| {
+            let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+            if line.is_empty() {
+                "\n".to_owned()
+            } else {
+                format!("{indent}{line}\n")
+            }
+        })
+        .collect::<String>();
+    format!("{indent}\"\"\"\n{lines}{indent}\"\"\"\n")
+}
+
+fn format_comments(doc_lines: &[String], indent: &str) -> String {
+    doc_lines
+        .iter()
+        .map(|line| format!("{}#{}\n", indent, line.trim_end()))
+        .collect()
+}
+
+fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(field.name.as_deref().unwrap_or_default()))
+    }
+}
+
+fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+/// Returns a name that can be used for an argument or method in Python.
+fn get_arg_name(name: &str) -> String {
+    let name = get_variable_name(name);
+    if PYTHON_KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_owned()
+    }
+}
+
+fn get_pointer_name(name: &str) -> String {
+    format!("{}_ptr", get_variable_name(name))
+}
+
+fn indent_lines(lines: &str, indent: &str) -> String {
+    lines
+        .trim_end()
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn join_lines(lines: &[String], indent: &str) -> String {
+    lines
+        .iter()
+        .map(|line| format!("{}\n", indent_lines(line, indent)))
+        .collect()
+}
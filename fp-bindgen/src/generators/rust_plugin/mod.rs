@@ -1,5 +1,5 @@
 use crate::functions::Function;
-use crate::generators::RustPluginConfigValue;
+use crate::generators::{protocol_json::protocol_fingerprint, RustPluginConfigValue};
 use crate::types::is_runtime_bound;
 use crate::{
     functions::FunctionList,
@@ -9,7 +9,7 @@ use crate::{
 use std::iter::FromIterator;
 use std::{
     collections::{BTreeMap, BTreeSet},
-    fs,
+    path::PathBuf,
 };
 
 pub(crate) fn generate_bindings(
@@ -17,20 +17,29 @@ pub(crate) fn generate_bindings(
     export_functions: FunctionList,
     types: TypeMap,
     config: RustPluginConfig,
-    path: &str,
-) {
-    let src_path = format!("{path}/src");
-    fs::create_dir_all(&src_path).expect("Could not create output directory");
+) -> BTreeMap<PathBuf, String> {
+    let protocol_version = protocol_fingerprint(&import_functions, &export_functions, &types);
 
-    generate_cargo_file(config, &import_functions, &types, path);
-
-    generate_type_bindings(&types, &src_path);
-    generate_imported_function_bindings(import_functions, &types, &src_path);
-    generate_exported_function_bindings(export_functions, &types, &src_path);
-
-    write_bindings_file(
-        format!("{src_path}/lib.rs"),
-        "#![allow(unused_imports)]
+    BTreeMap::from([
+        (
+            PathBuf::from("Cargo.toml"),
+            generate_cargo_file(config, &import_functions, &types),
+        ),
+        (
+            PathBuf::from("src/types.rs"),
+            generate_type_bindings(&types),
+        ),
+        (
+            PathBuf::from("src/import.rs"),
+            generate_imported_function_bindings(import_functions, &types),
+        ),
+        (
+            PathBuf::from("src/export.rs"),
+            generate_exported_function_bindings(export_functions, protocol_version, &types),
+        ),
+        (
+            PathBuf::from("src/lib.rs"),
+            "#![allow(unused_imports)]
 #[rustfmt::skip]
 mod export;
 #[rustfmt::skip]
@@ -43,16 +52,17 @@ pub use import::*;
 pub use types::*;
 
 pub use fp_bindgen_support::*;
-",
-    );
+"
+            .to_owned(),
+        ),
+    ])
 }
 
 fn generate_cargo_file(
     config: RustPluginConfig,
     import_functions: &FunctionList,
     types: &TypeMap,
-    path: &str,
-) {
+) -> String {
     let requires_async = import_functions.iter().any(|function| function.is_async);
 
     let mut support_features = BTreeSet::from(["guest"]);
@@ -97,31 +107,31 @@ fn generate_cargo_file(
         dependencies.insert(name, dependency);
     }
 
-    write_bindings_file(
-        format!("{path}/Cargo.toml"),
-        format!(
-            "[package]
+    format!(
+        "[package]
 {}{}{}edition = \"2018\"
 {}{}{}
 [dependencies]
 {}
+
+[features]
+mock_host = [\"fp-bindgen-support/mock_host\"]
 ",
-            format_cargo_key("name", config.name),
-            format_cargo_key("version", config.version),
-            format_cargo_key("authors", config.authors),
-            format_cargo_key("description", config.description),
-            format_cargo_key("readme", config.readme),
-            format_cargo_key("license", config.license),
-            dependencies
-                .iter()
-                .map(|(name, value)| format!("{name} = {value}"))
-                .collect::<Vec<_>>()
-                .join("\n")
-        ),
-    );
+        format_cargo_key("name", config.name),
+        format_cargo_key("version", config.version),
+        format_cargo_key("authors", config.authors),
+        format_cargo_key("description", config.description),
+        format_cargo_key("readme", config.readme),
+        format_cargo_key("license", config.license),
+        dependencies
+            .iter()
+            .map(|(name, value)| format!("{name} = {value}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
 }
 
-pub fn generate_type_bindings(types: &TypeMap, path: &str) {
+pub fn generate_type_bindings(types: &TypeMap) -> String {
     let std_types: BTreeSet<_> = types.values().filter_map(collect_std_types).collect();
     let std_imports = if std_types.is_empty() {
         "".to_owned()
@@ -178,16 +188,13 @@ pub fn generate_type_bindings(types: &TypeMap, path: &str) {
         })
         .collect::<Vec<_>>();
 
-    write_bindings_file(
-        format!("{path}/types.rs"),
-        format!(
-            "#![allow(dead_code, unused_imports)]\n\
-            use serde::{{Deserialize, Serialize}};\n{}\n{}{}\n",
-            std_imports,
-            type_imports,
-            type_defs.join("\n\n")
-        ),
-    );
+    format!(
+        "#![allow(dead_code, unused_imports)]\n\
+        use serde::{{Deserialize, Serialize}};\n{}\n{}{}\n",
+        std_imports,
+        type_imports,
+        type_defs.join("\n\n")
+    )
 }
 
 pub fn format_doc_lines(doc_lines: &[String]) -> String {
@@ -202,31 +209,45 @@ pub fn format_modifiers(function: &Function) -> String {
     if function.is_async { "async " } else { "" }.to_owned()
 }
 
-fn format_functions(functions: FunctionList, types: &TypeMap, macro_path: &str) -> String {
+fn format_functions(functions: &FunctionList, types: &TypeMap, attrs: &[&str]) -> String {
     functions
         .iter()
         .map(|func| {
-            let name = &func.name;
             let doc = format_doc_lines(&func.doc_lines);
-            let modifiers = format_modifiers(func);
-            let args_with_types = func
-                .args
+            let attrs = attrs
                 .iter()
-                .map(|arg| format!("{}: {}", arg.name, format_ident(&arg.ty, types)))
+                .map(|attr| format!("#[{attr}]\n"))
                 .collect::<Vec<_>>()
-                .join(", ");
-            let return_type = match &func.return_type {
-                Some(ty) => format!(" -> {}", format_ident(ty, types)),
-                None => "".to_owned(),
-            };
-            format!(
-                "{doc}#[{macro_path}]\npub {modifiers}fn {name}({args_with_types}){return_type};",
-            )
+                .join("");
+            let modifiers = format_modifiers(func);
+            let signature = format_signature(func, None, types);
+            format!("{doc}{attrs}pub {modifiers}{signature};")
         })
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Formats the signature of the given function, starting from the `fn`
+/// keyword. If a receiver is given, it is added as the first argument.
+fn format_signature(func: &Function, receiver: Option<&str>, types: &TypeMap) -> String {
+    let name = &func.name;
+    let args_with_types = receiver
+        .map(str::to_owned)
+        .into_iter()
+        .chain(
+            func.args
+                .iter()
+                .map(|arg| format!("{}: {}", arg.name, format_ident(&arg.ty, types))),
+        )
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = match &func.return_type {
+        Some(ty) => format!(" -> {}", format_ident(ty, types)),
+        None => "".to_owned(),
+    };
+    format!("fn {name}({args_with_types}){return_type}")
+}
+
 pub fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
     match types.get(ident) {
         Some(ty) => format_type_with_ident(ty, ident, types),
@@ -287,40 +308,129 @@ fn format_bounds(bounds: &[String]) -> String {
         .join(" + ")
 }
 
-fn generate_imported_function_bindings(
-    import_functions: FunctionList,
-    types: &TypeMap,
-    path: &str,
-) {
-    write_bindings_file(
-        format!("{path}/import.rs"),
-        format!(
-            "use crate::types::*;\n\n{}\n",
-            format_functions(
-                import_functions,
-                types,
+fn generate_imported_function_bindings(import_functions: FunctionList, types: &TypeMap) -> String {
+    format!(
+        "use crate::types::*;\n\n{}\n\n{}\n",
+        format_functions(
+            &import_functions,
+            types,
+            &[
+                "cfg(not(feature = \"mock_host\"))",
                 "fp_bindgen_support::fp_import_signature"
+            ]
+        ),
+        format_mock_imports(&import_functions, types)
+    )
+}
+
+/// Generates the `MockImports` trait, along with implementations of the
+/// imported functions that dispatch to it. These are used instead of the Wasm
+/// imports when the `mock_host` feature is enabled, so that plugins can be
+/// tested natively.
+fn format_mock_imports(import_functions: &FunctionList, types: &TypeMap) -> String {
+    let trait_methods = import_functions
+        .iter()
+        .map(|func| {
+            let doc = format_doc_lines(&func.doc_lines);
+            let signature = format_signature(func, Some("&self"), types);
+            format!(
+                "{doc}#[allow(unused_variables)]\n\
+                {signature} {{\n    \
+                    fp_bindgen_support::mock_host::unimplemented_import(\"{}\")\n\
+                }}",
+                func.name
+            )
+            .split('\n')
+            .map(|line| format!("    {line}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let functions = import_functions
+        .iter()
+        .map(|func| {
+            let doc = format_doc_lines(&func.doc_lines);
+            let modifiers = format_modifiers(func);
+            let signature = format_signature(func, None, types);
+            let args = func
+                .args
+                .iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "{doc}#[cfg(feature = \"mock_host\")]\n\
+                pub {modifiers}{signature} {{\n    \
+                    mock_imports().{}({args})\n\
+                }}",
+                func.name
             )
+        })
+        .collect::<Vec<_>>();
+
+    let mut items = vec![
+        format!(
+            "/// Mock implementations of the imported functions, which are called instead of\n\
+            /// the runtime when the `mock_host` feature is enabled.\n\
+            ///\n\
+            /// Functions that are not implemented panic when they are called. Use\n\
+            /// `set_mock_imports()` to register an implementation for the current thread.\n\
+            #[cfg(feature = \"mock_host\")]\n\
+            pub trait MockImports {{{}}}",
+            if trait_methods.is_empty() {
+                "".to_owned()
+            } else {
+                format!("\n{trait_methods}\n")
+            }
         ),
-    );
+        "/// Registers the mock implementations of the imported functions for the\n\
+        /// current thread.\n\
+        #[cfg(feature = \"mock_host\")]\n\
+        pub fn set_mock_imports(imports: impl MockImports + 'static) {\n    \
+            let imports: Box<dyn MockImports> = Box::new(imports);\n    \
+            fp_bindgen_support::mock_host::set_mock_imports(std::rc::Rc::new(imports));\n\
+        }"
+        .to_owned(),
+    ];
+    if !functions.is_empty() {
+        items.push(
+            "#[cfg(feature = \"mock_host\")]\n\
+            fn mock_imports() -> std::rc::Rc<Box<dyn MockImports>> {\n    \
+                fp_bindgen_support::mock_host::get_mock_imports()\n\
+            }"
+            .to_owned(),
+        );
+    }
+    items.extend(functions);
+    items.join("\n\n")
 }
 
 fn generate_exported_function_bindings(
     export_functions: FunctionList,
+    protocol_version: u64,
     types: &TypeMap,
-    path: &str,
-) {
-    write_bindings_file(
-        format!("{path}/export.rs"),
-        format!(
-            "use crate::types::*;\n\n{}\n",
-            format_functions(
-                export_functions,
-                types,
-                "fp_bindgen_support::fp_export_signature"
-            )
-        ),
-    );
+) -> String {
+    format!(
+        "use crate::types::*;
+
+/// Fingerprint of the protocol these bindings were generated from, which is
+/// verified by the runtime when the plugin is loaded.
+#[doc(hidden)]
+#[no_mangle]
+pub extern \"C\" fn __fp_protocol_version() -> u64 {{
+    {protocol_version:#018x}
+}}
+
+{}
+",
+        format_functions(
+            &export_functions,
+            types,
+            &["fp_bindgen_support::fp_export_signature"]
+        )
+    )
 }
 
 fn collect_std_types(ty: &Type) -> Option<String> {
@@ -574,13 +684,6 @@ fn format_struct_fields(fields: &[Field], types: &TypeMap) -> Vec<String> {
         .collect()
 }
 
-fn write_bindings_file<C>(file_path: String, contents: C)
-where
-    C: AsRef<[u8]>,
-{
-    fs::write(file_path, &contents).expect("Could not write bindings file");
-}
-
 fn format_cargo_key(key: &str, value: Option<RustPluginConfigValue>) -> String {
     if let Some(value) = value {
         let toml_value = match value {
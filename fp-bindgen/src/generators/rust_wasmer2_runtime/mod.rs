@@ -1,23 +1,31 @@
 use crate::{
+    errors::BindgenError,
     functions::{Function, FunctionArg, FunctionList},
-    generators::rust_plugin::{
-        format_doc_lines, format_ident, format_modifiers, generate_type_bindings,
+    generators::{
+        format_rust_code,
+        protocol_json::protocol_fingerprint,
+        rust_plugin::{format_doc_lines, format_ident, format_modifiers, generate_type_bindings},
     },
     types::{TypeIdent, TypeMap},
 };
-use std::fs;
+use std::{collections::BTreeMap, path::PathBuf};
 
 pub(crate) fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
-    path: &str,
-) {
-    fs::create_dir_all(path).expect("Could not create output directory");
-
-    generate_type_bindings(&types, path);
-
-    generate_function_bindings(import_functions, export_functions, &types, path);
+) -> Result<BTreeMap<PathBuf, String>, BindgenError> {
+    Ok(BTreeMap::from([
+        (PathBuf::from("types.rs"), generate_type_bindings(&types)),
+        (
+            PathBuf::from("testing.rs"),
+            generate_testing_module(&import_functions, &types)?,
+        ),
+        (
+            PathBuf::from("bindings.rs"),
+            generate_function_bindings(import_functions, export_functions, &types)?,
+        ),
+    ]))
 }
 
 fn generate_create_import_object_func(import_functions: &FunctionList) -> String {
@@ -26,16 +34,17 @@ fn generate_create_import_object_func(import_functions: &FunctionList) -> String
         .map(|function| {
             let name = &function.name;
             format!(
-                "\"__fp_gen_{name}\" => Function::new_native_with_env(store, env.clone(), _{name}),"
+                "\"__fp_gen_{name}\" => Function::new_native_with_env(store, env.clone(), _{name}::<I>),"
             )
         })
         .collect::<Vec<_>>()
         .join("\n            ");
 
     format!(
-        r#"fn create_import_object(store: &Store, env: &RuntimeInstanceData) -> ImportObject {{
+        r#"fn create_import_object<I: Imports>(store: &Store, env: &RuntimeInstanceData) -> ImportObject {{
     imports! {{
         "fp" => {{
+            "__fp_host_panic" => Function::new_native_with_env(store, env.clone(), host_panic),
             "__fp_host_resolve_async_value" => Function::new_native_with_env(store, env.clone(), resolve_async_value),
             {imports}
         }}
@@ -44,6 +53,143 @@ fn generate_create_import_object_func(import_functions: &FunctionList) -> String
     )
 }
 
+/// Generates the `Imports` trait, which the runtime implements to provide the
+/// imported functions to the plugin.
+pub(crate) fn generate_imports_trait(import_functions: &FunctionList, types: &TypeMap) -> String {
+    let functions = import_functions
+        .iter()
+        .map(|function| {
+            let doc = format_doc_lines(&function.doc_lines);
+            let name = &function.name;
+            let args = function
+                .args
+                .iter()
+                .map(|FunctionArg { name, ty }| format!(", {name}: {}", format_ident(ty, types)))
+                .collect::<Vec<_>>()
+                .join("");
+            let return_type = match (function.is_async, &function.return_type) {
+                (true, Some(ty)) => format!(
+                    " -> impl std::future::Future<Output = {}> + Send",
+                    format_ident(ty, types)
+                ),
+                (true, None) => " -> impl std::future::Future<Output = ()> + Send".to_owned(),
+                (false, Some(ty)) => format!(" -> {}", format_ident(ty, types)),
+                (false, None) => "".to_owned(),
+            };
+            format!("{doc}fn {name}(&self{args}){return_type};")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n    ");
+
+    format!(
+        r#"/// Functions imported by the plugin, which are to be implemented by the runtime.
+///
+/// Every runtime instance owns its own implementation, so any state that should be
+/// scoped to a single instance can be kept on the implementing type.
+pub trait Imports: Send + Sync + 'static {{
+    {functions}
+}}"#
+    )
+}
+
+/// Generates the `testing` module, which contains the `RecordingImports`
+/// adapter. It implements the `Imports` trait by recording every call in a
+/// `MockHost` from `fp-bindgen-testing`, and forwards calls that are not
+/// stubbed to another implementation.
+///
+/// The module is not used by the bindings themselves, so only crates that
+/// include it need to depend on `fp-bindgen-testing`.
+pub(crate) fn generate_testing_module(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+) -> Result<String, BindgenError> {
+    let names = import_functions
+        .iter()
+        .map(|function| format!("\"{}\",", function.name))
+        .collect::<Vec<_>>()
+        .join("\n    ");
+
+    let functions = import_functions
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            let args = function
+                .args
+                .iter()
+                .map(|FunctionArg { name, ty }| format!(", {name}: {}", format_ident(ty, types)))
+                .collect::<Vec<_>>()
+                .join("");
+            let arg_names = function
+                .args
+                .iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let arg_tuple = match function.args.len() {
+                1 => format!("({arg_names},)"),
+                _ => format!("({arg_names})"),
+            };
+            let return_type = match &function.return_type {
+                Some(ty) => format!(" -> {}", format_ident(ty, types)),
+                None => "".to_owned(),
+            };
+            if function.is_async {
+                format!(
+                    r#"async fn {name}(&self{args}){return_type} {{
+        self.mock.call_async("{name}", {arg_tuple}, |{arg_tuple}| self.inner.{name}({arg_names})).await
+    }}"#
+                )
+            } else {
+                format!(
+                    r#"fn {name}(&self{args}){return_type} {{
+        self.mock.call("{name}", {arg_tuple}, |{arg_tuple}| self.inner.{name}({arg_names}))
+    }}"#
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n    ");
+
+    format_rust_code(
+        "testing.rs",
+        format!(
+            r#"#![allow(unused)]
+use super::bindings::Imports;
+use super::types::*;
+use fp_bindgen_testing::MockHost;
+
+/// Names of the functions imported by the plugin.
+pub const IMPORTED_FUNCTIONS: &[&str] = &[
+    {names}
+];
+
+/// Implementation of the imported functions for use in tests.
+///
+/// Every call is recorded by the `MockHost`, so tests can assert on them and
+/// stub their return values. Calls that are not stubbed are forwarded to the
+/// `inner` implementation.
+pub struct RecordingImports<I> {{
+    pub mock: MockHost,
+    pub inner: I,
+}}
+
+impl<I: Imports> RecordingImports<I> {{
+    /// Wraps the given implementation. The imported functions are registered
+    /// with the mock, so it rejects stubs and assertions for unknown functions.
+    pub fn new(mock: MockHost, inner: I) -> Self {{
+        mock.register_functions(IMPORTED_FUNCTIONS);
+        Self {{ mock, inner }}
+    }}
+}}
+
+impl<I: Imports> Imports for RecordingImports<I> {{
+    {functions}
+}}
+"#
+        ),
+    )
+}
+
 pub(crate) fn format_raw_ident(ty: &TypeIdent, types: &TypeMap) -> String {
     if ty.is_primitive() {
         format_ident(ty, types)
@@ -138,7 +284,7 @@ pub(crate) fn generate_export_function_variables<'a>(
         .iter()
         .filter(|arg| !arg.ty.is_primitive())
         .map(|FunctionArg { name, .. }| {
-            format!("let {name} = export_to_guest_raw(&self.env, {name});")
+            format!("let {name} = export_to_guest_raw(&self.env, {name})?;")
         })
         .collect::<Vec<_>>()
         .join("\n");
@@ -158,8 +304,8 @@ pub(crate) fn generate_export_function_variables<'a>(
 
     let (raw_return_wrapper, return_wrapper) = if function.is_async {
         (
-            "let result = ModuleRawFuture::new(self.env.clone(), result).await;".to_string(),
-            "let result = result.await;\nlet result = result.map(|ref data| deserialize_from_slice(data));".to_string(),
+            "let result = ModuleRawFuture::new(self.env.clone(), result).await?;".to_string(),
+            "let result = result.await;\nlet result = result.and_then(|ref data| deserialize_from_slice(data));".to_string(),
         )
     } else if !function
         .return_type
@@ -168,8 +314,8 @@ pub(crate) fn generate_export_function_variables<'a>(
         .unwrap_or(true)
     {
         (
-            "let result = import_from_guest_raw(&self.env, result);".to_string(),
-            "let result = result.map(|ref data| deserialize_from_slice(data));".to_string(),
+            "let result = import_from_guest_raw(&self.env, result)?;".to_string(),
+            "let result = result.and_then(|ref data| deserialize_from_slice(data));".to_string(),
         )
     } else {
         (
@@ -238,7 +384,7 @@ pub(crate) fn format_import_arg(name: &str, ty: &TypeIdent, types: &TypeMap) ->
         format!("let {name} = WasmAbi::from_abi({name});")
     } else {
         let ty = format_ident(ty, types);
-        format!("let {name} = import_from_guest::<{ty}>(env, {name});")
+        format!("let {name} = import_from_guest::<{ty}>(env, {name})?;")
     }
 }
 
@@ -252,9 +398,9 @@ pub(crate) fn format_import_function(function: &Function, types: &TypeMap) -> St
         .join("");
 
     let wrapper_return_type = match (function.is_async, &function.return_type) {
-        (true, _) => " -> FatPtr".to_owned(),
-        (false, Some(ty)) => format!(" -> {}", format_wasm_ident(ty)),
-        (false, None) => "".to_owned(),
+        (true, _) => "Result<FatPtr, InvocationError>".to_owned(),
+        (false, Some(ty)) => format!("Result<{}, InvocationError>", format_wasm_ident(ty)),
+        (false, None) => "Result<(), InvocationError>".to_owned(),
     };
 
     let import_args = function
@@ -264,7 +410,7 @@ pub(crate) fn format_import_function(function: &Function, types: &TypeMap) -> St
         .collect::<Vec<_>>()
         .join("\n");
 
-    let arg_names = function
+    let call_args = function
         .args
         .iter()
         .map(|arg| arg.name.as_ref())
@@ -273,27 +419,26 @@ pub(crate) fn format_import_function(function: &Function, types: &TypeMap) -> St
 
     let return_wrapper = if function.is_async {
         format!(
-            r#"let env = env.clone();
-    let async_ptr = create_future_value(&env);
-    let handle = tokio::runtime::Handle::current();
-    handle.spawn(async move {{
-        let result = super::{name}({arg_names}).await;
-        let result_ptr = export_to_guest(&env, &result);
-        env.guest_resolve_async_value(async_ptr, result_ptr);
-    }});
-    async_ptr"#
+            r#"let async_ptr = create_future_value(env);
+    let task_env = env.clone();
+    env.spawn(async move {{
+        let result = imports.{name}({call_args}).await;
+        resolve_future_value(&task_env, async_ptr, &result);
+    }})?;
+    Ok(async_ptr)"#
         )
     } else {
         match &function.return_type {
-            None => format!("super::{name}({arg_names})"),
-            Some(ty) if ty.is_primitive() => format!("super::{name}({arg_names}).to_abi()"),
-            _ => format!("export_to_guest(env, &super::{name}({arg_names}))"),
+            None => format!("imports.{name}({call_args});\n    Ok(())"),
+            Some(ty) if ty.is_primitive() => format!("Ok(imports.{name}({call_args}).to_abi())"),
+            _ => format!("export_to_guest(env, &imports.{name}({call_args}))"),
         }
     };
 
     format!(
-        r#"pub fn _{name}(env: &RuntimeInstanceData{wasm_args}){wrapper_return_type} {{
+        r#"pub fn _{name}<I: Imports>(env: &RuntimeInstanceData{wasm_args}) -> {wrapper_return_type} {{
     {import_args}
+    let imports = env.context::<I>()?;
     {return_wrapper}
 }}"#
     )
@@ -303,8 +448,7 @@ fn generate_function_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: &TypeMap,
-    path: &str,
-) {
+) -> Result<String, BindgenError> {
     let imports = import_functions
         .iter()
         .map(|function| format_import_function(function, types))
@@ -315,44 +459,165 @@ fn generate_function_bindings(
         .map(|function| format_export_function(function, types))
         .collect::<Vec<_>>()
         .join("\n\n");
-    let new_func = r#"pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {
-        let store = Self::default_store();
-        let module = Module::new(&store, wasm_module)?;
-        let mut env = RuntimeInstanceData::default();
-        let import_object = create_import_object(module.store(), &env);
+    let new_func = format_new_func(
+        &import_functions,
+        r#"let import_object = create_import_object::<I>(module.store(), &env);
         let instance = Instance::new(&module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
-        Ok(Self { instance, env })
-    }"#
-    .to_string();
+        initialize_instance(&instance)?;
+        Ok(Self { instance, env })"#,
+    );
     let create_import_object_func = generate_create_import_object_func(&import_functions);
+    let imports_trait = generate_imports_trait(&import_functions, types);
+    let protocol_version = format_protocol_version(&import_functions, &export_functions, types);
+
+    format_function_bindings(
+        protocol_version,
+        imports_trait,
+        imports,
+        exports,
+        new_func,
+        create_import_object_func,
+    )
+}
 
-    write_bindings_file(
-        format!("{path}/bindings.rs"),
-        format_function_bindings(imports, exports, new_func, create_import_object_func),
-    );
+/// Formats the constructor(s) of the runtime, using the given code to
+/// instantiate the module and its `env` after they have been created.
+pub(crate) fn format_new_func(import_functions: &FunctionList, instantiate: &str) -> String {
+    format_constructors(
+        import_functions,
+        "check_protocol_version(&runtime.instance, PROTOCOL_VERSION)?;",
+        |with_spawner| {
+            let set_spawner = if with_spawner {
+                "env.set_spawner(spawner);"
+            } else {
+                ""
+            };
+            format!(
+                r#"let store = Self::default_store();
+        let module = Module::new(&store, wasm_module)?;
+        let mut env = RuntimeInstanceData::with_context(imports);
+        {set_spawner}
+        {instantiate}"#
+            )
+        },
+    )
+}
+
+/// Formats the constructors of a Rust runtime. The body of the unchecked
+/// constructors is formatted by `unchecked_body`, which is told whether a
+/// `spawner` is in scope that should be set on the instance data, while
+/// `check_protocol_version` is the statement that verifies the protocol
+/// fingerprint of the created `runtime`.
+///
+/// Every constructor comes with an `_unchecked` variant, which skips verifying
+/// the protocol fingerprint of the plugin. If any of the imported functions are
+/// async, `with_spawner()` constructors are generated as well, which take the
+/// `Spawner` used to run their futures. In that case, `new()` spawns them on the
+/// current Tokio runtime.
+pub(crate) fn format_constructors(
+    import_functions: &FunctionList,
+    check_protocol_version: &str,
+    unchecked_body: impl Fn(bool) -> String,
+) -> String {
+    let has_async_imports = import_functions.iter().any(|function| function.is_async);
+    if has_async_imports {
+        let body = unchecked_body(true);
+        format!(
+            r#"/// Creates a runtime for the given plugin, which spawns the futures returned
+    /// by async imported functions on the current Tokio runtime.
+    pub fn new<I: Imports>(wasm_module: impl AsRef<[u8]>, imports: I) -> Result<Self, RuntimeError> {{
+        Self::with_spawner(wasm_module, imports, |future| {{
+            tokio::runtime::Handle::current().spawn(future);
+        }})
+    }}
+
+    /// Same as `new()`, but does not verify the plugin was generated from the
+    /// same protocol as the runtime.
+    pub fn new_unchecked<I: Imports>(wasm_module: impl AsRef<[u8]>, imports: I) -> Result<Self, RuntimeError> {{
+        Self::with_spawner_unchecked(wasm_module, imports, |future| {{
+            tokio::runtime::Handle::current().spawn(future);
+        }})
+    }}
+
+    /// Creates a runtime for the given plugin, which uses the given spawner to run
+    /// the futures returned by async imported functions. This allows the runtime
+    /// to be used with executors other than Tokio.
+    pub fn with_spawner<I: Imports>(wasm_module: impl AsRef<[u8]>, imports: I, spawner: impl Spawner) -> Result<Self, RuntimeError> {{
+        let runtime = Self::with_spawner_unchecked(wasm_module, imports, spawner)?;
+        {check_protocol_version}
+        Ok(runtime)
+    }}
+
+    /// Same as `with_spawner()`, but does not verify the plugin was generated from
+    /// the same protocol as the runtime.
+    pub fn with_spawner_unchecked<I: Imports>(wasm_module: impl AsRef<[u8]>, imports: I, spawner: impl Spawner) -> Result<Self, RuntimeError> {{
+        {body}
+    }}"#
+        )
+    } else {
+        let body = unchecked_body(false);
+        format!(
+            r#"/// Creates a runtime for the given plugin, after verifying it was generated
+    /// from the same protocol as the runtime.
+    pub fn new<I: Imports>(wasm_module: impl AsRef<[u8]>, imports: I) -> Result<Self, RuntimeError> {{
+        let runtime = Self::new_unchecked(wasm_module, imports)?;
+        {check_protocol_version}
+        Ok(runtime)
+    }}
+
+    /// Same as `new()`, but does not verify the plugin was generated from the
+    /// same protocol as the runtime.
+    pub fn new_unchecked<I: Imports>(wasm_module: impl AsRef<[u8]>, imports: I) -> Result<Self, RuntimeError> {{
+        {body}
+    }}"#
+        )
+    }
+}
+
+pub(crate) fn format_protocol_version(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> String {
+    let fingerprint = protocol_fingerprint(import_functions, export_functions, types);
+    format!(
+        "/// Fingerprint of the protocol these bindings were generated from. Plugins must
+/// have been generated from the same protocol for the runtime to accept them.
+pub const PROTOCOL_VERSION: u64 = {fingerprint:#018x};"
+    )
 }
 
 pub(crate) fn format_function_bindings(
+    protocol_version: String,
+    imports_trait: String,
     imports: String,
     exports: String,
     new_func: String,
     create_import_object_func: String,
-) -> String {
-    rustfmt_wrapper::rustfmt(format!(r#"#![allow(unused)]
+) -> Result<String, BindgenError> {
+    format_rust_code(
+        "bindings.rs",
+        format!(
+            r#"#![allow(unused)]
 use super::types::*;
 use fp_bindgen_support::{{
     common::{{mem::FatPtr, abi::WasmAbi}},
     wasmer2_host::{{
         errors::{{InvocationError, RuntimeError}},
         mem::{{export_to_guest, export_to_guest_raw, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec}},
-        r#async::{{create_future_value, future::ModuleRawFuture, resolve_async_value}},
-        runtime::RuntimeInstanceData,
+        panic::host_panic,
+        r#async::{{create_future_value, future::ModuleRawFuture, resolve_async_value, resolve_future_value, Spawner}},
+        runtime::{{check_protocol_version, initialize_instance, RuntimeInstanceData}},
     }},
 }};
 use std::cell::RefCell;
 use wasmer::{{imports, Function, ImportObject, Instance, Module, Store, WasmerEnv}};
 
+{protocol_version}
+
+{imports_trait}
+
 #[derive(Clone)]
 pub struct Runtime {{
     instance: Instance,
@@ -382,13 +647,7 @@ impl Runtime {{
 {create_import_object_func}
 
 {imports}
-"#))
-    .unwrap()
-}
-
-pub(crate) fn write_bindings_file<C>(file_path: String, contents: C)
-where
-    C: AsRef<[u8]>,
-{
-    fs::write(file_path, &contents).expect("Could not write bindings file");
+"#
+        ),
+    )
 }
@@ -1,27 +1,34 @@
 use crate::{
+    errors::BindgenError,
     functions::{Function, FunctionList},
     generators::{
         rust_plugin::generate_type_bindings,
         rust_wasmer2_runtime::{
-            format_function_bindings, format_import_function, generate_export_function_variables,
-            write_bindings_file, ExportFunctionVariables,
+            format_function_bindings, format_import_function, format_new_func,
+            format_protocol_version, generate_export_function_variables, generate_imports_trait,
+            generate_testing_module, ExportFunctionVariables,
         },
     },
     types::TypeMap,
 };
-use std::fs;
+use std::{collections::BTreeMap, path::PathBuf};
 
 pub(crate) fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
-    path: &str,
-) {
-    fs::create_dir_all(path).expect("Could not create output directory");
-
-    generate_type_bindings(&types, path);
-
-    generate_function_bindings(import_functions, export_functions, &types, path);
+) -> Result<BTreeMap<PathBuf, String>, BindgenError> {
+    Ok(BTreeMap::from([
+        (PathBuf::from("types.rs"), generate_type_bindings(&types)),
+        (
+            PathBuf::from("testing.rs"),
+            generate_testing_module(&import_functions, &types)?,
+        ),
+        (
+            PathBuf::from("bindings.rs"),
+            generate_function_bindings(import_functions, export_functions, &types)?,
+        ),
+    ]))
 }
 
 fn generate_create_import_object_func(import_functions: &FunctionList) -> String {
@@ -32,7 +39,7 @@ fn generate_create_import_object_func(import_functions: &FunctionList) -> String
             format!(
                 r#"namespace.insert(
             "__fp_gen_{name}",
-            Function::new_native_with_env(store, env.clone(), _{name})
+            Function::new_native_with_env(store, env.clone(), _{name}::<I>)
     );"#
             )
         })
@@ -40,8 +47,12 @@ fn generate_create_import_object_func(import_functions: &FunctionList) -> String
         .join("\n    ");
 
     format!(
-        r#"fn create_import_object(store: &Store, env: &RuntimeInstanceData) -> wasmer::Exports {{
+        r#"fn create_import_object<I: Imports>(store: &Store, env: &RuntimeInstanceData) -> wasmer::Exports {{
     let mut namespace = wasmer::Exports::new();
+    namespace.insert(
+            "__fp_host_panic",
+            Function::new_native_with_env(store, env.clone(), host_panic)
+    );
     namespace.insert(
             "__fp_host_resolve_async_value",
             Function::new_native_with_env(store, env.clone(), resolve_async_value)
@@ -92,8 +103,7 @@ fn generate_function_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: &TypeMap,
-    path: &str,
-) {
+) -> Result<String, BindgenError> {
     let imports = import_functions
         .iter()
         .map(|function| format_import_function(function, types))
@@ -104,23 +114,27 @@ fn generate_function_bindings(
         .map(|function| format_export_function(function, types))
         .collect::<Vec<_>>()
         .join("\n\n");
-    let new_func = r#"pub fn new(wasm_module: impl AsRef<[u8]>) -> Result<Self, RuntimeError> {
-        let store = Self::default_store();
-        let module = Module::new(&store, wasm_module)?;
-        let mut env = RuntimeInstanceData::default();
-        let mut wasi_env = wasmer_wasi::WasiState::new("fp").finalize().unwrap();
+    let new_func = format_new_func(
+        &import_functions,
+        r#"let mut wasi_env = wasmer_wasi::WasiState::new("fp").finalize().unwrap();
         let mut import_object = wasi_env.import_object(&module).unwrap();
-        let namespace = create_import_object(module.store(), &env);
+        let namespace = create_import_object::<I>(module.store(), &env);
         import_object.register("fp", namespace);
         let instance = Instance::new(&module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
-        Ok(Self { instance, env })
-    }"#
-    .to_string();
+        initialize_instance(&instance)?;
+        Ok(Self { instance, env })"#,
+    );
     let create_import_object_func = generate_create_import_object_func(&import_functions);
+    let imports_trait = generate_imports_trait(&import_functions, types);
+    let protocol_version = format_protocol_version(&import_functions, &export_functions, types);
 
-    write_bindings_file(
-        format!("{path}/bindings.rs"),
-        format_function_bindings(imports, exports, new_func, create_import_object_func),
-    );
+    format_function_bindings(
+        protocol_version,
+        imports_trait,
+        imports,
+        exports,
+        new_func,
+        create_import_object_func,
+    )
 }
@@ -0,0 +1,315 @@
+use crate::{
+    errors::BindgenError,
+    functions::{Function, FunctionArg, FunctionList},
+    generators::{
+        format_rust_code,
+        rust_plugin::{format_ident, generate_type_bindings},
+        rust_wasmer2_runtime::{
+            format_constructors, format_protocol_version, format_wasm_ident,
+            generate_export_function_variables, generate_imports_trait, generate_testing_module,
+            ExportFunctionVariables,
+        },
+    },
+    types::{TypeIdent, TypeMap},
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+) -> Result<BTreeMap<PathBuf, String>, BindgenError> {
+    Ok(BTreeMap::from([
+        (PathBuf::from("types.rs"), generate_type_bindings(&types)),
+        (
+            PathBuf::from("testing.rs"),
+            generate_testing_module(&import_functions, &types)?,
+        ),
+        (
+            PathBuf::from("bindings.rs"),
+            generate_function_bindings(import_functions, export_functions, &types)?,
+        ),
+    ]))
+}
+
+fn generate_create_imports_func(import_functions: &FunctionList) -> String {
+    let imports = import_functions
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            format!(
+                "\"__fp_gen_{name}\" => Function::new_typed_with_env(store, env, _{name}::<I>),"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n            ");
+
+    format!(
+        r#"fn create_imports<I: Imports>(store: &mut Store, env: &FunctionEnv<RuntimeInstanceData>) -> wasmer::Imports {{
+    imports! {{
+        "fp" => {{
+            "__fp_host_panic" => Function::new_typed_with_env(store, env, host_panic),
+            "__fp_host_resolve_async_value" => Function::new_typed_with_env(store, env, resolve_async_value),
+            {imports}
+        }}
+    }}
+}}"#
+    )
+}
+
+fn format_export_function(function: &Function, types: &TypeMap) -> String {
+    let ExportFunctionVariables {
+        doc,
+        modifiers,
+        name,
+        args,
+        raw_args,
+        wasm_args,
+        return_type,
+        raw_return_type,
+        wasm_return_type,
+        serialize_args,
+        arg_names,
+        wasm_arg_names,
+        return_wrapper,
+        ..
+    } = generate_export_function_variables(function, types);
+
+    let serialize_raw_args = function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive())
+        .map(|FunctionArg { name, .. }| {
+            format!("let {name} = export_to_guest_raw(&mut env, {name})?;")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let wasm_arg_names = if wasm_arg_names.is_empty() {
+        wasm_arg_names
+    } else {
+        format!(", {wasm_arg_names}")
+    };
+
+    let (raw_return_wrapper, async_return_wrapper) = if function.is_async {
+        (
+            "",
+            "let result = ModuleRawFuture::new(self.store.clone(), self.env.clone(), result).await?;",
+        )
+    } else if !function
+        .return_type
+        .as_ref()
+        .map(TypeIdent::is_primitive)
+        .unwrap_or(true)
+    {
+        ("let result = import_from_guest_raw(&mut env, result)?;", "")
+    } else {
+        ("let result = WasmAbi::from_abi(result);", "")
+    };
+
+    format!(
+        r#"{doc}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, InvocationError> {{
+    {serialize_args}
+    let result = self.{name}_raw({arg_names});
+    {return_wrapper}result
+}}
+pub {modifiers}fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
+    let result = {{
+        let mut store = self.store.lock().unwrap();
+        let mut env = self.env.clone().into_mut(&mut *store);
+        {serialize_raw_args}let function = self.instance
+            .exports
+            .get_typed_function::<{wasm_args}, {wasm_return_type}>(&env, "__fp_gen_{name}")
+            .map_err(|_| InvocationError::FunctionNotExported("__fp_gen_{name}".to_owned()))?;
+        let result = function.call(&mut env{wasm_arg_names})?;
+        {raw_return_wrapper}result
+    }};
+    {async_return_wrapper}Ok(result)
+}}"#
+    )
+}
+
+fn format_import_arg(name: &str, ty: &TypeIdent, types: &TypeMap) -> String {
+    if ty.is_primitive() {
+        format!("let {name} = WasmAbi::from_abi({name});")
+    } else {
+        let ty = format_ident(ty, types);
+        format!("let {name} = import_from_guest::<{ty}>(&mut env, {name})?;")
+    }
+}
+
+fn format_import_function(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let wasm_args = function
+        .args
+        .iter()
+        .map(|FunctionArg { name, ty }| format!(", {name}: {}", format_wasm_ident(ty)))
+        .collect::<Vec<_>>()
+        .join("");
+
+    let wrapper_return_type = match (function.is_async, &function.return_type) {
+        (true, _) => "Result<FatPtr, InvocationError>".to_owned(),
+        (false, Some(ty)) => format!("Result<{}, InvocationError>", format_wasm_ident(ty)),
+        (false, None) => "Result<(), InvocationError>".to_owned(),
+    };
+
+    let import_args = function
+        .args
+        .iter()
+        .map(|arg| format_import_arg(&arg.name, &arg.ty, types))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let call_args = function
+        .args
+        .iter()
+        .map(|arg| arg.name.as_ref())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let return_wrapper = if function.is_async {
+        format!(
+            r#"let store = env.data().store()?;
+    let function_env = env.as_ref();
+    let async_ptr = create_future_value(&mut env)?;
+    env.data().spawn(async move {{
+        let result = imports.{name}({call_args}).await;
+        resolve_future_value(&store, &function_env, async_ptr, &result);
+    }})?;
+    Ok(async_ptr)"#
+        )
+    } else {
+        match &function.return_type {
+            None => format!("imports.{name}({call_args});\n    Ok(())"),
+            Some(ty) if ty.is_primitive() => format!("Ok(imports.{name}({call_args}).to_abi())"),
+            _ => format!("export_to_guest(&mut env, &imports.{name}({call_args}))"),
+        }
+    };
+
+    format!(
+        r#"pub fn _{name}<I: Imports>(mut env: FunctionEnvMut<RuntimeInstanceData>{wasm_args}) -> {wrapper_return_type} {{
+    {import_args}
+    let imports = env.data().context::<I>()?;
+    {return_wrapper}
+}}"#
+    )
+}
+
+fn generate_function_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: &TypeMap,
+) -> Result<String, BindgenError> {
+    let imports = import_functions
+        .iter()
+        .map(|function| format_import_function(function, types))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let exports = export_functions
+        .iter()
+        .map(|function| format_export_function(function, types))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let new_func = format_new_func(&import_functions);
+    let create_imports_func = generate_create_imports_func(&import_functions);
+    let imports_trait = generate_imports_trait(&import_functions, types);
+    let protocol_version = format_protocol_version(&import_functions, &export_functions, types);
+
+    format_function_bindings(
+        protocol_version,
+        imports_trait,
+        imports,
+        exports,
+        new_func,
+        create_imports_func,
+    )
+}
+
+fn format_new_func(import_functions: &FunctionList) -> String {
+    format_constructors(
+        import_functions,
+        "check_protocol_version(&mut *runtime.store.lock().unwrap(), &runtime.instance, PROTOCOL_VERSION)?;",
+        |with_spawner| {
+            let create_data = if with_spawner {
+                "let mut data = RuntimeInstanceData::new(&store, imports);\n            data.set_spawner(spawner);"
+            } else {
+                "let data = RuntimeInstanceData::new(&store, imports);"
+            };
+            format!(
+                r#"let store = Arc::new(Mutex::new(Self::default_store()));
+        let (instance, env) = {{
+            let mut store_guard = store.lock().unwrap();
+            let module = Module::new(&*store_guard, wasm_module)?;
+            {create_data}
+            let env = FunctionEnv::new(&mut *store_guard, data);
+            let import_object = create_imports::<I>(&mut *store_guard, &env);
+            let instance = Instance::new(&mut *store_guard, &module, &import_object)?;
+            RuntimeInstanceData::init_with_instance(&mut env.clone().into_mut(&mut *store_guard), &instance)?;
+            initialize_instance(&mut *store_guard, &instance)?;
+            (instance, env)
+        }};
+        Ok(Self {{ instance, store, env }})"#
+            )
+        },
+    )
+}
+
+fn format_function_bindings(
+    protocol_version: String,
+    imports_trait: String,
+    imports: String,
+    exports: String,
+    new_func: String,
+    create_imports_func: String,
+) -> Result<String, BindgenError> {
+    format_rust_code(
+        "bindings.rs",
+        format!(
+            r#"#![allow(unused)]
+use super::types::*;
+use fp_bindgen_support::{{
+    common::{{mem::FatPtr, abi::WasmAbi}},
+    wasmer3_host::{{
+        errors::{{InvocationError, RuntimeError}},
+        mem::{{export_to_guest, export_to_guest_raw, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec}},
+        panic::host_panic,
+        r#async::{{create_future_value, future::ModuleRawFuture, resolve_async_value, resolve_future_value, Spawner}},
+        runtime::{{check_protocol_version, initialize_instance, RuntimeInstanceData, RuntimeStore}},
+    }},
+}};
+use std::sync::{{Arc, Mutex}};
+use wasmer::{{imports, Function, FunctionEnv, FunctionEnvMut, Instance, Module, Store}};
+
+{protocol_version}
+
+{imports_trait}
+
+#[derive(Clone)]
+pub struct Runtime {{
+    instance: Instance,
+    store: RuntimeStore,
+    env: FunctionEnv<RuntimeInstanceData>,
+}}
+
+impl Runtime {{
+    {new_func}
+
+    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+    fn default_store() -> Store {{
+        Store::new(wasmer::Cranelift::default())
+    }}
+
+    #[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
+    fn default_store() -> Store {{
+        Store::new(wasmer::Singlepass::default())
+    }}
+
+    {exports}
+}}
+
+{create_imports_func}
+
+{imports}
+"#
+        ),
+    )
+}
@@ -0,0 +1,330 @@
+use crate::{
+    errors::BindgenError,
+    functions::{Function, FunctionArg, FunctionList},
+    generators::{
+        format_rust_code,
+        rust_plugin::{format_ident, generate_type_bindings},
+        rust_wasmer2_runtime::{
+            format_constructors, format_protocol_version, generate_export_function_variables,
+            generate_imports_trait, generate_testing_module, ExportFunctionVariables,
+        },
+    },
+    types::{TypeIdent, TypeMap},
+};
+use std::{collections::BTreeMap, path::PathBuf};
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+) -> Result<BTreeMap<PathBuf, String>, BindgenError> {
+    Ok(BTreeMap::from([
+        (PathBuf::from("types.rs"), generate_type_bindings(&types)),
+        (
+            PathBuf::from("testing.rs"),
+            generate_testing_module(&import_functions, &types)?,
+        ),
+        (
+            PathBuf::from("bindings.rs"),
+            generate_function_bindings(import_functions, export_functions, &types)?,
+        ),
+    ]))
+}
+
+fn generate_link_imports_func(import_functions: &FunctionList) -> String {
+    let imports = import_functions
+        .iter()
+        .map(|function| {
+            let name = &function.name;
+            format!("linker.func_wrap(\"fp\", \"__fp_gen_{name}\", _{name}::<I>)?;")
+        })
+        .collect::<Vec<_>>()
+        .join("\n    ");
+
+    format!(
+        r#"fn link_imports<I: Imports>(linker: &mut Linker<RuntimeInstanceData>) -> Result<(), RuntimeError> {{
+    linker.func_wrap("fp", "__fp_host_panic", host_panic)?;
+    linker.func_wrap("fp", "__fp_host_resolve_async_value", resolve_async_value)?;
+    {imports}
+    Ok(())
+}}"#
+    )
+}
+
+/// Wasmtime passes multiple arguments to typed functions as a tuple, and a
+/// single argument as is.
+fn format_wasm_tuple(values: Vec<String>) -> String {
+    if values.len() == 1 {
+        let mut values = values;
+        values.remove(0)
+    } else {
+        format!("({})", values.join(", "))
+    }
+}
+
+pub(crate) fn format_wasm_ident(ty: &TypeIdent) -> String {
+    if ty.is_primitive() {
+        format!("<{} as WasmtimeAbi>::AbiType", ty.name)
+    } else {
+        "FatPtr".to_owned()
+    }
+}
+
+fn format_export_function(function: &Function, types: &TypeMap) -> String {
+    let ExportFunctionVariables {
+        doc,
+        modifiers,
+        name,
+        args,
+        raw_args,
+        return_type,
+        raw_return_type,
+        serialize_args,
+        arg_names,
+        return_wrapper,
+        ..
+    } = generate_export_function_variables(function, types);
+
+    let wasm_args = format_wasm_tuple(
+        function
+            .args
+            .iter()
+            .map(|arg| format_wasm_ident(&arg.ty))
+            .collect(),
+    );
+    let wasm_return_type = match (function.is_async, &function.return_type) {
+        (true, _) => "FatPtr".to_owned(),
+        (false, Some(ty)) => format_wasm_ident(ty),
+        (false, None) => "()".to_owned(),
+    };
+
+    let serialize_raw_args = function
+        .args
+        .iter()
+        .filter(|arg| !arg.ty.is_primitive())
+        .map(|FunctionArg { name, .. }| {
+            format!("let {name} = export_to_guest_raw(&mut *store, {name})?;")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let wasm_arg_names = format_wasm_tuple(
+        function
+            .args
+            .iter()
+            .map(|arg| format!("{}.to_abi()", arg.name))
+            .collect(),
+    );
+
+    let (raw_return_wrapper, async_return_wrapper) = if function.is_async {
+        (
+            "",
+            "let result = ModuleRawFuture::new(self.store.clone(), result).await?;",
+        )
+    } else if !function
+        .return_type
+        .as_ref()
+        .map(TypeIdent::is_primitive)
+        .unwrap_or(true)
+    {
+        (
+            "let result = import_from_guest_raw(&mut *store, result)?;",
+            "",
+        )
+    } else {
+        ("let result = WasmtimeAbi::from_abi(result);", "")
+    };
+
+    format!(
+        r#"{doc}pub {modifiers}fn {name}(&self{args}) -> Result<{return_type}, InvocationError> {{
+    {serialize_args}
+    let result = self.{name}_raw({arg_names});
+    {return_wrapper}result
+}}
+pub {modifiers}fn {name}_raw(&self{raw_args}) -> Result<{raw_return_type}, InvocationError> {{
+    let result = {{
+        let mut store = self.store.lock().unwrap();
+        {serialize_raw_args}let function = self.instance
+            .get_typed_func::<{wasm_args}, {wasm_return_type}>(&mut *store, "__fp_gen_{name}")
+            .map_err(|_| InvocationError::FunctionNotExported("__fp_gen_{name}".to_owned()))?;
+        let result = function.call(&mut *store, {wasm_arg_names})?;
+        {raw_return_wrapper}result
+    }};
+    {async_return_wrapper}Ok(result)
+}}"#
+    )
+}
+
+fn format_import_arg(name: &str, ty: &TypeIdent, types: &TypeMap) -> String {
+    if ty.is_primitive() {
+        format!("let {name} = WasmtimeAbi::from_abi({name});")
+    } else {
+        let ty = format_ident(ty, types);
+        format!("let {name} = import_from_guest::<{ty}>(&mut caller, {name})?;")
+    }
+}
+
+fn format_import_function(function: &Function, types: &TypeMap) -> String {
+    let name = &function.name;
+    let wasm_args = function
+        .args
+        .iter()
+        .map(|FunctionArg { name, ty }| format!(", {name}: {}", format_wasm_ident(ty)))
+        .collect::<Vec<_>>()
+        .join("");
+
+    let wrapper_return_type = match (function.is_async, &function.return_type) {
+        (true, _) => "wasmtime::Result<FatPtr>".to_owned(),
+        (false, Some(ty)) => format!("wasmtime::Result<{}>", format_wasm_ident(ty)),
+        (false, None) => "wasmtime::Result<()>".to_owned(),
+    };
+
+    let import_args = function
+        .args
+        .iter()
+        .map(|arg| format_import_arg(&arg.name, &arg.ty, types))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let call_args = function
+        .args
+        .iter()
+        .map(|arg| arg.name.as_ref())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let return_wrapper = if function.is_async {
+        format!(
+            r#"let store = caller.data().store()?;
+    let async_ptr = create_future_value(&mut caller)?;
+    caller.data().spawn(async move {{
+        let result = imports.{name}({call_args}).await;
+        resolve_future_value(&store, async_ptr, &result);
+    }})?;
+    Ok(async_ptr)"#
+        )
+    } else {
+        match &function.return_type {
+            None => format!("imports.{name}({call_args});\n    Ok(())"),
+            Some(ty) if ty.is_primitive() => format!("Ok(imports.{name}({call_args}).to_abi())"),
+            _ => format!("Ok(export_to_guest(&mut caller, &imports.{name}({call_args}))?)"),
+        }
+    };
+
+    format!(
+        r#"pub fn _{name}<I: Imports>(mut caller: Caller<'_, RuntimeInstanceData>{wasm_args}) -> {wrapper_return_type} {{
+    {import_args}
+    let imports = caller.data().context::<I>()?;
+    {return_wrapper}
+}}"#
+    )
+}
+
+fn generate_function_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: &TypeMap,
+) -> Result<String, BindgenError> {
+    let imports = import_functions
+        .iter()
+        .map(|function| format_import_function(function, types))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let exports = export_functions
+        .iter()
+        .map(|function| format_export_function(function, types))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let new_func = format_new_func(&import_functions);
+    let link_imports_func = generate_link_imports_func(&import_functions);
+    let imports_trait = generate_imports_trait(&import_functions, types);
+    let protocol_version = format_protocol_version(&import_functions, &export_functions, types);
+
+    format_function_bindings(
+        protocol_version,
+        imports_trait,
+        imports,
+        exports,
+        new_func,
+        link_imports_func,
+    )
+}
+
+fn format_new_func(import_functions: &FunctionList) -> String {
+    format_constructors(
+        import_functions,
+        "check_protocol_version(&mut *runtime.store.lock().unwrap(), &runtime.instance, PROTOCOL_VERSION)?;",
+        |with_spawner| {
+            let set_spawner = if with_spawner {
+                "store.data_mut().set_spawner(spawner);"
+            } else {
+                ""
+            };
+            format!(
+                r#"let engine = Engine::default();
+        let module = Module::new(&engine, wasm_module)?;
+        let mut linker = Linker::new(&engine);
+        link_imports::<I>(&mut linker)?;
+        let store = RuntimeInstanceData::new_store(&engine, imports);
+        let instance = {{
+            let mut store = store.lock().unwrap();
+            {set_spawner}
+            let instance = linker.instantiate(&mut *store, &module)?;
+            RuntimeInstanceData::init_with_instance(&mut *store, &instance)?;
+            initialize_instance(&mut *store, &instance)?;
+            instance
+        }};
+        Ok(Self {{ instance, store }})"#
+            )
+        },
+    )
+}
+
+fn format_function_bindings(
+    protocol_version: String,
+    imports_trait: String,
+    imports: String,
+    exports: String,
+    new_func: String,
+    link_imports_func: String,
+) -> Result<String, BindgenError> {
+    format_rust_code(
+        "bindings.rs",
+        format!(
+            r#"#![allow(unused)]
+use super::types::*;
+use fp_bindgen_support::{{
+    common::mem::FatPtr,
+    wasmtime_host::{{
+        abi::WasmtimeAbi,
+        errors::{{InvocationError, RuntimeError}},
+        mem::{{export_to_guest, export_to_guest_raw, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec}},
+        panic::host_panic,
+        r#async::{{create_future_value, future::ModuleRawFuture, resolve_async_value, resolve_future_value, Spawner}},
+        runtime::{{check_protocol_version, initialize_instance, RuntimeInstanceData, RuntimeStore}},
+    }},
+}};
+use wasmtime::{{Caller, Engine, Instance, Linker, Module}};
+
+{protocol_version}
+
+{imports_trait}
+
+#[derive(Clone)]
+pub struct Runtime {{
+    instance: Instance,
+    store: RuntimeStore,
+}}
+
+impl Runtime {{
+    {new_func}
+
+    {exports}
+}}
+
+{link_imports_func}
+
+{imports}
+"#
+        ),
+    )
+}
@@ -0,0 +1,1692 @@
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    generators::protocol_json::protocol_fingerprint,
+    prelude::Primitive,
+    types::{Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
+    TinyGoPluginConfig,
+};
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    str::FromStr,
+};
+
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Names of local variables and package-level names used by the generated
+/// wrappers, which arguments may not shadow.
+const RESERVED_NAMES: &[&str] = &[
+    "asyncValuePtr",
+    "callback",
+    "exports",
+    "r",
+    "resolve",
+    "result",
+    "resultPtr",
+    "w",
+];
+
+/// The support library is the same for every protocol, so it is kept in a
+/// regular Go file that is copied into the bindings with only its package name
+/// replaced.
+const SUPPORT_SOURCE: &str = include_str!("support.go");
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: TinyGoPluginConfig,
+) -> BTreeMap<PathBuf, String> {
+    let protocol_version = protocol_fingerprint(&import_functions, &export_functions, &types);
+
+    let mut generator = TypeGenerator::new(&types);
+    for (ident, ty) in &types {
+        let is_protocol_type = match ty {
+            Type::Alias(_, _) => true,
+            Type::Enum(ty) => ty.ident.generic_args.is_empty(),
+            Type::Struct(ty) => ty.ident.generic_args.is_empty(),
+            _ => false,
+        };
+        if is_protocol_type {
+            generator.visit(ident);
+        }
+    }
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        for arg in &function.args {
+            generator.visit(&arg.ty);
+        }
+        if let Some(ty) = &function.return_type {
+            generator.visit(ty);
+        }
+    }
+
+    BTreeMap::from([
+        (
+            PathBuf::from("bindings.go"),
+            generate_bindings_file(
+                &import_functions,
+                &export_functions,
+                protocol_version,
+                &generator,
+                &config,
+            ),
+        ),
+        (
+            PathBuf::from("support.go"),
+            SUPPORT_SOURCE.replacen(
+                "\npackage bindings\n",
+                &format!("\npackage {}\n", config.package_name),
+                1,
+            ),
+        ),
+        (PathBuf::from("types.go"), generator.format_source(&config)),
+    ])
+}
+
+fn generate_bindings_file(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    protocol_version: u64,
+    types: &TypeGenerator,
+    config: &TinyGoPluginConfig,
+) -> String {
+    let import_wrappers = import_functions
+        .iter()
+        .map(|function| format_import_wrapper(function, types))
+        .collect::<String>();
+    let export_methods = export_functions
+        .iter()
+        .map(|function| format_export_method(function, types))
+        .collect::<String>();
+    let export_wrappers = export_functions
+        .iter()
+        .map(|function| format_export_wrapper(function, types))
+        .collect::<String>();
+
+    format!(
+        "{}
+// ProtocolVersion is the fingerprint of the protocol the bindings were
+// generated for.
+const ProtocolVersion uint64 = {protocol_version:#018x}
+
+// Functions imported from the host.
+{import_wrappers}
+// Exports contains the functions the plugin exports to the host. Async functions
+// receive an additional callback, through which they should pass their result
+// once it is available.
+type Exports interface {{
+{export_methods}}}
+
+var exports Exports
+
+// SetExports registers the implementation of the exported functions. It should
+// be called from an init() function, since main() is not called for plugins.
+func SetExports(implementation Exports) {{
+\texports = implementation
+}}
+
+//go:wasmexport __fp_protocol_version
+func __fp_protocol_version() uint64 {{
+\treturn ProtocolVersion
+}}
+{export_wrappers}",
+        format_header("Bindings for TinyGo plugins", &config.package_name)
+    )
+}
+
+fn format_header(title: &str, package_name: &str) -> String {
+    format!(
+        "// ============================================= //
+// {title:45} //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package {package_name}
+"
+    )
+}
+
+fn format_import_wrapper(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let raw_return_type = match &function.return_type {
+        _ if function.is_async => " FatPtr".to_owned(),
+        None => String::new(),
+        Some(ty) => format!(" {}", format_raw_type(ty)),
+    };
+    let raw_args = function
+        .args
+        .iter()
+        .map(|arg| format!("{} {}", get_arg_name(&arg.name), format_raw_type(&arg.ty)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let raw_decl = format!(
+        "//go:wasmimport fp __fp_gen_{name}\nfunc __fp_gen_{name}({raw_args}){raw_return_type}"
+    );
+
+    let mut body = Vec::new();
+    for arg in function.args.iter().filter(|arg| !arg.ty.is_primitive()) {
+        let arg_name = get_arg_name(&arg.name);
+        body.push(format!("{arg_name}Writer := writer{{}}"));
+        body.push(format!(
+            "encode_{}(&{arg_name}Writer, {arg_name})",
+            types.fn_name(&arg.ty)
+        ));
+    }
+    let call_args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            match arg.ty.as_primitive() {
+                Some(primitive) => encode_primitive(primitive, &arg_name),
+                None => format!("{arg_name}Writer.finish()"),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let call = format!("__fp_gen_{name}({call_args})");
+
+    if function.is_async {
+        let mut handler = Vec::new();
+        match &function.return_type {
+            Some(ty) => {
+                handler.push(format_decode_result(ty, types, name));
+                handler.push("callback(result)".to_owned());
+            }
+            None => {
+                handler.push("openReader(resultPtr).close()".to_owned());
+                handler.push("callback()".to_owned());
+            }
+        }
+        body.push(format!("asyncValuePtr := {call}"));
+        body.push(format!(
+            "awaitAsyncValue(asyncValuePtr, func(resultPtr FatPtr) {{\n{}}})",
+            join_lines(&handler, "\t")
+        ));
+    } else {
+        match &function.return_type {
+            None => body.push(call),
+            Some(ty) => match ty.as_primitive() {
+                Some(primitive) => {
+                    body.push(format!("return {}", decode_primitive(primitive, &call)))
+                }
+                None => {
+                    body.push(format!("resultPtr := {call}"));
+                    body.push(format_decode_result(ty, types, name));
+                    body.push("return result".to_owned());
+                }
+            },
+        }
+    }
+
+    let mut args = format_args(function, types);
+    let return_type = match &function.return_type {
+        Some(ty) if function.is_async => {
+            args.push(format!("callback func({})", types.as_type(ty)));
+            String::new()
+        }
+        None if function.is_async => {
+            args.push("callback func()".to_owned());
+            String::new()
+        }
+        Some(ty) => format!(" {}", types.as_type(ty)),
+        None => String::new(),
+    };
+
+    format!(
+        "\n{raw_decl}\n\n{}func {fn_name}({}){return_type} {{\n{}}}\n",
+        format_comments(&function.doc_lines, ""),
+        args.join(", "),
+        join_lines(&body, "\t")
+    )
+}
+
+/// Formats the method of the `Exports` interface for the given function.
+fn format_export_method(function: &Function, types: &TypeGenerator) -> String {
+    let mut args = format_args(function, types);
+    let return_type = match &function.return_type {
+        Some(ty) if function.is_async => {
+            args.push(format!("resolve func({})", types.as_type(ty)));
+            String::new()
+        }
+        None if function.is_async => {
+            args.push("resolve func()".to_owned());
+            String::new()
+        }
+        Some(ty) => format!(" {}", types.as_type(ty)),
+        None => String::new(),
+    };
+    format!(
+        "{}\t{}({}){return_type}\n",
+        format_comments(&function.doc_lines, "\t"),
+        get_function_name(&function.name),
+        args.join(", ")
+    )
+}
+
+fn format_export_wrapper(function: &Function, types: &TypeGenerator) -> String {
+    let name = &function.name;
+    let fn_name = get_function_name(name);
+    let raw_return_type = match &function.return_type {
+        _ if function.is_async => " FatPtr".to_owned(),
+        None => String::new(),
+        Some(ty) => format!(" {}", format_raw_type(ty)),
+    };
+
+    let mut body = Vec::new();
+    for arg in function.args.iter().filter(|arg| !arg.ty.is_primitive()) {
+        let arg_name = get_arg_name(&arg.name);
+        body.push(format!("{arg_name}Reader := openReader({arg_name}Ptr)"));
+        body.push(format!(
+            "{arg_name} := decode_{}({arg_name}Reader)",
+            types.fn_name(&arg.ty)
+        ));
+        body.push(format!(
+            "if {arg_name}Reader.failed {{\n\tPanic(\"Could not decode argument `{}` of `{fn_name}()`\")\n}}",
+            get_variable_name(&arg.name)
+        ));
+        body.push(format!("{arg_name}Reader.close()"));
+    }
+
+    let mut call_args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            match arg.ty.as_primitive() {
+                Some(primitive) => decode_primitive(primitive, &arg_name),
+                None => arg_name,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if function.is_async {
+        let mut resolve = vec!["w := writer{}".to_owned()];
+        let resolve_args = match &function.return_type {
+            Some(ty) => {
+                resolve.push(format!("encode_{}(&w, result)", types.fn_name(ty)));
+                format!("result {}", types.as_type(ty))
+            }
+            None => {
+                resolve.push("w.writeNil()".to_owned());
+                String::new()
+            }
+        };
+        resolve.push("resolveAsyncValue(asyncValuePtr, w.finish())".to_owned());
+        call_args.push(format!(
+            "func({resolve_args}) {{\n{}}}",
+            join_lines(&resolve, "\t")
+        ));
+
+        body.push("asyncValuePtr := createAsyncValue()".to_owned());
+        body.push(format!("exports.{fn_name}({})", call_args.join(", ")));
+        body.push("return asyncValuePtr".to_owned());
+    } else {
+        let call = format!("exports.{fn_name}({})", call_args.join(", "));
+        match &function.return_type {
+            None => body.push(call),
+            Some(ty) => match ty.as_primitive() {
+                Some(primitive) => {
+                    body.push(format!("return {}", encode_primitive(primitive, &call)))
+                }
+                None => {
+                    body.push(format!("result := {call}"));
+                    body.push("w := writer{}".to_owned());
+                    body.push(format!("encode_{}(&w, result)", types.fn_name(ty)));
+                    body.push("return w.finish()".to_owned());
+                }
+            },
+        }
+    }
+
+    let raw_args = function
+        .args
+        .iter()
+        .map(|arg| {
+            let arg_name = get_arg_name(&arg.name);
+            if arg.ty.is_primitive() {
+                format!("{arg_name} {}", format_raw_type(&arg.ty))
+            } else {
+                format!("{arg_name}Ptr FatPtr")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "\n//go:wasmexport __fp_gen_{name}\nfunc __fp_gen_{name}({raw_args}){raw_return_type} {{\n{}}}\n",
+        join_lines(&body, "\t")
+    )
+}
+
+/// Decodes the value behind `resultPtr` into a new `result` variable.
+fn format_decode_result(ty: &TypeIdent, types: &TypeGenerator, function_name: &str) -> String {
+    format!(
+        "r := openReader(resultPtr)\n\
+        result := decode_{}(r)\n\
+        if r.failed {{\n\t\
+        Panic(\"Could not decode result of `{}()`\")\n\
+        }}\n\
+        r.close()",
+        types.fn_name(ty),
+        get_function_name(function_name)
+    )
+}
+
+/// Formats the arguments of a function as it is exposed to the plugin.
+fn format_args(function: &Function, types: &TypeGenerator) -> Vec<String> {
+    function
+        .args
+        .iter()
+        .map(|arg| format!("{} {}", get_arg_name(&arg.name), types.as_type(&arg.ty)))
+        .collect()
+}
+
+/// Returns the type with which a value is passed across the Wasm boundary.
+/// Complex values are passed as fat pointers.
+fn format_raw_type(ty: &TypeIdent) -> &'static str {
+    match ty.as_primitive() {
+        Some(Primitive::Bool) => "uint32",
+        Some(Primitive::F32) => "float32",
+        Some(Primitive::F64) => "float64",
+        Some(Primitive::I8 | Primitive::I16 | Primitive::I32) => "int32",
+        Some(Primitive::I64) => "int64",
+        Some(Primitive::U8 | Primitive::U16 | Primitive::U32) => "uint32",
+        Some(Primitive::U64) => "uint64",
+        None => "FatPtr",
+    }
+}
+
+/// Converts a primitive value to the type with which it is passed to the host.
+fn encode_primitive(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("encodeBool({value})"),
+        Primitive::I8 | Primitive::I16 => format!("int32({value})"),
+        Primitive::U8 | Primitive::U16 => format!("uint32({value})"),
+        _ => value.to_owned(),
+    }
+}
+
+/// Converts a primitive value received from the host to its Go type.
+fn decode_primitive(primitive: Primitive, value: &str) -> String {
+    match primitive {
+        Primitive::Bool => format!("{value} != 0"),
+        Primitive::I8 => format!("int8({value})"),
+        Primitive::I16 => format!("int16({value})"),
+        Primitive::U8 => format!("uint8({value})"),
+        Primitive::U16 => format!("uint16({value})"),
+        _ => value.to_owned(),
+    }
+}
+
+/// How a type from the protocol is represented in Go.
+enum GoType<'a> {
+    Primitive(Primitive),
+    String,
+    Bytes,
+    Value,
+    Unit,
+    /// A type parameter, which only occurs in the definitions of generic types.
+    Param(String),
+    Alias(String, TypeIdent),
+    /// A struct with a single unnamed field, which becomes a defined type.
+    Newtype(TypeIdent),
+    Array(Primitive, usize),
+    Pointer(TypeIdent),
+    Option(TypeIdent),
+    List(TypeIdent),
+    Map(TypeIdent, TypeIdent),
+    Tuple(Vec<TypeIdent>),
+    TupleStruct(Vec<TypeIdent>),
+    Struct(Vec<Field>, Casing),
+    Enum(&'a Enum, TypeParams),
+}
+
+/// Maps the names of generic parameters to the arguments they are
+/// instantiated with.
+type TypeParams = Vec<(String, TypeIdent)>;
+
+/// The payload of an enum variant.
+enum Payload {
+    Unit,
+    /// A single value, which is stored in the variant field directly.
+    Newtype(TypeIdent),
+    /// Multiple unnamed fields, which are stored in an auxiliary struct as
+    /// `F0`, `F1`, etc.
+    Tuple(Vec<TypeIdent>),
+    /// Named fields, which are stored in an auxiliary struct.
+    Struct(Vec<Field>, Casing),
+}
+
+/// A field as it appears in the encoded form of a struct. The fields of
+/// flattened structs are inlined into their parent.
+struct EncodedField {
+    /// Key of the field in the encoded map.
+    key: String,
+    /// Path to the field, relative to the struct being encoded.
+    path: String,
+    field: Field,
+}
+
+/// Generates the Go definitions for all the types that are used by the
+/// protocol, along with their encoding functions. Generic types are defined
+/// using type parameters, but their encoding functions are monomorphized, so
+/// that values can be encoded without reflection.
+struct TypeGenerator<'a> {
+    types: &'a TypeMap,
+    visited: BTreeSet<String>,
+
+    /// Definitions of anonymous tuples, which Go has no equivalent for.
+    definitions: Vec<String>,
+    functions: Vec<String>,
+}
+
+impl<'a> TypeGenerator<'a> {
+    fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            visited: BTreeSet::new(),
+            definitions: Vec::new(),
+            functions: Vec::new(),
+        }
+    }
+
+    fn format_source(&self, config: &TinyGoPluginConfig) -> String {
+        let mut definitions = self
+            .types
+            .iter()
+            .filter_map(|(ident, ty)| match ty {
+                Type::Alias(name, target) => {
+                    Some(format!("type {name} = {}", self.as_type(target)))
+                }
+                Type::Enum(ty) if is_string_enum(ty) => Some(create_string_enum_definition(ty)),
+                Type::Enum(ty) => Some(self.create_enum_definition(ident, ty)),
+                Type::Struct(ty) => Some(self.create_struct_definition(ident, ty)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        definitions.extend(self.definitions.iter().cloned());
+
+        let body = [&definitions, &self.functions]
+            .iter()
+            .filter(|section| !section.is_empty())
+            .map(|section| section.join("\n\n"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        format!(
+            "{}\n{body}\n",
+            format_header("Types for TinyGo plugins", &config.package_name)
+        )
+    }
+
+    fn resolve(&self, ident: &TypeIdent) -> GoType<'a> {
+        if let Some(len) = ident.array {
+            return match Primitive::from_str(&ident.name) {
+                Ok(primitive) => GoType::Array(primitive, len.get()),
+                Err(_) => GoType::Value,
+            };
+        }
+        if let Some(primitive) = ident.as_primitive() {
+            return GoType::Primitive(primitive);
+        }
+
+        let arg = |index: usize| ident.generic_args.get(index).map(|(arg, _)| arg.clone());
+        match self.types.get(ident) {
+            Some(Type::Alias(name, target)) => GoType::Alias(name.clone(), target.clone()),
+            Some(Type::Array(primitive, len)) => GoType::Array(*primitive, *len),
+            Some(Type::Container(name, _)) => match arg(0) {
+                Some(arg) if name == "Option" => GoType::Option(arg),
+                Some(arg) => GoType::Pointer(arg),
+                None => GoType::Value,
+            },
+            Some(Type::Custom(custom)) => match custom.ts_ty.as_str() {
+                "ArrayBuffer" | "Uint8Array" => GoType::Bytes,
+                "boolean" => GoType::Primitive(Primitive::Bool),
+                "number" => GoType::Primitive(Primitive::F64),
+                "string" => GoType::String,
+                _ => GoType::Value,
+            },
+            Some(Type::Enum(ty)) => GoType::Enum(ty, get_type_params(&ty.ident, ident)),
+            Some(Type::List(_, _)) => arg(0).map(GoType::List).unwrap_or(GoType::Value),
+            Some(Type::Map(_, _, _)) => match (arg(0), arg(1)) {
+                (Some(key), Some(value)) => GoType::Map(key, value),
+                _ => GoType::Value,
+            },
+            Some(Type::Primitive(primitive)) => GoType::Primitive(*primitive),
+            Some(Type::String) => GoType::String,
+            Some(Type::Struct(ty)) => {
+                let params = get_type_params(&ty.ident, ident);
+                let fields = ty
+                    .fields
+                    .iter()
+                    .map(|field| Field {
+                        ty: substitute(&field.ty, &params),
+                        ..field.clone()
+                    })
+                    .collect::<Vec<_>>();
+                match fields.as_slice() {
+                    [field] if field.name.is_none() => GoType::Newtype(field.ty.clone()),
+                    _ if !fields.is_empty() && fields.iter().all(|field| field.name.is_none()) => {
+                        GoType::TupleStruct(fields.into_iter().map(|field| field.ty).collect())
+                    }
+                    _ => GoType::Struct(fields, ty.options.field_casing),
+                }
+            }
+            Some(Type::Tuple(items)) if items.is_empty() => GoType::Unit,
+            Some(Type::Tuple(items)) => GoType::Tuple(items.clone()),
+            Some(Type::Unit) => GoType::Unit,
+            None if ident.name == "String" => GoType::String,
+            None => GoType::Param(ident.name.clone()),
+        }
+    }
+
+    /// Resolves the given type, following any aliases and pointers.
+    fn resolve_alias(&self, ident: &TypeIdent) -> GoType<'a> {
+        match self.resolve(ident) {
+            GoType::Alias(_, target) | GoType::Pointer(target) => self.resolve_alias(&target),
+            resolved => resolved,
+        }
+    }
+
+    /// Returns the name under which the encoding functions for the type are
+    /// generated, which is unique for every instantiation of a generic type.
+    fn mangle(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            GoType::Primitive(primitive) => primitive.name(),
+            GoType::String => "String".to_owned(),
+            GoType::Bytes => "Bytes".to_owned(),
+            GoType::Value => "RawValue".to_owned(),
+            GoType::Unit => "Unit".to_owned(),
+            GoType::Param(name) | GoType::Alias(name, _) => name,
+            GoType::Array(primitive, len) => format!("Array_{}_{len}", primitive.name()),
+            GoType::Pointer(inner) => self.mangle(&inner),
+            GoType::Option(inner) => format!("Option_{}", self.mangle(&inner)),
+            GoType::List(inner) => format!("Vec_{}", self.mangle(&inner)),
+            GoType::Map(key, value) => {
+                format!("Map_{}_{}", self.mangle(&key), self.mangle(&value))
+            }
+            GoType::Tuple(items) => format!(
+                "Tuple_{}",
+                items
+                    .iter()
+                    .map(|item| self.mangle(item))
+                    .collect::<Vec<_>>()
+                    .join("_")
+            ),
+            GoType::Newtype(_)
+            | GoType::TupleStruct(_)
+            | GoType::Struct(_, _)
+            | GoType::Enum(_, _) => std::iter::once(ident.name.clone())
+                .chain(ident.generic_args.iter().map(|(arg, _)| self.mangle(arg)))
+                .collect::<Vec<_>>()
+                .join("_"),
+        }
+    }
+
+    /// Returns the Go type for the given type.
+    fn as_type(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            GoType::Primitive(primitive) => format_primitive(primitive).to_owned(),
+            GoType::String => "string".to_owned(),
+            GoType::Bytes => "[]byte".to_owned(),
+            GoType::Value => "RawValue".to_owned(),
+            GoType::Unit => "Unit".to_owned(),
+            GoType::Param(name) | GoType::Alias(name, _) => name,
+            GoType::Array(primitive, len) => format!("[{len}]{}", format_primitive(primitive)),
+            GoType::Pointer(inner) => self.as_type(&inner),
+            GoType::Option(inner) => format!("*{}", self.as_type(&inner)),
+            GoType::List(inner) => format!("[]{}", self.as_type(&inner)),
+            GoType::Map(key, value) => {
+                format!("map[{}]{}", self.as_type(&key), self.as_type(&value))
+            }
+            GoType::Tuple(_) => self.mangle(ident),
+            GoType::Newtype(_)
+            | GoType::TupleStruct(_)
+            | GoType::Struct(_, _)
+            | GoType::Enum(_, _) => format!("{}{}", ident.name, self.format_type_args(ident)),
+        }
+    }
+
+    /// Formats the type arguments with which a generic type is instantiated.
+    fn format_type_args(&self, ident: &TypeIdent) -> String {
+        if ident.generic_args.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "[{}]",
+                ident
+                    .generic_args
+                    .iter()
+                    .map(|(arg, _)| self.as_type(arg))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+    }
+
+    /// Returns the suffix of the encoding functions for the given type, which
+    /// for aliases and pointers are those of the type they refer to.
+    fn fn_name(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            GoType::Alias(_, target) | GoType::Pointer(target) => self.fn_name(&target),
+            _ => self.mangle(ident),
+        }
+    }
+
+    fn get_payload(&self, variant: &Variant, params: &TypeParams) -> Payload {
+        match &variant.ty {
+            Type::Struct(variant_ty) => Payload::Struct(
+                variant_ty
+                    .fields
+                    .iter()
+                    .map(|field| Field {
+                        ty: substitute(&field.ty, params),
+                        ..field.clone()
+                    })
+                    .collect(),
+                variant.attrs.field_casing,
+            ),
+            Type::Tuple(items) if items.len() == 1 => {
+                Payload::Newtype(substitute(&items[0], params))
+            }
+            Type::Tuple(items) if !items.is_empty() => {
+                Payload::Tuple(items.iter().map(|item| substitute(item, params)).collect())
+            }
+            _ => Payload::Unit,
+        }
+    }
+
+    /// Generates a struct with a pointer field for every variant of the enum,
+    /// of which only one should be set.
+    fn create_enum_definition(&self, ident: &TypeIdent, ty: &Enum) -> String {
+        let name = &ty.ident.name;
+        let type_params = format_type_params(ident);
+        let type_args = format_type_param_names(ident);
+
+        let mut definitions = Vec::new();
+        let mut fields = Vec::new();
+        for variant in &ty.variants {
+            let field_name = get_variable_name(&variant.name).to_pascal_case();
+            let aux_name = format!("{name}{field_name}");
+            let field_type = match self.get_payload(variant, &Vec::new()) {
+                Payload::Unit => "struct{}".to_owned(),
+                Payload::Newtype(item) => self.as_type(&item),
+                Payload::Tuple(items) => {
+                    definitions.push(self.format_tuple_struct(
+                        &format!("{aux_name}{type_params}"),
+                        &[],
+                        &items,
+                    ));
+                    format!("{aux_name}{type_args}")
+                }
+                Payload::Struct(variant_fields, _) => {
+                    definitions.push(self.format_struct(
+                        &format!("{aux_name}{type_params}"),
+                        &[],
+                        &variant_fields,
+                    ));
+                    format!("{aux_name}{type_args}")
+                }
+            };
+            fields.push((
+                format_comments(&variant.doc_lines, "\t"),
+                field_name,
+                format!("*{field_type}"),
+            ));
+        }
+
+        definitions.push(format!(
+            "{}type {name}{type_params} struct {{\n{}}}",
+            format_comments(&ty.doc_lines, ""),
+            format_struct_fields(&fields)
+        ));
+        definitions.join("\n\n")
+    }
+
+    fn create_struct_definition(&self, ident: &TypeIdent, ty: &Struct) -> String {
+        let name = format!("{}{}", ty.ident.name, format_type_params(ident));
+        let is_tuple_struct =
+            !ty.fields.is_empty() && ty.fields.iter().all(|field| field.name.is_none());
+        if is_tuple_struct && ty.fields.len() == 1 {
+            format!(
+                "{}type {name} {}",
+                format_comments(&ty.doc_lines, ""),
+                self.as_type(&ty.fields[0].ty)
+            )
+        } else if is_tuple_struct {
+            let items: Vec<_> = ty.fields.iter().map(|field| field.ty.clone()).collect();
+            self.format_tuple_struct(&name, &ty.doc_lines, &items)
+        } else {
+            self.format_struct(&name, &ty.doc_lines, &ty.fields)
+        }
+    }
+
+    fn format_struct(&self, name: &str, doc_lines: &[String], fields: &[Field]) -> String {
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let comments = format_comments(&field.doc_lines, "\t");
+                let ty = self.as_type(&field.ty);
+                if self.is_embedded(field) {
+                    (comments, ty, String::new())
+                } else {
+                    (comments, get_member_name(field), ty)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "{}type {name} struct {{\n{}}}",
+            format_comments(doc_lines, ""),
+            format_struct_fields(&fields)
+        )
+    }
+
+    fn format_tuple_struct(&self, name: &str, doc_lines: &[String], items: &[TypeIdent]) -> String {
+        let fields = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| (String::new(), format!("F{index}"), self.as_type(item)))
+            .collect::<Vec<_>>();
+        format!(
+            "{}type {name} struct {{\n{}}}",
+            format_comments(doc_lines, ""),
+            format_struct_fields(&fields)
+        )
+    }
+
+    /// Returns whether the field is embedded in the Go struct, which is the
+    /// case for flattened structs.
+    fn is_embedded(&self, field: &Field) -> bool {
+        field.attrs.flatten && matches!(self.types.get(&field.ty), Some(Type::Struct(_)))
+    }
+
+    /// Returns the name through which a field is accessed in Go. Embedded
+    /// fields are accessed by the name of their type.
+    fn get_field_member(&self, field: &Field) -> String {
+        if self.is_embedded(field) {
+            field.ty.name.clone()
+        } else {
+            get_member_name(field)
+        }
+    }
+
+    fn visit(&mut self, ident: &TypeIdent) {
+        match self.resolve(ident) {
+            GoType::Alias(_, target) | GoType::Pointer(target) => return self.visit(&target),
+            _ => {}
+        }
+
+        let name = self.mangle(ident);
+        if !self.visited.insert(name.clone()) {
+            return;
+        }
+
+        let go_type = self.as_type(ident);
+        match self.resolve(ident) {
+            GoType::Primitive(_)
+            | GoType::String
+            | GoType::Bytes
+            | GoType::Value
+            | GoType::Unit
+            | GoType::Param(_)
+            | GoType::Alias(_, _)
+            | GoType::Pointer(_) => {}
+            GoType::Newtype(inner) => {
+                self.visit(&inner);
+                self.generate_newtype(&name, &go_type, &inner);
+            }
+            GoType::Array(primitive, len) => self.generate_array(&name, &go_type, primitive, len),
+            GoType::Option(inner) => {
+                self.visit(&inner);
+                self.generate_option(&name, &go_type, &inner);
+            }
+            GoType::List(inner) => {
+                self.visit(&inner);
+                self.generate_list(&name, &go_type, &inner);
+            }
+            GoType::Map(key, value) => {
+                self.visit(&key);
+                self.visit(&value);
+                self.generate_map(&name, &go_type, &key, &value);
+            }
+            GoType::Tuple(items) => {
+                let definition = self.format_tuple_struct(&name, &[], &items);
+                self.definitions.push(definition);
+                self.generate_tuple(&name, &go_type, &items);
+            }
+            GoType::TupleStruct(items) => self.generate_tuple(&name, &go_type, &items),
+            GoType::Struct(fields, casing) => {
+                for field in &fields {
+                    self.visit(&field.ty);
+                }
+                self.push_struct_functions(&name, &go_type, &fields, casing, None);
+            }
+            GoType::Enum(ty, _) if is_string_enum(ty) => {
+                self.generate_string_enum(&name, &go_type, ty)
+            }
+            GoType::Enum(ty, params) => self.generate_enum(&name, &go_type, ident, ty, &params),
+        }
+    }
+
+    /// Adds the encoding functions for the type with the given name, which
+    /// are `encode_{name}()` and `decode_{name}()`.
+    fn push_functions(&mut self, name: &str, go_type: &str, encode: &[String], decode: &[String]) {
+        self.functions.push(format!(
+            "func encode_{name}(w *writer, value {go_type}) {{\n{}}}\n\n\
+            func decode_{name}(r *reader) {go_type} {{\n{}}}",
+            join_lines(encode, "\t"),
+            join_lines(decode, "\t")
+        ));
+    }
+
+    fn generate_newtype(&mut self, name: &str, go_type: &str, inner: &TypeIdent) {
+        let inner_name = self.fn_name(inner);
+        let encode = vec![format!(
+            "encode_{inner_name}(w, {})",
+            format_conversion(&self.as_type(inner), "value")
+        )];
+        let decode = vec![format!(
+            "return {}",
+            format_conversion(go_type, &format!("decode_{inner_name}(r)"))
+        )];
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    fn generate_array(&mut self, name: &str, go_type: &str, primitive: Primitive, len: usize) {
+        let prim = primitive.name();
+        let encode = vec![
+            "w.writeArray(len(value))".to_owned(),
+            format!("for _, item := range value {{\n\tencode_{prim}(w, item)\n}}"),
+        ];
+        let decode = vec![
+            format!("var value {go_type}"),
+            format!("if r.readArray() != {len} {{\n\tr.fail()\n\treturn value\n}}"),
+            format!("for i := range value {{\n\tvalue[i] = decode_{prim}(r)\n}}"),
+            "return value".to_owned(),
+        ];
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    fn generate_option(&mut self, name: &str, go_type: &str, inner: &TypeIdent) {
+        let inner_name = self.fn_name(inner);
+        let encode = vec![format!(
+            "if value != nil {{\n\tencode_{inner_name}(w, *value)\n}} else {{\n\tw.writeNil()\n}}"
+        )];
+        let decode = vec![
+            "if r.readNil() {\n\treturn nil\n}".to_owned(),
+            format!("value := decode_{inner_name}(r)"),
+            "return &value".to_owned(),
+        ];
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    fn generate_list(&mut self, name: &str, go_type: &str, inner: &TypeIdent) {
+        let inner_name = self.fn_name(inner);
+        let encode = vec![
+            "w.writeArray(len(value))".to_owned(),
+            format!("for _, item := range value {{\n\tencode_{inner_name}(w, item)\n}}"),
+        ];
+        let decode = vec![
+            "length := r.readArray()".to_owned(),
+            format!("value := make({go_type}, 0, length)"),
+            format!(
+                "for i := 0; i < length && !r.failed; i++ {{\n\t\
+                value = append(value, decode_{inner_name}(r))\n\
+                }}"
+            ),
+            "return value".to_owned(),
+        ];
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    fn generate_map(&mut self, name: &str, go_type: &str, key: &TypeIdent, value: &TypeIdent) {
+        let key_name = self.fn_name(key);
+        let value_name = self.fn_name(value);
+        let encode = vec![
+            "w.writeMap(len(value))".to_owned(),
+            format!(
+                "for key, item := range value {{\n\t\
+                encode_{key_name}(w, key)\n\t\
+                encode_{value_name}(w, item)\n\
+                }}"
+            ),
+        ];
+        let decode = vec![
+            "length := r.readMap()".to_owned(),
+            format!("value := make({go_type}, length)"),
+            format!(
+                "for i := 0; i < length && !r.failed; i++ {{\n\t\
+                key := decode_{key_name}(r)\n\t\
+                value[key] = decode_{value_name}(r)\n\
+                }}"
+            ),
+            "return value".to_owned(),
+        ];
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    /// Generates the encoding functions for a struct with unnamed fields,
+    /// which is encoded as an array.
+    fn generate_tuple(&mut self, name: &str, go_type: &str, items: &[TypeIdent]) {
+        for item in items {
+            self.visit(item);
+        }
+
+        let len = items.len();
+        let mut encode = vec![format!("w.writeArray({len})")];
+        encode.extend(
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| format!("encode_{}(w, value.F{index})", self.fn_name(item))),
+        );
+
+        let mut decode = vec![
+            format!("var value {go_type}"),
+            format!("if r.readArray() != {len} {{\n\tr.fail()\n\treturn value\n}}"),
+        ];
+        decode.extend(
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| format!("value.F{index} = decode_{}(r)", self.fn_name(item))),
+        );
+        decode.push("return value".to_owned());
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    /// Adds the encoding functions for a struct with named fields, which is
+    /// encoded as a map. If a tag is given, it is encoded together with the
+    /// fields, as is done for the variants of internally tagged enums.
+    fn push_struct_functions(
+        &mut self,
+        name: &str,
+        go_type: &str,
+        fields: &[Field],
+        casing: Casing,
+        tag: Option<(&str, &str)>,
+    ) {
+        let mut encoded_fields = Vec::new();
+        self.collect_fields(fields, casing, "", &mut encoded_fields);
+
+        let encode = self.format_struct_encoding(&encoded_fields, tag);
+        let decode = self.format_struct_decoding(
+            go_type,
+            &encoded_fields,
+            tag.map(|(tag, _)| tag),
+            fields.iter().any(|field| self.is_flattened(field)),
+        );
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    /// Collects the encoded fields of a struct, inlining the fields of
+    /// flattened structs.
+    fn collect_fields(
+        &self,
+        fields: &[Field],
+        casing: Casing,
+        path: &str,
+        encoded_fields: &mut Vec<EncodedField>,
+    ) {
+        for field in fields {
+            let member = self.get_field_member(field);
+            if self.is_flattened(field) {
+                if let GoType::Struct(inner_fields, inner_casing) = self.resolve_alias(&field.ty) {
+                    self.collect_fields(
+                        &inner_fields,
+                        inner_casing,
+                        &format!("{path}{member}."),
+                        encoded_fields,
+                    );
+                    continue;
+                }
+            }
+
+            encoded_fields.push(EncodedField {
+                key: get_field_name(field, casing),
+                path: format!("{path}{member}"),
+                field: field.clone(),
+            });
+        }
+    }
+
+    /// Returns whether the fields of the given field should be flattened into
+    /// the parent struct. Only structs with named fields can be flattened;
+    /// other flattened types are treated as regular fields.
+    fn is_flattened(&self, field: &Field) -> bool {
+        field.attrs.flatten && matches!(self.resolve_alias(&field.ty), GoType::Struct(_, _))
+    }
+
+    fn format_struct_encoding(
+        &self,
+        fields: &[EncodedField],
+        tag: Option<(&str, &str)>,
+    ) -> Vec<String> {
+        let mut static_count = if tag.is_some() { 1 } else { 0 };
+        let mut count_lines = Vec::new();
+        let mut encode_lines = Vec::new();
+        if let Some((tag, variant_name)) = tag {
+            encode_lines.push(format!(
+                "w.writeString(\"{tag}\")\nw.writeString(\"{variant_name}\")"
+            ));
+        }
+        for field in fields {
+            let encode = format!(
+                "w.writeString(\"{}\")\nencode_{}(w, value.{})",
+                field.key,
+                self.fn_name(&field.field.ty),
+                field.path
+            );
+            match self.format_serialize_condition(&field.field, &field.path) {
+                Some(condition) => {
+                    count_lines.push(format!("if {condition} {{\n\tcount++\n}}"));
+                    encode_lines.push(format!(
+                        "if {condition} {{\n{}\n}}",
+                        indent_lines(&encode, "\t")
+                    ));
+                }
+                None => {
+                    static_count += 1;
+                    encode_lines.push(encode);
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        if count_lines.is_empty() {
+            lines.push(format!("w.writeMap({static_count})"));
+        } else {
+            lines.push(format!("count := {static_count}"));
+            lines.extend(count_lines);
+            lines.push("w.writeMap(count)".to_owned());
+        }
+        lines.extend(encode_lines);
+        lines
+    }
+
+    fn format_struct_decoding(
+        &self,
+        go_type: &str,
+        fields: &[EncodedField],
+        tag: Option<&str>,
+        has_flattened_fields: bool,
+    ) -> Vec<String> {
+        let mut required = Vec::new();
+        let mut cases = Vec::new();
+        for field in fields {
+            let ty = &field.field.ty;
+            let mut case = format!(
+                "case \"{}\":\n\tvalue.{} = decode_{}(r)",
+                field.key,
+                field.path,
+                self.fn_name(ty)
+            );
+            let is_required = field.field.attrs.default.is_none()
+                && !matches!(self.resolve_alias(ty), GoType::Option(_));
+            if is_required {
+                let flag = format!("has{}", field.path.replace('.', ""));
+                case.push_str(&format!("\n\t{flag} = true"));
+                required.push(flag);
+            }
+            cases.push(case);
+        }
+        if let Some(tag) = tag {
+            cases.push(format!("case \"{tag}\":\n\tr.skip()"));
+        }
+
+        let mut lines = vec![format!("var value {go_type}")];
+        if !required.is_empty() {
+            lines.push(format!("var {} bool", required.join(", ")));
+        }
+
+        let names = if has_flattened_fields {
+            "nil".to_owned()
+        } else {
+            let names = tag
+                .iter()
+                .map(|tag| format!("\"{tag}\""))
+                .chain(fields.iter().map(|field| format!("\"{}\"", field.key)))
+                .collect::<Vec<_>>();
+            if names.is_empty() {
+                "nil".to_owned()
+            } else {
+                format!("[]string{{{}}}", names.join(", "))
+            }
+        };
+        lines.push(format!("fields := r.readStruct({names})"));
+
+        let loop_body = if cases.is_empty() {
+            "fields.skip()".to_owned()
+        } else {
+            cases.push("default:\n\tfields.skip()".to_owned());
+            format!("switch fields.key {{\n{}\n}}", cases.join("\n"))
+        };
+        lines.push(format!(
+            "for fields.next() {{\n{}\n}}",
+            indent_lines(&loop_body, "\t")
+        ));
+
+        if !required.is_empty() {
+            let condition = required
+                .iter()
+                .map(|flag| format!("!{flag}"))
+                .collect::<Vec<_>>()
+                .join(" || ");
+            lines.push(format!("if {condition} {{\n\tr.fail()\n}}"));
+        }
+        lines.push("return value".to_owned());
+        lines
+    }
+
+    /// Returns the condition under which the field should be serialized, if
+    /// it has a `skip_serializing_if` attribute we can translate.
+    fn format_serialize_condition(&self, field: &Field, path: &str) -> Option<String> {
+        let skip_path = field.attrs.skip_serializing_if.as_deref()?;
+        match self.resolve_alias(&field.ty) {
+            GoType::Option(_) if skip_path.ends_with("is_none") => {
+                Some(format!("value.{path} != nil"))
+            }
+            GoType::String | GoType::Bytes | GoType::List(_) | GoType::Map(_, _)
+                if skip_path.ends_with("is_empty") =>
+            {
+                Some(format!("len(value.{path}) > 0"))
+            }
+            _ => None,
+        }
+    }
+
+    /// Enums that are generated as string types are encoded as their string
+    /// value.
+    fn generate_string_enum(&mut self, name: &str, go_type: &str, ty: &Enum) {
+        let constants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                format!(
+                    "{go_type}{}",
+                    get_variable_name(&variant.name).to_pascal_case()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let encode = vec!["w.writeString(string(value))".to_owned()];
+        let decode = vec![
+            format!("value := {go_type}(r.readString())"),
+            format!("switch value {{\ncase {constants}:\ndefault:\n\tr.fail()\n}}"),
+            "return value".to_owned(),
+        ];
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+
+    fn generate_enum(
+        &mut self,
+        name: &str,
+        go_type: &str,
+        ident: &TypeIdent,
+        ty: &Enum,
+        params: &TypeParams,
+    ) {
+        let opts = &ty.options;
+        let tag = opts
+            .tag_prop_name
+            .as_deref()
+            .filter(|_| opts.content_prop_name.is_none() && !opts.untagged);
+        let type_args = self.format_type_args(ident);
+
+        let mut variants = Vec::new();
+        for variant in &ty.variants {
+            let payload = self.get_payload(variant, params);
+            let field_name = get_variable_name(&variant.name).to_pascal_case();
+            let variant_name = get_variant_name(variant, opts);
+            let helper_name = format!("{name}_{field_name}");
+            let aux_type = format!("{}{field_name}{type_args}", ty.ident.name);
+
+            // Variants with multiple fields get their own encoding functions,
+            // as do newtype variants that need to be encoded with their tag:
+            let payload_fn = match &payload {
+                Payload::Unit => None,
+                Payload::Newtype(item) => {
+                    self.visit(item);
+                    match tag {
+                        Some(tag) => match self.resolve_alias(item) {
+                            GoType::Struct(fields, casing) => {
+                                let item_type = self.as_type(item);
+                                self.push_struct_functions(
+                                    &helper_name,
+                                    &item_type,
+                                    &fields,
+                                    casing,
+                                    Some((tag, &variant_name)),
+                                );
+                                Some(helper_name)
+                            }
+                            _ => panic!(
+                                "Variant {}::{} cannot be internally tagged, since it does not contain a struct",
+                                ty.ident.name, variant.name
+                            ),
+                        },
+                        None => Some(self.fn_name(item)),
+                    }
+                }
+                Payload::Tuple(items) => {
+                    if tag.is_some() {
+                        panic!(
+                            "Variant {}::{} cannot be internally tagged, since it does not contain a struct",
+                            ty.ident.name, variant.name
+                        );
+                    }
+                    self.generate_tuple(&helper_name, &aux_type, items);
+                    Some(helper_name)
+                }
+                Payload::Struct(fields, casing) => {
+                    for field in fields {
+                        self.visit(&field.ty);
+                    }
+                    self.push_struct_functions(
+                        &helper_name,
+                        &aux_type,
+                        fields,
+                        *casing,
+                        tag.map(|tag| (tag, variant_name.as_str())),
+                    );
+                    Some(helper_name)
+                }
+            };
+            variants.push((field_name, variant_name, payload_fn));
+        }
+
+        let encode_cases = variants
+            .iter()
+            .map(|(field_name, variant_name, payload_fn)| {
+                let lines = format_variant_encoding(field_name, variant_name, payload_fn, opts);
+                format!(
+                    "case value.{field_name} != nil:\n{}",
+                    join_lines(&lines, "\t")
+                )
+            })
+            .collect::<String>();
+        let encode = vec![format!(
+            "switch {{\n{encode_cases}default:\n\tPanic(\"No variant set for enum {}\")\n}}",
+            ty.ident.name
+        )];
+
+        let decode = format_enum_decoding(go_type, &variants, opts);
+        self.push_functions(name, go_type, &encode, &decode);
+    }
+}
+
+fn format_variant_encoding(
+    field_name: &str,
+    variant_name: &str,
+    payload_fn: &Option<String>,
+    opts: &EnumOptions,
+) -> Vec<String> {
+    let encode_payload = payload_fn
+        .as_ref()
+        .map(|payload_fn| format!("encode_{payload_fn}(w, *value.{field_name})"));
+
+    if opts.untagged {
+        vec![encode_payload.unwrap_or_else(|| "w.writeNil()".to_owned())]
+    } else if let Some(tag) = &opts.tag_prop_name {
+        let write_tag = format!("w.writeString(\"{tag}\")\nw.writeString(\"{variant_name}\")");
+        match (&opts.content_prop_name, encode_payload) {
+            (Some(content), Some(encode_payload)) => vec![
+                "w.writeMap(2)".to_owned(),
+                write_tag,
+                format!("w.writeString(\"{content}\")"),
+                encode_payload,
+            ],
+            // The tag is encoded together with the fields of the payload:
+            (None, Some(encode_payload)) => vec![encode_payload],
+            (_, None) => vec!["w.writeMap(1)".to_owned(), write_tag],
+        }
+    } else {
+        match encode_payload {
+            Some(encode_payload) => vec![
+                "w.writeMap(1)".to_owned(),
+                format!("w.writeString(\"{variant_name}\")"),
+                encode_payload,
+            ],
+            None => vec![format!("w.writeString(\"{variant_name}\")")],
+        }
+    }
+}
+
+fn format_enum_decoding(
+    go_type: &str,
+    variants: &[(String, String, Option<String>)],
+    opts: &EnumOptions,
+) -> Vec<String> {
+    let mut lines = vec![format!("var value {go_type}")];
+    if opts.untagged {
+        lines.push("attempt := r.attempt()".to_owned());
+        for (index, (field_name, _, payload_fn)) in variants.iter().enumerate() {
+            if index > 0 {
+                lines.push("attempt = r.attempt()".to_owned());
+            }
+            lines.push(match payload_fn {
+                Some(payload_fn) => format!(
+                    "if payload := decode_{payload_fn}(attempt); !attempt.failed {{\n\t\
+                    r.commit(attempt)\n\t\
+                    value.{field_name} = &payload\n\t\
+                    return value\n\
+                    }}"
+                ),
+                None => format!(
+                    "if attempt.readNil() {{\n\t\
+                    r.commit(attempt)\n\t\
+                    value.{field_name} = &struct{{}}{{}}\n\t\
+                    return value\n\
+                    }}"
+                ),
+            });
+        }
+        lines.push("r.fail()".to_owned());
+        lines.push("return value".to_owned());
+        return lines;
+    }
+
+    let is_internally_tagged = opts.tag_prop_name.is_some() && opts.content_prop_name.is_none();
+    let has_content = variants
+        .iter()
+        .any(|(_, _, payload_fn)| payload_fn.is_some());
+    let content = if has_content { "content" } else { "_" };
+    let switch_expr = match (&opts.tag_prop_name, &opts.content_prop_name) {
+        (Some(tag), Some(content_prop)) => {
+            lines.push(format!(
+                "name, {content} := r.readAdjacent(\"{tag}\", \"{content_prop}\")"
+            ));
+            "name".to_owned()
+        }
+        (Some(tag), None) => format!("r.readTag(\"{tag}\")"),
+        _ => {
+            lines.push(format!("name, {content} := r.readVariant()"));
+            "name".to_owned()
+        }
+    };
+
+    let mut cases = Vec::new();
+    for (field_name, variant_name, payload_fn) in variants {
+        let mut body = Vec::new();
+        match payload_fn {
+            None => {
+                if is_internally_tagged {
+                    body.push("r.skip()".to_owned());
+                }
+                body.push(format!("value.{field_name} = &struct{{}}{{}}"));
+            }
+            Some(payload_fn) if is_internally_tagged => {
+                body.push(format!("payload := decode_{payload_fn}(r)"));
+                body.push(format!("value.{field_name} = &payload"));
+            }
+            Some(payload_fn) => {
+                body.push(format!("payload := decode_{payload_fn}(content)"));
+                body.push(format!("value.{field_name} = &payload"));
+                body.push("if content.failed {\n\tr.fail()\n}".to_owned());
+            }
+        }
+        cases.push(format!(
+            "case \"{variant_name}\":\n{}",
+            join_lines(&body, "\t")
+        ));
+    }
+    lines.push(format!(
+        "switch {switch_expr} {{\n{}default:\n\tr.fail()\n}}",
+        cases.concat()
+    ));
+    lines.push("return value".to_owned());
+    lines
+}
+
+/// Enums with only unit variants that are represented using their name are
+/// generated as string types.
+fn is_string_enum(ty: &Enum) -> bool {
+    ty.ident.generic_args.is_empty()
+        && !ty.options.untagged
+        && ty.options.tag_prop_name.is_none()
+        && ty
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.ty, Type::Unit))
+}
+
+fn create_string_enum_definition(ty: &Enum) -> String {
+    let name = &ty.ident.name;
+    let constants = ty
+        .variants
+        .iter()
+        .map(|variant| {
+            format!(
+                "{}\t{}{} {} = \"{}\"\n",
+                format_comments(&variant.doc_lines, "\t"),
+                name,
+                get_variable_name(&variant.name).to_pascal_case(),
+                name,
+                get_variant_name(variant, &ty.options)
+            )
+        })
+        .collect::<String>();
+    format!(
+        "{}type {} string\n\nconst (\n{})",
+        format_comments(&ty.doc_lines, ""),
+        name,
+        constants
+    )
+}
+
+/// Formats struct fields, given as tuples of comments, name and type, with
+/// their types aligned the way `gofmt` does. Embedded fields have an empty
+/// type.
+fn format_struct_fields(fields: &[(String, String, String)]) -> String {
+    let name_width = fields
+        .iter()
+        .filter(|(_, _, ty)| !ty.is_empty())
+        .map(|(_, name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    fields
+        .iter()
+        .map(|(comments, name, ty)| {
+            let line = if ty.is_empty() {
+                name.clone()
+            } else {
+                format!("{name:name_width$} {ty}")
+            };
+            format!("{comments}\t{line}\n")
+        })
+        .collect()
+}
+
+/// Formats a conversion of the given expression to the given type.
+fn format_conversion(go_type: &str, expr: &str) -> String {
+    if go_type.starts_with('*') {
+        format!("({go_type})({expr})")
+    } else {
+        format!("{go_type}({expr})")
+    }
+}
+
+/// Formats the type parameters for the definition of a generic type.
+fn format_type_params(ident: &TypeIdent) -> String {
+    if ident.generic_args.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "[{}]",
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| format!("{} any", arg.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Formats the type arguments for referring to a generic type from its own
+/// definition.
+fn format_type_param_names(ident: &TypeIdent) -> String {
+    if ident.generic_args.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "[{}]",
+            ident
+                .generic_args
+                .iter()
+                .map(|(arg, _)| arg.name.clone())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+fn format_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::F32 => "float32",
+        Primitive::F64 => "float64",
+        Primitive::I8 => "int8",
+        Primitive::I16 => "int16",
+        Primitive::I32 => "int32",
+        Primitive::I64 => "int64",
+        Primitive::U8 => "uint8",
+        Primitive::U16 => "uint16",
+        Primitive::U32 => "uint32",
+        Primitive::U64 => "uint64",
+    }
+}
+
+fn get_type_params(definition: &TypeIdent, ident: &TypeIdent) -> TypeParams {
+    definition
+        .generic_args
+        .iter()
+        .zip(ident.generic_args.iter())
+        .map(|((param, _), (arg, _))| (param.name.clone(), arg.clone()))
+        .collect()
+}
+
+/// Replaces generic parameters in the given type with their arguments.
+fn substitute(ident: &TypeIdent, params: &[(String, TypeIdent)]) -> TypeIdent {
+    if ident.generic_args.is_empty() && ident.array.is_none() {
+        if let Some((_, arg)) = params.iter().find(|(name, _)| name == &ident.name) {
+            return arg.clone();
+        }
+    }
+
+    let mut ident = ident.clone();
+    ident.generic_args = ident
+        .generic_args
+        .iter()
+        .map(|(arg, bounds)| (substitute(arg, params), bounds.clone()))
+        .collect();
+    ident
+}
+
+fn format_comments(doc_lines: &[String], indent: &str) -> String {
+    doc_lines
+        .iter()
+        .map(|line| format!("{}//{}\n", indent, line.trim_end()))
+        .collect()
+}
+
+fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(field.name.as_deref().unwrap_or_default()))
+    }
+}
+
+fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.rename.as_ref() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+/// Returns the name of the Go struct field for the given field.
+fn get_member_name(field: &Field) -> String {
+    get_variable_name(field.name.as_deref().unwrap_or_default()).to_pascal_case()
+}
+
+/// Returns the name under which a function is exposed to the plugin.
+fn get_function_name(name: &str) -> String {
+    get_variable_name(name).to_pascal_case()
+}
+
+/// Returns a name that can be used for an argument in Go.
+fn get_arg_name(name: &str) -> String {
+    let name = get_variable_name(name).to_camel_case();
+    if GO_KEYWORDS.contains(&name.as_str()) || RESERVED_NAMES.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn indent_lines(lines: &str, indent: &str) -> String {
+    lines
+        .trim_end()
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn join_lines(lines: &[String], indent: &str) -> String {
+    lines
+        .iter()
+        .map(|line| format!("{}\n", indent_lines(line, indent)))
+        .collect()
+}
@@ -0,0 +1,882 @@
+// ============================================= //
+// Support library for WebAssembly plugins       //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+// Code generated by fp-bindgen. DO NOT EDIT.
+
+package bindings
+
+import (
+	"math"
+	"unsafe"
+)
+
+// FatPtr points to data in the memory of the plugin. The upper 32 bits contain
+// the address, while the lower 32 bits contain the length of the data.
+type FatPtr = uint64
+
+const (
+	// Extension bit in the length of a fat pointer, which indicates the length
+	// is stored in a 4-byte little-endian header, directly followed by the data.
+	fatPtrLenHeader = 0x0100_0000
+	fatPtrMaxLen    = 0x00FF_FFFF
+
+	asyncStatusReady = 1
+	asyncValueSize   = 12 // std::mem::size_of::<AsyncValue>()
+)
+
+// Unit represents Rust's unit type, which is serialized as nil.
+type Unit struct{}
+
+// RawValue contains an arbitrary MessagePack-encoded value, for types that have
+// no Go equivalent. An empty value is serialized as nil.
+type RawValue []byte
+
+//go:wasmimport fp __fp_host_panic
+func __fp_host_panic(guestPanic FatPtr)
+
+//go:wasmimport fp __fp_host_resolve_async_value
+func __fp_host_resolve_async_value(asyncValuePtr, resultPtr FatPtr)
+
+// allocations keeps the memory handed out by __fp_malloc() reachable until it
+// is passed to __fp_free(), so the garbage collector does not reclaim memory
+// that is still in use by the host.
+var allocations = make(map[uint32][]byte)
+
+// pendingValues contains the handlers for async values that have not been
+// resolved by the host yet.
+var pendingValues = make(map[FatPtr]func(resultPtr FatPtr))
+
+// Panic reports a panic to the host, which aborts the current call into the
+// plugin.
+func Panic(message string) {
+	w := writer{}
+	w.writeMap(2)
+	w.writeString("message")
+	w.writeString(message)
+	w.writeString("location")
+	w.writeNil()
+	__fp_host_panic(w.finish())
+	panic(message)
+}
+
+// Memory management, as required by the spec:
+
+//go:wasmexport __fp_malloc
+func __fp_malloc(length uint32) FatPtr {
+	size := uint64(length)
+	hasHeader := length > fatPtrMaxLen
+	if hasHeader {
+		size += 4
+	}
+	if size == 0 {
+		size = 1
+	}
+
+	data := make([]byte, size)
+	ptr := uint32(uintptr(unsafe.Pointer(&data[0])))
+	allocations[ptr] = data
+	if hasHeader {
+		putUint32(data, length)
+		return toFatPtr(ptr, fatPtrLenHeader)
+	}
+	return toFatPtr(ptr, length)
+}
+
+//go:wasmexport __fp_free
+func __fp_free(ptr FatPtr) {
+	delete(allocations, uint32(ptr>>32))
+}
+
+func toFatPtr(ptr, length uint32) FatPtr {
+	return FatPtr(ptr)<<32 | FatPtr(length)
+}
+
+// fatPtrData returns the data behind a fat pointer, which must have been
+// allocated using __fp_malloc().
+func fatPtrData(ptr FatPtr) []byte {
+	if ptr == 0 {
+		return nil
+	}
+
+	data, ok := allocations[uint32(ptr>>32)]
+	if !ok {
+		Panic("Invalid fat pointer")
+	}
+	length := uint32(ptr)
+	if length == fatPtrLenHeader {
+		return data[4 : 4+uint64(getUint32(data))]
+	} else if length > fatPtrMaxLen {
+		Panic("Unknown extension bits")
+	}
+	return data[:length]
+}
+
+func getUint32(data []byte) uint32 {
+	return uint32(data[0]) | uint32(data[1])<<8 | uint32(data[2])<<16 | uint32(data[3])<<24
+}
+
+func putUint32(data []byte, value uint32) {
+	data[0] = byte(value)
+	data[1] = byte(value >> 8)
+	data[2] = byte(value >> 16)
+	data[3] = byte(value >> 24)
+}
+
+func encodeBool(value bool) uint32 {
+	if value {
+		return 1
+	}
+	return 0
+}
+
+// Encoding:
+
+// writer is a growable buffer for encoding MessagePack.
+type writer struct {
+	data []byte
+}
+
+// finish copies the encoded data into memory allocated using __fp_malloc(), so
+// that it can be passed to the host.
+func (w *writer) finish() FatPtr {
+	ptr := __fp_malloc(uint32(len(w.data)))
+	copy(fatPtrData(ptr), w.data)
+	return ptr
+}
+
+func (w *writer) writeNil() {
+	w.data = append(w.data, 0xc0)
+}
+
+func (w *writer) writeBool(value bool) {
+	if value {
+		w.data = append(w.data, 0xc3)
+	} else {
+		w.data = append(w.data, 0xc2)
+	}
+}
+
+func (w *writer) writeInt(value int64) {
+	switch {
+	case value >= 0:
+		w.writeUint(uint64(value))
+	case value >= -32:
+		w.data = append(w.data, byte(value))
+	case value >= math.MinInt8:
+		w.writeHeader(0xd0, uint64(value), 1)
+	case value >= math.MinInt16:
+		w.writeHeader(0xd1, uint64(value), 2)
+	case value >= math.MinInt32:
+		w.writeHeader(0xd2, uint64(value), 4)
+	default:
+		w.writeHeader(0xd3, uint64(value), 8)
+	}
+}
+
+func (w *writer) writeUint(value uint64) {
+	switch {
+	case value < 0x80:
+		w.data = append(w.data, byte(value))
+	case value <= math.MaxUint8:
+		w.writeHeader(0xcc, value, 1)
+	case value <= math.MaxUint16:
+		w.writeHeader(0xcd, value, 2)
+	case value <= math.MaxUint32:
+		w.writeHeader(0xce, value, 4)
+	default:
+		w.writeHeader(0xcf, value, 8)
+	}
+}
+
+func (w *writer) writeFloat32(value float32) {
+	w.writeHeader(0xca, uint64(math.Float32bits(value)), 4)
+}
+
+func (w *writer) writeFloat64(value float64) {
+	w.writeHeader(0xcb, math.Float64bits(value), 8)
+}
+
+func (w *writer) writeString(value string) {
+	length := uint64(len(value))
+	switch {
+	case length < 32:
+		w.data = append(w.data, 0xa0|byte(length))
+	case length <= math.MaxUint8:
+		w.writeHeader(0xd9, length, 1)
+	case length <= math.MaxUint16:
+		w.writeHeader(0xda, length, 2)
+	default:
+		w.writeHeader(0xdb, length, 4)
+	}
+	w.data = append(w.data, value...)
+}
+
+func (w *writer) writeBytes(value []byte) {
+	length := uint64(len(value))
+	switch {
+	case length <= math.MaxUint8:
+		w.writeHeader(0xc4, length, 1)
+	case length <= math.MaxUint16:
+		w.writeHeader(0xc5, length, 2)
+	default:
+		w.writeHeader(0xc6, length, 4)
+	}
+	w.data = append(w.data, value...)
+}
+
+func (w *writer) writeArray(length int) {
+	switch {
+	case length < 16:
+		w.data = append(w.data, 0x90|byte(length))
+	case length <= math.MaxUint16:
+		w.writeHeader(0xdc, uint64(length), 2)
+	default:
+		w.writeHeader(0xdd, uint64(length), 4)
+	}
+}
+
+func (w *writer) writeMap(length int) {
+	switch {
+	case length < 16:
+		w.data = append(w.data, 0x80|byte(length))
+	case length <= math.MaxUint16:
+		w.writeHeader(0xde, uint64(length), 2)
+	default:
+		w.writeHeader(0xdf, uint64(length), 4)
+	}
+}
+
+func (w *writer) writeRaw(value []byte) {
+	w.data = append(w.data, value...)
+}
+
+// writeHeader writes the given type byte, followed by the given number of
+// least-significant bytes of the value in big-endian order.
+func (w *writer) writeHeader(kind byte, value uint64, size int) {
+	w.data = append(w.data, kind)
+	for i := size - 1; i >= 0; i-- {
+		w.data = append(w.data, byte(value>>(8*i)))
+	}
+}
+
+// Decoding:
+
+// reader is a cursor for decoding MessagePack.
+//
+// Decoding functions don't return errors. Instead, they mark the reader as
+// failed and return a zero value, which should not be used. Once a reader has
+// failed, all further reads fail as well.
+type reader struct {
+	data   []byte
+	pos    int
+	failed bool
+
+	// strict indicates unknown fields are rejected. This is used when
+	// attempting to decode the variants of untagged enums.
+	strict bool
+
+	// ptr is the fat pointer the reader was opened with, if any.
+	ptr FatPtr
+}
+
+// openReader opens a reader for the data behind a fat pointer received from the
+// host.
+func openReader(ptr FatPtr) *reader {
+	return &reader{data: fatPtrData(ptr), ptr: ptr}
+}
+
+// close frees the data behind the fat pointer the reader was opened with.
+func (r *reader) close() {
+	if r.ptr != 0 {
+		__fp_free(r.ptr)
+		r.ptr = 0
+	}
+}
+
+func (r *reader) fail() {
+	r.failed = true
+}
+
+// attempt creates a copy of the reader for attempting to decode a variant of an
+// untagged enum, which rejects unknown fields.
+func (r *reader) attempt() *reader {
+	return &reader{data: r.data, pos: r.pos, failed: r.failed, strict: true}
+}
+
+// commit advances the reader past a successful attempt.
+func (r *reader) commit(attempt *reader) {
+	r.pos = attempt.pos
+}
+
+// readNil reads a nil value, returning whether there was one. Nothing is
+// consumed if the next value is not nil.
+func (r *reader) readNil() bool {
+	if r.peekType() == 0xc0 {
+		r.pos++
+		return true
+	}
+	return false
+}
+
+func (r *reader) readBool() bool {
+	kind := r.peekType()
+	if kind == 0xc2 || kind == 0xc3 {
+		r.pos++
+		return kind == 0xc3
+	}
+	r.fail()
+	return false
+}
+
+// readInt reads a signed integer, which should be within the given range.
+func (r *reader) readInt(min, max int64) int64 {
+	bits, negative := r.readInteger()
+	value := int64(bits)
+	if (!negative && bits > math.MaxInt64) || value < min || value > max {
+		r.fail()
+		return 0
+	}
+	return value
+}
+
+// readUint reads an unsigned integer, which should not exceed the given
+// maximum.
+func (r *reader) readUint(max uint64) uint64 {
+	bits, negative := r.readInteger()
+	if negative || bits > max {
+		r.fail()
+		return 0
+	}
+	return bits
+}
+
+func (r *reader) readFloat64() float64 {
+	switch r.peekType() {
+	case 0xca:
+		r.pos++
+		return float64(math.Float32frombits(uint32(r.readBigEndian(4))))
+	case 0xcb:
+		r.pos++
+		return math.Float64frombits(r.readBigEndian(8))
+	}
+	bits, negative := r.readInteger()
+	if negative {
+		return float64(int64(bits))
+	}
+	return float64(bits)
+}
+
+func (r *reader) readString() string {
+	length := r.readStringLength()
+	return string(r.consume(length))
+}
+
+// readBytes reads a binary value. Strings are accepted as well.
+func (r *reader) readBytes() []byte {
+	kind := r.peekType()
+	length := 0
+	if kind >= 0xc4 && kind <= 0xc6 {
+		r.pos++
+		length = int(r.readBigEndian(1 << (kind - 0xc4)))
+	} else {
+		length = r.readStringLength()
+	}
+	return append([]byte(nil), r.consume(length)...)
+}
+
+// readArray reads the header of an array, returning its length.
+func (r *reader) readArray() int {
+	kind := r.peekType()
+	length := 0
+	switch {
+	case kind&0xf0 == 0x90:
+		r.pos++
+		length = kind & 0x0f
+	case kind == 0xdc || kind == 0xdd:
+		r.pos++
+		length = int(r.readBigEndian(2 << (kind - 0xdc)))
+	default:
+		r.fail()
+		return 0
+	}
+
+	// Every element takes at least one byte, which protects us against
+	// allocating memory for bogus lengths:
+	if length > len(r.data)-r.pos {
+		r.fail()
+		return 0
+	}
+	return length
+}
+
+// readMap reads the header of a map, returning its number of entries.
+func (r *reader) readMap() int {
+	kind := r.peekType()
+	length := 0
+	switch {
+	case kind&0xf0 == 0x80:
+		r.pos++
+		length = kind & 0x0f
+	case kind == 0xde || kind == 0xdf:
+		r.pos++
+		length = int(r.readBigEndian(2 << (kind - 0xde)))
+	default:
+		r.fail()
+		return 0
+	}
+
+	if 2*length > len(r.data)-r.pos {
+		r.fail()
+		return 0
+	}
+	return length
+}
+
+// readRaw reads the next value without decoding it.
+func (r *reader) readRaw() RawValue {
+	start := r.pos
+	r.skip()
+	if r.failed {
+		return nil
+	}
+	return append(RawValue(nil), r.data[start:r.pos]...)
+}
+
+// readStruct starts reading a struct, which may be encoded either as a map or,
+// if field names are given, as an array containing the field values in order.
+func (r *reader) readStruct(names []string) *fieldReader {
+	fields := &fieldReader{r: r, names: names, strict: r.strict}
+
+	// Only the struct itself is strict, not any of its fields:
+	r.strict = false
+
+	if len(names) > 0 && r.isArray() {
+		fields.isArray = true
+		fields.length = r.readArray()
+	} else {
+		fields.length = r.readMap()
+	}
+	return fields
+}
+
+// readVariant reads the name of an externally tagged enum variant, along with a
+// reader for its content, if there is any.
+func (r *reader) readVariant() (string, *reader) {
+	kind := r.peekType()
+	if kind&0xe0 == 0xa0 || (kind >= 0xd9 && kind <= 0xdb) {
+		return r.readString(), r.empty()
+	}
+
+	if r.readMap() != 1 {
+		r.fail()
+		return "", r.empty()
+	}
+	name := r.readString()
+	content := r.at(r.pos)
+	r.skip()
+	return name, content
+}
+
+// readTag reads the name of an internally tagged enum variant, without
+// consuming anything.
+func (r *reader) readTag(tag string) string {
+	lookahead := r.at(r.pos)
+	if lookahead.isArray() {
+		if lookahead.readArray() > 0 {
+			return lookahead.readString()
+		}
+		r.fail()
+		return ""
+	}
+
+	length := lookahead.readMap()
+	for i := 0; i < length && !lookahead.failed; i++ {
+		if lookahead.readString() == tag {
+			return lookahead.readString()
+		}
+		lookahead.skip()
+	}
+	r.fail()
+	return ""
+}
+
+// readAdjacent reads the name of an adjacently tagged enum variant, along with
+// a reader for its content, if there is any.
+func (r *reader) readAdjacent(tag, contentKey string) (string, *reader) {
+	if r.isArray() {
+		length := r.readArray()
+		if length != 1 && length != 2 {
+			r.fail()
+			return "", r.empty()
+		}
+		name := r.readString()
+		if length == 1 {
+			return name, r.empty()
+		}
+		content := r.at(r.pos)
+		r.skip()
+		return name, content
+	}
+
+	name, hasName := "", false
+	content := r.empty()
+	length := r.readMap()
+	for i := 0; i < length && !r.failed; i++ {
+		switch r.readString() {
+		case tag:
+			name, hasName = r.readString(), true
+		case contentKey:
+			content = r.at(r.pos)
+			r.skip()
+		default:
+			r.skip()
+		}
+	}
+	if !hasName {
+		r.fail()
+	}
+	return name, content
+}
+
+func (r *reader) isArray() bool {
+	kind := r.peekType()
+	return kind&0xf0 == 0x90 || kind == 0xdc || kind == 0xdd
+}
+
+// skip skips over the next value, including any nested values.
+func (r *reader) skip() {
+	for pending := uint64(1); pending > 0 && !r.failed; pending-- {
+		kind := r.peekType()
+		if kind < 0 {
+			r.fail()
+			return
+		}
+		r.pos++
+
+		size := 0
+		switch {
+		case kind <= 0x7f || kind >= 0xe0 || kind == 0xc0 || kind == 0xc2 || kind == 0xc3:
+		case kind&0xe0 == 0xa0:
+			size = kind & 0x1f
+		case kind&0xf0 == 0x90:
+			pending += uint64(kind & 0x0f)
+		case kind&0xf0 == 0x80:
+			pending += 2 * uint64(kind&0x0f)
+		case kind >= 0xc4 && kind <= 0xc6:
+			size = int(r.readBigEndian(1 << (kind - 0xc4)))
+		case kind >= 0xc7 && kind <= 0xc9:
+			// Extension type:
+			size = int(r.readBigEndian(1<<(kind-0xc7))) + 1
+		case kind == 0xca:
+			size = 4
+		case kind == 0xcb:
+			size = 8
+		case kind >= 0xcc && kind <= 0xcf:
+			size = 1 << (kind - 0xcc)
+		case kind >= 0xd0 && kind <= 0xd3:
+			size = 1 << (kind - 0xd0)
+		case kind >= 0xd4 && kind <= 0xd8:
+			size = 1<<(kind-0xd4) + 1
+		case kind >= 0xd9 && kind <= 0xdb:
+			size = int(r.readBigEndian(1 << (kind - 0xd9)))
+		case kind == 0xdc || kind == 0xdd:
+			pending += r.readBigEndian(2 << (kind - 0xdc))
+		case kind == 0xde || kind == 0xdf:
+			pending += 2 * r.readBigEndian(2<<(kind-0xde))
+		default:
+			r.fail()
+			return
+		}
+		r.consume(size)
+	}
+}
+
+// at returns a reader for the same data, positioned at the given offset.
+func (r *reader) at(pos int) *reader {
+	return &reader{data: r.data, pos: pos, failed: r.failed}
+}
+
+// empty returns a reader from which nothing can be decoded.
+func (r *reader) empty() *reader {
+	return &reader{}
+}
+
+func (r *reader) peekType() int {
+	if r.failed || r.pos >= len(r.data) {
+		return -1
+	}
+	return int(r.data[r.pos])
+}
+
+// consume consumes the given number of bytes and returns them.
+func (r *reader) consume(count int) []byte {
+	if r.failed || len(r.data)-r.pos < count {
+		r.fail()
+		return nil
+	}
+	data := r.data[r.pos : r.pos+count]
+	r.pos += count
+	return data
+}
+
+func (r *reader) readBigEndian(size int) uint64 {
+	value := uint64(0)
+	for _, b := range r.consume(size) {
+		value = value<<8 | uint64(b)
+	}
+	return value
+}
+
+func (r *reader) readStringLength() int {
+	kind := r.peekType()
+	switch {
+	case kind&0xe0 == 0xa0:
+		r.pos++
+		return kind & 0x1f
+	case kind >= 0xd9 && kind <= 0xdb:
+		r.pos++
+		return int(r.readBigEndian(1 << (kind - 0xd9)))
+	}
+	r.fail()
+	return 0
+}
+
+// readInteger reads an integer of any format. If it is negative, the result
+// should be interpreted as a signed integer.
+func (r *reader) readInteger() (uint64, bool) {
+	kind := r.peekType()
+	switch {
+	case kind < 0:
+	case kind <= 0x7f:
+		r.pos++
+		return uint64(kind), false
+	case kind >= 0xe0:
+		r.pos++
+		return uint64(int64(int8(kind))), true
+	case kind >= 0xcc && kind <= 0xcf:
+		r.pos++
+		return r.readBigEndian(1 << (kind - 0xcc)), false
+	case kind >= 0xd0 && kind <= 0xd3:
+		r.pos++
+		size := 1 << (kind - 0xd0)
+		bits := r.readBigEndian(size)
+		if size < 8 && bits>>(8*size-1)&1 != 0 {
+			bits |= math.MaxUint64 << (8 * size)
+		}
+		return bits, int64(bits) < 0
+	}
+	r.fail()
+	return 0, false
+}
+
+// fieldReader iterates over the fields of a struct. See reader.readStruct().
+type fieldReader struct {
+	// key is the name of the current field.
+	key string
+
+	r       *reader
+	names   []string
+	strict  bool
+	isArray bool
+	index   int
+	length  int
+}
+
+// next advances to the next field, returning whether there is one. The reader
+// is positioned at the value of the field.
+func (f *fieldReader) next() bool {
+	if f.index >= f.length || f.r.failed {
+		return false
+	}
+	if f.isArray {
+		f.key = ""
+		if f.index < len(f.names) {
+			f.key = f.names[f.index]
+		}
+	} else {
+		f.key = f.r.readString()
+	}
+	f.index++
+	return !f.r.failed
+}
+
+// skip skips the value of an unknown field, unless unknown fields are rejected.
+func (f *fieldReader) skip() {
+	if f.strict {
+		f.r.fail()
+	} else {
+		f.r.skip()
+	}
+}
+
+// Encoding and decoding of built-in types:
+
+func encode_bool(w *writer, value bool) {
+	w.writeBool(value)
+}
+
+func decode_bool(r *reader) bool {
+	return r.readBool()
+}
+
+func encode_i8(w *writer, value int8) {
+	w.writeInt(int64(value))
+}
+
+func decode_i8(r *reader) int8 {
+	return int8(r.readInt(math.MinInt8, math.MaxInt8))
+}
+
+func encode_i16(w *writer, value int16) {
+	w.writeInt(int64(value))
+}
+
+func decode_i16(r *reader) int16 {
+	return int16(r.readInt(math.MinInt16, math.MaxInt16))
+}
+
+func encode_i32(w *writer, value int32) {
+	w.writeInt(int64(value))
+}
+
+func decode_i32(r *reader) int32 {
+	return int32(r.readInt(math.MinInt32, math.MaxInt32))
+}
+
+func encode_i64(w *writer, value int64) {
+	w.writeInt(value)
+}
+
+func decode_i64(r *reader) int64 {
+	return r.readInt(math.MinInt64, math.MaxInt64)
+}
+
+func encode_u8(w *writer, value uint8) {
+	w.writeUint(uint64(value))
+}
+
+func decode_u8(r *reader) uint8 {
+	return uint8(r.readUint(math.MaxUint8))
+}
+
+func encode_u16(w *writer, value uint16) {
+	w.writeUint(uint64(value))
+}
+
+func decode_u16(r *reader) uint16 {
+	return uint16(r.readUint(math.MaxUint16))
+}
+
+func encode_u32(w *writer, value uint32) {
+	w.writeUint(uint64(value))
+}
+
+func decode_u32(r *reader) uint32 {
+	return uint32(r.readUint(math.MaxUint32))
+}
+
+func encode_u64(w *writer, value uint64) {
+	w.writeUint(value)
+}
+
+func decode_u64(r *reader) uint64 {
+	return r.readUint(math.MaxUint64)
+}
+
+func encode_f32(w *writer, value float32) {
+	w.writeFloat32(value)
+}
+
+func decode_f32(r *reader) float32 {
+	return float32(r.readFloat64())
+}
+
+func encode_f64(w *writer, value float64) {
+	w.writeFloat64(value)
+}
+
+func decode_f64(r *reader) float64 {
+	return r.readFloat64()
+}
+
+func encode_Unit(w *writer, value Unit) {
+	w.writeNil()
+}
+
+func decode_Unit(r *reader) Unit {
+	if !r.readNil() {
+		r.fail()
+	}
+	return Unit{}
+}
+
+func encode_String(w *writer, value string) {
+	w.writeString(value)
+}
+
+func decode_String(r *reader) string {
+	return r.readString()
+}
+
+func encode_Bytes(w *writer, value []byte) {
+	w.writeBytes(value)
+}
+
+func decode_Bytes(r *reader) []byte {
+	return r.readBytes()
+}
+
+func encode_RawValue(w *writer, value RawValue) {
+	if len(value) > 0 {
+		w.writeRaw(value)
+	} else {
+		w.writeNil()
+	}
+}
+
+func decode_RawValue(r *reader) RawValue {
+	return r.readRaw()
+}
+
+// Async support:
+
+// createAsyncValue creates the async value that is returned by an async export.
+func createAsyncValue() FatPtr {
+	return __fp_malloc(asyncValueSize)
+}
+
+// resolveAsyncValue passes the encoded result of an async export to the host.
+func resolveAsyncValue(asyncValuePtr, resultPtr FatPtr) {
+	__fp_host_resolve_async_value(asyncValuePtr, resultPtr)
+}
+
+// awaitAsyncValue invokes the handler once the given async value, as returned
+// by an async import, has been resolved by the host.
+func awaitAsyncValue(asyncValuePtr FatPtr, handler func(resultPtr FatPtr)) {
+	data := fatPtrData(asyncValuePtr)
+	if getUint32(data) == asyncStatusReady {
+		resultPtr := toFatPtr(getUint32(data[4:]), getUint32(data[8:]))
+		__fp_free(asyncValuePtr)
+		handler(resultPtr)
+		return
+	}
+
+	pendingValues[asyncValuePtr] = handler
+}
+
+//go:wasmexport __fp_guest_resolve_async_value
+func __fp_guest_resolve_async_value(asyncValuePtr, resultPtr FatPtr) {
+	data := fatPtrData(asyncValuePtr)
+	putUint32(data, asyncStatusReady)
+	putUint32(data[4:], uint32(resultPtr>>32))
+	putUint32(data[8:], uint32(resultPtr))
+
+	if handler, ok := pendingValues[asyncValuePtr]; ok {
+		delete(pendingValues, asyncValuePtr)
+		__fp_free(asyncValuePtr)
+		handler(resultPtr)
+	}
+}
@@ -1,21 +1,21 @@
 use crate::{
     casing::Casing,
     functions::{Function, FunctionList},
+    generators::protocol_json::protocol_fingerprint,
     prelude::Primitive,
     types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
     TsRuntimeConfig,
 };
 use inflector::Inflector;
-use std::fs;
+use std::{collections::BTreeMap, path::PathBuf};
 
 pub(crate) fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
     types: TypeMap,
     config: TsRuntimeConfig,
-    path: &str,
-) {
-    generate_type_bindings(&types, path);
+) -> BTreeMap<PathBuf, String> {
+    let protocol_version = protocol_fingerprint(&import_functions, &export_functions, &types);
 
     let import_decls =
         format_function_declarations(&import_functions, &types, FunctionType::Import);
@@ -34,6 +34,12 @@ pub(crate) fn generate_bindings(
     if has_async_export_functions {
         import_wrappers.push("__fp_host_resolve_async_value: resolvePromise,".to_owned());
     }
+    import_wrappers.extend([
+        "__fp_host_panic: (panicPtr: FatPtr) => {".to_owned(),
+        "    const { message, location } = parseObject<GuestPanic>(panicPtr);".to_owned(),
+        "    throw new FPGuestPanic(message, location);".to_owned(),
+        "},".to_owned(),
+    ]);
 
     let export_wrappers = format_export_wrappers(&export_functions, &types);
     let raw_export_wrappers = if config.generate_raw_export_wrappers {
@@ -81,6 +87,19 @@ import type * as types from \"./types{import_path_extension}\";
 
 type FatPtr = bigint;
 
+// Extension bit in the length of a fat pointer, which indicates the length is
+// stored in a 4-byte little-endian header, directly followed by the data.
+const FAT_PTR_LEN_HEADER = 0x0100_0000;
+const FAT_PTR_MAX_LEN = 0x00ff_ffff;
+
+/**
+ * Fingerprint of the protocol these bindings were generated from.
+ *
+ * Plugins built against a different protocol are rejected by `createRuntime()`,
+ * unless the `skipProtocolCheck` option is set.
+ */
+export const PROTOCOL_VERSION = {protocol_version:#018x}n;
+
 export type Imports = {{
 {import_lines}}};
 
@@ -98,16 +117,54 @@ export class FPRuntimeError extends Error {{
     }}
 }}
 
+/**
+ * Represents a panic that occurred inside the WASM plugin.
+ */
+export class FPGuestPanic extends FPRuntimeError {{
+    constructor(message: string, public location: string | null) {{
+        super(message);
+    }}
+}}
+
+/**
+ * Indicates the plugin was built against a different protocol than the runtime.
+ */
+export class FPProtocolMismatch extends FPRuntimeError {{
+    constructor(public expected: bigint, public found: bigint) {{
+        super(
+            `Plugin was built against a different protocol (expected version ${{formatProtocolVersion(expected)}}, found ${{formatProtocolVersion(found)}})`
+        );
+    }}
+}}
+
+/**
+ * Options for `createRuntime()`.
+ */
+export type RuntimeOptions = {{
+    /**
+     * Accept the plugin without verifying it was built against the same protocol
+     * as the runtime.
+     */
+    skipProtocolCheck?: boolean;
+}};
+
+type GuestPanic = {{
+    message: string;
+    location: string | null;
+}};
+
 /**
  * Creates a runtime for executing the given plugin.
  *
  * @param source {source_doc}.
  * @param importFunctions The host functions that may be imported by the plugin.
+ * @param options Options for loading the plugin.
  * @returns The functions that may be exported by the plugin.
  */
 export async function createRuntime(
     source: {source_type},
-    importFunctions: Imports
+    importFunctions: Imports,
+    options: RuntimeOptions = {{}}
 ): Promise<Exports> {{
     const promises = new Map<FatPtr, ((result: FatPtr) => void) | FatPtr>();
 
@@ -137,7 +194,7 @@ export async function createRuntime(
     }}
 
     function parseObject<T>(fatPtr: FatPtr): T {{
-        const [ptr, len] = fromFatPtr(fatPtr);
+        const [ptr, len] = resolveFatPtr(fatPtr);
         const buffer = new Uint8Array(memory.buffer, ptr, len);
         // Without creating a copy of the memory, we risk corruption of any
         // embedded `Uint8Array` objects returned from `decode()` after `free()`
@@ -149,6 +206,17 @@ export async function createRuntime(
         return object;
     }}
 
+    function resolveFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {{
+        const [ptr, len] = fromFatPtr(fatPtr);
+        if (len === FAT_PTR_LEN_HEADER) {{
+            const header = new DataView(memory.buffer, ptr, 4);
+            return [ptr + 4, header.getUint32(0, true)];
+        }} else if (len > FAT_PTR_MAX_LEN) {{
+            throw new FPRuntimeError(\"Unknown extension bits\");
+        }}
+        return [ptr, len];
+    }}
+
     function promiseFromPtr(ptr: FatPtr): Promise<FatPtr> {{
         const resultPtr = promises.get(ptr);
         if (resultPtr) {{
@@ -185,14 +253,14 @@ export async function createRuntime(
 
     function exportToMemory(serialized: Uint8Array): FatPtr {{
         const fatPtr = malloc(serialized.length);
-        const [ptr, len] = fromFatPtr(fatPtr);
+        const [ptr, len] = resolveFatPtr(fatPtr);
         const buffer = new Uint8Array(memory.buffer, ptr, len);
         buffer.set(serialized);
         return fatPtr;
     }}
 
     function importFromMemory(fatPtr: FatPtr): Uint8Array {{
-        const [ptr, len] = fromFatPtr(fatPtr);
+        const [ptr, len] = resolveFatPtr(fatPtr);
         const buffer = new Uint8Array(memory.buffer, ptr, len);
         const copy = new Uint8Array(len);
         copy.set(buffer);
@@ -216,6 +284,23 @@ export async function createRuntime(
     const memory = getExport<WebAssembly.Memory>(\"memory\");
     const malloc = getExport<(len: number) => FatPtr>(\"__fp_malloc\");
     const free = getExport<(ptr: FatPtr) => void>(\"__fp_free\");
+
+    // Reactor modules, such as plugins written in TinyGo, need to initialize
+    // themselves before any of their other exports may be called.
+    const initialize = instance.exports._initialize as (() => void) | undefined;
+    if (initialize) {{
+        initialize();
+    }}
+
+    // Plugins built with older bindings don't export their protocol version, so
+    // they cannot be verified.
+    const protocolVersion = instance.exports.__fp_protocol_version as (() => bigint) | undefined;
+    if (protocolVersion && !options.skipProtocolCheck) {{
+        const foundVersion = BigInt.asUintN(64, protocolVersion());
+        if (foundVersion !== PROTOCOL_VERSION) {{
+            throw new FPProtocolMismatch(PROTOCOL_VERSION, foundVersion);
+        }}
+    }}
 {}
     return {{
 {}{}    }};
@@ -228,6 +313,10 @@ function fromFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {{
 function toFatPtr(ptr: number, len: number): FatPtr {{
     return (BigInt(ptr) << 32n) | BigInt(len);
 }}
+
+function formatProtocolVersion(version: bigint): string {{
+    return `0x${{version.toString(16).padStart(16, \"0\")}}`;
+}}
 ",
         join_lines(&import_wrappers, |line| format!("            {line}")),
         if has_async_import_functions {
@@ -238,7 +327,10 @@ function toFatPtr(ptr: number, len: number): FatPtr {{
         join_lines(&export_wrappers, |line| format!("        {line}")),
         join_lines(&raw_export_wrappers, |line| format!("        {line}")),
     );
-    write_bindings_file(format!("{path}/index.ts"), contents);
+    BTreeMap::from([
+        (PathBuf::from("index.ts"), contents),
+        (PathBuf::from("types.ts"), generate_type_bindings(&types)),
+    ])
 }
 
 enum FunctionType {
@@ -663,7 +755,7 @@ fn format_raw_export_wrappers(export_functions: &FunctionList) -> Vec<String> {
         .collect()
 }
 
-fn generate_type_bindings(types: &TypeMap, path: &str) {
+fn generate_type_bindings(types: &TypeMap) -> String {
     let type_defs = types
         .values()
         .filter_map(|ty| match ty {
@@ -690,18 +782,15 @@ fn generate_type_bindings(types: &TypeMap, path: &str) {
         })
         .collect::<Vec<_>>();
 
-    write_bindings_file(
-        format!("{path}/types.ts"),
-        format!(
-            "// ============================================= //
+    format!(
+        "// ============================================= //
 // Types for WebAssembly runtime                 //
 //                                               //
 // This file is generated. PLEASE DO NOT MODIFY. //
 // ============================================= //
 
 {}\n",
-            type_defs.join("\n\n")
-        ),
+        type_defs.join("\n\n")
     )
 }
 
@@ -1139,10 +1228,3 @@ where
         format!("{lines}\n")
     }
 }
-
-fn write_bindings_file<C>(file_path: String, contents: C)
-where
-    C: AsRef<[u8]>,
-{
-    fs::write(file_path, &contents).expect("Could not write bindings file");
-}
@@ -0,0 +1,148 @@
+use super::BindingsType;
+use crate::{
+    errors::BindgenError,
+    functions::FunctionList,
+    types::{Type, TypeIdent, TypeMap},
+};
+
+/// Verifies that the protocol only uses constructs the generator for the
+/// given bindings type can handle, so that generators themselves don't need
+/// to report errors for them.
+pub(crate) fn validate_protocol(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    bindings_type: &BindingsType,
+) -> Result<(), BindgenError> {
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        let idents = function
+            .args
+            .iter()
+            .map(|arg| &arg.ty)
+            .chain(function.return_type.iter());
+        for ident in idents {
+            validate_ident(ident, types).map_err(|message| BindgenError::InvalidFunction {
+                function: function.name.clone(),
+                message,
+            })?;
+        }
+    }
+
+    for ty in types.values() {
+        validate_type(ty, types, bindings_type).map_err(|message| BindgenError::InvalidType {
+            ty: ty.name(),
+            message,
+        })?;
+    }
+
+    Ok(())
+}
+
+fn validate_type(ty: &Type, types: &TypeMap, bindings_type: &BindingsType) -> Result<(), String> {
+    match ty {
+        Type::Alias(_, ident) => validate_ident(ident, types),
+        Type::Enum(ty) => {
+            for variant in &ty.variants {
+                match &variant.ty {
+                    Type::Unit => {}
+                    Type::Struct(variant_ty) => {
+                        for field in &variant_ty.fields {
+                            validate_ident(&field.ty, types)?;
+                        }
+                    }
+                    Type::Tuple(items) => {
+                        if items.len() > 1 && matches!(bindings_type, BindingsType::TsRuntime(_)) {
+                            return Err(format!(
+                                "The variant `{}` has multiple unnamed fields, which is not \
+                                supported by the TypeScript generator",
+                                variant.name
+                            ));
+                        }
+                        for item in items {
+                            validate_ident(item, types)?;
+                        }
+                    }
+                    other => {
+                        return Err(format!(
+                            "Unsupported type for variant `{}`: {}",
+                            variant.name,
+                            other.name()
+                        ))
+                    }
+                }
+            }
+            Ok(())
+        }
+        Type::Struct(ty) => ty
+            .fields
+            .iter()
+            .try_for_each(|field| validate_ident(&field.ty, types)),
+        Type::Tuple(items) => items
+            .iter()
+            .try_for_each(|item| validate_ident(item, types)),
+        _ => Ok(()),
+    }
+}
+
+/// Verifies that every container, list and map type referenced by the given
+/// identifier receives the number of generic arguments the generators expect.
+fn validate_ident(ident: &TypeIdent, types: &TypeMap) -> Result<(), String> {
+    let num_expected_args = match types.get(ident) {
+        Some(Type::Container(_, _) | Type::List(_, _)) => Some(1),
+        Some(Type::Map(_, _, _)) => Some(2),
+        _ => None,
+    };
+    if let Some(num_expected_args) = num_expected_args {
+        if ident.generic_args.len() != num_expected_args {
+            return Err(format!(
+                "Expected {} generic argument(s) for `{}`, but found {}",
+                num_expected_args,
+                ident.name,
+                ident.generic_args.len()
+            ));
+        }
+    }
+
+    ident
+        .generic_args
+        .iter()
+        .try_for_each(|(arg, _)| validate_ident(arg, types))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{functions::Function, generators::TsRuntimeConfig};
+    use std::iter::FromIterator;
+
+    #[test]
+    fn test_missing_generic_argument() {
+        let types = TypeMap::from([(
+            TypeIdent::from("Vec"),
+            Type::List("Vec".to_owned(), TypeIdent::from("T")),
+        )]);
+        let functions = FunctionList::from_iter([Function {
+            name: "my_function".to_owned(),
+            doc_lines: Vec::new(),
+            args: Vec::new(),
+            return_type: Some(TypeIdent::from("Vec")),
+            is_async: false,
+        }]);
+
+        match validate_protocol(
+            &functions,
+            &FunctionList::new(),
+            &types,
+            &BindingsType::TsRuntime(TsRuntimeConfig::new()),
+        ) {
+            Err(BindgenError::InvalidFunction { function, message }) => {
+                assert_eq!(function, "my_function");
+                assert_eq!(
+                    message,
+                    "Expected 1 generic argument(s) for `Vec`, but found 0"
+                );
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,686 @@
+use crate::{
+    functions::{Function, FunctionList},
+    prelude::Primitive,
+    types::{CustomType, Enum, Field, Struct, Type, TypeIdent, TypeMap},
+    WitWorldConfig,
+};
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Words that need to be escaped with a `%` prefix when they are used as
+/// identifiers in WIT.
+const WIT_KEYWORDS: &[&str] = &[
+    "as",
+    "async",
+    "bool",
+    "borrow",
+    "char",
+    "constructor",
+    "enum",
+    "export",
+    "f32",
+    "f64",
+    "flags",
+    "from",
+    "func",
+    "future",
+    "import",
+    "include",
+    "interface",
+    "list",
+    "option",
+    "own",
+    "package",
+    "record",
+    "resource",
+    "result",
+    "s16",
+    "s32",
+    "s64",
+    "s8",
+    "static",
+    "stream",
+    "string",
+    "tuple",
+    "type",
+    "u16",
+    "u32",
+    "u64",
+    "u8",
+    "use",
+    "variant",
+    "with",
+    "world",
+];
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: WitWorldConfig,
+) -> BTreeMap<PathBuf, String> {
+    let mut generator = TypeGenerator::new(&types);
+    for (ident, ty) in &types {
+        let is_protocol_type = match ty {
+            Type::Alias(_, _) => true,
+            Type::Enum(ty) => ty.ident.generic_args.is_empty(),
+            Type::Struct(ty) => ty.ident.generic_args.is_empty(),
+            _ => false,
+        };
+        if is_protocol_type {
+            generator.format_type(ident, &format!("type `{ident}`"));
+        }
+    }
+
+    let imports = import_functions
+        .iter()
+        .map(|function| generator.format_function(function, "import"))
+        .collect::<String>();
+    let exports = export_functions
+        .iter()
+        .map(|function| generator.format_function(function, "export"))
+        .collect::<String>();
+
+    BTreeMap::from([
+        (
+            PathBuf::from("world.wit"),
+            generator.format_world(&config, &imports, &exports),
+        ),
+        (PathBuf::from("report.md"), generator.format_report()),
+    ])
+}
+
+fn format_header() -> String {
+    "// ============================================= //
+// WIT world for the component model             //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+"
+    .to_owned()
+}
+
+enum WitType<'a> {
+    Primitive(Primitive),
+    String,
+    Unit,
+    /// A type parameter, which only occurs in the definitions of generic types.
+    Param(String),
+    Alias(String, TypeIdent),
+    /// A type that is serialized as the type it wraps, such as `Box<T>`.
+    Pointer(TypeIdent),
+    Array(Primitive),
+    Option(TypeIdent),
+    Result(TypeIdent, TypeIdent),
+    List(TypeIdent),
+    Map(TypeIdent, TypeIdent),
+    Tuple(Vec<TypeIdent>),
+    Custom(&'a CustomType),
+    Struct(&'a Struct, TypeParams),
+    Enum(&'a Enum, TypeParams),
+}
+
+/// Maps the names of generic parameters to the arguments they are
+/// instantiated with.
+type TypeParams = Vec<(String, TypeIdent)>;
+
+/// Generates the WIT definitions for all the types that are used by the
+/// protocol. WIT has no generics, so every instantiation of a generic type is
+/// defined separately, with the type arguments appended to its name.
+///
+/// Constructs that WIT cannot express are approximated, and an issue is
+/// recorded for each of them, so that they can be listed in the report.
+struct TypeGenerator<'a> {
+    types: &'a TypeMap,
+    visited: BTreeSet<String>,
+
+    /// Names of the types that are currently being defined, which is used to
+    /// detect recursive types.
+    stack: Vec<String>,
+
+    definitions: Vec<String>,
+    issues: Vec<String>,
+}
+
+impl<'a> TypeGenerator<'a> {
+    fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            visited: BTreeSet::new(),
+            stack: Vec::new(),
+            definitions: Vec::new(),
+            issues: Vec::new(),
+        }
+    }
+
+    fn format_world(&self, config: &WitWorldConfig, imports: &str, exports: &str) -> String {
+        let mut sections = Vec::new();
+        if !self.visited.is_empty() {
+            sections.push(format!(
+                "    use types.{{\n{}    }};\n",
+                self.visited
+                    .iter()
+                    .map(|name| format!("        {},\n", escape(name)))
+                    .collect::<String>()
+            ));
+        }
+        sections.extend(
+            [imports, exports]
+                .iter()
+                .filter(|functions| !functions.is_empty())
+                .map(|functions| functions.to_string()),
+        );
+
+        let interface = if self.definitions.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "interface types {{\n{}}}\n\n",
+                self.definitions
+                    .iter()
+                    .map(|definition| indent_lines(definition, "    "))
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+                    + "\n"
+            )
+        };
+
+        format!(
+            "{}\npackage {};\n\n{interface}world {} {{\n{}}}\n",
+            format_header(),
+            config.package_name,
+            escape(&config.world_name),
+            sections.join("\n")
+        )
+    }
+
+    fn format_report(&self) -> String {
+        let body = if self.issues.is_empty() {
+            "All constructs used by the protocol can be represented in WIT.\n".to_owned()
+        } else {
+            format!(
+                "The following constructs used by the protocol cannot be represented in WIT. The\n\
+                generated world approximates them as described, so code that relies on them\n\
+                needs to be reviewed when migrating to the component model.\n\n{}",
+                self.issues
+                    .iter()
+                    .map(|issue| format!("- {issue}\n"))
+                    .collect::<String>()
+            )
+        };
+
+        format!("# WIT export report\n\n{body}")
+    }
+
+    fn format_function(&mut self, function: &Function, direction: &str) -> String {
+        let args = function
+            .args
+            .iter()
+            .map(|arg| {
+                let context = format!("argument `{}` of `{}`", arg.name, function.name);
+                format!(
+                    "{}: {}",
+                    escape(&to_kebab_case(&arg.name)),
+                    self.format_type(&arg.ty, &context)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let return_type = match &function.return_type {
+            Some(ty) if !matches!(self.resolve_alias(ty), WitType::Unit) => {
+                let context = format!("return type of `{}`", function.name);
+                format!(" -> {}", self.format_type(ty, &context))
+            }
+            _ => String::new(),
+        };
+        let modifier = if function.is_async { "async " } else { "" };
+
+        format!(
+            "{}    {direction} {}: {modifier}func({args}){return_type};\n",
+            format_docs(&function.doc_lines, "    "),
+            escape(&to_kebab_case(&function.name))
+        )
+    }
+
+    fn resolve(&self, ident: &TypeIdent) -> WitType<'a> {
+        if ident.array.is_some() {
+            return match Primitive::from_str(&ident.name) {
+                Ok(primitive) => WitType::Array(primitive),
+                Err(_) => WitType::List(TypeIdent::from(ident.name.clone())),
+            };
+        }
+        if let Some(primitive) = ident.as_primitive() {
+            return WitType::Primitive(primitive);
+        }
+
+        let arg = |index: usize| ident.generic_args.get(index).map(|(arg, _)| arg.clone());
+        match self.types.get(ident) {
+            Some(Type::Alias(name, target)) => WitType::Alias(name.clone(), target.clone()),
+            Some(Type::Array(primitive, _)) => WitType::Array(*primitive),
+            Some(Type::Container(name, _)) => match arg(0) {
+                Some(arg) if name == "Option" => WitType::Option(arg),
+                Some(arg) => WitType::Pointer(arg),
+                None => WitType::Unit,
+            },
+            Some(Type::Custom(custom)) => WitType::Custom(custom),
+            Some(Type::Enum(ty)) if is_result(ty) => match (arg(0), arg(1)) {
+                (Some(ok), Some(err)) => WitType::Result(ok, err),
+                _ => WitType::Enum(ty, get_type_params(&ty.ident, ident)),
+            },
+            Some(Type::Enum(ty)) => WitType::Enum(ty, get_type_params(&ty.ident, ident)),
+            Some(Type::List(_, _)) => arg(0).map(WitType::List).unwrap_or(WitType::Unit),
+            Some(Type::Map(_, _, _)) => match (arg(0), arg(1)) {
+                (Some(key), Some(value)) => WitType::Map(key, value),
+                _ => WitType::Unit,
+            },
+            Some(Type::Primitive(primitive)) => WitType::Primitive(*primitive),
+            Some(Type::String) => WitType::String,
+            Some(Type::Struct(ty)) => WitType::Struct(ty, get_type_params(&ty.ident, ident)),
+            Some(Type::Tuple(items)) if items.is_empty() => WitType::Unit,
+            Some(Type::Tuple(items)) => WitType::Tuple(items.clone()),
+            Some(Type::Unit) => WitType::Unit,
+            None if ident.name == "String" => WitType::String,
+            None => WitType::Param(ident.name.clone()),
+        }
+    }
+
+    /// Resolves the given type, following any aliases and pointers.
+    fn resolve_alias(&self, ident: &TypeIdent) -> WitType<'a> {
+        match self.resolve(ident) {
+            WitType::Alias(_, target) | WitType::Pointer(target) => self.resolve_alias(&target),
+            resolved => resolved,
+        }
+    }
+
+    /// Returns the name under which the given type is defined, which is unique
+    /// for every instantiation of a generic type.
+    fn mangle(&self, ident: &TypeIdent) -> String {
+        match self.resolve(ident) {
+            WitType::Primitive(primitive) => format_primitive(primitive).to_owned(),
+            WitType::String => "string".to_owned(),
+            WitType::Unit => "unit".to_owned(),
+            WitType::Param(name) | WitType::Alias(name, _) => to_kebab_case(&name),
+            WitType::Pointer(inner) => self.mangle(&inner),
+            WitType::Array(primitive) => format!("list-{}", format_primitive(primitive)),
+            WitType::Option(inner) => format!("option-{}", self.mangle(&inner)),
+            WitType::Result(ok, err) => {
+                format!("result-{}-{}", self.mangle(&ok), self.mangle(&err))
+            }
+            WitType::List(inner) => format!("list-{}", self.mangle(&inner)),
+            WitType::Map(key, value) => {
+                format!("map-{}-{}", self.mangle(&key), self.mangle(&value))
+            }
+            WitType::Tuple(items) => std::iter::once("tuple".to_owned())
+                .chain(items.iter().map(|item| self.mangle(item)))
+                .collect::<Vec<_>>()
+                .join("-"),
+            WitType::Custom(custom) => {
+                to_kebab_case(custom.ident.name.rsplit("::").next().unwrap_or_default())
+            }
+            WitType::Struct(_, _) | WitType::Enum(_, _) => {
+                std::iter::once(to_kebab_case(&ident.name))
+                    .chain(ident.generic_args.iter().map(|(arg, _)| self.mangle(arg)))
+                    .collect::<Vec<_>>()
+                    .join("-")
+            }
+        }
+    }
+
+    /// Returns the WIT type for the given type, defining any named types it
+    /// refers to. `context` describes where the type is used, for reporting
+    /// issues.
+    fn format_type(&mut self, ident: &TypeIdent, context: &str) -> String {
+        match self.resolve(ident) {
+            WitType::Primitive(primitive) => format_primitive(primitive).to_owned(),
+            WitType::String => "string".to_owned(),
+            WitType::Unit => {
+                self.report(format!(
+                    "The unit type is used in {context}. WIT has no unit type outside of \
+                    function results, so it is represented as an empty tuple, which WIT \
+                    rejects."
+                ));
+                "tuple<>".to_owned()
+            }
+            WitType::Param(name) => escape(&to_kebab_case(&name)),
+            WitType::Pointer(inner) => self.format_type(&inner, context),
+            WitType::Array(primitive) => format!("list<{}>", format_primitive(primitive)),
+            WitType::Option(inner) => format!("option<{}>", self.format_type(&inner, context)),
+            WitType::Result(ok, err) => {
+                let ok = match self.resolve_alias(&ok) {
+                    WitType::Unit => "_".to_owned(),
+                    _ => self.format_type(&ok, context),
+                };
+                match self.resolve_alias(&err) {
+                    WitType::Unit if ok == "_" => "result".to_owned(),
+                    WitType::Unit => format!("result<{ok}>"),
+                    _ => format!("result<{ok}, {}>", self.format_type(&err, context)),
+                }
+            }
+            WitType::List(inner) => format!("list<{}>", self.format_type(&inner, context)),
+            WitType::Map(key, value) => format!(
+                "list<tuple<{}, {}>>",
+                self.format_type(&key, context),
+                self.format_type(&value, context)
+            ),
+            WitType::Tuple(items) => format!(
+                "tuple<{}>",
+                items
+                    .iter()
+                    .map(|item| self.format_type(item, context))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            resolved => {
+                let name = self.mangle(ident);
+                if self.stack.contains(&name) {
+                    self.report(format!(
+                        "`{ident}` is a recursive type, which WIT does not support."
+                    ));
+                } else if self.visited.insert(name.clone()) {
+                    self.stack.push(name.clone());
+                    let definition = self.create_definition(&name, resolved);
+                    self.definitions.push(definition);
+                    self.stack.pop();
+                }
+                escape(&name)
+            }
+        }
+    }
+
+    fn create_definition(&mut self, name: &str, resolved: WitType<'a>) -> String {
+        match resolved {
+            WitType::Alias(alias, target) => {
+                let context = format!("type `{alias}`");
+                format!(
+                    "type {} = {};",
+                    escape(name),
+                    self.format_type(&target, &context)
+                )
+            }
+            WitType::Custom(custom) => self.create_custom_definition(name, custom),
+            WitType::Struct(ty, params) => self.create_struct_definition(name, ty, &params),
+            WitType::Enum(ty, params) => self.create_enum_definition(name, ty, &params),
+            _ => unreachable!("Only named types have definitions"),
+        }
+    }
+
+    fn create_custom_definition(&mut self, name: &str, custom: &CustomType) -> String {
+        let (wit_ty, description) = match custom.ts_ty.as_str() {
+            "ArrayBuffer" | "Uint8Array" => ("list<u8>", "a list of bytes"),
+            "boolean" => ("bool", "a boolean"),
+            "number" => ("f64", "a 64-bit float"),
+            "string" => ("string", "a string"),
+            _ => (
+                "list<u8>",
+                "a list of bytes containing its MessagePack encoding",
+            ),
+        };
+        self.report(format!(
+            "`{}` is a custom type (`{}`), which WIT cannot describe. It is represented \
+            as {description}.",
+            custom.ident, custom.rs_ty
+        ));
+
+        format!("type {} = {wit_ty};", escape(name))
+    }
+
+    fn create_struct_definition(&mut self, name: &str, ty: &Struct, params: &TypeParams) -> String {
+        let context = format!("struct `{}`", ty.ident);
+        let fields = ty
+            .fields
+            .iter()
+            .map(|field| Field {
+                ty: substitute(&field.ty, params),
+                ..field.clone()
+            })
+            .collect::<Vec<_>>();
+
+        let docs = format_docs(&ty.doc_lines, "");
+        match fields.as_slice() {
+            [field] if field.name.is_none() => {
+                let inner = self.format_type(&field.ty, &context);
+                format!("{docs}type {} = {inner};", escape(name))
+            }
+            _ if !fields.is_empty() && fields.iter().all(|field| field.name.is_none()) => {
+                let items = fields
+                    .iter()
+                    .map(|field| self.format_type(&field.ty, &context))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{docs}type {} = tuple<{items}>;", escape(name))
+            }
+            _ => format!(
+                "{docs}{}",
+                self.format_record(name, &ty.ident.to_string(), &fields)
+            ),
+        }
+    }
+
+    /// Formats a record with the given fields. `display_name` is the name of
+    /// the type in the protocol, which is used for reporting issues.
+    fn format_record(&mut self, name: &str, display_name: &str, fields: &[Field]) -> String {
+        if fields.is_empty() {
+            self.report(format!(
+                "`{display_name}` has no fields, but WIT records need at least one."
+            ));
+        }
+
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let field_name = field.name.as_deref().unwrap_or_default();
+                if field.attrs.flatten {
+                    self.report(format!(
+                        "Field `{field_name}` of `{display_name}` is flattened, which WIT \
+                        records cannot express. It is represented as a nested record instead."
+                    ));
+                }
+
+                let context = format!("field `{field_name}` of `{display_name}`");
+                format!(
+                    "{}    {}: {},\n",
+                    format_docs(&field.doc_lines, "    "),
+                    escape(&to_kebab_case(field_name)),
+                    self.format_type(&field.ty, &context)
+                )
+            })
+            .collect::<String>();
+
+        format!("record {} {{\n{fields}}}", escape(name))
+    }
+
+    fn create_enum_definition(&mut self, name: &str, ty: &Enum, params: &TypeParams) -> String {
+        let docs = format_docs(&ty.doc_lines, "");
+        if ty.options.untagged {
+            self.report(format!(
+                "`{}` is an untagged enum, but WIT variants are always tagged. It is \
+                represented as a regular variant, so values need to carry the case they \
+                belong to.",
+                ty.ident
+            ));
+        }
+
+        if ty
+            .variants
+            .iter()
+            .all(|variant| is_unit_variant(&variant.ty))
+        {
+            let cases = ty
+                .variants
+                .iter()
+                .map(|variant| {
+                    format!(
+                        "{}    {},\n",
+                        format_docs(&variant.doc_lines, "    "),
+                        escape(&to_kebab_case(&variant.name))
+                    )
+                })
+                .collect::<String>();
+            return format!("{docs}enum {} {{\n{cases}}}", escape(name));
+        }
+
+        let cases = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let case_name = to_kebab_case(&variant.name);
+                let context = format!("variant `{}` of `{}`", variant.name, ty.ident);
+                let payload = match &variant.ty {
+                    Type::Struct(variant_ty) if !variant_ty.fields.is_empty() => {
+                        let record_name = format!("{name}-{case_name}");
+                        let fields = variant_ty
+                            .fields
+                            .iter()
+                            .map(|field| Field {
+                                ty: substitute(&field.ty, params),
+                                ..field.clone()
+                            })
+                            .collect::<Vec<_>>();
+                        let display_name = format!("{}::{}", ty.ident.name, variant.name);
+                        let record = self.format_record(&record_name, &display_name, &fields);
+                        self.definitions.push(record);
+                        format!("({})", escape(&record_name))
+                    }
+                    Type::Tuple(items) if items.len() == 1 => format!(
+                        "({})",
+                        self.format_type(&substitute(&items[0], params), &context)
+                    ),
+                    Type::Tuple(items) if !items.is_empty() => format!(
+                        "(tuple<{}>)",
+                        items
+                            .iter()
+                            .map(|item| self.format_type(&substitute(item, params), &context))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    _ => String::new(),
+                };
+
+                format!(
+                    "{}    {}{payload},\n",
+                    format_docs(&variant.doc_lines, "    "),
+                    escape(&case_name)
+                )
+            })
+            .collect::<String>();
+
+        format!("{docs}variant {} {{\n{cases}}}", escape(name))
+    }
+
+    fn report(&mut self, issue: String) {
+        if !self.issues.contains(&issue) {
+            self.issues.push(issue);
+        }
+    }
+}
+
+/// Returns whether the enum is the `Result` type from the standard library,
+/// which maps to WIT's built-in `result` type.
+fn is_result(ty: &Enum) -> bool {
+    ty.ident.name == "Result"
+        && ty.ident.generic_args.len() == 2
+        && ty.variants.len() == 2
+        && ty.variants[0].name == "Ok"
+        && ty.variants[1].name == "Err"
+}
+
+fn is_unit_variant(ty: &Type) -> bool {
+    match ty {
+        Type::Unit => true,
+        Type::Struct(ty) => ty.fields.is_empty(),
+        Type::Tuple(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+fn format_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "bool",
+        Primitive::F32 => "f32",
+        Primitive::F64 => "f64",
+        Primitive::I8 => "s8",
+        Primitive::I16 => "s16",
+        Primitive::I32 => "s32",
+        Primitive::I64 => "s64",
+        Primitive::U8 => "u8",
+        Primitive::U16 => "u16",
+        Primitive::U32 => "u32",
+        Primitive::U64 => "u64",
+    }
+}
+
+fn get_type_params(definition: &TypeIdent, ident: &TypeIdent) -> TypeParams {
+    definition
+        .generic_args
+        .iter()
+        .zip(ident.generic_args.iter())
+        .map(|((param, _), (arg, _))| (param.name.clone(), arg.clone()))
+        .collect()
+}
+
+/// Replaces generic parameters in the given type with their arguments.
+fn substitute(ident: &TypeIdent, params: &[(String, TypeIdent)]) -> TypeIdent {
+    if ident.generic_args.is_empty() && ident.array.is_none() {
+        if let Some((_, arg)) = params.iter().find(|(name, _)| name == &ident.name) {
+            return arg.clone();
+        }
+    }
+
+    let mut ident = ident.clone();
+    ident.generic_args = ident
+        .generic_args
+        .iter()
+        .map(|(arg, bounds)| (substitute(arg, params), bounds.clone()))
+        .collect();
+    ident
+}
+
+fn format_docs(doc_lines: &[String], indent: &str) -> String {
+    doc_lines
+        .iter()
+        .map(|line| format!("{}///{}\n", indent, line.trim_end()))
+        .collect()
+}
+
+/// Converts a Rust identifier to kebab-case. Every word in a WIT identifier
+/// needs to start with a letter, so words that start with a digit are joined
+/// with the word before them.
+fn to_kebab_case(name: &str) -> String {
+    let name = name.strip_prefix("r#").unwrap_or(name).to_kebab_case();
+    let mut result = String::new();
+    for word in name.split('-').filter(|word| !word.is_empty()) {
+        let starts_with_digit = word.starts_with(|c: char| c.is_ascii_digit());
+        if !result.is_empty() && !starts_with_digit {
+            result.push('-');
+        }
+        result.push_str(word);
+    }
+    result
+}
+
+/// Escapes identifiers that collide with WIT keywords.
+fn escape(name: &str) -> String {
+    if WIT_KEYWORDS.contains(&name) {
+        format!("%{name}")
+    } else {
+        name.to_owned()
+    }
+}
+
+fn indent_lines(lines: &str, indent: &str) -> String {
+    lines
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -25,8 +25,8 @@ The following table is intended to highlight the major differences between the d
 
 | Feature                                                   |         `fp-bindgen`        | `wasm-bindgen` |         `wit-bindgen`           |
 | --------------------------------------------------------- | :-------------------------: | :------------: | :-----------------------------: |
-| Host environments                                         | Rust (Wasmer), TypeScript\* |     JS/TS      | Rust/Python (Wasmtime), JS/TS\* |
-| Guest languages                                           |            Rust\*           |      Rust      |           Rust, C\*             |
+| Host environments                                         | Rust (Wasmer, Wasmtime), Go (wazero), Python (Wasmtime), TypeScript\* |     JS/TS      | Rust/Python (Wasmtime), JS/TS\* |
+| Guest languages                                           | Rust, C, AssemblyScript, Go (TinyGo)\* |      Rust      |           Rust, C\*             |
 | Protocol format                                           |     Rust (using macros)     |      N/A       |              .wit               |
 | Serialization format                                      |         MessagePack         |      JSON      |             Custom              |
 | [Can use existing Rust types](#using-existing-rust-types) |           &#9989;           |    &#10060;    |            &#10060;             |
@@ -179,10 +179,165 @@ Currently, we support the following binding types:
 
 - `BindingsType::RustPlugin`: Generates bindings for a Rust plugin.
 - `BindingsType::RustWasmerRuntime`: Generates runtime bindings for use with Wasmer.
+- `BindingsType::RustWasmer3Runtime`: Generates runtime bindings for use with Wasmer 3.
+- `BindingsType::RustWasmtimeRuntime`: Generates runtime bindings for use with Wasmtime.
 - `BindingsType::TsRuntimeWithExtendedConfig`: Generates bindings for a TypeScript runtime.
+- `BindingsType::ProtocolJson`: Generates a `protocol.json` file describing the protocol.
+- `BindingsType::PythonWasmtimeRuntime`: Generates bindings for a Python runtime using `wasmtime-py`.
+- `BindingsType::GoWazeroRuntime`: Generates bindings for a Go runtime using `wazero`.
+- `BindingsType::CPlugin`: Generates bindings for a C plugin, including a small support library.
+- `BindingsType::AssemblyScriptPlugin`: Generates bindings for an AssemblyScript plugin.
+- `BindingsType::TinyGoPlugin`: Generates bindings for a Go plugin compiled using TinyGo.
+- `BindingsType::WitWorld`: Generates a WIT world describing the protocol for the component model.
+- `BindingsType::JsonSchema`: Generates a JSON Schema for every struct and enum in the protocol.
+- `BindingsType::ApiReference`: Generates a Markdown or HTML API reference for the protocol.
 
 Note that some binding types take an additional config argument.
 
+The `protocol.json` file contains all the functions and types of the protocol as a versioned JSON
+document. It can be used by tools written in other languages to generate their own bindings, or
+committed to your repository so that changes to the protocol show up in code review. The same
+document can be produced and parsed programmatically using `fp_bindgen::protocol_to_json()` and
+`fp_bindgen::protocol_from_json()`.
+
+The JSON Schema generator writes a `<Type>.schema.json` file (draft 2020-12) for every struct and
+enum that is not generic, which can be used to validate payloads in services that are not written
+in Rust. The schemas describe the JSON representation Serde uses for the types, so they honor
+attributes such as `rename`, `rename_all`, `flatten`, `default` and `skip_serializing_if`, as well
+as internally, adjacently and untagged enum representations. Fields with a default value, a
+`skip_serializing_if` attribute or an `Option` type are not required. Every schema is
+self-contained: the structs and enums it refers to are included in its `$defs`.
+
+The API reference generator renders the doc comments of your protocol into human-readable
+documentation, so plugin authors don't need to read the protocol crate itself. It writes an
+`index.md` page listing all imported and exported functions with their signatures, as well as a
+page for every type under `types/`, which describes its fields or variants and the shape of its
+JSON wire format. Use `ApiReferenceConfig::with_format()` to generate HTML pages instead, and
+`ApiReferenceConfig::with_title()` to change the title of the index page.
+
+If you ship plugins that were compiled against older revisions of your protocol, you can use
+`fp_bindgen::check_compatibility()` to compare a `protocol.json` from an older revision against
+the current one. It reports changes that would break those plugins, such as removed imports,
+changed function signatures, fields that were added without a default value, removed enum variants
+and changed Serde attributes:
+
+```ignore
+let old = fp_bindgen::protocol_from_json(&old_json)?;
+let new = fp_bindgen::protocol_from_json(&new_json)?;
+let report = fp_bindgen::check_compatibility(&old, &new);
+if !report.is_compatible() {
+    eprintln!("{report}");
+}
+```
+
+If you commit the generated bindings to your repository, you can use `fp_bindgen::check_bindings()`
+to verify they are up-to-date, for instance in a test that runs in CI. It takes the same arguments
+as `generate_bindings()`, but instead of writing any files, it compares the files that would be
+generated with those on disk and returns a `BindgenError::OutdatedBindings` error with a diff for
+every file that is missing or differs:
+
+```ignore
+let protocol = fp_bindgen::prelude::fp_protocol!();
+if let Err(outdated) = fp_bindgen::check_bindings(
+    protocol.import_functions,
+    protocol.export_functions,
+    protocol.types,
+    fp_bindgen::BindingConfig {
+        bindings_type: fp_bindgen::BindingsType::RustWasmtimeRuntime,
+        path: "bindings/rust-wasmtime-runtime",
+    },
+) {
+    panic!("{outdated}");
+}
+```
+
+`generate_bindings()` panics if the protocol uses constructs that are not supported by the selected
+bindings type, or if the bindings cannot be written. If you generate bindings from a build script
+or another tool, you may want to use `fp_bindgen::try_generate_bindings()` instead, which takes the
+same arguments but returns a `BindgenError`. The error includes the name of the offending function
+or type, so it can be reported without a backtrace:
+
+```ignore
+if let Err(error) = fp_bindgen::try_generate_bindings(
+    protocol.import_functions,
+    protocol.export_functions,
+    protocol.types,
+    fp_bindgen::BindingConfig {
+        bindings_type: fp_bindgen::BindingsType::RustWasmtimeRuntime,
+        path: "bindings/rust-wasmtime-runtime",
+    },
+) {
+    eprintln!("Could not generate bindings: {error}");
+    std::process::exit(1);
+}
+```
+
+Likewise, `Function::try_new()` and `Type::try_from_item()` are fallible alternatives to the
+constructors used by the `fp_import!`, `fp_export!` and `Serializable` macros, for tools that build
+a protocol programmatically.
+
+If you want to write the bindings somewhere other than `config.path`, you can use
+`fp_bindgen::render_bindings()`. Instead of writing any files, it returns their contents as a
+`BTreeMap<PathBuf, String>`, keyed by their path relative to the output directory. This allows you
+to generate bindings into `OUT_DIR` from a build script, to post-process the files, or to serve them
+from a development server:
+
+```ignore
+let protocol = my_protocol::protocol();
+let files = fp_bindgen::render_bindings(
+    protocol.import_functions,
+    protocol.export_functions,
+    protocol.types,
+    fp_bindgen::BindingsType::RustWasmtimeRuntime,
+)?;
+
+let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+for (path, contents) in files {
+    std::fs::write(out_dir.join(path), contents)?;
+}
+```
+
+### Using the CLI
+
+Instead of writing your own `main()` function, you can also generate bindings using the `fp-bindgen`
+command-line tool from the `fp-bindgen-cli` crate. It generates any number of bindings in one
+invocation, as configured in an `fp-bindgen.toml` file:
+
+```toml
+[protocol]
+json = "protocol.json"
+
+[[bindings]]
+type = "rust-plugin"
+path = "bindings/rust-plugin"
+name = "my-plugin-bindings"
+authors = ["Me <me@example.com>"]
+version = "1.0.0"
+
+[bindings.dependencies]
+time = { version = "0.3", features = ["macros"] }
+
+[[bindings]]
+type = "ts-runtime"
+path = "bindings/ts-runtime"
+generate-raw-export-wrappers = true
+```
+
+The protocol is loaded either from a `protocol.json` file, or from a protocol crate using
+`crate = "path/to/crate"`. In the latter case, the library target of the crate needs to expose
+the protocol using the `fp_protocol` macro:
+
+```ignore
+pub fn protocol() -> fp_bindgen::Protocol {
+    fp_bindgen::prelude::fp_protocol!()
+}
+```
+
+Then run `fp-bindgen --config fp-bindgen.toml`, optionally with `--only <type>` to only generate
+some of the configured bindings. Passing `--check` verifies that the bindings on disk are
+up-to-date instead of writing them, and exits with an error if they are not. See `examples/example-protocol/fp-bindgen.toml` for a complete
+example.
+
 ## Using the bindings
 
 How to use the generated bindings differs between the various types.
@@ -214,23 +369,124 @@ See the `example-plugin/` directory for an example of a plugin that uses binding
 our `example-protocol/` (do note this plugin only builds after you've run `cargo run` inside the
 `example-protocol/` directory).
 
+### Testing Rust plugins natively
+
+The generated crate has a `mock_host` feature that allows plugins to be compiled for the host
+target, so you can test them using a regular `cargo test` and debug them like any other Rust code.
+With the feature enabled, the imported functions no longer call into the runtime, but dispatch to
+an implementation of the generated `MockImports` trait instead. You can enable it for your tests
+only by also adding the bindings crate to your plugin's `[dev-dependencies]`:
+
+```toml
+[dev-dependencies]
+my-bindings = { path = "../bindings", features = ["mock_host"] }
+```
+
+Then register a mock for the current thread using `set_mock_imports()` before calling your
+functions. Imported functions that are not implemented by the mock panic when they are called:
+
+```ignore
+struct Mock;
+
+impl MockImports for Mock {
+    fn my_imported_function(&self, a: u32) -> u32 {
+        a + 1
+    }
+}
+
+#[test]
+fn test_my_exported_function() {
+    set_mock_imports(Mock);
+    assert_eq!(my_exported_function(1, 2), 5);
+}
+```
+
+Async imported functions are mocked using regular methods, which return their result directly.
+Note that the wrappers that are used when a plugin is loaded by a runtime are not usable with a
+mock host, so async exported functions can only be tested by awaiting them directly.
+
+The `example-plugin/` has tests that are set up this way. Because its Cargo config builds for the
+Wasm targets by default, run them using `cargo test --target <host target>`.
+
 ### Using the Rust Wasmer runtime bindings
 
 The generator for our Rust Wasmer runtime works a bit differently. Instead of generating a crate,
-it generates two files: `bindings.rs` and `types.rs`. These can be placed in a module of your
-choosing (we chose a module named `spec` in the `example-rust-runtime/`).
+it generates three files: `bindings.rs`, `types.rs` and `testing.rs`. These can be placed in a module
+of your choosing (we chose a module named `spec` in the `example-rust-runtime/`). The `testing.rs`
+module is only needed for testing plugins, as described below.
 
 As the implementor of the runtime, it is then your responsibility to implement the `fp_import!`
-functions within the same module as you've placed the generated files. You can see an example of
-this in `example-rust-runtime/spec/mod.rs` (do note the example runtime only builds after you've run
-`cargo run` inside the `example-protocol/` directory).
+functions. The generated `bindings.rs` contains an `Imports` trait with a method for every imported
+function, which you can implement on a type of your own choosing. You can see an example of this in
+`example-rust-runtime/spec/mod.rs` (do note the example runtime only builds after you've run
+`cargo run` inside the `example-protocol/` directory). Every runtime instance holds its own
+`Imports` implementation, so this is also where you keep any state that should be scoped to a
+single plugin instance.
 
 Finally, the `bindings.rs` file contains a constructor (`Runtime::new()`) that you can use to
-instantiate Wasmer runtimes with the Wasm module provided as a blob. The `fp_export!` functions are
-provided on the `Runtime` instance as methods. Please be aware that implementation of the
+instantiate Wasmer runtimes with the Wasm module provided as a blob, along with the `Imports`
+implementation for the new instance. The `fp_export!` functions are provided on the `Runtime` instance as methods. Please be aware that implementation of the
 `fp_export!` functions is always at the discretion of the plugin, and an attempt to invoke a missing
 implementation can fail with an `InvocationError::FunctionNotExported` error.
 
+If your protocol contains async imported functions, the futures returned by their implementations
+are spawned on the current Tokio runtime by default. To use another executor, such as async-std,
+smol or one of your own, create the runtime using `Runtime::with_spawner()` instead. It takes a
+`Spawner` from `fp_bindgen_support::common::r#async`, which is implemented for any closure that
+takes the boxed future:
+
+```ignore
+let runtime = Runtime::with_spawner(wasm_module, imports, |future| {
+    async_std::task::spawn(future);
+})?;
+```
+
+The Rust Wasmer 3 and Wasmtime runtime bindings are used in exactly the same way. Their generated
+`bindings.rs` relies on the `wasmer3_host` and `wasmtime_host` features of `fp-bindgen-support`,
+respectively, instead of `wasmer2_host`.
+
+### Testing plugins against the Rust runtime bindings
+
+The `fp-bindgen-testing` crate offers a harness for testing a compiled plugin in-process, using the
+runtime bindings generated for your protocol. Next to `bindings.rs`, the Rust runtime generators
+emit a `testing.rs` module, which contains the `RecordingImports` adapter. It implements the
+`Imports` trait by recording every call and its arguments in a `MockHost`, and returns either the
+stubbed value or that of the `Imports` implementation it wraps. Include the module in your tests
+only, so that only your tests depend on `fp-bindgen-testing`:
+
+```ignore
+pub mod bindings;
+#[cfg(test)]
+pub mod testing;
+pub mod types;
+```
+
+Tests then load the plugin using `PluginTest::new()`, which gives every test its own runtime and
+mock host. Return values can be stubbed per test, exported functions can be called on the test
+directly, and afterwards you can assert which imported functions were called with which arguments.
+The adapter registers the names of all imported functions with the mock host, so stubbing or
+asserting on a misspelled name panics instead of silently passing:
+
+```ignore
+use fp_bindgen_testing::{expect, PluginTest};
+
+#[test]
+fn add_three() {
+    let test = PluginTest::new(WASM_BYTES, |wasm, host| {
+        let runtime = Runtime::new(wasm, RecordingImports::new(host, MyImports::default()))?;
+        runtime.init()?;
+        Ok::<_, anyhow::Error>(runtime)
+    })
+    .unwrap();
+    test.imports().returns("import_primitive_u32_add_one", 42u32);
+
+    expect(test.export_primitive_u32_add_three(32)).to_return(43);
+    test.imports().assert_called_with("import_primitive_u32_add_one", (33u32,));
+}
+```
+
+The end-to-end tests in `examples/example-rust-wasmer2-runtime/` are written using this harness.
+
 ### Using the TypeScript runtime bindings
 
 The TypeScript runtime generator can work with browsers, Node.js and Deno.
@@ -242,6 +498,203 @@ are expected to provide implementations for all the `fp_import!` functions, whil
 `Promise` will give you an object with all the `fp_export!` functions the provided plugin has
 implemented.
 
+### Using the Python runtime bindings
+
+The Python runtime generator produces a package with a `runtime.py` and a `types.py`, for use with
+[`wasmtime-py`](https://github.com/bytecodealliance/wasmtime-py) and
+[`msgpack`](https://pypi.org/project/msgpack/). `types.py` contains `TypedDict` definitions for the
+data structures, while `runtime.py` contains an `Imports` protocol describing the `fp_import!`
+functions and a `Runtime` class that exposes the `fp_export!` functions as methods:
+
+```python
+from bindings import Runtime
+
+class Imports:
+    def log(self, message: str) -> None:
+        print(message)
+
+with open("plugin.wasm", "rb") as f:
+    runtime = Runtime(f.read(), Imports())
+
+runtime.my_plain_exported_function(1, 2)
+```
+
+Async functions are mapped to coroutines, so async imports should be implemented using `async def`
+and async exports need to be awaited. Both require a running `asyncio` event loop.
+
+### Using the Go runtime bindings
+
+The Go runtime generator produces a `runtime.go` and a `types.go` in a single package, for use with
+[`wazero`](https://wazero.io) and [`msgpack`](https://github.com/vmihailenco/msgpack). Because
+wazero is written in pure Go, no cgo is required. `types.go` contains the type definitions for the
+data structures, while `runtime.go` contains an `Imports` interface describing the `fp_import!`
+functions and a `Runtime` type that exposes the `fp_export!` functions as methods:
+
+```go
+type imports struct{}
+
+func (imports) Log(ctx context.Context, message string) {
+    fmt.Println(message)
+}
+
+runtime, err := bindings.NewRuntime(ctx, wasmModule, imports{})
+if err != nil {
+    return err
+}
+defer runtime.Close(ctx)
+
+result, err := runtime.MyPlainExportedFunction(ctx, 1, 2)
+```
+
+The package name defaults to `bindings` and can be changed using
+`GoWazeroRuntimeConfig::with_package_name()`. Async imports are called from their own goroutine,
+while async exports block until the plugin has resolved their result.
+
+### Writing plugins in C
+
+The C plugin generator produces bindings for writing plugins in C, without any dependencies besides
+the C standard library:
+
+- `fp_support.h` and `fp_support.c` contain a small support library. It provides the `__fp_malloc`
+  and `__fp_free` exports the runtime relies on, handles async values and bundles a minimal
+  MessagePack encoder/decoder. This library is the same for every protocol.
+- `types.h` and `types.c` contain the protocol's types, together with `fp_encode_*()`,
+  `fp_decode_*()` and `fp_free_*()` functions for each of them. Generic types are monomorphized, so
+  `Point<f64>` becomes `Point_f64`.
+- `bindings.h` and `bindings.c` declare the `fp_import!` functions and wrap them around the
+  `__fp_gen_*` imports, while the `__fp_gen_*` exports call into the `fp_export!` functions the
+  plugin should implement:
+
+```c
+#include "bindings.h"
+
+uint32_t my_exported_function(uint32_t a, uint32_t b) {
+    FpString message = fp_string("Hello from C!");
+    log_(&message);
+    fp_free_String(&message);
+    return a + b;
+}
+```
+
+Complex arguments are passed by pointer and remain owned by the caller, while returned values are
+owned by the receiver. Async imports take a callback that is invoked with their result, and async
+exports receive an `FpAsyncValue` that should be passed to the corresponding `_resolve()` function
+once the result is available. Functions that share their name with a C library function, such as
+`log`, receive a trailing underscore.
+
+Plugins can be compiled using [wasi-sdk](https://github.com/WebAssembly/wasi-sdk):
+
+```sh
+clang --target=wasm32-wasi -mexec-model=reactor -O2 -o plugin.wasm *.c
+```
+
+### Writing plugins in AssemblyScript
+
+The AssemblyScript plugin generator produces bindings for writing plugins in
+[AssemblyScript](https://www.assemblyscript.org/), a TypeScript-like language that compiles to
+WebAssembly:
+
+- `support.ts` contains a small support library. It provides the `__fp_malloc` and `__fp_free`
+  exports the runtime relies on, handles async values and bundles a minimal MessagePack
+  encoder/decoder. This library is the same for every protocol.
+- `types.ts` contains a class for every type in the protocol, together with `encode_*()` and
+  `decode_*()` functions for each of them. Generic types are monomorphized, so `Point<f64>` becomes
+  `Point_f64`. Enums with payloads become a base class with a `tag` property and a subclass for
+  every variant, such as `RequestError_Timeout`.
+- `imports.ts` wraps the `__fp_gen_*` imports in functions that take and return these classes.
+- `exports.ts` provides the `__fp_gen_*` exports, which call into the `fp_export!` functions the
+  plugin should implement.
+
+By convention, the bindings are placed in `assembly/bindings/`, and the exported functions are
+implemented in `assembly/plugin.ts`, using the camelCase variants of their names. The entry file
+should then re-export the generated exports:
+
+```ts
+// assembly/plugin.ts
+import { log } from "./bindings/imports";
+
+export function myExportedFunction(a: u32, b: u32): u32 {
+  log("Hello from AssemblyScript!");
+  return a + b;
+}
+
+// assembly/index.ts
+export * from "./bindings/exports";
+```
+
+Async imports take a callback that is invoked with their result, and async exports receive an
+`AsyncValue` that should be passed to the corresponding `*Resolve()` function from `imports.ts`
+once the result is available. Optional primitives are wrapped in a `Box`, since AssemblyScript
+does not support nullable value types.
+
+Plugins can be compiled using `asc`, which should be told to report aborts through the support
+library:
+
+```sh
+asc assembly/index.ts --outFile plugin.wasm --optimize --use abort=assembly/bindings/support/fpAbort
+```
+
+### Writing plugins in TinyGo
+
+The TinyGo plugin generator produces a Go package for writing plugins in Go, to be compiled using
+[TinyGo](https://tinygo.org/) 0.34 or newer:
+
+- `support.go` contains a small support library. It provides the `__fp_malloc` and `__fp_free`
+  exports the runtime relies on, which keep allocations in a table until they are freed, so the
+  garbage collector cannot reclaim memory the runtime still uses. It also handles async values and
+  bundles a minimal MessagePack encoder/decoder. This library is the same for every protocol.
+- `types.go` contains the protocol's types, in the same shape as the Go runtime bindings, together
+  with unexported encoding functions for each of them.
+- `bindings.go` declares the `__fp_gen_*` imports using `//go:wasmimport` and wraps them in
+  functions that take and return the protocol's types. It also defines an `Exports` interface for
+  the `fp_export!` functions, which the `//go:wasmexport` wrappers call into.
+
+Plugins should register their implementation of the `Exports` interface from an `init()` function:
+
+```go
+type plugin struct{}
+
+func (plugin) MyExportedFunction(a uint32, b uint32) uint32 {
+    bindings.Log("Hello from TinyGo!")
+    return a + b
+}
+
+func init() {
+    bindings.SetExports(plugin{})
+}
+
+func main() {}
+```
+
+Async imports take a callback that is invoked with their result, and async exports receive an
+additional callback to which they should pass their result once it is available. The package name
+defaults to `bindings` and can be changed using `TinyGoPluginConfig::with_package_name()`.
+
+Plugins can be compiled as a reactor module, so that they keep running after initialization:
+
+```sh
+tinygo build -target=wasm-unknown -buildmode=c-shared -o plugin.wasm .
+```
+
+Such modules export an `_initialize` function, which initializes the Go runtime and needs to be
+called before any other export. The runtimes generated by `fp-bindgen` call it when they are created,
+before verifying the protocol version of the plugin.
+
+### Exporting to WIT
+
+To ease a migration to the [component model](https://component-model.bytecodealliance.org/), the
+WIT world generator translates the protocol into a `world.wit` file. Structs become records, enums
+become variants (or WIT enums if none of their variants carry data), and `Option` and `Result` map
+onto their WIT counterparts. WIT has no generics, so every instantiation of a generic type is
+defined separately, with its type arguments appended to its name, such as `point-f64`. Imports and
+exports of the world correspond to the `fp_import!` and `fp_export!` functions, where async
+functions are declared using `async func`.
+
+Not every protocol can be expressed in WIT. Flattened fields, untagged enums and custom types are
+approximated, and each of them is listed in a `report.md` file next to the world, so you can review
+them before migrating. The package and world names default to `local:protocol` and `plugin`, and can
+be changed using `WitWorldConfig::with_package_name()` and `WitWorldConfig::with_world_name()`.
+
 ## Examples
 
 Please have a look at [`examples/README.md`](examples/README.md) for various examples on how to use
@@ -302,9 +755,24 @@ fp_bindgen::prelude::fp_import! {
 
 ### What about versioning?
 
-Generally, versioning is considered out-of-scope for this project. This means it is your own
-responsibility to verify a plugin you execute was compiled against a compatible version of the
-protocol your runtime provides.
+Plugins generated by `fp-bindgen` export a `__fp_protocol_version()` function, which returns a
+fingerprint of the function signatures and type definitions of the protocol they were compiled
+against. Doc comments are not included in the fingerprint. The Rust runtimes verify this
+fingerprint as part of `Runtime::new()`, and fail with `RuntimeError::ProtocolMismatch` if it does
+not match their own `PROTOCOL_VERSION`. The TypeScript runtime throws an `FPProtocolMismatch` error
+from `createRuntime()` instead. The fingerprint can also be computed using
+`fp_bindgen::protocol_fingerprint()`.
+
+Plugins built with older bindings don't export `__fp_protocol_version()`, and are accepted without
+verification. Plugins that do export it, but whose fingerprint cannot be retrieved because the
+function has another signature or traps, are rejected with `RuntimeError::ProtocolCheckFailed` by
+the Rust runtimes. If you know a plugin to be compatible even though its fingerprint differs, you can
+skip the check using `Runtime::new_unchecked()` (or `Runtime::with_spawner_unchecked()`) in Rust,
+or by passing `{ skipProtocolCheck: true }` as the options to `createRuntime()` in TypeScript.
+
+Beyond this check, versioning is considered out-of-scope for this project. Note that any change
+to the protocol changes its fingerprint, so plugins need to be rebuilt whenever the protocol
+changes, even if the change is backwards compatible according to the guidelines below.
 
 If your protocol ever needs to introduce breaking changes, we advise to include a `version() -> u32`
 export function in the protocol itself that you can call before invoking any other functions.
@@ -350,7 +818,9 @@ See [LICENSE-APACHE](LICENSE-APACHE) and [LICENSE-MIT](LICENSE-MIT).
 */
 
 mod casing;
+mod compatibility;
 mod docs;
+mod errors;
 mod functions;
 #[cfg(feature = "generators")]
 mod generators;
@@ -358,6 +828,7 @@ mod serializable;
 
 pub mod prelude;
 pub mod primitives;
+mod protocol;
 pub mod types;
 mod utils;
 
@@ -366,8 +837,17 @@ use prelude::*;
 
 primitive_impls!();
 
+pub use compatibility::{check_compatibility, BreakingChange, CompatibilityReport};
+pub use errors::BindgenError;
+pub use protocol::Protocol;
+
 #[cfg(feature = "generators")]
 pub use generators::{
-    generate_bindings, BindingConfig, BindingsType, RustPluginConfig, RustPluginConfigValue,
-    TsRuntimeConfig,
+    check_bindings, generate_bindings,
+    protocol_json::{
+        protocol_fingerprint, protocol_from_json, protocol_to_json, PROTOCOL_JSON_VERSION,
+    },
+    render_bindings, try_generate_bindings, ApiReferenceConfig, ApiReferenceFormat, BindingConfig,
+    BindingsType, GoWazeroRuntimeConfig, OutdatedBindings, OutdatedFile, RustPluginConfig,
+    RustPluginConfigValue, TinyGoPluginConfig, TsRuntimeConfig, WitWorldConfig,
 };
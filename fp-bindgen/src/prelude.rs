@@ -4,6 +4,7 @@ pub use crate::serializable::Serializable;
 pub use crate::types::{CustomType, Type, TypeIdent, TypeMap};
 #[cfg(feature = "generators")]
 pub use crate::{
-    BindingConfig, BindingsType, RustPluginConfig, RustPluginConfigValue, TsRuntimeConfig,
+    ApiReferenceConfig, ApiReferenceFormat, BindingConfig, BindingsType, GoWazeroRuntimeConfig,
+    RustPluginConfig, RustPluginConfigValue, TinyGoPluginConfig, TsRuntimeConfig, WitWorldConfig,
 };
 pub use fp_bindgen_macros::*;
@@ -0,0 +1,28 @@
+use crate::{functions::FunctionList, types::TypeMap};
+
+/// All the functions and types that make up a protocol, as collected from the
+/// `fp_import!` and `fp_export!` blocks.
+///
+/// Use the `fp_protocol!()` macro to obtain the protocol declared in the
+/// current module.
+#[derive(Clone, Debug, Default)]
+pub struct Protocol {
+    /// Functions that are implemented by the runtime and imported by plugins.
+    pub import_functions: FunctionList,
+
+    /// Functions that are implemented by plugins and exported to the runtime.
+    pub export_functions: FunctionList,
+
+    /// All the types that are used by the functions.
+    pub types: TypeMap,
+}
+
+#[cfg(feature = "generators")]
+impl Protocol {
+    /// Serializes the protocol to a versioned JSON document.
+    ///
+    /// See also: `protocol_to_json()`.
+    pub fn to_json(&self) -> String {
+        crate::protocol_to_json(&self.import_functions, &self.export_functions, &self.types)
+    }
+}
@@ -3,7 +3,10 @@ use super::{
     Type, TypeIdent,
 };
 use crate::types::format_bounds;
-use crate::{casing::Casing, docs::get_doc_lines, primitives::Primitive, types::FieldAttrs};
+use crate::{
+    casing::Casing, docs::get_doc_lines, errors::BindgenError, primitives::Primitive,
+    types::FieldAttrs,
+};
 use quote::ToTokens;
 use std::{convert::TryFrom, str::FromStr};
 use syn::{
@@ -19,7 +22,7 @@ pub struct Enum {
     pub options: EnumOptions,
 }
 
-pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
+pub(crate) fn parse_enum_item(item: ItemEnum) -> std::result::Result<Enum, BindgenError> {
     let ident = TypeIdent {
         name: item.ident.to_string(),
         generic_args: item
@@ -35,16 +38,22 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
             .collect(),
         ..Default::default()
     };
-    let options = EnumOptions::from_attrs(&item.attrs);
+    let invalid_type = |message: String| BindgenError::InvalidType {
+        ty: ident.to_string(),
+        message,
+    };
+
+    let options = EnumOptions::try_from_attrs(&item.attrs)
+        .map_err(|error| invalid_type(format!("Could not parse attributes: {error}")))?;
     let variants = item
         .variants
         .iter()
         .map(|variant| {
             if variant.discriminant.is_some() {
-                panic!(
-                    "Discriminants in enum variants are not supported. Found: {:?}",
-                    item
-                );
+                return Err(invalid_type(format!(
+                    "Discriminants in enum variants are not supported, found one in variant `{}`",
+                    variant.ident
+                )));
             }
 
             // Variants with inline tags may result in unserializable types.
@@ -62,25 +71,33 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
                         let name = field
                             .ident
                             .as_ref()
-                            .unwrap_or_else(|| panic!("Unnamed field in variant of enum {}", ident))
+                            .ok_or_else(|| {
+                                invalid_type(format!(
+                                    "Unnamed field in variant `{}`",
+                                    variant.ident
+                                ))
+                            })?
                             .to_string();
                         if has_inline_tag && options.tag_prop_name.as_ref() == Some(&name) {
-                            panic!(
-                                "Enum {} cannot be serialized, because the variant `{}` has a \
-                                    field with the same name as the enum's `tag` attribute",
-                                ident, variant.ident
-                            );
+                            return Err(invalid_type(format!(
+                                "Enum cannot be serialized, because the variant `{}` has a field \
+                                    with the same name as the enum's `tag` attribute",
+                                variant.ident
+                            )));
                         }
 
-                        Field {
+                        Ok(Field {
                             name: Some(name),
-                            ty: TypeIdent::try_from(&field.ty)
-                                .unwrap_or_else(|_| panic!("Invalid field type in enum {}", ident)),
+                            ty: TypeIdent::try_from(&field.ty).map_err(|error| {
+                                invalid_type(format!("Invalid field type: {error}"))
+                            })?,
                             doc_lines: get_doc_lines(&field.attrs),
-                            attrs: FieldAttrs::from_attrs(&field.attrs),
-                        }
+                            attrs: FieldAttrs::try_from_attrs(&field.attrs).map_err(|error| {
+                                invalid_type(format!("Could not parse field attributes: {error}"))
+                            })?,
+                        })
                     })
-                    .collect();
+                    .collect::<std::result::Result<_, _>>()?;
                 Type::Struct(Struct {
                     ident: TypeIdent::from(name.clone()),
                     fields,
@@ -88,54 +105,55 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
                     options: StructOptions::default(),
                 })
             } else {
-                let item_types: Vec<_> = variant
+                let item_types = variant
                     .fields
                     .iter()
                     .map(|field| {
                         if has_inline_tag && is_path_to_primitive(&field.ty) {
-                            panic!(
-                                "Enum {} cannot be serialized, because the variant `{}` has a \
+                            return Err(invalid_type(format!(
+                                "Enum cannot be serialized, because the variant `{}` has a \
                                     primitive unnamed field ({}) and the enum has no `content` \
                                     attribute",
-                                ident,
                                 variant.ident,
                                 field.ty.to_token_stream()
-                            );
+                            )));
                         }
 
                         TypeIdent::try_from(&field.ty)
-                            .unwrap_or_else(|_| panic!("Invalid field type in enum {}", ident))
+                            .map_err(|error| invalid_type(format!("Invalid field type: {error}")))
                     })
-                    .collect();
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
 
                 if has_inline_tag && item_types.len() > 1 {
-                    panic!(
-                        "Enum {} cannot be serialized, because the variant `{}` contains multiple \
+                    return Err(invalid_type(format!(
+                        "Enum cannot be serialized, because the variant `{}` contains multiple \
                             unnamed fields and the enum has no `content` attribute",
-                        ident, variant.ident,
-                    );
+                        variant.ident,
+                    )));
                 }
 
                 Type::Tuple(item_types)
             };
             let doc_lines = get_doc_lines(&variant.attrs);
-            let attrs = VariantAttrs::from_attrs(&variant.attrs);
+            let attrs = VariantAttrs::try_from_attrs(&variant.attrs).map_err(|error| {
+                invalid_type(format!("Could not parse variant attributes: {error}"))
+            })?;
 
-            Variant {
+            Ok(Variant {
                 name,
                 ty,
                 doc_lines,
                 attrs,
-            }
+            })
         })
-        .collect();
+        .collect::<std::result::Result<_, _>>()?;
 
-    Enum {
+    Ok(Enum {
         ident,
         variants,
         doc_lines: get_doc_lines(&item.attrs),
         options,
-    }
+    })
 }
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
@@ -173,15 +191,17 @@ pub struct EnumOptions {
 
 impl EnumOptions {
     pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        Self::try_from_attrs(attrs).expect("Could not parse attributes")
+    }
+
+    pub fn try_from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut opts = Self::default();
         for attr in attrs {
             if attr.path.is_ident("fp") || attr.path.is_ident("serde") {
-                opts.merge_with(
-                    &syn::parse2::<Self>(attr.tokens.clone()).expect("Could not parse attributes"),
-                );
+                opts.merge_with(&syn::parse2::<Self>(attr.tokens.clone())?);
             }
         }
-        opts
+        Ok(opts)
     }
 
     fn merge_with(&mut self, other: &EnumOptions) {
@@ -289,16 +309,17 @@ pub struct VariantAttrs {
 
 impl VariantAttrs {
     pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        Self::try_from_attrs(attrs).expect("Could not parse variant attributes")
+    }
+
+    pub fn try_from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut opts = Self::default();
         for attr in attrs {
             if attr.path.is_ident("fp") || attr.path.is_ident("serde") {
-                opts.merge_with(
-                    &syn::parse2::<Self>(attr.tokens.clone())
-                        .expect("Could not parse variant attributes"),
-                );
+                opts.merge_with(&syn::parse2::<Self>(attr.tokens.clone())?);
             }
         }
-        opts
+        Ok(opts)
     }
 
     fn merge_with(&mut self, other: &Self) {
@@ -1,4 +1,5 @@
-use crate::primitives::Primitive;
+use crate::{errors::BindgenError, primitives::Primitive};
+use quote::ToTokens;
 use std::{collections::BTreeMap, hash::Hash};
 use syn::{Item, TypeParam, TypeParamBound};
 
@@ -34,14 +35,21 @@ pub enum Type {
 
 impl Type {
     pub fn from_item(item_str: &str) -> Self {
-        let item = syn::parse_str::<Item>(item_str).unwrap();
+        Self::try_from_item(item_str).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_from_item(item_str: &str) -> Result<Self, BindgenError> {
+        let item = syn::parse_str::<Item>(item_str).map_err(|error| BindgenError::InvalidType {
+            ty: item_str.trim().to_owned(),
+            message: format!("Cannot parse type definition: {error}"),
+        })?;
         match item {
-            Item::Enum(item) => Type::Enum(enums::parse_enum_item(item)),
-            Item::Struct(item) => Type::Struct(structs::parse_struct_item(item)),
-            item => panic!(
-                "Only struct and enum types can be constructed from an item. Found: {:?}",
-                item
-            ),
+            Item::Enum(item) => enums::parse_enum_item(item).map(Type::Enum),
+            Item::Struct(item) => structs::parse_struct_item(item).map(Type::Struct),
+            item => Err(BindgenError::InvalidType {
+                ty: item.to_token_stream().to_string(),
+                message: "Only struct and enum types can be constructed from an item".to_owned(),
+            }),
         }
     }
 
@@ -1,6 +1,6 @@
 use super::TypeIdent;
 use crate::types::format_bounds;
-use crate::{casing::Casing, docs::get_doc_lines};
+use crate::{casing::Casing, docs::get_doc_lines, errors::BindgenError};
 use quote::ToTokens;
 use std::convert::TryFrom;
 use syn::{
@@ -16,7 +16,7 @@ pub struct Struct {
     pub options: StructOptions,
 }
 
-pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
+pub(crate) fn parse_struct_item(item: ItemStruct) -> std::result::Result<Struct, BindgenError> {
     let ident = TypeIdent {
         name: item.ident.to_string(),
         generic_args: item
@@ -32,24 +32,35 @@ pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
             .collect(),
         ..Default::default()
     };
+    let invalid_type = |message: String| BindgenError::InvalidType {
+        ty: ident.to_string(),
+        message,
+    };
+
     let fields = item
         .fields
         .iter()
-        .map(|field| Field {
-            name: field.ident.as_ref().map(Ident::to_string),
-            ty: TypeIdent::try_from(&field.ty)
-                .unwrap_or_else(|_| panic!("Invalid field type in struct {}", ident)),
-            doc_lines: get_doc_lines(&field.attrs),
-            attrs: FieldAttrs::from_attrs(&field.attrs),
+        .map(|field| {
+            Ok(Field {
+                name: field.ident.as_ref().map(Ident::to_string),
+                ty: TypeIdent::try_from(&field.ty)
+                    .map_err(|error| invalid_type(format!("Invalid field type: {error}")))?,
+                doc_lines: get_doc_lines(&field.attrs),
+                attrs: FieldAttrs::try_from_attrs(&field.attrs).map_err(|error| {
+                    invalid_type(format!("Could not parse field attributes: {error}"))
+                })?,
+            })
         })
-        .collect();
+        .collect::<std::result::Result<_, BindgenError>>()?;
+    let options = StructOptions::try_from_attrs(&item.attrs)
+        .map_err(|error| invalid_type(format!("Could not parse attributes: {error}")))?;
 
-    Struct {
+    Ok(Struct {
         ident,
         fields,
         doc_lines: get_doc_lines(&item.attrs),
-        options: StructOptions::from_attrs(&item.attrs),
-    }
+        options,
+    })
 }
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
@@ -80,15 +91,17 @@ pub struct StructOptions {
 
 impl StructOptions {
     pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        Self::try_from_attrs(attrs).expect("Could not parse attributes")
+    }
+
+    pub fn try_from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut opts = Self::default();
         for attr in attrs {
             if attr.path.is_ident("fp") || attr.path.is_ident("serde") {
-                opts.merge_with(
-                    &syn::parse2::<Self>(attr.tokens.clone()).expect("Could not parse attributes"),
-                );
+                opts.merge_with(&syn::parse2::<Self>(attr.tokens.clone())?);
             }
         }
-        opts
+        Ok(opts)
     }
 
     fn merge_with(&mut self, other: &Self) {
@@ -206,16 +219,17 @@ pub struct FieldAttrs {
 
 impl FieldAttrs {
     pub fn from_attrs(attrs: &[Attribute]) -> Self {
+        Self::try_from_attrs(attrs).expect("Could not parse field attributes")
+    }
+
+    pub fn try_from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut opts = Self::default();
         for attr in attrs {
             if attr.path.is_ident("fp") || attr.path.is_ident("serde") {
-                opts.merge_with(
-                    &syn::parse2::<Self>(attr.tokens.clone())
-                        .expect("Could not parse field attributes"),
-                );
+                opts.merge_with(&syn::parse2::<Self>(attr.tokens.clone())?);
             }
         }
-        opts
+        Ok(opts)
     }
 
     fn merge_with(&mut self, other: &Self) {
@@ -1,5 +1,6 @@
 use super::is_runtime_bound;
 use crate::primitives::Primitive;
+use quote::ToTokens;
 use std::num::NonZeroUsize;
 use std::{convert::TryFrom, fmt::Display, str::FromStr};
 use syn::{PathArguments, TypeParamBound, TypePath, TypeTuple};
@@ -95,15 +96,14 @@ impl FromStr for TypeIdent {
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let (string, array) = if string.starts_with('[') {
             // Remove brackets and split on ;
-            let split = string
+            let (element, len) = string
                 .strip_prefix('[')
                 .and_then(|s| s.strip_suffix(']'))
-                .ok_or(format!("Invalid array syntax in: {string}"))?
-                .split(';')
-                .collect::<Vec<_>>();
+                .and_then(|s| s.split_once(';'))
+                .ok_or(format!("Invalid array syntax in: {string}"))?;
 
-            let element = split[0].trim();
-            let len = usize::from_str(split[1].trim())
+            let element = element.trim();
+            let len = usize::from_str(len.trim())
                 .map_err(|_| format!("Invalid array length in: {string}"))?;
 
             let primitive = Primitive::from_str(element)?;
@@ -188,10 +188,10 @@ impl TryFrom<&syn::Type> for TypeIdent {
                 ..
             }) => {
                 let array_len = match lit {
-                    syn::Lit::Int(int) => int.base10_digits().parse::<usize>(),
-                    _ => panic!(),
+                    syn::Lit::Int(int) => int.base10_digits().parse::<usize>().ok(),
+                    _ => None,
                 }
-                .unwrap();
+                .ok_or_else(|| format!("Invalid array length: {}", lit.to_token_stream()))?;
                 let elem_ident = TypeIdent::try_from(elem.as_ref())?;
 
                 Ok(Self {
@@ -209,6 +209,28 @@ pub fn fp_bindgen(args: TokenStream) -> TokenStream {
     replacement.into()
 }
 
+/// Returns a `fp_bindgen::Protocol` with the functions declared in the `fp_import!{}` and
+/// `fp_export!{}` blocks.
+#[proc_macro]
+pub fn fp_protocol(_: TokenStream) -> TokenStream {
+    let replacement = quote! {
+        {
+            let (import_functions, import_types) = __fp_declare_import_fns();
+            let (export_functions, mut export_types) = __fp_declare_export_fns();
+
+            let mut types = import_types;
+            types.append(&mut export_types);
+
+            fp_bindgen::Protocol {
+                import_functions,
+                export_functions,
+                types,
+            }
+        }
+    };
+    replacement.into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn primitive_impls(_: TokenStream) -> TokenStream {
@@ -317,8 +339,10 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         /// This is a implementation detail an should not be called directly
         #[inline(always)]
         pub #sig {
+            fp_bindgen_support::guest::panic::install_panic_hook();
             #(let #complex_names = unsafe { fp_bindgen_support::guest::io::import_value_from_host::<#complex_types>(#complex_names) };)*
             #func_wrapper
+            fp_bindgen_support::guest::panic::install_panic_hook();
             ret
         }
     })
@@ -11,6 +11,8 @@ pub fn clean() -> TaskResult<()> {
         "examples/example-plugin/target",
         "examples/example-protocol/bindings",
         "examples/example-rust-wasmer2-runtime/target",
+        "examples/example-rust-wasmer3-runtime/target",
+        "examples/example-rust-wasmtime-runtime/target",
     ];
     let mut progress = ProgressReporter::new(paths.len());
 
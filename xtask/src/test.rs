@@ -13,7 +13,7 @@ static TRUCK: Emoji<'_, '_> = Emoji("🚚 ", "");
 static TEST: Emoji<'_, '_> = Emoji("🧪 ", "");
 
 pub fn test() -> TaskResult<()> {
-    let mut progress = ProgressReporter::new(9);
+    let mut progress = ProgressReporter::new(12);
     progress.next_step(LOOKING_GLASS, "Checking prerequisites...");
 
     let deno_path = which("deno").with_context(|| {
@@ -58,6 +58,15 @@ pub fn test() -> TaskResult<()> {
     progress.next_step(TRUCK, "Building example plugin...");
     run(cargo(["build"]).dir(from_root("examples/example-plugin")))?;
 
+    progress.next_step(TEST, "Running example plugin tests using the mock host...");
+    let output = run(cmd("rustc", &["-vV"]).stdout_capture())?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let host = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .context("Could not determine the host target")?;
+    run(cargo(["test", "--target", host]).dir(from_root("examples/example-plugin")))?;
+
     progress.next_step(TEST, "Running deno tests...");
     run(deno(["test", "--allow-read", "tests.ts"]).dir(from_root("examples/example-deno-runtime")))?;
 
@@ -71,5 +80,11 @@ pub fn test() -> TaskResult<()> {
     run(cargo(["test", "--features", "wasi"])
         .dir(from_root("examples/example-rust-wasmer2-runtime")))?;
 
+    progress.next_step(TEST, "Running end-to-end wasmer 3 tests...");
+    run(cargo(["test"]).dir(from_root("examples/example-rust-wasmer3-runtime")))?;
+
+    progress.next_step(TEST, "Running end-to-end wasmtime tests...");
+    run(cargo(["test"]).dir(from_root("examples/example-rust-wasmtime-runtime")))?;
+
     Ok(())
 }